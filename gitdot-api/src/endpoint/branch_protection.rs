@@ -0,0 +1,8 @@
+pub mod create_branch_protection;
+pub mod delete_branch_protection;
+pub mod list_branch_protections;
+pub mod update_branch_protection;
+
+fn default_true() -> bool {
+    true
+}
@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+
+use super::default_true;
+use crate::{endpoint::Endpoint, resource::BranchProtectionResource};
+
+pub struct CreateBranchProtection;
+
+impl Endpoint for CreateBranchProtection {
+    const PATH: &'static str = "/repository/{owner}/{repo}/branch-protections";
+    const METHOD: http::Method = http::Method::POST;
+
+    type Request = CreateBranchProtectionRequest;
+    type Response = CreateBranchProtectionResponse;
+}
+
+#[derive(ApiRequest, Debug, Serialize, Deserialize)]
+pub struct CreateBranchProtectionRequest {
+    pub pattern: String,
+    #[serde(default = "default_true")]
+    pub block_force_push: bool,
+    #[serde(default = "default_true")]
+    pub block_deletion: bool,
+    #[serde(default)]
+    pub require_review: bool,
+    #[serde(default)]
+    pub require_green_build: bool,
+}
+
+pub type CreateBranchProtectionResponse = BranchProtectionResource;
@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+use crate::endpoint::Endpoint;
+
+pub struct DeleteBranchProtection;
+
+impl Endpoint for DeleteBranchProtection {
+    const PATH: &'static str = "/repository/{owner}/{repo}/branch-protections/{protection_id}";
+    const METHOD: http::Method = http::Method::DELETE;
+
+    type Request = DeleteBranchProtectionRequest;
+    type Response = DeleteBranchProtectionResponse;
+}
+
+#[derive(ApiRequest, Debug, Serialize, Deserialize)]
+pub struct DeleteBranchProtectionRequest {}
+
+pub type DeleteBranchProtectionResponse = ();
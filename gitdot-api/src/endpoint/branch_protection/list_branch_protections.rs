@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{endpoint::Endpoint, resource::BranchProtectionResource};
+
+pub struct ListBranchProtections;
+
+impl Endpoint for ListBranchProtections {
+    const PATH: &'static str = "/repository/{owner}/{repo}/branch-protections";
+    const METHOD: http::Method = http::Method::GET;
+
+    type Request = ListBranchProtectionsRequest;
+    type Response = ListBranchProtectionsResponse;
+}
+
+#[derive(ApiRequest, Debug, Default, Serialize, Deserialize)]
+pub struct ListBranchProtectionsRequest {}
+
+pub type ListBranchProtectionsResponse = Vec<BranchProtectionResource>;
@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{endpoint::Endpoint, resource::BranchProtectionResource};
+
+pub struct UpdateBranchProtection;
+
+impl Endpoint for UpdateBranchProtection {
+    const PATH: &'static str = "/repository/{owner}/{repo}/branch-protections/{protection_id}";
+    const METHOD: http::Method = http::Method::PATCH;
+
+    type Request = UpdateBranchProtectionRequest;
+    type Response = UpdateBranchProtectionResponse;
+}
+
+#[derive(ApiRequest, Debug, Serialize, Deserialize)]
+pub struct UpdateBranchProtectionRequest {
+    pub pattern: String,
+    pub block_force_push: bool,
+    pub block_deletion: bool,
+    pub require_review: bool,
+    pub require_green_build: bool,
+}
+
+pub type UpdateBranchProtectionResponse = BranchProtectionResource;
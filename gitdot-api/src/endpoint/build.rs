@@ -1,4 +1,8 @@
+pub mod cancel_build;
 pub mod create_build;
+pub mod download_build_artifact;
 pub mod get_build;
+pub mod list_build_artifacts;
 pub mod list_build_tasks;
 pub mod list_builds;
+pub mod retry_build_task;
@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{endpoint::Endpoint, resource::BuildResource};
+
+pub struct CancelBuild;
+
+impl Endpoint for CancelBuild {
+    const PATH: &'static str = "/repository/{owner}/{repo}/build/{number}/cancel";
+    const METHOD: http::Method = http::Method::POST;
+
+    type Request = CancelBuildRequest;
+    type Response = CancelBuildResponse;
+}
+
+#[derive(ApiRequest, Debug, Serialize, Deserialize)]
+pub struct CancelBuildRequest;
+
+pub type CancelBuildResponse = BuildResource;
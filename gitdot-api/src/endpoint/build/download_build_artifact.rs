@@ -0,0 +1,11 @@
+use crate::endpoint::Endpoint;
+
+pub struct DownloadBuildArtifact;
+
+impl Endpoint for DownloadBuildArtifact {
+    const PATH: &'static str = "/repository/{owner}/{repo}/build/{number}/artifact/{id}";
+    const METHOD: http::Method = http::Method::GET;
+
+    type Request = ();
+    type Response = ();
+}
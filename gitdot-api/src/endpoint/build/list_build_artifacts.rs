@@ -0,0 +1,13 @@
+use crate::{endpoint::Endpoint, resource::ArtifactResource};
+
+pub struct ListBuildArtifacts;
+
+impl Endpoint for ListBuildArtifacts {
+    const PATH: &'static str = "/repository/{owner}/{repo}/build/{number}/artifacts";
+    const METHOD: http::Method = http::Method::GET;
+
+    type Request = ();
+    type Response = ListBuildArtifactsResponse;
+}
+
+pub type ListBuildArtifactsResponse = Vec<ArtifactResource>;
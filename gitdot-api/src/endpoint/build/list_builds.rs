@@ -17,6 +17,8 @@ impl Endpoint for ListBuilds {
 
 #[derive(ApiRequest, Debug, Default, Serialize, Deserialize)]
 pub struct ListBuildsRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub review: Option<i32>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub cursor: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
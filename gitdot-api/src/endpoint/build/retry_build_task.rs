@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{endpoint::Endpoint, resource::TaskResource};
+
+pub struct RetryBuildTask;
+
+impl Endpoint for RetryBuildTask {
+    const PATH: &'static str = "/repository/{owner}/{repo}/build/{number}/task/{task_id}/retry";
+    const METHOD: http::Method = http::Method::POST;
+
+    type Request = RetryBuildTaskRequest;
+    type Response = RetryBuildTaskResponse;
+}
+
+#[derive(ApiRequest, Debug, Serialize, Deserialize)]
+pub struct RetryBuildTaskRequest;
+
+pub type RetryBuildTaskResponse = TaskResource;
@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{endpoint::Endpoint, resource::CodeSearchResource};
+
+pub struct SearchCode;
+
+impl Endpoint for SearchCode {
+    const PATH: &'static str = "/repository/{owner}/{repo}/search";
+    const METHOD: http::Method = http::Method::GET;
+
+    type Request = SearchCodeRequest;
+    type Response = SearchCodeResponse;
+}
+
+#[derive(ApiRequest, Debug, Default, Serialize, Deserialize)]
+pub struct SearchCodeRequest {
+    pub q: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub regex: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub case_sensitive: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+}
+
+pub type SearchCodeResponse = CodeSearchResource;
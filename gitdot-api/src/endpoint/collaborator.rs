@@ -0,0 +1,9 @@
+pub mod accept_repository_invitation;
+pub mod cancel_repository_invitation;
+pub mod decline_repository_invitation;
+pub mod invite_repository_collaborator;
+pub mod list_repository_collaborators;
+pub mod list_repository_invitations;
+pub mod list_user_invitations;
+pub mod remove_repository_collaborator;
+pub mod update_repository_collaborator;
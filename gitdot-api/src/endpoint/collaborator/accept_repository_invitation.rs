@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{endpoint::Endpoint, resource::CollaboratorResource};
+
+pub struct AcceptRepositoryInvitation;
+
+impl Endpoint for AcceptRepositoryInvitation {
+    const PATH: &'static str = "/user/invitation/{invitation_id}/accept";
+    const METHOD: http::Method = http::Method::POST;
+
+    type Request = AcceptRepositoryInvitationRequest;
+    type Response = AcceptRepositoryInvitationResponse;
+}
+
+#[derive(ApiRequest, Debug, Serialize, Deserialize)]
+pub struct AcceptRepositoryInvitationRequest {}
+
+pub type AcceptRepositoryInvitationResponse = CollaboratorResource;
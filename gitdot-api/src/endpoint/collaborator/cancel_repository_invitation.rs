@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+use crate::endpoint::Endpoint;
+
+pub struct CancelRepositoryInvitation;
+
+impl Endpoint for CancelRepositoryInvitation {
+    const PATH: &'static str = "/repository/{owner}/{repo}/invitation/{invitation_id}";
+    const METHOD: http::Method = http::Method::DELETE;
+
+    type Request = CancelRepositoryInvitationRequest;
+    type Response = CancelRepositoryInvitationResponse;
+}
+
+#[derive(ApiRequest, Debug, Serialize, Deserialize)]
+pub struct CancelRepositoryInvitationRequest {}
+
+pub type CancelRepositoryInvitationResponse = ();
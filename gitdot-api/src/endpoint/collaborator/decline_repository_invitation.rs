@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+use crate::endpoint::Endpoint;
+
+pub struct DeclineRepositoryInvitation;
+
+impl Endpoint for DeclineRepositoryInvitation {
+    const PATH: &'static str = "/user/invitation/{invitation_id}/decline";
+    const METHOD: http::Method = http::Method::POST;
+
+    type Request = DeclineRepositoryInvitationRequest;
+    type Response = DeclineRepositoryInvitationResponse;
+}
+
+#[derive(ApiRequest, Debug, Serialize, Deserialize)]
+pub struct DeclineRepositoryInvitationRequest {}
+
+pub type DeclineRepositoryInvitationResponse = ();
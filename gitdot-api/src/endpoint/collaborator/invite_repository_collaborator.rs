@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{endpoint::Endpoint, resource::RepositoryInvitationResource};
+
+pub struct InviteRepositoryCollaborator;
+
+impl Endpoint for InviteRepositoryCollaborator {
+    const PATH: &'static str = "/repository/{owner}/{repo}/collaborator";
+    const METHOD: http::Method = http::Method::POST;
+
+    type Request = InviteRepositoryCollaboratorRequest;
+    type Response = InviteRepositoryCollaboratorResponse;
+}
+
+#[derive(ApiRequest, Debug, Serialize, Deserialize)]
+pub struct InviteRepositoryCollaboratorRequest {
+    pub user_name: String,
+    pub role: String,
+}
+
+pub type InviteRepositoryCollaboratorResponse = RepositoryInvitationResource;
@@ -0,0 +1,13 @@
+use crate::{endpoint::Endpoint, resource::CollaboratorResource};
+
+pub struct ListRepositoryCollaborators;
+
+impl Endpoint for ListRepositoryCollaborators {
+    const PATH: &'static str = "/repository/{owner}/{repo}/collaborators";
+    const METHOD: http::Method = http::Method::GET;
+
+    type Request = ();
+    type Response = ListRepositoryCollaboratorsResponse;
+}
+
+pub type ListRepositoryCollaboratorsResponse = Vec<CollaboratorResource>;
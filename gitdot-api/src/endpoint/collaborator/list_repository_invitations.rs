@@ -0,0 +1,13 @@
+use crate::{endpoint::Endpoint, resource::RepositoryInvitationResource};
+
+pub struct ListRepositoryInvitations;
+
+impl Endpoint for ListRepositoryInvitations {
+    const PATH: &'static str = "/repository/{owner}/{repo}/invitations";
+    const METHOD: http::Method = http::Method::GET;
+
+    type Request = ();
+    type Response = ListRepositoryInvitationsResponse;
+}
+
+pub type ListRepositoryInvitationsResponse = Vec<RepositoryInvitationResource>;
@@ -0,0 +1,13 @@
+use crate::{endpoint::Endpoint, resource::RepositoryInvitationResource};
+
+pub struct ListUserInvitations;
+
+impl Endpoint for ListUserInvitations {
+    const PATH: &'static str = "/user/invitations";
+    const METHOD: http::Method = http::Method::GET;
+
+    type Request = ();
+    type Response = ListUserInvitationsResponse;
+}
+
+pub type ListUserInvitationsResponse = Vec<RepositoryInvitationResource>;
@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+use crate::endpoint::Endpoint;
+
+pub struct RemoveRepositoryCollaborator;
+
+impl Endpoint for RemoveRepositoryCollaborator {
+    const PATH: &'static str = "/repository/{owner}/{repo}/collaborator/{user_name}";
+    const METHOD: http::Method = http::Method::DELETE;
+
+    type Request = RemoveRepositoryCollaboratorRequest;
+    type Response = RemoveRepositoryCollaboratorResponse;
+}
+
+#[derive(ApiRequest, Debug, Serialize, Deserialize)]
+pub struct RemoveRepositoryCollaboratorRequest {}
+
+pub type RemoveRepositoryCollaboratorResponse = ();
@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{endpoint::Endpoint, resource::CollaboratorResource};
+
+pub struct UpdateRepositoryCollaborator;
+
+impl Endpoint for UpdateRepositoryCollaborator {
+    const PATH: &'static str = "/repository/{owner}/{repo}/collaborator/{user_name}";
+    const METHOD: http::Method = http::Method::PATCH;
+
+    type Request = UpdateRepositoryCollaboratorRequest;
+    type Response = UpdateRepositoryCollaboratorResponse;
+}
+
+#[derive(ApiRequest, Debug, Serialize, Deserialize)]
+pub struct UpdateRepositoryCollaboratorRequest {
+    pub role: String,
+}
+
+pub type UpdateRepositoryCollaboratorResponse = CollaboratorResource;
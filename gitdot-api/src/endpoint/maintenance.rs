@@ -0,0 +1,2 @@
+pub mod get_maintenance;
+pub mod request_maintenance;
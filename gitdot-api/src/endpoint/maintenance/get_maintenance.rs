@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{endpoint::Endpoint, resource::MaintenanceResource};
+
+pub struct GetMaintenance;
+
+impl Endpoint for GetMaintenance {
+    const PATH: &'static str = "/repository/{owner}/{repo}/maintenance";
+    const METHOD: http::Method = http::Method::GET;
+
+    type Request = GetMaintenanceRequest;
+    type Response = GetMaintenanceResponse;
+}
+
+#[derive(ApiRequest, Debug, Default, Serialize, Deserialize)]
+pub struct GetMaintenanceRequest {}
+
+pub type GetMaintenanceResponse = MaintenanceResource;
@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{endpoint::Endpoint, resource::MaintenanceResource};
+
+pub struct RequestMaintenance;
+
+impl Endpoint for RequestMaintenance {
+    const PATH: &'static str = "/repository/{owner}/{repo}/maintenance";
+    const METHOD: http::Method = http::Method::POST;
+
+    type Request = RequestMaintenanceRequest;
+    type Response = RequestMaintenanceResponse;
+}
+
+#[derive(ApiRequest, Debug, Default, Serialize, Deserialize)]
+pub struct RequestMaintenanceRequest {}
+
+pub type RequestMaintenanceResponse = MaintenanceResource;
@@ -1,3 +1,5 @@
+pub mod cutover_repository_mirror;
 pub mod get_migration;
+pub mod get_repository_mirror;
 pub mod github;
 pub mod list_migrations;
@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{endpoint::Endpoint, resource::migration::MirrorCutoverResource};
+
+pub struct CutoverRepositoryMirror;
+
+impl Endpoint for CutoverRepositoryMirror {
+    const PATH: &'static str = "/repository/{owner}/{repo}/mirror/cutover";
+    const METHOD: http::Method = http::Method::POST;
+
+    type Request = CutoverRepositoryMirrorRequest;
+    type Response = CutoverRepositoryMirrorResponse;
+}
+
+#[derive(ApiRequest, Debug, Serialize, Deserialize)]
+pub struct CutoverRepositoryMirrorRequest {
+    #[serde(default)]
+    pub keep_push_mirror: bool,
+    #[serde(default)]
+    pub username: Option<String>,
+    #[serde(default)]
+    pub password: Option<String>,
+}
+
+pub type CutoverRepositoryMirrorResponse = MirrorCutoverResource;
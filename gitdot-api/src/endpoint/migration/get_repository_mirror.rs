@@ -0,0 +1,13 @@
+use crate::{endpoint::Endpoint, resource::migration::RepositoryMirrorResource};
+
+pub struct GetRepositoryMirror;
+
+impl Endpoint for GetRepositoryMirror {
+    const PATH: &'static str = "/repository/{owner}/{repo}/mirror";
+    const METHOD: http::Method = http::Method::GET;
+
+    type Request = ();
+    type Response = GetRepositoryMirrorResponse;
+}
+
+pub type GetRepositoryMirrorResponse = RepositoryMirrorResource;
@@ -20,6 +20,9 @@ pub struct MigrateGitHubRepositoriesRequest {
     pub destination_type: String,
     pub repositories: Vec<GitHubRepository>,
     pub readonly: bool,
+
+    #[serde(default)]
+    pub import_history: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -0,0 +1,5 @@
+pub mod create_mirror;
+pub mod delete_mirror;
+pub mod get_mirror;
+pub mod list_mirrors;
+pub mod request_mirror_sync;
@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{endpoint::Endpoint, resource::MirrorResource};
+
+pub struct CreateMirror;
+
+impl Endpoint for CreateMirror {
+    const PATH: &'static str = "/repository/{owner}/{repo}/mirrors";
+    const METHOD: http::Method = http::Method::POST;
+
+    type Request = CreateMirrorRequest;
+    type Response = CreateMirrorResponse;
+}
+
+#[derive(ApiRequest, Debug, Serialize, Deserialize)]
+pub struct CreateMirrorRequest {
+    pub direction: String,
+    pub url: String,
+    #[serde(default)]
+    pub username: Option<String>,
+    #[serde(default)]
+    pub password: Option<String>,
+    #[serde(default)]
+    pub interval_seconds: Option<i32>,
+    #[serde(default)]
+    pub replace_refs: bool,
+}
+
+pub type CreateMirrorResponse = MirrorResource;
@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+use crate::endpoint::Endpoint;
+
+pub struct DeleteMirror;
+
+impl Endpoint for DeleteMirror {
+    const PATH: &'static str = "/repository/{owner}/{repo}/mirrors/{mirror_id}";
+    const METHOD: http::Method = http::Method::DELETE;
+
+    type Request = DeleteMirrorRequest;
+    type Response = DeleteMirrorResponse;
+}
+
+#[derive(ApiRequest, Debug, Serialize, Deserialize)]
+pub struct DeleteMirrorRequest {}
+
+pub type DeleteMirrorResponse = ();
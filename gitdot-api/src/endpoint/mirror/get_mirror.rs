@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{endpoint::Endpoint, resource::MirrorResource};
+
+pub struct GetMirror;
+
+impl Endpoint for GetMirror {
+    const PATH: &'static str = "/repository/{owner}/{repo}/mirrors/{mirror_id}";
+    const METHOD: http::Method = http::Method::GET;
+
+    type Request = GetMirrorRequest;
+    type Response = GetMirrorResponse;
+}
+
+#[derive(ApiRequest, Debug, Default, Serialize, Deserialize)]
+pub struct GetMirrorRequest {}
+
+pub type GetMirrorResponse = MirrorResource;
@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{endpoint::Endpoint, resource::MirrorResource};
+
+pub struct ListMirrors;
+
+impl Endpoint for ListMirrors {
+    const PATH: &'static str = "/repository/{owner}/{repo}/mirrors";
+    const METHOD: http::Method = http::Method::GET;
+
+    type Request = ListMirrorsRequest;
+    type Response = ListMirrorsResponse;
+}
+
+#[derive(ApiRequest, Debug, Default, Serialize, Deserialize)]
+pub struct ListMirrorsRequest {}
+
+pub type ListMirrorsResponse = Vec<MirrorResource>;
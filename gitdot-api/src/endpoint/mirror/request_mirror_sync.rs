@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{endpoint::Endpoint, resource::MirrorResource};
+
+pub struct RequestMirrorSync;
+
+impl Endpoint for RequestMirrorSync {
+    const PATH: &'static str = "/repository/{owner}/{repo}/mirrors/{mirror_id}/sync";
+    const METHOD: http::Method = http::Method::POST;
+
+    type Request = RequestMirrorSyncRequest;
+    type Response = RequestMirrorSyncResponse;
+}
+
+#[derive(ApiRequest, Debug, Default, Serialize, Deserialize)]
+pub struct RequestMirrorSyncRequest {}
+
+pub type RequestMirrorSyncResponse = MirrorResource;
@@ -0,0 +1,8 @@
+pub mod create_release;
+pub mod delete_release;
+pub mod delete_release_asset;
+pub mod download_release_asset;
+pub mod get_release;
+pub mod list_releases;
+pub mod update_release;
+pub mod upload_release_asset;
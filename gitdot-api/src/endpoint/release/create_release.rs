@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{endpoint::Endpoint, resource::ReleaseResource};
+
+pub struct CreateRelease;
+
+impl Endpoint for CreateRelease {
+    const PATH: &'static str = "/repository/{owner}/{repo}/releases";
+    const METHOD: http::Method = http::Method::POST;
+
+    type Request = CreateReleaseRequest;
+    type Response = CreateReleaseResponse;
+}
+
+#[derive(ApiRequest, Debug, Default, Serialize, Deserialize)]
+pub struct CreateReleaseRequest {
+    pub tag: String,
+    /// Defaults to the tag name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub draft: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prerelease: Option<bool>,
+}
+
+pub type CreateReleaseResponse = ReleaseResource;
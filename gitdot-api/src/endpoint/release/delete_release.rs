@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+use crate::endpoint::Endpoint;
+
+pub struct DeleteRelease;
+
+impl Endpoint for DeleteRelease {
+    const PATH: &'static str = "/repository/{owner}/{repo}/releases/{tag}";
+    const METHOD: http::Method = http::Method::DELETE;
+
+    type Request = DeleteReleaseRequest;
+    type Response = DeleteReleaseResponse;
+}
+
+#[derive(ApiRequest, Debug, Default, Serialize, Deserialize)]
+pub struct DeleteReleaseRequest {}
+
+pub type DeleteReleaseResponse = ();
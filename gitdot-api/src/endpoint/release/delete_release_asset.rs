@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+use crate::endpoint::Endpoint;
+
+pub struct DeleteReleaseAsset;
+
+impl Endpoint for DeleteReleaseAsset {
+    const PATH: &'static str = "/repository/{owner}/{repo}/releases/{tag}/assets/{name}";
+    const METHOD: http::Method = http::Method::DELETE;
+
+    type Request = DeleteReleaseAssetRequest;
+    type Response = DeleteReleaseAssetResponse;
+}
+
+#[derive(ApiRequest, Debug, Default, Serialize, Deserialize)]
+pub struct DeleteReleaseAssetRequest {}
+
+pub type DeleteReleaseAssetResponse = ();
@@ -0,0 +1,11 @@
+use crate::endpoint::Endpoint;
+
+pub struct DownloadReleaseAsset;
+
+impl Endpoint for DownloadReleaseAsset {
+    const PATH: &'static str = "/repository/{owner}/{repo}/releases/{tag}/assets/{name}";
+    const METHOD: http::Method = http::Method::GET;
+
+    type Request = ();
+    type Response = ();
+}
@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{endpoint::Endpoint, resource::ReleaseResource};
+
+pub struct GetRelease;
+
+impl Endpoint for GetRelease {
+    const PATH: &'static str = "/repository/{owner}/{repo}/releases/{tag}";
+    const METHOD: http::Method = http::Method::GET;
+
+    type Request = GetReleaseRequest;
+    type Response = GetReleaseResponse;
+}
+
+#[derive(ApiRequest, Debug, Default, Serialize, Deserialize)]
+pub struct GetReleaseRequest {}
+
+pub type GetReleaseResponse = ReleaseResource;
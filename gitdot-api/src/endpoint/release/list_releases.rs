@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{endpoint::Endpoint, resource::ReleaseResource};
+
+pub struct ListReleases;
+
+impl Endpoint for ListReleases {
+    const PATH: &'static str = "/repository/{owner}/{repo}/releases";
+    const METHOD: http::Method = http::Method::GET;
+
+    type Request = ListReleasesRequest;
+    type Response = ListReleasesResponse;
+}
+
+#[derive(ApiRequest, Debug, Default, Serialize, Deserialize)]
+pub struct ListReleasesRequest {}
+
+pub type ListReleasesResponse = Vec<ReleaseResource>;
@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{endpoint::Endpoint, resource::ReleaseResource};
+
+pub struct UpdateRelease;
+
+impl Endpoint for UpdateRelease {
+    const PATH: &'static str = "/repository/{owner}/{repo}/releases/{tag}";
+    const METHOD: http::Method = http::Method::PATCH;
+
+    type Request = UpdateReleaseRequest;
+    type Response = UpdateReleaseResponse;
+}
+
+#[derive(ApiRequest, Debug, Default, Serialize, Deserialize)]
+pub struct UpdateReleaseRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub draft: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prerelease: Option<bool>,
+}
+
+pub type UpdateReleaseResponse = ReleaseResource;
@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{endpoint::Endpoint, resource::ReleaseAssetResource};
+
+pub struct UploadReleaseAsset;
+
+impl Endpoint for UploadReleaseAsset {
+    const PATH: &'static str = "/repository/{owner}/{repo}/releases/{tag}/assets";
+    const METHOD: http::Method = http::Method::POST;
+
+    type Request = UploadReleaseAssetRequest;
+    type Response = UploadReleaseAssetResponse;
+}
+
+/// Sent as query parameters; the request body is the raw file and its
+/// `Content-Type` header is stored with the asset.
+#[derive(ApiRequest, Debug, Clone, Serialize, Deserialize)]
+pub struct UploadReleaseAssetRequest {
+    pub name: String,
+}
+
+pub type UploadReleaseAssetResponse = ReleaseAssetResource;
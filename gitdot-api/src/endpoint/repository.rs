@@ -1,9 +1,12 @@
+pub mod compare_repository_refs;
 pub mod create_repository;
 pub mod create_repository_commit_filter;
 pub mod delete_repository;
 pub mod delete_repository_commit_filter;
 pub mod get_repository;
 pub mod get_repository_activity;
+pub mod get_repository_archive;
+pub mod get_repository_blame;
 pub mod get_repository_blob;
 pub mod get_repository_blobs;
 pub mod get_repository_commit;
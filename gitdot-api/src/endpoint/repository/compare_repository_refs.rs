@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{endpoint::Endpoint, resource::repository::RepositoryCompareResource};
+
+pub struct CompareRepositoryRefs;
+
+impl Endpoint for CompareRepositoryRefs {
+    const PATH: &'static str = "/repository/{owner}/{repo}/compare/{range}";
+    const METHOD: http::Method = http::Method::GET;
+
+    type Request = CompareRepositoryRefsRequest;
+    type Response = CompareRepositoryRefsResponse;
+}
+
+#[derive(ApiRequest, Debug, Default, Serialize, Deserialize)]
+pub struct CompareRepositoryRefsRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ignore_whitespace: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detect_renames: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+}
+
+pub type CompareRepositoryRefsResponse = RepositoryCompareResource;
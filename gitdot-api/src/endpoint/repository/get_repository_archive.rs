@@ -0,0 +1,11 @@
+use crate::endpoint::Endpoint;
+
+pub struct GetRepositoryArchive;
+
+impl Endpoint for GetRepositoryArchive {
+    const PATH: &'static str = "/repository/{owner}/{repo}/archive/{archive}";
+    const METHOD: http::Method = http::Method::GET;
+
+    type Request = ();
+    type Response = ();
+}
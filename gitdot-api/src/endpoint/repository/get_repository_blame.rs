@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+use super::default_ref;
+use crate::{endpoint::Endpoint, resource::repository::RepositoryBlameResource};
+
+pub struct GetRepositoryBlame;
+
+impl Endpoint for GetRepositoryBlame {
+    const PATH: &'static str = "/repository/{owner}/{repo}/blame";
+    const METHOD: http::Method = http::Method::GET;
+
+    type Request = GetRepositoryBlameRequest;
+    type Response = GetRepositoryBlameResponse;
+}
+
+#[derive(ApiRequest, Debug, Serialize, Deserialize)]
+pub struct GetRepositoryBlameRequest {
+    #[serde(default = "default_ref")]
+    pub ref_name: String,
+    pub path: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ignore_revs_file: Option<String>,
+}
+
+pub type GetRepositoryBlameResponse = RepositoryBlameResource;
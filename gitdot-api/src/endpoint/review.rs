@@ -1,6 +1,8 @@
 pub mod add_review_reviewer;
 pub mod get_review;
 pub mod get_review_diff_blobs;
+pub mod get_review_diff_mergeability;
+pub mod get_review_merge_requirements;
 pub mod list_reviews;
 pub mod merge_review_diff;
 pub mod publish_review;
@@ -12,3 +14,4 @@ pub mod review_review_diff;
 pub mod update_review;
 pub mod update_review_comment;
 pub mod update_review_diff;
+pub mod update_review_merge_requirements;
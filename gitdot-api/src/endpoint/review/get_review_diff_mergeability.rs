@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{endpoint::Endpoint, resource::review::ReviewMergeabilityResource};
+
+pub struct GetReviewDiffMergeability;
+
+impl Endpoint for GetReviewDiffMergeability {
+    const PATH: &'static str =
+        "/repository/{owner}/{repo}/review/{number}/diff/{position}/mergeability";
+    const METHOD: http::Method = http::Method::GET;
+
+    type Request = GetReviewDiffMergeabilityRequest;
+    type Response = GetReviewDiffMergeabilityResponse;
+}
+
+#[derive(ApiRequest, Debug, Default, Serialize, Deserialize)]
+pub struct GetReviewDiffMergeabilityRequest {}
+
+pub type GetReviewDiffMergeabilityResponse = ReviewMergeabilityResource;
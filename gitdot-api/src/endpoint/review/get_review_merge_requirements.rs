@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{endpoint::Endpoint, resource::review::MergeRequirementsResource};
+
+pub struct GetReviewMergeRequirements;
+
+impl Endpoint for GetReviewMergeRequirements {
+    const PATH: &'static str = "/repository/{owner}/{repo}/review-merge-requirements";
+    const METHOD: http::Method = http::Method::GET;
+
+    type Request = GetReviewMergeRequirementsRequest;
+    type Response = GetReviewMergeRequirementsResponse;
+}
+
+#[derive(ApiRequest, Debug, Default, Serialize, Deserialize)]
+pub struct GetReviewMergeRequirementsRequest {}
+
+pub type GetReviewMergeRequirementsResponse = MergeRequirementsResource;
@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{endpoint::Endpoint, resource::review::MergeRequirementsResource};
+
+pub struct UpdateReviewMergeRequirements;
+
+impl Endpoint for UpdateReviewMergeRequirements {
+    const PATH: &'static str = "/repository/{owner}/{repo}/review-merge-requirements";
+    const METHOD: http::Method = http::Method::PUT;
+
+    type Request = UpdateReviewMergeRequirementsRequest;
+    type Response = UpdateReviewMergeRequirementsResponse;
+}
+
+#[derive(ApiRequest, Debug, Serialize, Deserialize)]
+pub struct UpdateReviewMergeRequirementsRequest {
+    pub required_approvals: i32,
+    pub block_on_rejection: bool,
+    pub require_resolved_comments: bool,
+    pub require_green_build: bool,
+}
+
+pub type UpdateReviewMergeRequirementsResponse = MergeRequirementsResource;
@@ -0,0 +1,6 @@
+pub mod delete_organization_secret;
+pub mod delete_repository_secret;
+pub mod list_organization_secrets;
+pub mod list_repository_secrets;
+pub mod set_organization_secret;
+pub mod set_repository_secret;
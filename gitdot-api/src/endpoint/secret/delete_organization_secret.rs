@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+use crate::endpoint::Endpoint;
+
+pub struct DeleteOrganizationSecret;
+
+impl Endpoint for DeleteOrganizationSecret {
+    const PATH: &'static str = "/organization/{org_name}/secret/{name}";
+    const METHOD: http::Method = http::Method::DELETE;
+
+    type Request = DeleteOrganizationSecretRequest;
+    type Response = DeleteOrganizationSecretResponse;
+}
+
+#[derive(ApiRequest, Debug, Serialize, Deserialize)]
+pub struct DeleteOrganizationSecretRequest {}
+
+pub type DeleteOrganizationSecretResponse = ();
@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+use crate::endpoint::Endpoint;
+
+pub struct DeleteRepositorySecret;
+
+impl Endpoint for DeleteRepositorySecret {
+    const PATH: &'static str = "/repository/{owner}/{repo}/secret/{name}";
+    const METHOD: http::Method = http::Method::DELETE;
+
+    type Request = DeleteRepositorySecretRequest;
+    type Response = DeleteRepositorySecretResponse;
+}
+
+#[derive(ApiRequest, Debug, Serialize, Deserialize)]
+pub struct DeleteRepositorySecretRequest {}
+
+pub type DeleteRepositorySecretResponse = ();
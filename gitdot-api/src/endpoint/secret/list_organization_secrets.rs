@@ -0,0 +1,13 @@
+use crate::{endpoint::Endpoint, resource::SecretResource};
+
+pub struct ListOrganizationSecrets;
+
+impl Endpoint for ListOrganizationSecrets {
+    const PATH: &'static str = "/organization/{org_name}/secrets";
+    const METHOD: http::Method = http::Method::GET;
+
+    type Request = ();
+    type Response = ListOrganizationSecretsResponse;
+}
+
+pub type ListOrganizationSecretsResponse = Vec<SecretResource>;
@@ -0,0 +1,13 @@
+use crate::{endpoint::Endpoint, resource::SecretResource};
+
+pub struct ListRepositorySecrets;
+
+impl Endpoint for ListRepositorySecrets {
+    const PATH: &'static str = "/repository/{owner}/{repo}/secrets";
+    const METHOD: http::Method = http::Method::GET;
+
+    type Request = ();
+    type Response = ListRepositorySecretsResponse;
+}
+
+pub type ListRepositorySecretsResponse = Vec<SecretResource>;
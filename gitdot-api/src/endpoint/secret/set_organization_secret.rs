@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{endpoint::Endpoint, resource::SecretResource};
+
+pub struct SetOrganizationSecret;
+
+impl Endpoint for SetOrganizationSecret {
+    const PATH: &'static str = "/organization/{org_name}/secret/{name}";
+    const METHOD: http::Method = http::Method::PUT;
+
+    type Request = SetOrganizationSecretRequest;
+    type Response = SetOrganizationSecretResponse;
+}
+
+#[derive(ApiRequest, Debug, Serialize, Deserialize)]
+pub struct SetOrganizationSecretRequest {
+    pub value: String,
+}
+
+pub type SetOrganizationSecretResponse = SecretResource;
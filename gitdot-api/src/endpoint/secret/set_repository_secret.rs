@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{endpoint::Endpoint, resource::SecretResource};
+
+pub struct SetRepositorySecret;
+
+impl Endpoint for SetRepositorySecret {
+    const PATH: &'static str = "/repository/{owner}/{repo}/secret/{name}";
+    const METHOD: http::Method = http::Method::PUT;
+
+    type Request = SetRepositorySecretRequest;
+    type Response = SetRepositorySecretResponse;
+}
+
+#[derive(ApiRequest, Debug, Serialize, Deserialize)]
+pub struct SetRepositorySecretRequest {
+    pub value: String,
+}
+
+pub type SetRepositorySecretResponse = SecretResource;
@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{endpoint::Endpoint, resource::TagResource};
+
+pub struct CreateTag;
+
+impl Endpoint for CreateTag {
+    const PATH: &'static str = "/repository/{owner}/{repo}/tags";
+    const METHOD: http::Method = http::Method::POST;
+
+    type Request = CreateTagRequest;
+    type Response = CreateTagResponse;
+}
+
+/// Creates an annotated tag when `message` is set, otherwise a lightweight one.
+#[derive(ApiRequest, Debug, Default, Serialize, Deserialize)]
+pub struct CreateTagRequest {
+    pub name: String,
+    /// A commit sha, branch or other ref to tag.
+    pub target: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+pub type CreateTagResponse = TagResource;
@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+use crate::endpoint::Endpoint;
+
+pub struct DeleteTag;
+
+impl Endpoint for DeleteTag {
+    const PATH: &'static str = "/repository/{owner}/{repo}/tags/{tag}";
+    const METHOD: http::Method = http::Method::DELETE;
+
+    type Request = DeleteTagRequest;
+    type Response = DeleteTagResponse;
+}
+
+#[derive(ApiRequest, Debug, Default, Serialize, Deserialize)]
+pub struct DeleteTagRequest {}
+
+pub type DeleteTagResponse = ();
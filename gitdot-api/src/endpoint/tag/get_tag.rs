@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{endpoint::Endpoint, resource::TagResource};
+
+pub struct GetTag;
+
+impl Endpoint for GetTag {
+    const PATH: &'static str = "/repository/{owner}/{repo}/tags/{tag}";
+    const METHOD: http::Method = http::Method::GET;
+
+    type Request = GetTagRequest;
+    type Response = GetTagResponse;
+}
+
+#[derive(ApiRequest, Debug, Default, Serialize, Deserialize)]
+pub struct GetTagRequest {}
+
+pub type GetTagResponse = TagResource;
@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{endpoint::Endpoint, resource::TagResource};
+
+pub struct ListTags;
+
+impl Endpoint for ListTags {
+    const PATH: &'static str = "/repository/{owner}/{repo}/tags";
+    const METHOD: http::Method = http::Method::GET;
+
+    type Request = ListTagsRequest;
+    type Response = ListTagsResponse;
+}
+
+#[derive(ApiRequest, Debug, Default, Serialize, Deserialize)]
+pub struct ListTagsRequest {}
+
+pub type ListTagsResponse = Vec<TagResource>;
@@ -1,3 +1,9 @@
+pub mod download_task_artifact;
+pub mod download_task_cache;
+pub mod heartbeat_task;
 pub mod issue_task_token;
+pub mod list_task_artifacts;
 pub mod poll_task;
 pub mod update_task;
+pub mod upload_task_artifact;
+pub mod upload_task_cache;
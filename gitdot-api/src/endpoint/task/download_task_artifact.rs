@@ -0,0 +1,11 @@
+use crate::endpoint::Endpoint;
+
+pub struct DownloadTaskArtifact;
+
+impl Endpoint for DownloadTaskArtifact {
+    const PATH: &'static str = "/ci/task/{id}/artifact/{artifact_id}";
+    const METHOD: http::Method = http::Method::GET;
+
+    type Request = ();
+    type Response = ();
+}
@@ -0,0 +1,11 @@
+use crate::endpoint::Endpoint;
+
+pub struct DownloadTaskCache;
+
+impl Endpoint for DownloadTaskCache {
+    const PATH: &'static str = "/ci/task/{id}/cache";
+    const METHOD: http::Method = http::Method::GET;
+
+    type Request = ();
+    type Response = ();
+}
@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{endpoint::Endpoint, resource::TaskResource};
+
+pub struct HeartbeatTask;
+
+impl Endpoint for HeartbeatTask {
+    const PATH: &'static str = "/ci/task/{id}/heartbeat";
+    const METHOD: http::Method = http::Method::POST;
+
+    type Request = HeartbeatTaskRequest;
+    type Response = HeartbeatTaskResponse;
+}
+
+#[derive(ApiRequest, Debug, Clone, Serialize, Deserialize)]
+pub struct HeartbeatTaskRequest;
+
+pub type HeartbeatTaskResponse = TaskResource;
@@ -0,0 +1,13 @@
+use crate::{endpoint::Endpoint, resource::ArtifactResource};
+
+pub struct ListTaskArtifacts;
+
+impl Endpoint for ListTaskArtifacts {
+    const PATH: &'static str = "/ci/task/{id}/artifacts";
+    const METHOD: http::Method = http::Method::GET;
+
+    type Request = ();
+    type Response = ListTaskArtifactsResponse;
+}
+
+pub type ListTaskArtifactsResponse = Vec<ArtifactResource>;
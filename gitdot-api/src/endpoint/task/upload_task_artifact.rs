@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{endpoint::Endpoint, resource::ArtifactResource};
+
+pub struct UploadTaskArtifact;
+
+impl Endpoint for UploadTaskArtifact {
+    const PATH: &'static str = "/ci/task/{id}/artifact";
+    const METHOD: http::Method = http::Method::POST;
+
+    type Request = UploadTaskArtifactRequest;
+    type Response = UploadTaskArtifactResponse;
+}
+
+#[derive(ApiRequest, Debug, Clone, Serialize, Deserialize)]
+pub struct UploadTaskArtifactRequest {
+    pub name: String,
+}
+
+pub type UploadTaskArtifactResponse = ArtifactResource;
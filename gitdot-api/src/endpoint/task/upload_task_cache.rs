@@ -0,0 +1,11 @@
+use crate::endpoint::Endpoint;
+
+pub struct UploadTaskCache;
+
+impl Endpoint for UploadTaskCache {
+    const PATH: &'static str = "/ci/task/{id}/cache";
+    const METHOD: http::Method = http::Method::POST;
+
+    type Request = ();
+    type Response = ();
+}
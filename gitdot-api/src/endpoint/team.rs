@@ -0,0 +1,8 @@
+pub mod add_team_member;
+pub mod create_team;
+pub mod delete_team;
+pub mod get_team;
+pub mod list_teams;
+pub mod remove_team_member;
+pub mod remove_team_repository;
+pub mod set_team_repository;
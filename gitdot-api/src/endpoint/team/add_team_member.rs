@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{endpoint::Endpoint, resource::TeamMemberResource};
+
+pub struct AddTeamMember;
+
+impl Endpoint for AddTeamMember {
+    const PATH: &'static str = "/organization/{org_name}/team/{team_name}/member";
+    const METHOD: http::Method = http::Method::POST;
+
+    type Request = AddTeamMemberRequest;
+    type Response = AddTeamMemberResponse;
+}
+
+#[derive(ApiRequest, Debug, Serialize, Deserialize)]
+pub struct AddTeamMemberRequest {
+    pub user_name: String,
+}
+
+pub type AddTeamMemberResponse = TeamMemberResource;
@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{endpoint::Endpoint, resource::TeamResource};
+
+pub struct CreateTeam;
+
+impl Endpoint for CreateTeam {
+    const PATH: &'static str = "/organization/{org_name}/team";
+    const METHOD: http::Method = http::Method::POST;
+
+    type Request = CreateTeamRequest;
+    type Response = CreateTeamResponse;
+}
+
+#[derive(ApiRequest, Debug, Serialize, Deserialize)]
+pub struct CreateTeamRequest {
+    pub name: String,
+    pub description: Option<String>,
+}
+
+pub type CreateTeamResponse = TeamResource;
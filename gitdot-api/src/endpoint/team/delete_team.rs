@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+use crate::endpoint::Endpoint;
+
+pub struct DeleteTeam;
+
+impl Endpoint for DeleteTeam {
+    const PATH: &'static str = "/organization/{org_name}/team/{team_name}";
+    const METHOD: http::Method = http::Method::DELETE;
+
+    type Request = DeleteTeamRequest;
+    type Response = DeleteTeamResponse;
+}
+
+#[derive(ApiRequest, Debug, Serialize, Deserialize)]
+pub struct DeleteTeamRequest {}
+
+pub type DeleteTeamResponse = ();
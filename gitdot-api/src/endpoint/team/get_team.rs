@@ -0,0 +1,13 @@
+use crate::{endpoint::Endpoint, resource::TeamResource};
+
+pub struct GetTeam;
+
+impl Endpoint for GetTeam {
+    const PATH: &'static str = "/organization/{org_name}/team/{team_name}";
+    const METHOD: http::Method = http::Method::GET;
+
+    type Request = ();
+    type Response = GetTeamResponse;
+}
+
+pub type GetTeamResponse = TeamResource;
@@ -0,0 +1,13 @@
+use crate::{endpoint::Endpoint, resource::TeamResource};
+
+pub struct ListTeams;
+
+impl Endpoint for ListTeams {
+    const PATH: &'static str = "/organization/{org_name}/teams";
+    const METHOD: http::Method = http::Method::GET;
+
+    type Request = ();
+    type Response = ListTeamsResponse;
+}
+
+pub type ListTeamsResponse = Vec<TeamResource>;
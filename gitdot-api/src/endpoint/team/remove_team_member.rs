@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+use crate::endpoint::Endpoint;
+
+pub struct RemoveTeamMember;
+
+impl Endpoint for RemoveTeamMember {
+    const PATH: &'static str = "/organization/{org_name}/team/{team_name}/member/{user_name}";
+    const METHOD: http::Method = http::Method::DELETE;
+
+    type Request = RemoveTeamMemberRequest;
+    type Response = RemoveTeamMemberResponse;
+}
+
+#[derive(ApiRequest, Debug, Serialize, Deserialize)]
+pub struct RemoveTeamMemberRequest {}
+
+pub type RemoveTeamMemberResponse = ();
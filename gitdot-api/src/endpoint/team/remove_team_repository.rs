@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+use crate::endpoint::Endpoint;
+
+pub struct RemoveTeamRepository;
+
+impl Endpoint for RemoveTeamRepository {
+    const PATH: &'static str = "/organization/{org_name}/team/{team_name}/repository/{repo}";
+    const METHOD: http::Method = http::Method::DELETE;
+
+    type Request = RemoveTeamRepositoryRequest;
+    type Response = RemoveTeamRepositoryResponse;
+}
+
+#[derive(ApiRequest, Debug, Serialize, Deserialize)]
+pub struct RemoveTeamRepositoryRequest {}
+
+pub type RemoveTeamRepositoryResponse = ();
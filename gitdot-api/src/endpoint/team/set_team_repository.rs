@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{endpoint::Endpoint, resource::TeamRepositoryResource};
+
+pub struct SetTeamRepository;
+
+impl Endpoint for SetTeamRepository {
+    const PATH: &'static str = "/organization/{org_name}/team/{team_name}/repository/{repo}";
+    const METHOD: http::Method = http::Method::PUT;
+
+    type Request = SetTeamRepositoryRequest;
+    type Response = SetTeamRepositoryResponse;
+}
+
+#[derive(ApiRequest, Debug, Serialize, Deserialize)]
+pub struct SetTeamRepositoryRequest {
+    pub role: String,
+}
+
+pub type SetTeamRepositoryResponse = TeamRepositoryResource;
@@ -1,11 +1,20 @@
+pub mod add_signing_key;
+pub mod add_ssh_key;
+pub mod create_personal_token;
+pub mod delete_signing_key;
+pub mod delete_ssh_key;
 pub mod get_current_user;
 pub mod get_user;
 pub mod has_user;
+pub mod list_personal_tokens;
+pub mod list_signing_keys;
+pub mod list_ssh_keys;
 pub mod list_user_commits;
 pub mod list_user_organizations;
 pub mod list_user_repositories;
 pub mod list_user_repositories_contributed;
 pub mod list_user_repositories_starred;
 pub mod list_user_reviews;
+pub mod revoke_personal_token;
 pub mod update_current_user;
 pub mod upload_user_image;
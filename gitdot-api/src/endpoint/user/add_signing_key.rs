@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{endpoint::Endpoint, resource::user::SigningKeyResource};
+
+pub struct AddSigningKey;
+
+impl Endpoint for AddSigningKey {
+    const PATH: &'static str = "/user/signing-key";
+    const METHOD: http::Method = http::Method::POST;
+
+    type Request = AddSigningKeyRequest;
+    type Response = AddSigningKeyResponse;
+}
+
+#[derive(ApiRequest, Debug, Serialize, Deserialize)]
+pub struct AddSigningKeyRequest {
+    pub name: Option<String>,
+    pub public_key: String,
+    /// For SSH keys, `ssh-keygen -Y sign -n gitdot` of the challenge named
+    /// when the key is first added without one.
+    pub signature: Option<String>,
+}
+
+pub type AddSigningKeyResponse = SigningKeyResource;
@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{endpoint::Endpoint, resource::user::SshKeyResource};
+
+pub struct AddSshKey;
+
+impl Endpoint for AddSshKey {
+    const PATH: &'static str = "/user/ssh-key";
+    const METHOD: http::Method = http::Method::POST;
+
+    type Request = AddSshKeyRequest;
+    type Response = AddSshKeyResponse;
+}
+
+#[derive(ApiRequest, Debug, Serialize, Deserialize)]
+pub struct AddSshKeyRequest {
+    pub name: Option<String>,
+    pub public_key: String,
+}
+
+pub type AddSshKeyResponse = SshKeyResource;
@@ -0,0 +1,23 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{endpoint::Endpoint, resource::user::NewPersonalTokenResource};
+
+pub struct CreatePersonalToken;
+
+impl Endpoint for CreatePersonalToken {
+    const PATH: &'static str = "/user/token";
+    const METHOD: http::Method = http::Method::POST;
+
+    type Request = CreatePersonalTokenRequest;
+    type Response = CreatePersonalTokenResponse;
+}
+
+#[derive(ApiRequest, Debug, Serialize, Deserialize)]
+pub struct CreatePersonalTokenRequest {
+    pub name: String,
+    pub scopes: Vec<String>,
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+pub type CreatePersonalTokenResponse = NewPersonalTokenResource;
@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+use crate::endpoint::Endpoint;
+
+pub struct DeleteSigningKey;
+
+impl Endpoint for DeleteSigningKey {
+    const PATH: &'static str = "/user/signing-key/{key_id}";
+    const METHOD: http::Method = http::Method::DELETE;
+
+    type Request = DeleteSigningKeyRequest;
+    type Response = DeleteSigningKeyResponse;
+}
+
+#[derive(ApiRequest, Debug, Serialize, Deserialize)]
+pub struct DeleteSigningKeyRequest {}
+
+pub type DeleteSigningKeyResponse = ();
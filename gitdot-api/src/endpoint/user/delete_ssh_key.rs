@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+use crate::endpoint::Endpoint;
+
+pub struct DeleteSshKey;
+
+impl Endpoint for DeleteSshKey {
+    const PATH: &'static str = "/user/ssh-key/{key_id}";
+    const METHOD: http::Method = http::Method::DELETE;
+
+    type Request = DeleteSshKeyRequest;
+    type Response = DeleteSshKeyResponse;
+}
+
+#[derive(ApiRequest, Debug, Serialize, Deserialize)]
+pub struct DeleteSshKeyRequest {}
+
+pub type DeleteSshKeyResponse = ();
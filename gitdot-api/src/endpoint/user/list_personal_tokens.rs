@@ -0,0 +1,13 @@
+use crate::{endpoint::Endpoint, resource::user::PersonalTokenResource};
+
+pub struct ListPersonalTokens;
+
+impl Endpoint for ListPersonalTokens {
+    const PATH: &'static str = "/user/tokens";
+    const METHOD: http::Method = http::Method::GET;
+
+    type Request = ();
+    type Response = ListPersonalTokensResponse;
+}
+
+pub type ListPersonalTokensResponse = Vec<PersonalTokenResource>;
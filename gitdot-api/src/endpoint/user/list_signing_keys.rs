@@ -0,0 +1,13 @@
+use crate::{endpoint::Endpoint, resource::user::SigningKeyResource};
+
+pub struct ListSigningKeys;
+
+impl Endpoint for ListSigningKeys {
+    const PATH: &'static str = "/user/signing-keys";
+    const METHOD: http::Method = http::Method::GET;
+
+    type Request = ();
+    type Response = ListSigningKeysResponse;
+}
+
+pub type ListSigningKeysResponse = Vec<SigningKeyResource>;
@@ -0,0 +1,13 @@
+use crate::{endpoint::Endpoint, resource::user::SshKeyResource};
+
+pub struct ListSshKeys;
+
+impl Endpoint for ListSshKeys {
+    const PATH: &'static str = "/user/ssh-keys";
+    const METHOD: http::Method = http::Method::GET;
+
+    type Request = ();
+    type Response = ListSshKeysResponse;
+}
+
+pub type ListSshKeysResponse = Vec<SshKeyResource>;
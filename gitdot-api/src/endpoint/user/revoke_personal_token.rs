@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+use crate::endpoint::Endpoint;
+
+pub struct RevokePersonalToken;
+
+impl Endpoint for RevokePersonalToken {
+    const PATH: &'static str = "/user/token/{token_id}";
+    const METHOD: http::Method = http::Method::DELETE;
+
+    type Request = RevokePersonalTokenRequest;
+    type Response = RevokePersonalTokenResponse;
+}
+
+#[derive(ApiRequest, Debug, Serialize, Deserialize)]
+pub struct RevokePersonalTokenRequest {}
+
+pub type RevokePersonalTokenResponse = ();
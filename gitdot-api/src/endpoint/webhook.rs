@@ -1,5 +1,7 @@
 pub mod create_webhook;
 pub mod delete_webhook;
 pub mod get_webhook;
+pub mod list_webhook_deliveries;
 pub mod list_webhooks;
+pub mod redeliver_webhook_delivery;
 pub mod update_webhook;
@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    endpoint::Endpoint,
+    resource::{WebhookDeliveryResource, common::Page},
+};
+
+pub struct ListWebhookDeliveries;
+
+impl Endpoint for ListWebhookDeliveries {
+    const PATH: &'static str = "/repository/{owner}/{repo}/webhook/{webhook_id}/deliveries";
+    const METHOD: http::Method = http::Method::GET;
+
+    type Request = ListWebhookDeliveriesRequest;
+    type Response = ListWebhookDeliveriesResponse;
+}
+
+#[derive(ApiRequest, Debug, Default, Serialize, Deserialize)]
+pub struct ListWebhookDeliveriesRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+}
+
+pub type ListWebhookDeliveriesResponse = Page<WebhookDeliveryResource>;
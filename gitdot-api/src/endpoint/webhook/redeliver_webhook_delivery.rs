@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{endpoint::Endpoint, resource::WebhookDeliveryResource};
+
+pub struct RedeliverWebhookDelivery;
+
+impl Endpoint for RedeliverWebhookDelivery {
+    const PATH: &'static str =
+        "/repository/{owner}/{repo}/webhook/{webhook_id}/delivery/{delivery_id}/redeliver";
+    const METHOD: http::Method = http::Method::POST;
+
+    type Request = RedeliverWebhookDeliveryRequest;
+    type Response = RedeliverWebhookDeliveryResponse;
+}
+
+#[derive(ApiRequest, Debug, Serialize, Deserialize)]
+pub struct RedeliverWebhookDeliveryRequest {}
+
+pub type RedeliverWebhookDeliveryResponse = WebhookDeliveryResource;
@@ -1,30 +1,48 @@
 pub mod auth;
+pub mod branch_protection;
 pub mod build;
+pub mod code_search;
+pub mod collaborator;
 pub mod common;
+pub mod maintenance;
 pub mod migration;
+pub mod mirror;
 pub mod organization;
 pub mod question;
+pub mod release;
 pub mod repository;
 pub mod review;
 pub mod runner;
+pub mod secret;
 pub mod slack;
+pub mod tag;
 pub mod task;
+pub mod team;
 pub mod user;
 pub mod webhook;
 
 use serde::{Serialize, de::DeserializeOwned};
 
 pub use auth::*;
+pub use branch_protection::*;
 pub use build::*;
+pub use code_search::*;
+pub use collaborator::*;
 pub use common::*;
+pub use maintenance::*;
 pub use migration::*;
+pub use mirror::*;
 pub use organization::*;
 pub use question::*;
+pub use release::*;
 pub use repository::*;
 pub use review::*;
 pub use runner::*;
+pub use secret::*;
 pub use slack::*;
+pub use tag::*;
 pub use task::*;
+pub use team::*;
 pub use user::*;
 pub use webhook::*;
 
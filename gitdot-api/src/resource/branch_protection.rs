@@ -0,0 +1,16 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(ApiResource, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BranchProtectionResource {
+    pub id: Uuid,
+    pub repository_id: Uuid,
+    pub pattern: String,
+    pub block_force_push: bool,
+    pub block_deletion: bool,
+    pub require_review: bool,
+    pub require_green_build: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
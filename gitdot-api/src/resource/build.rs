@@ -11,8 +11,21 @@ pub struct BuildResource {
     pub trigger: String,
     pub commit_sha: String,
     pub status: String,
+    pub review_id: Option<Uuid>,
+    pub diff_id: Option<Uuid>,
+    pub revision_id: Option<Uuid>,
     pub total_tasks: i32,
     pub completed_tasks: i32,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
+
+#[derive(ApiResource, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ArtifactResource {
+    pub id: Uuid,
+    pub build_id: Uuid,
+    pub task_id: Uuid,
+    pub name: String,
+    pub size_bytes: i64,
+    pub created_at: DateTime<Utc>,
+}
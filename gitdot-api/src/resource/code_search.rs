@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(ApiResource, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CodeSearchResource {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commit_sha: Option<String>,
+    pub files: Vec<CodeSearchFileResource>,
+    pub truncated: bool,
+}
+
+#[derive(ApiResource, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CodeSearchFileResource {
+    pub path: String,
+    pub blob_sha: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    pub match_count: u32,
+    pub matches: Vec<CodeSearchMatchResource>,
+}
+
+#[derive(ApiResource, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CodeSearchMatchResource {
+    pub line_number: u32,
+    pub content: String,
+    pub ranges: Vec<MatchRangeResource>,
+    pub before: Vec<String>,
+    pub after: Vec<String>,
+}
+
+#[derive(ApiResource, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MatchRangeResource {
+    pub start: u32,
+    pub end: u32,
+}
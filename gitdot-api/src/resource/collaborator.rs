@@ -0,0 +1,24 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(ApiResource, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CollaboratorResource {
+    pub user_id: Uuid,
+    pub user_name: String,
+    pub role: String,
+
+    pub created_at: DateTime<Utc>,
+    pub image_updated_at: DateTime<Utc>,
+}
+
+#[derive(ApiResource, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RepositoryInvitationResource {
+    pub id: Uuid,
+    pub owner_name: String,
+    pub repository_name: String,
+    pub invitee_name: String,
+    pub inviter_name: String,
+    pub role: String,
+    pub created_at: DateTime<Utc>,
+}
@@ -57,6 +57,27 @@ pub struct MigrationRepositoryResource {
 
     pub status: String,
     pub error: Option<String>,
+    pub imported_questions: i32,
+    pub imported_reviews: i32,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
+
+#[derive(ApiResource, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RepositoryMirrorResource {
+    pub origin_full_name: String,
+
+    pub status: Option<String>,
+    pub error: Option<String>,
+    pub last_attempted_at: Option<DateTime<Utc>>,
+    pub last_synced_at: Option<DateTime<Utc>>,
+}
+
+#[derive(ApiResource, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MirrorCutoverResource {
+    pub id: Uuid,
+    pub origin_full_name: String,
+    pub actor_id: Uuid,
+    pub push_mirror_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
@@ -51,6 +51,67 @@ pub struct RepositoryBlobResource {
     pub encoding: String,
 }
 
+#[derive(ApiResource, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RepositoryBlameResource {
+    pub ref_name: String,
+    pub commit_sha: String,
+    pub path: String,
+    pub ranges: Vec<BlameRangeResource>,
+    pub commits: Vec<RepositoryCommitResource>,
+}
+
+#[derive(ApiResource, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlameRangeResource {
+    pub start_line: u32,
+    pub line_count: u32,
+    pub commit_sha: String,
+}
+
+#[derive(ApiResource, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RepositoryCompareResource {
+    pub base_sha: String,
+    pub head_sha: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub merge_base_sha: Option<String>,
+    pub commits: Vec<RepositoryCommitResource>,
+    pub total_commits: usize,
+    pub files: Vec<FileDiffResource>,
+    pub total_files: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+}
+
+#[derive(ApiResource, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileDiffResource {
+    pub path: String,
+    pub old_path: String,
+    pub status: String,
+    pub binary: bool,
+    pub lines_added: u32,
+    pub lines_removed: u32,
+    pub hunks: Vec<DiffHunkResource>,
+}
+
+#[derive(ApiResource, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DiffHunkResource {
+    pub header: String,
+    pub old_start: u32,
+    pub old_lines: u32,
+    pub new_start: u32,
+    pub new_lines: u32,
+    pub lines: Vec<DiffLineResource>,
+}
+
+#[derive(ApiResource, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DiffLineResource {
+    pub origin: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub old_lineno: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_lineno: Option<u32>,
+    pub content: String,
+}
+
 #[derive(ApiResource, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct RepositoryBlobsResource {
     pub blobs: Vec<RepositoryBlobResource>,
@@ -75,6 +136,10 @@ pub struct RepositoryCommitResource {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub diff_position: Option<i32>,
     pub diffs: Vec<CommitDiffResource>,
+    /// `verified`, `unverified`, `unknown_key` or `bad_email`; absent for
+    /// unsigned commits.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature_status: Option<String>,
 }
 
 #[derive(ApiResource, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
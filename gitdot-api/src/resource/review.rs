@@ -48,6 +48,10 @@ pub struct RevisionResource {
     pub parent_hash: String,
     pub created_at: DateTime<Utc>,
     pub verdicts: Vec<ReviewVerdictResource>,
+    /// The revision commit's signature status, as on
+    /// [`RepositoryCommitResource`](crate::resource::repository::RepositoryCommitResource).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature_status: Option<String>,
 }
 
 #[derive(ApiResource, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -89,3 +93,24 @@ pub struct ReviewCommentResource {
     pub updated_at: DateTime<Utc>,
     pub author: Option<ReviewAuthorResource>,
 }
+
+#[derive(ApiResource, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MergeRequirementsResource {
+    pub required_approvals: i32,
+    pub block_on_rejection: bool,
+    pub require_resolved_comments: bool,
+    pub require_green_build: bool,
+}
+
+#[derive(ApiResource, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReviewMergeabilityResource {
+    pub mergeable: bool,
+    pub checks: Vec<MergeCheckResource>,
+}
+
+#[derive(ApiResource, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MergeCheckResource {
+    pub kind: String,
+    pub passed: bool,
+    pub message: String,
+}
@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
@@ -12,6 +14,18 @@ pub struct TaskResource {
     pub command: String,
     pub status: String,
     pub waits_for: Vec<Uuid>,
+    pub attempt: i32,
+    pub env: BTreeMap<String, String>,
+    pub timeout_seconds: Option<i32>,
+    pub retries: i32,
+    pub working_directory: Option<String>,
+    pub allow_failure: bool,
+    pub artifacts: Vec<String>,
+    pub cache_key: Option<String>,
+    pub cache_paths: Vec<String>,
+    pub image: Option<String>,
+    pub network: String,
+    pub secrets: Vec<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -28,6 +42,19 @@ pub struct PollTaskResource {
     pub name: String,
     pub command: String,
     pub status: String,
+
+    pub env: BTreeMap<String, String>,
+    pub timeout_seconds: Option<i32>,
+    pub retries: i32,
+    pub working_directory: Option<String>,
+    pub allow_failure: bool,
+    pub artifacts: Vec<String>,
+    pub cache_key: Option<String>,
+    pub cache_paths: Vec<String>,
+    pub image: Option<String>,
+    pub network: String,
+    pub secrets: BTreeMap<String, String>,
+    pub secrets_withheld: bool,
 }
 
 #[derive(ApiResource, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -0,0 +1,31 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(ApiResource, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TeamResource {
+    pub id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub created_at: DateTime<Utc>,
+
+    pub members: Option<Vec<TeamMemberResource>>,
+    pub repositories: Option<Vec<TeamRepositoryResource>>,
+}
+
+#[derive(ApiResource, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TeamMemberResource {
+    pub user_id: Uuid,
+    pub user_name: String,
+
+    pub created_at: DateTime<Utc>,
+    pub image_updated_at: DateTime<Utc>,
+}
+
+#[derive(ApiResource, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TeamRepositoryResource {
+    pub repository_id: Uuid,
+    pub repository_name: String,
+    pub role: String,
+    pub created_at: DateTime<Utc>,
+}
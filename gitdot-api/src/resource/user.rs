@@ -101,3 +101,47 @@ pub struct UserCommitResource {
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub diffs: Vec<CommitDiffResource>,
 }
+
+/// A personal access token as listed to its owner. The token itself is never
+/// listed.
+#[derive(ApiResource, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PersonalTokenResource {
+    pub id: Uuid,
+    pub name: String,
+    pub scopes: Vec<String>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+}
+
+/// A newly created personal access token. This is the only time `token` is
+/// returned.
+#[derive(ApiResource, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NewPersonalTokenResource {
+    pub token: String,
+    pub personal_token: PersonalTokenResource,
+}
+
+/// An SSH public key registered for git over SSH, identified by its
+/// fingerprint.
+#[derive(ApiResource, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SshKeyResource {
+    pub id: Uuid,
+    pub name: String,
+    pub key_type: String,
+    pub fingerprint: String,
+    pub created_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+}
+
+/// A GPG or SSH key whose signatures mark its owner's commits as verified,
+/// identified by its fingerprint.
+#[derive(ApiResource, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SigningKeyResource {
+    pub id: Uuid,
+    pub name: String,
+    /// `gpg` or `ssh`.
+    pub key_type: String,
+    pub fingerprint: String,
+    pub created_at: DateTime<Utc>,
+}
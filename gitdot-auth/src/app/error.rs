@@ -97,6 +97,7 @@ impl HttpStatus for TokenExtractionError {
             | Self::InvalidPublicKey(_)
             | Self::InvalidToken(_)
             | Self::Unauthorized => StatusCode::UNAUTHORIZED,
+            Self::Forbidden(_) => StatusCode::FORBIDDEN,
         }
     }
 }
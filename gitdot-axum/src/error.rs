@@ -16,4 +16,7 @@ pub enum TokenExtractionError {
 
     #[error("Unauthorized")]
     Unauthorized,
+
+    #[error("Forbidden: {0}")]
+    Forbidden(String),
 }
@@ -169,6 +169,50 @@ impl GitdotClient {
         Ok(response)
     }
 
+    /// Posts a gzipped tarball as the request body, with `request` as the query
+    /// string.
+    pub(crate) async fn post_archive<T>(
+        &self,
+        path: String,
+        request: T,
+        archive: Vec<u8>,
+    ) -> Result<reqwest::Response, Error>
+    where
+        T: ApiRequest,
+    {
+        let url = format!("{}/{}", self.api_server_url, path);
+        let response = self
+            .client
+            .post(&url)
+            .auth(&self.credentials)
+            .query(&request)
+            .header("Content-Type", "application/gzip")
+            .body(archive)
+            .send()
+            .await?
+            .error_for_status()?;
+
+        Ok(response)
+    }
+
+    /// Downloads a gzipped tarball; `None` when the server has none
+    /// (`204 No Content`).
+    pub(crate) async fn get_archive(&self, path: String) -> Result<Option<Vec<u8>>, Error> {
+        let url = format!("{}/{}", self.api_server_url, path);
+        let response = self
+            .client
+            .get(&url)
+            .auth(&self.credentials)
+            .send()
+            .await?
+            .error_for_status()?;
+
+        if response.status() == reqwest::StatusCode::NO_CONTENT {
+            return Ok(None);
+        }
+        Ok(Some(response.bytes().await?.to_vec()))
+    }
+
     pub(crate) async fn patch<T, R>(&self, path: String, request: T) -> Result<R, Error>
     where
         T: ApiRequest,
@@ -1,9 +1,12 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
 use uuid::Uuid;
 
 use gitdot_api::endpoint::task::{
+    heartbeat_task::{HeartbeatTaskRequest, HeartbeatTaskResponse},
+    list_task_artifacts::ListTaskArtifactsResponse,
     poll_task::{PollTaskRequest, PollTaskResponse},
     update_task::{UpdateTaskRequest, UpdateTaskResponse},
+    upload_task_artifact::{UploadTaskArtifactRequest, UploadTaskArtifactResponse},
 };
 
 use crate::client::GitdotClient;
@@ -19,4 +22,44 @@ impl GitdotClient {
         };
         self.patch(format!("ci/task/{}", id), request).await
     }
+
+    pub async fn heartbeat_task(&self, id: Uuid) -> Result<HeartbeatTaskResponse> {
+        self.post(format!("ci/task/{}/heartbeat", id), HeartbeatTaskRequest)
+            .await
+    }
+
+    pub async fn upload_task_artifact(
+        &self,
+        id: Uuid,
+        name: &str,
+        archive: Vec<u8>,
+    ) -> Result<UploadTaskArtifactResponse> {
+        let request = UploadTaskArtifactRequest {
+            name: name.to_string(),
+        };
+        let response = self
+            .post_archive(format!("ci/task/{}/artifact", id), request, archive)
+            .await?;
+        Ok(response.json().await?)
+    }
+
+    pub async fn list_task_artifacts(&self, id: Uuid) -> Result<ListTaskArtifactsResponse> {
+        self.get(format!("ci/task/{}/artifacts", id), ()).await
+    }
+
+    pub async fn download_task_artifact(&self, id: Uuid, artifact_id: Uuid) -> Result<Vec<u8>> {
+        self.get_archive(format!("ci/task/{}/artifact/{}", id, artifact_id))
+            .await?
+            .context("Artifact has no content")
+    }
+
+    pub async fn upload_task_cache(&self, id: Uuid, archive: Vec<u8>) -> Result<()> {
+        self.post_archive(format!("ci/task/{}/cache", id), (), archive)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn download_task_cache(&self, id: Uuid) -> Result<Option<Vec<u8>>> {
+        self.get_archive(format!("ci/task/{}/cache", id)).await
+    }
 }
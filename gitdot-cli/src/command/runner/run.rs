@@ -1,13 +1,19 @@
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
 use anyhow::Context;
+use tokio::sync::watch;
+use uuid::Uuid;
+
+use gitdot_api::resource::PollTaskResource;
 
 use crate::{
     client::GitdotClient,
-    config::RunnerConfig,
-    executor::{Executor, local::LocalExecutor},
+    config::{RunnerConfig, runner::ExecutorKind},
+    executor::{Executor, TaskOutcome, container::ContainerExecutor, local::LocalExecutor},
 };
 
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(15);
+
 pub async fn run(config: RunnerConfig) -> anyhow::Result<()> {
     if config.runner_token.is_none() {
         eprintln!("Error: runner is not installed. Please run `gitdot-runner install` first.");
@@ -48,32 +54,92 @@ pub async fn run(config: RunnerConfig) -> anyhow::Result<()> {
                 return;
             }
 
-            let executor = match LocalExecutor::initialize(&config, &task).await {
-                Ok(e) => e,
-                Err(e) => {
-                    eprintln!("Failed to initialize executor: {}", e);
-                    return;
+            match config.executor {
+                ExecutorKind::Local => {
+                    run_task::<LocalExecutor>(&config, &task_client, &task).await
                 }
-            };
-
-            let task_id = executor.task.id;
-            let result = executor.execute().await;
-
-            let final_status = match result {
-                Ok(()) => "success",
-                Err(ref e) => {
-                    eprintln!("Task {} failed: {}", task_id, e);
-                    "failure"
+                ExecutorKind::Container => {
+                    run_task::<ContainerExecutor>(&config, &task_client, &task).await
                 }
-            };
+            }
+        });
+    }
+}
 
-            if let Err(e) = task_client.update_task(task_id, final_status).await {
-                eprintln!("Failed to mark task {} as {}: {}", task_id, final_status, e);
+/// Runs a polled task to completion with executor `E` and reports how it
+/// ended.
+async fn run_task<E: Executor>(
+    config: &RunnerConfig,
+    task_client: &Arc<GitdotClient>,
+    task: &PollTaskResource,
+) {
+    let task_id = task.id;
+    let (cancel_tx, cancel_rx) = watch::channel(false);
+    // initializing can take a while (e.g. pulling an image), so the task is
+    // kept alive from the start
+    let heartbeat = tokio::spawn(heartbeat(Arc::clone(task_client), task_id, cancel_tx));
+    let executor = match E::initialize(config, task).await {
+        Ok(e) => e,
+        Err(e) => {
+            heartbeat.abort();
+            eprintln!("Failed to initialize executor: {}", e);
+            if !*cancel_rx.borrow()
+                && let Err(e) = task_client.update_task(task_id, "failure").await
+            {
+                eprintln!("Failed to mark task {} as failure: {}", task_id, e);
             }
+            return;
+        }
+    };
+
+    let result = match executor.restore(task_client).await {
+        Ok(()) => executor.execute(cancel_rx).await,
+        Err(e) => Err(e),
+    };
+    // dependents need the artifacts, so failing to upload them fails the task
+    let result = match result {
+        Ok(TaskOutcome::Success) => executor
+            .save(task_client)
+            .await
+            .map(|()| TaskOutcome::Success),
+        result => result,
+    };
+    heartbeat.abort();
 
-            if let Err(e) = executor.cleanup().await {
-                eprintln!("Failed to clean up task {}: {}", task_id, e);
+    let final_status = match result {
+        // the server cancelled the task; there is nothing to report
+        Ok(TaskOutcome::Cancelled) => None,
+        Ok(outcome) => Some(outcome.as_status()),
+        Err(ref e) => {
+            eprintln!("Task {} failed: {}", task_id, e);
+            Some("failure")
+        }
+    };
+
+    if let Some(final_status) = final_status
+        && let Err(e) = task_client.update_task(task_id, final_status).await
+    {
+        eprintln!("Failed to mark task {} as {}: {}", task_id, final_status, e);
+    }
+
+    if let Err(e) = executor.cleanup().await {
+        eprintln!("Failed to clean up task {}: {}", task_id, e);
+    }
+}
+
+/// Keeps the server from timing out a running task and flips `cancelled` once
+/// the server reports the task cancelled or timed out.
+async fn heartbeat(client: Arc<GitdotClient>, task_id: Uuid, cancelled: watch::Sender<bool>) {
+    let mut interval = tokio::time::interval(HEARTBEAT_INTERVAL);
+    loop {
+        interval.tick().await;
+        match client.heartbeat_task(task_id).await {
+            Ok(task) if task.status == "cancelled" || task.status == "timed_out" => {
+                let _ = cancelled.send(true);
+                return;
             }
-        });
+            Ok(_) => {}
+            Err(e) => eprintln!("Failed to heartbeat task {}: {}", task_id, e),
+        }
     }
 }
@@ -1,3 +1,5 @@
+use std::path::Path;
+
 use anyhow::Context;
 use figment::{
     Figment,
@@ -10,6 +12,8 @@ use crate::util::url::{API_SERVER_URL, S2_SERVER_URL, WEB_URL};
 const SYSTEM_USER: &str = "gitdot";
 const RUNNER_CONFIG_PATH: &str = "/etc/gitdot/runner.toml";
 const DEFAULT_NUM_EXECUTORS: i8 = 4;
+const DEFAULT_CONTAINER_RUNTIME: &str = "podman";
+const DEFAULT_CONTAINER_IMAGE: &str = "docker.io/library/debian:stable-slim";
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RunnerConfig {
@@ -18,6 +22,23 @@ pub struct RunnerConfig {
     pub s2_server_url: String,
     pub runner_token: Option<String>,
     pub num_executors: i8,
+    pub executor: ExecutorKind,
+    /// Rootless podman binary used by the container executor. Its flags are
+    /// podman-specific, so other runtimes are rejected on load.
+    pub container_runtime: String,
+    /// Image for tasks that don't set `image` in `.gitdot-ci.toml`.
+    pub container_image: String,
+}
+
+/// How the runner executes tasks.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExecutorKind {
+    /// Directly on the host, as the runner's user.
+    #[default]
+    Local,
+    /// In a rootless container with no host credentials.
+    Container,
 }
 
 impl Default for RunnerConfig {
@@ -28,17 +49,36 @@ impl Default for RunnerConfig {
             s2_server_url: S2_SERVER_URL.to_string(),
             runner_token: None,
             num_executors: DEFAULT_NUM_EXECUTORS,
+            executor: ExecutorKind::default(),
+            container_runtime: DEFAULT_CONTAINER_RUNTIME.to_string(),
+            container_image: DEFAULT_CONTAINER_IMAGE.to_string(),
         }
     }
 }
 
 impl RunnerConfig {
     pub fn load() -> anyhow::Result<Self> {
-        Figment::new()
+        let config: Self = Figment::new()
             .merge(Serialized::defaults(Self::default()))
             .merge(Toml::file(RUNNER_CONFIG_PATH))
             .extract()
-            .with_context(|| format!("Failed to load runner config from {RUNNER_CONFIG_PATH}"))
+            .with_context(|| format!("Failed to load runner config from {RUNNER_CONFIG_PATH}"))?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    fn validate(&self) -> anyhow::Result<()> {
+        if self.executor == ExecutorKind::Container {
+            let runtime = Path::new(&self.container_runtime)
+                .file_name()
+                .and_then(|name| name.to_str());
+            anyhow::ensure!(
+                runtime == Some("podman"),
+                "Unsupported container_runtime {:?}: the container executor requires podman",
+                self.container_runtime
+            );
+        }
+        Ok(())
     }
 
     pub fn save(&self) -> anyhow::Result<()> {
@@ -56,3 +96,36 @@ impl RunnerConfig {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{ExecutorKind, RunnerConfig};
+
+    fn config(executor: ExecutorKind, container_runtime: &str) -> RunnerConfig {
+        RunnerConfig {
+            executor,
+            container_runtime: container_runtime.to_string(),
+            ..RunnerConfig::default()
+        }
+    }
+
+    #[test]
+    fn validate_accepts_podman_by_name_or_path() {
+        assert!(config(ExecutorKind::Container, "podman").validate().is_ok());
+        assert!(
+            config(ExecutorKind::Container, "/usr/bin/podman")
+                .validate()
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn validate_rejects_other_runtimes_only_for_the_container_executor() {
+        assert!(
+            config(ExecutorKind::Container, "docker")
+                .validate()
+                .is_err()
+        );
+        assert!(config(ExecutorKind::Local, "docker").validate().is_ok());
+    }
+}
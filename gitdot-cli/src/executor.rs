@@ -1,13 +1,42 @@
+pub mod container;
 pub mod local;
+mod mask;
 
 use anyhow::Result;
+use tokio::sync::watch;
 
 use gitdot_api::resource::PollTaskResource;
 
-use crate::config::RunnerConfig;
+use crate::{client::GitdotClient, config::RunnerConfig};
 
 pub trait Executor: Sized {
     async fn initialize(config: &RunnerConfig, task: &PollTaskResource) -> Result<Self>;
-    async fn execute(&self) -> Result<()>;
+    /// Extracts the artifacts of the tasks this one waits for, and its cache if
+    /// one was saved, into the checkout.
+    async fn restore(&self, client: &GitdotClient) -> Result<()>;
+    /// Runs the task, stopping early once `cancelled` becomes `true`.
+    async fn execute(&self, cancelled: watch::Receiver<bool>) -> Result<TaskOutcome>;
+    /// Uploads the task's artifacts and cache after it succeeded.
+    async fn save(&self, client: &GitdotClient) -> Result<()>;
     async fn cleanup(self) -> Result<()>;
 }
+
+/// How a task run ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskOutcome {
+    Success,
+    Failure,
+    TimedOut,
+    Cancelled,
+}
+
+impl TaskOutcome {
+    pub fn as_status(&self) -> &'static str {
+        match self {
+            TaskOutcome::Success => "success",
+            TaskOutcome::Failure => "failure",
+            TaskOutcome::TimedOut => "timed_out",
+            TaskOutcome::Cancelled => "cancelled",
+        }
+    }
+}
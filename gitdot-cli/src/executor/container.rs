@@ -0,0 +1,237 @@
+use std::{path::Path, process::Stdio};
+
+use anyhow::{Context, Result};
+use tokio::{
+    process::{Child, Command},
+    sync::watch,
+};
+
+use gitdot_api::resource::PollTaskResource;
+
+use crate::{
+    client::GitdotClient,
+    config::RunnerConfig,
+    executor::{Executor, TaskOutcome, local::LocalExecutor},
+};
+
+/// Where the checkout is mounted inside the container.
+const WORKSPACE: &str = "/workspace";
+
+/// Runs each task in a rootless container. The checkout, artifacts, and caches
+/// are handled on the host like [`LocalExecutor`] does, but the command only
+/// sees the checkout, the task's own `env`, and the network its config allows.
+/// Nothing of the runner, such as its token or config, is mounted or passed in.
+pub struct ContainerExecutor {
+    local: LocalExecutor,
+    runtime: String,
+    image: String,
+}
+
+impl Executor for ContainerExecutor {
+    async fn initialize(config: &RunnerConfig, task: &PollTaskResource) -> Result<Self> {
+        let local = LocalExecutor::initialize(config, task).await?;
+        let image = task
+            .image
+            .clone()
+            .unwrap_or_else(|| config.container_image.clone());
+
+        Ok(Self {
+            local,
+            runtime: config.container_runtime.clone(),
+            image,
+        })
+    }
+
+    async fn restore(&self, client: &GitdotClient) -> Result<()> {
+        self.local.restore(client).await
+    }
+
+    async fn execute(&self, cancelled: watch::Receiver<bool>) -> Result<TaskOutcome> {
+        let outcome = self.local.execute_with(cancelled, || self.spawn()).await;
+
+        // killing the runtime's client does not always stop the container
+        // after a timeout or cancellation
+        if let Err(e) = self.remove().await {
+            eprintln!(
+                "Failed to remove container for task {}: {}",
+                self.local.task.id, e
+            );
+        }
+
+        outcome
+    }
+
+    async fn save(&self, client: &GitdotClient) -> Result<()> {
+        self.local.save(client).await
+    }
+
+    async fn cleanup(self) -> Result<()> {
+        self.local.cleanup().await
+    }
+}
+
+impl ContainerExecutor {
+    fn container_name(&self) -> String {
+        format!("gitdot-task-{}", self.local.task.id)
+    }
+
+    fn spawn(&self) -> Result<Child> {
+        let task = &self.local.task;
+        let args = run_args(
+            task,
+            &self.container_name(),
+            &self.image,
+            &self.local.working_directory,
+        );
+
+        // secrets are read from the runtime's environment so their values
+        // don't show up in the host's process list
+        Command::new(&self.runtime)
+            .args(args)
+            .envs(&task.secrets)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .with_context(|| format!("Failed to spawn {}", self.runtime))
+    }
+
+    async fn remove(&self) -> Result<()> {
+        let output = Command::new(&self.runtime)
+            .args(["rm", "--force", "--ignore"])
+            .arg(self.container_name())
+            .output()
+            .await
+            .with_context(|| format!("Failed to run {}", self.runtime))?;
+        anyhow::ensure!(
+            output.status.success(),
+            "{} rm failed: {}",
+            self.runtime,
+            String::from_utf8_lossy(&output.stderr)
+        );
+
+        Ok(())
+    }
+}
+
+/// The `podman run` arguments for `task`. `--replace`, `--userns=keep-id`
+/// and `rm --ignore` are podman-only, which is why the runner config rejects
+/// any other runtime.
+fn run_args(task: &PollTaskResource, name: &str, image: &str, checkout: &Path) -> Vec<String> {
+    let workdir = match &task.working_directory {
+        Some(dir) => format!("{WORKSPACE}/{dir}"),
+        None => WORKSPACE.to_string(),
+    };
+
+    let mut args: Vec<String> = ["run", "--rm", "--replace", "--pull=missing"]
+        .map(String::from)
+        .to_vec();
+    args.push(format!("--name={name}"));
+    // files written to the checkout stay owned by the runner's user
+    args.extend(
+        [
+            "--userns=keep-id",
+            "--cap-drop=all",
+            "--security-opt=no-new-privileges",
+        ]
+        .map(String::from),
+    );
+    args.push(format!("--volume={}:{WORKSPACE}", checkout.display()));
+    args.push(format!("--workdir={workdir}"));
+    if task.network != "outbound" {
+        args.push("--network=none".to_string());
+    }
+    // the runtime does not forward its own environment, only these
+    for (key, value) in &task.env {
+        args.push(format!("--env={key}={value}"));
+    }
+    // values come from the runtime's environment, see `spawn`
+    for name in task.secrets.keys() {
+        args.push(format!("--env={name}"));
+    }
+
+    args.push(image.to_string());
+    args.extend(["sh", "-c", task.command.as_str()].map(String::from));
+    args
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeMap, path::Path};
+
+    use gitdot_api::resource::PollTaskResource;
+
+    use super::run_args;
+
+    fn task(network: &str) -> PollTaskResource {
+        PollTaskResource {
+            id: "00000000-0000-0000-0000-000000000001".parse().unwrap(),
+            token: "task-token".to_string(),
+            owner_name: "alice".to_string(),
+            repository_name: "demo".to_string(),
+            s2_uri: String::new(),
+            name: "test".to_string(),
+            command: "cargo test".to_string(),
+            status: "running".to_string(),
+            env: BTreeMap::from([("RUST_LOG".to_string(), "debug".to_string())]),
+            timeout_seconds: None,
+            retries: 0,
+            working_directory: Some("crates/app".to_string()),
+            allow_failure: false,
+            artifacts: Vec::new(),
+            cache_key: None,
+            cache_paths: Vec::new(),
+            image: None,
+            network: network.to_string(),
+            secrets: BTreeMap::from([("API_KEY".to_string(), "hunter2".to_string())]),
+            secrets_withheld: false,
+        }
+    }
+
+    #[test]
+    fn run_args_confine_the_task_to_its_checkout() {
+        let args = run_args(
+            &task("none"),
+            "gitdot-task-1",
+            "debian:stable-slim",
+            Path::new("/tmp/gitdot/tasks/1"),
+        );
+
+        assert_eq!(
+            args,
+            [
+                "run",
+                "--rm",
+                "--replace",
+                "--pull=missing",
+                "--name=gitdot-task-1",
+                "--userns=keep-id",
+                "--cap-drop=all",
+                "--security-opt=no-new-privileges",
+                "--volume=/tmp/gitdot/tasks/1:/workspace",
+                "--workdir=/workspace/crates/app",
+                "--network=none",
+                "--env=RUST_LOG=debug",
+                "--env=API_KEY",
+                "debian:stable-slim",
+                "sh",
+                "-c",
+                "cargo test",
+            ]
+        );
+    }
+
+    #[test]
+    fn run_args_allow_outbound_network_and_never_carry_secret_values() {
+        let args = run_args(
+            &task("outbound"),
+            "gitdot-task-1",
+            "debian:stable-slim",
+            Path::new("/tmp/gitdot/tasks/1"),
+        );
+
+        assert!(!args.iter().any(|arg| arg.starts_with("--network")));
+        assert!(!args.iter().any(|arg| arg.contains("hunter2")));
+        assert!(!args.iter().any(|arg| arg.contains("task-token")));
+    }
+}
@@ -1,16 +1,28 @@
-use std::{path::PathBuf, process::Stdio};
+use std::{
+    path::PathBuf,
+    process::{ExitStatus, Stdio},
+    time::Duration,
+};
 
 use anyhow::{Context, Result};
 use s2_sdk::{
     S2,
-    producer::ProducerConfig,
+    producer::{Producer, ProducerConfig},
     types::{AppendRecord, Header},
 };
-use tokio::{io::AsyncReadExt, process::Command};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    process::{Child, Command},
+    sync::watch,
+};
 
 use gitdot_api::resource::PollTaskResource;
 
-use crate::{config::RunnerConfig, executor::Executor};
+use crate::{
+    client::GitdotClient,
+    config::RunnerConfig,
+    executor::{Executor, TaskOutcome, mask::SecretMasker},
+};
 
 pub struct LocalExecutor {
     pub working_directory: PathBuf,
@@ -40,73 +52,292 @@ impl Executor for LocalExecutor {
         })
     }
 
-    async fn execute(&self) -> Result<()> {
+    async fn restore(&self, client: &GitdotClient) -> Result<()> {
+        for artifact in client.list_task_artifacts(self.task.id).await? {
+            let archive = client
+                .download_task_artifact(self.task.id, artifact.id)
+                .await?;
+            self.extract(archive)
+                .await
+                .with_context(|| format!("Failed to restore artifact '{}'", artifact.name))?;
+        }
+
+        // a missing or broken cache only makes the task slower
+        if self.task.cache_key.is_some() {
+            let restored = match client.download_task_cache(self.task.id).await {
+                Ok(Some(archive)) => self.extract(archive).await,
+                Ok(None) => Ok(()),
+                Err(e) => Err(e),
+            };
+            if let Err(e) = restored {
+                eprintln!("Failed to restore cache for task {}: {}", self.task.id, e);
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn execute(&self, cancelled: watch::Receiver<bool>) -> Result<TaskOutcome> {
+        self.execute_with(cancelled, || self.spawn()).await
+    }
+
+    async fn save(&self, client: &GitdotClient) -> Result<()> {
+        for name in &self.task.artifacts {
+            let Some(archive) = self.archive(std::slice::from_ref(name)).await? else {
+                eprintln!(
+                    "Task {}: artifact '{}' does not exist, skipping",
+                    self.task.id, name
+                );
+                continue;
+            };
+            client
+                .upload_task_artifact(self.task.id, name, archive)
+                .await
+                .with_context(|| format!("Failed to upload artifact '{}'", name))?;
+        }
+
+        if self.task.cache_key.is_some() {
+            let saved = match self.archive(&self.task.cache_paths).await {
+                Ok(Some(archive)) => client.upload_task_cache(self.task.id, archive).await,
+                Ok(None) => Ok(()),
+                Err(e) => Err(e),
+            };
+            if let Err(e) = saved {
+                eprintln!("Failed to save cache for task {}: {}", self.task.id, e);
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn cleanup(self) -> Result<()> {
+        tokio::fs::remove_dir_all(&self.working_directory).await?;
+        Ok(())
+    }
+}
+
+impl LocalExecutor {
+    /// Runs every attempt of the task with processes started by `spawn`,
+    /// streaming their output to the task's log. Other executors reuse this
+    /// to run the command somewhere other than the host.
+    pub async fn execute_with(
+        &self,
+        mut cancelled: watch::Receiver<bool>,
+        spawn: impl Fn() -> Result<Child>,
+    ) -> Result<TaskOutcome> {
         let (basin_name, stream_name) = crate::util::s2::parse_s2_uri(&self.task.s2_uri)?;
 
         let stream = self.s2.basin(basin_name).stream(stream_name);
         let producer = stream.producer(ProducerConfig::default());
 
-        let mut child = Command::new("sh")
+        let timeout = self
+            .task
+            .timeout_seconds
+            .map(|secs| Duration::from_secs(secs.max(0) as u64));
+        let attempts = self.task.retries.max(0) + 1;
+
+        if self.task.secrets_withheld {
+            let notice = "gitdot: secrets withheld, the review author lacks write access\n";
+            Self::log_notice(&producer, notice.to_string()).await?;
+        }
+
+        let mut outcome = TaskOutcome::Failure;
+        for attempt in 1..=attempts {
+            if attempt > 1 {
+                let notice = format!("gitdot: retrying task (attempt {attempt} of {attempts})\n");
+                Self::log_notice(&producer, notice).await?;
+            }
+
+            outcome = self
+                .run_attempt(&spawn, &producer, timeout, &mut cancelled)
+                .await?;
+            if matches!(outcome, TaskOutcome::Success | TaskOutcome::Cancelled) {
+                break;
+            }
+        }
+
+        let record = AppendRecord::new(vec![])?
+            .with_headers([Header::new("task-finished", outcome.as_status())])?;
+        producer.submit(record).await?;
+        producer.close().await?;
+
+        Ok(outcome)
+    }
+
+    /// Runs the command once, killing it if it overruns `timeout` or the task
+    /// is cancelled.
+    async fn run_attempt(
+        &self,
+        spawn: &impl Fn() -> Result<Child>,
+        producer: &Producer,
+        timeout: Option<Duration>,
+        cancelled: &mut watch::Receiver<bool>,
+    ) -> Result<TaskOutcome> {
+        let mut child = spawn()?;
+
+        let deadline = async {
+            match timeout {
+                Some(timeout) => tokio::time::sleep(timeout).await,
+                None => std::future::pending().await,
+            }
+        };
+        let stopped = tokio::select! {
+            status = self.stream_output(&mut child, producer) => {
+                return Ok(if status?.success() {
+                    TaskOutcome::Success
+                } else {
+                    TaskOutcome::Failure
+                });
+            }
+            _ = deadline => TaskOutcome::TimedOut,
+            Ok(_) = cancelled.wait_for(|cancelled| *cancelled) => TaskOutcome::Cancelled,
+        };
+
+        child.kill().await?;
+        let notice = match stopped {
+            TaskOutcome::TimedOut => format!(
+                "gitdot: task timed out after {}s\n",
+                timeout.unwrap_or_default().as_secs()
+            ),
+            _ => "gitdot: task cancelled\n".to_string(),
+        };
+        Self::log_notice(producer, notice).await?;
+
+        Ok(stopped)
+    }
+
+    /// Packs the `paths` that exist in the checkout into a gzipped tarball,
+    /// keeping them relative to the checkout so [`extract`](Self::extract)
+    /// puts them back in place. `None` if none of them exist.
+    ///
+    /// tar follows symlinks in the directories leading up to a path, so each
+    /// path is resolved first and rejected if it leaves the checkout; a task
+    /// could otherwise link to host files and upload them as an artifact.
+    async fn archive(&self, paths: &[String]) -> Result<Option<Vec<u8>>> {
+        let root = tokio::fs::canonicalize(&self.working_directory).await?;
+        let mut existing = Vec::new();
+        for path in paths {
+            let resolved = match tokio::fs::canonicalize(root.join(path)).await {
+                Ok(resolved) => resolved,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+                Err(e) => return Err(e.into()),
+            };
+            anyhow::ensure!(
+                resolved.starts_with(&root),
+                "Path '{}' resolves outside the checkout",
+                path
+            );
+            existing.push(path.as_str());
+        }
+        if existing.is_empty() {
+            return Ok(None);
+        }
+
+        let output = Command::new("tar")
+            .args(["-czf", "-", "-C"])
+            .arg(&self.working_directory)
+            .arg("--")
+            .args(existing)
+            .output()
+            .await
+            .context("Failed to run tar")?;
+        anyhow::ensure!(
+            output.status.success(),
+            "tar failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+
+        Ok(Some(output.stdout))
+    }
+
+    /// Unpacks a tarball made by [`archive`](Self::archive) into the checkout.
+    async fn extract(&self, archive: Vec<u8>) -> Result<()> {
+        let mut child = Command::new("tar")
+            .args(["-xzf", "-", "-C"])
+            .arg(&self.working_directory)
+            .stdin(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .context("Failed to run tar")?;
+
+        let mut stdin = child.stdin.take().unwrap();
+        stdin.write_all(&archive).await?;
+        drop(stdin);
+
+        let output = child.wait_with_output().await?;
+        anyhow::ensure!(
+            output.status.success(),
+            "tar failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+
+        Ok(())
+    }
+
+    async fn log_notice(producer: &Producer, notice: String) -> Result<()> {
+        let record = AppendRecord::new(notice.into_bytes())?
+            .with_headers([Header::new("stream", "stderr")])?;
+        producer.submit(record).await?;
+        Ok(())
+    }
+
+    fn spawn(&self) -> Result<Child> {
+        let current_dir = match &self.task.working_directory {
+            Some(dir) => self.working_directory.join(dir),
+            None => self.working_directory.clone(),
+        };
+
+        Command::new("sh")
             .args(["-c", &self.task.command])
-            .current_dir(&self.working_directory)
+            .current_dir(current_dir)
+            .envs(&self.task.env)
+            .envs(&self.task.secrets)
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
             .spawn()
-            .context("Failed to spawn process")?;
+            .context("Failed to spawn process")
+    }
 
+    /// Streams the child's stdout and stderr to the log, with the task's
+    /// secrets masked, until both close.
+    async fn stream_output(&self, child: &mut Child, producer: &Producer) -> Result<ExitStatus> {
         let mut stdout = child.stdout.take().unwrap();
         let mut stderr = child.stderr.take().unwrap();
         let mut stdout_buf = [0u8; 8192];
         let mut stderr_buf = [0u8; 8192];
+        let mut stdout_masker = SecretMasker::new(self.task.secrets.values());
+        let mut stderr_masker = SecretMasker::new(self.task.secrets.values());
         let mut stdout_done = false;
         let mut stderr_done = false;
 
         while !stdout_done || !stderr_done {
-            tokio::select! {
+            let (stream, masked) = tokio::select! {
                 result = stdout.read(&mut stdout_buf), if !stdout_done => {
                     let n = result?;
                     if n == 0 {
                         stdout_done = true;
+                        ("stdout", stdout_masker.finish())
                     } else {
-                        let record = AppendRecord::new(stdout_buf[..n].to_vec())?
-                            .with_headers([Header::new("stream", "stdout")])?;
-                        producer.submit(record).await?;
+                        ("stdout", stdout_masker.push(&stdout_buf[..n]))
                     }
                 },
                 result = stderr.read(&mut stderr_buf), if !stderr_done => {
                     let n = result?;
                     if n == 0 {
                         stderr_done = true;
+                        ("stderr", stderr_masker.finish())
                     } else {
-                        let record = AppendRecord::new(stderr_buf[..n].to_vec())?
-                            .with_headers([Header::new("stream", "stderr")])?;
-                        producer.submit(record).await?;
+                        ("stderr", stderr_masker.push(&stderr_buf[..n]))
                     }
                 },
+            };
+            if !masked.is_empty() {
+                let record =
+                    AppendRecord::new(masked)?.with_headers([Header::new("stream", stream)])?;
+                producer.submit(record).await?;
             }
         }
 
-        let status = child.wait().await?;
-
-        let task_status = if status.success() {
-            "success"
-        } else {
-            "failure"
-        };
-
-        let record =
-            AppendRecord::new(vec![])?.with_headers([Header::new("task-finished", task_status)])?;
-        producer.submit(record).await?;
-        producer.close().await?;
-
-        if !status.success() {
-            anyhow::bail!("Task {} exited with status {}", self.task.id, status);
-        }
-        Ok(())
-    }
-
-    async fn cleanup(self) -> Result<()> {
-        tokio::fs::remove_dir_all(&self.working_directory).await?;
-        Ok(())
+        Ok(child.wait().await?)
     }
 }
@@ -0,0 +1,63 @@
+const MASK: &[u8] = b"***";
+
+/// Replaces secret values in a task's output with `***` before it reaches the
+/// log. Output arrives in arbitrary chunks, so bytes that could be the start
+/// of a secret split across two chunks are held back until the next one.
+pub struct SecretMasker {
+    secrets: Vec<Vec<u8>>,
+    pending: Vec<u8>,
+}
+
+impl SecretMasker {
+    pub fn new<'a>(secrets: impl IntoIterator<Item = &'a String>) -> Self {
+        let mut secrets: Vec<Vec<u8>> = secrets
+            .into_iter()
+            .filter(|secret| !secret.is_empty())
+            .map(|secret| secret.as_bytes().to_vec())
+            .collect();
+        // mask the longest match when one secret contains another
+        secrets.sort_by_key(|secret| std::cmp::Reverse(secret.len()));
+
+        Self {
+            secrets,
+            pending: Vec::new(),
+        }
+    }
+
+    /// Masks `chunk` and returns what can be logged so far.
+    pub fn push(&mut self, chunk: &[u8]) -> Vec<u8> {
+        if self.secrets.is_empty() {
+            return chunk.to_vec();
+        }
+        self.pending.extend_from_slice(chunk);
+        self.drain(false)
+    }
+
+    /// Masks and returns whatever was held back, once the output ended.
+    pub fn finish(&mut self) -> Vec<u8> {
+        self.drain(true)
+    }
+
+    fn drain(&mut self, finished: bool) -> Vec<u8> {
+        let mut masked = Vec::with_capacity(self.pending.len());
+        let mut pos = 0;
+        'scan: while pos < self.pending.len() {
+            let rest = &self.pending[pos..];
+            for secret in &self.secrets {
+                if rest.starts_with(secret) {
+                    masked.extend_from_slice(MASK);
+                    pos += secret.len();
+                    continue 'scan;
+                }
+                if !finished && secret.starts_with(rest) {
+                    break 'scan;
+                }
+            }
+            masked.push(rest[0]);
+            pos += 1;
+        }
+
+        self.pending.drain(..pos);
+        masked
+    }
+}
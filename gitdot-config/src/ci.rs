@@ -1,6 +1,8 @@
+use std::{collections::BTreeMap, time::Duration};
+
 use serde::{Deserialize, Serialize};
 
-use super::{error::CiConfigError, validate::validate_ci_config};
+use super::{condition::Condition, error::CiConfigError, glob, validate::validate_ci_config};
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct CiConfig {
@@ -34,6 +36,36 @@ impl CiConfig {
 pub struct BuildConfig {
     pub trigger: BuildTrigger,
     pub tasks: Vec<String>,
+    /// Branch globs a `push_to_main` or `pull_request` build is limited to.
+    pub branches: Option<Vec<String>>,
+    /// Tag globs a `tag_push` build is limited to.
+    pub tags: Option<Vec<String>>,
+    /// Path globs; the build only runs if a changed file matches one.
+    pub paths: Option<Vec<String>>,
+    /// Cron expression for a `schedule` build, see [`CronSchedule`].
+    ///
+    /// [`CronSchedule`]: crate::cron::CronSchedule
+    pub schedule: Option<String>,
+}
+
+impl BuildConfig {
+    /// Whether the build's branch, tag, and path filters accept `context`.
+    /// Path filters are only applied when the changed paths are known.
+    pub fn matches(&self, context: &BuildContext) -> bool {
+        let accepts = |patterns: &Option<Vec<String>>, name: Option<&str>| match (patterns, name) {
+            (None, _) => true,
+            (Some(patterns), Some(name)) => patterns.iter().any(|p| glob::matches(p, name)),
+            (Some(_), None) => false,
+        };
+        let paths_match = match (&self.paths, context.changed_paths) {
+            (Some(patterns), Some(paths)) => paths
+                .iter()
+                .any(|path| patterns.iter().any(|p| glob::matches(p, path))),
+            _ => true,
+        };
+
+        accepts(&self.branches, context.branch) && accepts(&self.tags, context.tag) && paths_match
+    }
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -41,6 +73,107 @@ pub struct TaskConfig {
     pub name: String,
     pub command: String,
     pub waits_for: Option<Vec<String>>,
+    #[serde(default)]
+    pub env: BTreeMap<String, String>,
+    /// Maximum run time per attempt, e.g. `"90s"`, `"10m"` or `"2h"`.
+    pub timeout: Option<String>,
+    /// How many times a failed task is re-run before it is reported failed.
+    #[serde(default)]
+    pub retries: u32,
+    /// Directory, relative to the repository root, the command runs in.
+    pub working_directory: Option<String>,
+    /// A failure of this task does not fail the build or block its dependents.
+    #[serde(default)]
+    pub allow_failure: bool,
+    /// Expression deciding whether the task runs at all, see [`Condition`].
+    #[serde(rename = "if")]
+    pub condition: Option<String>,
+    /// Paths, relative to the repository root, uploaded as build artifacts
+    /// when the task succeeds. Tasks that wait for this one get them restored
+    /// into their checkout.
+    #[serde(default)]
+    pub artifacts: Vec<String>,
+    /// Directories saved under a key after the task succeeds and restored
+    /// before it runs in later builds, see [`CacheConfig`].
+    pub cache: Option<CacheConfig>,
+    /// Container image the task runs in on runners using the container
+    /// executor; they fall back to their configured default image.
+    pub image: Option<String>,
+    /// Network access inside the container, see [`NetworkPolicy`].
+    #[serde(default)]
+    pub network: NetworkPolicy,
+    /// Names of repository or organization secrets passed to the command as
+    /// environment variables of the same name.
+    #[serde(default)]
+    pub secrets: Vec<String>,
+}
+
+/// What a containerized task may reach over the network. Only enforced by
+/// the container executor.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NetworkPolicy {
+    /// No network interfaces besides loopback.
+    #[default]
+    None,
+    /// Outbound connections, e.g. to fetch dependencies.
+    Outbound,
+}
+
+/// A task cache: `paths` are archived under `key`, shared by every build of
+/// the repository.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CacheConfig {
+    pub key: String,
+    pub paths: Vec<String>,
+}
+
+impl TaskConfig {
+    /// The parsed `timeout`; `None` if unset (or invalid, which validation
+    /// rejects).
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout.as_deref().and_then(|t| parse_duration(t).ok())
+    }
+
+    /// Whether the task's `if` condition holds for `context`. Tasks without a
+    /// condition always run.
+    pub fn should_run(&self, context: &BuildContext) -> bool {
+        match self.condition.as_deref().map(Condition::parse) {
+            Some(Ok(condition)) => condition.evaluate(context),
+            Some(Err(_)) => false,
+            None => true,
+        }
+    }
+}
+
+/// What a build is being started for, used to evaluate build filters and task
+/// conditions. `branch` and `tag` are short names (no `refs/heads/` or
+/// `refs/tags/` prefix).
+#[derive(Debug, Clone)]
+pub struct BuildContext<'a> {
+    pub trigger: BuildTrigger,
+    pub branch: Option<&'a str>,
+    pub tag: Option<&'a str>,
+    pub changed_paths: Option<&'a [String]>,
+}
+
+/// Parses a duration such as `"45s"`, `"10m"` or `"2h"`.
+pub(crate) fn parse_duration(value: &str) -> Result<Duration, String> {
+    let value = value.trim();
+    let split = value
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(value.len());
+    let (amount, unit) = value.split_at(split);
+    let amount: u64 = amount
+        .parse()
+        .map_err(|_| format!("'{value}' must be a number followed by s, m or h"))?;
+    let seconds = match unit {
+        "s" => amount,
+        "m" => amount * 60,
+        "h" => amount * 60 * 60,
+        _ => return Err(format!("'{value}' must be a number followed by s, m or h")),
+    };
+    Ok(Duration::from_secs(seconds))
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -48,17 +181,29 @@ pub struct TaskConfig {
 pub enum BuildTrigger {
     PullRequest,
     PushToMain,
+    TagPush,
+    Manual,
+    Schedule,
 }
 
-impl Into<String> for BuildTrigger {
-    fn into(self) -> String {
+impl BuildTrigger {
+    pub fn as_str(&self) -> &'static str {
         match self {
-            BuildTrigger::PullRequest => "pull_request".to_string(),
-            BuildTrigger::PushToMain => "push_to_main".to_string(),
+            BuildTrigger::PullRequest => "pull_request",
+            BuildTrigger::PushToMain => "push_to_main",
+            BuildTrigger::TagPush => "tag_push",
+            BuildTrigger::Manual => "manual",
+            BuildTrigger::Schedule => "schedule",
         }
     }
 }
 
+impl Into<String> for BuildTrigger {
+    fn into(self) -> String {
+        self.as_str().to_string()
+    }
+}
+
 impl TryFrom<&str> for BuildTrigger {
     type Error = CiConfigError;
 
@@ -66,7 +211,67 @@ impl TryFrom<&str> for BuildTrigger {
         match s {
             "pull_request" => Ok(BuildTrigger::PullRequest),
             "push_to_main" => Ok(BuildTrigger::PushToMain),
+            "tag_push" => Ok(BuildTrigger::TagPush),
+            "manual" => Ok(BuildTrigger::Manual),
+            "schedule" => Ok(BuildTrigger::Schedule),
             other => Err(CiConfigError::InvalidTrigger(other.to_string())),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{BuildContext, BuildTrigger, CiConfig};
+
+    fn config() -> CiConfig {
+        CiConfig::new(
+            r#"
+            [[builds]]
+            trigger = "pull_request"
+            branches = ["main", "release/*"]
+            paths = ["src/**"]
+            tasks = ["test", "deploy"]
+
+            [[tasks]]
+            name = "test"
+            command = "cargo test"
+
+            [[tasks]]
+            name = "deploy"
+            command = "./deploy.sh"
+            if = "branch == 'main'"
+        "#,
+        )
+        .unwrap()
+    }
+
+    fn context<'a>(branch: &'a str, changed_paths: Option<&'a [String]>) -> BuildContext<'a> {
+        BuildContext {
+            trigger: BuildTrigger::PullRequest,
+            branch: Some(branch),
+            tag: None,
+            changed_paths,
+        }
+    }
+
+    #[test]
+    fn build_filters_check_branches_and_changed_paths() {
+        let config = config();
+        let build = &config.builds[0];
+        let src = vec!["src/lib.rs".to_string()];
+        let docs = vec!["docs/index.md".to_string()];
+
+        assert!(build.matches(&context("release/1.0", Some(&src))));
+        assert!(build.matches(&context("main", None)));
+        assert!(!build.matches(&context("feature", Some(&src))));
+        assert!(!build.matches(&context("main", Some(&docs))));
+    }
+
+    #[test]
+    fn tasks_run_when_their_condition_holds() {
+        let config = config();
+        assert!(config.tasks[0].should_run(&context("dev", None)));
+        assert!(config.tasks[1].should_run(&context("main", None)));
+        assert!(!config.tasks[1].should_run(&context("dev", None)));
+    }
+}
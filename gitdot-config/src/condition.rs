@@ -0,0 +1,333 @@
+use crate::{
+    ci::{BuildContext, BuildTrigger},
+    glob,
+};
+
+/// A parsed task `if` expression.
+///
+/// Comparisons test a build variable against a quoted string and can be
+/// combined with `!`, `&&`, `||` and parentheses:
+///
+/// ```text
+/// trigger == 'push_to_main' && !(branch =~ 'release/*')
+/// ```
+///
+/// Variables are `trigger`, `branch`, and `tag`. `==` and `!=` compare exactly;
+/// `=~` matches a glob pattern. A variable that is unset for the build (e.g.
+/// `tag` on a branch push) is unequal to every string and matches no pattern.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Condition(Expr);
+
+#[derive(Debug, Clone, PartialEq)]
+enum Expr {
+    Compare(Variable, Op, String),
+    Not(Box<Expr>),
+    And(Box<Expr>, Box<Expr>),
+    Or(Box<Expr>, Box<Expr>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Variable {
+    Trigger,
+    Branch,
+    Tag,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Op {
+    Eq,
+    Ne,
+    Glob,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Ident(String),
+    Str(String),
+    Eq,
+    Ne,
+    Glob,
+    Not,
+    And,
+    Or,
+    LParen,
+    RParen,
+}
+
+impl Condition {
+    pub fn parse(source: &str) -> Result<Self, String> {
+        let tokens = tokenize(source)?;
+        let mut parser = Parser { tokens, pos: 0 };
+        let expr = parser.parse_or()?;
+        match parser.tokens.get(parser.pos) {
+            None => Ok(Condition(expr)),
+            Some(token) => Err(format!("unexpected {} after expression", describe(token))),
+        }
+    }
+
+    pub fn evaluate(&self, context: &BuildContext) -> bool {
+        evaluate(&self.0, context)
+    }
+}
+
+fn evaluate(expr: &Expr, context: &BuildContext) -> bool {
+    match expr {
+        Expr::Compare(variable, op, value) => {
+            let actual = match variable {
+                Variable::Trigger => Some(context.trigger.as_str()),
+                Variable::Branch => context.branch,
+                Variable::Tag => context.tag,
+            };
+            match (op, actual) {
+                (Op::Eq, actual) => actual == Some(value.as_str()),
+                (Op::Ne, actual) => actual != Some(value.as_str()),
+                (Op::Glob, Some(actual)) => glob::matches(value, actual),
+                (Op::Glob, None) => false,
+            }
+        }
+        Expr::Not(inner) => !evaluate(inner, context),
+        Expr::And(left, right) => evaluate(left, context) && evaluate(right, context),
+        Expr::Or(left, right) => evaluate(left, context) || evaluate(right, context),
+    }
+}
+
+fn tokenize(source: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = Vec::new();
+    let mut chars = source.char_indices().peekable();
+
+    while let Some((i, c)) = chars.next() {
+        let token = match c {
+            c if c.is_whitespace() => continue,
+            '(' => Token::LParen,
+            ')' => Token::RParen,
+            '=' | '!' | '&' | '|' => {
+                let pair = match (c, chars.peek().map(|&(_, next)| next)) {
+                    ('=', Some('=')) => Some(Token::Eq),
+                    ('=', Some('~')) => Some(Token::Glob),
+                    ('!', Some('=')) => Some(Token::Ne),
+                    ('&', Some('&')) => Some(Token::And),
+                    ('|', Some('|')) => Some(Token::Or),
+                    _ => None,
+                };
+                match pair {
+                    Some(token) => {
+                        chars.next();
+                        token
+                    }
+                    None if c == '!' => Token::Not,
+                    None => return Err(format!("unexpected '{c}' at position {i}")),
+                }
+            }
+            '\'' | '"' => {
+                let mut value = String::new();
+                loop {
+                    match chars.next() {
+                        Some((_, q)) if q == c => break,
+                        Some((_, ch)) => value.push(ch),
+                        None => return Err(format!("unterminated string at position {i}")),
+                    }
+                }
+                Token::Str(value)
+            }
+            c if c.is_ascii_alphabetic() || c == '_' => {
+                let mut ident = c.to_string();
+                while let Some(&(_, ch)) = chars.peek() {
+                    if !(ch.is_ascii_alphanumeric() || ch == '_') {
+                        break;
+                    }
+                    ident.push(ch);
+                    chars.next();
+                }
+                Token::Ident(ident)
+            }
+            _ => return Err(format!("unexpected '{c}' at position {i}")),
+        };
+        tokens.push(token);
+    }
+
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn eat(&mut self, expected: &Token) -> bool {
+        if self.tokens.get(self.pos) == Some(expected) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn parse_or(&mut self) -> Result<Expr, String> {
+        let mut expr = self.parse_and()?;
+        while self.eat(&Token::Or) {
+            expr = Expr::Or(Box::new(expr), Box::new(self.parse_and()?));
+        }
+        Ok(expr)
+    }
+
+    fn parse_and(&mut self) -> Result<Expr, String> {
+        let mut expr = self.parse_unary()?;
+        while self.eat(&Token::And) {
+            expr = Expr::And(Box::new(expr), Box::new(self.parse_unary()?));
+        }
+        Ok(expr)
+    }
+
+    fn parse_unary(&mut self) -> Result<Expr, String> {
+        match self.next() {
+            Some(Token::Not) => Ok(Expr::Not(Box::new(self.parse_unary()?))),
+            Some(Token::LParen) => {
+                let expr = self.parse_or()?;
+                if self.eat(&Token::RParen) {
+                    Ok(expr)
+                } else {
+                    Err("missing closing ')'".to_string())
+                }
+            }
+            Some(Token::Ident(name)) => self.parse_comparison(&name),
+            Some(token) => Err(format!("expected a comparison, found {}", describe(&token))),
+            None => Err("expected a comparison, found end of expression".to_string()),
+        }
+    }
+
+    fn parse_comparison(&mut self, name: &str) -> Result<Expr, String> {
+        let variable = match name {
+            "trigger" => Variable::Trigger,
+            "branch" => Variable::Branch,
+            "tag" => Variable::Tag,
+            other => {
+                return Err(format!(
+                    "unknown variable '{other}' (expected trigger, branch, or tag)"
+                ));
+            }
+        };
+        let op = match self.next() {
+            Some(Token::Eq) => Op::Eq,
+            Some(Token::Ne) => Op::Ne,
+            Some(Token::Glob) => Op::Glob,
+            Some(token) => {
+                return Err(format!(
+                    "expected ==, != or =~ after '{name}', found {}",
+                    describe(&token)
+                ));
+            }
+            None => return Err(format!("expected ==, != or =~ after '{name}'")),
+        };
+        let value = match self.next() {
+            Some(Token::Str(value)) => value,
+            Some(token) => {
+                return Err(format!(
+                    "expected a quoted string after '{name}', found {}",
+                    describe(&token)
+                ));
+            }
+            None => return Err(format!("expected a quoted string after '{name}'")),
+        };
+        if variable == Variable::Trigger && BuildTrigger::try_from(value.as_str()).is_err() {
+            return Err(format!("unknown trigger '{value}'"));
+        }
+        Ok(Expr::Compare(variable, op, value))
+    }
+}
+
+fn describe(token: &Token) -> String {
+    match token {
+        Token::Ident(name) => format!("'{name}'"),
+        Token::Str(value) => format!("string '{value}'"),
+        Token::Eq => "'=='".to_string(),
+        Token::Ne => "'!='".to_string(),
+        Token::Glob => "'=~'".to_string(),
+        Token::Not => "'!'".to_string(),
+        Token::And => "'&&'".to_string(),
+        Token::Or => "'||'".to_string(),
+        Token::LParen => "'('".to_string(),
+        Token::RParen => "')'".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Condition;
+    use crate::ci::{BuildContext, BuildTrigger};
+
+    fn push(branch: &str) -> BuildContext<'_> {
+        BuildContext {
+            trigger: BuildTrigger::PushToMain,
+            branch: Some(branch),
+            tag: None,
+            changed_paths: None,
+        }
+    }
+
+    fn eval(source: &str, context: &BuildContext) -> bool {
+        Condition::parse(source).unwrap().evaluate(context)
+    }
+
+    #[test]
+    fn compares_variables_exactly() {
+        assert!(eval("trigger == 'push_to_main'", &push("main")));
+        assert!(eval("branch != \"dev\"", &push("main")));
+        assert!(!eval("branch == 'mai'", &push("main")));
+    }
+
+    #[test]
+    fn glob_operator_matches_patterns() {
+        assert!(eval("branch =~ 'release/*'", &push("release/1.0")));
+        assert!(!eval("branch =~ 'release/*'", &push("main")));
+    }
+
+    #[test]
+    fn unset_variables_never_match() {
+        assert!(!eval("tag == 'v1'", &push("main")));
+        assert!(eval("tag != 'v1'", &push("main")));
+        assert!(!eval("tag =~ '**'", &push("main")));
+    }
+
+    #[test]
+    fn combines_with_precedence_and_parentheses() {
+        let context = push("main");
+        assert!(eval(
+            "branch == 'x' || branch == 'main' && trigger == 'push_to_main'",
+            &context
+        ));
+        assert!(!eval(
+            "(branch == 'x' || branch == 'main') && trigger == 'manual'",
+            &context
+        ));
+        assert!(eval("!(branch == 'x')", &context));
+    }
+
+    #[test]
+    fn reports_parse_errors() {
+        let err = |source: &str| Condition::parse(source).unwrap_err();
+        assert_eq!(
+            err("commit == 'abc'"),
+            "unknown variable 'commit' (expected trigger, branch, or tag)"
+        );
+        assert_eq!(err("trigger == 'nightly'"), "unknown trigger 'nightly'");
+        assert_eq!(err("branch = 'main'"), "unexpected '=' at position 7");
+        assert_eq!(err("branch == 'main"), "unterminated string at position 10");
+        assert_eq!(err("(branch == 'main'"), "missing closing ')'");
+        assert_eq!(
+            err("branch == main"),
+            "expected a quoted string after 'branch', found 'main'"
+        );
+        assert_eq!(
+            err("branch == 'a' branch == 'b'"),
+            "unexpected 'branch' after expression"
+        );
+        assert_eq!(err(""), "expected a comparison, found end of expression");
+    }
+}
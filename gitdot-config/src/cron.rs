@@ -0,0 +1,178 @@
+/// A parsed five-field cron expression (`minute hour day-of-month month
+/// day-of-week`), evaluated in UTC.
+///
+/// Each field is `*` or a comma-separated list of values, `a-b` ranges, and
+/// `/n` steps (`*/15`, `1-5/2`). Day-of-week accepts both 0 and 7 for Sunday.
+/// As in standard cron, when both day fields are restricted a time matches if
+/// either one does.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CronSchedule {
+    minutes: Vec<bool>,
+    hours: Vec<bool>,
+    days: Vec<bool>,
+    months: Vec<bool>,
+    weekdays: Vec<bool>,
+    days_restricted: bool,
+    weekdays_restricted: bool,
+}
+
+const FIELDS: [(&str, u32, u32); 5] = [
+    ("minute", 0, 59),
+    ("hour", 0, 23),
+    ("day-of-month", 1, 31),
+    ("month", 1, 12),
+    ("day-of-week", 0, 7),
+];
+
+impl CronSchedule {
+    pub fn parse(expression: &str) -> Result<Self, String> {
+        let fields: Vec<&str> = expression.split_whitespace().collect();
+        if fields.len() != FIELDS.len() {
+            return Err(format!(
+                "expected 5 fields (minute hour day-of-month month day-of-week), found {}",
+                fields.len()
+            ));
+        }
+
+        let mut sets = Vec::with_capacity(FIELDS.len());
+        for (field, &(name, min, max)) in fields.iter().zip(FIELDS.iter()) {
+            sets.push(parse_field(field, min, max).map_err(|e| format!("{name} field: {e}"))?);
+        }
+
+        let mut weekdays = sets.pop().unwrap_or_default();
+        if weekdays[7] {
+            weekdays[0] = true;
+        }
+        weekdays.truncate(7);
+        let months = sets.pop().unwrap_or_default();
+        let days = sets.pop().unwrap_or_default();
+        let hours = sets.pop().unwrap_or_default();
+        let minutes = sets.pop().unwrap_or_default();
+
+        Ok(Self {
+            minutes,
+            hours,
+            days,
+            months,
+            weekdays,
+            days_restricted: fields[2] != "*",
+            weekdays_restricted: fields[4] != "*",
+        })
+    }
+
+    /// Whether the schedule fires at the given UTC minute. `weekday` counts
+    /// from Sunday = 0.
+    pub fn matches(&self, minute: u32, hour: u32, day: u32, month: u32, weekday: u32) -> bool {
+        let hit = |set: &[bool], value: u32| set.get(value as usize).copied().unwrap_or(false);
+
+        let day_hit = hit(&self.days, day);
+        let weekday_hit = hit(&self.weekdays, weekday % 7);
+        let day_matches = match (self.days_restricted, self.weekdays_restricted) {
+            (true, true) => day_hit || weekday_hit,
+            (true, false) => day_hit,
+            (false, true) => weekday_hit,
+            (false, false) => true,
+        };
+
+        hit(&self.minutes, minute)
+            && hit(&self.hours, hour)
+            && hit(&self.months, month)
+            && day_matches
+    }
+}
+
+fn parse_field(field: &str, min: u32, max: u32) -> Result<Vec<bool>, String> {
+    let mut set = vec![false; max as usize + 1];
+
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => {
+                let step = parse_number(step)?;
+                if step == 0 {
+                    return Err(format!("step in '{part}' must be greater than 0"));
+                }
+                (range, step)
+            }
+            None => (part, 1),
+        };
+
+        let (start, end) = if range == "*" {
+            (min, max)
+        } else if let Some((start, end)) = range.split_once('-') {
+            (parse_number(start)?, parse_number(end)?)
+        } else {
+            let value = parse_number(range)?;
+            // `5/10` means "every 10 starting at 5"
+            (value, if step > 1 { max } else { value })
+        };
+
+        if start < min || end > max {
+            return Err(format!("'{part}' is outside {min}-{max}"));
+        }
+        if start > end {
+            return Err(format!("range '{range}' is reversed"));
+        }
+
+        for value in (start..=end).step_by(step as usize) {
+            set[value as usize] = true;
+        }
+    }
+
+    Ok(set)
+}
+
+fn parse_number(value: &str) -> Result<u32, String> {
+    value
+        .parse()
+        .map_err(|_| format!("'{value}' is not a number"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CronSchedule;
+
+    #[test]
+    fn parses_wildcards_lists_ranges_and_steps() {
+        let schedule = CronSchedule::parse("*/15 9-17 * * 1-5").unwrap();
+        assert!(schedule.matches(0, 9, 1, 1, 1));
+        assert!(schedule.matches(45, 17, 1, 1, 5));
+        assert!(!schedule.matches(10, 9, 1, 1, 1));
+        assert!(!schedule.matches(0, 8, 1, 1, 1));
+        assert!(!schedule.matches(0, 9, 1, 1, 6));
+
+        let schedule = CronSchedule::parse("0,30 0 1 1,7 *").unwrap();
+        assert!(schedule.matches(30, 0, 1, 7, 3));
+        assert!(!schedule.matches(30, 0, 1, 6, 3));
+    }
+
+    #[test]
+    fn sunday_is_zero_or_seven() {
+        let schedule = CronSchedule::parse("0 0 * * 7").unwrap();
+        assert!(schedule.matches(0, 0, 5, 1, 0));
+    }
+
+    #[test]
+    fn restricted_day_fields_match_either() {
+        let schedule = CronSchedule::parse("0 0 13 * 5").unwrap();
+        assert!(schedule.matches(0, 0, 13, 3, 2));
+        assert!(schedule.matches(0, 0, 20, 3, 5));
+        assert!(!schedule.matches(0, 0, 20, 3, 2));
+    }
+
+    #[test]
+    fn reports_invalid_expressions() {
+        let err = |expression: &str| CronSchedule::parse(expression).unwrap_err();
+        assert_eq!(
+            err("* * * *"),
+            "expected 5 fields (minute hour day-of-month month day-of-week), found 4"
+        );
+        assert_eq!(err("60 * * * *"), "minute field: '60' is outside 0-59");
+        assert_eq!(err("* 5-2 * * *"), "hour field: range '5-2' is reversed");
+        assert_eq!(err("* * 0 * *"), "day-of-month field: '0' is outside 1-31");
+        assert_eq!(
+            err("*/0 * * * *"),
+            "minute field: step in '*/0' must be greater than 0"
+        );
+        assert_eq!(err("* * * jan *"), "month field: 'jan' is not a number");
+    }
+}
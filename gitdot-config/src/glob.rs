@@ -0,0 +1,75 @@
+/// Matches a `/`-separated name (a branch, tag, or file path) against a glob
+/// pattern: `*` matches within a single path segment, `**` matches across
+/// segments (`**/` may also match no segments at all), and `?` matches one
+/// non-`/` character.
+pub fn matches(pattern: &str, name: &str) -> bool {
+    match_bytes(pattern.as_bytes(), name.as_bytes())
+}
+
+fn match_bytes(pattern: &[u8], name: &[u8]) -> bool {
+    match pattern.split_first() {
+        None => name.is_empty(),
+        Some((b'*', rest)) if rest.first() == Some(&b'*') => {
+            let rest = &rest[1..];
+            if let Some(after_slash) = rest.strip_prefix(b"/")
+                && match_bytes(after_slash, name)
+            {
+                return true;
+            }
+            (0..=name.len()).any(|i| match_bytes(rest, &name[i..]))
+        }
+        Some((b'*', rest)) => {
+            let segment_len = name.iter().position(|&c| c == b'/').unwrap_or(name.len());
+            (0..=segment_len).any(|i| match_bytes(rest, &name[i..]))
+        }
+        Some((b'?', rest)) => match name.split_first() {
+            Some((&c, name_rest)) if c != b'/' => match_bytes(rest, name_rest),
+            _ => false,
+        },
+        Some((&p, rest)) => match name.split_first() {
+            Some((&c, name_rest)) if c == p => match_bytes(rest, name_rest),
+            _ => false,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::matches;
+
+    #[test]
+    fn literal_pattern_matches_exactly() {
+        assert!(matches("main", "main"));
+        assert!(!matches("main", "main2"));
+        assert!(!matches("main", "mai"));
+    }
+
+    #[test]
+    fn single_star_stays_within_segment() {
+        assert!(matches("release/*", "release/1.0"));
+        assert!(matches("feature-*", "feature-login"));
+        assert!(!matches("release/*", "release/1.0/hotfix"));
+        assert!(!matches("*", "a/b"));
+    }
+
+    #[test]
+    fn double_star_crosses_segments() {
+        assert!(matches("release/**", "release/1.0/hotfix"));
+        assert!(matches("**", "any/branch/name"));
+        assert!(matches("**/stable", "team/stable"));
+    }
+
+    #[test]
+    fn leading_double_star_matches_top_level_paths() {
+        assert!(matches("**/*.rs", "main.rs"));
+        assert!(matches("**/*.rs", "src/ci/main.rs"));
+        assert!(!matches("**/*.rs", "README.md"));
+    }
+
+    #[test]
+    fn question_mark_matches_one_char() {
+        assert!(matches("v?", "v1"));
+        assert!(!matches("v?", "v10"));
+        assert!(!matches("a?b", "a/b"));
+    }
+}
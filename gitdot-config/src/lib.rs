@@ -2,3 +2,6 @@ mod error;
 mod validate;
 
 pub mod ci;
+pub mod condition;
+pub mod cron;
+pub mod glob;
@@ -1,7 +1,16 @@
 use std::collections::{HashMap, HashSet};
 
 use super::error::CiConfigError;
-use crate::ci::CiConfig;
+use crate::{
+    ci::{BuildTrigger, CiConfig, parse_duration},
+    condition::Condition,
+    cron::CronSchedule,
+};
+
+const MAX_TIMEOUT_SECS: u64 = 24 * 60 * 60;
+const MAX_RETRIES: u32 = 10;
+const RESERVED_ENV_PREFIX: &str = "GITDOT_";
+const MAX_CACHE_KEY_LEN: usize = 128;
 
 pub fn validate_ci_config(config: &CiConfig) -> Result<(), CiConfigError> {
     let mut errors = Vec::new();
@@ -16,6 +25,17 @@ pub fn validate_ci_config(config: &CiConfig) -> Result<(), CiConfigError> {
     errors.extend(check_no_orphaned_tasks(config));
     errors.extend(check_waits_for_unknown_tasks(config));
     errors.extend(check_dag(config));
+    errors.extend(check_task_env(config));
+    errors.extend(check_task_timeouts(config));
+    errors.extend(check_task_retries(config));
+    errors.extend(check_working_directories(config));
+    errors.extend(check_task_artifacts(config));
+    errors.extend(check_task_caches(config));
+    errors.extend(check_task_images(config));
+    errors.extend(check_task_secrets(config));
+    errors.extend(check_task_conditions(config));
+    errors.extend(check_build_filters(config));
+    errors.extend(check_build_schedules(config));
 
     if errors.is_empty() {
         Ok(())
@@ -212,9 +232,262 @@ fn check_dag(config: &CiConfig) -> Vec<String> {
     errors
 }
 
+fn check_task_env(config: &CiConfig) -> Vec<String> {
+    let mut errors = Vec::new();
+    for task in &config.tasks {
+        for key in task.env.keys() {
+            if !is_env_name(key) {
+                errors.push(format!(
+                    "task '{}' env has invalid variable name '{key}'",
+                    task.name
+                ));
+            } else if key.starts_with(RESERVED_ENV_PREFIX) {
+                errors.push(format!(
+                    "task '{}' env cannot set reserved variable '{key}'",
+                    task.name
+                ));
+            }
+        }
+    }
+    errors
+}
+
+fn check_task_timeouts(config: &CiConfig) -> Vec<String> {
+    let mut errors = Vec::new();
+    for task in &config.tasks {
+        let Some(timeout) = &task.timeout else {
+            continue;
+        };
+        match parse_duration(timeout) {
+            Ok(duration) if duration.is_zero() => {
+                errors.push(format!(
+                    "task '{}' timeout must be greater than 0",
+                    task.name
+                ));
+            }
+            Ok(duration) if duration.as_secs() > MAX_TIMEOUT_SECS => {
+                errors.push(format!("task '{}' timeout cannot exceed 24h", task.name));
+            }
+            Ok(_) => {}
+            Err(e) => errors.push(format!("task '{}' has an invalid timeout: {e}", task.name)),
+        }
+    }
+    errors
+}
+
+fn check_task_retries(config: &CiConfig) -> Vec<String> {
+    config
+        .tasks
+        .iter()
+        .filter(|t| t.retries > MAX_RETRIES)
+        .map(|t| format!("task '{}' retries cannot exceed {MAX_RETRIES}", t.name))
+        .collect()
+}
+
+fn check_working_directories(config: &CiConfig) -> Vec<String> {
+    let mut errors = Vec::new();
+    for task in &config.tasks {
+        let Some(dir) = &task.working_directory else {
+            continue;
+        };
+        if !is_repository_path(dir) {
+            errors.push(format!(
+                "task '{}' working_directory '{dir}' must be a relative path inside the repository",
+                task.name
+            ));
+        }
+    }
+    errors
+}
+
+fn check_task_artifacts(config: &CiConfig) -> Vec<String> {
+    let mut errors = Vec::new();
+    for task in &config.tasks {
+        for path in &task.artifacts {
+            if !is_repository_path(path) {
+                errors.push(format!(
+                    "task '{}' artifact '{path}' must be a relative path inside the repository",
+                    task.name
+                ));
+            }
+        }
+    }
+    errors
+}
+
+fn check_task_caches(config: &CiConfig) -> Vec<String> {
+    let mut errors = Vec::new();
+    for task in &config.tasks {
+        let Some(cache) = &task.cache else {
+            continue;
+        };
+        let valid_key = !cache.key.is_empty()
+            && cache.key.len() <= MAX_CACHE_KEY_LEN
+            && cache
+                .key
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-'));
+        if !valid_key {
+            errors.push(format!(
+                "task '{}' cache key '{}' must be 1-{MAX_CACHE_KEY_LEN} letters, digits, '.', '_' or '-'",
+                task.name, cache.key
+            ));
+        }
+        if cache.paths.is_empty() {
+            errors.push(format!("task '{}' cache paths cannot be empty", task.name));
+        }
+        for path in &cache.paths {
+            if !is_repository_path(path) {
+                errors.push(format!(
+                    "task '{}' cache path '{path}' must be a relative path inside the repository",
+                    task.name
+                ));
+            }
+        }
+    }
+    errors
+}
+
+fn check_task_images(config: &CiConfig) -> Vec<String> {
+    let mut errors = Vec::new();
+    for task in &config.tasks {
+        if let Some(image) = &task.image
+            && (image.is_empty() || image.chars().any(char::is_whitespace))
+        {
+            errors.push(format!(
+                "task '{}' image '{image}' must be a container image reference",
+                task.name
+            ));
+        }
+    }
+    errors
+}
+
+fn check_task_secrets(config: &CiConfig) -> Vec<String> {
+    let mut errors = Vec::new();
+    for task in &config.tasks {
+        let mut seen = HashSet::new();
+        for name in &task.secrets {
+            if !is_env_name(name) || name.starts_with(RESERVED_ENV_PREFIX) {
+                errors.push(format!(
+                    "task '{}' secret '{name}' must be a valid variable name not starting with {RESERVED_ENV_PREFIX}",
+                    task.name
+                ));
+            } else if task.env.contains_key(name) {
+                errors.push(format!(
+                    "task '{}' secret '{name}' conflicts with an env variable",
+                    task.name
+                ));
+            } else if !seen.insert(name) {
+                errors.push(format!(
+                    "task '{}' has duplicate secret '{name}'",
+                    task.name
+                ));
+            }
+        }
+    }
+    errors
+}
+
+fn is_env_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn is_repository_path(path: &str) -> bool {
+    let escapes = path.split('/').any(|segment| segment == "..");
+    !path.trim().is_empty() && !path.starts_with('/') && !escapes
+}
+
+fn check_task_conditions(config: &CiConfig) -> Vec<String> {
+    let mut errors = Vec::new();
+    for task in &config.tasks {
+        if let Some(condition) = &task.condition
+            && let Err(e) = Condition::parse(condition)
+        {
+            errors.push(format!(
+                "task '{}' has an invalid if condition: {e}",
+                task.name
+            ));
+        }
+    }
+    errors
+}
+
+fn check_build_filters(config: &CiConfig) -> Vec<String> {
+    let mut errors = Vec::new();
+    for (i, build) in config.builds.iter().enumerate() {
+        let trigger = build.trigger.as_str();
+        let filters = [
+            ("branches", &build.branches),
+            ("tags", &build.tags),
+            ("paths", &build.paths),
+        ];
+        for (name, patterns) in filters {
+            let Some(patterns) = patterns else {
+                continue;
+            };
+            if patterns.is_empty() {
+                errors.push(format!("builds[{i}] has an empty {name} list"));
+            } else if patterns.iter().any(|p| p.trim().is_empty()) {
+                errors.push(format!("builds[{i}] has an empty pattern in {name}"));
+            }
+        }
+
+        let allows_branches = matches!(
+            build.trigger,
+            BuildTrigger::PullRequest | BuildTrigger::PushToMain
+        );
+        if build.branches.is_some() && !allows_branches {
+            errors.push(format!(
+                "builds[{i}] cannot filter on branches for trigger '{trigger}'"
+            ));
+        }
+        if build.tags.is_some() && build.trigger != BuildTrigger::TagPush {
+            errors.push(format!(
+                "builds[{i}] cannot filter on tags for trigger '{trigger}'"
+            ));
+        }
+        let allows_paths = !matches!(build.trigger, BuildTrigger::Manual | BuildTrigger::Schedule);
+        if build.paths.is_some() && !allows_paths {
+            errors.push(format!(
+                "builds[{i}] cannot filter on paths for trigger '{trigger}'"
+            ));
+        }
+    }
+    errors
+}
+
+fn check_build_schedules(config: &CiConfig) -> Vec<String> {
+    let mut errors = Vec::new();
+    for (i, build) in config.builds.iter().enumerate() {
+        match (&build.trigger, &build.schedule) {
+            (BuildTrigger::Schedule, None) => {
+                errors.push(format!(
+                    "builds[{i}] with trigger 'schedule' requires a schedule"
+                ));
+            }
+            (BuildTrigger::Schedule, Some(schedule)) => {
+                if let Err(e) = CronSchedule::parse(schedule) {
+                    errors.push(format!("builds[{i}] has an invalid schedule: {e}"));
+                }
+            }
+            (trigger, Some(_)) => errors.push(format!(
+                "builds[{i}] sets a schedule but its trigger is '{}'",
+                trigger.as_str()
+            )),
+            (_, None) => {}
+        }
+    }
+    errors
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::ci::CiConfig;
+    use crate::ci::{CiConfig, NetworkPolicy};
 
     fn valid_toml() -> &'static str {
         r#"
@@ -857,4 +1130,414 @@ mod tests {
             ],
         );
     }
+
+    // --- Task options ---
+
+    #[test]
+    fn valid_task_options() {
+        let toml = r#"
+            [[builds]]
+            trigger = "push_to_main"
+            tasks = ["test"]
+            paths = ["src/**", "Cargo.toml"]
+
+            [[builds]]
+            trigger = "schedule"
+            schedule = "0 3 * * *"
+            tasks = ["test"]
+
+            [[tasks]]
+            name = "test"
+            command = "cargo test"
+            env = { RUST_BACKTRACE = "1" }
+            timeout = "30m"
+            retries = 2
+            working_directory = "crates/core"
+            allow_failure = true
+            if = "trigger == 'push_to_main' || branch =~ 'release/*'"
+        "#;
+        let config = CiConfig::new(toml).unwrap();
+        let task = &config.tasks[0];
+        assert_eq!(task.env["RUST_BACKTRACE"], "1");
+        assert_eq!(task.timeout().map(|t| t.as_secs()), Some(1800));
+        assert_eq!(task.retries, 2);
+        assert!(task.allow_failure);
+    }
+
+    #[test]
+    fn invalid_env_names() {
+        let toml = r#"
+            [[builds]]
+            trigger = "pull_request"
+            tasks = ["test"]
+
+            [[tasks]]
+            name = "test"
+            command = "cargo test"
+            env = { "1BAD" = "x", GITDOT_TOKEN = "y" }
+        "#;
+        assert_validation_errors(
+            toml,
+            &[
+                "task 'test' env has invalid variable name '1BAD'",
+                "task 'test' env cannot set reserved variable 'GITDOT_TOKEN'",
+            ],
+        );
+    }
+
+    #[test]
+    fn invalid_timeouts() {
+        let toml = r#"
+            [[builds]]
+            trigger = "pull_request"
+            tasks = ["a", "b", "c"]
+
+            [[tasks]]
+            name = "a"
+            command = "echo a"
+            timeout = "10 minutes"
+
+            [[tasks]]
+            name = "b"
+            command = "echo b"
+            timeout = "0s"
+
+            [[tasks]]
+            name = "c"
+            command = "echo c"
+            timeout = "25h"
+        "#;
+        assert_validation_errors(
+            toml,
+            &[
+                "task 'a' has an invalid timeout: '10 minutes' must be a number followed by s, m or h",
+                "task 'b' timeout must be greater than 0",
+                "task 'c' timeout cannot exceed 24h",
+            ],
+        );
+    }
+
+    #[test]
+    fn too_many_retries() {
+        let toml = r#"
+            [[builds]]
+            trigger = "pull_request"
+            tasks = ["test"]
+
+            [[tasks]]
+            name = "test"
+            command = "cargo test"
+            retries = 11
+        "#;
+        assert_validation_errors(toml, &["task 'test' retries cannot exceed 10"]);
+    }
+
+    #[test]
+    fn working_directory_outside_repository() {
+        let toml = r#"
+            [[builds]]
+            trigger = "pull_request"
+            tasks = ["a", "b"]
+
+            [[tasks]]
+            name = "a"
+            command = "echo a"
+            working_directory = "/etc"
+
+            [[tasks]]
+            name = "b"
+            command = "echo b"
+            working_directory = "src/../../secrets"
+        "#;
+        assert_validation_errors(
+            toml,
+            &[
+                "task 'a' working_directory '/etc' must be a relative path inside the repository",
+                "task 'b' working_directory 'src/../../secrets' must be a relative path inside the repository",
+            ],
+        );
+    }
+
+    #[test]
+    fn valid_artifacts_and_cache() {
+        let toml = r#"
+            [[builds]]
+            trigger = "pull_request"
+            tasks = ["build", "test"]
+
+            [[tasks]]
+            name = "build"
+            command = "cargo build --release"
+            artifacts = ["target/release/gitdot"]
+            cache = { key = "cargo-v1", paths = ["target", ".cargo/registry"] }
+
+            [[tasks]]
+            name = "test"
+            command = "./target/release/gitdot --version"
+            waits_for = ["build"]
+        "#;
+        let config = CiConfig::new(toml).unwrap();
+        let task = &config.tasks[0];
+        assert_eq!(task.artifacts, ["target/release/gitdot"]);
+        let cache = task.cache.as_ref().unwrap();
+        assert_eq!(cache.key, "cargo-v1");
+        assert_eq!(cache.paths, ["target", ".cargo/registry"]);
+    }
+
+    #[test]
+    fn artifacts_outside_repository() {
+        let toml = r#"
+            [[builds]]
+            trigger = "pull_request"
+            tasks = ["build"]
+
+            [[tasks]]
+            name = "build"
+            command = "make"
+            artifacts = ["dist", "/etc/passwd", "../out"]
+        "#;
+        assert_validation_errors(
+            toml,
+            &[
+                "task 'build' artifact '/etc/passwd' must be a relative path inside the repository",
+                "task 'build' artifact '../out' must be a relative path inside the repository",
+            ],
+        );
+    }
+
+    #[test]
+    fn invalid_cache() {
+        let toml = r#"
+            [[builds]]
+            trigger = "pull_request"
+            tasks = ["a", "b"]
+
+            [[tasks]]
+            name = "a"
+            command = "echo a"
+            cache = { key = "deps/v1", paths = ["node_modules", "/tmp"] }
+
+            [[tasks]]
+            name = "b"
+            command = "echo b"
+            cache = { key = "deps", paths = [] }
+        "#;
+        assert_validation_errors(
+            toml,
+            &[
+                "task 'a' cache key 'deps/v1' must be 1-128 letters, digits, '.', '_' or '-'",
+                "task 'a' cache path '/tmp' must be a relative path inside the repository",
+                "task 'b' cache paths cannot be empty",
+            ],
+        );
+    }
+
+    #[test]
+    fn valid_image_and_network() {
+        let toml = r#"
+            [[builds]]
+            trigger = "pull_request"
+            tasks = ["lint", "test"]
+
+            [[tasks]]
+            name = "lint"
+            command = "cargo clippy"
+
+            [[tasks]]
+            name = "test"
+            command = "cargo test"
+            image = "docker.io/library/rust:1.90"
+            network = "outbound"
+        "#;
+        let config = CiConfig::new(toml).unwrap();
+        assert_eq!(config.tasks[0].image, None);
+        assert_eq!(config.tasks[0].network, NetworkPolicy::None);
+        assert_eq!(
+            config.tasks[1].image.as_deref(),
+            Some("docker.io/library/rust:1.90")
+        );
+        assert_eq!(config.tasks[1].network, NetworkPolicy::Outbound);
+    }
+
+    #[test]
+    fn invalid_image() {
+        let toml = r#"
+            [[builds]]
+            trigger = "pull_request"
+            tasks = ["a", "b"]
+
+            [[tasks]]
+            name = "a"
+            command = "echo a"
+            image = ""
+
+            [[tasks]]
+            name = "b"
+            command = "echo b"
+            image = "rust latest"
+        "#;
+        assert_validation_errors(
+            toml,
+            &[
+                "task 'a' image '' must be a container image reference",
+                "task 'b' image 'rust latest' must be a container image reference",
+            ],
+        );
+    }
+
+    #[test]
+    fn valid_secrets() {
+        let toml = r#"
+            [[builds]]
+            trigger = "push_to_main"
+            tasks = ["deploy"]
+
+            [[tasks]]
+            name = "deploy"
+            command = "./deploy.sh"
+            secrets = ["DEPLOY_TOKEN", "AWS_SECRET_ACCESS_KEY"]
+        "#;
+        let config = CiConfig::new(toml).unwrap();
+        assert_eq!(
+            config.tasks[0].secrets,
+            vec!["DEPLOY_TOKEN", "AWS_SECRET_ACCESS_KEY"]
+        );
+    }
+
+    #[test]
+    fn invalid_secrets() {
+        let toml = r#"
+            [[builds]]
+            trigger = "push_to_main"
+            tasks = ["deploy"]
+
+            [[tasks]]
+            name = "deploy"
+            command = "./deploy.sh"
+            env = { TOKEN = "x" }
+            secrets = ["1TOKEN", "GITDOT_TOKEN", "TOKEN", "KEY", "KEY"]
+        "#;
+        assert_validation_errors(
+            toml,
+            &[
+                "task 'deploy' secret '1TOKEN' must be a valid variable name not starting with GITDOT_",
+                "task 'deploy' secret 'GITDOT_TOKEN' must be a valid variable name not starting with GITDOT_",
+                "task 'deploy' secret 'TOKEN' conflicts with an env variable",
+                "task 'deploy' has duplicate secret 'KEY'",
+            ],
+        );
+    }
+
+    #[test]
+    fn invalid_if_condition() {
+        let toml = r#"
+            [[builds]]
+            trigger = "pull_request"
+            tasks = ["test"]
+
+            [[tasks]]
+            name = "test"
+            command = "cargo test"
+            if = "commit == 'abc'"
+        "#;
+        assert_validation_errors(
+            toml,
+            &["task 'test' has an invalid if condition: unknown variable 'commit'"],
+        );
+    }
+
+    // --- Build filters and triggers ---
+
+    #[test]
+    fn filters_not_supported_by_trigger() {
+        let toml = r#"
+            [[builds]]
+            trigger = "tag_push"
+            branches = ["main"]
+            tasks = ["test"]
+
+            [[builds]]
+            trigger = "manual"
+            tags = ["v*"]
+            paths = ["src/**"]
+            tasks = ["test"]
+
+            [[tasks]]
+            name = "test"
+            command = "cargo test"
+        "#;
+        assert_validation_errors(
+            toml,
+            &[
+                "builds[0] cannot filter on branches for trigger 'tag_push'",
+                "builds[1] cannot filter on tags for trigger 'manual'",
+                "builds[1] cannot filter on paths for trigger 'manual'",
+            ],
+        );
+    }
+
+    #[test]
+    fn empty_filter_lists() {
+        let toml = r#"
+            [[builds]]
+            trigger = "pull_request"
+            branches = []
+            paths = [""]
+            tasks = ["test"]
+
+            [[tasks]]
+            name = "test"
+            command = "cargo test"
+        "#;
+        assert_validation_errors(
+            toml,
+            &[
+                "builds[0] has an empty branches list",
+                "builds[0] has an empty pattern in paths",
+            ],
+        );
+    }
+
+    #[test]
+    fn schedule_required_for_schedule_trigger_only() {
+        let toml = r#"
+            [[builds]]
+            trigger = "schedule"
+            tasks = ["test"]
+
+            [[builds]]
+            trigger = "push_to_main"
+            schedule = "0 * * * *"
+            tasks = ["test"]
+
+            [[tasks]]
+            name = "test"
+            command = "cargo test"
+        "#;
+        assert_validation_errors(
+            toml,
+            &[
+                "builds[0] with trigger 'schedule' requires a schedule",
+                "builds[1] sets a schedule but its trigger is 'push_to_main'",
+            ],
+        );
+    }
+
+    #[test]
+    fn invalid_schedule() {
+        let toml = r#"
+            [[builds]]
+            trigger = "schedule"
+            schedule = "0 25 * * *"
+            tasks = ["test"]
+
+            [[tasks]]
+            name = "test"
+            command = "cargo test"
+        "#;
+        assert_validation_errors(
+            toml,
+            &["builds[0] has an invalid schedule: hour field: '25' is outside 0-23"],
+        );
+    }
 }
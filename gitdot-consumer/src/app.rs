@@ -12,6 +12,10 @@ pub use settings::Settings;
 pub use state::{ConsumerHandle, ConsumerState};
 
 const REPO_PUSHED_TOPIC: &str = "gitdot.repo.pushed";
+const REVIEW_EVENTS_TOPIC: &str = "gitdot.review.events";
+const TAG_EVENTS_TOPIC: &str = "gitdot.tag.events";
+
+const TOPICS: [&str; 3] = [REPO_PUSHED_TOPIC, REVIEW_EVENTS_TOPIC, TAG_EVENTS_TOPIC];
 
 pub struct GitdotConsumer {
     state: ConsumerState,
@@ -27,12 +31,8 @@ impl GitdotConsumer {
         let state = ConsumerState::new(settings, pool).await?;
         let kafka = state::build_consumer(&state.settings).await?;
         match &kafka {
-            ConsumerHandle::Plain(c) => c
-                .subscribe(&[REPO_PUSHED_TOPIC])
-                .context("subscribe to topic")?,
-            ConsumerHandle::Gcp(c) => c
-                .subscribe(&[REPO_PUSHED_TOPIC])
-                .context("subscribe to topic")?,
+            ConsumerHandle::Plain(c) => c.subscribe(&TOPICS).context("subscribe to topics")?,
+            ConsumerHandle::Gcp(c) => c.subscribe(&TOPICS).context("subscribe to topics")?,
         }
 
         Ok(Self { state, kafka })
@@ -40,7 +40,7 @@ impl GitdotConsumer {
 
     pub async fn run(self) -> anyhow::Result<()> {
         tracing::info!(
-            topic = REPO_PUSHED_TOPIC,
+            topics = ?TOPICS,
             group_id = %self.state.settings.kafka_consumer_group_id,
             "starting consumer",
         );
@@ -7,11 +7,14 @@ use rdkafka::{
 };
 
 use gitdot_core::{
-    dto::{ListSlackWebhooksRequest, NotifyRepoPushRequest, RepoPushEvent},
+    dto::{
+        DeliverWebhookEventRequest, ListSlackWebhooksRequest, NotifyRepoPushRequest,
+        NotifyReviewEventRequest, RepoPushEvent, ReviewEvent, TagEvent,
+    },
     model::WebhookEventType,
 };
 
-use super::ConsumerState;
+use super::{ConsumerState, REPO_PUSHED_TOPIC, REVIEW_EVENTS_TOPIC, TAG_EVENTS_TOPIC};
 
 pub async fn run<C>(state: ConsumerState, kafka: StreamConsumer<C>) -> anyhow::Result<()>
 where
@@ -56,9 +59,51 @@ async fn handle_message(state: &ConsumerState, msg: &BorrowedMessage<'_>) -> any
         .payload()
         .ok_or_else(|| anyhow::anyhow!("message has no payload"))?;
 
-    let event: RepoPushEvent =
-        serde_json::from_slice(payload).context("deserialize RepoPushEvent")?;
+    match msg.topic() {
+        REPO_PUSHED_TOPIC => {
+            let event: RepoPushEvent =
+                serde_json::from_slice(payload).context("deserialize RepoPushEvent")?;
 
+            notify_slack_of_repo_push(state, &event).await?;
+            deliver_webhooks(
+                state,
+                &event.owner,
+                &event.repo,
+                WebhookEventType::Push,
+                &event,
+            )
+            .await;
+        }
+        REVIEW_EVENTS_TOPIC => {
+            let event: ReviewEvent =
+                serde_json::from_slice(payload).context("deserialize ReviewEvent")?;
+
+            notify_slack_of_review_event(state, &event).await?;
+            deliver_webhooks(state, &event.owner, &event.repo, event.event_type(), &event).await;
+        }
+        TAG_EVENTS_TOPIC => {
+            let event: TagEvent =
+                serde_json::from_slice(payload).context("deserialize TagEvent")?;
+
+            deliver_webhooks(
+                state,
+                &event.owner,
+                &event.repo,
+                WebhookEventType::Tag,
+                &event,
+            )
+            .await;
+        }
+        topic => tracing::warn!(topic, "message from unexpected topic; skipping"),
+    }
+
+    Ok(())
+}
+
+async fn notify_slack_of_repo_push(
+    state: &ConsumerState,
+    event: &RepoPushEvent,
+) -> anyhow::Result<()> {
     let list_request =
         ListSlackWebhooksRequest::new(&event.owner, &event.repo, WebhookEventType::Push)?;
     let subscriptions = state
@@ -110,6 +155,92 @@ async fn handle_message(state: &ConsumerState, msg: &BorrowedMessage<'_>) -> any
     Ok(())
 }
 
+async fn notify_slack_of_review_event(
+    state: &ConsumerState,
+    event: &ReviewEvent,
+) -> anyhow::Result<()> {
+    let list_request =
+        ListSlackWebhooksRequest::new(&event.owner, &event.repo, event.event_type())?;
+    let subscriptions = state
+        .slack_webhook_service
+        .list_slack_webhooks(list_request)
+        .await?;
+
+    if subscriptions.is_empty() {
+        return Ok(());
+    }
+
+    tracing::info!(
+        owner = %event.owner,
+        repo = %event.repo,
+        number = event.number,
+        subscribers = subscriptions.len(),
+        "fanning out review notification",
+    );
+
+    for sub in subscriptions {
+        let channel_id = sub.slack_channel_id.clone();
+        let body = NotifyReviewEventRequest {
+            channel_id: sub.slack_channel_id,
+            owner: event.owner.clone(),
+            repo: event.repo.clone(),
+            number: event.number,
+            title: event.title.clone(),
+            actor_name: event.actor_name.clone(),
+            action: event.action.clone(),
+        };
+        if let Err(e) = state
+            .slack_webhook_service
+            .notify_slack_of_review_event(body)
+            .await
+        {
+            tracing::error!(
+                ?e,
+                channel_id = %channel_id,
+                "failed to notify slack channel; aborting batch",
+            );
+            return Err(e.into());
+        }
+    }
+
+    Ok(())
+}
+
+/// HTTP webhook failures are recorded in the delivery log and can be redelivered
+/// from there, so they never hold back the offset commit.
+async fn deliver_webhooks<T: serde::Serialize>(
+    state: &ConsumerState,
+    owner: &str,
+    repo: &str,
+    event_type: WebhookEventType,
+    event: &T,
+) {
+    let payload = match serde_json::to_value(event) {
+        Ok(payload) => payload,
+        Err(e) => {
+            tracing::error!(?e, "failed to serialize event for webhooks");
+            return;
+        }
+    };
+    let request = match DeliverWebhookEventRequest::new(owner, repo, event_type, payload) {
+        Ok(request) => request,
+        Err(e) => {
+            tracing::error!(?e, "invalid webhook delivery request");
+            return;
+        }
+    };
+
+    if let Err(e) = state.webhook_service.deliver_webhook_event(request).await {
+        tracing::error!(
+            ?e,
+            owner,
+            repo,
+            event = event_type.as_str(),
+            "failed to deliver webhooks",
+        );
+    }
+}
+
 async fn shutdown_signal() {
     use tokio::signal::unix::{SignalKind, signal};
 
@@ -6,9 +6,9 @@ use secrecy::ExposeSecret;
 use sqlx::PgPool;
 
 use gitdot_core::{
-    client::{GcpKafkaContext, KafkaAuthMode, SlackBotClientImpl},
-    repository::{PgRepositoryRepository, PgSlackWebhookRepository},
-    service::{SlackWebhookService, SlackWebhookServiceImpl},
+    client::{GcpKafkaContext, KafkaAuthMode, SlackBotClientImpl, WebhookClientImpl},
+    repository::{PgRepositoryRepository, PgSlackWebhookRepository, PgWebhookRepository},
+    service::{SlackWebhookService, SlackWebhookServiceImpl, WebhookService, WebhookServiceImpl},
 };
 
 use super::Settings;
@@ -17,11 +17,13 @@ use super::Settings;
 pub struct ConsumerState {
     pub settings: Settings,
     pub slack_webhook_service: Arc<dyn SlackWebhookService>,
+    pub webhook_service: Arc<dyn WebhookService>,
 }
 
 impl ConsumerState {
     pub async fn new(settings: Settings, pool: PgPool) -> anyhow::Result<Self> {
         let slack_webhook_repo = PgSlackWebhookRepository::new(pool.clone());
+        let webhook_repo = PgWebhookRepository::new(pool.clone());
         let repo_repo = PgRepositoryRepository::new(pool.clone());
 
         let slack_bot_client = SlackBotClientImpl::new(
@@ -31,13 +33,20 @@ impl ConsumerState {
 
         let slack_webhook_service = Arc::new(SlackWebhookServiceImpl::new(
             slack_webhook_repo,
-            repo_repo,
+            repo_repo.clone(),
             slack_bot_client,
         ));
 
+        let webhook_service = Arc::new(WebhookServiceImpl::new(
+            webhook_repo,
+            repo_repo,
+            WebhookClientImpl::new(),
+        ));
+
         Ok(Self {
             settings,
             slack_webhook_service,
+            webhook_service,
         })
     }
 }
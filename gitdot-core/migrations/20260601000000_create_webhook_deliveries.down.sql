@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS webhook.webhook_deliveries;
+DROP TYPE IF EXISTS webhook.webhook_delivery_status;
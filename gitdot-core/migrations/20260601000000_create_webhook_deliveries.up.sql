@@ -0,0 +1,23 @@
+CREATE TYPE webhook.webhook_delivery_status AS ENUM ('pending', 'success', 'failure');
+
+-- One row per delivery of an event to a webhook. Retries of the same delivery
+-- update the row in place (`attempts`, last response); a manual redelivery
+-- inserts a new row pointing back at the original via `redelivery_of`.
+CREATE TABLE webhook.webhook_deliveries (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    webhook_id UUID NOT NULL REFERENCES webhook.webhooks(id) ON DELETE CASCADE,
+    event webhook.webhook_event_type NOT NULL,
+    payload JSONB NOT NULL,
+    status webhook.webhook_delivery_status NOT NULL DEFAULT 'pending',
+    attempts INTEGER NOT NULL DEFAULT 0,
+    status_code INTEGER,
+    duration_ms INTEGER,
+    response_excerpt TEXT,
+    error TEXT,
+    redelivery_of UUID REFERENCES webhook.webhook_deliveries(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    delivered_at TIMESTAMPTZ
+);
+
+CREATE INDEX idx_webhook_deliveries_webhook_id
+    ON webhook.webhook_deliveries (webhook_id, created_at DESC, id DESC);
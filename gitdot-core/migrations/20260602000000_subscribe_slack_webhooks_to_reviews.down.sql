@@ -0,0 +1,2 @@
+UPDATE webhook.slack_webhooks
+SET events = array_remove(array_remove(events, 'review_publish'), 'review_update');
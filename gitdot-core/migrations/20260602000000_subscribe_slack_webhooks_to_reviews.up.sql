@@ -0,0 +1,6 @@
+UPDATE webhook.slack_webhooks
+SET events = ARRAY(
+    SELECT DISTINCT unnest(
+        events || ARRAY['review_publish', 'review_update']::webhook.webhook_event_type[]
+    )
+);
@@ -0,0 +1,14 @@
+CREATE TABLE core.branch_protections (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    repository_id UUID NOT NULL REFERENCES core.repositories(id) ON DELETE CASCADE,
+    pattern VARCHAR(255) NOT NULL,
+    block_force_push BOOLEAN NOT NULL DEFAULT TRUE,
+    block_deletion BOOLEAN NOT NULL DEFAULT TRUE,
+    require_review BOOLEAN NOT NULL DEFAULT FALSE,
+    require_green_build BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(repository_id, pattern)
+);
+
+CREATE INDEX idx_branch_protections_repository_id ON core.branch_protections (repository_id);
@@ -0,0 +1 @@
+DROP TABLE core.review_merge_requirements;
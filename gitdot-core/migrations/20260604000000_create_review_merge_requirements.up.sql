@@ -0,0 +1,9 @@
+CREATE TABLE core.review_merge_requirements (
+    repository_id UUID PRIMARY KEY REFERENCES core.repositories(id) ON DELETE CASCADE,
+    required_approvals INTEGER NOT NULL DEFAULT 0 CHECK (required_approvals >= 0),
+    block_on_rejection BOOLEAN NOT NULL DEFAULT FALSE,
+    require_resolved_comments BOOLEAN NOT NULL DEFAULT FALSE,
+    require_green_build BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
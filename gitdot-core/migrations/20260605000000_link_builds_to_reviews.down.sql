@@ -0,0 +1,6 @@
+DROP INDEX ci.idx_builds_revision_id;
+DROP INDEX ci.idx_builds_review_id;
+
+ALTER TABLE ci.builds DROP COLUMN revision_id;
+ALTER TABLE ci.builds DROP COLUMN diff_id;
+ALTER TABLE ci.builds DROP COLUMN review_id;
@@ -0,0 +1,8 @@
+ALTER TABLE ci.builds ADD COLUMN review_id UUID REFERENCES core.reviews(id) ON DELETE SET NULL;
+ALTER TABLE ci.builds ADD COLUMN diff_id UUID REFERENCES core.diffs(id) ON DELETE SET NULL;
+ALTER TABLE ci.builds ADD COLUMN revision_id UUID REFERENCES core.revisions(id) ON DELETE SET NULL;
+
+-- Supports listing a review's builds and looking up the latest build of a
+-- revision when deduplicating review-triggered builds.
+CREATE INDEX idx_builds_review_id ON ci.builds (review_id);
+CREATE INDEX idx_builds_revision_id ON ci.builds (revision_id);
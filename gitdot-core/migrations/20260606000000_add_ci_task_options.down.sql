@@ -0,0 +1,9 @@
+ALTER TABLE ci.tasks DROP COLUMN allow_failure;
+ALTER TABLE ci.tasks DROP COLUMN working_directory;
+ALTER TABLE ci.tasks DROP COLUMN retries;
+ALTER TABLE ci.tasks DROP COLUMN timeout_seconds;
+ALTER TABLE ci.tasks DROP COLUMN env;
+
+-- Postgres cannot drop enum values; builds with the new triggers are removed
+-- so the remaining rows stay valid for the previous code.
+DELETE FROM ci.builds WHERE trigger IN ('tag_push', 'manual', 'schedule');
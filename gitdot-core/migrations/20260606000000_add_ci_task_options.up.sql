@@ -0,0 +1,9 @@
+ALTER TYPE ci.build_trigger ADD VALUE 'tag_push';
+ALTER TYPE ci.build_trigger ADD VALUE 'manual';
+ALTER TYPE ci.build_trigger ADD VALUE 'schedule';
+
+ALTER TABLE ci.tasks ADD COLUMN env JSONB NOT NULL DEFAULT '{}'::jsonb;
+ALTER TABLE ci.tasks ADD COLUMN timeout_seconds INTEGER;
+ALTER TABLE ci.tasks ADD COLUMN retries INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE ci.tasks ADD COLUMN working_directory TEXT;
+ALTER TABLE ci.tasks ADD COLUMN allow_failure BOOLEAN NOT NULL DEFAULT FALSE;
@@ -0,0 +1,7 @@
+DROP INDEX IF EXISTS ci.idx_tasks_active;
+
+ALTER TABLE ci.tasks DROP COLUMN attempt;
+
+-- Postgres cannot drop enum values; cancelled and timed-out tasks are recorded
+-- as failures so the remaining rows stay valid for the previous code.
+UPDATE ci.tasks SET status = 'failure' WHERE status IN ('cancelled', 'timed_out');
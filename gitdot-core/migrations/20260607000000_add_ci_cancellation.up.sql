@@ -0,0 +1,10 @@
+-- 'blocked' was dropped when the CI types were recreated but is still written
+-- for tasks with dependencies.
+ALTER TYPE ci.task_status ADD VALUE IF NOT EXISTS 'blocked' BEFORE 'pending';
+ALTER TYPE ci.task_status ADD VALUE 'cancelled';
+ALTER TYPE ci.task_status ADD VALUE 'timed_out';
+ALTER TYPE ci.build_status ADD VALUE 'cancelled';
+
+ALTER TABLE ci.tasks ADD COLUMN attempt INTEGER NOT NULL DEFAULT 1;
+
+CREATE INDEX idx_tasks_active ON ci.tasks (updated_at) WHERE status IN ('assigned', 'running');
@@ -0,0 +1,6 @@
+DROP TABLE ci.caches;
+DROP TABLE ci.artifacts;
+
+ALTER TABLE ci.tasks DROP COLUMN cache_paths;
+ALTER TABLE ci.tasks DROP COLUMN cache_key;
+ALTER TABLE ci.tasks DROP COLUMN artifacts;
@@ -0,0 +1,29 @@
+ALTER TABLE ci.tasks ADD COLUMN artifacts TEXT[] NOT NULL DEFAULT '{}';
+ALTER TABLE ci.tasks ADD COLUMN cache_key TEXT;
+ALTER TABLE ci.tasks ADD COLUMN cache_paths TEXT[] NOT NULL DEFAULT '{}';
+
+CREATE TABLE ci.artifacts (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    repository_id UUID NOT NULL REFERENCES core.repositories(id) ON DELETE CASCADE,
+    build_id UUID NOT NULL REFERENCES ci.builds(id) ON DELETE CASCADE,
+    task_id UUID NOT NULL REFERENCES ci.tasks(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    object_key TEXT NOT NULL,
+    size_bytes BIGINT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(task_id, name)
+);
+
+CREATE INDEX idx_artifacts_build_id ON ci.artifacts (build_id);
+
+-- `scope` keeps caches saved by review builds apart from those restored
+-- into trusted builds.
+CREATE TABLE ci.caches (
+    repository_id UUID NOT NULL REFERENCES core.repositories(id) ON DELETE CASCADE,
+    scope TEXT NOT NULL,
+    key TEXT NOT NULL,
+    object_key TEXT NOT NULL,
+    size_bytes BIGINT NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (repository_id, scope, key)
+);
@@ -0,0 +1,4 @@
+ALTER TABLE ci.tasks DROP COLUMN network;
+ALTER TABLE ci.tasks DROP COLUMN image;
+
+DROP TYPE ci.task_network;
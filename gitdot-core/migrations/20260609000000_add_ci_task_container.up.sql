@@ -0,0 +1,4 @@
+CREATE TYPE ci.task_network AS ENUM ('none', 'outbound');
+
+ALTER TABLE ci.tasks ADD COLUMN image TEXT;
+ALTER TABLE ci.tasks ADD COLUMN network ci.task_network NOT NULL DEFAULT 'none';
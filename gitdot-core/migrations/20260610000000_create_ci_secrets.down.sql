@@ -0,0 +1,4 @@
+DROP TABLE ci.organization_secrets;
+DROP TABLE ci.repository_secrets;
+
+ALTER TABLE ci.tasks DROP COLUMN secrets;
@@ -0,0 +1,19 @@
+ALTER TABLE ci.tasks ADD COLUMN secrets TEXT[] NOT NULL DEFAULT '{}';
+
+CREATE TABLE ci.repository_secrets (
+    repository_id UUID NOT NULL REFERENCES core.repositories(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    ciphertext BYTEA NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (repository_id, name)
+);
+
+CREATE TABLE ci.organization_secrets (
+    organization_id UUID NOT NULL REFERENCES core.organizations(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    ciphertext BYTEA NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (organization_id, name)
+);
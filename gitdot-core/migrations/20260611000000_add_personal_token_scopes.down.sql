@@ -0,0 +1,5 @@
+ALTER TABLE auth.tokens DROP COLUMN expires_at;
+ALTER TABLE auth.tokens DROP COLUMN scopes;
+ALTER TABLE auth.tokens DROP COLUMN name;
+
+DROP TYPE auth.token_scope;
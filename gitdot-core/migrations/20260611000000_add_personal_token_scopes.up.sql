@@ -0,0 +1,10 @@
+CREATE TYPE auth.token_scope AS ENUM ('repo:read', 'repo:write', 'review:write', 'runner:admin', 'admin:org');
+
+ALTER TABLE auth.tokens ADD COLUMN name VARCHAR(64) NOT NULL DEFAULT '';
+ALTER TABLE auth.tokens ADD COLUMN scopes auth.token_scope[] NOT NULL DEFAULT '{}';
+ALTER TABLE auth.tokens ADD COLUMN expires_at TIMESTAMPTZ;
+
+-- tokens minted by the device flow so far keep the power they were issued with
+UPDATE auth.tokens
+SET name = client_id, scopes = '{repo:read, repo:write, review:write, runner:admin, admin:org}'
+WHERE token_type = 'personal';
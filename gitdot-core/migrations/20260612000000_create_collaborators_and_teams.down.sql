@@ -0,0 +1,6 @@
+DROP TABLE core.team_repositories;
+DROP TABLE core.team_members;
+DROP TABLE core.teams;
+DROP TABLE core.repository_invitations;
+DROP TABLE core.repository_collaborators;
+DROP TYPE core.repository_role;
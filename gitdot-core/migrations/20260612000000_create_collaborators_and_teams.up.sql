@@ -0,0 +1,68 @@
+CREATE TYPE core.repository_role AS ENUM ('read', 'triage', 'write', 'maintain', 'admin');
+
+CREATE TABLE core.repository_collaborators (
+    repository_id UUID NOT NULL REFERENCES core.repositories(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES core.users(id) ON DELETE CASCADE,
+    role core.repository_role NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (repository_id, user_id)
+);
+
+CREATE INDEX idx_repository_collaborators_user_id ON core.repository_collaborators (user_id);
+
+CREATE TABLE core.repository_invitations (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    repository_id UUID NOT NULL REFERENCES core.repositories(id) ON DELETE CASCADE,
+    invitee_id UUID NOT NULL REFERENCES core.users(id) ON DELETE CASCADE,
+    inviter_id UUID NOT NULL REFERENCES core.users(id) ON DELETE CASCADE,
+    role core.repository_role NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(repository_id, invitee_id)
+);
+
+CREATE INDEX idx_repository_invitations_invitee_id ON core.repository_invitations (invitee_id);
+
+CREATE TABLE core.teams (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    organization_id UUID NOT NULL REFERENCES core.organizations(id) ON DELETE CASCADE,
+    name VARCHAR(100) NOT NULL,
+    description TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(organization_id, name)
+);
+
+CREATE TABLE core.team_members (
+    team_id UUID NOT NULL REFERENCES core.teams(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES core.users(id) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (team_id, user_id)
+);
+
+CREATE INDEX idx_team_members_user_id ON core.team_members (user_id);
+
+CREATE TABLE core.team_repositories (
+    team_id UUID NOT NULL REFERENCES core.teams(id) ON DELETE CASCADE,
+    repository_id UUID NOT NULL REFERENCES core.repositories(id) ON DELETE CASCADE,
+    role core.repository_role NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (team_id, repository_id)
+);
+
+CREATE INDEX idx_team_repositories_repository_id ON core.team_repositories (repository_id);
+
+-- Org members used to have write access to every org repository. Keep that
+-- access through a "members" team so nobody loses it when this ships.
+INSERT INTO core.teams (organization_id, name, description)
+SELECT id, 'members', 'All members at the time teams were introduced'
+FROM core.organizations;
+
+INSERT INTO core.team_members (team_id, user_id)
+SELECT t.id, m.user_id
+FROM core.teams t
+JOIN core.organization_members m ON m.organization_id = t.organization_id
+WHERE m.role = 'member';
+
+INSERT INTO core.team_repositories (team_id, repository_id, role)
+SELECT t.id, r.id, 'write'
+FROM core.teams t
+JOIN core.repositories r ON r.owner_id = t.organization_id AND r.owner_type = 'organization';
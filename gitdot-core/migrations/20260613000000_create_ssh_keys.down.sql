@@ -0,0 +1 @@
+DROP TABLE IF EXISTS auth.ssh_keys CASCADE;
@@ -0,0 +1,2 @@
+DROP TABLE core.repository_maintenance;
+DROP TYPE core.maintenance_status;
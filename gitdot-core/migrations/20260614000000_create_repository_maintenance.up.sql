@@ -0,0 +1,12 @@
+CREATE TYPE core.maintenance_status AS ENUM ('success', 'failure');
+
+CREATE TABLE core.repository_maintenance (
+    repository_id UUID PRIMARY KEY REFERENCES core.repositories(id) ON DELETE CASCADE,
+    pending_pushes INTEGER NOT NULL DEFAULT 0,
+    last_pushed_at TIMESTAMPTZ,
+    requested_at TIMESTAMPTZ,
+    started_at TIMESTAMPTZ,
+    finished_at TIMESTAMPTZ,
+    last_status core.maintenance_status,
+    last_error TEXT
+);
@@ -0,0 +1,2 @@
+DROP TABLE core.release_assets;
+DROP TABLE core.releases;
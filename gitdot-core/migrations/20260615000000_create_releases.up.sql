@@ -0,0 +1,28 @@
+ALTER TYPE webhook.webhook_event_type ADD VALUE IF NOT EXISTS 'tag';
+
+CREATE TABLE core.releases (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    repository_id UUID NOT NULL REFERENCES core.repositories(id) ON DELETE CASCADE,
+    tag_name TEXT NOT NULL,
+    name TEXT NOT NULL,
+    body TEXT NOT NULL DEFAULT '',
+    draft BOOLEAN NOT NULL DEFAULT FALSE,
+    prerelease BOOLEAN NOT NULL DEFAULT FALSE,
+    author_id UUID REFERENCES core.users(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    published_at TIMESTAMPTZ,
+    UNIQUE(repository_id, tag_name)
+);
+
+CREATE TABLE core.release_assets (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    release_id UUID NOT NULL REFERENCES core.releases(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    content_type TEXT NOT NULL,
+    object_key TEXT NOT NULL,
+    size_bytes BIGINT NOT NULL,
+    uploader_id UUID REFERENCES core.users(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(release_id, name)
+);
@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS migration.idx_migration_repositories_destination_repository_id;
+DROP TABLE IF EXISTS migration.mirror_syncs;
+DROP TYPE IF EXISTS migration.mirror_sync_status;
@@ -0,0 +1,15 @@
+CREATE TYPE migration.mirror_sync_status AS ENUM ('synced', 'failed');
+
+CREATE TABLE migration.mirror_syncs (
+    migration_repository_id UUID PRIMARY KEY
+        REFERENCES migration.migration_repositories(id) ON DELETE CASCADE,
+    status migration.mirror_sync_status NOT NULL,
+    error TEXT,
+    last_attempted_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    last_synced_at TIMESTAMPTZ
+);
+
+CREATE INDEX idx_mirror_syncs_last_attempted_at ON migration.mirror_syncs (last_attempted_at);
+
+CREATE INDEX idx_migration_repositories_destination_repository_id
+    ON migration.migration_repositories (destination_repository_id);
@@ -0,0 +1,4 @@
+DROP TABLE IF EXISTS core.repository_mirror_runs;
+DROP TABLE IF EXISTS core.repository_mirrors;
+DROP TYPE IF EXISTS core.mirror_run_status;
+DROP TYPE IF EXISTS core.mirror_direction;
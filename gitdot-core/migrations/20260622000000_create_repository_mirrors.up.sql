@@ -0,0 +1,40 @@
+CREATE TYPE core.mirror_direction AS ENUM ('pull', 'push');
+CREATE TYPE core.mirror_run_status AS ENUM ('success', 'failure');
+
+CREATE TABLE core.repository_mirrors (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    repository_id UUID NOT NULL REFERENCES core.repositories(id) ON DELETE CASCADE,
+    direction core.mirror_direction NOT NULL,
+    url TEXT NOT NULL,
+    username TEXT,
+    password_ciphertext BYTEA,
+    -- How often a pull mirror fetches; push mirrors sync after every push.
+    interval_seconds INTEGER,
+    requested_at TIMESTAMPTZ,
+    started_at TIMESTAMPTZ,
+    finished_at TIMESTAMPTZ,
+    last_synced_at TIMESTAMPTZ,
+    last_status core.mirror_run_status,
+    last_error TEXT,
+    -- The repository's read-only state before a pull mirror made it read-only,
+    -- restored when the mirror is removed.
+    was_readonly BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (repository_id, direction, url)
+);
+
+-- A repository follows at most one upstream.
+CREATE UNIQUE INDEX idx_repository_mirrors_one_pull
+    ON core.repository_mirrors(repository_id) WHERE direction = 'pull';
+
+CREATE TABLE core.repository_mirror_runs (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    mirror_id UUID NOT NULL REFERENCES core.repository_mirrors(id) ON DELETE CASCADE,
+    status core.mirror_run_status NOT NULL,
+    error TEXT,
+    started_at TIMESTAMPTZ NOT NULL,
+    finished_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_repository_mirror_runs_mirror_id
+    ON core.repository_mirror_runs(mirror_id, finished_at DESC);
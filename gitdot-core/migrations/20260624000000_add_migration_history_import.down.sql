@@ -0,0 +1,7 @@
+ALTER TABLE migration.migration_repositories
+    DROP COLUMN imported_reviews,
+    DROP COLUMN imported_questions;
+
+-- Postgres cannot drop enum values; repositories still importing are recorded
+-- as completed since their git data was already mirrored.
+UPDATE migration.migration_repositories SET status = 'completed' WHERE status = 'importing';
@@ -0,0 +1,5 @@
+ALTER TYPE migration.migration_repository_status ADD VALUE 'importing' BEFORE 'completed';
+
+ALTER TABLE migration.migration_repositories
+    ADD COLUMN imported_questions INTEGER NOT NULL DEFAULT 0,
+    ADD COLUMN imported_reviews INTEGER NOT NULL DEFAULT 0;
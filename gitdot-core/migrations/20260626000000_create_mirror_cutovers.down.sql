@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS migration.idx_mirror_cutovers_repository_id;
+DROP TABLE IF EXISTS migration.mirror_cutovers;
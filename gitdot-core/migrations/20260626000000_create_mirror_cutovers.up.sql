@@ -0,0 +1,20 @@
+-- One row per read-only GitHub mirror turned into a live repository.
+CREATE TABLE migration.mirror_cutovers (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    migration_repository_id UUID NOT NULL
+        REFERENCES migration.migration_repositories(id) ON DELETE CASCADE,
+    repository_id UUID NOT NULL REFERENCES core.repositories(id) ON DELETE CASCADE,
+    actor_id UUID NOT NULL REFERENCES core.users(id) ON DELETE CASCADE,
+    origin_full_name VARCHAR(255) NOT NULL,
+    -- The push mirror back to the origin, when one was kept.
+    push_mirror_id UUID REFERENCES core.repository_mirrors(id) ON DELETE SET NULL,
+    -- A cutover stays due for a commit backfill until one finishes; commits
+    -- already recorded are skipped when it is retried.
+    backfill_attempted_at TIMESTAMPTZ,
+    backfilled_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_mirror_cutovers_repository_id ON migration.mirror_cutovers (repository_id);
+CREATE INDEX idx_mirror_cutovers_pending_backfill
+    ON migration.mirror_cutovers (created_at) WHERE backfilled_at IS NULL;
@@ -0,0 +1,5 @@
+ALTER TABLE core.commits DROP COLUMN IF EXISTS signature_status;
+DROP TYPE IF EXISTS core.commit_signature_status;
+
+DROP TABLE IF EXISTS auth.signing_keys CASCADE;
+DROP TYPE IF EXISTS auth.signing_key_type;
@@ -0,0 +1,24 @@
+CREATE TYPE auth.signing_key_type AS ENUM ('gpg', 'ssh');
+
+-- Keys are only added once shown to be their owner's: GPG keys by verified
+-- emails, SSH keys by a signed challenge.
+-- `key_ids` holds the full fingerprints of the key and its signing subkeys.
+CREATE TABLE auth.signing_keys (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL REFERENCES core.users(id) ON DELETE CASCADE,
+    name VARCHAR(64) NOT NULL,
+    key_type auth.signing_key_type NOT NULL,
+    public_key TEXT NOT NULL,
+    fingerprint VARCHAR(64) NOT NULL UNIQUE,
+    key_ids TEXT[] NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_signing_keys_user_id ON auth.signing_keys (user_id);
+CREATE INDEX idx_signing_keys_key_ids ON auth.signing_keys USING GIN (key_ids);
+
+CREATE TYPE core.commit_signature_status AS ENUM (
+    'verified', 'unverified', 'unknown_key', 'bad_email'
+);
+
+ALTER TABLE core.commits ADD COLUMN signature_status core.commit_signature_status;
@@ -4,7 +4,8 @@
 //! concrete `Impl`: git via `git2` and the `git http-backend` CGI, GitHub
 //! (Octocrab), object storage (Cloudflare R2), Redis, Kafka, ClickHouse, S2
 //! durable streams, SMTP email, image processing, Google Secret Manager, the
-//! Slack bot API, and JWT/token generation.
+//! Slack bot API, JWT/token generation, signed outbound webhook delivery, and
+//! commit signature checks via `gpg` and `ssh-keygen`.
 
 mod clickhouse;
 mod email;
@@ -17,8 +18,10 @@ mod r2;
 mod redis;
 mod s2;
 mod secret;
+mod signature;
 mod slack_bot;
 mod token;
+mod webhook;
 
 pub use clickhouse::{ClickHouseClient, ClickHouseClientImpl};
 pub use email::{EmailClient, SmtpClient};
@@ -31,7 +34,12 @@ pub use r2::{R2Client, R2ClientImpl};
 pub use redis::{RedisClient, RedisClientImpl};
 pub use s2::{S2Client, S2ClientImpl};
 pub use secret::{GoogleSecretClient, SecretClient};
+pub use signature::{SignatureClient, SignatureClientImpl};
 pub use slack_bot::{
     SLACK_BOT_SIGNATURE_HEADER, SLACK_BOT_TIMESTAMP_HEADER, SlackBotClient, SlackBotClientImpl,
 };
 pub use token::{TokenClient, TokenClientImpl};
+pub use webhook::{
+    WEBHOOK_DELIVERY_HEADER, WEBHOOK_EVENT_HEADER, WEBHOOK_SIGNATURE_HEADER, WebhookClient,
+    WebhookClientImpl, sign_webhook_payload,
+};
@@ -1,16 +1,32 @@
+use std::collections::{HashMap, HashSet};
+
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
-use tokio::{fs, task};
+use futures::stream;
+use tokio::{fs, io::AsyncReadExt, task};
+use uuid::Uuid;
 
 use crate::{
     dto::{
-        CommitDiffResponse, InitialCommitFile, PathType, RepositoryBlobResponse,
-        RepositoryCommitResponse, RepositoryPath, RepositoryPathsResponse,
+        ArchiveFormat, BlameCommitResponse, BlameRange, CodeSearchFileResponse, CodeSearchMatch,
+        CodeSearchQuery, CodeSearchResponse, CommitDiffResponse, DiffHunkResponse,
+        DiffLineResponse, DiffOptions, FileDiffResponse, FileDiffStatus, IgnoreWhitespace,
+        InitialCommitFile, MAX_MATCHES_PER_FILE, MatchRange, MirrorCredentials, MirrorRefUpdate,
+        PathType, RepositoryArchiveResponse, RepositoryBlameResponse, RepositoryBlobResponse,
+        RepositoryCommitResponse, RepositoryDiffResponse, RepositoryPath, RepositoryPathsResponse,
+        TagResponse, TaggerResponse,
     },
     error::GitError,
     util::{
-        git::{DEFAULT_BRANCH, GitHookType, REPO_SUFFIX},
+        git::{
+            BRANCH_PREFIX, DEFAULT_BRANCH, GitHookType, POST_RECEIVE_SCRIPT, PRE_RECEIVE_SCRIPT,
+            PROC_RECEIVE_SCRIPT, REPO_SUFFIX, TAG_PREFIX, ZERO_SHA, split_tag_signature,
+        },
         review::MAGIC_REF_PREFIX,
+        search::{
+            MAX_INDEXED_FILE_SIZE, SEARCH_INDEX_DIR, SEARCH_INDEX_FILE, SearchIndex,
+            SearchIndexReader, language_for_path,
+        },
     },
 };
 
@@ -64,6 +80,23 @@ pub trait GitClient: Send + Sync + Clone + 'static {
     /// - [`GitError::JoinError`] — the blocking task panicked.
     async fn mirror_repo(&self, owner: &str, repo: &str, url: &str) -> Result<(), GitError>;
 
+    /// Runs one round of background maintenance on the bare repo: re-applies
+    /// gitdot's repo config, geometrically repacks loose objects and small
+    /// packs under a multi-pack-index with a reachability bitmap, appends to
+    /// the split commit-graph, and prunes unreachable loose objects older than
+    /// two weeks. Each step is incremental, so runs stay cheap on repos that
+    /// were maintained recently. Cached archives are dropped as well, and a
+    /// code search index that is missing or behind `HEAD` is rebuilt. Repos
+    /// with hooks installed get any missing or outdated hook rewritten; repos
+    /// with none (mirrors awaiting cutover) are left without.
+    ///
+    /// # Errors
+    /// - [`GitError::IoError`] — spawning `git` failed.
+    /// - [`GitError::Git2Error`] — applying config failed or a `git` step
+    ///   exited non-zero.
+    /// - [`GitError::JoinError`] — the blocking task panicked.
+    async fn maintain_repo(&self, owner: &str, repo: &str) -> Result<(), GitError>;
+
     /// Returns the symbolic `HEAD` of the repo (the full default ref name, e.g.
     /// `refs/heads/main`).
     ///
@@ -72,21 +105,75 @@ pub trait GitClient: Send + Sync + Clone + 'static {
     /// - [`GitError::Git2Error`] — `git symbolic-ref HEAD` exited non-zero.
     async fn get_default_ref(&self, owner: &str, repo: &str) -> Result<String, GitError>;
 
-    /// Fetches a single `sha` from `url` into local `ref_name`, used for
-    /// incremental mirror syncs. The refspec force-updates the target ref.
+    /// Fetches `source` (a single sha, or a ref on the remote) from `url` into
+    /// local `ref_name`, used for incremental mirror syncs and for pull request
+    /// heads during migrations. The refspec force-updates the target ref.
     ///
     /// # Errors
     /// - [`GitError::IoError`] — spawning `git` failed.
-    /// - [`GitError::Git2Error`] — `git fetch` exited non-zero.
+    /// - [`GitError::Git2Error`] — `git fetch` exited non-zero, e.g. the
+    ///   remote has no such ref.
     async fn fetch_ref(
         &self,
         owner: &str,
         repo: &str,
         url: &str,
         ref_name: &str,
-        sha: &str,
+        source: &str,
+    ) -> Result<(), GitError>;
+
+    /// Returns whether the repo has the commit `sha`.
+    ///
+    /// # Errors
+    /// - [`GitError::Git2Error`] — `sha` is invalid or opening the repo failed.
+    /// - [`GitError::JoinError`] — the blocking task panicked.
+    async fn has_commit(&self, owner: &str, repo: &str, sha: &str) -> Result<bool, GitError>;
+
+    /// Fetches every branch and tag from `url`, force-updating local ones and
+    /// pruning those the remote no longer has, so the repo ends up an exact
+    /// copy of the remote's `refs/heads/*` and `refs/tags/*`. Other refs are
+    /// left alone. Returns the refs that changed, sorted by name.
+    /// `credentials` are answered to the remote's http(s) authentication
+    /// challenge and never put in `url`.
+    ///
+    /// # Errors
+    /// - [`GitError::IoError`] — spawning `git` failed.
+    /// - [`GitError::Git2Error`] — `git fetch` exited non-zero, reading refs
+    ///   failed, or `credentials` contain a line break.
+    /// - [`GitError::JoinError`] — the blocking task panicked.
+    async fn sync_mirror(
+        &self,
+        owner: &str,
+        repo: &str,
+        url: &str,
+        credentials: Option<&MirrorCredentials>,
+    ) -> Result<Vec<MirrorRefUpdate>, GitError>;
+
+    /// Force-pushes every branch and tag to `url` and deletes the remote's
+    /// branches and tags that no longer exist locally, so the remote ends up
+    /// an exact copy of the repo's `refs/heads/*` and `refs/tags/*`.
+    /// `credentials` are passed as for [`sync_mirror`](Self::sync_mirror).
+    ///
+    /// # Errors
+    /// - [`GitError::IoError`] — spawning `git` failed.
+    /// - [`GitError::Git2Error`] — `git push` exited non-zero, e.g. the remote
+    ///   rejected the credentials or a ref update, or `credentials` contain a
+    ///   line break.
+    async fn push_mirror(
+        &self,
+        owner: &str,
+        repo: &str,
+        url: &str,
+        credentials: Option<&MirrorCredentials>,
     ) -> Result<(), GitError>;
 
+    /// Deletes `ref_name`. A ref that does not exist is not an error.
+    ///
+    /// # Errors
+    /// - [`GitError::Git2Error`] — opening the repo or deleting the ref failed.
+    /// - [`GitError::JoinError`] — the blocking task panicked.
+    async fn delete_ref(&self, owner: &str, repo: &str, ref_name: &str) -> Result<(), GitError>;
+
     /// Creates `ref_name` pointing at the commit `sha`, failing if the ref
     /// already exists.
     ///
@@ -116,6 +203,69 @@ pub trait GitClient: Send + Sync + Clone + 'static {
         sha: &str,
     ) -> Result<(), GitError>;
 
+    /// Lists every branch as `(ref_name, sha)` pairs, sorted by full ref name.
+    ///
+    /// # Errors
+    /// - [`GitError::Git2Error`] — opening the repo or reading refs failed.
+    /// - [`GitError::JoinError`] — the blocking task panicked.
+    async fn list_branches(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<(String, String)>, GitError>;
+
+    /// Lists the repository's tags, newest first by tagger date (commit date
+    /// for lightweight tags). Tags of trees or blobs are skipped.
+    ///
+    /// # Errors
+    /// - [`GitError::NotFound`] — the repository does not exist.
+    /// - [`GitError::Git2Error`] — a git operation failed.
+    /// - [`GitError::JoinError`] — the blocking task panicked.
+    async fn list_tags(&self, owner: &str, repo: &str) -> Result<Vec<TagResponse>, GitError>;
+
+    /// Reads the tag `name` (without `refs/tags/`), or `None` if it does not
+    /// exist or does not point at a commit.
+    ///
+    /// # Errors
+    /// - [`GitError::NotFound`] — the repository does not exist.
+    /// - [`GitError::Git2Error`] — a git operation failed.
+    /// - [`GitError::JoinError`] — the blocking task panicked.
+    async fn get_tag(
+        &self,
+        owner: &str,
+        repo: &str,
+        name: &str,
+    ) -> Result<Option<TagResponse>, GitError>;
+
+    /// Tags the commit `sha` as `name`. With a `message` the tag is annotated
+    /// and tagged by `tagger_name <tagger_email>` at the current time;
+    /// otherwise it is lightweight.
+    ///
+    /// # Errors
+    /// - [`GitError::NotFound`] — the repository or commit does not exist.
+    /// - [`GitError::Git2Error`] — the tag already exists or another git
+    ///   error occurred.
+    /// - [`GitError::JoinError`] — the blocking task panicked.
+    #[allow(clippy::too_many_arguments)]
+    async fn create_tag(
+        &self,
+        owner: &str,
+        repo: &str,
+        name: &str,
+        sha: &str,
+        message: Option<&str>,
+        tagger_name: &str,
+        tagger_email: &str,
+    ) -> Result<TagResponse, GitError>;
+
+    /// Deletes the tag `name` (without `refs/tags/`).
+    ///
+    /// # Errors
+    /// - [`GitError::NotFound`] — the repository or tag does not exist.
+    /// - [`GitError::Git2Error`] — a git operation failed.
+    /// - [`GitError::JoinError`] — the blocking task panicked.
+    async fn delete_tag(&self, owner: &str, repo: &str, name: &str) -> Result<(), GitError>;
+
     /// Reads the entry at `path` in the tree of `ref_name`, returning either a
     /// file (with content; binary blobs are base64-encoded) or a folder listing
     /// of its immediate children.
@@ -163,6 +313,78 @@ pub trait GitClient: Send + Sync + Clone + 'static {
         ref_name: &str,
     ) -> Result<RepositoryPathsResponse, GitError>;
 
+    /// Attributes every line of the file at `path` in `ref_name` to the commit
+    /// that last changed it, via `git blame`. If `ignore_revs_file` names a
+    /// file in the same tree, the revisions it lists (one per line, `#`
+    /// comments allowed) are skipped as with `--ignore-revs-file`; a missing
+    /// file or an unresolvable revision in it is ignored. Commit authors are
+    /// returned unresolved.
+    ///
+    /// # Errors
+    /// - [`GitError::NotFound`] — `ref_name` or `path` does not exist.
+    /// - [`GitError::NotABlob`] — `path` is a folder.
+    /// - [`GitError::IoError`] — spawning `git` failed.
+    /// - [`GitError::Git2Error`] — `git blame` exited non-zero or another git
+    ///   error occurred.
+    /// - [`GitError::JoinError`] — the blocking task panicked.
+    async fn blame(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_name: &str,
+        path: &str,
+        ignore_revs_file: Option<&str>,
+    ) -> Result<RepositoryBlameResponse, GitError>;
+
+    /// Builds an archive of the tree at `ref_name` whose entries sit under a
+    /// `{repo}-{shortsha}/` directory, and streams it back. Archives are
+    /// cached inside the bare repo by tree SHA and prefix, so repeat downloads
+    /// of an unchanged tree skip `git archive`. The cache is capped at 512 MiB
+    /// per repo, evicting the least recently downloaded archives first.
+    ///
+    /// # Errors
+    /// - [`GitError::NotFound`] — `ref_name` does not exist.
+    /// - [`GitError::IoError`] — spawning `git` or reading the cache failed.
+    /// - [`GitError::Git2Error`] — `git archive` exited non-zero or another
+    ///   git error occurred.
+    /// - [`GitError::JoinError`] — the blocking task panicked.
+    async fn get_repo_archive(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_name: &str,
+        format: ArchiveFormat,
+    ) -> Result<RepositoryArchiveResponse, GitError>;
+
+    /// Rebuilds the code search index for the commit `HEAD` points at, unless
+    /// it is already current, and returns its SHA, or `None` when the
+    /// repository is empty. The index is stored inside the bare repository
+    /// and replaced atomically.
+    ///
+    /// # Errors
+    /// - [`GitError::NotFound`] — the repository does not exist.
+    /// - [`GitError::IoError`] — the index could not be written.
+    /// - [`GitError::Git2Error`] — a git operation failed.
+    /// - [`GitError::JoinError`] — the blocking task panicked.
+    async fn index_code(&self, owner: &str, repo: &str) -> Result<Option<String>, GitError>;
+
+    /// Searches the text files of the last indexed commit, returning up to
+    /// `limit` files with line-level matches. Searches never build the index;
+    /// until [`index_code`](Self::index_code) has run, nothing matches.
+    ///
+    /// # Errors
+    /// - [`GitError::NotFound`] — the repository does not exist.
+    /// - [`GitError::IoError`] — the index could not be read.
+    /// - [`GitError::Git2Error`] — a git operation failed.
+    /// - [`GitError::JoinError`] — the blocking task panicked.
+    async fn search_code(
+        &self,
+        owner: &str,
+        repo: &str,
+        query: CodeSearchQuery,
+        limit: usize,
+    ) -> Result<CodeSearchResponse, GitError>;
+
     /// Resolves `ref_name` to its commit and returns the commit metadata.
     ///
     /// # Errors
@@ -208,6 +430,55 @@ pub trait GitClient: Send + Sync + Clone + 'static {
         new_sha: &str,
     ) -> Result<Vec<RepositoryCommitResponse>, GitError>;
 
+    /// Reads the `gpgsig` header of the commit `sha` as `(signature, payload)`,
+    /// where `payload` is the commit object without that header — the bytes
+    /// the signature was made over. `None` for an unsigned commit.
+    ///
+    /// # Errors
+    /// - [`GitError::Git2Error`] — the SHA is invalid or the commit is missing.
+    /// - [`GitError::JoinError`] — the blocking task panicked.
+    async fn get_commit_signature(
+        &self,
+        owner: &str,
+        repo: &str,
+        sha: &str,
+    ) -> Result<Option<(String, Vec<u8>)>, GitError>;
+
+    /// Finds the best common ancestor of `left_sha` and `right_sha`, or `None`
+    /// when the two commits share no history.
+    ///
+    /// # Errors
+    /// - [`GitError::Git2Error`] — a SHA is invalid or a commit is missing.
+    /// - [`GitError::JoinError`] — the blocking task panicked.
+    async fn merge_base(
+        &self,
+        owner: &str,
+        repo: &str,
+        left_sha: &str,
+        right_sha: &str,
+    ) -> Result<Option<String>, GitError>;
+
+    /// Diffs the tree of `right_sha` against `left_sha` (or the empty tree when
+    /// `None`), returning line-level hunks for the `limit` files starting at
+    /// `offset` along with the total number of changed files. Renames are
+    /// paired up only when `options.detect_renames` is set.
+    ///
+    /// # Errors
+    /// - [`GitError::NotFound`] — `left_sha` or `right_sha` does not exist.
+    /// - [`GitError::Git2Error`] — a git operation failed.
+    /// - [`GitError::JoinError`] — the blocking task panicked.
+    #[allow(clippy::too_many_arguments)]
+    async fn get_repo_diff(
+        &self,
+        owner: &str,
+        repo: &str,
+        left_sha: Option<&str>,
+        right_sha: &str,
+        options: DiffOptions,
+        offset: usize,
+        limit: usize,
+    ) -> Result<RepositoryDiffResponse, GitError>;
+
     /// Resolves `ref_name` to the hex SHA of the commit it points at.
     ///
     /// # Errors
@@ -271,7 +542,8 @@ pub trait GitClient: Send + Sync + Clone + 'static {
     ) -> Result<String, GitError>;
 
     /// Writes `hook_script` as the named `hook_type` in the repo's `hooks/`
-    /// directory and makes it executable (on Unix).
+    /// directory and makes it executable (on Unix). The script is swapped in
+    /// with a rename, so a concurrent push never runs a partial hook.
     ///
     /// # Errors
     /// - [`GitError::IoError`] — writing the hook or setting permissions
@@ -303,6 +575,22 @@ pub trait GitClient: Send + Sync + Clone + 'static {
     }
 }
 
+/// Directory inside each bare repo that holds cached archives.
+const ARCHIVE_CACHE_DIR: &str = "gitdot-archives";
+
+/// Environment variables the mirror credential helper reads its answer from.
+const MIRROR_USERNAME_ENV: &str = "GITDOT_MIRROR_USERNAME";
+const MIRROR_PASSWORD_ENV: &str = "GITDOT_MIRROR_PASSWORD";
+
+/// Answers git's `get` request with [`MIRROR_USERNAME_ENV`] and
+/// [`MIRROR_PASSWORD_ENV`], ignoring `store` and `erase`.
+const MIRROR_CREDENTIAL_HELPER: &str = "!f() { test \"$1\" = get && \
+    printf 'username=%s\\npassword=%s\\n' \"$GITDOT_MIRROR_USERNAME\" \"$GITDOT_MIRROR_PASSWORD\"; }; f";
+
+/// Total size of a repo's cached archives past which the least recently
+/// downloaded ones are evicted.
+const ARCHIVE_CACHE_MAX_BYTES: u64 = 512 * 1024 * 1024;
+
 #[derive(Debug, Clone)]
 pub struct Git2Client {
     project_root: String,
@@ -327,6 +615,101 @@ impl Git2Client {
         git2::Repository::open_bare(&repo_path)
     }
 
+    /// Snapshots the branch and tag refs a mirror sync manages, by full name.
+    async fn list_mirrored_refs(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<HashMap<String, String>, GitError> {
+        let repository = self.open_repository(owner, repo)?;
+
+        task::spawn_blocking(move || {
+            let mut refs = HashMap::new();
+            for prefix in [BRANCH_PREFIX, TAG_PREFIX] {
+                for reference in repository.references_glob(&format!("{prefix}*"))? {
+                    let reference = reference?;
+                    if let (Some(name), Some(oid)) = (reference.name(), reference.target()) {
+                        refs.insert(name.to_string(), oid.to_string());
+                    }
+                }
+            }
+            Ok(refs)
+        })
+        .await?
+    }
+
+    fn get_archive_cache_path(&self, owner: &str, repo: &str) -> String {
+        format!("{}/{}", self.get_repo_path(owner, repo), ARCHIVE_CACHE_DIR)
+    }
+
+    /// A `git -C <repo_path>` command for talking to a mirror remote. With
+    /// `credentials`, a one-shot credential helper answers the remote from
+    /// the command's environment, so they never show up in its arguments,
+    /// which any local user can read.
+    fn mirror_command(
+        repo_path: &str,
+        credentials: Option<&MirrorCredentials>,
+    ) -> Result<tokio::process::Command, GitError> {
+        let mut command = tokio::process::Command::new("git");
+        command.env("GIT_TERMINAL_PROMPT", "0");
+        if let Some(credentials) = credentials {
+            // a line break would let the value inject other credential fields
+            if [&credentials.username, &credentials.password]
+                .iter()
+                .any(|value| value.contains(['\n', '\r', '\0']))
+            {
+                return Err(GitError::Git2Error(git2::Error::from_str(
+                    "mirror credentials must not contain line breaks",
+                )));
+            }
+            command
+                .env(MIRROR_USERNAME_ENV, &credentials.username)
+                .env(MIRROR_PASSWORD_ENV, &credentials.password)
+                // the empty helper drops any configured before ours
+                .args(["-c", "credential.helper=", "-c"])
+                .arg(format!("credential.helper={MIRROR_CREDENTIAL_HELPER}"));
+        }
+        command.arg("-C").arg(repo_path);
+        Ok(command)
+    }
+
+    /// Deletes the least recently used archives in `cache_dir` (by
+    /// modification time, which cache hits bump) until the rest fit in
+    /// `max_bytes`. `keep` is never deleted, so it may exceed the cap alone.
+    fn evict_archives(
+        cache_dir: &std::path::Path,
+        keep: &std::path::Path,
+        max_bytes: u64,
+    ) -> Result<(), std::io::Error> {
+        let mut archives = Vec::new();
+        let mut total = 0;
+        for entry in std::fs::read_dir(cache_dir)? {
+            let entry = entry?;
+            let path = entry.path();
+            // In-flight builds are left to their writers.
+            if path.extension().is_some_and(|ext| ext == "tmp") {
+                continue;
+            }
+            let metadata = entry.metadata()?;
+            total += metadata.len();
+            if path != keep {
+                archives.push((metadata.modified()?, metadata.len(), path));
+            }
+        }
+
+        archives.sort();
+        for (_, len, path) in archives {
+            if total <= max_bytes {
+                break;
+            }
+            match std::fs::remove_file(&path) {
+                Err(e) if e.kind() != std::io::ErrorKind::NotFound => return Err(e),
+                _ => total -= len,
+            }
+        }
+        Ok(())
+    }
+
     fn apply_repo_config(repo: &git2::Repository) -> Result<(), git2::Error> {
         let mut config = repo.config()?;
 
@@ -340,6 +723,11 @@ impl Git2Client {
         config.set_bool("core.commitGraph", true)?;
         config.set_bool("gc.writeCommitGraph", true)?;
         config.set_bool("fetch.writeCommitGraph", true)?;
+
+        // Serve partial clones (`--filter=blob:none`, `tree:0`) and the lazy
+        // fetches of missing objects that follow them.
+        config.set_bool("uploadpack.allowFilter", true)?;
+        config.set_bool("uploadpack.allowReachableSHA1InWant", true)?;
         Ok(())
     }
 
@@ -377,6 +765,278 @@ impl Git2Client {
         repo.find_blob(tree_entry.id())
     }
 
+    /// Reads the revisions listed in an ignore-revs file, skipping blank
+    /// lines, `#` comments and revisions that do not resolve to a commit.
+    fn read_ignore_revs(repo: &git2::Repository, tree: &git2::Tree, path: &str) -> Vec<String> {
+        let Ok(entry) = tree.get_path(std::path::Path::new(path)) else {
+            return Vec::new();
+        };
+        let Ok(blob) = repo.find_blob(entry.id()) else {
+            return Vec::new();
+        };
+
+        String::from_utf8_lossy(blob.content())
+            .lines()
+            .map(|line| line.split('#').next().unwrap_or("").trim())
+            .filter(|rev| !rev.is_empty())
+            .filter_map(|rev| repo.revparse_single(rev).ok()?.peel_to_commit().ok())
+            .map(|commit| commit.id().to_string())
+            .collect()
+    }
+
+    /// Collapses `git blame --porcelain` output into line ranges. Each group
+    /// header is `<sha> <orig_line> <final_line> <line_count>`; adjacent
+    /// groups from the same commit are merged.
+    fn parse_blame_porcelain(output: &str) -> Vec<BlameRange> {
+        let mut ranges: Vec<BlameRange> = Vec::new();
+        for line in output.lines() {
+            if line.starts_with('\t') {
+                continue;
+            }
+            let fields: Vec<&str> = line.split(' ').collect();
+            let [sha, _, start_line, line_count] = fields[..] else {
+                continue;
+            };
+            if sha.len() != 40 || !sha.bytes().all(|b| b.is_ascii_hexdigit()) {
+                continue;
+            }
+            let (Ok(start_line), Ok(line_count)) = (start_line.parse(), line_count.parse()) else {
+                continue;
+            };
+
+            match ranges.last_mut() {
+                Some(last)
+                    if last.commit_sha == sha
+                        && last.start_line + last.line_count == start_line =>
+                {
+                    last.line_count += line_count;
+                }
+                _ => ranges.push(BlameRange {
+                    start_line,
+                    line_count,
+                    commit_sha: sha.to_string(),
+                }),
+            }
+        }
+        ranges
+    }
+
+    fn patch_to_response(patch: &git2::Patch) -> Result<FileDiffResponse, git2::Error> {
+        let delta = patch.delta();
+        let path_of =
+            |file: git2::DiffFile| file.path().and_then(|p| p.to_str()).map(str::to_string);
+        let path = path_of(delta.new_file())
+            .or_else(|| path_of(delta.old_file()))
+            .unwrap_or_default();
+        let old_path = path_of(delta.old_file()).unwrap_or_else(|| path.clone());
+        let status = match delta.status() {
+            git2::Delta::Added => FileDiffStatus::Added,
+            git2::Delta::Deleted => FileDiffStatus::Deleted,
+            git2::Delta::Renamed => FileDiffStatus::Renamed,
+            git2::Delta::Copied => FileDiffStatus::Copied,
+            git2::Delta::Typechange => FileDiffStatus::TypeChanged,
+            _ => FileDiffStatus::Modified,
+        };
+        let binary = delta.flags().is_binary();
+        let (_, insertions, deletions) = patch.line_stats()?;
+
+        let mut hunks = Vec::new();
+        if !binary {
+            for h in 0..patch.num_hunks() {
+                let (hunk, num_lines) = patch.hunk(h)?;
+                let mut lines = Vec::with_capacity(num_lines);
+                for l in 0..num_lines {
+                    let line = patch.line_in_hunk(h, l)?;
+                    // Skip the "no newline at end of file" markers.
+                    if !matches!(line.origin(), '+' | '-' | ' ') {
+                        continue;
+                    }
+                    lines.push(DiffLineResponse {
+                        origin: line.origin(),
+                        old_lineno: line.old_lineno(),
+                        new_lineno: line.new_lineno(),
+                        content: String::from_utf8_lossy(line.content()).into_owned(),
+                    });
+                }
+                hunks.push(DiffHunkResponse {
+                    header: String::from_utf8_lossy(hunk.header())
+                        .trim_end()
+                        .to_string(),
+                    old_start: hunk.old_start(),
+                    old_lines: hunk.old_lines(),
+                    new_start: hunk.new_start(),
+                    new_lines: hunk.new_lines(),
+                    lines,
+                });
+            }
+        }
+
+        Ok(FileDiffResponse {
+            path,
+            old_path,
+            status,
+            binary,
+            lines_added: insertions as u32,
+            lines_removed: deletions as u32,
+            hunks,
+        })
+    }
+
+    fn get_search_index_path(&self, owner: &str, repo: &str) -> String {
+        format!(
+            "{}/{}/{}",
+            self.get_repo_path(owner, repo),
+            SEARCH_INDEX_DIR,
+            SEARCH_INDEX_FILE
+        )
+    }
+
+    fn head_commit(repo: &git2::Repository) -> Result<Option<git2::Commit<'_>>, git2::Error> {
+        match repo.head() {
+            Ok(head) => head.peel_to_commit().map(Some),
+            Err(e)
+                if matches!(
+                    e.code(),
+                    git2::ErrorCode::UnbornBranch | git2::ErrorCode::NotFound
+                ) =>
+            {
+                Ok(None)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    fn build_search_index(
+        repo: &git2::Repository,
+        commit: &git2::Commit,
+    ) -> Result<SearchIndex, git2::Error> {
+        let mut index = SearchIndex::new(commit.id().to_string());
+        let mut blobs = Vec::new();
+        commit
+            .tree()?
+            .walk(git2::TreeWalkMode::PreOrder, |dir, entry| {
+                if entry.kind() == Some(git2::ObjectType::Blob)
+                    && let Some(name) = entry.name()
+                {
+                    blobs.push((format!("{dir}{name}"), entry.id()));
+                }
+                git2::TreeWalkResult::Ok
+            })?;
+
+        for (path, oid) in blobs {
+            let blob = repo.find_blob(oid)?;
+            let content = blob.content();
+            if content.len() > MAX_INDEXED_FILE_SIZE || Self::is_binary(content) {
+                continue;
+            }
+            index.add_file(path, oid.to_string(), content);
+        }
+        Ok(index)
+    }
+
+    fn write_search_index(path: &str, index: &SearchIndex) -> Result<(), std::io::Error> {
+        let path = std::path::Path::new(path);
+        let dir = path.parent().expect("index path has a parent");
+        std::fs::create_dir_all(dir)?;
+        let tmp_path = dir.join(format!("{}.tmp", Uuid::new_v4()));
+        index.write_to(std::fs::File::create(&tmp_path)?)?;
+        std::fs::rename(&tmp_path, path)?;
+
+        // Left behind by the earlier JSON format.
+        match std::fs::remove_file(dir.join("index.json")) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e),
+            _ => Ok(()),
+        }
+    }
+
+    /// Rebuilds the search index at `index_path` unless it was already built
+    /// from `HEAD`, returning the indexed commit.
+    fn refresh_search_index(
+        repository: &git2::Repository,
+        index_path: &str,
+    ) -> Result<Option<String>, GitError> {
+        let Some(commit) = Self::head_commit(repository)? else {
+            return Ok(None);
+        };
+        let commit_sha = commit.id().to_string();
+        let current = SearchIndexReader::open(std::path::Path::new(index_path))?;
+        if current.is_none_or(|index| index.commit_sha() != commit_sha) {
+            let index = Self::build_search_index(repository, &commit)?;
+            Self::write_search_index(index_path, &index)?;
+        }
+        Ok(Some(commit_sha))
+    }
+
+    /// Returns the number of matching lines and the first
+    /// [`MAX_MATCHES_PER_FILE`] of them with context, or `None` if the file
+    /// does not match.
+    fn search_file(content: &str, query: &CodeSearchQuery) -> Option<(u32, Vec<CodeSearchMatch>)> {
+        let lines: Vec<&str> = content.lines().collect();
+        let context = query.context_lines as usize;
+        let mut match_count = 0;
+        let mut matches = Vec::new();
+        for (i, line) in lines.iter().enumerate() {
+            let ranges: Vec<MatchRange> = query
+                .pattern
+                .find_iter(line)
+                .filter(|m| !m.is_empty())
+                .map(|m| MatchRange {
+                    start: m.start() as u32,
+                    end: m.end() as u32,
+                })
+                .collect();
+            if ranges.is_empty() {
+                continue;
+            }
+            match_count += 1;
+            if matches.len() < MAX_MATCHES_PER_FILE {
+                let to_owned = |lines: &[&str]| lines.iter().map(|l| l.to_string()).collect();
+                matches.push(CodeSearchMatch {
+                    line_number: i as u32 + 1,
+                    content: line.to_string(),
+                    ranges,
+                    before: to_owned(&lines[i.saturating_sub(context)..i]),
+                    after: to_owned(&lines[i + 1..(i + 1 + context).min(lines.len())]),
+                });
+            }
+        }
+        (match_count > 0).then_some((match_count, matches))
+    }
+
+    /// Describes a tag ref, or `None` if it does not point at a commit.
+    fn tag_to_response(reference: &git2::Reference) -> Option<TagResponse> {
+        let name = reference.name()?.strip_prefix(TAG_PREFIX)?.to_string();
+        let sha = reference.target()?.to_string();
+        let commit = reference.peel_to_commit().ok()?;
+        let annotation = reference.peel_to_tag().ok();
+
+        let (message, signature) = match annotation.as_ref().and_then(|tag| tag.message()) {
+            Some(message) => {
+                let (message, signature) = split_tag_signature(message);
+                (Some(message.to_string()), signature.map(str::to_string))
+            }
+            None => (None, None),
+        };
+        let tagger = annotation
+            .as_ref()
+            .and_then(|tag| tag.tagger())
+            .map(|tagger| TaggerResponse {
+                name: tagger.name().unwrap_or("Unknown").to_string(),
+                email: tagger.email().unwrap_or("").to_string(),
+                date: DateTime::from_timestamp(tagger.when().seconds(), 0).unwrap_or_default(),
+            });
+
+        Some(TagResponse {
+            name,
+            sha,
+            commit: RepositoryCommitResponse::from(&commit),
+            annotated: annotation.is_some(),
+            message,
+            tagger,
+            signature,
+        })
+    }
+
     fn is_binary(data: &[u8]) -> bool {
         data.iter().take(8000).any(|&b| b == 0)
     }
@@ -537,6 +1197,83 @@ impl GitClient for Git2Client {
         Ok(())
     }
 
+    async fn maintain_repo(&self, owner: &str, repo: &str) -> Result<(), GitError> {
+        let repo_path = self.get_repo_path(owner, repo);
+
+        // Repos created before a config change pick it up here.
+        let repo_path_clone = repo_path.clone();
+        task::spawn_blocking(move || -> Result<(), git2::Error> {
+            let repo = git2::Repository::open_bare(&repo_path_clone)?;
+            Self::apply_repo_config(&repo)?;
+            Ok(())
+        })
+        .await??;
+
+        for args in [
+            &[
+                "repack",
+                "-d",
+                "-l",
+                "--geometric=2",
+                "--write-midx",
+                "--write-bitmap-index",
+            ][..],
+            &["commit-graph", "write", "--reachable", "--split"],
+            &["prune", "--expire=2.weeks.ago"],
+        ] {
+            let output = tokio::process::Command::new("git")
+                .arg("-C")
+                .arg(&repo_path)
+                .args(args)
+                .output()
+                .await?;
+            if !output.status.success() {
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                return Err(GitError::Git2Error(git2::Error::from_str(&format!(
+                    "git {} failed: {}",
+                    args[0], stderr
+                ))));
+            }
+        }
+
+        match fs::remove_dir_all(self.get_archive_cache_path(owner, repo)).await {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => return Err(e.into()),
+            _ => {}
+        }
+
+        // Repos created before a hook existed, or installed with an older
+        // copy of it, pick up the current scripts here.
+        let hooks_dir = format!("{}/hooks", repo_path);
+        if fs::try_exists(format!(
+            "{}/{}",
+            hooks_dir,
+            GitHookType::PostReceive.as_str()
+        ))
+        .await?
+        {
+            for (hook_type, script) in [
+                (GitHookType::PreReceive, PRE_RECEIVE_SCRIPT),
+                (GitHookType::PostReceive, POST_RECEIVE_SCRIPT),
+                (GitHookType::ProcReceive, PROC_RECEIVE_SCRIPT),
+            ] {
+                let hook_path = format!("{}/{}", hooks_dir, hook_type.as_str());
+                match fs::read_to_string(&hook_path).await {
+                    Ok(installed) if installed == script => continue,
+                    Err(e) if e.kind() != std::io::ErrorKind::NotFound => return Err(e.into()),
+                    _ => self.install_hook(owner, repo, hook_type, script).await?,
+                }
+            }
+        }
+
+        // Repos last pushed to before search existed are indexed here.
+        let index_path = self.get_search_index_path(owner, repo);
+        let repository = self.open_repository(owner, repo)?;
+        task::spawn_blocking(move || Self::refresh_search_index(&repository, &index_path))
+            .await??;
+
+        Ok(())
+    }
+
     async fn get_default_ref(&self, owner: &str, repo: &str) -> Result<String, GitError> {
         let repo_path = self.get_repo_path(owner, repo);
 
@@ -563,10 +1300,10 @@ impl GitClient for Git2Client {
         repo: &str,
         url: &str,
         ref_name: &str,
-        sha: &str,
+        source: &str,
     ) -> Result<(), GitError> {
         let repo_path = self.get_repo_path(owner, repo);
-        let refspec = format!("+{}:{}", sha, ref_name);
+        let refspec = format!("+{}:{}", source, ref_name);
 
         let output = tokio::process::Command::new("git")
             .arg("-C")
@@ -586,21 +1323,128 @@ impl GitClient for Git2Client {
         Ok(())
     }
 
-    async fn create_ref(
-        &self,
-        owner: &str,
-        repo: &str,
-        ref_name: &str,
-        sha: &str,
-    ) -> Result<(), GitError> {
-        let ref_name = ref_name.to_string();
+    async fn has_commit(&self, owner: &str, repo: &str, sha: &str) -> Result<bool, GitError> {
         let sha = sha.to_string();
         let repository = self.open_repository(owner, repo)?;
 
         task::spawn_blocking(move || {
             let oid = git2::Oid::from_str(&sha)?;
-            let commit = repository.find_commit(oid)?;
-            repository.reference(&ref_name, commit.id(), false, "create_ref")?;
+            match repository.find_commit(oid) {
+                Ok(_) => Ok(true),
+                Err(e) if e.code() == git2::ErrorCode::NotFound => Ok(false),
+                Err(e) => Err(e.into()),
+            }
+        })
+        .await?
+    }
+
+    async fn sync_mirror(
+        &self,
+        owner: &str,
+        repo: &str,
+        url: &str,
+        credentials: Option<&MirrorCredentials>,
+    ) -> Result<Vec<MirrorRefUpdate>, GitError> {
+        let before = self.list_mirrored_refs(owner, repo).await?;
+
+        let repo_path = self.get_repo_path(owner, repo);
+        let output = Self::mirror_command(&repo_path, credentials)?
+            .arg("fetch")
+            .arg("--prune")
+            .arg("--no-tags")
+            .arg(url)
+            .arg(format!("+{BRANCH_PREFIX}*:{BRANCH_PREFIX}*"))
+            .arg(format!("+{TAG_PREFIX}*:{TAG_PREFIX}*"))
+            .output()
+            .await?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(GitError::Git2Error(git2::Error::from_str(&format!(
+                "git fetch failed: {}",
+                stderr
+            ))));
+        }
+
+        let mut after = self.list_mirrored_refs(owner, repo).await?;
+        let mut updates = Vec::new();
+        for (ref_name, old_sha) in before {
+            let new_sha = after
+                .remove(&ref_name)
+                .unwrap_or_else(|| ZERO_SHA.to_string());
+            if new_sha != old_sha {
+                updates.push(MirrorRefUpdate {
+                    ref_name,
+                    old_sha,
+                    new_sha,
+                });
+            }
+        }
+        updates.extend(
+            after
+                .into_iter()
+                .map(|(ref_name, new_sha)| MirrorRefUpdate {
+                    ref_name,
+                    old_sha: ZERO_SHA.to_string(),
+                    new_sha,
+                }),
+        );
+        updates.sort_by(|a, b| a.ref_name.cmp(&b.ref_name));
+        Ok(updates)
+    }
+
+    async fn push_mirror(
+        &self,
+        owner: &str,
+        repo: &str,
+        url: &str,
+        credentials: Option<&MirrorCredentials>,
+    ) -> Result<(), GitError> {
+        let repo_path = self.get_repo_path(owner, repo);
+        let output = Self::mirror_command(&repo_path, credentials)?
+            .arg("push")
+            .arg("--prune")
+            .arg(url)
+            .arg(format!("+{BRANCH_PREFIX}*:{BRANCH_PREFIX}*"))
+            .arg(format!("+{TAG_PREFIX}*:{TAG_PREFIX}*"))
+            .output()
+            .await?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(GitError::Git2Error(git2::Error::from_str(&format!(
+                "git push failed: {}",
+                stderr
+            ))));
+        }
+        Ok(())
+    }
+
+    async fn delete_ref(&self, owner: &str, repo: &str, ref_name: &str) -> Result<(), GitError> {
+        let ref_name = ref_name.to_string();
+        let repository = self.open_repository(owner, repo)?;
+
+        task::spawn_blocking(move || match repository.find_reference(&ref_name) {
+            Ok(mut reference) => Ok(reference.delete()?),
+            Err(e) if e.code() == git2::ErrorCode::NotFound => Ok(()),
+            Err(e) => Err(e.into()),
+        })
+        .await?
+    }
+
+    async fn create_ref(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_name: &str,
+        sha: &str,
+    ) -> Result<(), GitError> {
+        let ref_name = ref_name.to_string();
+        let sha = sha.to_string();
+        let repository = self.open_repository(owner, repo)?;
+
+        task::spawn_blocking(move || {
+            let oid = git2::Oid::from_str(&sha)?;
+            let commit = repository.find_commit(oid)?;
+            repository.reference(&ref_name, commit.id(), false, "create_ref")?;
             Ok(())
         })
         .await?
@@ -626,6 +1470,108 @@ impl GitClient for Git2Client {
         .await?
     }
 
+    async fn list_branches(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<(String, String)>, GitError> {
+        let repository = self.open_repository(owner, repo)?;
+
+        task::spawn_blocking(move || {
+            let mut branches = Vec::new();
+            for reference in repository.references_glob(&format!("{BRANCH_PREFIX}*"))? {
+                let reference = reference?;
+                if let (Some(name), Some(oid)) = (reference.name(), reference.target()) {
+                    branches.push((name.to_string(), oid.to_string()));
+                }
+            }
+            branches.sort();
+            Ok(branches)
+        })
+        .await?
+    }
+
+    async fn list_tags(&self, owner: &str, repo: &str) -> Result<Vec<TagResponse>, GitError> {
+        let repository = self.open_repository(owner, repo)?;
+
+        task::spawn_blocking(move || {
+            let mut tags = Vec::new();
+            for reference in repository.references_glob(&format!("{TAG_PREFIX}*"))? {
+                if let Some(tag) = Self::tag_to_response(&reference?) {
+                    tags.push(tag);
+                }
+            }
+
+            let date = |tag: &TagResponse| tag.tagger.as_ref().map_or(tag.commit.date, |t| t.date);
+            tags.sort_by(|a, b| date(b).cmp(&date(a)).then_with(|| a.name.cmp(&b.name)));
+            Ok(tags)
+        })
+        .await?
+    }
+
+    async fn get_tag(
+        &self,
+        owner: &str,
+        repo: &str,
+        name: &str,
+    ) -> Result<Option<TagResponse>, GitError> {
+        let ref_name = format!("{TAG_PREFIX}{name}");
+        let repository = self.open_repository(owner, repo)?;
+
+        task::spawn_blocking(move || match repository.find_reference(&ref_name) {
+            Ok(reference) => Ok(Self::tag_to_response(&reference)),
+            Err(e) if e.code() == git2::ErrorCode::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        })
+        .await?
+    }
+
+    async fn create_tag(
+        &self,
+        owner: &str,
+        repo: &str,
+        name: &str,
+        sha: &str,
+        message: Option<&str>,
+        tagger_name: &str,
+        tagger_email: &str,
+    ) -> Result<TagResponse, GitError> {
+        let name = name.to_string();
+        let sha = sha.to_string();
+        let message = message.map(|m| format!("{}\n", m.trim_end()));
+        let tagger_name = tagger_name.to_string();
+        let tagger_email = tagger_email.to_string();
+        let repository = self.open_repository(owner, repo)?;
+
+        task::spawn_blocking(move || {
+            let commit = repository.find_commit(git2::Oid::from_str(&sha)?)?;
+            match &message {
+                Some(message) => {
+                    let tagger = git2::Signature::now(&tagger_name, &tagger_email)?;
+                    repository.tag(&name, commit.as_object(), &tagger, message, false)?;
+                }
+                None => {
+                    repository.tag_lightweight(&name, commit.as_object(), false)?;
+                }
+            }
+
+            let reference = repository.find_reference(&format!("{TAG_PREFIX}{name}"))?;
+            Self::tag_to_response(&reference).ok_or(GitError::NotFound(name))
+        })
+        .await?
+    }
+
+    async fn delete_tag(&self, owner: &str, repo: &str, name: &str) -> Result<(), GitError> {
+        let ref_name = format!("{TAG_PREFIX}{name}");
+        let repository = self.open_repository(owner, repo)?;
+
+        task::spawn_blocking(move || {
+            repository.find_reference(&ref_name)?.delete()?;
+            Ok(())
+        })
+        .await?
+    }
+
     async fn get_repo_blob(
         &self,
         owner: &str,
@@ -729,6 +1675,244 @@ impl GitClient for Git2Client {
         .await?
     }
 
+    async fn blame(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_name: &str,
+        path: &str,
+        ignore_revs_file: Option<&str>,
+    ) -> Result<RepositoryBlameResponse, GitError> {
+        let path = path.to_string();
+        let ref_name = ref_name.to_string();
+        let ignore_revs_file = ignore_revs_file.map(str::to_string);
+        let repository = self.open_repository(owner, repo)?;
+
+        let (commit_sha, ignore_revs, ref_name, path) =
+            task::spawn_blocking(move || -> Result<_, GitError> {
+                let commit = Self::resolve_ref(&repository, &ref_name)?;
+                let tree = commit.tree()?;
+                if tree.get_path(std::path::Path::new(&path))?.kind()
+                    != Some(git2::ObjectType::Blob)
+                {
+                    return Err(GitError::NotABlob(path));
+                }
+                let ignore_revs = ignore_revs_file
+                    .map(|file| Self::read_ignore_revs(&repository, &tree, &file))
+                    .unwrap_or_default();
+                Ok((commit.id().to_string(), ignore_revs, ref_name, path))
+            })
+            .await??;
+
+        let mut cmd = tokio::process::Command::new("git");
+        cmd.arg("-C")
+            .arg(self.get_repo_path(owner, repo))
+            .arg("blame")
+            .arg("--porcelain");
+        for rev in &ignore_revs {
+            cmd.arg("--ignore-rev").arg(rev);
+        }
+        let output = cmd.arg(&commit_sha).arg("--").arg(&path).output().await?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(GitError::Git2Error(git2::Error::from_str(&format!(
+                "git blame failed: {}",
+                stderr
+            ))));
+        }
+        let ranges = Self::parse_blame_porcelain(&String::from_utf8_lossy(&output.stdout));
+
+        let repository = self.open_repository(owner, repo)?;
+        task::spawn_blocking(move || {
+            let mut seen = HashSet::new();
+            let mut commits: Vec<BlameCommitResponse> = Vec::new();
+            for range in &ranges {
+                if seen.insert(range.commit_sha.as_str()) {
+                    let oid = git2::Oid::from_str(&range.commit_sha)?;
+                    commits.push((&repository.find_commit(oid)?).into());
+                }
+            }
+            Ok(RepositoryBlameResponse {
+                ref_name,
+                commit_sha,
+                path,
+                ranges,
+                commits,
+            })
+        })
+        .await?
+    }
+
+    async fn get_repo_archive(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_name: &str,
+        format: ArchiveFormat,
+    ) -> Result<RepositoryArchiveResponse, GitError> {
+        let ref_name = ref_name.to_string();
+        let repository = self.open_repository(owner, repo)?;
+
+        let (commit_sha, tree_sha) = task::spawn_blocking(move || -> Result<_, GitError> {
+            let commit = Self::resolve_ref(&repository, &ref_name)?;
+            Ok((commit.id().to_string(), commit.tree_id().to_string()))
+        })
+        .await??;
+
+        let repo_name = repo.strip_suffix(REPO_SUFFIX).unwrap_or(repo);
+        let prefix = format!("{}-{}", repo_name, &commit_sha[..7]);
+        let file_name = format!("{}.{}", prefix, format.extension());
+
+        let cache_dir = self.get_archive_cache_path(owner, repo);
+        let cache_path = format!("{}/{}-{}", cache_dir, tree_sha, file_name);
+        if !fs::try_exists(&cache_path).await? {
+            fs::create_dir_all(&cache_dir).await?;
+            // Concurrent builds of the same archive each write their own file
+            // and the last rename wins; the contents are identical.
+            let temp_path = format!("{}/{}.tmp", cache_dir, Uuid::new_v4());
+            let output = tokio::process::Command::new("git")
+                .arg("-C")
+                .arg(self.get_repo_path(owner, repo))
+                .arg("archive")
+                .arg(format!("--format={}", format.extension()))
+                .arg(format!("--prefix={}/", prefix))
+                .arg("-o")
+                .arg(&temp_path)
+                .arg(&commit_sha)
+                .output()
+                .await?;
+            if !output.status.success() {
+                let _ = fs::remove_file(&temp_path).await;
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                return Err(GitError::Git2Error(git2::Error::from_str(&format!(
+                    "git archive failed: {}",
+                    stderr
+                ))));
+            }
+            fs::rename(&temp_path, &cache_path).await?;
+
+            let (cache_dir, keep) = (cache_dir.clone(), cache_path.clone());
+            task::spawn_blocking(move || {
+                Self::evict_archives(
+                    std::path::Path::new(&cache_dir),
+                    std::path::Path::new(&keep),
+                    ARCHIVE_CACHE_MAX_BYTES,
+                )
+            })
+            .await??;
+        } else {
+            // Marks the archive recently used, for eviction.
+            let cache_path = cache_path.clone();
+            task::spawn_blocking(move || {
+                std::fs::File::options()
+                    .append(true)
+                    .open(&cache_path)?
+                    .set_modified(std::time::SystemTime::now())
+            })
+            .await??;
+        }
+
+        let file = fs::File::open(&cache_path).await?;
+        let size = file.metadata().await?.len();
+        let body = stream::unfold(Some(file), |file| async move {
+            let mut file = file?;
+            let mut buf = vec![0u8; 65536];
+            match file.read(&mut buf).await {
+                Ok(0) => None,
+                Ok(n) => {
+                    buf.truncate(n);
+                    Some((Ok(buf), Some(file)))
+                }
+                Err(e) => Some((Err(e), None)),
+            }
+        });
+
+        Ok(RepositoryArchiveResponse {
+            commit_sha,
+            tree_sha,
+            file_name,
+            format,
+            size,
+            body: Box::pin(body),
+        })
+    }
+
+    async fn index_code(&self, owner: &str, repo: &str) -> Result<Option<String>, GitError> {
+        let index_path = self.get_search_index_path(owner, repo);
+        let repository = self.open_repository(owner, repo)?;
+
+        task::spawn_blocking(move || Self::refresh_search_index(&repository, &index_path)).await?
+    }
+
+    async fn search_code(
+        &self,
+        owner: &str,
+        repo: &str,
+        query: CodeSearchQuery,
+        limit: usize,
+    ) -> Result<CodeSearchResponse, GitError> {
+        let index_path = self.get_search_index_path(owner, repo);
+        let repository = self.open_repository(owner, repo)?;
+
+        task::spawn_blocking(move || {
+            let Some(index) = SearchIndexReader::open(std::path::Path::new(&index_path))? else {
+                return Ok(CodeSearchResponse {
+                    commit_sha: None,
+                    files: Vec::new(),
+                    truncated: false,
+                });
+            };
+
+            let all_files = || (0..index.file_count()).collect();
+            let candidates: Vec<u32> = match query.literal.as_deref() {
+                Some(literal) => index.candidates(literal)?.unwrap_or_else(all_files),
+                None => all_files(),
+            };
+
+            let mut files = Vec::new();
+            let mut truncated = false;
+            for id in candidates {
+                let file = index.file(id)?;
+                if let Some(glob) = &query.path_glob
+                    && !gitdot_config::glob::matches(glob, &file.path)
+                {
+                    continue;
+                }
+                let language = language_for_path(&file.path);
+                if let Some(wanted) = &query.language
+                    && language != Some(wanted.as_str())
+                {
+                    continue;
+                }
+
+                let blob = repository.find_blob(git2::Oid::from_str(&file.blob_sha)?)?;
+                let content = String::from_utf8_lossy(blob.content());
+                let Some(result) = Self::search_file(&content, &query) else {
+                    continue;
+                };
+                if files.len() == limit {
+                    truncated = true;
+                    break;
+                }
+                let (match_count, matches) = result;
+                files.push(CodeSearchFileResponse {
+                    path: file.path,
+                    blob_sha: file.blob_sha,
+                    language: language.map(str::to_string),
+                    match_count,
+                    matches,
+                });
+            }
+
+            Ok(CodeSearchResponse {
+                commit_sha: Some(index.commit_sha().to_string()),
+                files,
+                truncated,
+            })
+        })
+        .await?
+    }
+
     async fn get_repo_commit(
         &self,
         owner: &str,
@@ -835,6 +2019,116 @@ impl GitClient for Git2Client {
         .await?
     }
 
+    async fn get_commit_signature(
+        &self,
+        owner: &str,
+        repo: &str,
+        sha: &str,
+    ) -> Result<Option<(String, Vec<u8>)>, GitError> {
+        let sha = sha.to_string();
+        let repository = self.open_repository(owner, repo)?;
+
+        task::spawn_blocking(move || {
+            let oid = repository.find_commit(git2::Oid::from_str(&sha)?)?.id();
+            match repository.extract_signature(&oid, None) {
+                Ok((signature, payload)) => Ok(Some((
+                    String::from_utf8_lossy(&signature).into_owned(),
+                    payload.to_vec(),
+                ))),
+                Err(e) if e.code() == git2::ErrorCode::NotFound => Ok(None),
+                Err(e) => Err(e.into()),
+            }
+        })
+        .await?
+    }
+
+    async fn merge_base(
+        &self,
+        owner: &str,
+        repo: &str,
+        left_sha: &str,
+        right_sha: &str,
+    ) -> Result<Option<String>, GitError> {
+        let left_sha = left_sha.to_string();
+        let right_sha = right_sha.to_string();
+        let repository = self.open_repository(owner, repo)?;
+
+        task::spawn_blocking(move || {
+            let left_oid = git2::Oid::from_str(&left_sha)?;
+            let right_oid = git2::Oid::from_str(&right_sha)?;
+            match repository.merge_base(left_oid, right_oid) {
+                Ok(oid) => Ok(Some(oid.to_string())),
+                Err(e) if e.code() == git2::ErrorCode::NotFound => Ok(None),
+                Err(e) => Err(e.into()),
+            }
+        })
+        .await?
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    async fn get_repo_diff(
+        &self,
+        owner: &str,
+        repo: &str,
+        left_sha: Option<&str>,
+        right_sha: &str,
+        options: DiffOptions,
+        offset: usize,
+        limit: usize,
+    ) -> Result<RepositoryDiffResponse, GitError> {
+        let left_sha = left_sha.map(str::to_string);
+        let right_sha = right_sha.to_string();
+        let repository = self.open_repository(owner, repo)?;
+
+        task::spawn_blocking(move || {
+            let left_tree = match left_sha {
+                None => {
+                    let empty_oid = repository.treebuilder(None)?.write()?;
+                    repository.find_tree(empty_oid)?
+                }
+                Some(ref sha) => Self::resolve_ref(&repository, sha)?.tree()?,
+            };
+            let right_tree = Self::resolve_ref(&repository, &right_sha)?.tree()?;
+
+            let mut diff_opts = git2::DiffOptions::new();
+            match options.ignore_whitespace {
+                IgnoreWhitespace::None => {}
+                IgnoreWhitespace::All => {
+                    diff_opts.ignore_whitespace(true);
+                }
+                IgnoreWhitespace::Change => {
+                    diff_opts.ignore_whitespace_change(true);
+                }
+                IgnoreWhitespace::Eol => {
+                    diff_opts.ignore_whitespace_eol(true);
+                }
+            }
+            let mut diff = repository.diff_tree_to_tree(
+                Some(&left_tree),
+                Some(&right_tree),
+                Some(&mut diff_opts),
+            )?;
+            if options.detect_renames {
+                let mut find_opts = git2::DiffFindOptions::new();
+                find_opts.renames(true);
+                diff.find_similar(Some(&mut find_opts))?;
+            }
+
+            let total_files = diff.deltas().len();
+            let end = offset.saturating_add(limit).min(total_files);
+            let mut files = Vec::new();
+            for i in offset.min(end)..end {
+                let Some(patch) = git2::Patch::from_diff(&diff, i)? else {
+                    continue;
+                };
+                files.push(Self::patch_to_response(&patch)?);
+            }
+
+            Ok(RepositoryDiffResponse { files, total_files })
+        })
+        .await?
+    }
+
     async fn resolve_ref_sha(
         &self,
         owner: &str,
@@ -1003,15 +2297,17 @@ impl GitClient for Git2Client {
     ) -> Result<(), GitError> {
         let repo_path = self.get_repo_path(owner, repo);
         let hook_path = format!("{}/hooks/{}", repo_path, hook_type.as_str());
-        fs::write(&hook_path, hook_script).await?;
+        let temp_path = format!("{}.{}.tmp", hook_path, Uuid::new_v4());
+        fs::write(&temp_path, hook_script).await?;
 
         #[cfg(unix)]
         {
             use std::os::unix::fs::PermissionsExt;
             let perms = std::fs::Permissions::from_mode(0o755);
-            fs::set_permissions(&hook_path, perms).await?;
+            fs::set_permissions(&temp_path, perms).await?;
         }
 
+        fs::rename(&temp_path, &hook_path).await?;
         Ok(())
     }
 
@@ -1033,10 +2329,28 @@ impl GitClient for Git2Client {
 
 #[cfg(test)]
 mod tests {
+    use chrono::Utc;
+    use futures::StreamExt;
     use tempfile::tempdir;
-    use tokio::fs;
-
-    use super::{Git2Client, GitClient};
+    use tokio::{fs, io::AsyncWriteExt};
+    use uuid::Uuid;
+
+    use super::{ARCHIVE_CACHE_DIR, Git2Client, GitClient};
+    use crate::{
+        client::{SignatureClient, SignatureClientImpl},
+        dto::{
+            ArchiveFormat, BlameRange, DiffOptions, FileDiffStatus, IgnoreWhitespace,
+            MirrorCredentials, MirrorRefUpdate, SearchCodeRequest, SshPublicKey,
+        },
+        model::{SigningKey, SigningKeyType},
+        util::{
+            git::{
+                GitHookType, POST_RECEIVE_SCRIPT, PRE_RECEIVE_SCRIPT, PROC_RECEIVE_SCRIPT, ZERO_SHA,
+            },
+            search::{SEARCH_INDEX_DIR, SEARCH_INDEX_FILE},
+            signature::signature_key_id,
+        },
+    };
 
     #[tokio::test]
     async fn rename_owner_moves_existing_directory() {
@@ -1071,4 +2385,761 @@ mod tests {
         assert!(!root.path().join("ghost").exists());
         assert!(!root.path().join("ghost2").exists());
     }
+
+    #[tokio::test]
+    async fn maintain_repo_packs_and_indexes_pushed_objects() {
+        let root = tempdir().unwrap();
+        let client = Git2Client::new(root.path().to_str().unwrap().to_string());
+        client.create_repo("alice", "demo").await.unwrap();
+
+        let work = tempdir().unwrap();
+        let git = |args: &[&str]| {
+            let output = std::process::Command::new("git")
+                .args(args)
+                .current_dir(work.path())
+                .env("GIT_AUTHOR_NAME", "alice")
+                .env("GIT_AUTHOR_EMAIL", "alice@example.com")
+                .env("GIT_COMMITTER_NAME", "alice")
+                .env("GIT_COMMITTER_EMAIL", "alice@example.com")
+                .output()
+                .unwrap();
+            assert!(output.status.success(), "git {args:?}");
+        };
+        git(&["init", "-b", "main"]);
+        fs::write(work.path().join("README.md"), "hello\n")
+            .await
+            .unwrap();
+        git(&["add", "."]);
+        git(&["commit", "-m", "first"]);
+        let bare = root.path().join("alice").join("demo.git");
+        git(&["push", bare.to_str().unwrap(), "main"]);
+
+        client.maintain_repo("alice", "demo").await.unwrap();
+
+        let objects = bare.join("objects");
+        assert!(objects.join("pack").join("multi-pack-index").exists());
+        assert!(
+            objects
+                .join("info")
+                .join("commit-graphs")
+                .join("commit-graph-chain")
+                .exists()
+        );
+    }
+
+    #[tokio::test]
+    async fn maintain_repo_backfills_hooks_only_where_hooks_are_installed() {
+        let root = tempdir().unwrap();
+        let client = Git2Client::new(root.path().to_str().unwrap().to_string());
+        for repo in ["demo", "mirror"] {
+            client.create_repo("alice", repo).await.unwrap();
+            client.empty_hooks("alice", repo).await.unwrap();
+        }
+        client
+            .install_hook("alice", "demo", GitHookType::PostReceive, "#!/bin/sh\n")
+            .await
+            .unwrap();
+
+        client.maintain_repo("alice", "demo").await.unwrap();
+        client.maintain_repo("alice", "mirror").await.unwrap();
+
+        let hooks = root.path().join("alice").join("demo.git").join("hooks");
+        for (hook, script) in [
+            ("pre-receive", PRE_RECEIVE_SCRIPT),
+            ("post-receive", POST_RECEIVE_SCRIPT),
+            ("proc-receive", PROC_RECEIVE_SCRIPT),
+        ] {
+            assert_eq!(fs::read_to_string(hooks.join(hook)).await.unwrap(), script);
+        }
+        let mirror_hooks = root.path().join("alice").join("mirror.git").join("hooks");
+        assert!(!mirror_hooks.join("pre-receive").exists());
+    }
+
+    #[tokio::test]
+    #[ignore = "requires ssh-keygen"]
+    async fn get_commit_signature_returns_the_signed_payload() {
+        let root = tempdir().unwrap();
+        let client = Git2Client::new(root.path().to_str().unwrap().to_string());
+        client.create_repo("alice", "demo").await.unwrap();
+
+        let work = tempdir().unwrap();
+        let key_path = work.path().join("id_ed25519");
+        let output = std::process::Command::new("ssh-keygen")
+            .args(["-q", "-t", "ed25519", "-N", "", "-f"])
+            .arg(&key_path)
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "ssh-keygen");
+
+        let git = |args: &[&str]| {
+            let output = std::process::Command::new("git")
+                .args(args)
+                .current_dir(work.path())
+                .env("GIT_AUTHOR_NAME", "alice")
+                .env("GIT_AUTHOR_EMAIL", "alice@example.com")
+                .env("GIT_COMMITTER_NAME", "alice")
+                .env("GIT_COMMITTER_EMAIL", "alice@example.com")
+                .output()
+                .unwrap();
+            assert!(output.status.success(), "git {args:?}");
+            String::from_utf8(output.stdout).unwrap().trim().to_string()
+        };
+        git(&["init", "-b", "main"]);
+        git(&["commit", "--allow-empty", "-m", "unsigned"]);
+        let unsigned = git(&["rev-parse", "HEAD"]);
+        git(&[
+            "-c",
+            "gpg.format=ssh",
+            "-c",
+            &format!("user.signingkey={}", key_path.display()),
+            "commit",
+            "--allow-empty",
+            "-S",
+            "-m",
+            "signed",
+        ]);
+        let signed = git(&["rev-parse", "HEAD"]);
+        let bare = root.path().join("alice").join("demo.git");
+        git(&["push", bare.to_str().unwrap(), "main"]);
+
+        assert!(
+            client
+                .get_commit_signature("alice", "demo", &unsigned)
+                .await
+                .unwrap()
+                .is_none()
+        );
+
+        let (signature, payload) = client
+            .get_commit_signature("alice", "demo", &signed)
+            .await
+            .unwrap()
+            .expect("signed commit");
+        let public_key = std::fs::read_to_string(key_path.with_extension("pub")).unwrap();
+        let key = SshPublicKey::parse(&public_key, "public key").unwrap();
+        assert_eq!(
+            signature_key_id(&signature),
+            Some((SigningKeyType::Ssh, key.fingerprint()))
+        );
+
+        // The payload is exactly what the signature covers.
+        let signing_key = SigningKey {
+            id: Uuid::new_v4(),
+            user_id: Uuid::new_v4(),
+            name: "laptop".to_string(),
+            key_type: SigningKeyType::Ssh,
+            public_key: format!("{} {}", key.key_type(), key.key()),
+            fingerprint: key.fingerprint(),
+            key_ids: vec![key.fingerprint()],
+            created_at: Utc::now(),
+        };
+        assert!(
+            SignatureClientImpl::new()
+                .verify_signature(&signing_key, &signature, &payload)
+                .await
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn sync_mirror_copies_branches_and_tags_and_prunes() {
+        let root = tempdir().unwrap();
+        let client = Git2Client::new(root.path().to_str().unwrap().to_string());
+        client.create_repo("origin", "demo").await.unwrap();
+        client.create_repo("alice", "demo").await.unwrap();
+
+        let work = tempdir().unwrap();
+        let git = |args: &[&str]| {
+            let output = std::process::Command::new("git")
+                .args(args)
+                .current_dir(work.path())
+                .env("GIT_AUTHOR_NAME", "alice")
+                .env("GIT_AUTHOR_EMAIL", "alice@example.com")
+                .env("GIT_COMMITTER_NAME", "alice")
+                .env("GIT_COMMITTER_EMAIL", "alice@example.com")
+                .output()
+                .unwrap();
+            assert!(output.status.success(), "git {args:?}");
+            String::from_utf8(output.stdout).unwrap().trim().to_string()
+        };
+        let origin = root.path().join("origin").join("demo.git");
+        let origin = origin.to_str().unwrap();
+        git(&["init", "-b", "main"]);
+        git(&["commit", "--allow-empty", "-m", "first"]);
+        let first = git(&["rev-parse", "HEAD"]);
+        git(&["tag", "v1"]);
+        git(&["push", origin, "main", "main:refs/heads/feature", "v1"]);
+
+        let updates = client
+            .sync_mirror("alice", "demo", origin, None)
+            .await
+            .unwrap();
+        let names: Vec<_> = updates.iter().map(|u| u.ref_name.as_str()).collect();
+        assert_eq!(
+            names,
+            ["refs/heads/feature", "refs/heads/main", "refs/tags/v1"]
+        );
+        assert!(updates.iter().all(|u| u.old_sha == ZERO_SHA));
+
+        git(&["commit", "--allow-empty", "-m", "second"]);
+        let second = git(&["rev-parse", "HEAD"]);
+        git(&["push", origin, "main", ":refs/heads/feature"]);
+
+        let updates = client
+            .sync_mirror("alice", "demo", origin, None)
+            .await
+            .unwrap();
+        assert_eq!(
+            updates,
+            [
+                MirrorRefUpdate {
+                    ref_name: "refs/heads/feature".to_string(),
+                    old_sha: first.clone(),
+                    new_sha: ZERO_SHA.to_string(),
+                },
+                MirrorRefUpdate {
+                    ref_name: "refs/heads/main".to_string(),
+                    old_sha: first,
+                    new_sha: second.clone(),
+                },
+            ]
+        );
+        assert!(
+            client
+                .sync_mirror("alice", "demo", origin, None)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+        assert_eq!(
+            client.list_branches("alice", "demo").await.unwrap(),
+            [("refs/heads/main".to_string(), second)]
+        );
+
+        client
+            .delete_ref("alice", "demo", "refs/tags/v1")
+            .await
+            .unwrap();
+        client
+            .delete_ref("alice", "demo", "refs/tags/v1")
+            .await
+            .unwrap();
+        assert!(
+            client
+                .get_tag("alice", "demo", "v1")
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn mirror_command_answers_credentials_from_its_environment() {
+        let dir = tempdir().unwrap();
+        let credentials = MirrorCredentials {
+            username: "bot".to_string(),
+            password: "s3cret p@ss".to_string(),
+        };
+        let mut command =
+            Git2Client::mirror_command(dir.path().to_str().unwrap(), Some(&credentials)).unwrap();
+        assert!(
+            !command
+                .as_std()
+                .get_args()
+                .any(|arg| arg.to_string_lossy().contains("s3cret"))
+        );
+
+        let mut child = command
+            .args(["credential", "fill"])
+            .stdin(std::process::Stdio::piped())
+            .stdout(std::process::Stdio::piped())
+            .spawn()
+            .unwrap();
+        child
+            .stdin
+            .take()
+            .unwrap()
+            .write_all(b"protocol=https\nhost=example.com\n\n")
+            .await
+            .unwrap();
+        let output = child.wait_with_output().await.unwrap();
+        assert!(output.status.success());
+        let answer = String::from_utf8(output.stdout).unwrap();
+        assert!(answer.contains("username=bot\n"), "{answer}");
+        assert!(answer.contains("password=s3cret p@ss\n"), "{answer}");
+
+        let injected = MirrorCredentials {
+            username: "bot".to_string(),
+            password: "token\nhost=evil.example".to_string(),
+        };
+        assert!(Git2Client::mirror_command("/tmp", Some(&injected)).is_err());
+    }
+
+    #[tokio::test]
+    async fn push_mirror_replicates_branches_and_tags_and_prunes() {
+        let root = tempdir().unwrap();
+        let client = Git2Client::new(root.path().to_str().unwrap().to_string());
+        client.create_repo("alice", "demo").await.unwrap();
+        client.create_repo("remote", "demo").await.unwrap();
+
+        let work = tempdir().unwrap();
+        let git = |args: &[&str]| {
+            let output = std::process::Command::new("git")
+                .args(args)
+                .current_dir(work.path())
+                .env("GIT_AUTHOR_NAME", "alice")
+                .env("GIT_AUTHOR_EMAIL", "alice@example.com")
+                .env("GIT_COMMITTER_NAME", "alice")
+                .env("GIT_COMMITTER_EMAIL", "alice@example.com")
+                .output()
+                .unwrap();
+            assert!(output.status.success(), "git {args:?}");
+        };
+        let local = root.path().join("alice").join("demo.git");
+        let local = local.to_str().unwrap();
+        let remote = root.path().join("remote").join("demo.git");
+        let remote = remote.to_str().unwrap();
+        git(&["init", "-b", "main"]);
+        git(&["commit", "--allow-empty", "-m", "first"]);
+        git(&["tag", "v1"]);
+        git(&["push", local, "main", "v1"]);
+        git(&["push", remote, "main:refs/heads/stale"]);
+
+        client
+            .push_mirror("alice", "demo", remote, None)
+            .await
+            .unwrap();
+
+        let updates = client
+            .sync_mirror("alice", "demo", remote, None)
+            .await
+            .unwrap();
+        assert!(updates.is_empty());
+        let refs = client.list_mirrored_refs("remote", "demo").await.unwrap();
+        let mut names: Vec<_> = refs.keys().map(String::as_str).collect();
+        names.sort();
+        assert_eq!(names, ["refs/heads/main", "refs/tags/v1"]);
+    }
+
+    #[tokio::test]
+    async fn get_repo_archive_prefixes_and_caches_by_tree() {
+        let root = tempdir().unwrap();
+        let client = Git2Client::new(root.path().to_str().unwrap().to_string());
+        client.create_repo("alice", "demo").await.unwrap();
+
+        let work = tempdir().unwrap();
+        let git = |args: &[&str]| {
+            let output = std::process::Command::new("git")
+                .args(args)
+                .current_dir(work.path())
+                .env("GIT_AUTHOR_NAME", "alice")
+                .env("GIT_AUTHOR_EMAIL", "alice@example.com")
+                .env("GIT_COMMITTER_NAME", "alice")
+                .env("GIT_COMMITTER_EMAIL", "alice@example.com")
+                .output()
+                .unwrap();
+            assert!(output.status.success(), "git {args:?}");
+            String::from_utf8(output.stdout).unwrap()
+        };
+        git(&["init", "-b", "main"]);
+        fs::create_dir(work.path().join("src")).await.unwrap();
+        fs::write(work.path().join("src").join("lib.rs"), "fn main() {}\n")
+            .await
+            .unwrap();
+        git(&["add", "."]);
+        git(&["commit", "-m", "first"]);
+        let bare = root.path().join("alice").join("demo.git");
+        git(&["push", bare.to_str().unwrap(), "main"]);
+        let sha = git(&["rev-parse", "HEAD"]).trim().to_string();
+        let tree_sha = git(&["rev-parse", "HEAD^{tree}"]).trim().to_string();
+
+        let archive = client
+            .get_repo_archive("alice", "demo", "main", ArchiveFormat::TarGz)
+            .await
+            .unwrap();
+        assert_eq!(archive.commit_sha, sha);
+        assert_eq!(archive.tree_sha, tree_sha);
+        assert_eq!(archive.file_name, format!("demo-{}.tar.gz", &sha[..7]));
+        let bytes: Vec<u8> = archive.body.map(|chunk| chunk.unwrap()).concat().await;
+        assert_eq!(bytes.len() as u64, archive.size);
+
+        let tarball = work.path().join("demo.tar.gz");
+        fs::write(&tarball, &bytes).await.unwrap();
+        let output = std::process::Command::new("tar")
+            .arg("tzf")
+            .arg(&tarball)
+            .output()
+            .unwrap();
+        let entries = String::from_utf8(output.stdout).unwrap();
+        assert!(entries.contains(&format!("demo-{}/src/lib.rs", &sha[..7])));
+
+        let cached =
+            bare.join(ARCHIVE_CACHE_DIR)
+                .join(format!("{}-demo-{}.tar.gz", tree_sha, &sha[..7]));
+        assert!(cached.exists());
+
+        let zip = client
+            .get_repo_archive("alice", "demo", &sha, ArchiveFormat::Zip)
+            .await
+            .unwrap();
+        let bytes: Vec<u8> = zip.body.map(|chunk| chunk.unwrap()).concat().await;
+        assert!(bytes.starts_with(b"PK"));
+
+        client.maintain_repo("alice", "demo").await.unwrap();
+        assert!(!bare.join(ARCHIVE_CACHE_DIR).exists());
+    }
+
+    #[test]
+    fn evict_archives_drops_least_recently_used_until_under_cap() {
+        let dir = tempdir().unwrap();
+        let now = std::time::SystemTime::now();
+        let write = |name: &str, len: usize, age_secs: u64| {
+            let path = dir.path().join(name);
+            std::fs::write(&path, vec![0; len]).unwrap();
+            std::fs::File::options()
+                .append(true)
+                .open(&path)
+                .unwrap()
+                .set_modified(now - std::time::Duration::from_secs(age_secs))
+                .unwrap();
+            path
+        };
+        let oldest = write("a.tar.gz", 40, 300);
+        let recent = write("b.tar.gz", 40, 100);
+        let building = write("c.tmp", 40, 400);
+        let new = write("d.zip", 40, 0);
+
+        Git2Client::evict_archives(dir.path(), &new, 100).unwrap();
+        assert!(!oldest.exists());
+        assert!(recent.exists() && building.exists() && new.exists());
+
+        Git2Client::evict_archives(dir.path(), &new, 10).unwrap();
+        assert!(!recent.exists());
+        assert!(new.exists());
+    }
+
+    #[test]
+    fn parse_blame_porcelain_merges_adjacent_groups() {
+        let a = "a".repeat(40);
+        let b = "b".repeat(40);
+        let output = format!(
+            "{a} 1 1 2\nauthor alice\nsummary first\n\tone\n{a} 2 2\n\ttwo\n\
+             {a} 5 3 1\n\tthree\n{b} 1 4 1\nauthor bob\n\tfour\n"
+        );
+
+        let ranges = Git2Client::parse_blame_porcelain(&output);
+
+        assert_eq!(
+            ranges,
+            [
+                BlameRange {
+                    start_line: 1,
+                    line_count: 3,
+                    commit_sha: a
+                },
+                BlameRange {
+                    start_line: 4,
+                    line_count: 1,
+                    commit_sha: b
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn blame_attributes_lines_and_skips_ignored_revs() {
+        let root = tempdir().unwrap();
+        let client = Git2Client::new(root.path().to_str().unwrap().to_string());
+        client.create_repo("alice", "demo").await.unwrap();
+
+        let work = tempdir().unwrap();
+        let git = |args: &[&str]| {
+            let output = std::process::Command::new("git")
+                .args(args)
+                .current_dir(work.path())
+                .env("GIT_AUTHOR_NAME", "alice")
+                .env("GIT_AUTHOR_EMAIL", "alice@example.com")
+                .env("GIT_COMMITTER_NAME", "alice")
+                .env("GIT_COMMITTER_EMAIL", "alice@example.com")
+                .output()
+                .unwrap();
+            assert!(output.status.success(), "git {args:?}");
+            String::from_utf8(output.stdout).unwrap().trim().to_string()
+        };
+        git(&["init", "-b", "main"]);
+        fs::write(work.path().join("lib.rs"), "fn a() {}\nfn b() {}\n")
+            .await
+            .unwrap();
+        git(&["add", "."]);
+        git(&["commit", "-m", "first"]);
+        let first = git(&["rev-parse", "HEAD"]);
+        fs::write(
+            work.path().join("lib.rs"),
+            "fn a() {}\nfn b() { }\nfn c() {}\n",
+        )
+        .await
+        .unwrap();
+        git(&["commit", "-am", "reformat"]);
+        let second = git(&["rev-parse", "HEAD"]);
+        fs::write(
+            work.path().join(".git-blame-ignore-revs"),
+            format!("# formatting\n{second}\nnot-a-rev\n"),
+        )
+        .await
+        .unwrap();
+        git(&["add", "."]);
+        git(&["commit", "-m", "ignore reformat"]);
+        let bare = root.path().join("alice").join("demo.git");
+        git(&["push", bare.to_str().unwrap(), "main"]);
+
+        let blame = client
+            .blame("alice", "demo", "main", "lib.rs", None)
+            .await
+            .unwrap();
+        let shas: Vec<(u32, u32, &str)> = blame
+            .ranges
+            .iter()
+            .map(|r| (r.start_line, r.line_count, r.commit_sha.as_str()))
+            .collect();
+        assert_eq!(shas, [(1, 1, first.as_str()), (2, 2, second.as_str())]);
+        assert_eq!(blame.commits.len(), 2);
+        assert_eq!(blame.commits[1].message, "reformat\n");
+
+        let blame = client
+            .blame(
+                "alice",
+                "demo",
+                "main",
+                "lib.rs",
+                Some(".git-blame-ignore-revs"),
+            )
+            .await
+            .unwrap();
+        assert_eq!(blame.ranges[0].commit_sha, first);
+        assert_eq!(blame.ranges[0].start_line, 1);
+        assert!(blame.ranges[0].line_count >= 2);
+
+        let err = client
+            .blame("alice", "demo", "main", "missing.rs", None)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, crate::error::GitError::NotFound(_)));
+    }
+
+    #[tokio::test]
+    async fn get_repo_diff_detects_renames_and_ignores_whitespace() {
+        let root = tempdir().unwrap();
+        let client = Git2Client::new(root.path().to_str().unwrap().to_string());
+        client.create_repo("alice", "demo").await.unwrap();
+
+        let work = tempdir().unwrap();
+        let git = |args: &[&str]| {
+            let output = std::process::Command::new("git")
+                .args(args)
+                .current_dir(work.path())
+                .env("GIT_AUTHOR_NAME", "alice")
+                .env("GIT_AUTHOR_EMAIL", "alice@example.com")
+                .env("GIT_COMMITTER_NAME", "alice")
+                .env("GIT_COMMITTER_EMAIL", "alice@example.com")
+                .output()
+                .unwrap();
+            assert!(output.status.success(), "git {args:?}");
+            String::from_utf8(output.stdout).unwrap().trim().to_string()
+        };
+        let body = "line one\nline two\nline three\nline four\nline five\n";
+        git(&["init", "-b", "main"]);
+        fs::write(work.path().join("old.txt"), body).await.unwrap();
+        fs::write(work.path().join("lib.rs"), "fn a() {}\n")
+            .await
+            .unwrap();
+        git(&["add", "."]);
+        git(&["commit", "-m", "base"]);
+        let base = git(&["rev-parse", "HEAD"]);
+        git(&["checkout", "-b", "feature"]);
+        git(&["mv", "old.txt", "new.txt"]);
+        fs::write(work.path().join("lib.rs"), "fn a()  {}\n")
+            .await
+            .unwrap();
+        git(&["commit", "-am", "rename and reformat"]);
+        let head = git(&["rev-parse", "HEAD"]);
+        git(&["checkout", "main"]);
+        fs::write(work.path().join("main.txt"), "main only\n")
+            .await
+            .unwrap();
+        git(&["add", "."]);
+        git(&["commit", "-m", "diverge"]);
+        let main = git(&["rev-parse", "HEAD"]);
+        let bare = root.path().join("alice").join("demo.git");
+        git(&["push", bare.to_str().unwrap(), "main", "feature"]);
+
+        let merge_base = client
+            .merge_base("alice", "demo", &main, &head)
+            .await
+            .unwrap();
+        assert_eq!(merge_base.as_deref(), Some(base.as_str()));
+
+        let options = DiffOptions {
+            ignore_whitespace: IgnoreWhitespace::None,
+            detect_renames: true,
+        };
+        let diff = client
+            .get_repo_diff("alice", "demo", Some(&base), &head, options, 0, 10)
+            .await
+            .unwrap();
+        assert_eq!(diff.total_files, 2);
+        let lib = diff.files.iter().find(|f| f.path == "lib.rs").unwrap();
+        assert_eq!(lib.status, FileDiffStatus::Modified);
+        assert_eq!((lib.lines_added, lib.lines_removed), (1, 1));
+        let origins: Vec<char> = lib.hunks[0].lines.iter().map(|l| l.origin).collect();
+        assert_eq!(origins, ['-', '+']);
+        let renamed = diff.files.iter().find(|f| f.path == "new.txt").unwrap();
+        assert_eq!(renamed.status, FileDiffStatus::Renamed);
+        assert_eq!(renamed.old_path, "old.txt");
+
+        let options = DiffOptions {
+            ignore_whitespace: IgnoreWhitespace::Change,
+            detect_renames: false,
+        };
+        let diff = client
+            .get_repo_diff("alice", "demo", Some(&base), &head, options, 0, 10)
+            .await
+            .unwrap();
+        assert_eq!(diff.total_files, 3);
+        let lib = diff.files.iter().find(|f| f.path == "lib.rs").unwrap();
+        assert!(lib.hunks.is_empty());
+
+        let page = client
+            .get_repo_diff("alice", "demo", Some(&base), &head, options, 2, 10)
+            .await
+            .unwrap();
+        assert_eq!(page.total_files, 3);
+        assert_eq!(page.files.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn search_code_filters_matches_of_the_last_indexed_commit() {
+        let root = tempdir().unwrap();
+        let client = Git2Client::new(root.path().to_str().unwrap().to_string());
+        client.create_repo("alice", "demo").await.unwrap();
+        let query = |q: &str, regex: bool, path: Option<&str>, language: Option<&str>| {
+            SearchCodeRequest::new(
+                "demo",
+                "alice",
+                q,
+                Some(regex),
+                None,
+                path.map(str::to_string),
+                language.map(str::to_string),
+                Some(1),
+                None,
+            )
+            .unwrap()
+            .query
+        };
+
+        let empty = client
+            .search_code("alice", "demo", query("config", false, None, None), 10)
+            .await
+            .unwrap();
+        assert!(empty.commit_sha.is_none());
+
+        let work = tempdir().unwrap();
+        let git = |args: &[&str]| {
+            let output = std::process::Command::new("git")
+                .args(args)
+                .current_dir(work.path())
+                .env("GIT_AUTHOR_NAME", "alice")
+                .env("GIT_AUTHOR_EMAIL", "alice@example.com")
+                .env("GIT_COMMITTER_NAME", "alice")
+                .env("GIT_COMMITTER_EMAIL", "alice@example.com")
+                .output()
+                .unwrap();
+            assert!(output.status.success(), "git {args:?}");
+            String::from_utf8(output.stdout).unwrap().trim().to_string()
+        };
+        git(&["init", "-b", "main"]);
+        fs::create_dir_all(work.path().join("src")).await.unwrap();
+        fs::write(
+            work.path().join("src/lib.rs"),
+            "use std::fs;\n\nfn load_config() {}\n\nfn main() { load_config(); }\n",
+        )
+        .await
+        .unwrap();
+        fs::write(work.path().join("README.md"), "Set the Config path.\n")
+            .await
+            .unwrap();
+        fs::write(work.path().join("logo.bin"), b"config\0\x01")
+            .await
+            .unwrap();
+        git(&["add", "."]);
+        git(&["commit", "-m", "first"]);
+        let bare = root.path().join("alice").join("demo.git");
+        git(&["push", bare.to_str().unwrap(), "main"]);
+
+        // Searches never build the index themselves.
+        let unindexed = client
+            .search_code("alice", "demo", query("config", false, None, None), 10)
+            .await
+            .unwrap();
+        assert!(unindexed.commit_sha.is_none());
+
+        let first = client.index_code("alice", "demo").await.unwrap().unwrap();
+        assert!(bare.join(SEARCH_INDEX_DIR).join(SEARCH_INDEX_FILE).exists());
+
+        let result = client
+            .search_code("alice", "demo", query("config", false, None, None), 10)
+            .await
+            .unwrap();
+        assert_eq!(result.commit_sha.as_deref(), Some(first.as_str()));
+        let paths: Vec<&str> = result.files.iter().map(|f| f.path.as_str()).collect();
+        assert_eq!(paths, ["README.md", "src/lib.rs"]);
+        let lib = &result.files[1];
+        assert_eq!(lib.language.as_deref(), Some("rust"));
+        assert_eq!(lib.match_count, 2);
+        assert_eq!(lib.matches[0].line_number, 3);
+        assert_eq!(lib.matches[0].before, [""]);
+        assert_eq!(lib.matches[0].ranges[0].start, 8);
+
+        let result = client
+            .search_code("alice", "demo", query("config", false, None, None), 1)
+            .await
+            .unwrap();
+        assert_eq!(result.files.len(), 1);
+        assert!(result.truncated);
+
+        let result = client
+            .search_code(
+                "alice",
+                "demo",
+                query(r"fn \w+\(\)", true, Some("src/**"), Some("rust")),
+                10,
+            )
+            .await
+            .unwrap();
+        assert_eq!(result.files.len(), 1);
+        assert_eq!(result.files[0].match_count, 2);
+
+        fs::write(work.path().join("README.md"), "Nothing to see.\n")
+            .await
+            .unwrap();
+        git(&["commit", "-am", "second"]);
+        git(&["push", bare.to_str().unwrap(), "main"]);
+        let readme_query = || query("config", false, Some("*.md"), None);
+
+        // The last good index is served until it is rebuilt.
+        let result = client
+            .search_code("alice", "demo", readme_query(), 10)
+            .await
+            .unwrap();
+        assert_eq!(result.commit_sha.as_deref(), Some(first.as_str()));
+        assert_eq!(result.files.len(), 1);
+
+        client.maintain_repo("alice", "demo").await.unwrap();
+        let result = client
+            .search_code("alice", "demo", readme_query(), 10)
+            .await
+            .unwrap();
+        assert_ne!(result.commit_sha.as_deref(), Some(first.as_str()));
+        assert!(result.files.is_empty());
+    }
 }
@@ -26,6 +26,8 @@ const GIT_HTTP_BACKEND_TIMEOUT: Duration = Duration::from_secs(600);
 pub trait GitHttpClient: Send + Sync + Clone + 'static {
     /// Handles the `GET /info/refs?service=...` advertisement phase of clone
     /// and fetch. The full CGI output is buffered into the response body.
+    /// `env_vars` are injected into the CGI environment (e.g. `GIT_PROTOCOL`
+    /// to advertise protocol v2 capabilities).
     ///
     /// # Errors
     /// - [`GitHttpError::SpawnError`] — `git http-backend` could not be spawned.
@@ -38,6 +40,7 @@ pub trait GitHttpClient: Send + Sync + Clone + 'static {
         owner: &str,
         repo: &str,
         service: &str,
+        env_vars: Vec<(String, String)>,
     ) -> Result<GitHttpResponse, GitHttpError>;
 
     /// Handles the `POST /git-<service>` data phase (`git-upload-pack` /
@@ -172,16 +175,22 @@ impl GitHttpClient for GitHttpClientImpl {
         owner: &str,
         repo: &str,
         service: &str,
+        env_vars: Vec<(String, String)>,
     ) -> Result<GitHttpResponse, GitHttpError> {
         let repo_name = self.normalize_repo_name(repo);
 
-        let child = Command::new("git")
-            .arg("http-backend")
+        let mut cmd = Command::new("git");
+        cmd.arg("http-backend")
             .env("REQUEST_METHOD", "GET")
             .env("PATH_INFO", format!("/{}/{}/info/refs", owner, repo_name))
             .env("QUERY_STRING", format!("service={}", service))
             .env("GIT_PROJECT_ROOT", &self.project_root)
-            .env("GIT_HTTP_EXPORT_ALL", "1")
+            .env("GIT_HTTP_EXPORT_ALL", "1");
+        for (key, value) in &env_vars {
+            cmd.env(key, value);
+        }
+
+        let child = cmd
             .stdin(Stdio::null())
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
@@ -307,3 +316,265 @@ impl GitHttpClient for GitHttpClientImpl {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, io::Cursor, path::Path};
+
+    use axum::{
+        Router,
+        body::Body,
+        extract::{Path as UrlPath, Query, State},
+        http::{HeaderMap, header},
+        response::Response,
+        routing::{get, post},
+    };
+    use tempfile::{TempDir, tempdir};
+    use tokio::{net::TcpListener, process::Command};
+
+    use super::{GitHttpClient, GitHttpClientImpl};
+    use crate::{
+        client::{Git2Client, GitClient},
+        dto::{GitHttpBody, GitHttpResponse},
+    };
+
+    /// A bare `alice/demo` repository with two commits, served over smart HTTP
+    /// by `GitHttpClientImpl`. Keeps its directories alive.
+    struct TestServer {
+        url: String,
+        root: TempDir,
+        _work: TempDir,
+    }
+
+    async fn git(dir: &Path, args: &[&str]) -> String {
+        let output = Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .env("GIT_AUTHOR_NAME", "alice")
+            .env("GIT_AUTHOR_EMAIL", "alice@example.com")
+            .env("GIT_COMMITTER_NAME", "alice")
+            .env("GIT_COMMITTER_EMAIL", "alice@example.com")
+            .output()
+            .await
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "git {args:?}: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        String::from_utf8(output.stdout).unwrap()
+    }
+
+    fn git_protocol_env(headers: &HeaderMap) -> Vec<(String, String)> {
+        headers
+            .get("git-protocol")
+            .map(|v| vec![("GIT_PROTOCOL".to_string(), v.to_str().unwrap().to_string())])
+            .unwrap_or_default()
+    }
+
+    fn into_response(response: GitHttpResponse) -> Response {
+        let mut builder = Response::builder().status(response.status_code);
+        for (name, value) in response.headers {
+            builder = builder.header(name, value);
+        }
+        let body = match response.body {
+            GitHttpBody::Buffered(bytes) => Body::from(bytes),
+            GitHttpBody::Stream(stream) => Body::from_stream(stream),
+        };
+        builder.body(body).unwrap()
+    }
+
+    async fn info_refs(
+        State(client): State<GitHttpClientImpl>,
+        UrlPath((owner, repo)): UrlPath<(String, String)>,
+        Query(query): Query<HashMap<String, String>>,
+        headers: HeaderMap,
+    ) -> Response {
+        let response = client
+            .info_refs(&owner, &repo, &query["service"], git_protocol_env(&headers))
+            .await
+            .unwrap();
+        into_response(response)
+    }
+
+    async fn upload_pack(
+        State(client): State<GitHttpClientImpl>,
+        UrlPath((owner, repo)): UrlPath<(String, String)>,
+        headers: HeaderMap,
+        body: Body,
+    ) -> Response {
+        let content_type = headers[header::CONTENT_TYPE].to_str().unwrap();
+        let body = axum::body::to_bytes(body, usize::MAX).await.unwrap();
+        let response = client
+            .service_rpc(
+                &owner,
+                &repo,
+                "upload-pack",
+                content_type,
+                Box::new(Cursor::new(body.to_vec())),
+                git_protocol_env(&headers),
+            )
+            .await
+            .unwrap();
+        into_response(response)
+    }
+
+    async fn serve_test_repo() -> TestServer {
+        let root = tempdir().unwrap();
+        let project_root = root.path().to_str().unwrap().to_string();
+        Git2Client::new(project_root.clone())
+            .create_repo("alice", "demo")
+            .await
+            .unwrap();
+
+        // Two commits, so the first commit's blob is absent from a partial
+        // clone's checkout of the second.
+        let work = tempdir().unwrap();
+        git(work.path(), &["init", "-b", "main"]).await;
+        std::fs::create_dir(work.path().join("src")).unwrap();
+        std::fs::write(work.path().join("src/lib.rs"), "// lib\n").unwrap();
+        std::fs::write(work.path().join("README.md"), "v1\n").unwrap();
+        git(work.path(), &["add", "."]).await;
+        git(work.path(), &["commit", "-m", "first"]).await;
+        std::fs::write(work.path().join("README.md"), "v2\n").unwrap();
+        git(work.path(), &["commit", "-am", "second"]).await;
+        let bare = root.path().join("alice/demo.git");
+        git(work.path(), &["push", bare.to_str().unwrap(), "main"]).await;
+
+        let app = Router::new()
+            .route("/{owner}/{repo}/info/refs", get(info_refs))
+            .route("/{owner}/{repo}/git-upload-pack", post(upload_pack))
+            .with_state(GitHttpClientImpl::new(project_root));
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        TestServer {
+            url: format!("http://{addr}/alice/demo.git"),
+            root,
+            _work: work,
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn advertises_protocol_v2_with_filter() {
+        let server = serve_test_repo().await;
+        let client = GitHttpClientImpl::new(server.root.path().to_str().unwrap().to_string());
+
+        let response = client
+            .info_refs(
+                "alice",
+                "demo",
+                "git-upload-pack",
+                vec![("GIT_PROTOCOL".to_string(), "version=2".to_string())],
+            )
+            .await
+            .unwrap();
+        let GitHttpBody::Buffered(body) = response.body else {
+            panic!("info/refs is buffered");
+        };
+        let body = String::from_utf8_lossy(&body);
+
+        assert!(body.contains("version 2"), "{body}");
+        assert!(body.contains("filter"), "{body}");
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn clones_with_blob_filter() {
+        let server = serve_test_repo().await;
+        let dest = tempdir().unwrap();
+
+        git(
+            dest.path(),
+            &[
+                "-c",
+                "protocol.version=2",
+                "clone",
+                "--filter=blob:none",
+                &server.url,
+                "demo",
+            ],
+        )
+        .await;
+        let clone = dest.path().join("demo");
+
+        assert_eq!(
+            git(&clone, &["config", "remote.origin.promisor"])
+                .await
+                .trim(),
+            "true"
+        );
+        let objects = git(
+            &clone,
+            &["rev-list", "--objects", "--missing=print", "HEAD"],
+        )
+        .await;
+        assert!(objects.lines().any(|l| l.starts_with('?')), "{objects}");
+
+        // Checking out the first commit lazily fetches its missing blob.
+        git(&clone, &["checkout", "HEAD~1"]).await;
+        assert_eq!(
+            std::fs::read_to_string(clone.join("README.md")).unwrap(),
+            "v1\n"
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn clones_with_tree_filter() {
+        let server = serve_test_repo().await;
+        let dest = tempdir().unwrap();
+
+        git(
+            dest.path(),
+            &[
+                "-c",
+                "protocol.version=2",
+                "clone",
+                "--filter=tree:0",
+                &server.url,
+                "demo",
+            ],
+        )
+        .await;
+        let clone = dest.path().join("demo");
+
+        assert_eq!(
+            std::fs::read_to_string(clone.join("src/lib.rs")).unwrap(),
+            "// lib\n"
+        );
+        assert_eq!(
+            git(&clone, &["rev-list", "--count", "HEAD"]).await.trim(),
+            "2"
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn clones_and_deepens_shallow() {
+        let server = serve_test_repo().await;
+        let dest = tempdir().unwrap();
+
+        git(
+            dest.path(),
+            &[
+                "-c",
+                "protocol.version=2",
+                "clone",
+                "--depth=1",
+                &server.url,
+                "demo",
+            ],
+        )
+        .await;
+        let clone = dest.path().join("demo");
+        assert_eq!(
+            git(&clone, &["rev-list", "--count", "HEAD"]).await.trim(),
+            "1"
+        );
+
+        git(&clone, &["-c", "protocol.version=2", "fetch", "--deepen=1"]).await;
+        assert_eq!(
+            git(&clone, &["rev-list", "--count", "HEAD"]).await.trim(),
+            "2"
+        );
+    }
+}
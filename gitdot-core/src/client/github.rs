@@ -4,16 +4,55 @@ use chrono::Utc;
 use hmac::{Hmac, Mac};
 use octocrab::models::{AppId, Installation, InstallationId, InstallationRepositories};
 use secrecy::ExposeSecret;
-use serde::Deserialize;
+use serde::{Deserialize, de::DeserializeOwned, de::IgnoredAny};
 use sha2::Sha256;
 use uuid::Uuid;
 
 use crate::{
-    dto::{GitHubAppInstallAction, GitHubEmail, GitHubMembership, GitHubUser, InstallStatePayload},
+    dto::{
+        GitHubAccount, GitHubAppInstallAction, GitHubDiscussion, GitHubDiscussionComment,
+        GitHubEmail, GitHubIssue, GitHubIssueComment, GitHubMembership, GitHubPullRequest,
+        GitHubPullRequestCommit, GitHubPullRequestReview, GitHubReviewComment, GitHubUser,
+        InstallStatePayload,
+    },
     error::GitHubError,
 };
 
 const INSTALL_STATE_TTL_SECONDS: i64 = 600;
+const PAGE_SIZE: usize = 100;
+
+/// Discussions with their comments and replies, oldest first. Comments and
+/// replies are capped at the first 100 of each.
+const DISCUSSIONS_QUERY: &str = r#"
+query($owner: String!, $name: String!, $cursor: String) {
+  repository(owner: $owner, name: $name) {
+    discussions(first: 50, after: $cursor, orderBy: {field: CREATED_AT, direction: ASC}) {
+      pageInfo { hasNextPage endCursor }
+      nodes {
+        number title body createdAt updatedAt
+        author { ...Account }
+        comments(first: 100) {
+          nodes {
+            body createdAt updatedAt
+            author { ...Account }
+            replies(first: 100) {
+              nodes { body createdAt updatedAt author { ...Account } }
+            }
+          }
+        }
+      }
+    }
+  }
+}
+
+fragment Account on Actor {
+  login
+  ... on User { databaseId }
+  ... on Bot { databaseId }
+  ... on Mannequin { databaseId }
+  ... on Organization { databaseId }
+}
+"#;
 
 /// Talks to GitHub on behalf of gitdot across three concerns: user OAuth
 /// (sign-in via `user:email`), GitHub App installation management, and webhook
@@ -115,6 +154,103 @@ pub trait GitHubClient: Send + Sync + Clone + 'static {
         installation_id: u64,
     ) -> Result<InstallationRepositories, GitHubError>;
 
+    // Repository history operations
+
+    /// Lists every issue of `full_name` (`owner/repo`), open and closed,
+    /// oldest first. Pull requests, which GitHub also reports as issues, are
+    /// left out.
+    ///
+    /// # Errors
+    /// - [`GitHubError::OctocrabError`] — a request failed or the token lacks
+    ///   access to the repository.
+    async fn list_issues(
+        &self,
+        token: &str,
+        full_name: &str,
+    ) -> Result<Vec<GitHubIssue>, GitHubError>;
+
+    /// Lists the conversation comments of issue or pull request `number`,
+    /// oldest first.
+    ///
+    /// # Errors
+    /// - [`GitHubError::OctocrabError`] — a request failed.
+    async fn list_issue_comments(
+        &self,
+        token: &str,
+        full_name: &str,
+        number: i32,
+    ) -> Result<Vec<GitHubIssueComment>, GitHubError>;
+
+    /// Lists every discussion of `full_name` with its comments and their
+    /// replies (GraphQL), oldest first. Only the first 100 comments of a
+    /// discussion and 100 replies of a comment are returned.
+    ///
+    /// # Errors
+    /// - [`GitHubError::OctocrabError`] — the query failed, including when
+    ///   discussions are disabled for the repository.
+    async fn list_discussions(
+        &self,
+        token: &str,
+        full_name: &str,
+    ) -> Result<Vec<GitHubDiscussion>, GitHubError>;
+
+    /// Lists every pull request of `full_name`, open and closed, oldest first.
+    ///
+    /// # Errors
+    /// - [`GitHubError::OctocrabError`] — a request failed.
+    async fn list_pull_requests(
+        &self,
+        token: &str,
+        full_name: &str,
+    ) -> Result<Vec<GitHubPullRequest>, GitHubError>;
+
+    /// Lists the commits of pull request `number` in order (GitHub caps this
+    /// at 250 commits).
+    ///
+    /// # Errors
+    /// - [`GitHubError::OctocrabError`] — a request failed.
+    async fn list_pull_request_commits(
+        &self,
+        token: &str,
+        full_name: &str,
+        number: i32,
+    ) -> Result<Vec<GitHubPullRequestCommit>, GitHubError>;
+
+    /// Lists the submitted reviews of pull request `number`, oldest first.
+    ///
+    /// # Errors
+    /// - [`GitHubError::OctocrabError`] — a request failed.
+    async fn list_pull_request_reviews(
+        &self,
+        token: &str,
+        full_name: &str,
+        number: i32,
+    ) -> Result<Vec<GitHubPullRequestReview>, GitHubError>;
+
+    /// Lists the inline review comments of pull request `number`, oldest
+    /// first.
+    ///
+    /// # Errors
+    /// - [`GitHubError::OctocrabError`] — a request failed.
+    async fn list_pull_request_comments(
+        &self,
+        token: &str,
+        full_name: &str,
+        number: i32,
+    ) -> Result<Vec<GitHubReviewComment>, GitHubError>;
+
+    /// Returns the public profile email of `login`, or `None` when the user
+    /// keeps it private.
+    ///
+    /// # Errors
+    /// - [`GitHubError::OctocrabError`] — the request failed or the user does
+    ///   not exist.
+    async fn get_user_public_email(
+        &self,
+        token: &str,
+        login: &str,
+    ) -> Result<Option<String>, GitHubError>;
+
     // Webhook operations
 
     /// Verifies the `X-Hub-Signature-256` header (`sha256=<hex>`) of an inbound
@@ -176,6 +312,37 @@ impl OctocrabClient {
     }
 }
 
+impl OctocrabClient {
+    fn token_client(token: &str) -> Result<octocrab::Octocrab, GitHubError> {
+        Ok(octocrab::Octocrab::builder()
+            .personal_token(token.to_string())
+            .build()?)
+    }
+
+    /// Fetches `path` page by page until GitHub returns a short page.
+    async fn get_all_pages<T: DeserializeOwned>(
+        crab: &octocrab::Octocrab,
+        path: &str,
+        params: &[(&str, &str)],
+    ) -> Result<Vec<T>, GitHubError> {
+        let mut items = Vec::new();
+        for page in 1.. {
+            let page = page.to_string();
+            let per_page = PAGE_SIZE.to_string();
+            let mut query = vec![("per_page", per_page.as_str()), ("page", page.as_str())];
+            query.extend_from_slice(params);
+
+            let batch: Vec<T> = crab.get(path, Some(&query)).await?;
+            let done = batch.len() < PAGE_SIZE;
+            items.extend(batch);
+            if done {
+                break;
+            }
+        }
+        Ok(items)
+    }
+}
+
 #[crate::instrument_all(level = "debug")]
 #[async_trait]
 impl GitHubClient for OctocrabClient {
@@ -328,6 +495,228 @@ impl GitHubClient for OctocrabClient {
         Ok(repositories)
     }
 
+    async fn list_issues(
+        &self,
+        token: &str,
+        full_name: &str,
+    ) -> Result<Vec<GitHubIssue>, GitHubError> {
+        #[derive(Deserialize)]
+        struct Item {
+            #[serde(flatten)]
+            issue: GitHubIssue,
+            pull_request: Option<IgnoredAny>,
+        }
+
+        let crab = Self::token_client(token)?;
+        let items: Vec<Item> = Self::get_all_pages(
+            &crab,
+            &format!("/repos/{full_name}/issues"),
+            &[("state", "all"), ("sort", "created"), ("direction", "asc")],
+        )
+        .await?;
+
+        Ok(items
+            .into_iter()
+            .filter(|item| item.pull_request.is_none())
+            .map(|item| item.issue)
+            .collect())
+    }
+
+    async fn list_issue_comments(
+        &self,
+        token: &str,
+        full_name: &str,
+        number: i32,
+    ) -> Result<Vec<GitHubIssueComment>, GitHubError> {
+        let crab = Self::token_client(token)?;
+        Self::get_all_pages(
+            &crab,
+            &format!("/repos/{full_name}/issues/{number}/comments"),
+            &[],
+        )
+        .await
+    }
+
+    async fn list_discussions(
+        &self,
+        token: &str,
+        full_name: &str,
+    ) -> Result<Vec<GitHubDiscussion>, GitHubError> {
+        #[derive(Deserialize)]
+        struct Data {
+            repository: Repository,
+        }
+        #[derive(Deserialize)]
+        struct Repository {
+            discussions: Connection<Discussion>,
+        }
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Connection<T> {
+            page_info: Option<PageInfo>,
+            nodes: Vec<T>,
+        }
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct PageInfo {
+            has_next_page: bool,
+            end_cursor: Option<String>,
+        }
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Discussion {
+            number: i32,
+            title: String,
+            body: String,
+            author: Option<Actor>,
+            created_at: chrono::DateTime<Utc>,
+            updated_at: chrono::DateTime<Utc>,
+            comments: Connection<Comment>,
+        }
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Comment {
+            body: String,
+            author: Option<Actor>,
+            created_at: chrono::DateTime<Utc>,
+            updated_at: chrono::DateTime<Utc>,
+            replies: Option<Connection<Comment>>,
+        }
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Actor {
+            login: String,
+            database_id: Option<i64>,
+        }
+
+        fn account(actor: Option<Actor>) -> Option<GitHubAccount> {
+            actor.and_then(|a| a.database_id.map(|id| GitHubAccount { id, login: a.login }))
+        }
+        fn comment(c: Comment) -> GitHubDiscussionComment {
+            GitHubDiscussionComment {
+                body: c.body,
+                author: account(c.author),
+                created_at: c.created_at,
+                updated_at: c.updated_at,
+                replies: c
+                    .replies
+                    .map(|r| r.nodes.into_iter().map(comment).collect())
+                    .unwrap_or_default(),
+            }
+        }
+
+        let (owner, name) = full_name
+            .split_once('/')
+            .ok_or_else(|| GitHubError::Other(format!("invalid repository {full_name}")))?;
+        let crab = Self::token_client(token)?;
+
+        let mut discussions = Vec::new();
+        let mut cursor: Option<String> = None;
+        loop {
+            let data: Data = crab
+                .graphql(&serde_json::json!({
+                    "query": DISCUSSIONS_QUERY,
+                    "variables": { "owner": owner, "name": name, "cursor": cursor },
+                }))
+                .await?;
+            let connection = data.repository.discussions;
+
+            discussions.extend(connection.nodes.into_iter().map(|d| GitHubDiscussion {
+                number: d.number,
+                title: d.title,
+                body: d.body,
+                author: account(d.author),
+                created_at: d.created_at,
+                updated_at: d.updated_at,
+                comments: d.comments.nodes.into_iter().map(comment).collect(),
+            }));
+
+            match connection.page_info {
+                Some(PageInfo {
+                    has_next_page: true,
+                    end_cursor: Some(end_cursor),
+                }) => cursor = Some(end_cursor),
+                _ => break,
+            }
+        }
+
+        Ok(discussions)
+    }
+
+    async fn list_pull_requests(
+        &self,
+        token: &str,
+        full_name: &str,
+    ) -> Result<Vec<GitHubPullRequest>, GitHubError> {
+        let crab = Self::token_client(token)?;
+        Self::get_all_pages(
+            &crab,
+            &format!("/repos/{full_name}/pulls"),
+            &[("state", "all"), ("sort", "created"), ("direction", "asc")],
+        )
+        .await
+    }
+
+    async fn list_pull_request_commits(
+        &self,
+        token: &str,
+        full_name: &str,
+        number: i32,
+    ) -> Result<Vec<GitHubPullRequestCommit>, GitHubError> {
+        let crab = Self::token_client(token)?;
+        Self::get_all_pages(
+            &crab,
+            &format!("/repos/{full_name}/pulls/{number}/commits"),
+            &[],
+        )
+        .await
+    }
+
+    async fn list_pull_request_reviews(
+        &self,
+        token: &str,
+        full_name: &str,
+        number: i32,
+    ) -> Result<Vec<GitHubPullRequestReview>, GitHubError> {
+        let crab = Self::token_client(token)?;
+        Self::get_all_pages(
+            &crab,
+            &format!("/repos/{full_name}/pulls/{number}/reviews"),
+            &[],
+        )
+        .await
+    }
+
+    async fn list_pull_request_comments(
+        &self,
+        token: &str,
+        full_name: &str,
+        number: i32,
+    ) -> Result<Vec<GitHubReviewComment>, GitHubError> {
+        let crab = Self::token_client(token)?;
+        Self::get_all_pages(
+            &crab,
+            &format!("/repos/{full_name}/pulls/{number}/comments"),
+            &[("sort", "created"), ("direction", "asc")],
+        )
+        .await
+    }
+
+    async fn get_user_public_email(
+        &self,
+        token: &str,
+        login: &str,
+    ) -> Result<Option<String>, GitHubError> {
+        #[derive(Deserialize)]
+        struct Profile {
+            email: Option<String>,
+        }
+
+        let crab = Self::token_client(token)?;
+        let profile: Profile = crab.get(format!("/users/{login}"), None::<&()>).await?;
+        Ok(profile.email)
+    }
+
     fn verify_webhook_signature(
         &self,
         body: &[u8],
@@ -11,11 +11,16 @@ use rdkafka::{
 };
 use serde_json::json;
 
-use crate::{dto::RepoPushEvent, error::KafkaError};
+use crate::{
+    dto::{RepoPushEvent, ReviewEvent, TagEvent},
+    error::KafkaError,
+};
 
 // TODO: REWRITE THIS CLIENT
 
 const REPO_PUSHED_TOPIC: &str = "gitdot.repo.pushed";
+const REVIEW_EVENTS_TOPIC: &str = "gitdot.review.events";
+const TAG_EVENTS_TOPIC: &str = "gitdot.tag.events";
 
 const SEND_TIMEOUT: Duration = Duration::from_secs(5);
 
@@ -63,6 +68,25 @@ pub trait KafkaClient: Send + Sync + Clone + 'static {
     /// - [`KafkaError::KafkaError`] — the broker rejected the message or the
     ///   send timed out.
     async fn publish_repo_push(&self, event: RepoPushEvent) -> Result<(), KafkaError>;
+
+    /// Publishes a review lifecycle event to the `gitdot.review.events` topic,
+    /// keyed by `owner/repo` like pushes so review activity for a repo stays
+    /// ordered. Awaits broker acknowledgement.
+    ///
+    /// # Errors
+    /// - [`KafkaError::SerializationError`] — the event could not be serialized.
+    /// - [`KafkaError::KafkaError`] — the broker rejected the message or the
+    ///   send timed out.
+    async fn publish_review_event(&self, event: ReviewEvent) -> Result<(), KafkaError>;
+
+    /// Publishes a tag creation, move or deletion to the `gitdot.tag.events`
+    /// topic, keyed by `owner/repo`. Awaits broker acknowledgement.
+    ///
+    /// # Errors
+    /// - [`KafkaError::SerializationError`] — the event could not be serialized.
+    /// - [`KafkaError::KafkaError`] — the broker rejected the message or the
+    ///   send timed out.
+    async fn publish_tag_event(&self, event: TagEvent) -> Result<(), KafkaError>;
 }
 
 #[derive(Clone)]
@@ -200,6 +224,25 @@ impl KafkaClientImpl {
     }
 }
 
+impl KafkaClientImpl {
+    async fn send(&self, topic: &str, key: &str, payload: &[u8]) -> Result<(), KafkaError> {
+        let record = FutureRecord::to(topic).key(key).payload(payload);
+
+        match &self.producer {
+            ProducerHandle::Plain(p) => p
+                .send(record, Timeout::After(SEND_TIMEOUT))
+                .await
+                .map_err(|(e, _)| KafkaError::from(e))?,
+            ProducerHandle::Gcp(p) => p
+                .send(record, Timeout::After(SEND_TIMEOUT))
+                .await
+                .map_err(|(e, _)| KafkaError::from(e))?,
+        };
+
+        Ok(())
+    }
+}
+
 impl std::fmt::Debug for KafkaClientImpl {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("KafkaClientImpl").finish_non_exhaustive()
@@ -226,21 +269,18 @@ impl KafkaClient for KafkaClientImpl {
     async fn publish_repo_push(&self, event: RepoPushEvent) -> Result<(), KafkaError> {
         let key = format!("{}/{}", event.owner, event.repo);
         let payload = serde_json::to_vec(&event)?;
-        let record = FutureRecord::to(REPO_PUSHED_TOPIC)
-            .key(&key)
-            .payload(&payload);
+        self.send(REPO_PUSHED_TOPIC, &key, &payload).await
+    }
 
-        match &self.producer {
-            ProducerHandle::Plain(p) => p
-                .send(record, Timeout::After(SEND_TIMEOUT))
-                .await
-                .map_err(|(e, _)| KafkaError::from(e))?,
-            ProducerHandle::Gcp(p) => p
-                .send(record, Timeout::After(SEND_TIMEOUT))
-                .await
-                .map_err(|(e, _)| KafkaError::from(e))?,
-        };
+    async fn publish_review_event(&self, event: ReviewEvent) -> Result<(), KafkaError> {
+        let key = format!("{}/{}", event.owner, event.repo);
+        let payload = serde_json::to_vec(&event)?;
+        self.send(REVIEW_EVENTS_TOPIC, &key, &payload).await
+    }
 
-        Ok(())
+    async fn publish_tag_event(&self, event: TagEvent) -> Result<(), KafkaError> {
+        let key = format!("{}/{}", event.owner, event.repo);
+        let payload = serde_json::to_vec(&event)?;
+        self.send(TAG_EVENTS_TOPIC, &key, &payload).await
     }
 }
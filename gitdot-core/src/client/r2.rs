@@ -1,3 +1,5 @@
+use std::{io::ErrorKind, path::PathBuf};
+
 use async_trait::async_trait;
 use aws_config::BehaviorVersion;
 use aws_sdk_s3::{config::Credentials, primitives::ByteStream};
@@ -5,9 +7,11 @@ use bytes::Bytes;
 
 use crate::error::R2Error;
 
-/// Uploads objects to a Cloudflare R2 bucket via the S3-compatible API.
+/// Stores objects in a Cloudflare R2 bucket via the S3-compatible API, or in
+/// a local directory for self-hosted deployments.
 ///
-/// Used for serving immutable static assets such as avatars.
+/// Used for serving immutable static assets such as avatars, and for CI
+/// artifacts and caches.
 #[async_trait]
 pub trait R2Client: Send + Sync + Clone + 'static {
     /// Puts `body` at `key` in the configured bucket with a long-lived,
@@ -16,12 +20,34 @@ pub trait R2Client: Send + Sync + Clone + 'static {
     /// # Errors
     /// - [`R2Error::UploadError`] — the upload request failed.
     async fn upload_object(&self, key: &str, body: Bytes) -> Result<(), R2Error>;
+
+    /// Reads the object at `key`. Returns `Ok(None)` if there is none.
+    ///
+    /// # Errors
+    /// - [`R2Error::DownloadError`] — the download request failed.
+    async fn get_object(&self, key: &str) -> Result<Option<Bytes>, R2Error>;
+
+    /// Removes the object at `key`. Deleting a missing object succeeds.
+    ///
+    /// # Errors
+    /// - [`R2Error::DeleteError`] — the delete request failed.
+    async fn delete_object(&self, key: &str) -> Result<(), R2Error>;
 }
 
 #[derive(Clone)]
 pub struct R2ClientImpl {
-    client: aws_sdk_s3::Client,
-    bucket_name: String,
+    backend: Backend,
+}
+
+#[derive(Clone)]
+enum Backend {
+    R2 {
+        client: aws_sdk_s3::Client,
+        bucket_name: String,
+    },
+    Filesystem {
+        root: PathBuf,
+    },
 }
 
 impl R2ClientImpl {
@@ -45,8 +71,18 @@ impl R2ClientImpl {
             .await;
 
         Self {
-            client: aws_sdk_s3::Client::new(&config),
-            bucket_name,
+            backend: Backend::R2 {
+                client: aws_sdk_s3::Client::new(&config),
+                bucket_name,
+            },
+        }
+    }
+
+    /// Stores objects as files under `root`, one per key. Keys are always
+    /// generated by gitdot, never taken from user input.
+    pub fn filesystem(root: impl Into<PathBuf>) -> Self {
+        Self {
+            backend: Backend::Filesystem { root: root.into() },
         }
     }
 }
@@ -54,15 +90,91 @@ impl R2ClientImpl {
 #[async_trait]
 impl R2Client for R2ClientImpl {
     async fn upload_object(&self, key: &str, body: Bytes) -> Result<(), R2Error> {
-        self.client
-            .put_object()
-            .bucket(&self.bucket_name)
-            .key(key)
-            .body(ByteStream::from(body))
-            .cache_control("public, max-age=31536000, immutable")
-            .send()
-            .await
-            .map_err(|e| R2Error::UploadError(e.to_string()))?;
+        match &self.backend {
+            Backend::R2 {
+                client,
+                bucket_name,
+            } => {
+                client
+                    .put_object()
+                    .bucket(bucket_name)
+                    .key(key)
+                    .body(ByteStream::from(body))
+                    .cache_control("public, max-age=31536000, immutable")
+                    .send()
+                    .await
+                    .map_err(|e| R2Error::UploadError(e.to_string()))?;
+            }
+            Backend::Filesystem { root } => {
+                let path = root.join(key);
+                if let Some(parent) = path.parent() {
+                    tokio::fs::create_dir_all(parent)
+                        .await
+                        .map_err(|e| R2Error::UploadError(e.to_string()))?;
+                }
+                tokio::fs::write(&path, &body)
+                    .await
+                    .map_err(|e| R2Error::UploadError(e.to_string()))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn get_object(&self, key: &str) -> Result<Option<Bytes>, R2Error> {
+        match &self.backend {
+            Backend::R2 {
+                client,
+                bucket_name,
+            } => {
+                let output = match client
+                    .get_object()
+                    .bucket(bucket_name)
+                    .key(key)
+                    .send()
+                    .await
+                {
+                    Ok(output) => output,
+                    Err(e) if e.as_service_error().is_some_and(|e| e.is_no_such_key()) => {
+                        return Ok(None);
+                    }
+                    Err(e) => return Err(R2Error::DownloadError(e.to_string())),
+                };
+                let body = output
+                    .body
+                    .collect()
+                    .await
+                    .map_err(|e| R2Error::DownloadError(e.to_string()))?;
+                Ok(Some(body.into_bytes()))
+            }
+            Backend::Filesystem { root } => match tokio::fs::read(root.join(key)).await {
+                Ok(body) => Ok(Some(Bytes::from(body))),
+                Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+                Err(e) => Err(R2Error::DownloadError(e.to_string())),
+            },
+        }
+    }
+
+    async fn delete_object(&self, key: &str) -> Result<(), R2Error> {
+        match &self.backend {
+            Backend::R2 {
+                client,
+                bucket_name,
+            } => {
+                client
+                    .delete_object()
+                    .bucket(bucket_name)
+                    .key(key)
+                    .send()
+                    .await
+                    .map_err(|e| R2Error::DeleteError(e.to_string()))?;
+            }
+            Backend::Filesystem { root } => match tokio::fs::remove_file(root.join(key)).await {
+                Ok(()) => {}
+                Err(e) if e.kind() == ErrorKind::NotFound => {}
+                Err(e) => return Err(R2Error::DeleteError(e.to_string())),
+            },
+        }
 
         Ok(())
     }
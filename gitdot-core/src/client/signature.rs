@@ -0,0 +1,268 @@
+use std::process::Stdio;
+
+use async_trait::async_trait;
+use tokio::process::Command;
+
+use crate::{
+    error::SignatureError,
+    model::{SigningKey, SigningKeyType},
+};
+
+/// SSH signatures on git commits are made in the `git` namespace.
+const SSH_NAMESPACE: &str = "git";
+
+/// Checks commit signatures against a registered signing key by shelling out
+/// to `gpg` and `ssh-keygen`, the same tools git itself uses to verify them.
+/// Each check runs in a throwaway directory holding only the one key, so no
+/// keyring or allowed-signers file is shared between checks.
+#[async_trait]
+pub trait SignatureClient: Send + Sync + Clone + 'static {
+    /// Returns whether `signature` is a valid signature by `key` over
+    /// `payload` (the commit object without its signature header).
+    ///
+    /// # Errors
+    /// - [`SignatureError::CommandError`] — `gpg` or `ssh-keygen` could not be
+    ///   run.
+    /// - [`SignatureError::IoError`] — the scratch directory could not be
+    ///   written.
+    async fn verify_signature(
+        &self,
+        key: &SigningKey,
+        signature: &str,
+        payload: &[u8],
+    ) -> Result<bool, SignatureError>;
+
+    /// Returns whether `signature` is an `ssh-keygen -Y sign` signature by
+    /// `public_key` (`<type> <base64>`) over `message` in `namespace`.
+    ///
+    /// # Errors
+    /// - [`SignatureError::CommandError`] — `ssh-keygen` could not be run.
+    /// - [`SignatureError::IoError`] — the scratch directory could not be
+    ///   written.
+    async fn verify_ssh_signature(
+        &self,
+        public_key: &str,
+        namespace: &str,
+        signature: &str,
+        message: &[u8],
+    ) -> Result<bool, SignatureError>;
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SignatureClientImpl;
+
+impl SignatureClientImpl {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+#[crate::instrument_all(level = "debug")]
+#[async_trait]
+impl SignatureClient for SignatureClientImpl {
+    async fn verify_signature(
+        &self,
+        key: &SigningKey,
+        signature: &str,
+        payload: &[u8],
+    ) -> Result<bool, SignatureError> {
+        match key.key_type {
+            SigningKeyType::Gpg => {
+                // The scratch directory doubles as GnuPG's home, holding a
+                // keyring with only this key.
+                let dir = tempfile::tempdir()?;
+                let key_path = dir.path().join("key.asc");
+                let signature_path = dir.path().join("signature");
+                let payload_path = dir.path().join("payload");
+                tokio::fs::write(&key_path, &key.public_key).await?;
+                tokio::fs::write(&signature_path, signature).await?;
+                tokio::fs::write(&payload_path, payload).await?;
+                let gpg = || {
+                    let mut cmd = Command::new("gpg");
+                    cmd.arg("--homedir").arg(dir.path()).args([
+                        "--batch",
+                        "--no-tty",
+                        "--no-autostart",
+                    ]);
+                    cmd
+                };
+
+                let import = gpg()
+                    .arg("--import")
+                    .arg(&key_path)
+                    .output()
+                    .await
+                    .map_err(|e| SignatureError::CommandError("gpg", e))?;
+                if !import.status.success() {
+                    return Ok(false);
+                }
+
+                let verify = gpg()
+                    .args(["--status-fd", "1", "--verify"])
+                    .arg(&signature_path)
+                    .arg(&payload_path)
+                    .output()
+                    .await
+                    .map_err(|e| SignatureError::CommandError("gpg", e))?;
+                let valid = String::from_utf8_lossy(&verify.stdout)
+                    .lines()
+                    .any(|line| line.starts_with("[GNUPG:] VALIDSIG "));
+
+                Ok(verify.status.success() && valid)
+            }
+            SigningKeyType::Ssh => {
+                self.verify_ssh_signature(&key.public_key, SSH_NAMESPACE, signature, payload)
+                    .await
+            }
+        }
+    }
+
+    async fn verify_ssh_signature(
+        &self,
+        public_key: &str,
+        namespace: &str,
+        signature: &str,
+        message: &[u8],
+    ) -> Result<bool, SignatureError> {
+        let dir = tempfile::tempdir()?;
+        let allowed_signers_path = dir.path().join("allowed_signers");
+        let signature_path = dir.path().join("signature");
+        let message_path = dir.path().join("message");
+        tokio::fs::write(&allowed_signers_path, format!("signer {public_key}\n")).await?;
+        tokio::fs::write(&signature_path, signature).await?;
+        tokio::fs::write(&message_path, message).await?;
+
+        let verify = Command::new("ssh-keygen")
+            .args(["-Y", "verify", "-I", "signer", "-n", namespace, "-f"])
+            .arg(&allowed_signers_path)
+            .arg("-s")
+            .arg(&signature_path)
+            .stdin(Stdio::from(std::fs::File::open(&message_path)?))
+            .output()
+            .await
+            .map_err(|e| SignatureError::CommandError("ssh-keygen", e))?;
+
+        Ok(verify.status.success())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    use super::*;
+
+    // Generated with `ssh-keygen -t ed25519`; the signature is
+    // `ssh-keygen -Y sign -n git` over PAYLOAD.
+    const SSH_KEY: &str =
+        "ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAIFc4cahJxsnZbwU7UrDMlqaAw0DGqTnUTXjmERbLjC2l";
+    const SSH_SIGNATURE: &str = "-----BEGIN SSH SIGNATURE-----
+U1NIU0lHAAAAAQAAADMAAAALc3NoLWVkMjU1MTkAAAAgVzhxqEnGydlvBTtSsMyWpoDDQM
+apOdRNeOYRFsuMLaUAAAADZ2l0AAAAAAAAAAZzaGE1MTIAAABTAAAAC3NzaC1lZDI1NTE5
+AAAAQKExOepW5bZorNFN1KoMj79Dxrx6nHHNLOzGYZ724sk4wM42s3bJJXLFi+sd/mKjLM
+1TTZIQ4kE/TYN/nGE2dws=
+-----END SSH SIGNATURE-----
+";
+    // `gpg --quick-gen-key ed25519` plus an ed25519 signing subkey; the
+    // signature is `gpg --detach-sign` over PAYLOAD by the subkey.
+    const GPG_KEY: &str = "-----BEGIN PGP PUBLIC KEY BLOCK-----
+
+mDMEatUaJxYJKwYBBAHaRw8BAQdAMnylJbd1nUGM8TrtEMPyH8f8SiPGeUOsnan7
+jiyWVAC0GUFsaWNlIDxhbGljZUBleGFtcGxlLmNvbT6IkAQTFggAOBYhBERg74bA
+CNuAaavNNl3NQNIAVYLIBQJq1RonAhsDBQsJCAcCBhUKCQgLAgQWAgMBAh4BAheA
+AAoJEF3NQNIAVYLIVbYBALftSMnvw1a30jRZmi+mvpFZ9xz3/d1nh1zV83nePenB
+AP9YoZ9nlpz+9gDIoiwAeYltZx9Ixc2EyteNrWfDcts7AbgzBGrVGicWCSsGAQQB
+2kcPAQEHQMsbbmYy+K+jSbfP+PUcgmrZduq5E1Z17R4p1Q5GpyGjiO8EGBYIACAW
+IQREYO+GwAjbgGmrzTZdzUDSAFWCyAUCatUaJwIbAgCBCRBdzUDSAFWCyHYgBBkW
+CAAdFiEEudraX8lWL3bqwfB3QCqt68YAKc8FAmrVGicACgkQQCqt68YAKc88fwD/
+bE72nfHmyb7AOR2jdeXEay3qkIIR9a6+CWPYi7iP+h4BAPoyThFzLdeYFt2FmFaI
+IK2YMa7MbC4qB5ngfo58ku8DsMMBANxOnV3dSA4rQnoM97a5OhgaQeRNn+kL5ef6
+hSFhIGiMAP9DL2rYQPjyShYWGiOPp0bE2vjoWhnfYXbgAf9sgSaVDQ==
+=QZ+4
+-----END PGP PUBLIC KEY BLOCK-----
+";
+    const GPG_SIGNATURE: &str = "-----BEGIN PGP SIGNATURE-----
+
+iHUEABYIAB0WIQS52tpfyVYvdurB8HdAKq3rxgApzwUCatUaJwAKCRBAKq3rxgAp
+z7NFAQCJzS4dZpp/cZOgZavqsB+51l0UYWsYbRXdhtBqEo9MfAEAjVVc8yvAZdpe
+Sl+gYeWItCGN+e0JvOe/kcS+bEeDXwk=
+=RClh
+-----END PGP SIGNATURE-----
+";
+
+    const PAYLOAD: &[u8] = b"hello\n";
+
+    fn signing_key(key_type: SigningKeyType, public_key: &str) -> SigningKey {
+        SigningKey {
+            id: Uuid::new_v4(),
+            user_id: Uuid::new_v4(),
+            name: "laptop".to_string(),
+            key_type,
+            public_key: public_key.to_string(),
+            fingerprint: String::new(),
+            key_ids: vec![],
+            created_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    #[ignore = "requires gpg"]
+    async fn verifies_gpg_signatures() {
+        let client = SignatureClientImpl::new();
+        let key = signing_key(SigningKeyType::Gpg, GPG_KEY);
+
+        assert!(
+            client
+                .verify_signature(&key, GPG_SIGNATURE, PAYLOAD)
+                .await
+                .unwrap()
+        );
+        assert!(
+            !client
+                .verify_signature(&key, GPG_SIGNATURE, b"tampered\n")
+                .await
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    #[ignore = "requires ssh-keygen"]
+    async fn verifies_ssh_signatures() {
+        let client = SignatureClientImpl::new();
+        let key = signing_key(SigningKeyType::Ssh, SSH_KEY);
+
+        assert!(
+            client
+                .verify_signature(&key, SSH_SIGNATURE, PAYLOAD)
+                .await
+                .unwrap()
+        );
+        assert!(
+            !client
+                .verify_signature(&key, SSH_SIGNATURE, b"tampered\n")
+                .await
+                .unwrap()
+        );
+
+        // A different key does not verify the signature.
+        let other = signing_key(
+            SigningKeyType::Ssh,
+            "ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAINMxyRTl8Mv7fUpeuLSY3VjGDnqJEfl/9ePx4G+jBpYj",
+        );
+        assert!(
+            !client
+                .verify_signature(&other, SSH_SIGNATURE, PAYLOAD)
+                .await
+                .unwrap()
+        );
+
+        // Nor does a commit signature count in another namespace.
+        assert!(
+            !client
+                .verify_ssh_signature(SSH_KEY, "gitdot", SSH_SIGNATURE, PAYLOAD)
+                .await
+                .unwrap()
+        );
+    }
+}
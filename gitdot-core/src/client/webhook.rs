@@ -0,0 +1,174 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use hmac::{Hmac, Mac};
+use reqwest::{
+    Client,
+    header::{HeaderMap, HeaderValue},
+};
+use sha2::Sha256;
+use uuid::Uuid;
+
+use crate::{dto::WebhookHttpResponse, error::WebhookClientError, model::WebhookEventType};
+
+pub const WEBHOOK_EVENT_HEADER: &str = "x-gitdot-event";
+pub const WEBHOOK_DELIVERY_HEADER: &str = "x-gitdot-delivery";
+pub const WEBHOOK_SIGNATURE_HEADER: &str = "x-gitdot-signature-256";
+
+const USER_AGENT: &str = "gitdot-webhook/1.0";
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+// Only keep the start of the receiver's response body for the delivery log.
+const RESPONSE_EXCERPT_MAX_BYTES: usize = 1024;
+
+/// Delivers repository events to user-configured webhook URLs.
+///
+/// Every request is a JSON `POST` signed with HMAC-SHA256 over the raw body
+/// using the webhook's secret, sent as `x-gitdot-signature-256: sha256=<hex>`
+/// alongside the event name and a per-delivery id so receivers can verify and
+/// deduplicate deliveries.
+#[async_trait]
+pub trait WebhookClient: Send + Sync + Clone + 'static {
+    /// Posts `body` to `url`, signed with `secret`.
+    ///
+    /// Non-2xx responses are not errors: the status and a truncated excerpt of
+    /// the response body are returned so the caller can log and decide whether
+    /// to retry.
+    ///
+    /// # Errors
+    /// - [`WebhookClientError::RequestError`] — the request could not be sent
+    ///   (DNS, connect, TLS, timeout) or the response body could not be read.
+    async fn deliver(
+        &self,
+        url: &str,
+        secret: &str,
+        event: WebhookEventType,
+        delivery_id: Uuid,
+        body: &[u8],
+    ) -> Result<WebhookHttpResponse, WebhookClientError>;
+}
+
+#[derive(Debug, Clone)]
+pub struct WebhookClientImpl {
+    http: Client,
+}
+
+impl WebhookClientImpl {
+    pub fn new() -> Self {
+        let http = Client::builder()
+            .user_agent(USER_AGENT)
+            .timeout(REQUEST_TIMEOUT)
+            .build()
+            .expect("static reqwest client config is valid");
+        Self { http }
+    }
+}
+
+impl Default for WebhookClientImpl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Signs `body` with `secret`, returning the `sha256=<hex>` header value.
+pub fn sign_webhook_payload(secret: &str, body: &[u8]) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts any key length");
+    mac.update(body);
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+fn truncate_excerpt(mut body: String) -> String {
+    if body.len() > RESPONSE_EXCERPT_MAX_BYTES {
+        let mut end = RESPONSE_EXCERPT_MAX_BYTES;
+        while !body.is_char_boundary(end) {
+            end -= 1;
+        }
+        body.truncate(end);
+    }
+    body
+}
+
+#[crate::instrument_all(level = "debug")]
+#[async_trait]
+impl WebhookClient for WebhookClientImpl {
+    async fn deliver(
+        &self,
+        url: &str,
+        secret: &str,
+        event: WebhookEventType,
+        delivery_id: Uuid,
+        body: &[u8],
+    ) -> Result<WebhookHttpResponse, WebhookClientError> {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            reqwest::header::CONTENT_TYPE,
+            HeaderValue::from_static("application/json"),
+        );
+        headers.insert(
+            WEBHOOK_EVENT_HEADER,
+            HeaderValue::from_static(event.as_str()),
+        );
+        headers.insert(
+            WEBHOOK_DELIVERY_HEADER,
+            HeaderValue::from_str(&delivery_id.to_string()).expect("uuid is a valid header value"),
+        );
+        headers.insert(
+            WEBHOOK_SIGNATURE_HEADER,
+            HeaderValue::from_str(&sign_webhook_payload(secret, body))
+                .expect("hex-encoded signature is a valid header value"),
+        );
+
+        let response = self
+            .http
+            .post(url)
+            .headers(headers)
+            .body(body.to_vec())
+            .send()
+            .await?;
+
+        let status = response.status().as_u16();
+        let body = response.text().await?;
+
+        Ok(WebhookHttpResponse {
+            status,
+            body_excerpt: truncate_excerpt(body),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_signature_matches_known_vector() {
+        // HMAC-SHA256("It's a Secret to Everybody", "Hello, World!")
+        assert_eq!(
+            sign_webhook_payload("It's a Secret to Everybody", b"Hello, World!"),
+            "sha256=757107ea0eb2509fc211221cce984b8a37570b6d7586c22c46f4379c8b043e17"
+        );
+    }
+
+    #[test]
+    fn test_signature_depends_on_secret() {
+        let body = br#"{"ref_name":"refs/heads/main"}"#;
+        assert_ne!(
+            sign_webhook_payload("one", body),
+            sign_webhook_payload("two", body)
+        );
+    }
+
+    #[test]
+    fn test_excerpt_is_truncated_on_char_boundary() {
+        let body = "é".repeat(RESPONSE_EXCERPT_MAX_BYTES);
+        let excerpt = truncate_excerpt(body);
+        assert!(excerpt.len() <= RESPONSE_EXCERPT_MAX_BYTES);
+        assert!(excerpt.chars().all(|c| c == 'é'));
+    }
+
+    #[test]
+    fn test_short_excerpt_is_untouched() {
+        assert_eq!(truncate_excerpt("ok".to_string()), "ok");
+    }
+}
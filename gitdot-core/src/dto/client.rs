@@ -1,7 +1,9 @@
 mod email;
 mod git;
 mod github;
+mod webhook;
 
 pub use email::*;
 pub use git::*;
 pub use github::*;
+pub use webhook::*;
@@ -1,3 +1,4 @@
+use chrono::{DateTime, Utc};
 use serde::Deserialize;
 
 #[derive(Debug, Clone, Deserialize)]
@@ -17,3 +18,130 @@ pub struct GitHubMembership {
     pub state: String,
     pub role: String,
 }
+
+/// A GitHub account as it appears on issues, pull requests and comments.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+pub struct GitHubAccount {
+    pub id: i64,
+    pub login: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct GitHubIssue {
+    pub number: i32,
+    pub title: String,
+    pub body: Option<String>,
+
+    /// `None` for deleted ("ghost") accounts.
+    pub user: Option<GitHubAccount>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct GitHubIssueComment {
+    pub body: Option<String>,
+    pub user: Option<GitHubAccount>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct GitHubDiscussion {
+    pub number: i32,
+    pub title: String,
+    pub body: String,
+    pub author: Option<GitHubAccount>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub comments: Vec<GitHubDiscussionComment>,
+}
+
+#[derive(Debug, Clone)]
+pub struct GitHubDiscussionComment {
+    pub body: String,
+    pub author: Option<GitHubAccount>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+
+    /// Always empty on replies; GitHub threads discussions one level deep.
+    pub replies: Vec<GitHubDiscussionComment>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct GitHubPullRequest {
+    pub number: i32,
+    pub title: String,
+    pub body: Option<String>,
+    pub user: Option<GitHubAccount>,
+
+    /// `open` or `closed`; merged pull requests are closed with `merged_at`.
+    pub state: String,
+    #[serde(default)]
+    pub draft: bool,
+    pub merged_at: Option<DateTime<Utc>>,
+    pub base: GitHubPullRequestBase,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct GitHubPullRequestBase {
+    #[serde(rename = "ref")]
+    pub ref_name: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct GitHubPullRequestCommit {
+    pub sha: String,
+    pub commit: GitHubCommitDetails,
+
+    /// The GitHub account the commit email belongs to, if any.
+    pub author: Option<GitHubAccount>,
+    pub parents: Vec<GitHubCommitParent>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct GitHubCommitDetails {
+    pub message: String,
+    pub author: Option<GitHubCommitAuthor>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct GitHubCommitAuthor {
+    pub email: String,
+    pub date: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct GitHubCommitParent {
+    pub sha: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct GitHubPullRequestReview {
+    pub user: Option<GitHubAccount>,
+    pub body: Option<String>,
+
+    /// `APPROVED`, `CHANGES_REQUESTED`, `COMMENTED`, `DISMISSED` or `PENDING`.
+    pub state: String,
+    pub commit_id: Option<String>,
+    pub submitted_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct GitHubReviewComment {
+    pub id: i64,
+    pub in_reply_to_id: Option<i64>,
+    pub user: Option<GitHubAccount>,
+    pub body: String,
+    pub path: String,
+    pub commit_id: String,
+    pub line: Option<i32>,
+    pub start_line: Option<i32>,
+
+    /// `LEFT` (the old file) or `RIGHT` (the new file).
+    pub side: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
@@ -0,0 +1,6 @@
+/// What a webhook receiver answered to a delivery.
+#[derive(Debug, Clone)]
+pub struct WebhookHttpResponse {
+    pub status: u16,
+    pub body_excerpt: String,
+}
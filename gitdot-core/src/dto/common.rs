@@ -1,11 +1,16 @@
 //! Define common structs and constants that can be shared across domains.
 
+mod branch;
 mod email;
 mod filter;
 mod git;
+mod gpg_key;
 mod owner;
+mod remote;
 mod repository;
 mod runner;
+mod ssh_key;
+mod team;
 mod url;
 mod user_code;
 
@@ -13,12 +18,17 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+pub use branch::BranchPattern;
 pub use email::Email;
 pub use filter::FilterName;
-pub use git::{GitContentType, GitService};
+pub use git::{GitContentType, GitProtocol, GitService, TagName};
+pub use gpg_key::GpgPublicKey;
 pub use owner::OwnerName;
+pub use remote::RemoteUrl;
 pub use repository::RepositoryName;
 pub use runner::RunnerName;
+pub use ssh_key::SshPublicKey;
+pub use team::TeamName;
 pub use url::Url;
 pub use user_code::UserCode;
 
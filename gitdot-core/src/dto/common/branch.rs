@@ -0,0 +1,83 @@
+use std::ops::Deref;
+
+use crate::error::InputError;
+
+/// A validated branch-name glob, e.g. `main` or `release/*`.
+///
+/// Patterns match short branch names, so a leading `refs/heads/` is stripped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BranchPattern(String);
+
+impl BranchPattern {
+    pub(crate) fn parse(value: impl Into<String>, field: &'static str) -> Result<Self, InputError> {
+        let value = value.into();
+        let pattern = value.trim();
+        let pattern = pattern.strip_prefix("refs/heads/").unwrap_or(pattern);
+        if pattern.is_empty() {
+            return Err(InputError::new(field, "cannot be empty"));
+        }
+        if pattern.len() > 255 {
+            return Err(InputError::new(field, "must be at most 255 characters"));
+        }
+        if pattern.chars().any(|c| c.is_whitespace() || c.is_control()) {
+            return Err(InputError::new(field, "cannot contain whitespace"));
+        }
+        if pattern.starts_with('/') || pattern.ends_with('/') || pattern.contains("//") {
+            return Err(InputError::new(field, "cannot have empty path segments"));
+        }
+        Ok(Self(pattern.to_string()))
+    }
+}
+
+impl AsRef<str> for BranchPattern {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Deref for BranchPattern {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(value: &str) -> Result<BranchPattern, InputError> {
+        BranchPattern::parse(value, "branch pattern")
+    }
+
+    #[test]
+    fn accepts_names_and_globs() {
+        assert_eq!(parse("main").unwrap().as_ref(), "main");
+        assert_eq!(parse("release/*").unwrap().as_ref(), "release/*");
+        assert_eq!(parse("**").unwrap().as_ref(), "**");
+    }
+
+    #[test]
+    fn strips_full_ref_prefix() {
+        assert_eq!(parse("refs/heads/main").unwrap().as_ref(), "main");
+    }
+
+    #[test]
+    fn message_names_the_specific_rule() {
+        let msg = |v: &str| parse(v).unwrap_err().to_string();
+        assert_eq!(msg("  "), "Invalid branch pattern: cannot be empty");
+        assert_eq!(
+            msg(&"a".repeat(256)),
+            "Invalid branch pattern: must be at most 255 characters"
+        );
+        assert_eq!(
+            msg("feature branch"),
+            "Invalid branch pattern: cannot contain whitespace"
+        );
+        assert_eq!(
+            msg("release//*"),
+            "Invalid branch pattern: cannot have empty path segments"
+        );
+    }
+}
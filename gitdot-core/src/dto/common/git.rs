@@ -1,6 +1,6 @@
 use std::ops::Deref;
 
-use crate::error::InputError;
+use crate::{error::InputError, util::git::TAG_PREFIX};
 
 /// A validated git smart-HTTP service name.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -66,6 +66,85 @@ impl Deref for GitContentType {
     }
 }
 
+/// A validated `Git-Protocol` request header (e.g. `version=2`), forwarded to
+/// git as `GIT_PROTOCOL` to negotiate the wire protocol version.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GitProtocol(String);
+
+impl GitProtocol {
+    pub(crate) fn parse(value: impl Into<String>, field: &'static str) -> Result<Self, InputError> {
+        let protocol = value.into();
+        // Colon-separated `key[=value]` pairs, per gitprotocol-v2.
+        let valid = !protocol.is_empty()
+            && protocol.len() <= 256
+            && protocol
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '=' | ':' | '.' | '_' | '-'));
+        if !valid {
+            return Err(InputError::new(
+                field,
+                "must be colon-separated key=value pairs",
+            ));
+        }
+        Ok(Self(protocol))
+    }
+}
+
+impl AsRef<str> for GitProtocol {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Deref for GitProtocol {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// A validated short tag name, e.g. `v1.0.0`. A leading `refs/tags/` is
+/// stripped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TagName(String);
+
+impl TagName {
+    pub(crate) fn parse(value: impl Into<String>, field: &'static str) -> Result<Self, InputError> {
+        let value = value.into();
+        let name = value.strip_prefix(TAG_PREFIX).unwrap_or(&value);
+        if name.is_empty() {
+            return Err(InputError::new(field, "cannot be empty"));
+        }
+        if name.len() > 255 {
+            return Err(InputError::new(field, "must be at most 255 characters"));
+        }
+        if !git2::Reference::is_valid_name(&format!("{TAG_PREFIX}{name}")) {
+            return Err(InputError::new(field, "is not a valid git ref name"));
+        }
+        Ok(Self(name.to_string()))
+    }
+
+    /// The full ref name, e.g. `refs/tags/v1.0.0`.
+    pub fn ref_name(&self) -> String {
+        format!("{TAG_PREFIX}{}", self.0)
+    }
+}
+
+impl AsRef<str> for TagName {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Deref for TagName {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -130,4 +209,41 @@ mod tests {
             assert!(GitContentType::parse("", "content type").is_err());
         }
     }
+
+    mod git_protocol {
+        use super::*;
+
+        #[test]
+        fn accepts_protocol_headers() {
+            assert!(GitProtocol::parse("version=2", "git protocol").is_ok());
+            assert!(GitProtocol::parse("version=2:object-format=sha256", "git protocol").is_ok());
+        }
+
+        #[test]
+        fn rejects_invalid_headers() {
+            assert!(GitProtocol::parse("", "git protocol").is_err());
+            assert!(GitProtocol::parse("version=2\nX=1", "git protocol").is_err());
+            assert!(GitProtocol::parse("version 2", "git protocol").is_err());
+            assert!(GitProtocol::parse("v".repeat(257), "git protocol").is_err());
+        }
+    }
+
+    mod tag_name {
+        use super::*;
+
+        #[test]
+        fn accepts_short_and_full_names() {
+            assert_eq!(TagName::parse("v1.0.0", "tag").unwrap().as_ref(), "v1.0.0");
+            let tag = TagName::parse("refs/tags/release/2.1", "tag").unwrap();
+            assert_eq!(tag.as_ref(), "release/2.1");
+            assert_eq!(tag.ref_name(), "refs/tags/release/2.1");
+        }
+
+        #[test]
+        fn rejects_invalid_ref_names() {
+            for name in ["", "refs/tags/", "v1..0", "v1 0", "v1~1", "v1.lock", "-/x/"] {
+                assert!(TagName::parse(name, "tag").is_err(), "{name}");
+            }
+        }
+    }
 }
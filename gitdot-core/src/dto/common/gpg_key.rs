@@ -0,0 +1,159 @@
+use sha1::{Digest, Sha1};
+
+use crate::{
+    error::InputError,
+    util::signature::{
+        PGP_PUBLIC_KEY_TAG, PGP_PUBLIC_SUBKEY_TAG, PGP_USER_ID_TAG, dearmor, pgp_packets,
+    },
+};
+
+/// A validated, ASCII-armored OpenPGP public key, as printed by
+/// `gpg --armor --export`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GpgPublicKey {
+    armored: String,
+    fingerprint: String,
+    key_ids: Vec<String>,
+    user_ids: Vec<String>,
+}
+
+impl GpgPublicKey {
+    /// Parses a single v4 key: a primary key with its user ids and subkeys.
+    pub(crate) fn parse(value: &str, field: &'static str) -> Result<Self, InputError> {
+        let data = dearmor(value, "PGP PUBLIC KEY BLOCK")
+            .ok_or_else(|| InputError::new(field, "must be an ASCII-armored PGP public key"))?;
+        let packets = pgp_packets(&data)
+            .ok_or_else(|| InputError::new(field, "key is not valid OpenPGP data"))?;
+
+        let mut fingerprint = None;
+        let mut key_ids = Vec::new();
+        let mut user_ids = Vec::new();
+        for (tag, body) in packets {
+            match tag {
+                PGP_PUBLIC_KEY_TAG | PGP_PUBLIC_SUBKEY_TAG => {
+                    if tag == PGP_PUBLIC_KEY_TAG && fingerprint.is_some() {
+                        return Err(InputError::new(field, "must contain a single key"));
+                    }
+                    if body.first() != Some(&4) {
+                        return Err(InputError::new(field, "only v4 keys are supported"));
+                    }
+
+                    // A v4 fingerprint is the SHA-1 of the key packet body
+                    // behind an old-format header.
+                    let len = u16::try_from(body.len())
+                        .map_err(|_| InputError::new(field, "key is too large"))?;
+                    let mut hasher = Sha1::new();
+                    hasher.update([0x99]);
+                    hasher.update(len.to_be_bytes());
+                    hasher.update(body);
+                    let digest = hex::encode_upper(hasher.finalize());
+
+                    key_ids.push(digest.clone());
+                    fingerprint.get_or_insert(digest);
+                }
+                PGP_USER_ID_TAG => {
+                    let user_id = String::from_utf8(body.to_vec())
+                        .map_err(|_| InputError::new(field, "user ids must be UTF-8"))?;
+                    user_ids.push(user_id);
+                }
+                _ => {}
+            }
+        }
+
+        let fingerprint =
+            fingerprint.ok_or_else(|| InputError::new(field, "must contain a public key"))?;
+        Ok(Self {
+            armored: value.trim().to_string(),
+            fingerprint,
+            key_ids,
+            user_ids,
+        })
+    }
+
+    pub fn armored(&self) -> &str {
+        &self.armored
+    }
+
+    /// The primary key's 40-hex-digit fingerprint, as printed by
+    /// `gpg --fingerprint` without spaces.
+    pub fn fingerprint(&self) -> &str {
+        &self.fingerprint
+    }
+
+    /// The fingerprints of the primary key and every subkey, any of which may
+    /// have made a signature.
+    pub fn key_ids(&self) -> &[String] {
+        &self.key_ids
+    }
+
+    /// The first user id, e.g. `Alice <alice@example.com>`.
+    pub fn user_id(&self) -> Option<&str> {
+        self.user_ids.first().map(String::as_str)
+    }
+
+    /// The email address of every user id, from `Name <email>` or a bare
+    /// address. User ids without one are skipped.
+    pub fn emails(&self) -> Vec<&str> {
+        self.user_ids
+            .iter()
+            .filter_map(|user_id| match user_id.rsplit_once('<') {
+                Some((_, email)) => email.strip_suffix('>'),
+                None => Some(user_id.trim()),
+            })
+            .filter(|email| email.contains('@'))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // `gpg --quick-gen-key ed25519` plus an ed25519 signing subkey.
+    const KEY: &str = "-----BEGIN PGP PUBLIC KEY BLOCK-----
+
+mDMEatUaJxYJKwYBBAHaRw8BAQdAMnylJbd1nUGM8TrtEMPyH8f8SiPGeUOsnan7
+jiyWVAC0GUFsaWNlIDxhbGljZUBleGFtcGxlLmNvbT6IkAQTFggAOBYhBERg74bA
+CNuAaavNNl3NQNIAVYLIBQJq1RonAhsDBQsJCAcCBhUKCQgLAgQWAgMBAh4BAheA
+AAoJEF3NQNIAVYLIVbYBALftSMnvw1a30jRZmi+mvpFZ9xz3/d1nh1zV83nePenB
+AP9YoZ9nlpz+9gDIoiwAeYltZx9Ixc2EyteNrWfDcts7AbgzBGrVGicWCSsGAQQB
+2kcPAQEHQMsbbmYy+K+jSbfP+PUcgmrZduq5E1Z17R4p1Q5GpyGjiO8EGBYIACAW
+IQREYO+GwAjbgGmrzTZdzUDSAFWCyAUCatUaJwIbAgCBCRBdzUDSAFWCyHYgBBkW
+CAAdFiEEudraX8lWL3bqwfB3QCqt68YAKc8FAmrVGicACgkQQCqt68YAKc88fwD/
+bE72nfHmyb7AOR2jdeXEay3qkIIR9a6+CWPYi7iP+h4BAPoyThFzLdeYFt2FmFaI
+IK2YMa7MbC4qB5ngfo58ku8DsMMBANxOnV3dSA4rQnoM97a5OhgaQeRNn+kL5ef6
+hSFhIGiMAP9DL2rYQPjyShYWGiOPp0bE2vjoWhnfYXbgAf9sgSaVDQ==
+=QZ+4
+-----END PGP PUBLIC KEY BLOCK-----
+";
+
+    #[test]
+    fn fingerprints_match_gpg() {
+        let key = GpgPublicKey::parse(KEY, "public key").unwrap();
+        assert_eq!(
+            key.fingerprint(),
+            "4460EF86C008DB8069ABCD365DCD40D2005582C8"
+        );
+        assert_eq!(
+            key.key_ids(),
+            [
+                "4460EF86C008DB8069ABCD365DCD40D2005582C8",
+                "B9DADA5FC9562F76EAC1F077402AADEBC60029CF"
+            ]
+        );
+        assert_eq!(key.user_id(), Some("Alice <alice@example.com>"));
+        assert_eq!(key.emails(), ["alice@example.com"]);
+    }
+
+    #[test]
+    fn rejects_invalid_keys() {
+        for value in [
+            "",
+            "ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAINMxyRTl8Mv7fUpeuLSY3VjGDnqJEfl/9ePx4G+jBpYj",
+            "-----BEGIN PGP PUBLIC KEY BLOCK-----\n\n!!!\n-----END PGP PUBLIC KEY BLOCK-----",
+            "-----BEGIN PGP PUBLIC KEY BLOCK-----\n\nAAAA\n-----END PGP PUBLIC KEY BLOCK-----",
+        ] {
+            assert!(GpgPublicKey::parse(value, "public key").is_err(), "{value}");
+        }
+    }
+}
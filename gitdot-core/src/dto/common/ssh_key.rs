@@ -0,0 +1,128 @@
+use base64::{
+    Engine,
+    engine::general_purpose::{STANDARD, STANDARD_NO_PAD},
+};
+use sha2::{Digest, Sha256};
+
+use crate::error::InputError;
+
+/// Key types accepted for git over SSH. DSA keys are rejected, as OpenSSH no
+/// longer accepts them either.
+const SUPPORTED_KEY_TYPES: &[&str] = &[
+    "ssh-ed25519",
+    "ssh-rsa",
+    "ecdsa-sha2-nistp256",
+    "ecdsa-sha2-nistp384",
+    "ecdsa-sha2-nistp521",
+    "sk-ssh-ed25519@openssh.com",
+    "sk-ecdsa-sha2-nistp256@openssh.com",
+];
+
+/// A validated OpenSSH public key: its type and base64 key blob, without the
+/// comment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SshPublicKey {
+    key_type: String,
+    key: String,
+}
+
+impl SshPublicKey {
+    /// Parses a line in `authorized_keys` format (`<type> <base64> [comment]`),
+    /// as found in a `.pub` file.
+    pub(crate) fn parse(value: &str, field: &'static str) -> Result<Self, InputError> {
+        let mut parts = value.split_whitespace();
+        match (parts.next(), parts.next()) {
+            (Some(key_type), Some(key)) => Self::from_parts(key_type, key, field),
+            _ => Err(InputError::new(
+                field,
+                "must be an OpenSSH public key (<type> <base64> [comment])",
+            )),
+        }
+    }
+
+    /// Builds a key from its type and base64 blob, as passed to an
+    /// `AuthorizedKeysCommand` by the `%t` and `%k` tokens.
+    pub(crate) fn from_parts(
+        key_type: &str,
+        key: &str,
+        field: &'static str,
+    ) -> Result<Self, InputError> {
+        if !SUPPORTED_KEY_TYPES.contains(&key_type) {
+            return Err(InputError::new(
+                field,
+                format!("unsupported key type {key_type}"),
+            ));
+        }
+
+        // The blob starts with the key type as an SSH string (u32 length +
+        // bytes), which must agree with the declared type.
+        let blob = STANDARD
+            .decode(key)
+            .map_err(|_| InputError::new(field, "key is not valid base64"))?;
+        let embedded_type = blob
+            .get(..4)
+            .map(|len| u32::from_be_bytes([len[0], len[1], len[2], len[3]]) as usize)
+            .and_then(|len| blob.get(4..4 + len));
+        if embedded_type != Some(key_type.as_bytes()) {
+            return Err(InputError::new(field, "key does not match its type"));
+        }
+
+        Ok(Self {
+            key_type: key_type.to_string(),
+            key: key.to_string(),
+        })
+    }
+
+    pub fn key_type(&self) -> &str {
+        &self.key_type
+    }
+
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// The key's `SHA256:` fingerprint, as printed by `ssh-keygen -l`.
+    pub fn fingerprint(&self) -> String {
+        // `key` was decoded successfully when the key was parsed.
+        let blob = STANDARD.decode(&self.key).unwrap_or_default();
+        format!("SHA256:{}", STANDARD_NO_PAD.encode(Sha256::digest(blob)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // `ssh-keygen -t ed25519`, fingerprint as printed by `ssh-keygen -l`.
+    const ED25519: &str = "AAAAC3NzaC1lZDI1NTE5AAAAINMxyRTl8Mv7fUpeuLSY3VjGDnqJEfl/9ePx4G+jBpYj";
+    const ED25519_FINGERPRINT: &str = "SHA256:kXVIe/l/3LxNg44nrYwKRaE3bi5OA59ASVDnOw0eKZk";
+
+    #[test]
+    fn parses_a_pub_file_line() {
+        let key =
+            SshPublicKey::parse(&format!("ssh-ed25519 {ED25519} alice@laptop\n"), "key").unwrap();
+
+        assert_eq!(key.key_type(), "ssh-ed25519");
+        assert_eq!(key.key(), ED25519);
+    }
+
+    #[test]
+    fn fingerprint_matches_ssh_keygen() {
+        let key = SshPublicKey::from_parts("ssh-ed25519", ED25519, "key").unwrap();
+
+        assert_eq!(key.fingerprint(), ED25519_FINGERPRINT);
+    }
+
+    #[test]
+    fn rejects_invalid_keys() {
+        for value in [
+            "",
+            "ssh-ed25519",
+            &format!("ssh-dss {ED25519}"),
+            "ssh-ed25519 not-base64!",
+            &format!("ssh-rsa {ED25519}"),
+        ] {
+            assert!(SshPublicKey::parse(value, "key").is_err(), "{value}");
+        }
+    }
+}
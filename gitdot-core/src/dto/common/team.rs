@@ -0,0 +1,61 @@
+use std::ops::Deref;
+
+use crate::error::InputError;
+
+use super::validate_slug;
+
+/// A validated organization team name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TeamName(String);
+
+impl TeamName {
+    pub(crate) fn parse(value: impl Into<String>, field: &'static str) -> Result<Self, InputError> {
+        let name = value.into().trim().to_lowercase();
+        validate_slug(&name, true).map_err(|reason| InputError::new(field, reason))?;
+        Ok(Self(name))
+    }
+}
+
+impl AsRef<str> for TeamName {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Deref for TeamName {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(value: &str) -> Result<TeamName, InputError> {
+        TeamName::parse(value, "team name")
+    }
+
+    #[test]
+    fn accepts_valid_names() {
+        assert_eq!(parse("backend").unwrap().as_ref(), "backend");
+        assert_eq!(
+            parse("release_managers").unwrap().as_ref(),
+            "release_managers"
+        );
+    }
+
+    #[test]
+    fn sanitizes_whitespace_and_case() {
+        assert_eq!(parse("  Core-Team  ").unwrap().as_ref(), "core-team");
+    }
+
+    #[test]
+    fn rejects_invalid_input() {
+        assert!(parse("a").is_err());
+        assert!(parse("core team").is_err());
+        assert!(parse("-core").is_err());
+    }
+}
@@ -4,8 +4,11 @@ mod email;
 mod github;
 mod issue_task_jwt;
 mod logout;
+mod personal_token;
 mod refresh_session;
+mod signing_key;
 mod slack;
+mod ssh_key;
 mod validate_token;
 
 use serde::{Deserialize, Deserializer, Serialize};
@@ -16,8 +19,11 @@ pub use email::*;
 pub use github::*;
 pub use issue_task_jwt::{IssueTaskJwtRequest, IssueTaskJwtResponse};
 pub use logout::LogoutRequest;
+pub use personal_token::*;
 pub use refresh_session::RefreshSessionRequest;
+pub use signing_key::*;
 pub use slack::*;
+pub use ssh_key::*;
 pub use validate_token::{ValidateTokenRequest, ValidateTokenResponse};
 
 #[derive(Debug, Clone)]
@@ -0,0 +1,35 @@
+mod create_personal_token;
+mod revoke_personal_token;
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::model::{AccessToken, TokenScope};
+
+pub use create_personal_token::{CreatePersonalTokenRequest, CreatePersonalTokenResponse};
+pub use revoke_personal_token::RevokePersonalTokenRequest;
+
+/// A personal access token as shown to its owner. The token itself is only
+/// returned once, when it is created.
+#[derive(Debug, Clone)]
+pub struct PersonalTokenResponse {
+    pub id: Uuid,
+    pub name: String,
+    pub scopes: Vec<TokenScope>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+}
+
+impl From<AccessToken> for PersonalTokenResponse {
+    fn from(token: AccessToken) -> Self {
+        Self {
+            id: token.id,
+            name: token.name,
+            scopes: token.scopes,
+            expires_at: token.expires_at,
+            created_at: token.created_at,
+            last_used_at: token.last_used_at,
+        }
+    }
+}
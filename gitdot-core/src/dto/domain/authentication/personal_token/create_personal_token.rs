@@ -0,0 +1,110 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::{
+    dto::PersonalTokenResponse,
+    error::{InputError, TokenServiceError},
+    model::TokenScope,
+};
+
+const MAX_NAME_LEN: usize = 64;
+
+#[derive(Debug, Clone)]
+pub struct CreatePersonalTokenRequest {
+    pub user_id: Uuid,
+    pub name: String,
+    pub scopes: Vec<TokenScope>,
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+impl CreatePersonalTokenRequest {
+    pub fn new(
+        user_id: Uuid,
+        name: &str,
+        scopes: Vec<String>,
+        expires_at: Option<DateTime<Utc>>,
+    ) -> Result<Self, TokenServiceError> {
+        let name = name.trim();
+        if name.is_empty() || name.chars().count() > MAX_NAME_LEN {
+            return Err(InputError::new(
+                "name",
+                format!("name must be 1 to {MAX_NAME_LEN} characters"),
+            )
+            .into());
+        }
+
+        if scopes.is_empty() {
+            return Err(InputError::new("scopes", "at least one scope is required").into());
+        }
+        let mut parsed = Vec::with_capacity(scopes.len());
+        for scope in &scopes {
+            let scope = TokenScope::try_from(scope.as_str())?;
+            if !parsed.contains(&scope) {
+                parsed.push(scope);
+            }
+        }
+
+        if expires_at.is_some_and(|expires_at| expires_at <= Utc::now()) {
+            return Err(InputError::new("expires_at", "must be in the future").into());
+        }
+
+        Ok(Self {
+            user_id,
+            name: name.to_string(),
+            scopes: parsed,
+            expires_at,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CreatePersonalTokenResponse {
+    pub token: String,
+    pub personal_token: PersonalTokenResponse,
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+
+    use super::*;
+
+    fn scopes(scopes: &[&str]) -> Vec<String> {
+        scopes.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn parses_and_dedupes_scopes() {
+        let request = CreatePersonalTokenRequest::new(
+            Uuid::new_v4(),
+            " deploy ",
+            scopes(&["repo:read", "review:write", "repo:read"]),
+            Some(Utc::now() + Duration::days(30)),
+        )
+        .unwrap();
+
+        assert_eq!(request.name, "deploy");
+        assert_eq!(
+            request.scopes,
+            vec![TokenScope::RepoRead, TokenScope::ReviewWrite]
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_input() {
+        let user_id = Uuid::new_v4();
+        let past = Some(Utc::now() - Duration::minutes(1));
+
+        for (name, scopes, expires_at) in [
+            ("", scopes(&["repo:read"]), None),
+            (&*"a".repeat(MAX_NAME_LEN + 1), scopes(&["repo:read"]), None),
+            ("deploy", scopes(&[]), None),
+            ("deploy", scopes(&["repo:admin"]), None),
+            ("deploy", scopes(&["repo:read"]), past),
+        ] {
+            let err =
+                CreatePersonalTokenRequest::new(user_id, name, scopes, expires_at).unwrap_err();
+            assert!(matches!(err, TokenServiceError::Input(_)));
+        }
+    }
+}
@@ -0,0 +1,13 @@
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct RevokePersonalTokenRequest {
+    pub user_id: Uuid,
+    pub token_id: Uuid,
+}
+
+impl RevokePersonalTokenRequest {
+    pub fn new(user_id: Uuid, token_id: Uuid) -> Self {
+        Self { user_id, token_id }
+    }
+}
@@ -0,0 +1,35 @@
+mod add_signing_key;
+mod delete_signing_key;
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::model::{SigningKey, SigningKeyType};
+
+pub use add_signing_key::{AddSigningKeyRequest, SIGNING_KEY_CHALLENGE_NAMESPACE};
+pub use delete_signing_key::DeleteSigningKeyRequest;
+
+/// A GPG or SSH commit signing key as shown to its owner, identified by its
+/// fingerprint.
+#[derive(Debug, Clone)]
+pub struct SigningKeyResponse {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub name: String,
+    pub key_type: SigningKeyType,
+    pub fingerprint: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<SigningKey> for SigningKeyResponse {
+    fn from(key: SigningKey) -> Self {
+        Self {
+            id: key.id,
+            user_id: key.user_id,
+            name: key.name,
+            key_type: key.key_type,
+            fingerprint: key.fingerprint,
+            created_at: key.created_at,
+        }
+    }
+}
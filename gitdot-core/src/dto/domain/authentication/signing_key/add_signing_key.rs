@@ -0,0 +1,231 @@
+use uuid::Uuid;
+
+use crate::{
+    dto::{GpgPublicKey, SshPublicKey},
+    error::{InputError, SigningKeyError},
+    model::SigningKeyType,
+};
+
+const MAX_NAME_LEN: usize = 64;
+
+/// The `ssh-keygen -Y sign` namespace of the challenge that proves ownership
+/// of an SSH signing key, kept apart from the `git` namespace so that a
+/// commit signature cannot stand in for it.
+pub const SIGNING_KEY_CHALLENGE_NAMESPACE: &str = "gitdot";
+
+#[derive(Debug, Clone)]
+pub struct AddSigningKeyRequest {
+    pub user_id: Uuid,
+    pub name: String,
+    pub key_type: SigningKeyType,
+    /// The armored GPG key, or the SSH key as `<type> <base64>`.
+    pub public_key: String,
+    pub fingerprint: String,
+    /// The ids a signature by this key names its signer with: the
+    /// fingerprints of the GPG primary key and its subkeys, or the SSH key's
+    /// fingerprint.
+    pub key_ids: Vec<String>,
+    /// The email address of every GPG user id, each of which must be one of
+    /// the user's verified emails. Empty for SSH keys.
+    pub emails: Vec<String>,
+    /// For SSH keys, a signature of [`Self::challenge`] by the key.
+    pub signature: Option<String>,
+}
+
+impl AddSigningKeyRequest {
+    /// `public_key` is either the output of `gpg --armor --export` or the
+    /// contents of an SSH `.pub` file. Without a `name`, a GPG key is named
+    /// after its first user id and an SSH key after its comment, falling back
+    /// to the key type.
+    ///
+    /// A GPG key must have at least one user id with an email address. An SSH
+    /// key needs a `signature` of its [`Self::challenge`]; without one, the
+    /// error names the text to sign.
+    pub fn new(
+        user_id: Uuid,
+        name: Option<&str>,
+        public_key: &str,
+        signature: Option<&str>,
+    ) -> Result<Self, SigningKeyError> {
+        let (key_type, default_name, public_key, fingerprint, key_ids, emails) =
+            if public_key.trim_start().starts_with("-----BEGIN PGP") {
+                let parsed = GpgPublicKey::parse(public_key, "public key")?;
+                let emails = parsed.emails();
+                if emails.is_empty() {
+                    return Err(InputError::new(
+                        "public key",
+                        "key must have a user id with an email address",
+                    )
+                    .into());
+                }
+                (
+                    SigningKeyType::Gpg,
+                    parsed
+                        .user_id()
+                        .unwrap_or(SigningKeyType::Gpg.as_str())
+                        .to_string(),
+                    parsed.armored().to_string(),
+                    parsed.fingerprint().to_string(),
+                    parsed.key_ids().to_vec(),
+                    emails.into_iter().map(str::to_string).collect(),
+                )
+            } else {
+                let parsed = SshPublicKey::parse(public_key, "public key")?;
+                let comment = public_key.split_whitespace().skip(2).collect::<Vec<_>>();
+                let fingerprint = parsed.fingerprint();
+                (
+                    SigningKeyType::Ssh,
+                    if comment.is_empty() {
+                        parsed.key_type().to_string()
+                    } else {
+                        comment.join(" ")
+                    },
+                    format!("{} {}", parsed.key_type(), parsed.key()),
+                    fingerprint.clone(),
+                    vec![fingerprint],
+                    Vec::new(),
+                )
+            };
+
+        let signature = signature
+            .map(str::trim)
+            .filter(|signature| !signature.is_empty());
+        if key_type == SigningKeyType::Ssh && signature.is_none() {
+            return Err(InputError::new(
+                "signature",
+                format!(
+                    "sign \"{}\" with `ssh-keygen -Y sign -n {SIGNING_KEY_CHALLENGE_NAMESPACE}`",
+                    Self::challenge(user_id, &fingerprint)
+                ),
+            )
+            .into());
+        }
+
+        let name = name.map_or(default_name, |name| name.trim().to_string());
+        if name.is_empty() || name.chars().count() > MAX_NAME_LEN {
+            return Err(InputError::new(
+                "name",
+                format!("name must be 1 to {MAX_NAME_LEN} characters"),
+            )
+            .into());
+        }
+
+        Ok(Self {
+            user_id,
+            name,
+            key_type,
+            public_key,
+            fingerprint,
+            key_ids,
+            emails,
+            signature: signature.map(str::to_string),
+        })
+    }
+
+    /// The text an SSH key must sign, without a trailing newline, to be
+    /// registered by `user_id`. Naming the user stops a signature made for
+    /// one account being replayed to register the key to another.
+    pub fn challenge(user_id: Uuid, fingerprint: &str) -> String {
+        format!("gitdot signing key {fingerprint} for user {user_id}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SSH_KEY: &str =
+        "ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAINMxyRTl8Mv7fUpeuLSY3VjGDnqJEfl/9ePx4G+jBpYj";
+    // Only checked against the key by the service.
+    const SIGNATURE: &str = "-----BEGIN SSH SIGNATURE-----\nU1NIU0lH\n-----END SSH SIGNATURE-----";
+    // `gpg --quick-gen-key ed25519` plus an ed25519 signing subkey.
+    const GPG_KEY: &str = "-----BEGIN PGP PUBLIC KEY BLOCK-----
+
+mDMEatUaJxYJKwYBBAHaRw8BAQdAMnylJbd1nUGM8TrtEMPyH8f8SiPGeUOsnan7
+jiyWVAC0GUFsaWNlIDxhbGljZUBleGFtcGxlLmNvbT6IkAQTFggAOBYhBERg74bA
+CNuAaavNNl3NQNIAVYLIBQJq1RonAhsDBQsJCAcCBhUKCQgLAgQWAgMBAh4BAheA
+AAoJEF3NQNIAVYLIVbYBALftSMnvw1a30jRZmi+mvpFZ9xz3/d1nh1zV83nePenB
+AP9YoZ9nlpz+9gDIoiwAeYltZx9Ixc2EyteNrWfDcts7AbgzBGrVGicWCSsGAQQB
+2kcPAQEHQMsbbmYy+K+jSbfP+PUcgmrZduq5E1Z17R4p1Q5GpyGjiO8EGBYIACAW
+IQREYO+GwAjbgGmrzTZdzUDSAFWCyAUCatUaJwIbAgCBCRBdzUDSAFWCyHYgBBkW
+CAAdFiEEudraX8lWL3bqwfB3QCqt68YAKc8FAmrVGicACgkQQCqt68YAKc88fwD/
+bE72nfHmyb7AOR2jdeXEay3qkIIR9a6+CWPYi7iP+h4BAPoyThFzLdeYFt2FmFaI
+IK2YMa7MbC4qB5ngfo58ku8DsMMBANxOnV3dSA4rQnoM97a5OhgaQeRNn+kL5ef6
+hSFhIGiMAP9DL2rYQPjyShYWGiOPp0bE2vjoWhnfYXbgAf9sgSaVDQ==
+=QZ+4
+-----END PGP PUBLIC KEY BLOCK-----
+";
+
+    #[test]
+    fn parses_gpg_keys() {
+        let request = AddSigningKeyRequest::new(Uuid::new_v4(), None, GPG_KEY, None).unwrap();
+        assert_eq!(request.key_type, SigningKeyType::Gpg);
+        assert_eq!(request.name, "Alice <alice@example.com>");
+        assert_eq!(
+            request.fingerprint,
+            "4460EF86C008DB8069ABCD365DCD40D2005582C8"
+        );
+        assert_eq!(
+            request.key_ids,
+            [
+                "4460EF86C008DB8069ABCD365DCD40D2005582C8",
+                "B9DADA5FC9562F76EAC1F077402AADEBC60029CF"
+            ]
+        );
+        assert_eq!(request.emails, ["alice@example.com"]);
+    }
+
+    #[test]
+    fn parses_ssh_keys() {
+        let request = AddSigningKeyRequest::new(
+            Uuid::new_v4(),
+            None,
+            &format!("{SSH_KEY} alice@laptop"),
+            Some(SIGNATURE),
+        )
+        .unwrap();
+        assert_eq!(request.key_type, SigningKeyType::Ssh);
+        assert_eq!(request.name, "alice@laptop");
+        assert_eq!(request.public_key, SSH_KEY);
+        assert_eq!(request.key_ids, std::slice::from_ref(&request.fingerprint));
+        assert_eq!(request.signature.as_deref(), Some(SIGNATURE));
+
+        let request =
+            AddSigningKeyRequest::new(Uuid::new_v4(), Some(" work "), SSH_KEY, Some(SIGNATURE))
+                .unwrap();
+        assert_eq!(request.name, "work");
+    }
+
+    #[test]
+    fn ssh_keys_without_a_signature_name_the_challenge() {
+        let user_id = Uuid::new_v4();
+        let err = AddSigningKeyRequest::new(user_id, None, SSH_KEY, Some(" ")).unwrap_err();
+
+        let SigningKeyError::Input(err) = err else {
+            panic!("expected an input error, got {err:?}");
+        };
+        assert!(err.reason.contains(&AddSigningKeyRequest::challenge(
+            user_id,
+            "SHA256:kXVIe/l/3LxNg44nrYwKRaE3bi5OA59ASVDnOw0eKZk"
+        )));
+    }
+
+    #[test]
+    fn rejects_invalid_input() {
+        let user_id = Uuid::new_v4();
+        let long_name = "a".repeat(MAX_NAME_LEN + 1);
+
+        for (name, key) in [
+            (None, "not a key"),
+            (
+                None,
+                "-----BEGIN PGP PUBLIC KEY BLOCK-----\n\nAAAA\n-----END PGP PUBLIC KEY BLOCK-----",
+            ),
+            (Some(""), SSH_KEY),
+            (Some(&*long_name), GPG_KEY),
+        ] {
+            let err = AddSigningKeyRequest::new(user_id, name, key, Some(SIGNATURE)).unwrap_err();
+            assert!(matches!(err, SigningKeyError::Input(_)));
+        }
+    }
+}
@@ -0,0 +1,41 @@
+mod add_ssh_key;
+mod authorize_ssh_command;
+mod delete_ssh_key;
+mod find_ssh_key;
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::model::SshKey;
+
+pub use add_ssh_key::AddSshKeyRequest;
+pub use authorize_ssh_command::AuthorizeSshCommandRequest;
+pub use delete_ssh_key::DeleteSshKeyRequest;
+pub use find_ssh_key::FindSshKeyRequest;
+
+/// An SSH key as shown to its owner. The key material itself is not echoed
+/// back; it is identified by its fingerprint.
+#[derive(Debug, Clone)]
+pub struct SshKeyResponse {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub name: String,
+    pub key_type: String,
+    pub fingerprint: String,
+    pub created_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+}
+
+impl From<SshKey> for SshKeyResponse {
+    fn from(key: SshKey) -> Self {
+        Self {
+            id: key.id,
+            user_id: key.user_id,
+            name: key.name,
+            key_type: key.key_type,
+            fingerprint: key.fingerprint,
+            created_at: key.created_at,
+            last_used_at: key.last_used_at,
+        }
+    }
+}
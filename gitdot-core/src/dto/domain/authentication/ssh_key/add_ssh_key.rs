@@ -0,0 +1,84 @@
+use uuid::Uuid;
+
+use crate::{
+    dto::SshPublicKey,
+    error::{InputError, SshKeyError},
+};
+
+const MAX_NAME_LEN: usize = 64;
+
+#[derive(Debug, Clone)]
+pub struct AddSshKeyRequest {
+    pub user_id: Uuid,
+    pub name: String,
+    pub public_key: SshPublicKey,
+}
+
+impl AddSshKeyRequest {
+    /// `public_key` is the contents of a `.pub` file. Without a `name`, the
+    /// key's comment is used, falling back to its type.
+    pub fn new(user_id: Uuid, name: Option<&str>, public_key: &str) -> Result<Self, SshKeyError> {
+        let parsed = SshPublicKey::parse(public_key, "public key")?;
+
+        let comment = public_key.split_whitespace().skip(2).collect::<Vec<_>>();
+        let name = match name.map(str::trim) {
+            Some(name) => name.to_string(),
+            None if !comment.is_empty() => comment.join(" "),
+            None => parsed.key_type().to_string(),
+        };
+        if name.is_empty() || name.chars().count() > MAX_NAME_LEN {
+            return Err(InputError::new(
+                "name",
+                format!("name must be 1 to {MAX_NAME_LEN} characters"),
+            )
+            .into());
+        }
+
+        Ok(Self {
+            user_id,
+            name,
+            public_key: parsed,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: &str =
+        "ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAINMxyRTl8Mv7fUpeuLSY3VjGDnqJEfl/9ePx4G+jBpYj";
+
+    #[test]
+    fn names_the_key_after_its_comment() {
+        let request =
+            AddSshKeyRequest::new(Uuid::new_v4(), None, &format!("{KEY} alice@laptop")).unwrap();
+        assert_eq!(request.name, "alice@laptop");
+
+        let request = AddSshKeyRequest::new(Uuid::new_v4(), None, KEY).unwrap();
+        assert_eq!(request.name, "ssh-ed25519");
+
+        let request = AddSshKeyRequest::new(
+            Uuid::new_v4(),
+            Some(" work "),
+            &format!("{KEY} alice@laptop"),
+        )
+        .unwrap();
+        assert_eq!(request.name, "work");
+    }
+
+    #[test]
+    fn rejects_invalid_input() {
+        let user_id = Uuid::new_v4();
+        let long_name = "a".repeat(MAX_NAME_LEN + 1);
+
+        for (name, key) in [
+            (None, "not a key"),
+            (Some(""), KEY),
+            (Some(&*long_name), KEY),
+        ] {
+            let err = AddSshKeyRequest::new(user_id, name, key).unwrap_err();
+            assert!(matches!(err, SshKeyError::Input(_)));
+        }
+    }
+}
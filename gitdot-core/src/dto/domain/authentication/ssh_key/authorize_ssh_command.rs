@@ -0,0 +1,129 @@
+use uuid::Uuid;
+
+use crate::{
+    dto::{GitService, OwnerName, RepositoryName, RepositoryPermission},
+    error::{InputError, SshKeyError},
+};
+
+/// A git command run over SSH by the holder of `key_id`, parsed from the
+/// `SSH_ORIGINAL_COMMAND` sshd hands to the forced command, e.g.
+/// `git-upload-pack 'owner/repo.git'`.
+#[derive(Debug, Clone)]
+pub struct AuthorizeSshCommandRequest {
+    pub key_id: Uuid,
+    pub service: GitService,
+    pub owner: OwnerName,
+    pub repo: RepositoryName,
+}
+
+impl AuthorizeSshCommandRequest {
+    pub fn new(key_id: Uuid, command: &str) -> Result<Self, SshKeyError> {
+        let invalid = || {
+            InputError::new(
+                "command",
+                "must be git-upload-pack or git-receive-pack '<owner>/<repo>'",
+            )
+        };
+
+        let command = command.trim();
+        let (program, rest) = command
+            .split_once(char::is_whitespace)
+            .ok_or_else(invalid)?;
+        // `git upload-pack` is accepted as a spelling of `git-upload-pack`.
+        let (service, path) = match program {
+            "git" => {
+                let (subcommand, path) = rest
+                    .trim_start()
+                    .split_once(char::is_whitespace)
+                    .ok_or_else(invalid)?;
+                (format!("git-{subcommand}"), path)
+            }
+            _ => (program.to_string(), rest),
+        };
+        let service = GitService::parse(service, "command")?;
+
+        let path = path.trim();
+        let path = path
+            .strip_prefix('\'')
+            .and_then(|path| path.strip_suffix('\''))
+            .unwrap_or(path);
+        let path = path.strip_prefix('/').unwrap_or(path);
+        let (owner, repo) = path
+            .split_once('/')
+            .filter(|(_, repo)| !repo.contains('/'))
+            .ok_or_else(invalid)?;
+
+        Ok(Self {
+            key_id,
+            service,
+            owner: OwnerName::parse(owner, "owner name")?,
+            repo: RepositoryName::parse(repo, "repository name")?,
+        })
+    }
+
+    /// Fetching needs read access; pushing needs write access.
+    pub fn permission(&self) -> RepositoryPermission {
+        if self.service.as_ref() == "git-receive-pack" {
+            RepositoryPermission::Write
+        } else {
+            RepositoryPermission::Read
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(command: &str) -> Result<AuthorizeSshCommandRequest, SshKeyError> {
+        AuthorizeSshCommandRequest::new(Uuid::new_v4(), command)
+    }
+
+    #[test]
+    fn parses_git_commands() {
+        for (command, service, permission) in [
+            (
+                "git-upload-pack 'alice/dotfiles.git'",
+                "git-upload-pack",
+                RepositoryPermission::Read,
+            ),
+            (
+                "git-upload-pack '/alice/dotfiles'",
+                "git-upload-pack",
+                RepositoryPermission::Read,
+            ),
+            (
+                "git upload-pack 'alice/dotfiles.git'",
+                "git-upload-pack",
+                RepositoryPermission::Read,
+            ),
+            (
+                "git-receive-pack 'alice/dotfiles.git'",
+                "git-receive-pack",
+                RepositoryPermission::Write,
+            ),
+        ] {
+            let request = parse(command).unwrap();
+            assert_eq!(request.service.as_ref(), service);
+            assert_eq!(request.owner.as_ref(), "alice");
+            assert_eq!(request.repo.as_ref(), "dotfiles");
+            assert_eq!(request.permission(), permission);
+        }
+    }
+
+    #[test]
+    fn rejects_other_commands() {
+        for command in [
+            "",
+            "bash",
+            "git-upload-pack",
+            "git-upload-archive 'alice/dotfiles.git'",
+            "git-upload-pack 'dotfiles.git'",
+            "git-upload-pack 'alice/dotfiles/../x.git'",
+            "git-upload-pack '../alice/dotfiles.git'",
+            "rm -rf /",
+        ] {
+            assert!(parse(command).is_err(), "{command}");
+        }
+    }
+}
@@ -0,0 +1,13 @@
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct DeleteSshKeyRequest {
+    pub user_id: Uuid,
+    pub key_id: Uuid,
+}
+
+impl DeleteSshKeyRequest {
+    pub fn new(user_id: Uuid, key_id: Uuid) -> Self {
+        Self { user_id, key_id }
+    }
+}
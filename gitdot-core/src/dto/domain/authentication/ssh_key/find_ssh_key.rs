@@ -0,0 +1,16 @@
+use crate::{dto::SshPublicKey, error::SshKeyError};
+
+/// Looks up the user a presented key belongs to, as sshd's
+/// `AuthorizedKeysCommand` does with the key's type and blob.
+#[derive(Debug, Clone)]
+pub struct FindSshKeyRequest {
+    pub public_key: SshPublicKey,
+}
+
+impl FindSshKeyRequest {
+    pub fn new(key_type: &str, key: &str) -> Result<Self, SshKeyError> {
+        Ok(Self {
+            public_key: SshPublicKey::from_parts(key_type, key, "public key")?,
+        })
+    }
+}
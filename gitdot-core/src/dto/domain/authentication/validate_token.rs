@@ -1,11 +1,13 @@
 use uuid::Uuid;
 
-use crate::model::TokenType;
+use crate::model::{TokenScope, TokenType};
 
 #[derive(Debug, Clone)]
 pub struct ValidateTokenRequest {
     pub token: String,
     pub token_type: TokenType,
+    /// The scope the request needs, checked against the token's scopes.
+    pub scope: Option<TokenScope>,
 }
 
 #[derive(Debug, Clone)]
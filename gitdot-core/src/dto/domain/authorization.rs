@@ -10,6 +10,8 @@ mod verify_authorized_for_review;
 mod verify_authorized_for_review_comment;
 mod verify_authorized_for_reviewing;
 
+use crate::model::RepositoryRole;
+
 pub use verify_authorized_for_answer::AnswerAuthorizationRequest;
 pub use verify_authorized_for_comment::CommentAuthorizationRequest;
 pub use verify_authorized_for_migration::MigrationAuthorizationRequest;
@@ -22,18 +24,47 @@ pub use verify_authorized_for_review::ReviewAuthorizationRequest;
 pub use verify_authorized_for_review_comment::ReviewCommentAuthorizationRequest;
 pub use verify_authorized_for_reviewing::ReviewingAuthorizationRequest;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// What a caller needs on a repository. Variants are ordered, so a caller
+/// holding a higher permission is granted every lower one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub enum RepositoryPermission {
     Read,
+    Triage,
     Write,
+    Maintain,
     Admin,
 }
 
+impl RepositoryPermission {
+    /// Whether the permission covers changing the repository's contents, which
+    /// a readonly repository refuses to everyone.
+    pub fn writes_contents(self) -> bool {
+        matches!(
+            self,
+            RepositoryPermission::Write | RepositoryPermission::Maintain
+        )
+    }
+}
+
+impl From<RepositoryRole> for RepositoryPermission {
+    fn from(role: RepositoryRole) -> Self {
+        match role {
+            RepositoryRole::Read => RepositoryPermission::Read,
+            RepositoryRole::Triage => RepositoryPermission::Triage,
+            RepositoryRole::Write => RepositoryPermission::Write,
+            RepositoryRole::Maintain => RepositoryPermission::Maintain,
+            RepositoryRole::Admin => RepositoryPermission::Admin,
+        }
+    }
+}
+
 impl Into<String> for RepositoryPermission {
     fn into(self) -> String {
         match self {
             RepositoryPermission::Read => "read".to_string(),
+            RepositoryPermission::Triage => "triage".to_string(),
             RepositoryPermission::Write => "write".to_string(),
+            RepositoryPermission::Maintain => "maintain".to_string(),
             RepositoryPermission::Admin => "admin".to_string(),
         }
     }
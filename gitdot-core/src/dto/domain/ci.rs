@@ -1,7 +1,11 @@
+mod artifact;
 mod build;
 mod runner;
+mod secret;
 mod task;
 
+pub use artifact::*;
 pub use build::*;
 pub use runner::*;
+pub use secret::*;
 pub use task::*;
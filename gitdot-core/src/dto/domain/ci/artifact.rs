@@ -0,0 +1,42 @@
+mod upload_artifact;
+mod upload_cache;
+
+use bytes::Bytes;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::model::Artifact;
+
+pub use upload_artifact::UploadArtifactRequest;
+pub use upload_cache::UploadCacheRequest;
+
+#[derive(Debug, Clone)]
+pub struct ArtifactResponse {
+    pub id: Uuid,
+    pub build_id: Uuid,
+    pub task_id: Uuid,
+    pub name: String,
+    pub size_bytes: i64,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<Artifact> for ArtifactResponse {
+    fn from(artifact: Artifact) -> Self {
+        Self {
+            id: artifact.id,
+            build_id: artifact.build_id,
+            task_id: artifact.task_id,
+            name: artifact.name,
+            size_bytes: artifact.size_bytes,
+            created_at: artifact.created_at,
+        }
+    }
+}
+
+/// A downloaded artifact: its gzipped tarball and the path it was archived
+/// from.
+#[derive(Debug, Clone)]
+pub struct ArtifactDownload {
+    pub name: String,
+    pub bytes: Bytes,
+}
@@ -0,0 +1,19 @@
+use bytes::Bytes;
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct UploadArtifactRequest {
+    pub task_id: Uuid,
+    pub name: String,
+    pub bytes: Bytes,
+}
+
+impl UploadArtifactRequest {
+    pub fn new(task_id: Uuid, name: &str, bytes: Bytes) -> Self {
+        Self {
+            task_id,
+            name: name.to_string(),
+            bytes,
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use bytes::Bytes;
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct UploadCacheRequest {
+    pub task_id: Uuid,
+    pub bytes: Bytes,
+}
+
+impl UploadCacheRequest {
+    pub fn new(task_id: Uuid, bytes: Bytes) -> Self {
+        Self { task_id, bytes }
+    }
+}
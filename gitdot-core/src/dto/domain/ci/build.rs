@@ -20,6 +20,9 @@ pub struct BuildResponse {
     pub trigger: BuildTrigger,
     pub commit_sha: String,
     pub status: BuildStatus,
+    pub review_id: Option<Uuid>,
+    pub diff_id: Option<Uuid>,
+    pub revision_id: Option<Uuid>,
     pub total_tasks: i32,
     pub completed_tasks: i32,
     pub created_at: DateTime<Utc>,
@@ -36,6 +39,9 @@ impl From<BuildWithStats> for BuildResponse {
             trigger: build.trigger,
             commit_sha: build.commit_sha,
             status: build.status,
+            review_id: build.review_id,
+            diff_id: build.diff_id,
+            revision_id: build.revision_id,
             total_tasks: build.total_tasks,
             completed_tasks: build.completed_tasks,
             created_at: build.created_at,
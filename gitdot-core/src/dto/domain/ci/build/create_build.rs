@@ -1,6 +1,16 @@
+use gitdot_config::ci::BuildTrigger;
+
 use crate::{
-    dto::common::{OwnerName, RepositoryName},
+    dto::{
+        DiffResponse, ReviewResponse, RevisionResponse,
+        common::{OwnerName, RepositoryName},
+    },
     error::BuildError,
+    model::BuildReviewLink,
+    util::{
+        git::{BRANCH_PREFIX, DEFAULT_BRANCH, TAG_PREFIX, ZERO_SHA},
+        review::get_revision_ref,
+    },
 };
 
 #[derive(Debug, Clone)]
@@ -9,20 +19,174 @@ pub struct CreateBuildRequest {
     pub repo_name: RepositoryName,
     pub ref_name: String,
     pub commit_sha: String,
+    pub trigger: BuildTrigger,
+    /// Branch the build's `branches` filter and `if` conditions see: the pushed
+    /// branch, or a review's target branch.
+    pub branch: Option<String>,
+    /// Tag the build's `tags` filter and `if` conditions see.
+    pub tag: Option<String>,
+    /// Commit the build's `paths` filter diffs against; unset when the changed
+    /// paths are unknown (manual builds, new refs).
+    pub base_sha: Option<String>,
+    pub review_link: Option<BuildReviewLink>,
 }
 
 impl CreateBuildRequest {
+    /// A manually dispatched build of `commit_sha`.
     pub fn new(
         repo_owner: &str,
         repo_name: &str,
         ref_name: String,
         commit_sha: String,
     ) -> Result<Self, BuildError> {
+        let (branch, tag) = match ref_name.strip_prefix(TAG_PREFIX) {
+            Some(tag) => (None, Some(tag.to_string())),
+            None => {
+                let branch = ref_name.strip_prefix(BRANCH_PREFIX).unwrap_or(&ref_name);
+                (Some(branch.to_string()), None)
+            }
+        };
         Ok(Self {
             repo_owner: OwnerName::parse(repo_owner, "owner name")?,
             repo_name: RepositoryName::parse(repo_name, "repository name")?,
             ref_name,
             commit_sha,
+            trigger: BuildTrigger::Manual,
+            branch,
+            tag,
+            base_sha: None,
+            review_link: None,
+        })
+    }
+
+    /// A build of a pushed ref: [`PushToMain`] for the default branch and
+    /// [`TagPush`] for tags. `None` for pushes that start no build (deletions
+    /// and other branches, which are built through reviews).
+    ///
+    /// [`PushToMain`]: BuildTrigger::PushToMain
+    /// [`TagPush`]: BuildTrigger::TagPush
+    pub fn for_push(
+        repo_owner: &str,
+        repo_name: &str,
+        ref_name: String,
+        old_sha: String,
+        new_sha: String,
+    ) -> Result<Option<Self>, BuildError> {
+        if new_sha == ZERO_SHA {
+            return Ok(None);
+        }
+
+        let (trigger, branch, tag) = if let Some(tag) = ref_name.strip_prefix(TAG_PREFIX) {
+            (BuildTrigger::TagPush, None, Some(tag.to_string()))
+        } else if ref_name.strip_prefix(BRANCH_PREFIX) == Some(DEFAULT_BRANCH) {
+            (
+                BuildTrigger::PushToMain,
+                Some(DEFAULT_BRANCH.to_string()),
+                None,
+            )
+        } else {
+            return Ok(None);
+        };
+
+        Ok(Some(Self {
+            repo_owner: OwnerName::parse(repo_owner, "owner name")?,
+            repo_name: RepositoryName::parse(repo_name, "repository name")?,
+            ref_name,
+            commit_sha: new_sha,
+            trigger,
+            branch,
+            tag,
+            base_sha: (old_sha != ZERO_SHA).then_some(old_sha),
+            review_link: None,
+        }))
+    }
+
+    /// A [`PullRequest`](BuildTrigger::PullRequest) build of `revision`'s
+    /// commit, linked back to its review and diff and named after the
+    /// revision's ref.
+    pub fn for_review_revision(
+        repo_owner: &str,
+        repo_name: &str,
+        review: &ReviewResponse,
+        diff: &DiffResponse,
+        revision: &RevisionResponse,
+    ) -> Result<Self, BuildError> {
+        let review_ref_id = review.id.to_string();
+        Ok(Self {
+            repo_owner: OwnerName::parse(repo_owner, "owner name")?,
+            repo_name: RepositoryName::parse(repo_name, "repository name")?,
+            ref_name: get_revision_ref(&review_ref_id[..8], diff.position, revision.number),
+            commit_sha: revision.commit_hash.clone(),
+            trigger: BuildTrigger::PullRequest,
+            branch: Some(review.target_branch.clone()),
+            tag: None,
+            base_sha: Some(revision.parent_hash.clone()).filter(|sha| !sha.is_empty()),
+            review_link: Some(BuildReviewLink {
+                review_id: review.id,
+                diff_id: diff.id,
+                revision_id: revision.id,
+            }),
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OLD_SHA: &str = "1111111111111111111111111111111111111111";
+    const NEW_SHA: &str = "2222222222222222222222222222222222222222";
+
+    fn push(ref_name: &str, old_sha: &str, new_sha: &str) -> Option<CreateBuildRequest> {
+        CreateBuildRequest::for_push(
+            "owner",
+            "repo",
+            ref_name.to_string(),
+            old_sha.to_string(),
+            new_sha.to_string(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn default_branch_push_starts_a_push_to_main_build() {
+        let request = push("refs/heads/main", OLD_SHA, NEW_SHA).unwrap();
+        assert_eq!(request.trigger, BuildTrigger::PushToMain);
+        assert_eq!(request.branch.as_deref(), Some("main"));
+        assert_eq!(request.base_sha.as_deref(), Some(OLD_SHA));
+    }
+
+    #[test]
+    fn tag_push_starts_a_tag_build() {
+        let request = push("refs/tags/v1.0", ZERO_SHA, NEW_SHA).unwrap();
+        assert_eq!(request.trigger, BuildTrigger::TagPush);
+        assert_eq!(request.tag.as_deref(), Some("v1.0"));
+        assert_eq!(request.base_sha, None);
+    }
+
+    #[test]
+    fn other_branches_and_deletions_start_no_build() {
+        assert!(push("refs/heads/feature/main", OLD_SHA, NEW_SHA).is_none());
+        assert!(push("refs/heads/main", OLD_SHA, ZERO_SHA).is_none());
+        assert!(push("refs/tags/v1.0", OLD_SHA, ZERO_SHA).is_none());
+    }
+
+    #[test]
+    fn manual_builds_read_branch_or_tag_from_ref() {
+        let request =
+            CreateBuildRequest::new("owner", "repo", "main".to_string(), NEW_SHA.to_string())
+                .unwrap();
+        assert_eq!(request.trigger, BuildTrigger::Manual);
+        assert_eq!(request.branch.as_deref(), Some("main"));
+
+        let request = CreateBuildRequest::new(
+            "owner",
+            "repo",
+            "refs/tags/v2".to_string(),
+            NEW_SHA.to_string(),
+        )
+        .unwrap();
+        assert_eq!(request.tag.as_deref(), Some("v2"));
+        assert_eq!(request.branch, None);
+    }
+}
@@ -8,6 +8,7 @@ use crate::{
 pub struct ListBuildsRequest {
     pub repo_owner: OwnerName,
     pub repo_name: RepositoryName,
+    pub review_number: Option<i32>,
     pub cursor: Option<Cursor>,
     pub limit: u32,
 }
@@ -16,6 +17,7 @@ impl ListBuildsRequest {
     pub fn new(
         repo_owner: &str,
         repo_name: &str,
+        review_number: Option<i32>,
         cursor: Option<&str>,
         limit: Option<u32>,
     ) -> Result<Self, BuildError> {
@@ -25,6 +27,7 @@ impl ListBuildsRequest {
         Ok(Self {
             repo_owner,
             repo_name,
+            review_number,
             cursor,
             limit: limit
                 .unwrap_or(DEFAULT_PER_PAGE_LIMIT)
@@ -0,0 +1,71 @@
+mod delete_secret;
+mod set_secret;
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+
+use crate::{
+    dto::common::{OwnerName, RepositoryName},
+    error::CiSecretError,
+    model::CiSecret,
+};
+
+pub use delete_secret::DeleteCiSecretRequest;
+pub use set_secret::SetCiSecretRequest;
+
+/// Where a CI secret lives. Organization secrets are visible to every
+/// repository the organization owns.
+#[derive(Debug, Clone)]
+pub enum CiSecretScope {
+    Repository {
+        owner_name: OwnerName,
+        repo_name: RepositoryName,
+    },
+    Organization {
+        org_name: OwnerName,
+    },
+}
+
+impl CiSecretScope {
+    pub fn repository(owner: &str, repo: &str) -> Result<Self, CiSecretError> {
+        Ok(Self::Repository {
+            owner_name: OwnerName::parse(owner, "owner name")?,
+            repo_name: RepositoryName::parse(repo, "repository name")?,
+        })
+    }
+
+    pub fn organization(org_name: &str) -> Result<Self, CiSecretError> {
+        Ok(Self::Organization {
+            org_name: OwnerName::parse(org_name, "organization name")?,
+        })
+    }
+}
+
+/// A CI secret as shown to admins: its name and when it was set, never its
+/// value.
+#[derive(Debug, Clone)]
+pub struct CiSecretResponse {
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<CiSecret> for CiSecretResponse {
+    fn from(secret: CiSecret) -> Self {
+        Self {
+            name: secret.name,
+            created_at: secret.created_at,
+            updated_at: secret.updated_at,
+        }
+    }
+}
+
+/// The decrypted secrets a task declares, keyed by name. `withheld` is set
+/// when the task belongs to a build of a review whose author may not see the
+/// repository's secrets, in which case `secrets` is empty.
+#[derive(Debug, Clone, Default)]
+pub struct TaskSecretsResponse {
+    pub secrets: BTreeMap<String, String>,
+    pub withheld: bool,
+}
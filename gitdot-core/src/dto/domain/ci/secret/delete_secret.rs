@@ -0,0 +1,16 @@
+use crate::dto::CiSecretScope;
+
+#[derive(Debug, Clone)]
+pub struct DeleteCiSecretRequest {
+    pub scope: CiSecretScope,
+    pub name: String,
+}
+
+impl DeleteCiSecretRequest {
+    pub fn new(scope: CiSecretScope, name: &str) -> Self {
+        Self {
+            scope,
+            name: name.trim().to_string(),
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use crate::{
+    dto::CiSecretScope,
+    error::{CiSecretError, InputError},
+};
+
+const MAX_NAME_LEN: usize = 128;
+const MAX_VALUE_LEN: usize = 64 * 1024;
+const RESERVED_PREFIX: &str = "GITDOT_";
+
+#[derive(Debug, Clone)]
+pub struct SetCiSecretRequest {
+    pub scope: CiSecretScope,
+    pub name: String,
+    pub value: String,
+}
+
+impl SetCiSecretRequest {
+    pub fn new(scope: CiSecretScope, name: &str, value: String) -> Result<Self, CiSecretError> {
+        let name = name.trim();
+        if !is_secret_name(name) {
+            return Err(InputError::new(
+                "secret name",
+                "must be 1-128 letters, digits or '_' and not start with a digit",
+            )
+            .into());
+        }
+        if name.starts_with(RESERVED_PREFIX) {
+            return Err(InputError::new(
+                "secret name",
+                format!("cannot start with {RESERVED_PREFIX}"),
+            )
+            .into());
+        }
+        if value.is_empty() || value.len() > MAX_VALUE_LEN {
+            return Err(
+                InputError::new("secret value", "must be between 1 byte and 64 KiB").into(),
+            );
+        }
+
+        Ok(Self {
+            scope,
+            name: name.to_string(),
+            value,
+        })
+    }
+}
+
+/// Secret names are env variable names, matching what `.gitdot-ci.toml`
+/// accepts under a task's `secrets`.
+fn is_secret_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    name.len() <= MAX_NAME_LEN
+        && chars
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scope() -> CiSecretScope {
+        CiSecretScope::repository("alice", "repo").unwrap()
+    }
+
+    #[test]
+    fn accepts_env_variable_names() {
+        let request = SetCiSecretRequest::new(scope(), " DEPLOY_TOKEN ", "x".into()).unwrap();
+        assert_eq!(request.name, "DEPLOY_TOKEN");
+    }
+
+    #[test]
+    fn rejects_invalid_names() {
+        for name in [
+            "",
+            "1TOKEN",
+            "DEPLOY-TOKEN",
+            "GITDOT_TOKEN",
+            &"A".repeat(129),
+        ] {
+            assert!(
+                SetCiSecretRequest::new(scope(), name, "x".into()).is_err(),
+                "{name:?} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_empty_and_oversized_values() {
+        assert!(SetCiSecretRequest::new(scope(), "TOKEN", String::new()).is_err());
+        assert!(SetCiSecretRequest::new(scope(), "TOKEN", "x".repeat(MAX_VALUE_LEN + 1)).is_err());
+    }
+}
@@ -3,7 +3,7 @@ mod update_task;
 use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
-use crate::model::{Task, TaskStatus};
+use crate::model::{Task, TaskOptions, TaskStatus};
 
 pub use update_task::UpdateTaskRequest;
 
@@ -17,6 +17,8 @@ pub struct TaskResponse {
     pub command: String,
     pub status: TaskStatus,
     pub waits_for: Vec<Uuid>,
+    pub attempt: i32,
+    pub options: TaskOptions,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -32,6 +34,8 @@ impl From<Task> for TaskResponse {
             command: task.command,
             status: task.status,
             waits_for: task.waits_for,
+            attempt: task.attempt,
+            options: task.options,
             created_at: task.created_at,
             updated_at: task.updated_at,
         }
@@ -15,9 +15,10 @@ impl UpdateTaskRequest {
     pub fn new(id: Uuid, status: &str) -> Result<Self, TaskError> {
         let status = TaskStatus::try_from(status)?;
         match status {
-            TaskStatus::Running | TaskStatus::Success | TaskStatus::Failure => {
-                Ok(Self { id, status })
-            }
+            TaskStatus::Running
+            | TaskStatus::Success
+            | TaskStatus::Failure
+            | TaskStatus::TimedOut => Ok(Self { id, status }),
             _ => Err(InputError::new("task status", Into::<String>::into(status)).into()),
         }
     }
@@ -1,15 +1,31 @@
+mod branch_protection;
+mod code_search;
+mod collaborator;
 mod commit;
 mod git_http;
+mod maintenance;
+mod mirror;
 mod organization;
 mod question;
+mod release;
 mod repository;
 mod review;
+mod tag;
+mod team;
 mod user;
 
+pub use branch_protection::*;
+pub use code_search::*;
+pub use collaborator::*;
 pub use commit::*;
 pub use git_http::*;
+pub use maintenance::*;
+pub use mirror::*;
 pub use organization::*;
 pub use question::*;
+pub use release::*;
 pub use repository::*;
 pub use review::*;
+pub use tag::*;
+pub use team::*;
 pub use user::*;
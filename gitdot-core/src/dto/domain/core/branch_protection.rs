@@ -0,0 +1,45 @@
+mod check_ref_updates;
+mod create_branch_protection;
+mod delete_branch_protection;
+mod list_branch_protections;
+mod update_branch_protection;
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::model::BranchProtection;
+
+pub use check_ref_updates::{CheckRefUpdatesRequest, RefUpdate, RefUpdateRejection};
+pub use create_branch_protection::CreateBranchProtectionRequest;
+pub use delete_branch_protection::DeleteBranchProtectionRequest;
+pub use list_branch_protections::ListBranchProtectionsRequest;
+pub use update_branch_protection::UpdateBranchProtectionRequest;
+
+#[derive(Debug, Clone)]
+pub struct BranchProtectionResponse {
+    pub id: Uuid,
+    pub repository_id: Uuid,
+    pub pattern: String,
+    pub block_force_push: bool,
+    pub block_deletion: bool,
+    pub require_review: bool,
+    pub require_green_build: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<BranchProtection> for BranchProtectionResponse {
+    fn from(protection: BranchProtection) -> Self {
+        Self {
+            id: protection.id,
+            repository_id: protection.repository_id,
+            pattern: protection.pattern,
+            block_force_push: protection.block_force_push,
+            block_deletion: protection.block_deletion,
+            require_review: protection.require_review,
+            require_green_build: protection.require_green_build,
+            created_at: protection.created_at,
+            updated_at: protection.updated_at,
+        }
+    }
+}
@@ -0,0 +1,137 @@
+use crate::{
+    dto::{OwnerName, RepositoryName},
+    error::{BranchProtectionError, InputError},
+};
+
+/// The ref updates of a single push, as seen by the `pre-receive` hook.
+#[derive(Debug, Clone)]
+pub struct CheckRefUpdatesRequest {
+    pub owner: OwnerName,
+    pub repo: RepositoryName,
+    pub updates: Vec<RefUpdate>,
+}
+
+/// One `<old> <new> <ref>` line from `pre-receive` stdin.
+///
+/// `forced` is computed by the hook (`git merge-base --is-ancestor`) because
+/// the pushed objects are still quarantined and invisible to the server. The
+/// hook relays each line with it appended, as `<old> <new> <ref> <forced>`.
+#[derive(Debug, Clone)]
+pub struct RefUpdate {
+    pub ref_name: String,
+    pub old_sha: String,
+    pub new_sha: String,
+    pub forced: bool,
+}
+
+impl RefUpdate {
+    /// The short branch name for `refs/heads/*` refs, `None` for any other ref.
+    pub fn branch(&self) -> Option<&str> {
+        self.ref_name.strip_prefix("refs/heads/")
+    }
+
+    pub fn is_deletion(&self) -> bool {
+        is_zero_sha(&self.new_sha)
+    }
+
+    pub fn is_creation(&self) -> bool {
+        is_zero_sha(&self.old_sha)
+    }
+
+    fn parse_hook_line(line: &str) -> Result<Self, InputError> {
+        let invalid = || InputError::new("ref update", format!("malformed line: {line:?}"));
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        let [old_sha, new_sha, ref_name, forced] = fields[..] else {
+            return Err(invalid());
+        };
+        let is_sha = |sha: &str| sha.len() >= 40 && sha.bytes().all(|b| b.is_ascii_hexdigit());
+        if !is_sha(old_sha) || !is_sha(new_sha) || !ref_name.starts_with("refs/") {
+            return Err(invalid());
+        }
+
+        Ok(Self {
+            ref_name: ref_name.to_string(),
+            old_sha: old_sha.to_string(),
+            new_sha: new_sha.to_string(),
+            forced: forced.parse().map_err(|_| invalid())?,
+        })
+    }
+}
+
+fn is_zero_sha(sha: &str) -> bool {
+    !sha.is_empty() && sha.bytes().all(|b| b == b'0')
+}
+
+/// A ref update refused by a branch protection rule, with the reason relayed
+/// back to the git client.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RefUpdateRejection {
+    pub ref_name: String,
+    pub reason: String,
+}
+
+impl CheckRefUpdatesRequest {
+    pub fn new(
+        owner: &str,
+        repo: &str,
+        updates: Vec<RefUpdate>,
+    ) -> Result<Self, BranchProtectionError> {
+        Ok(Self {
+            owner: OwnerName::parse(owner, "owner name")?,
+            repo: RepositoryName::parse(repo, "repository name")?,
+            updates,
+        })
+    }
+
+    /// Parses the hook's `<old> <new> <ref> <forced>` lines. Ref names cannot
+    /// contain whitespace, so splitting on it is unambiguous.
+    pub fn from_hook_input(
+        owner: &str,
+        repo: &str,
+        input: &str,
+    ) -> Result<Self, BranchProtectionError> {
+        let updates = input
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(RefUpdate::parse_hook_line)
+            .collect::<Result<_, _>>()?;
+        Self::new(owner, repo, updates)
+    }
+
+    pub fn get_repo_path(&self) -> String {
+        format!("{}/{}", self.owner.as_ref(), self.repo.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OLD: &str = "1111111111111111111111111111111111111111";
+    const NEW: &str = "2222222222222222222222222222222222222222";
+
+    #[test]
+    fn parses_hook_lines() {
+        let input = format!("{OLD} {NEW} refs/heads/main false\n{OLD} {NEW} refs/tags/v1 true\n");
+        let request = CheckRefUpdatesRequest::from_hook_input("alice", "demo", &input).unwrap();
+
+        assert_eq!(request.updates.len(), 2);
+        assert_eq!(request.updates[0].branch(), Some("main"));
+        assert!(!request.updates[0].forced);
+        assert_eq!(request.updates[1].ref_name, "refs/tags/v1");
+        assert!(request.updates[1].forced);
+    }
+
+    #[test]
+    fn rejects_malformed_hook_lines() {
+        for line in [
+            format!("{OLD} {NEW} refs/heads/main"),
+            format!("{OLD} {NEW} refs/heads/main yes"),
+            format!("{OLD} not-a-sha refs/heads/main false"),
+            format!("{OLD} {NEW} main false"),
+            format!("{OLD} {NEW} refs/heads/main false extra"),
+        ] {
+            assert!(CheckRefUpdatesRequest::from_hook_input("alice", "demo", &line).is_err());
+        }
+    }
+}
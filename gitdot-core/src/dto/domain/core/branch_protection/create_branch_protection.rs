@@ -0,0 +1,33 @@
+use crate::{
+    dto::{BranchPattern, OwnerName, RepositoryName},
+    error::BranchProtectionError,
+    model::BranchProtectionRules,
+};
+
+#[derive(Debug, Clone)]
+pub struct CreateBranchProtectionRequest {
+    pub owner: OwnerName,
+    pub repo: RepositoryName,
+    pub pattern: BranchPattern,
+    pub rules: BranchProtectionRules,
+}
+
+impl CreateBranchProtectionRequest {
+    pub fn new(
+        owner: &str,
+        repo: &str,
+        pattern: &str,
+        rules: BranchProtectionRules,
+    ) -> Result<Self, BranchProtectionError> {
+        Ok(Self {
+            owner: OwnerName::parse(owner, "owner name")?,
+            repo: RepositoryName::parse(repo, "repository name")?,
+            pattern: BranchPattern::parse(pattern, "branch pattern")?,
+            rules,
+        })
+    }
+
+    pub fn get_repo_path(&self) -> String {
+        format!("{}/{}", self.owner.as_ref(), self.repo.as_ref())
+    }
+}
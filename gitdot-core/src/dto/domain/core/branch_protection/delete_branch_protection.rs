@@ -0,0 +1,31 @@
+use uuid::Uuid;
+
+use crate::{
+    dto::{OwnerName, RepositoryName},
+    error::BranchProtectionError,
+};
+
+#[derive(Debug, Clone)]
+pub struct DeleteBranchProtectionRequest {
+    pub owner: OwnerName,
+    pub repo: RepositoryName,
+    pub protection_id: Uuid,
+}
+
+impl DeleteBranchProtectionRequest {
+    pub fn new(
+        owner: &str,
+        repo: &str,
+        protection_id: Uuid,
+    ) -> Result<Self, BranchProtectionError> {
+        Ok(Self {
+            owner: OwnerName::parse(owner, "owner name")?,
+            repo: RepositoryName::parse(repo, "repository name")?,
+            protection_id,
+        })
+    }
+
+    pub fn get_repo_path(&self) -> String {
+        format!("{}/{}", self.owner.as_ref(), self.repo.as_ref())
+    }
+}
@@ -0,0 +1,38 @@
+use uuid::Uuid;
+
+use crate::{
+    dto::{BranchPattern, OwnerName, RepositoryName},
+    error::BranchProtectionError,
+    model::BranchProtectionRules,
+};
+
+#[derive(Debug, Clone)]
+pub struct UpdateBranchProtectionRequest {
+    pub owner: OwnerName,
+    pub repo: RepositoryName,
+    pub protection_id: Uuid,
+    pub pattern: BranchPattern,
+    pub rules: BranchProtectionRules,
+}
+
+impl UpdateBranchProtectionRequest {
+    pub fn new(
+        owner: &str,
+        repo: &str,
+        protection_id: Uuid,
+        pattern: &str,
+        rules: BranchProtectionRules,
+    ) -> Result<Self, BranchProtectionError> {
+        Ok(Self {
+            owner: OwnerName::parse(owner, "owner name")?,
+            repo: RepositoryName::parse(repo, "repository name")?,
+            protection_id,
+            pattern: BranchPattern::parse(pattern, "branch pattern")?,
+            rules,
+        })
+    }
+
+    pub fn get_repo_path(&self) -> String {
+        format!("{}/{}", self.owner.as_ref(), self.repo.as_ref())
+    }
+}
@@ -0,0 +1,46 @@
+mod index_code;
+mod search_code;
+
+pub use index_code::IndexCodeRequest;
+pub use search_code::{
+    CodeSearchQuery, DEFAULT_SEARCH_LIMIT, MAX_CONTEXT_LINES, MAX_MATCHES_PER_FILE,
+    MAX_SEARCH_LIMIT, SearchCodeRequest,
+};
+
+#[derive(Debug, Clone)]
+pub struct CodeSearchResponse {
+    /// The indexed default-branch commit that was searched, or `None` when
+    /// the repository has not been indexed yet.
+    pub commit_sha: Option<String>,
+    pub files: Vec<CodeSearchFileResponse>,
+    /// Whether more files matched than were returned.
+    pub truncated: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct CodeSearchFileResponse {
+    pub path: String,
+    pub blob_sha: String,
+    pub language: Option<String>,
+    /// Number of matching lines in the file; `matches` holds at most
+    /// [`MAX_MATCHES_PER_FILE`](crate::dto::MAX_MATCHES_PER_FILE) of them.
+    pub match_count: u32,
+    pub matches: Vec<CodeSearchMatch>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CodeSearchMatch {
+    /// 1-based line number.
+    pub line_number: u32,
+    pub content: String,
+    /// Byte ranges of each match within `content`.
+    pub ranges: Vec<MatchRange>,
+    pub before: Vec<String>,
+    pub after: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MatchRange {
+    pub start: u32,
+    pub end: u32,
+}
@@ -0,0 +1,33 @@
+use crate::{
+    dto::{OwnerName, RepositoryName},
+    error::CodeSearchError,
+    util::git::ZERO_SHA,
+};
+
+#[derive(Debug, Clone)]
+pub struct IndexCodeRequest {
+    pub name: RepositoryName,
+    pub owner_name: OwnerName,
+    pub ref_name: String,
+    pub new_sha: String,
+}
+
+impl IndexCodeRequest {
+    pub fn new(
+        repo_name: &str,
+        owner_name: &str,
+        ref_name: String,
+        new_sha: String,
+    ) -> Result<Self, CodeSearchError> {
+        Ok(Self {
+            name: RepositoryName::parse(repo_name, "repository name")?,
+            owner_name: OwnerName::parse(owner_name, "owner name")?,
+            ref_name,
+            new_sha,
+        })
+    }
+
+    pub fn is_delete(&self) -> bool {
+        self.new_sha == ZERO_SHA
+    }
+}
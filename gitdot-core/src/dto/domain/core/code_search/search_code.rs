@@ -0,0 +1,148 @@
+use regex::{Regex, RegexBuilder};
+
+use crate::{
+    dto::{OwnerName, RepositoryName},
+    error::{CodeSearchError, InputError},
+};
+
+pub const DEFAULT_SEARCH_LIMIT: u32 = 20;
+pub const MAX_SEARCH_LIMIT: u32 = 100;
+pub const MAX_CONTEXT_LINES: u32 = 5;
+pub const MAX_MATCHES_PER_FILE: usize = 20;
+const MAX_QUERY_LENGTH: usize = 256;
+const MAX_REGEX_SIZE: usize = 1 << 20;
+
+#[derive(Debug, Clone)]
+pub struct SearchCodeRequest {
+    pub name: RepositoryName,
+    pub owner_name: OwnerName,
+    pub query: CodeSearchQuery,
+    pub limit: u32,
+}
+
+#[derive(Debug, Clone)]
+pub struct CodeSearchQuery {
+    /// Matched against each line of every candidate file.
+    pub pattern: Regex,
+    /// The query text when it is matched literally, used to narrow candidates
+    /// through the trigram index. Regex queries scan every indexed file.
+    pub literal: Option<String>,
+    pub path_glob: Option<String>,
+    pub language: Option<String>,
+    pub context_lines: u32,
+}
+
+impl SearchCodeRequest {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        repo_name: &str,
+        owner_name: &str,
+        query: &str,
+        regex: Option<bool>,
+        case_sensitive: Option<bool>,
+        path_glob: Option<String>,
+        language: Option<String>,
+        context_lines: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<Self, CodeSearchError> {
+        if query.is_empty() {
+            return Err(InputError::new("query", "must not be empty").into());
+        }
+        if query.len() > MAX_QUERY_LENGTH {
+            return Err(InputError::new(
+                "query",
+                format!("must be at most {MAX_QUERY_LENGTH} bytes"),
+            )
+            .into());
+        }
+
+        let regex = regex.unwrap_or(false);
+        let source = if regex {
+            query.to_string()
+        } else {
+            regex::escape(query)
+        };
+        let pattern = RegexBuilder::new(&source)
+            .case_insensitive(!case_sensitive.unwrap_or(false))
+            .size_limit(MAX_REGEX_SIZE)
+            .build()
+            .map_err(|e| InputError::new("query", format!("invalid regex: {e}")))?;
+
+        Ok(Self {
+            name: RepositoryName::parse(repo_name, "repository name")?,
+            owner_name: OwnerName::parse(owner_name, "owner name")?,
+            query: CodeSearchQuery {
+                pattern,
+                literal: (!regex).then(|| query.to_string()),
+                path_glob: path_glob.filter(|glob| !glob.is_empty()),
+                language: language
+                    .filter(|language| !language.is_empty())
+                    .map(|language| language.to_ascii_lowercase()),
+                context_lines: context_lines.unwrap_or(2).min(MAX_CONTEXT_LINES),
+            },
+            limit: limit
+                .unwrap_or(DEFAULT_SEARCH_LIMIT)
+                .clamp(1, MAX_SEARCH_LIMIT),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(query: &str, regex: bool, case_sensitive: bool) -> SearchCodeRequest {
+        SearchCodeRequest::new(
+            "dotfiles",
+            "alice",
+            query,
+            Some(regex),
+            Some(case_sensitive),
+            None,
+            Some("Rust".to_string()),
+            Some(50),
+            None,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn literal_queries_are_escaped_and_case_insensitive() {
+        let request = request("a.b(", false, false);
+        assert!(request.query.pattern.is_match("let x = A.B(1);"));
+        assert!(!request.query.pattern.is_match("axb("));
+        assert_eq!(request.query.literal.as_deref(), Some("a.b("));
+        assert_eq!(request.query.language.as_deref(), Some("rust"));
+        assert_eq!(request.query.context_lines, MAX_CONTEXT_LINES);
+        assert_eq!(request.limit, DEFAULT_SEARCH_LIMIT);
+    }
+
+    #[test]
+    fn regex_queries_skip_the_literal_prefilter() {
+        let request = request(r"fn \w+_config", true, true);
+        assert!(request.query.pattern.is_match("fn load_config()"));
+        assert!(!request.query.pattern.is_match("FN load_config()"));
+        assert!(request.query.literal.is_none());
+    }
+
+    #[test]
+    fn rejects_empty_and_invalid_queries() {
+        for (query, regex) in [("", false), ("(unclosed", true)] {
+            assert!(
+                SearchCodeRequest::new(
+                    "dotfiles",
+                    "alice",
+                    query,
+                    Some(regex),
+                    None,
+                    None,
+                    None,
+                    None,
+                    None
+                )
+                .is_err(),
+                "{query}"
+            );
+        }
+    }
+}
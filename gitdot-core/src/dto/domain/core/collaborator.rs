@@ -0,0 +1,66 @@
+mod cancel_invitation;
+mod invite_collaborator;
+mod list_collaborators;
+mod list_repository_invitations;
+mod remove_collaborator;
+mod respond_to_invitation;
+mod update_collaborator;
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::model::{RepositoryCollaborator, RepositoryInvitation, RepositoryRole};
+
+pub use cancel_invitation::CancelInvitationRequest;
+pub use invite_collaborator::InviteCollaboratorRequest;
+pub use list_collaborators::ListCollaboratorsRequest;
+pub use list_repository_invitations::ListRepositoryInvitationsRequest;
+pub use remove_collaborator::RemoveCollaboratorRequest;
+pub use respond_to_invitation::RespondToInvitationRequest;
+pub use update_collaborator::UpdateCollaboratorRequest;
+
+#[derive(Debug, Clone)]
+pub struct CollaboratorResponse {
+    pub user_id: Uuid,
+    pub user_name: String,
+    pub role: RepositoryRole,
+    pub created_at: DateTime<Utc>,
+    pub image_updated_at: DateTime<Utc>,
+}
+
+impl From<RepositoryCollaborator> for CollaboratorResponse {
+    fn from(collaborator: RepositoryCollaborator) -> Self {
+        Self {
+            user_id: collaborator.user_id,
+            user_name: collaborator.user_name,
+            role: collaborator.role,
+            created_at: collaborator.created_at,
+            image_updated_at: collaborator.image_updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RepositoryInvitationResponse {
+    pub id: Uuid,
+    pub owner_name: String,
+    pub repository_name: String,
+    pub invitee_name: String,
+    pub inviter_name: String,
+    pub role: RepositoryRole,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<RepositoryInvitation> for RepositoryInvitationResponse {
+    fn from(invitation: RepositoryInvitation) -> Self {
+        Self {
+            id: invitation.id,
+            owner_name: invitation.owner_name,
+            repository_name: invitation.repository_name,
+            invitee_name: invitation.invitee_name,
+            inviter_name: invitation.inviter_name,
+            role: invitation.role,
+            created_at: invitation.created_at,
+        }
+    }
+}
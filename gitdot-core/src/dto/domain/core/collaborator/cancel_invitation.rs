@@ -0,0 +1,23 @@
+use uuid::Uuid;
+
+use crate::{
+    dto::{OwnerName, RepositoryName},
+    error::CollaboratorError,
+};
+
+#[derive(Debug, Clone)]
+pub struct CancelInvitationRequest {
+    pub owner: OwnerName,
+    pub repo: RepositoryName,
+    pub invitation_id: Uuid,
+}
+
+impl CancelInvitationRequest {
+    pub fn new(owner: &str, repo: &str, invitation_id: Uuid) -> Result<Self, CollaboratorError> {
+        Ok(Self {
+            owner: OwnerName::parse(owner, "owner name")?,
+            repo: RepositoryName::parse(repo, "repository name")?,
+            invitation_id,
+        })
+    }
+}
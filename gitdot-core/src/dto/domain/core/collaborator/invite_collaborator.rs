@@ -0,0 +1,34 @@
+use uuid::Uuid;
+
+use crate::{
+    dto::{OwnerName, RepositoryName},
+    error::CollaboratorError,
+    model::RepositoryRole,
+};
+
+#[derive(Debug, Clone)]
+pub struct InviteCollaboratorRequest {
+    pub owner: OwnerName,
+    pub repo: RepositoryName,
+    pub inviter_id: Uuid,
+    pub user_name: OwnerName,
+    pub role: RepositoryRole,
+}
+
+impl InviteCollaboratorRequest {
+    pub fn new(
+        owner: &str,
+        repo: &str,
+        inviter_id: Uuid,
+        user_name: &str,
+        role: &str,
+    ) -> Result<Self, CollaboratorError> {
+        Ok(Self {
+            owner: OwnerName::parse(owner, "owner name")?,
+            repo: RepositoryName::parse(repo, "repository name")?,
+            inviter_id,
+            user_name: OwnerName::parse(user_name, "user name")?,
+            role: RepositoryRole::try_from(role)?,
+        })
+    }
+}
@@ -0,0 +1,19 @@
+use crate::{
+    dto::{OwnerName, RepositoryName},
+    error::CollaboratorError,
+};
+
+#[derive(Debug, Clone)]
+pub struct ListCollaboratorsRequest {
+    pub owner: OwnerName,
+    pub repo: RepositoryName,
+}
+
+impl ListCollaboratorsRequest {
+    pub fn new(owner: &str, repo: &str) -> Result<Self, CollaboratorError> {
+        Ok(Self {
+            owner: OwnerName::parse(owner, "owner name")?,
+            repo: RepositoryName::parse(repo, "repository name")?,
+        })
+    }
+}
@@ -0,0 +1,21 @@
+use crate::{
+    dto::{OwnerName, RepositoryName},
+    error::CollaboratorError,
+};
+
+#[derive(Debug, Clone)]
+pub struct RemoveCollaboratorRequest {
+    pub owner: OwnerName,
+    pub repo: RepositoryName,
+    pub user_name: OwnerName,
+}
+
+impl RemoveCollaboratorRequest {
+    pub fn new(owner: &str, repo: &str, user_name: &str) -> Result<Self, CollaboratorError> {
+        Ok(Self {
+            owner: OwnerName::parse(owner, "owner name")?,
+            repo: RepositoryName::parse(repo, "repository name")?,
+            user_name: OwnerName::parse(user_name, "user name")?,
+        })
+    }
+}
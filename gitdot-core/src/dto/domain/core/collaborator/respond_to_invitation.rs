@@ -0,0 +1,16 @@
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct RespondToInvitationRequest {
+    pub user_id: Uuid,
+    pub invitation_id: Uuid,
+}
+
+impl RespondToInvitationRequest {
+    pub fn new(user_id: Uuid, invitation_id: Uuid) -> Self {
+        Self {
+            user_id,
+            invitation_id,
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use crate::{
+    dto::{OwnerName, RepositoryName},
+    error::CollaboratorError,
+    model::RepositoryRole,
+};
+
+#[derive(Debug, Clone)]
+pub struct UpdateCollaboratorRequest {
+    pub owner: OwnerName,
+    pub repo: RepositoryName,
+    pub user_name: OwnerName,
+    pub role: RepositoryRole,
+}
+
+impl UpdateCollaboratorRequest {
+    pub fn new(
+        owner: &str,
+        repo: &str,
+        user_name: &str,
+        role: &str,
+    ) -> Result<Self, CollaboratorError> {
+        Ok(Self {
+            owner: OwnerName::parse(owner, "owner name")?,
+            repo: RepositoryName::parse(repo, "repository name")?,
+            user_name: OwnerName::parse(user_name, "user name")?,
+            role: RepositoryRole::try_from(role)?,
+        })
+    }
+}
@@ -4,7 +4,7 @@ use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
 use super::RepositoryBlobResponse;
-use crate::model::{Commit, CommitDiff};
+use crate::model::{Commit, CommitDiff, CommitSignatureStatus};
 
 pub use create_commits::CreateCommitsRequest;
 
@@ -27,6 +27,7 @@ pub struct CommitResponse {
     pub created_at: DateTime<Utc>,
     pub review_number: Option<i32>,
     pub diff_position: Option<i32>,
+    pub signature_status: Option<CommitSignatureStatus>,
     pub diffs: Vec<CommitDiff>,
 }
 
@@ -56,6 +57,7 @@ impl From<Commit> for CommitResponse {
             created_at: commit.created_at,
             review_number: commit.review_number,
             diff_position: commit.diff_position,
+            signature_status: commit.signature_status,
             diffs: commit.diffs,
         }
     }
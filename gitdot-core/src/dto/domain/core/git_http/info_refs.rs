@@ -1,5 +1,5 @@
 use crate::{
-    dto::{GitService, OwnerName, RepositoryName},
+    dto::{GitProtocol, GitService, OwnerName, RepositoryName},
     error::GitHttpError,
 };
 
@@ -8,14 +8,23 @@ pub struct InfoRefsRequest {
     pub owner: OwnerName,
     pub repo: RepositoryName,
     pub service: GitService,
+    pub git_protocol: Option<GitProtocol>,
 }
 
 impl InfoRefsRequest {
-    pub fn new(owner: &str, repo: &str, service: &str) -> Result<Self, GitHttpError> {
+    pub fn new(
+        owner: &str,
+        repo: &str,
+        service: &str,
+        git_protocol: Option<&str>,
+    ) -> Result<Self, GitHttpError> {
         Ok(Self {
             owner: OwnerName::parse(owner, "owner name")?,
             repo: RepositoryName::parse(repo, "repository name")?,
             service: GitService::parse(service, "service")?,
+            git_protocol: git_protocol
+                .map(|p| GitProtocol::parse(p, "git protocol"))
+                .transpose()?,
         })
     }
 }
@@ -29,7 +38,8 @@ mod tests {
 
         #[test]
         fn valid_upload_pack_request() {
-            let request = InfoRefsRequest::new("johndoe", "my-repo", "git-upload-pack").unwrap();
+            let request =
+                InfoRefsRequest::new("johndoe", "my-repo", "git-upload-pack", None).unwrap();
 
             assert_eq!(request.owner.as_ref(), "johndoe");
             assert_eq!(request.repo.as_ref(), "my-repo");
@@ -38,14 +48,16 @@ mod tests {
 
         #[test]
         fn valid_receive_pack_request() {
-            let request = InfoRefsRequest::new("johndoe", "my-repo", "git-receive-pack").unwrap();
+            let request =
+                InfoRefsRequest::new("johndoe", "my-repo", "git-receive-pack", None).unwrap();
 
             assert_eq!(request.service.as_ref(), "git-receive-pack");
         }
 
         #[test]
         fn sanitizes_owner_and_repo() {
-            let request = InfoRefsRequest::new("JohnDoe", "MyRepo.git", "git-upload-pack").unwrap();
+            let request =
+                InfoRefsRequest::new("JohnDoe", "MyRepo.git", "git-upload-pack", None).unwrap();
 
             assert_eq!(request.owner.as_ref(), "johndoe");
             assert_eq!(request.repo.as_ref(), "myrepo");
@@ -53,28 +65,45 @@ mod tests {
 
         #[test]
         fn rejects_invalid_owner() {
-            let result = InfoRefsRequest::new("invalid@owner", "my-repo", "git-upload-pack");
+            let result = InfoRefsRequest::new("invalid@owner", "my-repo", "git-upload-pack", None);
 
             assert!(matches!(result, Err(GitHttpError::Input(_))));
         }
 
         #[test]
         fn rejects_invalid_repo() {
-            let result = InfoRefsRequest::new("johndoe", "invalid/repo", "git-upload-pack");
+            let result = InfoRefsRequest::new("johndoe", "invalid/repo", "git-upload-pack", None);
 
             assert!(matches!(result, Err(GitHttpError::Input(_))));
         }
 
         #[test]
         fn rejects_invalid_service() {
-            let result = InfoRefsRequest::new("johndoe", "my-repo", "invalid-service");
+            let result = InfoRefsRequest::new("johndoe", "my-repo", "invalid-service", None);
+
+            assert!(matches!(result, Err(GitHttpError::Input(_))));
+        }
+
+        #[test]
+        fn accepts_protocol_v2() {
+            let request =
+                InfoRefsRequest::new("johndoe", "my-repo", "git-upload-pack", Some("version=2"))
+                    .unwrap();
+
+            assert_eq!(request.git_protocol.as_deref(), Some("version=2"));
+        }
+
+        #[test]
+        fn rejects_invalid_git_protocol() {
+            let result =
+                InfoRefsRequest::new("johndoe", "my-repo", "git-upload-pack", Some("version 2"));
 
             assert!(matches!(result, Err(GitHttpError::Input(_))));
         }
 
         #[test]
         fn rejects_empty_service() {
-            let result = InfoRefsRequest::new("johndoe", "my-repo", "");
+            let result = InfoRefsRequest::new("johndoe", "my-repo", "", None);
 
             assert!(matches!(result, Err(GitHttpError::Input(_))));
         }
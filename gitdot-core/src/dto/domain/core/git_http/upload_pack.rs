@@ -1,7 +1,7 @@
 use tokio::io::AsyncRead;
 
 use crate::{
-    dto::{GitContentType, OwnerName, RepositoryName},
+    dto::{GitContentType, GitProtocol, OwnerName, RepositoryName},
     error::GitHttpError,
 };
 
@@ -9,6 +9,7 @@ pub struct UploadPackRequest {
     pub owner: OwnerName,
     pub repo: RepositoryName,
     pub content_type: GitContentType,
+    pub git_protocol: Option<GitProtocol>,
     pub body: Box<dyn AsyncRead + Unpin + Send>,
 }
 
@@ -17,12 +18,16 @@ impl UploadPackRequest {
         owner: &str,
         repo: &str,
         content_type: &str,
+        git_protocol: Option<&str>,
         body: Box<dyn AsyncRead + Unpin + Send>,
     ) -> Result<Self, GitHttpError> {
         Ok(Self {
             owner: OwnerName::parse(owner, "owner name")?,
             repo: RepositoryName::parse(repo, "repository name")?,
             content_type: GitContentType::parse(content_type, "content type")?,
+            git_protocol: git_protocol
+                .map(|p| GitProtocol::parse(p, "git protocol"))
+                .transpose()?,
             body,
         })
     }
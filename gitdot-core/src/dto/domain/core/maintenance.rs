@@ -0,0 +1,43 @@
+mod get_maintenance;
+mod record_push;
+mod request_maintenance;
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::model::{MaintenanceStatus, RepositoryMaintenance};
+
+pub use get_maintenance::GetMaintenanceRequest;
+pub use record_push::RecordPushRequest;
+pub use request_maintenance::RequestMaintenanceRequest;
+
+#[derive(Debug, Clone)]
+pub struct MaintenanceResponse {
+    pub repository_id: Uuid,
+    pub owner_name: String,
+    pub repository_name: String,
+    pub pending_pushes: i32,
+    pub last_pushed_at: Option<DateTime<Utc>>,
+    pub requested_at: Option<DateTime<Utc>>,
+    pub started_at: Option<DateTime<Utc>>,
+    pub finished_at: Option<DateTime<Utc>>,
+    pub last_status: Option<MaintenanceStatus>,
+    pub last_error: Option<String>,
+}
+
+impl From<RepositoryMaintenance> for MaintenanceResponse {
+    fn from(maintenance: RepositoryMaintenance) -> Self {
+        Self {
+            repository_id: maintenance.repository_id,
+            owner_name: maintenance.owner_name,
+            repository_name: maintenance.repository_name,
+            pending_pushes: maintenance.pending_pushes,
+            last_pushed_at: maintenance.last_pushed_at,
+            requested_at: maintenance.requested_at,
+            started_at: maintenance.started_at,
+            finished_at: maintenance.finished_at,
+            last_status: maintenance.last_status,
+            last_error: maintenance.last_error,
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use crate::{
+    dto::{OwnerName, RepositoryName},
+    error::MaintenanceError,
+};
+
+#[derive(Debug, Clone)]
+pub struct RequestMaintenanceRequest {
+    pub owner: OwnerName,
+    pub repo: RepositoryName,
+}
+
+impl RequestMaintenanceRequest {
+    pub fn new(owner: &str, repo: &str) -> Result<Self, MaintenanceError> {
+        Ok(Self {
+            owner: OwnerName::parse(owner, "owner name")?,
+            repo: RepositoryName::parse(repo, "repository name")?,
+        })
+    }
+
+    pub fn get_repo_path(&self) -> String {
+        format!("{}/{}", self.owner.as_ref(), self.repo.as_ref())
+    }
+}
@@ -0,0 +1,96 @@
+mod create_mirror;
+mod delete_mirror;
+mod get_mirror;
+mod list_mirrors;
+mod request_mirror_sync;
+mod request_push_mirrors;
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::{
+    dto::MirrorRefUpdate,
+    model::{MirrorDirection, MirrorRunStatus, RepositoryMirror, RepositoryMirrorRun},
+};
+
+pub use create_mirror::CreateMirrorRequest;
+pub use delete_mirror::DeleteMirrorRequest;
+pub use get_mirror::GetMirrorRequest;
+pub use list_mirrors::ListMirrorsRequest;
+pub use request_mirror_sync::RequestMirrorSyncRequest;
+pub use request_push_mirrors::RequestPushMirrorsRequest;
+
+#[derive(Debug, Clone)]
+pub struct MirrorResponse {
+    pub id: Uuid,
+    pub repository_id: Uuid,
+    pub owner_name: String,
+    pub repository_name: String,
+    pub direction: MirrorDirection,
+    pub url: String,
+    pub username: Option<String>,
+    /// Whether a password is stored. The password itself is never returned.
+    pub has_password: bool,
+    pub interval_seconds: Option<i32>,
+    pub requested_at: Option<DateTime<Utc>>,
+    pub started_at: Option<DateTime<Utc>>,
+    pub finished_at: Option<DateTime<Utc>>,
+    pub last_synced_at: Option<DateTime<Utc>>,
+    pub last_status: Option<MirrorRunStatus>,
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    /// Recent runs, newest first. Only filled in for a single mirror.
+    pub runs: Vec<MirrorRunResponse>,
+}
+
+impl From<RepositoryMirror> for MirrorResponse {
+    fn from(mirror: RepositoryMirror) -> Self {
+        Self {
+            id: mirror.id,
+            repository_id: mirror.repository_id,
+            owner_name: mirror.owner_name,
+            repository_name: mirror.repository_name,
+            direction: mirror.direction,
+            url: mirror.url,
+            username: mirror.username,
+            has_password: mirror.password_ciphertext.is_some(),
+            interval_seconds: mirror.interval_seconds,
+            requested_at: mirror.requested_at,
+            started_at: mirror.started_at,
+            finished_at: mirror.finished_at,
+            last_synced_at: mirror.last_synced_at,
+            last_status: mirror.last_status,
+            last_error: mirror.last_error,
+            created_at: mirror.created_at,
+            runs: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MirrorRunResponse {
+    pub id: Uuid,
+    pub status: MirrorRunStatus,
+    pub error: Option<String>,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: DateTime<Utc>,
+}
+
+impl From<RepositoryMirrorRun> for MirrorRunResponse {
+    fn from(run: RepositoryMirrorRun) -> Self {
+        Self {
+            id: run.id,
+            status: run.status,
+            error: run.error,
+            started_at: run.started_at,
+            finished_at: run.finished_at,
+        }
+    }
+}
+
+/// A mirror after a scheduled sync, with the refs a successful pull moved.
+#[derive(Debug, Clone)]
+pub struct MirrorSyncResponse {
+    pub mirror: MirrorResponse,
+    pub ref_updates: Vec<MirrorRefUpdate>,
+}
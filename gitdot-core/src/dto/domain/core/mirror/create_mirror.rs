@@ -0,0 +1,144 @@
+use crate::{
+    dto::{OwnerName, RemoteUrl, RepositoryName},
+    error::{InputError, MirrorError},
+    model::MirrorDirection,
+};
+
+/// How often a pull mirror fetches when no interval is given.
+const DEFAULT_INTERVAL_SECONDS: i32 = 60 * 60;
+const MIN_INTERVAL_SECONDS: i32 = 5 * 60;
+const MAX_INTERVAL_SECONDS: i32 = 7 * 24 * 60 * 60;
+
+#[derive(Debug, Clone)]
+pub struct CreateMirrorRequest {
+    pub owner: OwnerName,
+    pub repo: RepositoryName,
+    pub direction: MirrorDirection,
+    pub url: RemoteUrl,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    /// Always set for pull mirrors and never for push mirrors.
+    pub interval_seconds: Option<i32>,
+    /// Confirms that a pull mirror may replace the branches and tags the
+    /// repository already has.
+    pub replace_refs: bool,
+}
+
+impl CreateMirrorRequest {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        owner: &str,
+        repo: &str,
+        direction: &str,
+        url: &str,
+        username: Option<String>,
+        password: Option<String>,
+        interval_seconds: Option<i32>,
+        replace_refs: bool,
+    ) -> Result<Self, MirrorError> {
+        let direction = match direction {
+            "pull" => MirrorDirection::Pull,
+            "push" => MirrorDirection::Push,
+            _ => {
+                return Err(InputError::new("direction", "must be pull or push").into());
+            }
+        };
+        let interval_seconds = match direction {
+            MirrorDirection::Pull => {
+                let interval = interval_seconds.unwrap_or(DEFAULT_INTERVAL_SECONDS);
+                if !(MIN_INTERVAL_SECONDS..=MAX_INTERVAL_SECONDS).contains(&interval) {
+                    return Err(InputError::new(
+                        "interval_seconds",
+                        "must be between 5 minutes and 7 days",
+                    )
+                    .into());
+                }
+                Some(interval)
+            }
+            MirrorDirection::Push if interval_seconds.is_some() => {
+                return Err(InputError::new(
+                    "interval_seconds",
+                    "push mirrors sync after every push",
+                )
+                .into());
+            }
+            MirrorDirection::Push => None,
+        };
+
+        let username = username
+            .map(|u| u.trim().to_string())
+            .filter(|u| !u.is_empty());
+        if username
+            .as_ref()
+            .is_some_and(|u| u.len() > 255 || u.chars().any(char::is_control))
+        {
+            return Err(
+                InputError::new("username", "must be at most 255 printable characters").into(),
+            );
+        }
+        let password = password.filter(|p| !p.is_empty());
+        if username.is_some() && password.is_none() {
+            return Err(InputError::new("password", "is required with a username").into());
+        }
+
+        Ok(Self {
+            owner: OwnerName::parse(owner, "owner name")?,
+            repo: RepositoryName::parse(repo, "repository name")?,
+            direction,
+            url: RemoteUrl::parse(url, "url")?,
+            username,
+            password,
+            interval_seconds,
+            replace_refs,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create(
+        direction: &str,
+        username: Option<&str>,
+        password: Option<&str>,
+        interval_seconds: Option<i32>,
+    ) -> Result<CreateMirrorRequest, MirrorError> {
+        CreateMirrorRequest::new(
+            "alice",
+            "demo",
+            direction,
+            "https://codeberg.org/alice/demo.git",
+            username.map(str::to_string),
+            password.map(str::to_string),
+            interval_seconds,
+            false,
+        )
+    }
+
+    #[test]
+    fn pull_mirrors_default_their_interval() {
+        let request = create("pull", None, None, None).unwrap();
+        assert_eq!(request.direction, MirrorDirection::Pull);
+        assert_eq!(request.interval_seconds, Some(DEFAULT_INTERVAL_SECONDS));
+
+        assert!(create("pull", None, None, Some(60)).is_err());
+        assert!(create("pull", None, None, Some(MAX_INTERVAL_SECONDS + 1)).is_err());
+    }
+
+    #[test]
+    fn push_mirrors_take_no_interval() {
+        let request = create("push", None, Some("token"), None).unwrap();
+        assert_eq!(request.interval_seconds, None);
+        assert_eq!(request.password.as_deref(), Some("token"));
+
+        assert!(create("push", None, None, Some(3600)).is_err());
+    }
+
+    #[test]
+    fn rejects_unknown_direction_and_username_without_password() {
+        assert!(create("both", None, None, None).is_err());
+        assert!(create("pull", Some("bot"), None, None).is_err());
+        assert!(create("pull", Some("bot"), Some(""), None).is_err());
+    }
+}
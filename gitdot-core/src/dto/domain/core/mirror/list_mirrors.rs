@@ -0,0 +1,19 @@
+use crate::{
+    dto::{OwnerName, RepositoryName},
+    error::MirrorError,
+};
+
+#[derive(Debug, Clone)]
+pub struct ListMirrorsRequest {
+    pub owner: OwnerName,
+    pub repo: RepositoryName,
+}
+
+impl ListMirrorsRequest {
+    pub fn new(owner: &str, repo: &str) -> Result<Self, MirrorError> {
+        Ok(Self {
+            owner: OwnerName::parse(owner, "owner name")?,
+            repo: RepositoryName::parse(repo, "repository name")?,
+        })
+    }
+}
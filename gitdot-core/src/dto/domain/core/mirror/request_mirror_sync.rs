@@ -0,0 +1,23 @@
+use uuid::Uuid;
+
+use crate::{
+    dto::{OwnerName, RepositoryName},
+    error::MirrorError,
+};
+
+#[derive(Debug, Clone)]
+pub struct RequestMirrorSyncRequest {
+    pub owner: OwnerName,
+    pub repo: RepositoryName,
+    pub id: Uuid,
+}
+
+impl RequestMirrorSyncRequest {
+    pub fn new(owner: &str, repo: &str, id: Uuid) -> Result<Self, MirrorError> {
+        Ok(Self {
+            owner: OwnerName::parse(owner, "owner name")?,
+            repo: RepositoryName::parse(repo, "repository name")?,
+            id,
+        })
+    }
+}
@@ -0,0 +1,115 @@
+mod create_release;
+mod delete_release;
+mod delete_release_asset;
+mod download_release_asset;
+mod get_release;
+mod list_releases;
+mod update_release;
+mod upload_release_asset;
+
+use bytes::Bytes;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::{
+    error::InputError,
+    model::{Release, ReleaseAsset},
+};
+
+pub use create_release::CreateReleaseRequest;
+pub use delete_release::DeleteReleaseRequest;
+pub use delete_release_asset::DeleteReleaseAssetRequest;
+pub use download_release_asset::DownloadReleaseAssetRequest;
+pub use get_release::GetReleaseRequest;
+pub use list_releases::ListReleasesRequest;
+pub use update_release::UpdateReleaseRequest;
+pub use upload_release_asset::UploadReleaseAssetRequest;
+
+const MAX_RELEASE_NAME_LENGTH: usize = 255;
+const MAX_RELEASE_BODY_LENGTH: usize = 125_000;
+
+#[derive(Debug, Clone)]
+pub struct ReleaseResponse {
+    pub id: Uuid,
+    pub tag_name: String,
+    pub name: String,
+    pub body: String,
+    pub draft: bool,
+    pub prerelease: bool,
+    pub author_name: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub published_at: Option<DateTime<Utc>>,
+    pub assets: Vec<ReleaseAssetResponse>,
+}
+
+impl ReleaseResponse {
+    pub fn new(release: Release, assets: Vec<ReleaseAsset>) -> Self {
+        Self {
+            id: release.id,
+            tag_name: release.tag_name,
+            name: release.name,
+            body: release.body,
+            draft: release.draft,
+            prerelease: release.prerelease,
+            author_name: release.author_name,
+            created_at: release.created_at,
+            updated_at: release.updated_at,
+            published_at: release.published_at,
+            assets: assets.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ReleaseAssetResponse {
+    pub id: Uuid,
+    pub name: String,
+    pub content_type: String,
+    pub size_bytes: i64,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<ReleaseAsset> for ReleaseAssetResponse {
+    fn from(asset: ReleaseAsset) -> Self {
+        Self {
+            id: asset.id,
+            name: asset.name,
+            content_type: asset.content_type,
+            size_bytes: asset.size_bytes,
+            created_at: asset.created_at,
+        }
+    }
+}
+
+/// A downloaded release asset and the content type it was uploaded with.
+#[derive(Debug, Clone)]
+pub struct ReleaseAssetDownload {
+    pub name: String,
+    pub content_type: String,
+    pub bytes: Bytes,
+}
+
+fn parse_release_name(name: String) -> Result<String, InputError> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(InputError::new("release name", "cannot be empty"));
+    }
+    if name.chars().count() > MAX_RELEASE_NAME_LENGTH {
+        return Err(InputError::new(
+            "release name",
+            format!("must be at most {MAX_RELEASE_NAME_LENGTH} characters"),
+        ));
+    }
+    Ok(name.to_string())
+}
+
+fn parse_release_body(body: String) -> Result<String, InputError> {
+    if body.chars().count() > MAX_RELEASE_BODY_LENGTH {
+        return Err(InputError::new(
+            "release body",
+            format!("must be at most {MAX_RELEASE_BODY_LENGTH} characters"),
+        ));
+    }
+    Ok(body)
+}
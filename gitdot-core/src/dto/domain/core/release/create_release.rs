@@ -0,0 +1,49 @@
+use uuid::Uuid;
+
+use crate::{
+    dto::{OwnerName, RepositoryName, TagName},
+    error::ReleaseError,
+};
+
+use super::{parse_release_body, parse_release_name};
+
+#[derive(Debug, Clone)]
+pub struct CreateReleaseRequest {
+    pub name: RepositoryName,
+    pub owner_name: OwnerName,
+    pub tag: TagName,
+    /// Defaults to the tag name.
+    pub release_name: String,
+    pub body: String,
+    pub draft: bool,
+    pub prerelease: bool,
+    pub user_id: Uuid,
+}
+
+impl CreateReleaseRequest {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        repo_name: &str,
+        owner_name: &str,
+        tag: &str,
+        release_name: Option<String>,
+        body: Option<String>,
+        draft: Option<bool>,
+        prerelease: Option<bool>,
+        user_id: Uuid,
+    ) -> Result<Self, ReleaseError> {
+        let tag = TagName::parse(tag, "tag")?;
+        let release_name = parse_release_name(release_name.unwrap_or_else(|| tag.to_string()))?;
+
+        Ok(Self {
+            name: RepositoryName::parse(repo_name, "repository name")?,
+            owner_name: OwnerName::parse(owner_name, "owner name")?,
+            tag,
+            release_name,
+            body: parse_release_body(body.unwrap_or_default())?,
+            draft: draft.unwrap_or(false),
+            prerelease: prerelease.unwrap_or(false),
+            user_id,
+        })
+    }
+}
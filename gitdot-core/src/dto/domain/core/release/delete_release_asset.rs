@@ -0,0 +1,28 @@
+use crate::{
+    dto::{OwnerName, RepositoryName, TagName},
+    error::ReleaseError,
+};
+
+#[derive(Debug, Clone)]
+pub struct DeleteReleaseAssetRequest {
+    pub name: RepositoryName,
+    pub owner_name: OwnerName,
+    pub tag: TagName,
+    pub asset_name: String,
+}
+
+impl DeleteReleaseAssetRequest {
+    pub fn new(
+        repo_name: &str,
+        owner_name: &str,
+        tag: &str,
+        asset_name: &str,
+    ) -> Result<Self, ReleaseError> {
+        Ok(Self {
+            name: RepositoryName::parse(repo_name, "repository name")?,
+            owner_name: OwnerName::parse(owner_name, "owner name")?,
+            tag: TagName::parse(tag, "tag")?,
+            asset_name: asset_name.to_string(),
+        })
+    }
+}
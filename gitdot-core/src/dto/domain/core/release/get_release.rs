@@ -0,0 +1,29 @@
+use crate::{
+    dto::{OwnerName, RepositoryName, TagName},
+    error::ReleaseError,
+};
+
+#[derive(Debug, Clone)]
+pub struct GetReleaseRequest {
+    pub name: RepositoryName,
+    pub owner_name: OwnerName,
+    pub tag: TagName,
+    /// Whether the caller may see drafts, i.e. can write to the repository.
+    pub include_drafts: bool,
+}
+
+impl GetReleaseRequest {
+    pub fn new(
+        repo_name: &str,
+        owner_name: &str,
+        tag: &str,
+        include_drafts: bool,
+    ) -> Result<Self, ReleaseError> {
+        Ok(Self {
+            name: RepositoryName::parse(repo_name, "repository name")?,
+            owner_name: OwnerName::parse(owner_name, "owner name")?,
+            tag: TagName::parse(tag, "tag")?,
+            include_drafts,
+        })
+    }
+}
@@ -0,0 +1,26 @@
+use crate::{
+    dto::{OwnerName, RepositoryName},
+    error::ReleaseError,
+};
+
+#[derive(Debug, Clone)]
+pub struct ListReleasesRequest {
+    pub name: RepositoryName,
+    pub owner_name: OwnerName,
+    /// Whether the caller may see drafts, i.e. can write to the repository.
+    pub include_drafts: bool,
+}
+
+impl ListReleasesRequest {
+    pub fn new(
+        repo_name: &str,
+        owner_name: &str,
+        include_drafts: bool,
+    ) -> Result<Self, ReleaseError> {
+        Ok(Self {
+            name: RepositoryName::parse(repo_name, "repository name")?,
+            owner_name: OwnerName::parse(owner_name, "owner name")?,
+            include_drafts,
+        })
+    }
+}
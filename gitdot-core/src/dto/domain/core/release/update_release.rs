@@ -0,0 +1,39 @@
+use crate::{
+    dto::{OwnerName, RepositoryName, TagName},
+    error::ReleaseError,
+};
+
+use super::{parse_release_body, parse_release_name};
+
+#[derive(Debug, Clone)]
+pub struct UpdateReleaseRequest {
+    pub name: RepositoryName,
+    pub owner_name: OwnerName,
+    pub tag: TagName,
+    pub release_name: Option<String>,
+    pub body: Option<String>,
+    pub draft: Option<bool>,
+    pub prerelease: Option<bool>,
+}
+
+impl UpdateReleaseRequest {
+    pub fn new(
+        repo_name: &str,
+        owner_name: &str,
+        tag: &str,
+        release_name: Option<String>,
+        body: Option<String>,
+        draft: Option<bool>,
+        prerelease: Option<bool>,
+    ) -> Result<Self, ReleaseError> {
+        Ok(Self {
+            name: RepositoryName::parse(repo_name, "repository name")?,
+            owner_name: OwnerName::parse(owner_name, "owner name")?,
+            tag: TagName::parse(tag, "tag")?,
+            release_name: release_name.map(parse_release_name).transpose()?,
+            body: body.map(parse_release_body).transpose()?,
+            draft,
+            prerelease,
+        })
+    }
+}
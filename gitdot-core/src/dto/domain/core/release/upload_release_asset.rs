@@ -0,0 +1,114 @@
+use bytes::Bytes;
+use uuid::Uuid;
+
+use crate::{
+    dto::{OwnerName, RepositoryName, TagName},
+    error::{InputError, ReleaseError},
+};
+
+const MAX_ASSET_NAME_LENGTH: usize = 255;
+const DEFAULT_CONTENT_TYPE: &str = "application/octet-stream";
+
+#[derive(Debug, Clone)]
+pub struct UploadReleaseAssetRequest {
+    pub name: RepositoryName,
+    pub owner_name: OwnerName,
+    pub tag: TagName,
+    pub asset_name: String,
+    pub content_type: String,
+    pub bytes: Bytes,
+    pub user_id: Uuid,
+}
+
+impl UploadReleaseAssetRequest {
+    pub fn new(
+        repo_name: &str,
+        owner_name: &str,
+        tag: &str,
+        asset_name: &str,
+        content_type: Option<&str>,
+        bytes: Bytes,
+        user_id: Uuid,
+    ) -> Result<Self, ReleaseError> {
+        if bytes.is_empty() {
+            return Err(InputError::new("asset", "cannot be empty").into());
+        }
+
+        Ok(Self {
+            name: RepositoryName::parse(repo_name, "repository name")?,
+            owner_name: OwnerName::parse(owner_name, "owner name")?,
+            tag: TagName::parse(tag, "tag")?,
+            asset_name: parse_asset_name(asset_name)?,
+            content_type: content_type
+                .map(str::trim)
+                .filter(|t| !t.is_empty())
+                .unwrap_or(DEFAULT_CONTENT_TYPE)
+                .to_string(),
+            bytes,
+            user_id,
+        })
+    }
+}
+
+/// Asset names become download file names, so they must be a single path
+/// segment.
+fn parse_asset_name(name: &str) -> Result<String, InputError> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(InputError::new("asset name", "cannot be empty"));
+    }
+    if name.len() > MAX_ASSET_NAME_LENGTH {
+        return Err(InputError::new(
+            "asset name",
+            format!("must be at most {MAX_ASSET_NAME_LENGTH} characters"),
+        ));
+    }
+    if name == "."
+        || name == ".."
+        || name.contains(['/', '\\'])
+        || name.chars().any(char::is_control)
+    {
+        return Err(InputError::new("asset name", "must be a plain file name"));
+    }
+    Ok(name.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn upload(
+        asset_name: &str,
+        content_type: Option<&str>,
+    ) -> Result<UploadReleaseAssetRequest, ReleaseError> {
+        UploadReleaseAssetRequest::new(
+            "dotfiles",
+            "alice",
+            "v1.0.0",
+            asset_name,
+            content_type,
+            Bytes::from_static(b"binary"),
+            Uuid::new_v4(),
+        )
+    }
+
+    #[test]
+    fn defaults_content_type() {
+        let request = upload(" app.tar.gz ", None).unwrap();
+        assert_eq!(request.asset_name, "app.tar.gz");
+        assert_eq!(request.content_type, DEFAULT_CONTENT_TYPE);
+        assert_eq!(
+            upload("app.zip", Some("application/zip"))
+                .unwrap()
+                .content_type,
+            "application/zip"
+        );
+    }
+
+    #[test]
+    fn rejects_path_like_names() {
+        for name in ["", "..", "dist/app", "dist\\app", "a\nb"] {
+            assert!(upload(name, None).is_err(), "{name:?}");
+        }
+    }
+}
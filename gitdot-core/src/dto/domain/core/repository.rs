@@ -1,9 +1,12 @@
+mod compare_repository_refs;
 mod create_repository;
 mod create_repository_commit_filter;
 mod delete_repository;
 mod delete_repository_commit_filter;
 mod get_repository;
 mod get_repository_activity;
+mod get_repository_archive;
+mod get_repository_blame;
 mod get_repository_blob;
 mod get_repository_blobs;
 mod get_repository_commit;
@@ -21,12 +24,23 @@ use uuid::Uuid;
 
 use crate::model::{CommitFilter, Repository};
 
+pub use compare_repository_refs::{
+    CompareRepositoryRefsRequest, DEFAULT_COMPARE_PAGE_LIMIT, DiffHunkResponse, DiffLineResponse,
+    DiffOptions, FileDiffResponse, FileDiffStatus, IgnoreWhitespace, MAX_COMPARE_PAGE_LIMIT,
+    RepositoryCompareResponse, RepositoryDiffResponse,
+};
 pub use create_repository::{CreateRepositoryRequest, GitignoreTemplate, LicenseTemplate};
 pub use create_repository_commit_filter::CreateRepositoryCommitFilterRequest;
 pub use delete_repository::DeleteRepositoryRequest;
 pub use delete_repository_commit_filter::DeleteRepositoryCommitFilterRequest;
 pub use get_repository::GetRepositoryRequest;
 pub use get_repository_activity::{GetRepositoryActivityRequest, RepositoryActivityEvent};
+pub use get_repository_archive::{
+    ArchiveFormat, GetRepositoryArchiveRequest, RepositoryArchiveResponse,
+};
+pub use get_repository_blame::{
+    BlameCommitResponse, BlameRange, GetRepositoryBlameRequest, RepositoryBlameResponse,
+};
 pub use get_repository_blob::{GetRepositoryBlobRequest, RepositoryBlobResponse};
 pub use get_repository_blobs::{GetRepositoryBlobsRequest, RepositoryBlobsResponse};
 pub use get_repository_commit::GetRepositoryCommitRequest;
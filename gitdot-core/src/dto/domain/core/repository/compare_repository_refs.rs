@@ -0,0 +1,256 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    dto::{OwnerName, RepositoryCommitResponse, RepositoryName},
+    error::{InputError, RepositoryError},
+    util::cursor,
+};
+
+pub const DEFAULT_COMPARE_PAGE_LIMIT: u32 = 100;
+pub const MAX_COMPARE_PAGE_LIMIT: u32 = 300;
+
+/// Which whitespace changes a diff ignores, mirroring `git diff -w`, `-b` and
+/// `--ignore-space-at-eol`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum IgnoreWhitespace {
+    #[default]
+    None,
+    All,
+    Change,
+    Eol,
+}
+
+impl IgnoreWhitespace {
+    pub fn parse(value: &str) -> Result<Self, InputError> {
+        match value {
+            "none" => Ok(Self::None),
+            "all" => Ok(Self::All),
+            "change" => Ok(Self::Change),
+            "eol" => Ok(Self::Eol),
+            _ => Err(InputError::new(
+                "ignore whitespace",
+                "must be one of none, all, change or eol",
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiffOptions {
+    pub ignore_whitespace: IgnoreWhitespace,
+    pub detect_renames: bool,
+}
+
+/// Offset into both the commit and file lists of a comparison.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+struct CompareCursor {
+    offset: u32,
+}
+
+#[derive(Debug, Clone)]
+pub struct CompareRepositoryRefsRequest {
+    pub name: RepositoryName,
+    pub owner_name: OwnerName,
+    pub base: String,
+    pub head: String,
+    pub options: DiffOptions,
+    pub offset: u32,
+    pub limit: u32,
+}
+
+impl CompareRepositoryRefsRequest {
+    /// Parses `range` as `{base}...{head}`.
+    pub fn new(
+        repo_name: &str,
+        owner_name: &str,
+        range: &str,
+        ignore_whitespace: Option<&str>,
+        detect_renames: Option<bool>,
+        cursor: Option<&str>,
+        limit: Option<u32>,
+    ) -> Result<Self, RepositoryError> {
+        let (base, head) = range
+            .split_once("...")
+            .filter(|(base, head)| !base.is_empty() && !head.is_empty())
+            .ok_or_else(|| InputError::new("range", "must be in the form base...head"))?;
+        let ignore_whitespace = ignore_whitespace
+            .map(IgnoreWhitespace::parse)
+            .transpose()?
+            .unwrap_or_default();
+        let offset = cursor
+            .map(cursor::decode::<CompareCursor>)
+            .transpose()?
+            .map_or(0, |c| c.offset);
+
+        Ok(Self {
+            name: RepositoryName::parse(repo_name, "repository name")?,
+            owner_name: OwnerName::parse(owner_name, "owner name")?,
+            base: base.to_string(),
+            head: head.to_string(),
+            options: DiffOptions {
+                ignore_whitespace,
+                detect_renames: detect_renames.unwrap_or(true),
+            },
+            offset,
+            limit: limit
+                .unwrap_or(DEFAULT_COMPARE_PAGE_LIMIT)
+                .clamp(1, MAX_COMPARE_PAGE_LIMIT),
+        })
+    }
+
+    /// The cursor for the page after this one.
+    pub fn next_cursor(&self) -> String {
+        cursor::encode(&CompareCursor {
+            offset: self.offset + self.limit,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RepositoryCompareResponse {
+    pub base_sha: String,
+    pub head_sha: String,
+    /// `None` when the refs share no history, in which case files are diffed
+    /// against an empty tree.
+    pub merge_base_sha: Option<String>,
+    /// Commits reachable from head but not from base, newest first.
+    pub commits: Vec<RepositoryCommitResponse>,
+    pub total_commits: usize,
+    pub files: Vec<FileDiffResponse>,
+    pub total_files: usize,
+    pub next_cursor: Option<String>,
+}
+
+/// One page of a tree-to-tree diff.
+#[derive(Debug, Clone)]
+pub struct RepositoryDiffResponse {
+    pub files: Vec<FileDiffResponse>,
+    pub total_files: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileDiffStatus {
+    Added,
+    Deleted,
+    Modified,
+    Renamed,
+    Copied,
+    TypeChanged,
+}
+
+impl FileDiffStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FileDiffStatus::Added => "added",
+            FileDiffStatus::Deleted => "deleted",
+            FileDiffStatus::Modified => "modified",
+            FileDiffStatus::Renamed => "renamed",
+            FileDiffStatus::Copied => "copied",
+            FileDiffStatus::TypeChanged => "type_changed",
+        }
+    }
+}
+
+impl From<FileDiffStatus> for String {
+    fn from(status: FileDiffStatus) -> Self {
+        status.as_str().to_string()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FileDiffResponse {
+    pub path: String,
+    /// The path before a rename or copy; otherwise equal to `path`.
+    pub old_path: String,
+    pub status: FileDiffStatus,
+    pub binary: bool,
+    pub lines_added: u32,
+    pub lines_removed: u32,
+    /// Empty for binary files.
+    pub hunks: Vec<DiffHunkResponse>,
+}
+
+#[derive(Debug, Clone)]
+pub struct DiffHunkResponse {
+    pub header: String,
+    pub old_start: u32,
+    pub old_lines: u32,
+    pub new_start: u32,
+    pub new_lines: u32,
+    pub lines: Vec<DiffLineResponse>,
+}
+
+#[derive(Debug, Clone)]
+pub struct DiffLineResponse {
+    /// `+`, `-` or ` ` for context.
+    pub origin: char,
+    pub old_lineno: Option<u32>,
+    pub new_lineno: Option<u32>,
+    pub content: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_range_and_options() {
+        let request = CompareRepositoryRefsRequest::new(
+            "dotfiles",
+            "alice",
+            "main...feature/login",
+            Some("change"),
+            Some(false),
+            None,
+            Some(1_000),
+        )
+        .unwrap();
+
+        assert_eq!(request.base, "main");
+        assert_eq!(request.head, "feature/login");
+        assert_eq!(request.options.ignore_whitespace, IgnoreWhitespace::Change);
+        assert!(!request.options.detect_renames);
+        assert_eq!(request.offset, 0);
+        assert_eq!(request.limit, MAX_COMPARE_PAGE_LIMIT);
+    }
+
+    #[test]
+    fn next_cursor_advances_by_limit() {
+        let first = CompareRepositoryRefsRequest::new(
+            "dotfiles",
+            "alice",
+            "a...b",
+            None,
+            None,
+            None,
+            Some(2),
+        )
+        .unwrap();
+        let cursor = first.next_cursor();
+        let second = CompareRepositoryRefsRequest::new(
+            "dotfiles",
+            "alice",
+            "a...b",
+            None,
+            None,
+            Some(&cursor),
+            Some(2),
+        )
+        .unwrap();
+
+        assert_eq!(second.offset, 2);
+    }
+
+    #[test]
+    fn rejects_malformed_ranges() {
+        for range in ["main", "main..feature", "...feature", "main..."] {
+            assert!(
+                CompareRepositoryRefsRequest::new(
+                    "dotfiles", "alice", range, None, None, None, None
+                )
+                .is_err(),
+                "{range}"
+            );
+        }
+    }
+}
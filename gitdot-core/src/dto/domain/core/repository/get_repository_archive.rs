@@ -0,0 +1,101 @@
+use std::pin::Pin;
+
+use futures::Stream;
+
+use crate::{
+    dto::{OwnerName, RepositoryName},
+    error::{InputError, RepositoryError},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArchiveFormat {
+    TarGz,
+    Zip,
+}
+
+impl ArchiveFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ArchiveFormat::TarGz => "tar.gz",
+            ArchiveFormat::Zip => "zip",
+        }
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            ArchiveFormat::TarGz => "application/gzip",
+            ArchiveFormat::Zip => "application/zip",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GetRepositoryArchiveRequest {
+    pub name: RepositoryName,
+    pub owner_name: OwnerName,
+    pub ref_name: String,
+    pub format: ArchiveFormat,
+}
+
+impl GetRepositoryArchiveRequest {
+    /// Splits `archive` (e.g. `main.tar.gz` or `v1.0.zip`) into the ref and
+    /// the archive format.
+    pub fn new(repo_name: &str, owner_name: &str, archive: &str) -> Result<Self, RepositoryError> {
+        let (ref_name, format) = [ArchiveFormat::TarGz, ArchiveFormat::Zip]
+            .into_iter()
+            .find_map(|format| {
+                archive
+                    .strip_suffix(format.extension())
+                    .and_then(|rest| rest.strip_suffix('.'))
+                    .map(|ref_name| (ref_name, format))
+            })
+            .ok_or_else(|| InputError::new("archive", "must end in .tar.gz or .zip"))?;
+        if ref_name.is_empty() {
+            return Err(InputError::new("archive", "ref must not be empty").into());
+        }
+
+        Ok(Self {
+            name: RepositoryName::parse(repo_name, "repository name")?,
+            owner_name: OwnerName::parse(owner_name, "owner name")?,
+            ref_name: ref_name.to_string(),
+            format,
+        })
+    }
+}
+
+pub struct RepositoryArchiveResponse {
+    pub commit_sha: String,
+    pub tree_sha: String,
+    /// `{repo}-{shortsha}.{ext}`, matching the archive's top-level directory.
+    pub file_name: String,
+    pub format: ArchiveFormat,
+    pub size: u64,
+    pub body: Pin<Box<dyn Stream<Item = Result<Vec<u8>, std::io::Error>> + Send>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_ref_and_format() {
+        let request = GetRepositoryArchiveRequest::new("dotfiles", "alice", "main.tar.gz").unwrap();
+        assert_eq!(request.ref_name, "main");
+        assert_eq!(request.format, ArchiveFormat::TarGz);
+
+        let request =
+            GetRepositoryArchiveRequest::new("dotfiles", "alice", "release/v1.0.zip").unwrap();
+        assert_eq!(request.ref_name, "release/v1.0");
+        assert_eq!(request.format, ArchiveFormat::Zip);
+    }
+
+    #[test]
+    fn rejects_unknown_formats() {
+        for archive in ["main.tar", "main.tar.bz2", "main", ".zip", "maintar.gz"] {
+            assert!(
+                GetRepositoryArchiveRequest::new("dotfiles", "alice", archive).is_err(),
+                "{archive}"
+            );
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::{
+    dto::{OwnerName, RepositoryName},
+    error::RepositoryError,
+};
+
+#[derive(Debug, Clone)]
+pub struct GetRepositoryBlameRequest {
+    pub name: RepositoryName,
+    pub owner_name: OwnerName,
+    pub ref_name: String,
+    pub path: String,
+    pub ignore_revs_file: Option<String>,
+}
+
+impl GetRepositoryBlameRequest {
+    pub fn new(
+        repo_name: &str,
+        owner_name: &str,
+        ref_name: String,
+        path: String,
+        ignore_revs_file: Option<String>,
+    ) -> Result<Self, RepositoryError> {
+        Ok(Self {
+            name: RepositoryName::parse(repo_name, "repository name")?,
+            owner_name: OwnerName::parse(owner_name, "owner name")?,
+            ref_name,
+            path,
+            ignore_revs_file,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RepositoryBlameResponse {
+    pub ref_name: String,
+    pub commit_sha: String,
+    pub path: String,
+    /// Consecutive runs of lines last changed by the same commit, in file
+    /// order and covering every line.
+    pub ranges: Vec<BlameRange>,
+    /// One entry per distinct commit referenced by `ranges`.
+    pub commits: Vec<BlameCommitResponse>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlameRange {
+    /// 1-based line number of the first line in the range.
+    pub start_line: u32,
+    pub line_count: u32,
+    pub commit_sha: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct BlameCommitResponse {
+    pub sha: String,
+    pub parent_sha: Option<String>,
+    pub message: String,
+    pub date: DateTime<Utc>,
+    pub author_id: Option<Uuid>,
+    pub author_name: Option<String>,
+    pub author_image_updated_at: Option<DateTime<Utc>>,
+    pub git_author_name: String,
+    pub git_author_email: String,
+}
+
+impl From<&git2::Commit<'_>> for BlameCommitResponse {
+    fn from(commit: &git2::Commit) -> Self {
+        let git_author = commit.author();
+        Self {
+            sha: commit.id().to_string(),
+            parent_sha: commit.parent(0).ok().map(|p| p.id().to_string()),
+            message: commit.message().unwrap_or("").to_string(),
+            date: DateTime::from_timestamp(git_author.when().seconds(), 0).unwrap_or_default(),
+            author_id: None,
+            author_name: None,
+            author_image_updated_at: None,
+            git_author_name: git_author.name().unwrap_or("Unknown").to_string(),
+            git_author_email: git_author.email().unwrap_or("").to_string(),
+        }
+    }
+}
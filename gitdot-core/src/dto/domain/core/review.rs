@@ -1,6 +1,8 @@
 mod add_review_reviewer;
 mod get_review;
 mod get_review_diff_blobs;
+mod get_review_diff_mergeability;
+mod get_review_merge_requirements;
 mod list_reviews;
 mod merge_review_diff;
 mod process_review;
@@ -13,18 +15,21 @@ mod review_review_diff;
 mod update_review;
 mod update_review_comment;
 mod update_review_diff;
+mod update_review_merge_requirements;
 
 use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
 use crate::model::{
-    CommentSide, Diff, DiffStatus, Review, ReviewComment, ReviewStatus, ReviewVerdict, Revision,
-    User, Verdict,
+    CommentSide, CommitSignatureStatus, Diff, DiffStatus, MergeCheck, MergeRequirements, Review,
+    ReviewComment, ReviewStatus, ReviewVerdict, Revision, User, Verdict,
 };
 
 pub use add_review_reviewer::AddReviewReviewerReqeuest;
 pub use get_review::GetReviewRequest;
 pub use get_review_diff_blobs::GetReviewDiffBlobsRequest;
+pub use get_review_diff_mergeability::GetReviewDiffMergeabilityRequest;
+pub use get_review_merge_requirements::GetReviewMergeRequirementsRequest;
 pub use list_reviews::ListReviewsRequest;
 pub use merge_review_diff::MergeReviewDiffRequest;
 pub use process_review::ProcessReviewRequest;
@@ -37,6 +42,7 @@ pub use review_review_diff::{ReviewAction, ReviewCommentInput, ReviewReviewDiffR
 pub use update_review::UpdateReviewRequest;
 pub use update_review_comment::UpdateReviewCommentRequest;
 pub use update_review_diff::UpdateReviewDiffRequest;
+pub use update_review_merge_requirements::UpdateReviewMergeRequirementsRequest;
 
 #[derive(Debug, Clone)]
 pub struct ReviewResponse {
@@ -149,6 +155,7 @@ pub struct RevisionResponse {
     pub commit_hash: String,
     pub parent_hash: String,
     pub created_at: DateTime<Utc>,
+    pub signature_status: Option<CommitSignatureStatus>,
     pub verdicts: Vec<ReviewVerdictResponse>,
 }
 
@@ -161,6 +168,7 @@ impl From<Revision> for RevisionResponse {
             commit_hash: revision.commit_hash,
             parent_hash: revision.parent_hash,
             created_at: revision.created_at,
+            signature_status: revision.signature_status,
             verdicts: revision
                 .verdicts
                 .unwrap_or_default()
@@ -289,3 +297,55 @@ fn verdict_to_string(verdict: Verdict) -> String {
         Verdict::Rejected => "rejected".to_string(),
     }
 }
+
+#[derive(Debug, Clone)]
+pub struct MergeRequirementsResponse {
+    pub required_approvals: i32,
+    pub block_on_rejection: bool,
+    pub require_resolved_comments: bool,
+    pub require_green_build: bool,
+}
+
+impl From<MergeRequirements> for MergeRequirementsResponse {
+    fn from(requirements: MergeRequirements) -> Self {
+        Self {
+            required_approvals: requirements.required_approvals,
+            block_on_rejection: requirements.block_on_rejection,
+            require_resolved_comments: requirements.require_resolved_comments,
+            require_green_build: requirements.require_green_build,
+        }
+    }
+}
+
+/// The merge checklist for merging a review up to a given diff position.
+#[derive(Debug, Clone)]
+pub struct ReviewMergeabilityResponse {
+    pub mergeable: bool,
+    pub checks: Vec<MergeCheckResponse>,
+}
+
+impl From<Vec<MergeCheck>> for ReviewMergeabilityResponse {
+    fn from(checks: Vec<MergeCheck>) -> Self {
+        Self {
+            mergeable: checks.iter().all(|c| c.passed),
+            checks: checks.into_iter().map(MergeCheckResponse::from).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MergeCheckResponse {
+    pub kind: String,
+    pub passed: bool,
+    pub message: String,
+}
+
+impl From<MergeCheck> for MergeCheckResponse {
+    fn from(check: MergeCheck) -> Self {
+        Self {
+            kind: check.kind.into(),
+            passed: check.passed,
+            message: check.message,
+        }
+    }
+}
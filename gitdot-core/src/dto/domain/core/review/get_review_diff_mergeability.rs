@@ -0,0 +1,23 @@
+use crate::{
+    dto::common::{OwnerName, RepositoryName},
+    error::ReviewError,
+};
+
+#[derive(Debug, Clone)]
+pub struct GetReviewDiffMergeabilityRequest {
+    pub owner: OwnerName,
+    pub repo: RepositoryName,
+    pub number: i32,
+    pub position: i32,
+}
+
+impl GetReviewDiffMergeabilityRequest {
+    pub fn new(owner: &str, repo: &str, number: i32, position: i32) -> Result<Self, ReviewError> {
+        Ok(Self {
+            owner: OwnerName::parse(owner, "owner name")?,
+            repo: RepositoryName::parse(repo, "repository name")?,
+            number,
+            position,
+        })
+    }
+}
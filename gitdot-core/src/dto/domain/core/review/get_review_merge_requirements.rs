@@ -0,0 +1,19 @@
+use crate::{
+    dto::common::{OwnerName, RepositoryName},
+    error::ReviewError,
+};
+
+#[derive(Debug, Clone)]
+pub struct GetReviewMergeRequirementsRequest {
+    pub owner: OwnerName,
+    pub repo: RepositoryName,
+}
+
+impl GetReviewMergeRequirementsRequest {
+    pub fn new(owner: &str, repo: &str) -> Result<Self, ReviewError> {
+        Ok(Self {
+            owner: OwnerName::parse(owner, "owner name")?,
+            repo: RepositoryName::parse(repo, "repository name")?,
+        })
+    }
+}
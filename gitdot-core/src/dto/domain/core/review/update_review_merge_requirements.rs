@@ -0,0 +1,69 @@
+use crate::{
+    dto::common::{OwnerName, RepositoryName},
+    error::{InputError, ReviewError},
+    model::MergeRequirements,
+};
+
+/// Upper bound on `required_approvals`; anything larger could never be met
+/// on a realistically sized team.
+const MAX_REQUIRED_APPROVALS: i32 = 10;
+
+#[derive(Debug, Clone)]
+pub struct UpdateReviewMergeRequirementsRequest {
+    pub owner: OwnerName,
+    pub repo: RepositoryName,
+    pub requirements: MergeRequirements,
+}
+
+impl UpdateReviewMergeRequirementsRequest {
+    pub fn new(
+        owner: &str,
+        repo: &str,
+        requirements: MergeRequirements,
+    ) -> Result<Self, ReviewError> {
+        if !(0..=MAX_REQUIRED_APPROVALS).contains(&requirements.required_approvals) {
+            return Err(InputError::new(
+                "required approvals",
+                format!("must be between 0 and {MAX_REQUIRED_APPROVALS}"),
+            )
+            .into());
+        }
+
+        Ok(Self {
+            owner: OwnerName::parse(owner, "owner name")?,
+            repo: RepositoryName::parse(repo, "repository name")?,
+            requirements,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn requirements(required_approvals: i32) -> MergeRequirements {
+        MergeRequirements {
+            required_approvals,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn accepts_approval_counts_in_range() {
+        for n in [0, 1, MAX_REQUIRED_APPROVALS] {
+            let request =
+                UpdateReviewMergeRequirementsRequest::new("alice", "repo", requirements(n))
+                    .unwrap();
+            assert_eq!(request.requirements.required_approvals, n);
+        }
+    }
+
+    #[test]
+    fn rejects_approval_counts_out_of_range() {
+        for n in [-1, MAX_REQUIRED_APPROVALS + 1] {
+            let err = UpdateReviewMergeRequirementsRequest::new("alice", "repo", requirements(n))
+                .unwrap_err();
+            assert!(matches!(err, ReviewError::Input(_)));
+        }
+    }
+}
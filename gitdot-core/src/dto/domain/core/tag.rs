@@ -0,0 +1,36 @@
+mod create_tag;
+mod delete_tag;
+mod get_tag;
+mod list_tags;
+
+use chrono::{DateTime, Utc};
+
+use crate::dto::RepositoryCommitResponse;
+
+pub use create_tag::CreateTagRequest;
+pub use delete_tag::DeleteTagRequest;
+pub use get_tag::GetTagRequest;
+pub use list_tags::ListTagsRequest;
+
+#[derive(Debug, Clone)]
+pub struct TagResponse {
+    /// The short tag name, e.g. `v1.0.0`.
+    pub name: String,
+    /// The object the tag ref points at: the tag object for annotated tags,
+    /// otherwise the tagged commit.
+    pub sha: String,
+    pub commit: RepositoryCommitResponse,
+    pub annotated: bool,
+    /// The annotation without its signature. `None` for lightweight tags.
+    pub message: Option<String>,
+    pub tagger: Option<TaggerResponse>,
+    /// The armored PGP, SSH or X.509 signature of a signed annotated tag.
+    pub signature: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct TaggerResponse {
+    pub name: String,
+    pub email: String,
+    pub date: DateTime<Utc>,
+}
@@ -0,0 +1,44 @@
+use uuid::Uuid;
+
+use crate::{
+    dto::{OwnerName, RepositoryName, TagName},
+    error::{InputError, TagError},
+};
+
+#[derive(Debug, Clone)]
+pub struct CreateTagRequest {
+    pub name: RepositoryName,
+    pub owner_name: OwnerName,
+    pub tag: TagName,
+    /// A branch, tag or commit SHA resolving to the commit to tag.
+    pub target: String,
+    /// Creates an annotated tag tagged by the requesting user when set;
+    /// otherwise the tag is lightweight.
+    pub message: Option<String>,
+    pub user_id: Uuid,
+}
+
+impl CreateTagRequest {
+    pub fn new(
+        repo_name: &str,
+        owner_name: &str,
+        tag: &str,
+        target: &str,
+        message: Option<String>,
+        user_id: Uuid,
+    ) -> Result<Self, TagError> {
+        let target = target.trim();
+        if target.is_empty() {
+            return Err(InputError::new("target", "cannot be empty").into());
+        }
+
+        Ok(Self {
+            name: RepositoryName::parse(repo_name, "repository name")?,
+            owner_name: OwnerName::parse(owner_name, "owner name")?,
+            tag: TagName::parse(tag, "tag")?,
+            target: target.to_string(),
+            message: message.filter(|m| !m.trim().is_empty()),
+            user_id,
+        })
+    }
+}
@@ -0,0 +1,21 @@
+use crate::{
+    dto::{OwnerName, RepositoryName, TagName},
+    error::TagError,
+};
+
+#[derive(Debug, Clone)]
+pub struct GetTagRequest {
+    pub name: RepositoryName,
+    pub owner_name: OwnerName,
+    pub tag: TagName,
+}
+
+impl GetTagRequest {
+    pub fn new(repo_name: &str, owner_name: &str, tag: &str) -> Result<Self, TagError> {
+        Ok(Self {
+            name: RepositoryName::parse(repo_name, "repository name")?,
+            owner_name: OwnerName::parse(owner_name, "owner name")?,
+            tag: TagName::parse(tag, "tag")?,
+        })
+    }
+}
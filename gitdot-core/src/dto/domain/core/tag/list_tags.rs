@@ -0,0 +1,19 @@
+use crate::{
+    dto::{OwnerName, RepositoryName},
+    error::TagError,
+};
+
+#[derive(Debug, Clone)]
+pub struct ListTagsRequest {
+    pub name: RepositoryName,
+    pub owner_name: OwnerName,
+}
+
+impl ListTagsRequest {
+    pub fn new(repo_name: &str, owner_name: &str) -> Result<Self, TagError> {
+        Ok(Self {
+            name: RepositoryName::parse(repo_name, "repository name")?,
+            owner_name: OwnerName::parse(owner_name, "owner name")?,
+        })
+    }
+}
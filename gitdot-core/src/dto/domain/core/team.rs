@@ -0,0 +1,88 @@
+mod add_team_member;
+mod create_team;
+mod delete_team;
+mod get_team;
+mod list_teams;
+mod remove_team_member;
+mod remove_team_repository;
+mod set_team_repository;
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::model::{RepositoryRole, Team, TeamMember, TeamRepositoryAccess};
+
+pub use add_team_member::AddTeamMemberRequest;
+pub use create_team::CreateTeamRequest;
+pub use delete_team::DeleteTeamRequest;
+pub use get_team::GetTeamRequest;
+pub use list_teams::ListTeamsRequest;
+pub use remove_team_member::RemoveTeamMemberRequest;
+pub use remove_team_repository::RemoveTeamRepositoryRequest;
+pub use set_team_repository::SetTeamRepositoryRequest;
+
+#[derive(Debug, Clone)]
+pub struct TeamResponse {
+    pub id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub created_at: DateTime<Utc>,
+
+    pub members: Option<Vec<TeamMemberResponse>>,
+    pub repositories: Option<Vec<TeamRepositoryResponse>>,
+}
+
+impl From<Team> for TeamResponse {
+    fn from(team: Team) -> Self {
+        Self {
+            id: team.id,
+            name: team.name,
+            description: team.description,
+            created_at: team.created_at,
+            members: team
+                .members
+                .map(|members| members.into_iter().map(Into::into).collect()),
+            repositories: team
+                .repositories
+                .map(|repositories| repositories.into_iter().map(Into::into).collect()),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TeamMemberResponse {
+    pub user_id: Uuid,
+    pub user_name: String,
+    pub created_at: DateTime<Utc>,
+    pub image_updated_at: DateTime<Utc>,
+}
+
+impl From<TeamMember> for TeamMemberResponse {
+    fn from(member: TeamMember) -> Self {
+        Self {
+            user_id: member.user_id,
+            user_name: member.user_name,
+            created_at: member.created_at,
+            image_updated_at: member.image_updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TeamRepositoryResponse {
+    pub repository_id: Uuid,
+    pub repository_name: String,
+    pub role: RepositoryRole,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<TeamRepositoryAccess> for TeamRepositoryResponse {
+    fn from(access: TeamRepositoryAccess) -> Self {
+        Self {
+            repository_id: access.repository_id,
+            repository_name: access.repository_name,
+            role: access.role,
+            created_at: access.created_at,
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use crate::{
+    dto::{OwnerName, TeamName},
+    error::TeamError,
+};
+
+#[derive(Debug, Clone)]
+pub struct CreateTeamRequest {
+    pub org_name: OwnerName,
+    pub name: TeamName,
+    pub description: Option<String>,
+}
+
+impl CreateTeamRequest {
+    pub fn new(org_name: &str, name: &str, description: Option<String>) -> Result<Self, TeamError> {
+        Ok(Self {
+            org_name: OwnerName::parse(org_name, "organization name")?,
+            name: TeamName::parse(name, "team name")?,
+            description: description
+                .map(|d| d.trim().to_string())
+                .filter(|d| !d.is_empty()),
+        })
+    }
+}
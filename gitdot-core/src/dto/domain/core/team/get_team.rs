@@ -0,0 +1,19 @@
+use crate::{
+    dto::{OwnerName, TeamName},
+    error::TeamError,
+};
+
+#[derive(Debug, Clone)]
+pub struct GetTeamRequest {
+    pub org_name: OwnerName,
+    pub team_name: TeamName,
+}
+
+impl GetTeamRequest {
+    pub fn new(org_name: &str, team_name: &str) -> Result<Self, TeamError> {
+        Ok(Self {
+            org_name: OwnerName::parse(org_name, "organization name")?,
+            team_name: TeamName::parse(team_name, "team name")?,
+        })
+    }
+}
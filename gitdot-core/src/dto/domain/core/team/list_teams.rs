@@ -0,0 +1,14 @@
+use crate::{dto::OwnerName, error::TeamError};
+
+#[derive(Debug, Clone)]
+pub struct ListTeamsRequest {
+    pub org_name: OwnerName,
+}
+
+impl ListTeamsRequest {
+    pub fn new(org_name: &str) -> Result<Self, TeamError> {
+        Ok(Self {
+            org_name: OwnerName::parse(org_name, "organization name")?,
+        })
+    }
+}
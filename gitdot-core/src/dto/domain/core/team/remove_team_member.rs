@@ -0,0 +1,21 @@
+use crate::{
+    dto::{OwnerName, TeamName},
+    error::TeamError,
+};
+
+#[derive(Debug, Clone)]
+pub struct RemoveTeamMemberRequest {
+    pub org_name: OwnerName,
+    pub team_name: TeamName,
+    pub user_name: OwnerName,
+}
+
+impl RemoveTeamMemberRequest {
+    pub fn new(org_name: &str, team_name: &str, user_name: &str) -> Result<Self, TeamError> {
+        Ok(Self {
+            org_name: OwnerName::parse(org_name, "organization name")?,
+            team_name: TeamName::parse(team_name, "team name")?,
+            user_name: OwnerName::parse(user_name, "user name")?,
+        })
+    }
+}
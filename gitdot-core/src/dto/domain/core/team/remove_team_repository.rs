@@ -0,0 +1,21 @@
+use crate::{
+    dto::{OwnerName, RepositoryName, TeamName},
+    error::TeamError,
+};
+
+#[derive(Debug, Clone)]
+pub struct RemoveTeamRepositoryRequest {
+    pub org_name: OwnerName,
+    pub team_name: TeamName,
+    pub repo: RepositoryName,
+}
+
+impl RemoveTeamRepositoryRequest {
+    pub fn new(org_name: &str, team_name: &str, repo: &str) -> Result<Self, TeamError> {
+        Ok(Self {
+            org_name: OwnerName::parse(org_name, "organization name")?,
+            team_name: TeamName::parse(team_name, "team name")?,
+            repo: RepositoryName::parse(repo, "repository name")?,
+        })
+    }
+}
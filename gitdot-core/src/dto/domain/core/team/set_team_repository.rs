@@ -0,0 +1,24 @@
+use crate::{
+    dto::{OwnerName, RepositoryName, TeamName},
+    error::TeamError,
+    model::RepositoryRole,
+};
+
+#[derive(Debug, Clone)]
+pub struct SetTeamRepositoryRequest {
+    pub org_name: OwnerName,
+    pub team_name: TeamName,
+    pub repo: RepositoryName,
+    pub role: RepositoryRole,
+}
+
+impl SetTeamRepositoryRequest {
+    pub fn new(org_name: &str, team_name: &str, repo: &str, role: &str) -> Result<Self, TeamError> {
+        Ok(Self {
+            org_name: OwnerName::parse(org_name, "organization name")?,
+            team_name: TeamName::parse(team_name, "team name")?,
+            repo: RepositoryName::parse(repo, "repository name")?,
+            role: RepositoryRole::try_from(role)?,
+        })
+    }
+}
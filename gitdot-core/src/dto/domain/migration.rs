@@ -1,4 +1,6 @@
+mod cutover_repository_mirror;
 mod get_migration;
+mod get_repository_mirror;
 mod github;
 mod list_migrations;
 
@@ -6,11 +8,16 @@ use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
 use crate::model::{
-    Migration, MigrationOriginService, MigrationRepository, MigrationRepositoryStatus,
-    MigrationStatus, RepositoryOwnerType, RepositoryVisibility,
+    Migration, MigrationMirror, MigrationOriginService, MigrationRepository,
+    MigrationRepositoryStatus, MigrationStatus, MirrorSyncStatus, RepositoryOwnerType,
+    RepositoryVisibility,
 };
 
+pub use cutover_repository_mirror::{
+    CutoverRepositoryMirrorRequest, MirrorCutoverBackfill, MirrorCutoverResponse,
+};
 pub use get_migration::GetMigrationRequest;
+pub use get_repository_mirror::GetRepositoryMirrorRequest;
 pub use github::*;
 pub use list_migrations::ListMigrationsRequest;
 
@@ -69,6 +76,8 @@ pub struct MigrationRepositoryResponse {
 
     pub status: MigrationRepositoryStatus,
     pub error: Option<String>,
+    pub imported_questions: i32,
+    pub imported_reviews: i32,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -82,8 +91,59 @@ impl From<MigrationRepository> for MigrationRepositoryResponse {
             visibility: r.visibility,
             status: r.status,
             error: r.error,
+            imported_questions: r.imported_questions,
+            imported_reviews: r.imported_reviews,
             created_at: r.created_at,
             updated_at: r.updated_at,
         }
     }
 }
+
+#[derive(Debug, Clone)]
+pub struct RepositoryMirrorResponse {
+    pub origin_full_name: String,
+
+    /// `None` until the mirror has been synced once after migrating.
+    pub status: Option<MirrorSyncStatus>,
+    pub error: Option<String>,
+    pub last_attempted_at: Option<DateTime<Utc>>,
+    pub last_synced_at: Option<DateTime<Utc>>,
+}
+
+impl From<MigrationMirror> for RepositoryMirrorResponse {
+    fn from(m: MigrationMirror) -> Self {
+        Self {
+            origin_full_name: m.origin_full_name,
+            status: m.sync_status,
+            error: m.sync_error,
+            last_attempted_at: m.last_attempted_at,
+            last_synced_at: m.last_synced_at,
+        }
+    }
+}
+
+/// The username and password (or access token) a mirror remote is
+/// authenticated with. `git` is handed them through its environment, never the
+/// remote URL, so they stay out of the process list.
+#[derive(Clone, PartialEq, Eq)]
+pub struct MirrorCredentials {
+    pub username: String,
+    pub password: String,
+}
+
+impl std::fmt::Debug for MirrorCredentials {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MirrorCredentials")
+            .field("username", &self.username)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A ref that moved while syncing a mirror. Created refs have a zero
+/// `old_sha` and deleted refs a zero `new_sha`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MirrorRefUpdate {
+    pub ref_name: String,
+    pub old_sha: String,
+    pub new_sha: String,
+}
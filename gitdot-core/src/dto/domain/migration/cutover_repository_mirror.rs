@@ -0,0 +1,133 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::{
+    dto::{OwnerName, RepositoryName, SyncedRepositoryInfo},
+    error::{InputError, MigrationError},
+    model::MirrorCutover,
+};
+
+#[derive(Debug, Clone)]
+pub struct CutoverRepositoryMirrorRequest {
+    pub name: RepositoryName,
+    pub owner_name: OwnerName,
+    pub actor_id: Uuid,
+    /// Keep a push mirror back to the GitHub origin, authenticated with
+    /// `password` (a GitHub token allowed to push).
+    pub keep_push_mirror: bool,
+    pub username: Option<String>,
+    pub password: Option<String>,
+}
+
+impl CutoverRepositoryMirrorRequest {
+    pub fn new(
+        repo_name: &str,
+        owner_name: &str,
+        actor_id: Uuid,
+        keep_push_mirror: bool,
+        username: Option<String>,
+        password: Option<String>,
+    ) -> Result<Self, MigrationError> {
+        let username = username
+            .map(|u| u.trim().to_string())
+            .filter(|u| !u.is_empty());
+        let password = password.filter(|p| !p.is_empty());
+        if !keep_push_mirror && (username.is_some() || password.is_some()) {
+            return Err(InputError::new(
+                "password",
+                "credentials are only used with keep_push_mirror",
+            )
+            .into());
+        }
+        if keep_push_mirror && password.is_none() {
+            return Err(InputError::new("password", "is required to keep a push mirror").into());
+        }
+        if username
+            .as_ref()
+            .is_some_and(|u| u.len() > 255 || u.chars().any(char::is_control))
+        {
+            return Err(
+                InputError::new("username", "must be at most 255 printable characters").into(),
+            );
+        }
+
+        Ok(Self {
+            name: RepositoryName::parse(repo_name, "repository name")?,
+            owner_name: OwnerName::parse(owner_name, "owner name")?,
+            actor_id,
+            keep_push_mirror,
+            username,
+            password,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MirrorCutoverResponse {
+    pub id: Uuid,
+    pub origin_full_name: String,
+    pub actor_id: Uuid,
+    pub push_mirror_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<MirrorCutover> for MirrorCutoverResponse {
+    fn from(c: MirrorCutover) -> Self {
+        Self {
+            id: c.id,
+            origin_full_name: c.origin_full_name,
+            actor_id: c.actor_id,
+            push_mirror_id: c.push_mirror_id,
+            created_at: c.created_at,
+        }
+    }
+}
+
+/// The history of a cut-over repository still to be recorded as commits.
+#[derive(Debug, Clone)]
+pub struct MirrorCutoverBackfill {
+    pub cutover_id: Uuid,
+    /// Every branch and tag, default branch first, as created refs for the
+    /// caller to record commits for. Other refs start from the default
+    /// branch's head so shared history is walked once.
+    pub repository: SyncedRepositoryInfo,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create(
+        keep_push_mirror: bool,
+        username: Option<&str>,
+        password: Option<&str>,
+    ) -> Result<CutoverRepositoryMirrorRequest, MigrationError> {
+        CutoverRepositoryMirrorRequest::new(
+            "demo",
+            "alice",
+            Uuid::new_v4(),
+            keep_push_mirror,
+            username.map(str::to_string),
+            password.map(str::to_string),
+        )
+    }
+
+    #[test]
+    fn push_mirror_needs_a_password() {
+        assert!(create(true, None, None).is_err());
+        assert!(create(true, Some("bot"), Some("")).is_err());
+
+        let request = create(true, Some("  bot "), Some("token")).unwrap();
+        assert_eq!(request.username.as_deref(), Some("bot"));
+        assert_eq!(request.password.as_deref(), Some("token"));
+    }
+
+    #[test]
+    fn credentials_are_rejected_without_push_mirror() {
+        assert!(create(false, None, Some("token")).is_err());
+        assert!(create(false, Some("bot"), None).is_err());
+
+        let request = create(false, Some(" "), Some("")).unwrap();
+        assert!(request.username.is_none() && request.password.is_none());
+    }
+}
@@ -0,0 +1,19 @@
+use crate::{
+    dto::{OwnerName, RepositoryName},
+    error::MigrationError,
+};
+
+#[derive(Debug, Clone)]
+pub struct GetRepositoryMirrorRequest {
+    pub name: RepositoryName,
+    pub owner_name: OwnerName,
+}
+
+impl GetRepositoryMirrorRequest {
+    pub fn new(repo_name: &str, owner_name: &str) -> Result<Self, MigrationError> {
+        Ok(Self {
+            name: RepositoryName::parse(repo_name, "repository name")?,
+            owner_name: OwnerName::parse(owner_name, "owner name")?,
+        })
+    }
+}
@@ -8,12 +8,17 @@ use crate::{
 #[derive(Debug, Clone)]
 pub struct MigrateGitHubRepositoriesRequest {
     pub installation_id: i64,
+
+    /// The user running the migration, who stands in for imported authors
+    /// without a gitdot account.
+    pub author_id: Uuid,
     pub owner_id: Uuid,
     pub owner_name: OwnerName,
     pub owner_type: RepositoryOwnerType,
     pub migration_id: Uuid,
     pub migration_repositories: Vec<MigrationRepository>,
     pub readonly: bool,
+    pub import_history: bool,
 }
 
 #[derive(Debug, Clone)]
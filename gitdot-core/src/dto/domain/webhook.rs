@@ -1,23 +1,31 @@
 mod create_webhook;
 mod delete_webhook;
+mod deliver_webhook_event;
 mod event;
 mod get_webhook;
 mod github;
+mod list_webhook_deliveries;
 mod list_webhooks;
+mod redeliver_webhook_delivery;
 mod slack;
 mod update_webhook;
 
 use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
-use crate::model::{SlackWebhook, Webhook, WebhookEventType};
+use crate::model::{
+    SlackWebhook, Webhook, WebhookDelivery, WebhookDeliveryStatus, WebhookEventType,
+};
 
 pub use create_webhook::CreateWebhookRequest;
 pub use delete_webhook::DeleteWebhookRequest;
+pub use deliver_webhook_event::DeliverWebhookEventRequest;
 pub use event::*;
 pub use get_webhook::GetWebhookRequest;
 pub use github::*;
+pub use list_webhook_deliveries::ListWebhookDeliveriesRequest;
 pub use list_webhooks::ListWebhooksRequest;
+pub use redeliver_webhook_delivery::RedeliverWebhookDeliveryRequest;
 pub use slack::*;
 pub use update_webhook::UpdateWebhookRequest;
 
@@ -46,6 +54,43 @@ impl From<Webhook> for WebhookResponse {
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct WebhookDeliveryResponse {
+    pub id: Uuid,
+    pub webhook_id: Uuid,
+    pub event: WebhookEventType,
+    pub payload: serde_json::Value,
+    pub status: WebhookDeliveryStatus,
+    pub attempts: i32,
+    pub status_code: Option<i32>,
+    pub duration_ms: Option<i32>,
+    pub response_excerpt: Option<String>,
+    pub error: Option<String>,
+    pub redelivery_of: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub delivered_at: Option<DateTime<Utc>>,
+}
+
+impl From<WebhookDelivery> for WebhookDeliveryResponse {
+    fn from(delivery: WebhookDelivery) -> Self {
+        Self {
+            id: delivery.id,
+            webhook_id: delivery.webhook_id,
+            event: delivery.event,
+            payload: delivery.payload,
+            status: delivery.status,
+            attempts: delivery.attempts,
+            status_code: delivery.status_code,
+            duration_ms: delivery.duration_ms,
+            response_excerpt: delivery.response_excerpt,
+            error: delivery.error,
+            redelivery_of: delivery.redelivery_of,
+            created_at: delivery.created_at,
+            delivered_at: delivery.delivered_at,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct SlackWebhookResponse {
     pub id: Uuid,
@@ -0,0 +1,29 @@
+use crate::{
+    dto::common::{OwnerName, RepositoryName},
+    error::WebhookError,
+    model::WebhookEventType,
+};
+
+#[derive(Debug, Clone)]
+pub struct DeliverWebhookEventRequest {
+    pub owner_name: OwnerName,
+    pub repo_name: RepositoryName,
+    pub event: WebhookEventType,
+    pub payload: serde_json::Value,
+}
+
+impl DeliverWebhookEventRequest {
+    pub fn new(
+        owner: &str,
+        repo: &str,
+        event: WebhookEventType,
+        payload: serde_json::Value,
+    ) -> Result<Self, WebhookError> {
+        Ok(Self {
+            owner_name: OwnerName::parse(owner, "owner name")?,
+            repo_name: RepositoryName::parse(repo, "repository name")?,
+            event,
+            payload,
+        })
+    }
+}
@@ -1,3 +1,7 @@
 mod publish_repo_push;
+mod publish_review_event;
+mod publish_tag_event;
 
 pub use publish_repo_push::{PublishRepoPushRequest, RepoPushCommit, RepoPushEvent};
+pub use publish_review_event::{PublishReviewEventRequest, ReviewEvent, ReviewEventAction};
+pub use publish_tag_event::{PublishTagEventRequest, TagEvent, TagEventAction};
@@ -0,0 +1,90 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+    dto::{OwnerName, RepositoryName},
+    error::WebhookError,
+    model::WebhookEventType,
+};
+
+#[derive(Debug, Clone)]
+pub struct PublishReviewEventRequest {
+    pub owner: OwnerName,
+    pub repo: RepositoryName,
+    pub number: i32,
+    pub actor_id: Uuid,
+    pub action: ReviewEventAction,
+}
+
+impl PublishReviewEventRequest {
+    pub fn new(
+        owner: &str,
+        repo: &str,
+        number: i32,
+        actor_id: Uuid,
+        action: ReviewEventAction,
+    ) -> Result<Self, WebhookError> {
+        Ok(Self {
+            owner: OwnerName::parse(owner, "owner name")?,
+            repo: RepositoryName::parse(repo, "repository name")?,
+            number,
+            actor_id,
+            action,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewEvent {
+    pub owner: String,
+    pub repo: String,
+    pub review_id: Uuid,
+    pub number: i32,
+    pub title: String,
+    pub target_branch: String,
+    pub status: String,
+    pub author_name: Option<String>,
+    pub actor_id: Uuid,
+    pub actor_name: String,
+    pub action: ReviewEventAction,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl ReviewEvent {
+    pub fn event_type(&self) -> WebhookEventType {
+        self.action.event_type()
+    }
+}
+
+/// What happened to the review. Diff-scoped actions carry the diff position
+/// within the review.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ReviewEventAction {
+    Published,
+    RevisionPushed,
+    VerdictGiven {
+        position: i32,
+        verdict: String,
+        comment_count: usize,
+    },
+    DiffMerged {
+        position: i32,
+    },
+    CommentAdded {
+        position: Option<i32>,
+        comment_count: usize,
+    },
+    Closed,
+}
+
+impl ReviewEventAction {
+    /// Publishing is its own subscription; everything after it is an update.
+    pub fn event_type(&self) -> WebhookEventType {
+        match self {
+            Self::Published => WebhookEventType::ReviewPublish,
+            _ => WebhookEventType::ReviewUpdate,
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+    dto::{OwnerName, RepositoryName},
+    error::WebhookError,
+    util::git::{TAG_PREFIX, ZERO_SHA},
+};
+
+#[derive(Debug, Clone)]
+pub struct PublishTagEventRequest {
+    pub owner: OwnerName,
+    pub repo: RepositoryName,
+    pub tag_name: String,
+    pub old_sha: String,
+    pub new_sha: String,
+    pub actor_id: Uuid,
+}
+
+impl PublishTagEventRequest {
+    /// Accepts either a bare tag name or a full `refs/tags/` ref.
+    pub fn new(
+        owner: &str,
+        repo: &str,
+        tag: &str,
+        old_sha: String,
+        new_sha: String,
+        actor_id: Uuid,
+    ) -> Result<Self, WebhookError> {
+        Ok(Self {
+            owner: OwnerName::parse(owner, "owner name")?,
+            repo: RepositoryName::parse(repo, "repository name")?,
+            tag_name: tag.strip_prefix(TAG_PREFIX).unwrap_or(tag).to_string(),
+            old_sha,
+            new_sha,
+            actor_id,
+        })
+    }
+
+    pub fn action(&self) -> TagEventAction {
+        if self.old_sha == ZERO_SHA {
+            TagEventAction::Created
+        } else if self.new_sha == ZERO_SHA {
+            TagEventAction::Deleted
+        } else {
+            TagEventAction::Updated
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TagEvent {
+    pub owner: String,
+    pub repo: String,
+    pub tag_name: String,
+    pub action: TagEventAction,
+    pub old_sha: String,
+    pub new_sha: String,
+    pub actor_id: Uuid,
+    pub actor_name: String,
+    pub occurred_at: DateTime<Utc>,
+}
+
+/// What happened to the tag, derived from the zero sha on either side of the
+/// ref update. Moving an existing tag is reported as an update.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TagEventAction {
+    Created,
+    Updated,
+    Deleted,
+}
@@ -1,7 +1,10 @@
 use serde::Deserialize;
 
 use super::{GithubInstallation, GithubPushCommit, GithubPusher, GithubRepository};
-use crate::error::{InputError, WebhookError};
+use crate::{
+    dto::MirrorRefUpdate,
+    error::{InputError, WebhookError},
+};
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct ProcessGithubPushRequest {
@@ -31,5 +34,5 @@ pub struct ProcessGithubPushResponse {
 pub struct SyncedRepositoryInfo {
     pub owner_name: String,
     pub repo_name: String,
-    pub head_sha: String,
+    pub ref_updates: Vec<MirrorRefUpdate>,
 }
@@ -0,0 +1,39 @@
+use uuid::Uuid;
+
+use crate::{
+    dto::common::{Cursor, DEFAULT_PER_PAGE_LIMIT, MAX_PER_PAGE_LIMIT, OwnerName, RepositoryName},
+    error::WebhookError,
+    util::cursor,
+};
+
+#[derive(Debug, Clone)]
+pub struct ListWebhookDeliveriesRequest {
+    pub owner_name: OwnerName,
+    pub repo_name: RepositoryName,
+    pub webhook_id: Uuid,
+    pub cursor: Option<Cursor>,
+    pub limit: u32,
+}
+
+impl ListWebhookDeliveriesRequest {
+    pub fn new(
+        owner: &str,
+        repo: &str,
+        webhook_id: Uuid,
+        cursor: Option<&str>,
+        limit: Option<u32>,
+    ) -> Result<Self, WebhookError> {
+        let owner_name = OwnerName::parse(owner, "owner name")?;
+        let repo_name = RepositoryName::parse(repo, "repository name")?;
+        let cursor = cursor.map(cursor::decode).transpose()?;
+        Ok(Self {
+            owner_name,
+            repo_name,
+            webhook_id,
+            cursor,
+            limit: limit
+                .unwrap_or(DEFAULT_PER_PAGE_LIMIT)
+                .clamp(1, MAX_PER_PAGE_LIMIT),
+        })
+    }
+}
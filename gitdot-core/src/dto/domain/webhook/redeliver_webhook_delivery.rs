@@ -0,0 +1,30 @@
+use uuid::Uuid;
+
+use crate::{
+    dto::common::{OwnerName, RepositoryName},
+    error::WebhookError,
+};
+
+#[derive(Debug, Clone)]
+pub struct RedeliverWebhookDeliveryRequest {
+    pub owner_name: OwnerName,
+    pub repo_name: RepositoryName,
+    pub webhook_id: Uuid,
+    pub delivery_id: Uuid,
+}
+
+impl RedeliverWebhookDeliveryRequest {
+    pub fn new(
+        owner: &str,
+        repo: &str,
+        webhook_id: Uuid,
+        delivery_id: Uuid,
+    ) -> Result<Self, WebhookError> {
+        Ok(Self {
+            owner_name: OwnerName::parse(owner, "owner name")?,
+            repo_name: RepositoryName::parse(repo, "repository name")?,
+            webhook_id,
+            delivery_id,
+        })
+    }
+}
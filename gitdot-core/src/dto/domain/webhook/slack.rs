@@ -1,9 +1,11 @@
 mod list;
 mod notify_repo_push;
+mod notify_review_event;
 mod subscribe;
 mod unsubscribe;
 
 pub use list::ListSlackWebhooksRequest;
 pub use notify_repo_push::NotifyRepoPushRequest;
+pub use notify_review_event::NotifyReviewEventRequest;
 pub use subscribe::SubscribeSlackWebhookRequest;
 pub use unsubscribe::UnsubscribeSlackWebhookRequest;
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+use crate::dto::ReviewEventAction;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotifyReviewEventRequest {
+    pub channel_id: String,
+    pub owner: String,
+    pub repo: String,
+    pub number: i32,
+    pub title: String,
+    pub actor_name: String,
+    pub action: ReviewEventAction,
+}
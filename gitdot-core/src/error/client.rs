@@ -7,8 +7,10 @@ mod kafka;
 mod r2;
 mod redis;
 mod secret;
+mod signature;
 mod slack_bot;
 mod token;
+mod webhook;
 
 pub use clickhouse::ClickHouseError;
 pub use email::EmailError;
@@ -19,5 +21,7 @@ pub use kafka::KafkaError;
 pub use r2::R2Error;
 pub use redis::RedisError;
 pub use secret::SecretError;
+pub use signature::SignatureError;
 pub use slack_bot::SlackBotError;
 pub use token::TokenError;
+pub use webhook::WebhookClientError;
@@ -4,4 +4,10 @@ use thiserror::Error;
 pub enum R2Error {
     #[error("R2 error: failed to upload object: {0}")]
     UploadError(String),
+
+    #[error("R2 error: failed to download object: {0}")]
+    DownloadError(String),
+
+    #[error("R2 error: failed to delete object: {0}")]
+    DeleteError(String),
 }
@@ -0,0 +1,10 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum SignatureError {
+    #[error("Signature error: failed to run {0}: {1}")]
+    CommandError(&'static str, std::io::Error),
+
+    #[error("IO error: {0}")]
+    IoError(#[from] std::io::Error),
+}
@@ -0,0 +1,7 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum WebhookClientError {
+    #[error("Webhook request error: {0}")]
+    RequestError(#[from] reqwest::Error),
+}
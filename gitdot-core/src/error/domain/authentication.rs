@@ -1,11 +1,15 @@
 mod account;
 mod device;
 mod session;
+mod signing_key;
 mod slack;
+mod ssh_key;
 mod token;
 
 pub use account::AccountError;
 pub use device::DeviceError;
 pub use session::SessionError;
+pub use signing_key::SigningKeyError;
 pub use slack::SlackError;
+pub use ssh_key::SshKeyError;
 pub use token::TokenServiceError;
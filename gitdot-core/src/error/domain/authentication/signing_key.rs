@@ -0,0 +1,21 @@
+use thiserror::Error;
+
+use crate::error::{ConflictError, DatabaseError, InputError, NotFoundError, SignatureError};
+
+#[derive(Debug, Error)]
+pub enum SigningKeyError {
+    #[error(transparent)]
+    Input(#[from] InputError),
+
+    #[error(transparent)]
+    NotFound(#[from] NotFoundError),
+
+    #[error(transparent)]
+    Conflict(#[from] ConflictError),
+
+    #[error(transparent)]
+    DatabaseError(#[from] DatabaseError),
+
+    #[error(transparent)]
+    SignatureError(#[from] SignatureError),
+}
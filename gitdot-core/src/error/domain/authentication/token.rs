@@ -1,12 +1,24 @@
 use thiserror::Error;
 
-use crate::error::{DatabaseError, TokenError};
+use crate::{
+    error::{DatabaseError, InputError, NotFoundError, TokenError},
+    model::TokenScope,
+};
 
 #[derive(Debug, Error)]
 pub enum TokenServiceError {
     #[error("Unauthorized")]
     Unauthorized,
 
+    #[error("Token is missing the {} scope", .0.as_str())]
+    MissingScope(TokenScope),
+
+    #[error(transparent)]
+    Input(#[from] InputError),
+
+    #[error(transparent)]
+    NotFound(#[from] NotFoundError),
+
     #[error(transparent)]
     TokenError(#[from] TokenError),
 
@@ -1,7 +1,11 @@
+mod artifact;
 mod build;
 mod runner;
+mod secret;
 mod task;
 
+pub use artifact::ArtifactError;
 pub use build::BuildError;
 pub use runner::RunnerError;
+pub use secret::CiSecretError;
 pub use task::TaskError;
@@ -0,0 +1,18 @@
+use thiserror::Error;
+
+use crate::error::{DatabaseError, InputError, NotFoundError, R2Error};
+
+#[derive(Debug, Error)]
+pub enum ArtifactError {
+    #[error(transparent)]
+    Input(#[from] InputError),
+
+    #[error(transparent)]
+    NotFound(#[from] NotFoundError),
+
+    #[error(transparent)]
+    DatabaseError(#[from] DatabaseError),
+
+    #[error(transparent)]
+    R2Error(#[from] R2Error),
+}
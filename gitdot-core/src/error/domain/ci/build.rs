@@ -14,6 +14,9 @@ pub enum BuildError {
     #[error("Invalid build config: {0}")]
     InvalidConfig(String),
 
+    #[error("Task is not retryable: {0}")]
+    TaskNotRetryable(String),
+
     #[error(transparent)]
     GitError(GitError),
 
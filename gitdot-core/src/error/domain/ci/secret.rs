@@ -0,0 +1,18 @@
+use thiserror::Error;
+
+use crate::error::{DatabaseError, InputError, NotFoundError};
+
+#[derive(Debug, Error)]
+pub enum CiSecretError {
+    #[error(transparent)]
+    Input(#[from] InputError),
+
+    #[error(transparent)]
+    NotFound(#[from] NotFoundError),
+
+    #[error(transparent)]
+    DatabaseError(#[from] DatabaseError),
+
+    #[error("Failed to decrypt secret: {0}")]
+    DecryptionError(String),
+}
@@ -1,15 +1,31 @@
+mod branch_protection;
+mod code_search;
+mod collaborator;
 mod commit;
 mod git_http;
+mod maintenance;
+mod mirror;
 mod organization;
 mod question;
+mod release;
 mod repository;
 mod review;
+mod tag;
+mod team;
 mod user;
 
+pub use branch_protection::BranchProtectionError;
+pub use code_search::CodeSearchError;
+pub use collaborator::CollaboratorError;
 pub use commit::CommitError;
 pub use git_http::GitHttpError;
+pub use maintenance::MaintenanceError;
+pub use mirror::MirrorError;
 pub use organization::OrganizationError;
 pub use question::QuestionError;
+pub use release::ReleaseError;
 pub use repository::RepositoryError;
 pub use review::ReviewError;
+pub use tag::TagError;
+pub use team::TeamError;
 pub use user::UserError;
@@ -0,0 +1,26 @@
+use thiserror::Error;
+
+use crate::error::{GitError, InputError, NotFoundError};
+
+#[derive(Debug, Error)]
+pub enum CodeSearchError {
+    #[error(transparent)]
+    Input(#[from] InputError),
+
+    #[error(transparent)]
+    NotFound(#[from] NotFoundError),
+
+    #[error(transparent)]
+    GitError(GitError),
+}
+
+impl From<GitError> for CodeSearchError {
+    fn from(e: GitError) -> Self {
+        match e {
+            GitError::NotFound(path) => {
+                CodeSearchError::NotFound(NotFoundError::new("repository", path))
+            }
+            other => CodeSearchError::GitError(other),
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use thiserror::Error;
 
-use crate::error::{DatabaseError, GitError, InputError, NotFoundError};
+use crate::error::{DatabaseError, GitError, InputError, NotFoundError, SignatureError};
 
 #[derive(Debug, Error)]
 pub enum CommitError {
@@ -13,6 +13,9 @@ pub enum CommitError {
     #[error(transparent)]
     GitError(#[from] GitError),
 
+    #[error(transparent)]
+    SignatureError(#[from] SignatureError),
+
     #[error(transparent)]
     DatabaseError(#[from] DatabaseError),
 }
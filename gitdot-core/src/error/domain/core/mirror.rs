@@ -0,0 +1,21 @@
+use thiserror::Error;
+
+use crate::error::{ConflictError, DatabaseError, GitError, InputError, NotFoundError};
+
+#[derive(Debug, Error)]
+pub enum MirrorError {
+    #[error(transparent)]
+    Input(#[from] InputError),
+
+    #[error(transparent)]
+    NotFound(#[from] NotFoundError),
+
+    #[error(transparent)]
+    Conflict(#[from] ConflictError),
+
+    #[error(transparent)]
+    GitError(#[from] GitError),
+
+    #[error(transparent)]
+    DatabaseError(#[from] DatabaseError),
+}
@@ -0,0 +1,24 @@
+use thiserror::Error;
+
+use crate::error::{ConflictError, DatabaseError, GitError, InputError, NotFoundError, R2Error};
+
+#[derive(Debug, Error)]
+pub enum ReleaseError {
+    #[error(transparent)]
+    Input(#[from] InputError),
+
+    #[error(transparent)]
+    NotFound(#[from] NotFoundError),
+
+    #[error(transparent)]
+    Conflict(#[from] ConflictError),
+
+    #[error(transparent)]
+    GitError(#[from] GitError),
+
+    #[error(transparent)]
+    R2Error(#[from] R2Error),
+
+    #[error(transparent)]
+    DatabaseError(#[from] DatabaseError),
+}
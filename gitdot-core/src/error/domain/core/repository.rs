@@ -1,7 +1,7 @@
 use thiserror::Error;
 
 use crate::error::{
-    ConflictError, DatabaseError, GitError as Git2Error, InputError, NotFoundError,
+    ConflictError, DatabaseError, GitError as Git2Error, InputError, NotFoundError, SignatureError,
 };
 
 #[derive(Debug, Error)]
@@ -24,6 +24,9 @@ pub enum RepositoryError {
     #[error(transparent)]
     GitError(Git2Error),
 
+    #[error(transparent)]
+    SignatureError(#[from] SignatureError),
+
     #[error(transparent)]
     DatabaseError(#[from] DatabaseError),
 }
@@ -1,6 +1,9 @@
 use thiserror::Error;
 
-use crate::error::{ConflictError, DatabaseError, GitError, InputError, NotFoundError};
+use crate::{
+    error::{ConflictError, DatabaseError, GitError, InputError, NotFoundError},
+    model::MergeCheck,
+};
 
 #[derive(Debug, Error)]
 pub enum ReviewError {
@@ -34,6 +37,9 @@ pub enum ReviewError {
     #[error("Diff is not mergeable: {0}")]
     DiffNotMergeable(String),
 
+    #[error("Diff is not mergeable: {}", unmet_checks(.0))]
+    MergeRequirementsNotMet(Vec<MergeCheck>),
+
     #[error("User is not an org admin: {0}")]
     NotOrgAdmin(String),
 
@@ -49,3 +55,12 @@ pub enum ReviewError {
     #[error(transparent)]
     DatabaseError(#[from] DatabaseError),
 }
+
+fn unmet_checks(checks: &[MergeCheck]) -> String {
+    checks
+        .iter()
+        .filter(|c| !c.passed)
+        .map(|c| c.message.as_str())
+        .collect::<Vec<_>>()
+        .join("; ")
+}
@@ -1,11 +1,15 @@
 mod device;
 mod email_verification;
 mod session;
+mod signing_key;
 mod slack;
+mod ssh_key;
 mod token;
 
 pub use device::*;
 pub use email_verification::*;
 pub use session::*;
+pub use signing_key::*;
 pub use slack::*;
+pub use ssh_key::*;
 pub use token::*;
@@ -0,0 +1,36 @@
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, Type};
+use uuid::Uuid;
+
+/// A GPG or SSH public key a user registered for signing commits. A commit
+/// signature names its key by one of `key_ids`: the `SHA256:` fingerprint for
+/// an SSH key, or the 16-hex-digit key id of the primary key or a subkey for a
+/// GPG key.
+#[derive(Debug, Clone, FromRow)]
+pub struct SigningKey {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub name: String,
+    pub key_type: SigningKeyType,
+    /// The armored key block for GPG, `<type> <base64>` for SSH.
+    pub public_key: String,
+    pub fingerprint: String,
+    pub key_ids: Vec<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Type)]
+#[sqlx(type_name = "auth.signing_key_type", rename_all = "lowercase")]
+pub enum SigningKeyType {
+    Gpg,
+    Ssh,
+}
+
+impl SigningKeyType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SigningKeyType::Gpg => "gpg",
+            SigningKeyType::Ssh => "ssh",
+        }
+    }
+}
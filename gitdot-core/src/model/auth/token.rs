@@ -11,10 +11,25 @@ pub struct AccessToken {
     pub client_id: String,
     pub token_hash: String,
     pub token_type: TokenType,
+    pub name: String,
+    pub scopes: Vec<TokenScope>,
+    pub expires_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
     pub last_used_at: Option<DateTime<Utc>>,
 }
 
+impl AccessToken {
+    pub fn is_expired(&self) -> bool {
+        self.expires_at
+            .is_some_and(|expires_at| expires_at <= Utc::now())
+    }
+
+    /// Whether the token may be used where `required` is needed.
+    pub fn has_scope(&self, required: TokenScope) -> bool {
+        self.scopes.iter().any(|scope| scope.grants(required))
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Type)]
 #[sqlx(type_name = "auth.token_type", rename_all = "lowercase")]
 pub enum TokenType {
@@ -54,3 +69,61 @@ impl Into<String> for TokenType {
         }
     }
 }
+
+/// What a personal access token may do. Runner tokens carry no scopes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Type)]
+#[sqlx(type_name = "auth.token_scope")]
+pub enum TokenScope {
+    #[sqlx(rename = "repo:read")]
+    RepoRead,
+    #[sqlx(rename = "repo:write")]
+    RepoWrite,
+    #[sqlx(rename = "review:write")]
+    ReviewWrite,
+    #[sqlx(rename = "runner:admin")]
+    RunnerAdmin,
+    #[sqlx(rename = "admin:org")]
+    AdminOrg,
+}
+
+impl TokenScope {
+    pub const ALL: [TokenScope; 5] = [
+        TokenScope::RepoRead,
+        TokenScope::RepoWrite,
+        TokenScope::ReviewWrite,
+        TokenScope::RunnerAdmin,
+        TokenScope::AdminOrg,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TokenScope::RepoRead => "repo:read",
+            TokenScope::RepoWrite => "repo:write",
+            TokenScope::ReviewWrite => "review:write",
+            TokenScope::RunnerAdmin => "runner:admin",
+            TokenScope::AdminOrg => "admin:org",
+        }
+    }
+
+    /// Whether this scope covers `required`. `repo:write` implies `repo:read`.
+    pub fn grants(&self, required: TokenScope) -> bool {
+        *self == required || (*self == TokenScope::RepoWrite && required == TokenScope::RepoRead)
+    }
+}
+
+impl TryFrom<&str> for TokenScope {
+    type Error = InputError;
+
+    fn try_from(scope: &str) -> Result<Self, Self::Error> {
+        TokenScope::ALL
+            .into_iter()
+            .find(|s| s.as_str() == scope)
+            .ok_or_else(|| InputError::new("scopes", format!("Invalid token scope: {}", scope)))
+    }
+}
+
+impl From<TokenScope> for String {
+    fn from(scope: TokenScope) -> Self {
+        scope.as_str().to_string()
+    }
+}
@@ -1,7 +1,13 @@
+mod artifact;
 mod build;
+mod cache;
 mod runner;
+mod secret;
 mod task;
 
+pub use artifact::*;
 pub use build::*;
+pub use cache::*;
 pub use runner::*;
+pub use secret::*;
 pub use task::*;
@@ -0,0 +1,17 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// A gzipped tarball of one `artifacts` path, uploaded by the task that
+/// produced it. `name` is the path as written in `.gitdot-ci.toml`.
+#[derive(Debug, Clone, FromRow)]
+pub struct Artifact {
+    pub id: Uuid,
+    pub repository_id: Uuid,
+    pub build_id: Uuid,
+    pub task_id: Uuid,
+    pub name: String,
+    pub object_key: String,
+    pub size_bytes: i64,
+    pub created_at: DateTime<Utc>,
+}
@@ -2,7 +2,10 @@ use chrono::{DateTime, Utc};
 use sqlx::{FromRow, Type};
 use uuid::Uuid;
 
-use crate::error::InputError;
+use crate::{
+    error::InputError,
+    util::git::{BRANCH_PREFIX, DEFAULT_BRANCH},
+};
 
 #[derive(Debug, Clone, FromRow)]
 pub struct Build {
@@ -13,6 +16,9 @@ pub struct Build {
     pub commit_sha: String,
     pub trigger: BuildTrigger,
     pub status: BuildStatus,
+    pub review_id: Option<Uuid>,
+    pub diff_id: Option<Uuid>,
+    pub revision_id: Option<Uuid>,
     pub created_at: DateTime<Utc>,
 }
 
@@ -25,17 +31,55 @@ pub struct BuildWithStats {
     pub commit_sha: String,
     pub trigger: BuildTrigger,
     pub status: BuildStatus,
+    pub review_id: Option<Uuid>,
+    pub diff_id: Option<Uuid>,
+    pub revision_id: Option<Uuid>,
     pub total_tasks: i32,
     pub completed_tasks: i32,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
+/// The review revision a [`PullRequest`](BuildTrigger::PullRequest) build was
+/// started for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BuildReviewLink {
+    pub review_id: Uuid,
+    pub diff_id: Uuid,
+    pub revision_id: Uuid,
+}
+
+impl Build {
+    /// The scope the caches saved by this build's tasks are stored under:
+    /// its review for review builds, otherwise its trigger and ref. Tasks
+    /// restore from their own scope or [`default_cache_scope`](Self::default_cache_scope),
+    /// so a review build cannot seed a cache that a default-branch build (which
+    /// may hold secrets) restores.
+    pub fn cache_scope(&self) -> String {
+        match self.review_id {
+            Some(review_id) => format!("review:{review_id}"),
+            None => {
+                let trigger: String = self.trigger.clone().into();
+                format!("{trigger}:{}", self.ref_name)
+            }
+        }
+    }
+
+    /// The scope of default-branch pushes, which every build may restore from.
+    pub fn default_cache_scope() -> String {
+        let trigger: String = BuildTrigger::PushToMain.into();
+        format!("{trigger}:{BRANCH_PREFIX}{DEFAULT_BRANCH}")
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Type)]
 #[sqlx(type_name = "ci.build_trigger", rename_all = "snake_case")]
 pub enum BuildTrigger {
     PullRequest,
     PushToMain,
+    TagPush,
+    Manual,
+    Schedule,
 }
 
 impl Into<String> for BuildTrigger {
@@ -43,6 +87,9 @@ impl Into<String> for BuildTrigger {
         match self {
             BuildTrigger::PullRequest => "pull_request".to_string(),
             BuildTrigger::PushToMain => "push_to_main".to_string(),
+            BuildTrigger::TagPush => "tag_push".to_string(),
+            BuildTrigger::Manual => "manual".to_string(),
+            BuildTrigger::Schedule => "schedule".to_string(),
         }
     }
 }
@@ -52,6 +99,9 @@ impl From<gitdot_config::ci::BuildTrigger> for BuildTrigger {
         match trigger {
             gitdot_config::ci::BuildTrigger::PullRequest => BuildTrigger::PullRequest,
             gitdot_config::ci::BuildTrigger::PushToMain => BuildTrigger::PushToMain,
+            gitdot_config::ci::BuildTrigger::TagPush => BuildTrigger::TagPush,
+            gitdot_config::ci::BuildTrigger::Manual => BuildTrigger::Manual,
+            gitdot_config::ci::BuildTrigger::Schedule => BuildTrigger::Schedule,
         }
     }
 }
@@ -62,6 +112,7 @@ pub enum BuildStatus {
     Running,
     Success,
     Failure,
+    Cancelled,
 }
 
 impl TryFrom<&str> for BuildStatus {
@@ -72,6 +123,7 @@ impl TryFrom<&str> for BuildStatus {
             "running" => Ok(BuildStatus::Running),
             "success" => Ok(BuildStatus::Success),
             "failure" => Ok(BuildStatus::Failure),
+            "cancelled" => Ok(BuildStatus::Cancelled),
             _ => Err(InputError::new("build status", status)),
         }
     }
@@ -83,6 +135,7 @@ impl Into<String> for BuildStatus {
             BuildStatus::Running => "running".to_string(),
             BuildStatus::Success => "success".to_string(),
             BuildStatus::Failure => "failure".to_string(),
+            BuildStatus::Cancelled => "cancelled".to_string(),
         }
     }
 }
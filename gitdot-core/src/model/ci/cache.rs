@@ -0,0 +1,17 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// The latest gzipped tarball saved under a task's cache `key`, shared by
+/// the builds of the repository with the same [`Build::cache_scope`].
+///
+/// [`Build::cache_scope`]: crate::model::Build::cache_scope
+#[derive(Debug, Clone, FromRow)]
+pub struct Cache {
+    pub repository_id: Uuid,
+    pub scope: String,
+    pub key: String,
+    pub object_key: String,
+    pub size_bytes: i64,
+    pub updated_at: DateTime<Utc>,
+}
@@ -0,0 +1,16 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// A repository or organization CI secret. `ciphertext` is the value sealed
+/// with [`EncryptionKey`](crate::util::crypto::EncryptionKey) and never leaves
+/// the server except in the env of a task that declares `name`.
+#[derive(Debug, Clone, FromRow)]
+pub struct CiSecret {
+    /// The repository or organization the secret belongs to.
+    pub owner_id: Uuid,
+    pub name: String,
+    pub ciphertext: Vec<u8>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use chrono::{DateTime, Utc};
 use sqlx::{FromRow, Type};
 use uuid::Uuid;
@@ -18,11 +20,82 @@ pub struct Task {
     pub command: String,
 
     pub runner_id: Option<Uuid>,
+    pub attempt: i32,
+
+    #[sqlx(flatten)]
+    pub options: TaskOptions,
 
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
+/// How a runner executes a task, copied from its `.gitdot-ci.toml` entry.
+#[derive(Debug, Clone, Default, FromRow)]
+pub struct TaskOptions {
+    #[sqlx(json)]
+    pub env: BTreeMap<String, String>,
+    pub timeout_seconds: Option<i32>,
+    pub retries: i32,
+    pub working_directory: Option<String>,
+    pub allow_failure: bool,
+    pub artifacts: Vec<String>,
+    pub cache_key: Option<String>,
+    pub cache_paths: Vec<String>,
+    pub image: Option<String>,
+    pub network: TaskNetwork,
+    /// Names of the secrets passed to the command as env variables.
+    pub secrets: Vec<String>,
+}
+
+impl From<&gitdot_config::ci::TaskConfig> for TaskOptions {
+    fn from(config: &gitdot_config::ci::TaskConfig) -> Self {
+        Self {
+            env: config.env.clone(),
+            timeout_seconds: config.timeout().map(|t| t.as_secs() as i32),
+            retries: config.retries as i32,
+            working_directory: config.working_directory.clone(),
+            allow_failure: config.allow_failure,
+            artifacts: config.artifacts.clone(),
+            cache_key: config.cache.as_ref().map(|c| c.key.clone()),
+            cache_paths: config
+                .cache
+                .as_ref()
+                .map(|c| c.paths.clone())
+                .unwrap_or_default(),
+            image: config.image.clone(),
+            network: config.network.into(),
+            secrets: config.secrets.clone(),
+        }
+    }
+}
+
+/// Network access of a task run by the container executor.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Type)]
+#[sqlx(type_name = "ci.task_network", rename_all = "lowercase")]
+pub enum TaskNetwork {
+    #[default]
+    None,
+    Outbound,
+}
+
+impl From<gitdot_config::ci::NetworkPolicy> for TaskNetwork {
+    fn from(policy: gitdot_config::ci::NetworkPolicy) -> Self {
+        match policy {
+            gitdot_config::ci::NetworkPolicy::None => TaskNetwork::None,
+            gitdot_config::ci::NetworkPolicy::Outbound => TaskNetwork::Outbound,
+        }
+    }
+}
+
+impl From<TaskNetwork> for String {
+    fn from(network: TaskNetwork) -> Self {
+        match network {
+            TaskNetwork::None => "none".to_string(),
+            TaskNetwork::Outbound => "outbound".to_string(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Type)]
 #[sqlx(type_name = "ci.task_status", rename_all = "lowercase")]
 pub enum TaskStatus {
@@ -32,6 +105,30 @@ pub enum TaskStatus {
     Running,
     Success,
     Failure,
+    Cancelled,
+    #[sqlx(rename = "timed_out")]
+    TimedOut,
+}
+
+impl TaskStatus {
+    /// Whether the task has stopped and will not change again unless retried.
+    pub fn is_finished(&self) -> bool {
+        matches!(
+            self,
+            TaskStatus::Success
+                | TaskStatus::Failure
+                | TaskStatus::Cancelled
+                | TaskStatus::TimedOut
+        )
+    }
+
+    /// Whether the task ended unsuccessfully and can be retried.
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            TaskStatus::Failure | TaskStatus::Cancelled | TaskStatus::TimedOut
+        )
+    }
 }
 
 impl TryFrom<&str> for TaskStatus {
@@ -45,6 +142,8 @@ impl TryFrom<&str> for TaskStatus {
             "running" => Ok(TaskStatus::Running),
             "success" => Ok(TaskStatus::Success),
             "failure" => Ok(TaskStatus::Failure),
+            "cancelled" => Ok(TaskStatus::Cancelled),
+            "timed_out" => Ok(TaskStatus::TimedOut),
             _ => Err(InputError::new("task status", status).into()),
         }
     }
@@ -59,6 +158,8 @@ impl Into<String> for TaskStatus {
             TaskStatus::Running => "running".to_string(),
             TaskStatus::Success => "success".to_string(),
             TaskStatus::Failure => "failure".to_string(),
+            TaskStatus::Cancelled => "cancelled".to_string(),
+            TaskStatus::TimedOut => "timed_out".to_string(),
         }
     }
 }
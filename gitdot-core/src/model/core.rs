@@ -1,15 +1,27 @@
+mod branch_protection;
+mod collaborator;
 mod commit;
 mod commit_filter;
+mod maintenance;
+mod mirror;
 mod organization;
 mod question;
+mod release;
 mod repository;
 mod review;
+mod team;
 mod user;
 
+pub use branch_protection::*;
+pub use collaborator::*;
 pub use commit::*;
 pub use commit_filter::*;
+pub use maintenance::*;
+pub use mirror::*;
 pub use organization::*;
 pub use question::*;
+pub use release::*;
 pub use repository::*;
 pub use review::*;
+pub use team::*;
 pub use user::*;
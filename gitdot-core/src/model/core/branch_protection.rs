@@ -0,0 +1,56 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use crate::util::git::matches_branch_pattern;
+
+#[derive(Debug, Clone, FromRow)]
+pub struct BranchProtection {
+    pub id: Uuid,
+    pub repository_id: Uuid,
+    pub pattern: String,
+    pub block_force_push: bool,
+    pub block_deletion: bool,
+    pub require_review: bool,
+    pub require_green_build: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// The checks a branch protection rule enforces on pushes to matching branches.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BranchProtectionRules {
+    pub block_force_push: bool,
+    pub block_deletion: bool,
+    pub require_review: bool,
+    pub require_green_build: bool,
+}
+
+impl BranchProtectionRules {
+    /// Combines two rule sets; a check applies if either side enables it.
+    pub fn union(self, other: Self) -> Self {
+        Self {
+            block_force_push: self.block_force_push || other.block_force_push,
+            block_deletion: self.block_deletion || other.block_deletion,
+            require_review: self.require_review || other.require_review,
+            require_green_build: self.require_green_build || other.require_green_build,
+        }
+    }
+}
+
+impl BranchProtection {
+    /// Whether `branch` (a short name like `main` or `release/1.0`) is covered
+    /// by this rule's glob pattern.
+    pub fn matches(&self, branch: &str) -> bool {
+        matches_branch_pattern(&self.pattern, branch)
+    }
+
+    pub fn rules(&self) -> BranchProtectionRules {
+        BranchProtectionRules {
+            block_force_push: self.block_force_push,
+            block_deletion: self.block_deletion,
+            require_review: self.require_review,
+            require_green_build: self.require_green_build,
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, Type};
+use uuid::Uuid;
+
+use crate::error::InputError;
+
+#[derive(Debug, Clone, FromRow)]
+pub struct RepositoryCollaborator {
+    pub repository_id: Uuid,
+    pub user_id: Uuid,
+    pub user_name: String,
+    pub role: RepositoryRole,
+    pub created_at: DateTime<Utc>,
+    pub image_updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct RepositoryInvitation {
+    pub id: Uuid,
+    pub repository_id: Uuid,
+    pub owner_name: String,
+    pub repository_name: String,
+    pub invitee_id: Uuid,
+    pub invitee_name: String,
+    pub inviter_id: Uuid,
+    pub inviter_name: String,
+    pub role: RepositoryRole,
+    pub created_at: DateTime<Utc>,
+}
+
+/// The access a collaborator or team is granted on a repository. Variants are
+/// ordered, so each role includes everything the roles before it allow.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Type, Serialize, Deserialize,
+)]
+#[sqlx(type_name = "core.repository_role", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum RepositoryRole {
+    Read,
+    Triage,
+    Write,
+    Maintain,
+    Admin,
+}
+
+impl TryFrom<&str> for RepositoryRole {
+    type Error = InputError;
+
+    fn try_from(role: &str) -> Result<Self, Self::Error> {
+        match role {
+            "read" => Ok(RepositoryRole::Read),
+            "triage" => Ok(RepositoryRole::Triage),
+            "write" => Ok(RepositoryRole::Write),
+            "maintain" => Ok(RepositoryRole::Maintain),
+            "admin" => Ok(RepositoryRole::Admin),
+            _ => Err(InputError::new("role", role)),
+        }
+    }
+}
+
+impl From<RepositoryRole> for String {
+    fn from(role: RepositoryRole) -> Self {
+        match role {
+            RepositoryRole::Read => "read".to_string(),
+            RepositoryRole::Triage => "triage".to_string(),
+            RepositoryRole::Write => "write".to_string(),
+            RepositoryRole::Maintain => "maintain".to_string(),
+            RepositoryRole::Admin => "admin".to_string(),
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use sqlx::FromRow;
+use sqlx::{FromRow, Type};
 use uuid::Uuid;
 
 #[derive(Debug, Clone, FromRow)]
@@ -24,6 +24,9 @@ pub struct Commit {
     pub review_number: Option<i32>,
     pub diff_position: Option<i32>,
 
+    /// `None` for an unsigned commit.
+    pub signature_status: Option<CommitSignatureStatus>,
+
     #[sqlx(json)]
     pub diffs: Vec<CommitDiff>,
 
@@ -31,6 +34,34 @@ pub struct Commit {
     pub repository: CommitRepository,
 }
 
+/// The outcome of checking a commit's signature against the signing keys users
+/// have registered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(type_name = "core.commit_signature_status", rename_all = "snake_case")]
+pub enum CommitSignatureStatus {
+    /// Signed by a registered key whose owner has verified the author email.
+    Verified,
+    /// The signature does not verify, or is in an unsupported format.
+    Unverified,
+    /// Signed by a key no user has registered.
+    UnknownKey,
+    /// Signed by a registered key, but the author email is not one of the
+    /// key owner's verified emails.
+    BadEmail,
+}
+
+impl CommitSignatureStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CommitSignatureStatus::Verified => "verified",
+            CommitSignatureStatus::Unverified => "unverified",
+            CommitSignatureStatus::UnknownKey => "unknown_key",
+            CommitSignatureStatus::BadEmail => "bad_email",
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CommitDiff {
     pub path: String,
@@ -0,0 +1,44 @@
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, Type};
+use uuid::Uuid;
+
+/// Background maintenance state of a bare repository. A repository has no row
+/// until it is first pushed to or has maintenance requested.
+#[derive(Debug, Clone, FromRow)]
+pub struct RepositoryMaintenance {
+    pub repository_id: Uuid,
+    pub owner_name: String,
+    pub repository_name: String,
+    /// Pushes (including review ref updates) since the last run started.
+    pub pending_pushes: i32,
+    pub last_pushed_at: Option<DateTime<Utc>>,
+    /// Set when a run was requested by hand and not yet started.
+    pub requested_at: Option<DateTime<Utc>>,
+    /// Set while a run is in progress.
+    pub started_at: Option<DateTime<Utc>>,
+    pub finished_at: Option<DateTime<Utc>>,
+    pub last_status: Option<MaintenanceStatus>,
+    pub last_error: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Type)]
+#[sqlx(type_name = "core.maintenance_status", rename_all = "lowercase")]
+pub enum MaintenanceStatus {
+    Success,
+    Failure,
+}
+
+impl MaintenanceStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MaintenanceStatus::Success => "success",
+            MaintenanceStatus::Failure => "failure",
+        }
+    }
+}
+
+impl From<MaintenanceStatus> for String {
+    fn from(status: MaintenanceStatus) -> Self {
+        status.as_str().to_string()
+    }
+}
@@ -0,0 +1,90 @@
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, Type};
+use uuid::Uuid;
+
+/// A remote a repository is kept in sync with. A pull mirror fetches the
+/// remote's branches and tags into the repository every `interval_seconds`; a
+/// push mirror pushes the repository's branches and tags to the remote after
+/// every push. Either can also be synced on request.
+#[derive(Debug, Clone, FromRow)]
+pub struct RepositoryMirror {
+    pub id: Uuid,
+    pub repository_id: Uuid,
+    pub owner_name: String,
+    pub repository_name: String,
+    pub direction: MirrorDirection,
+    pub url: String,
+    pub username: Option<String>,
+    /// The password or access token, sealed with
+    /// [`EncryptionKey`](crate::util::crypto::EncryptionKey).
+    pub password_ciphertext: Option<Vec<u8>>,
+    /// `None` for push mirrors.
+    pub interval_seconds: Option<i32>,
+    /// Set when a sync was requested and not yet started.
+    pub requested_at: Option<DateTime<Utc>>,
+    /// Set while a sync is in progress.
+    pub started_at: Option<DateTime<Utc>>,
+    pub finished_at: Option<DateTime<Utc>>,
+    pub last_synced_at: Option<DateTime<Utc>>,
+    pub last_status: Option<MirrorRunStatus>,
+    pub last_error: Option<String>,
+    /// Whether the repository was already read-only when the mirror was
+    /// added, so removing a pull mirror can leave it that way.
+    pub was_readonly: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+/// One finished sync of a [`RepositoryMirror`].
+#[derive(Debug, Clone, FromRow)]
+pub struct RepositoryMirrorRun {
+    pub id: Uuid,
+    pub mirror_id: Uuid,
+    pub status: MirrorRunStatus,
+    pub error: Option<String>,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Type)]
+#[sqlx(type_name = "core.mirror_direction", rename_all = "lowercase")]
+pub enum MirrorDirection {
+    Pull,
+    Push,
+}
+
+impl MirrorDirection {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MirrorDirection::Pull => "pull",
+            MirrorDirection::Push => "push",
+        }
+    }
+}
+
+impl From<MirrorDirection> for String {
+    fn from(direction: MirrorDirection) -> Self {
+        direction.as_str().to_string()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Type)]
+#[sqlx(type_name = "core.mirror_run_status", rename_all = "lowercase")]
+pub enum MirrorRunStatus {
+    Success,
+    Failure,
+}
+
+impl MirrorRunStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MirrorRunStatus::Success => "success",
+            MirrorRunStatus::Failure => "failure",
+        }
+    }
+}
+
+impl From<MirrorRunStatus> for String {
+    fn from(status: MirrorRunStatus) -> Self {
+        status.as_str().to_string()
+    }
+}
@@ -81,3 +81,33 @@ pub enum VoteTarget {
     Answer,
     Comment,
 }
+
+/// A question brought over from another forge (a GitHub issue or discussion),
+/// inserted with its original number and timestamps.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportedQuestion {
+    pub number: i32,
+    pub author_id: Uuid,
+    pub title: String,
+    pub body: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub answers: Vec<ImportedAnswer>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportedAnswer {
+    pub author_id: Uuid,
+    pub body: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub comments: Vec<ImportedComment>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportedComment {
+    pub author_id: Uuid,
+    pub body: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
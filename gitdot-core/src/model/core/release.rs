@@ -0,0 +1,36 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// A release of a repository, tied to one of its tags by name. The tag itself
+/// lives in git and may be deleted independently.
+#[derive(Debug, Clone, FromRow)]
+pub struct Release {
+    pub id: Uuid,
+    pub repository_id: Uuid,
+    pub tag_name: String,
+    pub name: String,
+    /// Release notes in markdown.
+    pub body: String,
+    pub draft: bool,
+    pub prerelease: bool,
+    pub author_id: Option<Uuid>,
+    pub author_name: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    /// Set the first time the release leaves draft.
+    pub published_at: Option<DateTime<Utc>>,
+}
+
+/// A file attached to a release, stored in R2 under `object_key`.
+#[derive(Debug, Clone, FromRow)]
+pub struct ReleaseAsset {
+    pub id: Uuid,
+    pub release_id: Uuid,
+    pub name: String,
+    pub content_type: String,
+    pub object_key: String,
+    pub size_bytes: i64,
+    pub uploader_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
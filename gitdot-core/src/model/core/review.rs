@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 use sqlx::{FromRow, Type};
 use uuid::Uuid;
 
-use crate::model::User;
+use crate::model::{CommitSignatureStatus, User};
 
 #[derive(Debug, Clone, FromRow)]
 pub struct Review {
@@ -72,6 +72,11 @@ pub struct Revision {
     pub parent_hash: String,
     pub created_at: DateTime<Utc>,
 
+    /// The signature status of the revision's commit, once it is ingested.
+    #[sqlx(default)]
+    #[serde(default)]
+    pub signature_status: Option<CommitSignatureStatus>,
+
     #[sqlx(json(nullable))]
     pub verdicts: Option<Vec<ReviewVerdict>>,
 }
@@ -138,3 +143,96 @@ pub enum CommentSide {
     Old,
     New,
 }
+
+/// A repository's conditions for merging review diffs. Repositories without
+/// a stored row use [`Default`], which imposes no requirements.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, FromRow)]
+pub struct MergeRequirements {
+    pub required_approvals: i32,
+    pub block_on_rejection: bool,
+    pub require_resolved_comments: bool,
+    pub require_green_build: bool,
+}
+
+/// One line of a merge checklist: a condition and whether it currently holds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MergeCheck {
+    pub kind: MergeCheckKind,
+    pub passed: bool,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MergeCheckKind {
+    ReviewOpen,
+    Approvals,
+    NoRejections,
+    CommentsResolved,
+    GreenBuild,
+}
+
+impl From<MergeCheckKind> for String {
+    fn from(kind: MergeCheckKind) -> Self {
+        match kind {
+            MergeCheckKind::ReviewOpen => "review_open".to_string(),
+            MergeCheckKind::Approvals => "approvals".to_string(),
+            MergeCheckKind::NoRejections => "no_rejections".to_string(),
+            MergeCheckKind::CommentsResolved => "comments_resolved".to_string(),
+            MergeCheckKind::GreenBuild => "green_build".to_string(),
+        }
+    }
+}
+
+/// A review brought over from another forge (a GitHub pull request), inserted
+/// with its original number and timestamps. Each diff carries a single
+/// revision; verdicts and comments point at diffs by `position`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportedReview {
+    pub number: i32,
+    pub author_id: Uuid,
+    pub title: String,
+    pub description: String,
+    pub target_branch: String,
+    pub status: ReviewStatus,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub diffs: Vec<ImportedDiff>,
+    pub reviewer_ids: Vec<Uuid>,
+    pub verdicts: Vec<ImportedVerdict>,
+    pub comments: Vec<ImportedReviewComment>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportedDiff {
+    pub position: i32,
+    pub message: String,
+    pub status: DiffStatus,
+    pub commit_hash: String,
+    pub parent_hash: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportedVerdict {
+    pub diff_position: i32,
+    pub reviewer_id: Uuid,
+    pub verdict: Verdict,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportedReviewComment {
+    pub diff_position: i32,
+    pub author_id: Uuid,
+
+    /// Index of the comment this one replies to, always an earlier entry.
+    pub parent: Option<usize>,
+
+    pub body: String,
+    pub file_path: Option<String>,
+    pub line_number_start: Option<i32>,
+    pub line_number_end: Option<i32>,
+    pub side: Option<CommentSide>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
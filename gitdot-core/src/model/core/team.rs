@@ -0,0 +1,39 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use super::RepositoryRole;
+
+#[derive(Debug, Clone, FromRow)]
+pub struct Team {
+    pub id: Uuid,
+    pub organization_id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub created_at: DateTime<Utc>,
+
+    #[sqlx(json(nullable))]
+    pub members: Option<Vec<TeamMember>>,
+    #[sqlx(json(nullable))]
+    pub repositories: Option<Vec<TeamRepositoryAccess>>,
+}
+
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct TeamMember {
+    pub team_id: Uuid,
+    pub user_id: Uuid,
+    pub user_name: String,
+    pub created_at: DateTime<Utc>,
+    pub image_updated_at: DateTime<Utc>,
+}
+
+/// A repository a team has been granted a role on.
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct TeamRepositoryAccess {
+    pub team_id: Uuid,
+    pub repository_id: Uuid,
+    pub repository_name: String,
+    pub role: RepositoryRole,
+    pub created_at: DateTime<Utc>,
+}
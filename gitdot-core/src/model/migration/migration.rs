@@ -57,6 +57,8 @@ pub struct MigrationRepository {
 
     pub status: MigrationRepositoryStatus,
     pub error: Option<String>,
+    pub imported_questions: i32,
+    pub imported_reviews: i32,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -70,6 +72,45 @@ pub struct MigrationRepository {
 pub enum MigrationRepositoryStatus {
     Pending,
     Running,
+    Importing,
     Completed,
     Failed,
 }
+
+/// A migrated repository that is still a read-only mirror of its GitHub origin,
+/// with the GitHub App installation used to fetch from it and its last sync.
+#[derive(Debug, Clone, FromRow)]
+pub struct MigrationMirror {
+    pub migration_repository_id: Uuid,
+    pub origin_full_name: String,
+    /// `None` once the GitHub App was uninstalled, which stops syncing.
+    pub installation_id: Option<i64>,
+    pub repository_id: Uuid,
+    pub owner_name: String,
+    pub repository_name: String,
+
+    pub sync_status: Option<MirrorSyncStatus>,
+    pub sync_error: Option<String>,
+    pub last_attempted_at: Option<DateTime<Utc>>,
+    pub last_synced_at: Option<DateTime<Utc>>,
+}
+
+/// The switch of a read-only mirror to a live repository, kept as an audit
+/// record of who made it and whether a push mirror back to GitHub was kept.
+#[derive(Debug, Clone, FromRow)]
+pub struct MirrorCutover {
+    pub id: Uuid,
+    pub migration_repository_id: Uuid,
+    pub repository_id: Uuid,
+    pub actor_id: Uuid,
+    pub origin_full_name: String,
+    pub push_mirror_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Type)]
+#[sqlx(type_name = "migration.mirror_sync_status", rename_all = "lowercase")]
+pub enum MirrorSyncStatus {
+    Synced,
+    Failed,
+}
@@ -1,5 +1,7 @@
+mod delivery;
 mod slack;
 mod webhook;
 
+pub use delivery::{WebhookDelivery, WebhookDeliveryStatus};
 pub use slack::SlackWebhook;
 pub use webhook::{Webhook, WebhookEventType};
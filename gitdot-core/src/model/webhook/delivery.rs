@@ -0,0 +1,51 @@
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, Type};
+use uuid::Uuid;
+
+use super::WebhookEventType;
+
+#[derive(Debug, Clone, FromRow)]
+pub struct WebhookDelivery {
+    pub id: Uuid,
+    pub webhook_id: Uuid,
+    pub event: WebhookEventType,
+    pub payload: serde_json::Value,
+    pub status: WebhookDeliveryStatus,
+    pub attempts: i32,
+
+    pub status_code: Option<i32>,
+    pub duration_ms: Option<i32>,
+    pub response_excerpt: Option<String>,
+    pub error: Option<String>,
+
+    pub redelivery_of: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub delivered_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Type)]
+#[sqlx(
+    type_name = "webhook.webhook_delivery_status",
+    rename_all = "lowercase"
+)]
+pub enum WebhookDeliveryStatus {
+    Pending,
+    Success,
+    Failure,
+}
+
+impl WebhookDeliveryStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WebhookDeliveryStatus::Pending => "pending",
+            WebhookDeliveryStatus::Success => "success",
+            WebhookDeliveryStatus::Failure => "failure",
+        }
+    }
+}
+
+impl From<WebhookDeliveryStatus> for String {
+    fn from(status: WebhookDeliveryStatus) -> Self {
+        status.as_str().to_string()
+    }
+}
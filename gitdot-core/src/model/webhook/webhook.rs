@@ -20,6 +20,7 @@ pub enum WebhookEventType {
     Push,
     ReviewPublish,
     ReviewUpdate,
+    Tag,
 }
 
 impl WebhookEventType {
@@ -28,6 +29,7 @@ impl WebhookEventType {
             WebhookEventType::Push => "push",
             WebhookEventType::ReviewPublish => "review_publish",
             WebhookEventType::ReviewUpdate => "review_update",
+            WebhookEventType::Tag => "tag",
         }
     }
 }
@@ -46,6 +48,7 @@ impl TryFrom<&str> for WebhookEventType {
             "push" => Ok(WebhookEventType::Push),
             "review_publish" => Ok(WebhookEventType::ReviewPublish),
             "review_update" => Ok(WebhookEventType::ReviewUpdate),
+            "tag" => Ok(WebhookEventType::Tag),
             _ => Err(format!("Invalid webhook event type: {value}")),
         }
     }
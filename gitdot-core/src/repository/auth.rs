@@ -1,7 +1,9 @@
 mod device;
 mod email_verification;
 mod session;
+mod signing_key;
 mod slack;
+mod ssh_key;
 mod token;
 
 pub use device::{DeviceRepository, PgDeviceRepository};
@@ -9,5 +11,7 @@ pub use email_verification::{
     EmailCodeVerification, EmailVerificationRepository, PgEmailVerificationRepository,
 };
 pub use session::{AuthCodeVerification, PgSessionRepository, SessionRepository};
+pub use signing_key::{PgSigningKeyRepository, SigningKeyRepository};
 pub use slack::{PgSlackRepository, SlackRepository};
+pub use ssh_key::{PgSshKeyRepository, SshKeyRepository};
 pub use token::{PgTokenRepository, TokenRepository};
@@ -0,0 +1,196 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{
+    error::DatabaseError,
+    model::{SigningKey, SigningKeyType},
+};
+
+/// sqlx data-access layer for the `auth.signing_keys` table, which holds the
+/// GPG and SSH public keys users register for signing commits. A key (by
+/// fingerprint) belongs to at most one user.
+#[async_trait]
+pub trait SigningKeyRepository: Send + Sync + Clone + 'static {
+    /// Inserts a key for `user_id` and returns the created row.
+    async fn create(
+        &self,
+        user_id: Uuid,
+        name: &str,
+        key_type: SigningKeyType,
+        public_key: &str,
+        fingerprint: &str,
+        key_ids: &[String],
+    ) -> Result<SigningKey, DatabaseError>;
+
+    /// Lists a user's keys, newest first.
+    async fn list_by_user(&self, user_id: Uuid) -> Result<Vec<SigningKey>, DatabaseError>;
+
+    /// Lists the keys that `key_id` (as named by a signature) may refer to,
+    /// oldest first. A full fingerprint matches at most one key, but a
+    /// 16-hex-digit GPG key id matches every key whose fingerprint ends with
+    /// it, so callers must check the signature against each.
+    async fn list_by_key_id(&self, key_id: &str) -> Result<Vec<SigningKey>, DatabaseError>;
+
+    /// Deletes the key if it belongs to `user_id`. Returns whether a row was
+    /// removed.
+    async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<bool, DatabaseError>;
+}
+
+#[derive(Debug, Clone)]
+pub struct PgSigningKeyRepository {
+    pool: PgPool,
+}
+
+impl PgSigningKeyRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[crate::instrument_all(level = "debug")]
+#[async_trait]
+impl SigningKeyRepository for PgSigningKeyRepository {
+    async fn create(
+        &self,
+        user_id: Uuid,
+        name: &str,
+        key_type: SigningKeyType,
+        public_key: &str,
+        fingerprint: &str,
+        key_ids: &[String],
+    ) -> Result<SigningKey, DatabaseError> {
+        let key = sqlx::query_as::<_, SigningKey>(
+            r#"
+            INSERT INTO auth.signing_keys (user_id, name, key_type, public_key, fingerprint, key_ids)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            RETURNING id, user_id, name, key_type, public_key, fingerprint, key_ids, created_at
+            "#,
+        )
+        .bind(user_id)
+        .bind(name)
+        .bind(key_type)
+        .bind(public_key)
+        .bind(fingerprint)
+        .bind(key_ids)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(key)
+    }
+
+    async fn list_by_user(&self, user_id: Uuid) -> Result<Vec<SigningKey>, DatabaseError> {
+        let keys = sqlx::query_as::<_, SigningKey>(
+            r#"
+            SELECT id, user_id, name, key_type, public_key, fingerprint, key_ids, created_at
+            FROM auth.signing_keys
+            WHERE user_id = $1
+            ORDER BY created_at DESC
+            "#,
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(keys)
+    }
+
+    async fn list_by_key_id(&self, key_id: &str) -> Result<Vec<SigningKey>, DatabaseError> {
+        let keys = sqlx::query_as::<_, SigningKey>(
+            r#"
+            SELECT id, user_id, name, key_type, public_key, fingerprint, key_ids, created_at
+            FROM auth.signing_keys
+            WHERE key_ids @> ARRAY[$1]
+               OR (length($1) = 16
+                   AND EXISTS (SELECT 1 FROM unnest(key_ids) AS k WHERE right(k, 16) = $1))
+            ORDER BY created_at ASC
+            "#,
+        )
+        .bind(key_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(keys)
+    }
+
+    async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<bool, DatabaseError> {
+        let result = sqlx::query("DELETE FROM auth.signing_keys WHERE id = $1 AND user_id = $2")
+            .bind(id)
+            .bind(user_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+#[cfg(all(test, feature = "db-tests"))]
+mod tests {
+    use sqlx::PgPool;
+    use uuid::Uuid;
+
+    use super::{PgSigningKeyRepository, SigningKeyRepository};
+    use crate::{model::SigningKeyType, repository::test_common::insert_user};
+
+    #[sqlx::test]
+    async fn keys_resolve_by_any_key_id_and_are_scoped_to_their_owner(pool: PgPool) {
+        let repo = PgSigningKeyRepository::new(pool.clone());
+        let alice = Uuid::new_v4();
+        let bob = Uuid::new_v4();
+        insert_user(&pool, alice, "alice").await;
+        insert_user(&pool, bob, "bob").await;
+
+        let key_ids = vec![
+            "4460EF86C008DB8069ABCD365DCD40D2005582C8".to_string(),
+            "B9DADA5FC9562F76EAC1F077402AADEBC60029CF".to_string(),
+        ];
+        let key = repo
+            .create(
+                alice,
+                "laptop",
+                SigningKeyType::Gpg,
+                "-----BEGIN PGP PUBLIC KEY BLOCK-----",
+                "4460EF86C008DB8069ABCD365DCD40D2005582C8",
+                &key_ids,
+            )
+            .await
+            .unwrap();
+        assert_eq!(key.key_type, SigningKeyType::Gpg);
+
+        // The same key cannot be registered twice, even by another user.
+        let err = repo
+            .create(
+                bob,
+                "laptop",
+                SigningKeyType::Gpg,
+                "-----BEGIN PGP PUBLIC KEY BLOCK-----",
+                "4460EF86C008DB8069ABCD365DCD40D2005582C8",
+                &key_ids,
+            )
+            .await
+            .unwrap_err();
+        assert!(err.is_unique_violation());
+
+        // A signature made by the subkey resolves to the registered key, by
+        // its fingerprint or its key id.
+        for key_id in [
+            "B9DADA5FC9562F76EAC1F077402AADEBC60029CF",
+            "402AADEBC60029CF",
+        ] {
+            let found = repo.list_by_key_id(key_id).await.unwrap();
+            assert_eq!(found.len(), 1, "{key_id}");
+            assert_eq!(found[0].id, key.id);
+        }
+        for key_id in ["0000000000000000", "B9DADA5FC9562F76", "402AADEBC60029CF0"] {
+            assert!(
+                repo.list_by_key_id(key_id).await.unwrap().is_empty(),
+                "{key_id}"
+            );
+        }
+
+        assert!(!repo.delete(bob, key.id).await.unwrap());
+        assert_eq!(repo.list_by_user(alice).await.unwrap().len(), 1);
+        assert!(repo.delete(alice, key.id).await.unwrap());
+        assert!(repo.list_by_user(alice).await.unwrap().is_empty());
+    }
+}
@@ -0,0 +1,201 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{error::DatabaseError, model::SshKey};
+
+/// sqlx data-access layer for the `auth.ssh_keys` table, which holds the SSH
+/// public keys users register for git over SSH. A key (by fingerprint) belongs
+/// to at most one user.
+#[async_trait]
+pub trait SshKeyRepository: Send + Sync + Clone + 'static {
+    /// Inserts a key for `user_id` and returns the created row.
+    async fn create(
+        &self,
+        user_id: Uuid,
+        name: &str,
+        key_type: &str,
+        public_key: &str,
+        fingerprint: &str,
+    ) -> Result<SshKey, DatabaseError>;
+
+    /// Lists a user's keys, newest first.
+    async fn list_by_user(&self, user_id: Uuid) -> Result<Vec<SshKey>, DatabaseError>;
+
+    /// Returns the key by id, or `Ok(None)` if it does not exist.
+    async fn get(&self, id: Uuid) -> Result<Option<SshKey>, DatabaseError>;
+
+    /// Returns the key with the given `SHA256:` fingerprint, or `Ok(None)` if
+    /// no user has registered it.
+    async fn get_by_fingerprint(&self, fingerprint: &str) -> Result<Option<SshKey>, DatabaseError>;
+
+    /// Sets `last_used_at` to now.
+    async fn touch(&self, id: Uuid) -> Result<(), DatabaseError>;
+
+    /// Deletes the key if it belongs to `user_id`. Returns whether a row was
+    /// removed.
+    async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<bool, DatabaseError>;
+}
+
+#[derive(Debug, Clone)]
+pub struct PgSshKeyRepository {
+    pool: PgPool,
+}
+
+impl PgSshKeyRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[crate::instrument_all(level = "debug")]
+#[async_trait]
+impl SshKeyRepository for PgSshKeyRepository {
+    async fn create(
+        &self,
+        user_id: Uuid,
+        name: &str,
+        key_type: &str,
+        public_key: &str,
+        fingerprint: &str,
+    ) -> Result<SshKey, DatabaseError> {
+        let key = sqlx::query_as::<_, SshKey>(
+            r#"
+            INSERT INTO auth.ssh_keys (user_id, name, key_type, public_key, fingerprint)
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING id, user_id, name, key_type, public_key, fingerprint, created_at,
+                      last_used_at
+            "#,
+        )
+        .bind(user_id)
+        .bind(name)
+        .bind(key_type)
+        .bind(public_key)
+        .bind(fingerprint)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(key)
+    }
+
+    async fn list_by_user(&self, user_id: Uuid) -> Result<Vec<SshKey>, DatabaseError> {
+        let keys = sqlx::query_as::<_, SshKey>(
+            r#"
+            SELECT id, user_id, name, key_type, public_key, fingerprint, created_at,
+                   last_used_at
+            FROM auth.ssh_keys
+            WHERE user_id = $1
+            ORDER BY created_at DESC
+            "#,
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(keys)
+    }
+
+    async fn get(&self, id: Uuid) -> Result<Option<SshKey>, DatabaseError> {
+        let key = sqlx::query_as::<_, SshKey>(
+            r#"
+            SELECT id, user_id, name, key_type, public_key, fingerprint, created_at,
+                   last_used_at
+            FROM auth.ssh_keys
+            WHERE id = $1
+            "#,
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(key)
+    }
+
+    async fn get_by_fingerprint(&self, fingerprint: &str) -> Result<Option<SshKey>, DatabaseError> {
+        let key = sqlx::query_as::<_, SshKey>(
+            r#"
+            SELECT id, user_id, name, key_type, public_key, fingerprint, created_at,
+                   last_used_at
+            FROM auth.ssh_keys
+            WHERE fingerprint = $1
+            "#,
+        )
+        .bind(fingerprint)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(key)
+    }
+
+    async fn touch(&self, id: Uuid) -> Result<(), DatabaseError> {
+        sqlx::query("UPDATE auth.ssh_keys SET last_used_at = NOW() WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<bool, DatabaseError> {
+        let result = sqlx::query("DELETE FROM auth.ssh_keys WHERE id = $1 AND user_id = $2")
+            .bind(id)
+            .bind(user_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+#[cfg(all(test, feature = "db-tests"))]
+mod tests {
+    use sqlx::PgPool;
+    use uuid::Uuid;
+
+    use super::{PgSshKeyRepository, SshKeyRepository};
+    use crate::repository::test_common::insert_user;
+
+    #[sqlx::test]
+    async fn keys_are_unique_and_scoped_to_their_owner(pool: PgPool) {
+        let repo = PgSshKeyRepository::new(pool.clone());
+        let alice = Uuid::new_v4();
+        let bob = Uuid::new_v4();
+        insert_user(&pool, alice, "alice").await;
+        insert_user(&pool, bob, "bob").await;
+
+        let key = repo
+            .create(alice, "laptop", "ssh-ed25519", "AAAA", "SHA256:abc")
+            .await
+            .unwrap();
+        assert!(key.last_used_at.is_none());
+
+        // The same key cannot be registered twice, even by another user.
+        let err = repo
+            .create(bob, "laptop", "ssh-ed25519", "AAAA", "SHA256:abc")
+            .await
+            .unwrap_err();
+        assert!(err.is_unique_violation());
+
+        let found = repo
+            .get_by_fingerprint("SHA256:abc")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(found.user_id, alice);
+
+        repo.touch(key.id).await.unwrap();
+        assert!(
+            repo.get(key.id)
+                .await
+                .unwrap()
+                .unwrap()
+                .last_used_at
+                .is_some()
+        );
+
+        assert!(!repo.delete(bob, key.id).await.unwrap());
+        assert_eq!(repo.list_by_user(alice).await.unwrap().len(), 1);
+        assert!(repo.delete(alice, key.id).await.unwrap());
+        assert!(repo.list_by_user(alice).await.unwrap().is_empty());
+    }
+}
@@ -1,10 +1,11 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use sqlx::PgPool;
 use uuid::Uuid;
 
 use crate::{
     error::DatabaseError,
-    model::{AccessToken, TokenType},
+    model::{AccessToken, TokenScope, TokenType},
 };
 
 /// sqlx data-access layer for the `auth.tokens` table, which stores hashed
@@ -21,6 +22,18 @@ pub trait TokenRepository: Send + Sync + Clone + 'static {
         token_type: TokenType,
     ) -> Result<AccessToken, DatabaseError>;
 
+    /// Inserts a personal token with a display `name`, `scopes`, and an
+    /// optional expiry, and returns the created row.
+    async fn create_personal_token(
+        &self,
+        principal_id: Uuid,
+        client_id: &str,
+        token_hash: &str,
+        name: &str,
+        scopes: &[TokenScope],
+        expires_at: Option<DateTime<Utc>>,
+    ) -> Result<AccessToken, DatabaseError>;
+
     /// Returns the token matching `token_hash`, or `Ok(None)` if none exists.
     async fn get_token_by_hash(
         &self,
@@ -31,10 +44,24 @@ pub trait TokenRepository: Send + Sync + Clone + 'static {
     /// still `Ok`) if no row matches.
     async fn touch_token(&self, id: Uuid) -> Result<(), DatabaseError>;
 
+    /// Lists the personal tokens of `principal_id`, newest first.
+    async fn list_personal_tokens(
+        &self,
+        principal_id: Uuid,
+    ) -> Result<Vec<AccessToken>, DatabaseError>;
+
     /// Hard-deletes the token with the given id. No-op (and still `Ok`) if no
     /// row matches.
     async fn delete_token(&self, id: Uuid) -> Result<(), DatabaseError>;
 
+    /// Hard-deletes the personal token `id` if it belongs to `principal_id`.
+    /// Returns whether a row was deleted.
+    async fn delete_personal_token(
+        &self,
+        principal_id: Uuid,
+        id: Uuid,
+    ) -> Result<bool, DatabaseError>;
+
     /// Hard-deletes every token belonging to `principal_id`.
     async fn delete_token_by_principal(&self, principal_id: Uuid) -> Result<(), DatabaseError>;
 }
@@ -64,7 +91,8 @@ impl TokenRepository for PgTokenRepository {
             r#"
             INSERT INTO auth.tokens (principal_id, client_id, token_hash, token_type)
             VALUES ($1, $2, $3, $4)
-            RETURNING id, principal_id, client_id, token_hash, token_type, created_at, last_used_at
+            RETURNING id, principal_id, client_id, token_hash, token_type, name, scopes, expires_at,
+                created_at, last_used_at
             "#,
         )
         .bind(principal_id)
@@ -77,13 +105,43 @@ impl TokenRepository for PgTokenRepository {
         Ok(token)
     }
 
+    async fn create_personal_token(
+        &self,
+        principal_id: Uuid,
+        client_id: &str,
+        token_hash: &str,
+        name: &str,
+        scopes: &[TokenScope],
+        expires_at: Option<DateTime<Utc>>,
+    ) -> Result<AccessToken, DatabaseError> {
+        let token = sqlx::query_as::<_, AccessToken>(
+            r#"
+            INSERT INTO auth.tokens (principal_id, client_id, token_hash, token_type, name, scopes, expires_at)
+            VALUES ($1, $2, $3, 'personal', $4, $5, $6)
+            RETURNING id, principal_id, client_id, token_hash, token_type, name, scopes, expires_at,
+                created_at, last_used_at
+            "#,
+        )
+        .bind(principal_id)
+        .bind(client_id)
+        .bind(token_hash)
+        .bind(name)
+        .bind(scopes)
+        .bind(expires_at)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(token)
+    }
+
     async fn get_token_by_hash(
         &self,
         token_hash: &str,
     ) -> Result<Option<AccessToken>, DatabaseError> {
         let token = sqlx::query_as::<_, AccessToken>(
             r#"
-            SELECT id, principal_id, client_id, token_hash, token_type, created_at, last_used_at
+            SELECT id, principal_id, client_id, token_hash, token_type, name, scopes, expires_at,
+                created_at, last_used_at
             FROM auth.tokens
             WHERE token_hash = $1
             "#,
@@ -104,6 +162,26 @@ impl TokenRepository for PgTokenRepository {
         Ok(())
     }
 
+    async fn list_personal_tokens(
+        &self,
+        principal_id: Uuid,
+    ) -> Result<Vec<AccessToken>, DatabaseError> {
+        let tokens = sqlx::query_as::<_, AccessToken>(
+            r#"
+            SELECT id, principal_id, client_id, token_hash, token_type, name, scopes, expires_at,
+                created_at, last_used_at
+            FROM auth.tokens
+            WHERE principal_id = $1 AND token_type = 'personal'
+            ORDER BY created_at DESC
+            "#,
+        )
+        .bind(principal_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(tokens)
+    }
+
     async fn delete_token(&self, id: Uuid) -> Result<(), DatabaseError> {
         sqlx::query("DELETE FROM auth.tokens WHERE id = $1")
             .bind(id)
@@ -113,6 +191,22 @@ impl TokenRepository for PgTokenRepository {
         Ok(())
     }
 
+    async fn delete_personal_token(
+        &self,
+        principal_id: Uuid,
+        id: Uuid,
+    ) -> Result<bool, DatabaseError> {
+        let result = sqlx::query(
+            "DELETE FROM auth.tokens WHERE id = $1 AND principal_id = $2 AND token_type = 'personal'",
+        )
+        .bind(id)
+        .bind(principal_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
     async fn delete_token_by_principal(&self, principal_id: Uuid) -> Result<(), DatabaseError> {
         sqlx::query("DELETE FROM auth.tokens WHERE principal_id = $1")
             .bind(principal_id)
@@ -128,7 +222,7 @@ mod tests {
     use sqlx::PgPool;
     use uuid::Uuid;
 
-    use super::{PgTokenRepository, TokenRepository, TokenType};
+    use super::{PgTokenRepository, TokenRepository, TokenScope, TokenType};
 
     #[sqlx::test]
     async fn create_and_get_token(pool: PgPool) {
@@ -208,4 +302,46 @@ mod tests {
         // Another principal's token is untouched.
         assert!(repo.get_token_by_hash("h3").await.unwrap().is_some());
     }
+
+    #[sqlx::test]
+    async fn personal_tokens_are_listed_and_deleted_per_principal(pool: PgPool) {
+        let repo = PgTokenRepository::new(pool.clone());
+        let principal = Uuid::new_v4();
+        let other = Uuid::new_v4();
+
+        let token = repo
+            .create_personal_token(
+                principal,
+                "web",
+                "h1",
+                "deploy",
+                &[TokenScope::RepoRead, TokenScope::ReviewWrite],
+                None,
+            )
+            .await
+            .unwrap();
+        assert_eq!(token.token_type, TokenType::Personal);
+        assert_eq!(token.name, "deploy");
+        assert_eq!(
+            token.scopes,
+            vec![TokenScope::RepoRead, TokenScope::ReviewWrite]
+        );
+        repo.create_token(principal, "runner", "h2", TokenType::Runner)
+            .await
+            .unwrap();
+
+        // Runner tokens are not listed.
+        let listed = repo.list_personal_tokens(principal).await.unwrap();
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].id, token.id);
+
+        // Another principal cannot delete the token.
+        assert!(!repo.delete_personal_token(other, token.id).await.unwrap());
+        assert!(
+            repo.delete_personal_token(principal, token.id)
+                .await
+                .unwrap()
+        );
+        assert!(repo.get_token_by_hash("h1").await.unwrap().is_none());
+    }
 }
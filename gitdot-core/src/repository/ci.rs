@@ -1,7 +1,13 @@
+mod artifact;
 mod build;
+mod cache;
 mod runner;
+mod secret;
 mod task;
 
+pub use artifact::{ArtifactRepository, PgArtifactRepository};
 pub use build::{BuildRepository, PgBuildRepository};
+pub use cache::{CacheRepository, PgCacheRepository};
 pub use runner::{PgRunnerRepository, RunnerRepository};
+pub use secret::{CiSecretRepository, PgCiSecretRepository};
 pub use task::{PgTaskRepository, TaskRepository};
@@ -0,0 +1,151 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{error::DatabaseError, model::Artifact};
+
+/// sqlx data-access layer for the `ci.artifacts` table (one row per
+/// `artifacts` path a task uploaded, pointing at its object-storage key).
+#[async_trait]
+pub trait ArtifactRepository: Send + Sync + Clone + 'static {
+    /// Inserts an artifact, or on a `(task_id, name)` conflict (a retried task
+    /// uploading again) replaces its `object_key`, `size_bytes` and
+    /// `created_at`. Returns the row via `RETURNING`.
+    async fn upsert(
+        &self,
+        repository_id: Uuid,
+        build_id: Uuid,
+        task_id: Uuid,
+        name: &str,
+        object_key: &str,
+        size_bytes: i64,
+    ) -> Result<Artifact, DatabaseError>;
+
+    /// Returns the artifact with the given id, or `Ok(None)` if none exists.
+    async fn get_by_id(&self, id: Uuid) -> Result<Option<Artifact>, DatabaseError>;
+
+    /// Returns the artifact a task uploaded under `name`, or `Ok(None)` if
+    /// none exists.
+    async fn get_by_task(
+        &self,
+        task_id: Uuid,
+        name: &str,
+    ) -> Result<Option<Artifact>, DatabaseError>;
+
+    /// Lists all artifacts of a build ordered by `created_at ASC`.
+    async fn list_by_build_id(&self, build_id: Uuid) -> Result<Vec<Artifact>, DatabaseError>;
+
+    /// Lists the artifacts uploaded by any of `task_ids`, ordered by
+    /// `created_at ASC`.
+    async fn list_by_task_ids(&self, task_ids: &[Uuid]) -> Result<Vec<Artifact>, DatabaseError>;
+}
+
+#[derive(Debug, Clone)]
+pub struct PgArtifactRepository {
+    pool: PgPool,
+}
+
+impl PgArtifactRepository {
+    pub fn new(pool: PgPool) -> PgArtifactRepository {
+        PgArtifactRepository { pool }
+    }
+}
+
+#[crate::instrument_all(level = "debug")]
+#[async_trait]
+impl ArtifactRepository for PgArtifactRepository {
+    async fn upsert(
+        &self,
+        repository_id: Uuid,
+        build_id: Uuid,
+        task_id: Uuid,
+        name: &str,
+        object_key: &str,
+        size_bytes: i64,
+    ) -> Result<Artifact, DatabaseError> {
+        let artifact = sqlx::query_as::<_, Artifact>(
+            r#"
+            INSERT INTO ci.artifacts (repository_id, build_id, task_id, name, object_key, size_bytes)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            ON CONFLICT (task_id, name) DO UPDATE
+            SET object_key = EXCLUDED.object_key,
+                size_bytes = EXCLUDED.size_bytes,
+                created_at = NOW()
+            RETURNING id, repository_id, build_id, task_id, name, object_key, size_bytes, created_at
+            "#,
+        )
+        .bind(repository_id)
+        .bind(build_id)
+        .bind(task_id)
+        .bind(name)
+        .bind(object_key)
+        .bind(size_bytes)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(artifact)
+    }
+
+    async fn get_by_id(&self, id: Uuid) -> Result<Option<Artifact>, DatabaseError> {
+        let artifact = sqlx::query_as::<_, Artifact>(
+            r#"
+            SELECT id, repository_id, build_id, task_id, name, object_key, size_bytes, created_at
+            FROM ci.artifacts WHERE id = $1
+            "#,
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(artifact)
+    }
+
+    async fn get_by_task(
+        &self,
+        task_id: Uuid,
+        name: &str,
+    ) -> Result<Option<Artifact>, DatabaseError> {
+        let artifact = sqlx::query_as::<_, Artifact>(
+            r#"
+            SELECT id, repository_id, build_id, task_id, name, object_key, size_bytes, created_at
+            FROM ci.artifacts WHERE task_id = $1 AND name = $2
+            "#,
+        )
+        .bind(task_id)
+        .bind(name)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(artifact)
+    }
+
+    async fn list_by_build_id(&self, build_id: Uuid) -> Result<Vec<Artifact>, DatabaseError> {
+        let artifacts = sqlx::query_as::<_, Artifact>(
+            r#"
+            SELECT id, repository_id, build_id, task_id, name, object_key, size_bytes, created_at
+            FROM ci.artifacts WHERE build_id = $1
+            ORDER BY created_at ASC
+            "#,
+        )
+        .bind(build_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(artifacts)
+    }
+
+    async fn list_by_task_ids(&self, task_ids: &[Uuid]) -> Result<Vec<Artifact>, DatabaseError> {
+        let artifacts = sqlx::query_as::<_, Artifact>(
+            r#"
+            SELECT id, repository_id, build_id, task_id, name, object_key, size_bytes, created_at
+            FROM ci.artifacts WHERE task_id = ANY($1)
+            ORDER BY created_at ASC
+            "#,
+        )
+        .bind(task_ids)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(artifacts)
+    }
+}
@@ -5,7 +5,7 @@ use uuid::Uuid;
 use crate::{
     dto::Cursor,
     error::DatabaseError,
-    model::{Build, BuildTrigger, BuildWithStats},
+    model::{Build, BuildReviewLink, BuildTrigger, BuildWithStats},
 };
 
 /// sqlx data-access layer for the `ci.builds` table (CI builds belonging to a
@@ -14,30 +14,61 @@ use crate::{
 pub trait BuildRepository: Send + Sync + Clone + 'static {
     /// Inserts a build into `ci.builds`, assigning the next per-repository
     /// `number` (`MAX(number) + 1` scoped to `repository_id`, starting at 1).
-    /// Returns the inserted row via `RETURNING`.
+    /// `review_link`, when set, records the review, diff, and revision the
+    /// build was started for. Returns the inserted row via `RETURNING`.
     async fn create(
         &self,
         repository_id: Uuid,
         trigger: BuildTrigger,
         commit_sha: &str,
         ref_name: &str,
+        review_link: Option<BuildReviewLink>,
     ) -> Result<Build, DatabaseError>;
 
     /// Returns the build matching `(repository_id, number)`, or `Ok(None)` if
     /// no such build exists.
     async fn get(&self, repository_id: Uuid, number: i32) -> Result<Option<Build>, DatabaseError>;
 
+    /// Returns the build with `id`, or `Ok(None)` if no such build exists.
+    async fn get_by_id(&self, id: Uuid) -> Result<Option<Build>, DatabaseError>;
+
     /// Lists builds for a repository, newest first (`created_at DESC, id DESC`),
-    /// keyset-paginated by `cursor`. Each row LEFT JOINs `ci.tasks` to derive an
-    /// aggregate `status` (running/failure/success based on task statuses) plus
-    /// `total_tasks`/`completed_tasks` counts. Returns the page and the next
-    /// cursor (`None` when no further rows remain).
+    /// keyset-paginated by `cursor`. When `review_number` is set, only builds
+    /// linked to that review are returned. Each row LEFT JOINs `ci.tasks` to
+    /// derive an aggregate `status` (failure if a task without `allow_failure`
+    /// failed or timed out, else cancelled if a task was cancelled, else
+    /// success once every task finished, else running) plus
+    /// `total_tasks`/`completed_tasks` counts (finished tasks). Returns the page
+    /// and the next cursor (`None` when no further rows remain).
     async fn list_by_repo(
         &self,
         repository_id: Uuid,
+        review_number: Option<i32>,
         cursor: Option<Cursor>,
         limit: i64,
     ) -> Result<(Vec<BuildWithStats>, Option<Cursor>), DatabaseError>;
+
+    /// Returns the most recent build of `commit_sha` in a repository, with the
+    /// same task-derived aggregate `status` and counts as
+    /// [`list_by_repo`](BuildRepository::list_by_repo). `Ok(None)` if the
+    /// commit has never been built.
+    async fn get_latest_by_commit(
+        &self,
+        repository_id: Uuid,
+        commit_sha: &str,
+    ) -> Result<Option<BuildWithStats>, DatabaseError>;
+
+    /// Returns the most recent build linked to review revision `revision_id`,
+    /// or `Ok(None)` if the revision has never been built.
+    async fn get_latest_by_revision(
+        &self,
+        revision_id: Uuid,
+    ) -> Result<Option<Build>, DatabaseError>;
+
+    /// Returns the author of the review a build was started for, or
+    /// `Ok(None)` if the build does not exist or was not started for a
+    /// review.
+    async fn get_review_author_id(&self, build_id: Uuid) -> Result<Option<Uuid>, DatabaseError>;
 }
 
 #[derive(Debug, Clone)]
@@ -60,18 +91,22 @@ impl BuildRepository for PgBuildRepository {
         trigger: BuildTrigger,
         commit_sha: &str,
         ref_name: &str,
+        review_link: Option<BuildReviewLink>,
     ) -> Result<Build, DatabaseError> {
         let build = sqlx::query_as::<_, Build>(
             r#"
-            INSERT INTO ci.builds (repository_id, trigger, commit_sha, ref_name, number)
-            VALUES ($1, $2, $3, $4, COALESCE((SELECT MAX(number) FROM ci.builds WHERE repository_id = $1), 0) + 1)
-            RETURNING id, number, repository_id, ref_name, trigger, commit_sha, status, created_at
+            INSERT INTO ci.builds (repository_id, trigger, commit_sha, ref_name, review_id, diff_id, revision_id, number)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, COALESCE((SELECT MAX(number) FROM ci.builds WHERE repository_id = $1), 0) + 1)
+            RETURNING id, number, repository_id, ref_name, trigger, commit_sha, status, review_id, diff_id, revision_id, created_at
             "#,
         )
         .bind(repository_id)
         .bind(trigger)
         .bind(commit_sha)
         .bind(ref_name)
+        .bind(review_link.map(|link| link.review_id))
+        .bind(review_link.map(|link| link.diff_id))
+        .bind(review_link.map(|link| link.revision_id))
         .fetch_one(&self.pool)
         .await?;
 
@@ -81,7 +116,8 @@ impl BuildRepository for PgBuildRepository {
     async fn get(&self, repository_id: Uuid, number: i32) -> Result<Option<Build>, DatabaseError> {
         let build = sqlx::query_as::<_, Build>(
             r#"
-            SELECT id, number, repository_id, ref_name, trigger, commit_sha, status, created_at
+            SELECT id, number, repository_id, ref_name, trigger, commit_sha, status,
+                   review_id, diff_id, revision_id, created_at
             FROM ci.builds WHERE repository_id = $1 AND number = $2
             "#,
         )
@@ -93,9 +129,25 @@ impl BuildRepository for PgBuildRepository {
         Ok(build)
     }
 
+    async fn get_by_id(&self, id: Uuid) -> Result<Option<Build>, DatabaseError> {
+        let build = sqlx::query_as::<_, Build>(
+            r#"
+            SELECT id, number, repository_id, ref_name, trigger, commit_sha, status,
+                   review_id, diff_id, revision_id, created_at
+            FROM ci.builds WHERE id = $1
+            "#,
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(build)
+    }
+
     async fn list_by_repo(
         &self,
         repository_id: Uuid,
+        review_number: Option<i32>,
         cursor: Option<Cursor>,
         limit: i64,
     ) -> Result<(Vec<BuildWithStats>, Option<Cursor>), DatabaseError> {
@@ -106,21 +158,27 @@ impl BuildRepository for PgBuildRepository {
             r#"
             SELECT
                 b.id, b.number, b.repository_id, b.ref_name, b.trigger, b.commit_sha,
+                b.review_id, b.diff_id, b.revision_id,
                 CASE
                     WHEN COUNT(t.id) = 0 THEN 'running'::ci.build_status
-                    WHEN COUNT(t.id) FILTER (WHERE t.status = 'failure') > 0 THEN 'failure'::ci.build_status
-                    WHEN COUNT(t.id) = COUNT(t.id) FILTER (WHERE t.status = 'success') THEN 'success'::ci.build_status
+                    WHEN COUNT(t.id) FILTER (WHERE t.status IN ('failure', 'timed_out') AND NOT t.allow_failure) > 0 THEN 'failure'::ci.build_status
+                    WHEN COUNT(t.id) FILTER (WHERE t.status = 'cancelled') > 0 THEN 'cancelled'::ci.build_status
+                    WHEN COUNT(t.id) = COUNT(t.id) FILTER (WHERE t.status IN ('success', 'failure', 'timed_out')) THEN 'success'::ci.build_status
                     ELSE 'running'::ci.build_status
                 END AS status,
                 CAST(COUNT(t.id) AS INT) AS total_tasks,
-                CAST(COUNT(t.id) FILTER (WHERE t.status = 'success') AS INT) AS completed_tasks,
+                CAST(COUNT(t.id) FILTER (WHERE t.status IN ('success', 'failure', 'cancelled', 'timed_out')) AS INT) AS completed_tasks,
                 b.created_at,
                 COALESCE(MAX(t.updated_at), b.created_at) AS updated_at
             FROM ci.builds b
             LEFT JOIN ci.tasks t ON t.build_id = b.id
             WHERE b.repository_id = $1
               AND ($2::timestamptz IS NULL OR (b.created_at, b.id) < ($2, $3))
-            GROUP BY b.id, b.number, b.repository_id, b.ref_name, b.trigger, b.commit_sha, b.created_at
+              AND ($5::int IS NULL OR b.review_id = (
+                  SELECT r.id FROM core.reviews r WHERE r.repository_id = $1 AND r.number = $5
+              ))
+            GROUP BY b.id, b.number, b.repository_id, b.ref_name, b.trigger, b.commit_sha,
+                     b.review_id, b.diff_id, b.revision_id, b.created_at
             ORDER BY b.created_at DESC, b.id DESC
             LIMIT $4
             "#,
@@ -129,6 +187,7 @@ impl BuildRepository for PgBuildRepository {
         .bind(cursor_created_at)
         .bind(cursor_id)
         .bind(limit + 1)
+        .bind(review_number)
         .fetch_all(&self.pool)
         .await?;
 
@@ -144,4 +203,78 @@ impl BuildRepository for PgBuildRepository {
 
         Ok((builds, next_cursor))
     }
+
+    async fn get_latest_by_commit(
+        &self,
+        repository_id: Uuid,
+        commit_sha: &str,
+    ) -> Result<Option<BuildWithStats>, DatabaseError> {
+        let build = sqlx::query_as::<_, BuildWithStats>(
+            r#"
+            SELECT
+                b.id, b.number, b.repository_id, b.ref_name, b.trigger, b.commit_sha,
+                b.review_id, b.diff_id, b.revision_id,
+                CASE
+                    WHEN COUNT(t.id) = 0 THEN 'running'::ci.build_status
+                    WHEN COUNT(t.id) FILTER (WHERE t.status IN ('failure', 'timed_out') AND NOT t.allow_failure) > 0 THEN 'failure'::ci.build_status
+                    WHEN COUNT(t.id) FILTER (WHERE t.status = 'cancelled') > 0 THEN 'cancelled'::ci.build_status
+                    WHEN COUNT(t.id) = COUNT(t.id) FILTER (WHERE t.status IN ('success', 'failure', 'timed_out')) THEN 'success'::ci.build_status
+                    ELSE 'running'::ci.build_status
+                END AS status,
+                CAST(COUNT(t.id) AS INT) AS total_tasks,
+                CAST(COUNT(t.id) FILTER (WHERE t.status IN ('success', 'failure', 'cancelled', 'timed_out')) AS INT) AS completed_tasks,
+                b.created_at,
+                COALESCE(MAX(t.updated_at), b.created_at) AS updated_at
+            FROM ci.builds b
+            LEFT JOIN ci.tasks t ON t.build_id = b.id
+            WHERE b.repository_id = $1 AND b.commit_sha = $2
+            GROUP BY b.id, b.number, b.repository_id, b.ref_name, b.trigger, b.commit_sha,
+                     b.review_id, b.diff_id, b.revision_id, b.created_at
+            ORDER BY b.created_at DESC, b.id DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(repository_id)
+        .bind(commit_sha)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(build)
+    }
+
+    async fn get_latest_by_revision(
+        &self,
+        revision_id: Uuid,
+    ) -> Result<Option<Build>, DatabaseError> {
+        let build = sqlx::query_as::<_, Build>(
+            r#"
+            SELECT id, number, repository_id, ref_name, trigger, commit_sha, status,
+                   review_id, diff_id, revision_id, created_at
+            FROM ci.builds WHERE revision_id = $1
+            ORDER BY created_at DESC, id DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(revision_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(build)
+    }
+
+    async fn get_review_author_id(&self, build_id: Uuid) -> Result<Option<Uuid>, DatabaseError> {
+        let author_id = sqlx::query_scalar::<_, Uuid>(
+            r#"
+            SELECT r.author_id
+            FROM ci.builds b
+            JOIN core.reviews r ON r.id = b.review_id
+            WHERE b.id = $1
+            "#,
+        )
+        .bind(build_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(author_id)
+    }
 }
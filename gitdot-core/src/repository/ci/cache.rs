@@ -0,0 +1,97 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{error::DatabaseError, model::Cache};
+
+/// sqlx data-access layer for the `ci.caches` table (the latest object saved
+/// under each `(repository_id, scope, key)` task cache key).
+#[async_trait]
+pub trait CacheRepository: Send + Sync + Clone + 'static {
+    /// Returns the cache saved under `key` in `scope` for a repository, or
+    /// `Ok(None)` if none exists.
+    async fn get(
+        &self,
+        repository_id: Uuid,
+        scope: &str,
+        key: &str,
+    ) -> Result<Option<Cache>, DatabaseError>;
+
+    /// Inserts a cache entry, or on a `(repository_id, scope, key)` conflict
+    /// replaces its `object_key`, `size_bytes` and `updated_at`. Returns the
+    /// row via `RETURNING`.
+    async fn upsert(
+        &self,
+        repository_id: Uuid,
+        scope: &str,
+        key: &str,
+        object_key: &str,
+        size_bytes: i64,
+    ) -> Result<Cache, DatabaseError>;
+}
+
+#[derive(Debug, Clone)]
+pub struct PgCacheRepository {
+    pool: PgPool,
+}
+
+impl PgCacheRepository {
+    pub fn new(pool: PgPool) -> PgCacheRepository {
+        PgCacheRepository { pool }
+    }
+}
+
+#[crate::instrument_all(level = "debug")]
+#[async_trait]
+impl CacheRepository for PgCacheRepository {
+    async fn get(
+        &self,
+        repository_id: Uuid,
+        scope: &str,
+        key: &str,
+    ) -> Result<Option<Cache>, DatabaseError> {
+        let cache = sqlx::query_as::<_, Cache>(
+            r#"
+            SELECT repository_id, scope, key, object_key, size_bytes, updated_at
+            FROM ci.caches WHERE repository_id = $1 AND scope = $2 AND key = $3
+            "#,
+        )
+        .bind(repository_id)
+        .bind(scope)
+        .bind(key)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(cache)
+    }
+
+    async fn upsert(
+        &self,
+        repository_id: Uuid,
+        scope: &str,
+        key: &str,
+        object_key: &str,
+        size_bytes: i64,
+    ) -> Result<Cache, DatabaseError> {
+        let cache = sqlx::query_as::<_, Cache>(
+            r#"
+            INSERT INTO ci.caches (repository_id, scope, key, object_key, size_bytes)
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (repository_id, scope, key) DO UPDATE
+            SET object_key = EXCLUDED.object_key,
+                size_bytes = EXCLUDED.size_bytes,
+                updated_at = NOW()
+            RETURNING repository_id, scope, key, object_key, size_bytes, updated_at
+            "#,
+        )
+        .bind(repository_id)
+        .bind(scope)
+        .bind(key)
+        .bind(object_key)
+        .bind(size_bytes)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(cache)
+    }
+}
@@ -0,0 +1,231 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{error::DatabaseError, model::CiSecret};
+
+/// sqlx data-access layer for the `ci.repository_secrets` and
+/// `ci.organization_secrets` tables. Values are stored as ciphertext; this
+/// layer never sees them in the clear.
+#[async_trait]
+pub trait CiSecretRepository: Send + Sync + Clone + 'static {
+    /// Lists a repository's secrets ordered by name.
+    async fn list_repository_secrets(
+        &self,
+        repository_id: Uuid,
+    ) -> Result<Vec<CiSecret>, DatabaseError>;
+
+    /// Inserts a repository secret, or on a `(repository_id, name)` conflict
+    /// replaces its `ciphertext` and `updated_at`. Returns the row via
+    /// `RETURNING`.
+    async fn upsert_repository_secret(
+        &self,
+        repository_id: Uuid,
+        name: &str,
+        ciphertext: &[u8],
+    ) -> Result<CiSecret, DatabaseError>;
+
+    /// Deletes a repository secret. Returns `false` if it did not exist.
+    async fn delete_repository_secret(
+        &self,
+        repository_id: Uuid,
+        name: &str,
+    ) -> Result<bool, DatabaseError>;
+
+    /// Lists an organization's secrets ordered by name.
+    async fn list_organization_secrets(
+        &self,
+        organization_id: Uuid,
+    ) -> Result<Vec<CiSecret>, DatabaseError>;
+
+    /// Inserts an organization secret, or on an `(organization_id, name)`
+    /// conflict replaces its `ciphertext` and `updated_at`. Returns the row
+    /// via `RETURNING`.
+    async fn upsert_organization_secret(
+        &self,
+        organization_id: Uuid,
+        name: &str,
+        ciphertext: &[u8],
+    ) -> Result<CiSecret, DatabaseError>;
+
+    /// Deletes an organization secret. Returns `false` if it did not exist.
+    async fn delete_organization_secret(
+        &self,
+        organization_id: Uuid,
+        name: &str,
+    ) -> Result<bool, DatabaseError>;
+
+    /// Returns the secrets among `names` visible to a repository: its own,
+    /// plus those of `organization_id` when it is owned by an organization. A
+    /// repository secret shadows an organization secret of the same name.
+    async fn list_by_names(
+        &self,
+        repository_id: Uuid,
+        organization_id: Option<Uuid>,
+        names: &[String],
+    ) -> Result<Vec<CiSecret>, DatabaseError>;
+}
+
+#[derive(Debug, Clone)]
+pub struct PgCiSecretRepository {
+    pool: PgPool,
+}
+
+impl PgCiSecretRepository {
+    pub fn new(pool: PgPool) -> PgCiSecretRepository {
+        PgCiSecretRepository { pool }
+    }
+}
+
+#[crate::instrument_all(level = "debug")]
+#[async_trait]
+impl CiSecretRepository for PgCiSecretRepository {
+    async fn list_repository_secrets(
+        &self,
+        repository_id: Uuid,
+    ) -> Result<Vec<CiSecret>, DatabaseError> {
+        let secrets = sqlx::query_as::<_, CiSecret>(
+            r#"
+            SELECT repository_id AS owner_id, name, ciphertext, created_at, updated_at
+            FROM ci.repository_secrets WHERE repository_id = $1
+            ORDER BY name
+            "#,
+        )
+        .bind(repository_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(secrets)
+    }
+
+    async fn upsert_repository_secret(
+        &self,
+        repository_id: Uuid,
+        name: &str,
+        ciphertext: &[u8],
+    ) -> Result<CiSecret, DatabaseError> {
+        let secret = sqlx::query_as::<_, CiSecret>(
+            r#"
+            INSERT INTO ci.repository_secrets (repository_id, name, ciphertext)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (repository_id, name) DO UPDATE
+            SET ciphertext = EXCLUDED.ciphertext,
+                updated_at = NOW()
+            RETURNING repository_id AS owner_id, name, ciphertext, created_at, updated_at
+            "#,
+        )
+        .bind(repository_id)
+        .bind(name)
+        .bind(ciphertext)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(secret)
+    }
+
+    async fn delete_repository_secret(
+        &self,
+        repository_id: Uuid,
+        name: &str,
+    ) -> Result<bool, DatabaseError> {
+        let result =
+            sqlx::query("DELETE FROM ci.repository_secrets WHERE repository_id = $1 AND name = $2")
+                .bind(repository_id)
+                .bind(name)
+                .execute(&self.pool)
+                .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn list_organization_secrets(
+        &self,
+        organization_id: Uuid,
+    ) -> Result<Vec<CiSecret>, DatabaseError> {
+        let secrets = sqlx::query_as::<_, CiSecret>(
+            r#"
+            SELECT organization_id AS owner_id, name, ciphertext, created_at, updated_at
+            FROM ci.organization_secrets WHERE organization_id = $1
+            ORDER BY name
+            "#,
+        )
+        .bind(organization_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(secrets)
+    }
+
+    async fn upsert_organization_secret(
+        &self,
+        organization_id: Uuid,
+        name: &str,
+        ciphertext: &[u8],
+    ) -> Result<CiSecret, DatabaseError> {
+        let secret = sqlx::query_as::<_, CiSecret>(
+            r#"
+            INSERT INTO ci.organization_secrets (organization_id, name, ciphertext)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (organization_id, name) DO UPDATE
+            SET ciphertext = EXCLUDED.ciphertext,
+                updated_at = NOW()
+            RETURNING organization_id AS owner_id, name, ciphertext, created_at, updated_at
+            "#,
+        )
+        .bind(organization_id)
+        .bind(name)
+        .bind(ciphertext)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(secret)
+    }
+
+    async fn delete_organization_secret(
+        &self,
+        organization_id: Uuid,
+        name: &str,
+    ) -> Result<bool, DatabaseError> {
+        let result = sqlx::query(
+            "DELETE FROM ci.organization_secrets WHERE organization_id = $1 AND name = $2",
+        )
+        .bind(organization_id)
+        .bind(name)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn list_by_names(
+        &self,
+        repository_id: Uuid,
+        organization_id: Option<Uuid>,
+        names: &[String],
+    ) -> Result<Vec<CiSecret>, DatabaseError> {
+        let secrets = sqlx::query_as::<_, CiSecret>(
+            r#"
+            SELECT DISTINCT ON (name) owner_id, name, ciphertext, created_at, updated_at
+            FROM (
+                SELECT repository_id AS owner_id, name, ciphertext, created_at, updated_at,
+                    0 AS precedence
+                FROM ci.repository_secrets
+                WHERE repository_id = $1 AND name = ANY($3)
+                UNION ALL
+                SELECT organization_id AS owner_id, name, ciphertext, created_at, updated_at,
+                    1 AS precedence
+                FROM ci.organization_secrets
+                WHERE organization_id = $2 AND name = ANY($3)
+            ) secrets
+            ORDER BY name, precedence
+            "#,
+        )
+        .bind(repository_id)
+        .bind(organization_id)
+        .bind(names)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(secrets)
+    }
+}
@@ -1,18 +1,21 @@
+use std::time::Duration;
+
 use async_trait::async_trait;
 use sqlx::PgPool;
 use uuid::Uuid;
 
 use crate::{
     error::DatabaseError,
-    model::{Task, TaskStatus},
+    model::{Task, TaskOptions, TaskStatus},
 };
 
 /// sqlx data-access layer for the `ci.tasks` table (individual CI tasks within a
 /// build, with status, dependency (`waits_for`) and runner-assignment columns).
 #[async_trait]
 pub trait TaskRepository: Send + Sync + Clone + 'static {
-    /// Inserts a task into `ci.tasks` with the caller-supplied `id`, `status` and
-    /// `waits_for` dependency array. Returns the inserted row via `RETURNING`.
+    /// Inserts a task into `ci.tasks` with the caller-supplied `id`, `status`,
+    /// `waits_for` dependency array and execution `options`. Returns the
+    /// inserted row via `RETURNING`.
     async fn create(
         &self,
         id: Uuid,
@@ -23,6 +26,7 @@ pub trait TaskRepository: Send + Sync + Clone + 'static {
         s2_uri: &str,
         status: TaskStatus,
         waits_for: &[Uuid],
+        options: &TaskOptions,
     ) -> Result<Task, DatabaseError>;
 
     /// Returns the task with the given id, or `Ok(None)` if none exists.
@@ -48,10 +52,33 @@ pub trait TaskRepository: Send + Sync + Clone + 'static {
     ) -> Result<Option<Task>, DatabaseError>;
 
     /// Transitions a build's `blocked` tasks to `pending` once all of their
-    /// `waits_for` dependencies have `status = 'success'` (the `NOT EXISTS`
-    /// guard against any non-success dependency). Returns the rows that were
+    /// `waits_for` dependencies have `status = 'success'`, or failed or timed
+    /// out with `allow_failure` set (the `NOT EXISTS` guard against any other
+    /// dependency). Returns the rows that were
     /// unblocked (empty `Vec` when none became eligible).
     async fn unblock_tasks(&self, build_id: Uuid) -> Result<Vec<Task>, DatabaseError>;
+
+    /// Sets every unfinished (`blocked`, `pending`, `assigned`, `running`) task
+    /// of a build to `cancelled`. Returns the cancelled rows.
+    async fn cancel_build_tasks(&self, build_id: Uuid) -> Result<Vec<Task>, DatabaseError>;
+
+    /// Requeues a `failure`, `cancelled`, or `timed_out` task for another run:
+    /// `pending` when its `waits_for` dependencies are satisfied (as in
+    /// [`unblock_tasks`](TaskRepository::unblock_tasks)), otherwise `blocked`,
+    /// with `runner_id` cleared and `attempt` incremented. The new attempt's
+    /// logs are appended to the same S2 stream. Returns `Ok(None)` when the
+    /// task is not in a retryable status.
+    async fn retry_task(&self, id: Uuid) -> Result<Option<Task>, DatabaseError>;
+
+    /// Sets `assigned` and `running` tasks to `timed_out` when their runner
+    /// has not been active within `heartbeat_timeout`, or when they have been
+    /// in their current status longer than `timeout_seconds` for every attempt
+    /// (`retries + 1`) plus `heartbeat_timeout` of slack. Returns the
+    /// timed-out rows.
+    async fn time_out_stale_tasks(
+        &self,
+        heartbeat_timeout: Duration,
+    ) -> Result<Vec<Task>, DatabaseError>;
 }
 
 #[derive(Debug, Clone)]
@@ -78,12 +105,19 @@ impl TaskRepository for PgTaskRepository {
         s2_uri: &str,
         status: TaskStatus,
         waits_for: &[Uuid],
+        options: &TaskOptions,
     ) -> Result<Task, DatabaseError> {
         let task = sqlx::query_as::<_, Task>(
             r#"
-            INSERT INTO ci.tasks (id, repository_id, name, command, build_id, s2_uri, status, waits_for)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
-            RETURNING id, repository_id, build_id, s2_uri, name, command, status, waits_for, runner_id, created_at, updated_at
+            INSERT INTO ci.tasks (
+                id, repository_id, name, command, build_id, s2_uri, status, waits_for,
+                env, timeout_seconds, retries, working_directory, allow_failure,
+                artifacts, cache_key, cache_paths, image, network, secrets
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19)
+            RETURNING id, repository_id, build_id, s2_uri, name, command, status, waits_for, runner_id, attempt,
+                      env, timeout_seconds, retries, working_directory, allow_failure,
+                   artifacts, cache_key, cache_paths, image, network, secrets, created_at, updated_at
             "#,
         )
         .bind(id)
@@ -94,6 +128,17 @@ impl TaskRepository for PgTaskRepository {
         .bind(s2_uri)
         .bind(status)
         .bind(waits_for)
+        .bind(sqlx::types::Json(&options.env))
+        .bind(options.timeout_seconds)
+        .bind(options.retries)
+        .bind(&options.working_directory)
+        .bind(options.allow_failure)
+        .bind(&options.artifacts)
+        .bind(&options.cache_key)
+        .bind(&options.cache_paths)
+        .bind(&options.image)
+        .bind(options.network)
+        .bind(&options.secrets)
         .fetch_one(&self.pool)
         .await?;
 
@@ -103,7 +148,9 @@ impl TaskRepository for PgTaskRepository {
     async fn get_by_id(&self, id: Uuid) -> Result<Option<Task>, DatabaseError> {
         let task = sqlx::query_as::<_, Task>(
             r#"
-            SELECT id, repository_id, build_id, s2_uri, name, command, status, waits_for, runner_id, created_at, updated_at
+            SELECT id, repository_id, build_id, s2_uri, name, command, status, waits_for, runner_id, attempt,
+                   env, timeout_seconds, retries, working_directory, allow_failure,
+                   artifacts, cache_key, cache_paths, image, network, secrets, created_at, updated_at
             FROM ci.tasks WHERE id = $1
             "#,
         )
@@ -117,7 +164,9 @@ impl TaskRepository for PgTaskRepository {
     async fn list_by_build_id(&self, build_id: Uuid) -> Result<Vec<Task>, DatabaseError> {
         let tasks = sqlx::query_as::<_, Task>(
             r#"
-            SELECT id, repository_id, build_id, s2_uri, name, command, status, waits_for, runner_id, created_at, updated_at
+            SELECT id, repository_id, build_id, s2_uri, name, command, status, waits_for, runner_id, attempt,
+                   env, timeout_seconds, retries, working_directory, allow_failure,
+                   artifacts, cache_key, cache_paths, image, network, secrets, created_at, updated_at
             FROM ci.tasks WHERE build_id = $1
             ORDER BY created_at ASC
             "#,
@@ -134,7 +183,9 @@ impl TaskRepository for PgTaskRepository {
             r#"
             UPDATE ci.tasks SET status = $1, updated_at = NOW()
             WHERE id = $2
-            RETURNING id, repository_id, build_id, s2_uri, name, command, status, waits_for, runner_id, created_at, updated_at
+            RETURNING id, repository_id, build_id, s2_uri, name, command, status, waits_for, runner_id, attempt,
+                      env, timeout_seconds, retries, working_directory, allow_failure,
+                   artifacts, cache_key, cache_paths, image, network, secrets, created_at, updated_at
             "#,
         )
         .bind(status)
@@ -161,7 +212,9 @@ impl TaskRepository for PgTaskRepository {
                 LIMIT 1
                 FOR UPDATE SKIP LOCKED
             )
-            RETURNING id, repository_id, build_id, s2_uri, name, command, status, waits_for, runner_id, created_at, updated_at
+            RETURNING id, repository_id, build_id, s2_uri, name, command, status, waits_for, runner_id, attempt,
+                      env, timeout_seconds, retries, working_directory, allow_failure,
+                   artifacts, cache_key, cache_paths, image, network, secrets, created_at, updated_at
             "#,
         )
         .bind(runner_id)
@@ -183,8 +236,11 @@ impl TaskRepository for PgTaskRepository {
                 SELECT 1 FROM unnest(waits_for) AS dep_id
                 JOIN ci.tasks t2 ON t2.id = dep_id
                 WHERE t2.status != 'success'
+                  AND NOT (t2.status IN ('failure', 'timed_out') AND t2.allow_failure)
               )
-            RETURNING id, repository_id, build_id, s2_uri, name, command, status, waits_for, runner_id, created_at, updated_at
+            RETURNING id, repository_id, build_id, s2_uri, name, command, status, waits_for, runner_id, attempt,
+                      env, timeout_seconds, retries, working_directory, allow_failure,
+                   artifacts, cache_key, cache_paths, image, network, secrets, created_at, updated_at
             "#,
         )
         .bind(build_id)
@@ -193,4 +249,87 @@ impl TaskRepository for PgTaskRepository {
 
         Ok(tasks)
     }
+
+    async fn cancel_build_tasks(&self, build_id: Uuid) -> Result<Vec<Task>, DatabaseError> {
+        let tasks = sqlx::query_as::<_, Task>(
+            r#"
+            UPDATE ci.tasks
+            SET status = 'cancelled', updated_at = NOW()
+            WHERE build_id = $1
+              AND status IN ('blocked', 'pending', 'assigned', 'running')
+            RETURNING id, repository_id, build_id, s2_uri, name, command, status, waits_for, runner_id, attempt,
+                      env, timeout_seconds, retries, working_directory, allow_failure,
+                   artifacts, cache_key, cache_paths, image, network, secrets, created_at, updated_at
+            "#,
+        )
+        .bind(build_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(tasks)
+    }
+
+    async fn retry_task(&self, id: Uuid) -> Result<Option<Task>, DatabaseError> {
+        let task = sqlx::query_as::<_, Task>(
+            r#"
+            UPDATE ci.tasks t
+            SET status = CASE
+                    WHEN EXISTS (
+                        SELECT 1 FROM unnest(t.waits_for) AS dep_id
+                        JOIN ci.tasks t2 ON t2.id = dep_id
+                        WHERE t2.status != 'success'
+                          AND NOT (t2.status IN ('failure', 'timed_out') AND t2.allow_failure)
+                    ) THEN 'blocked'::ci.task_status
+                    ELSE 'pending'::ci.task_status
+                END,
+                runner_id = NULL,
+                attempt = t.attempt + 1,
+                updated_at = NOW()
+            WHERE t.id = $1
+              AND t.status IN ('failure', 'cancelled', 'timed_out')
+            RETURNING t.id, t.repository_id, t.build_id, t.s2_uri, t.name, t.command, t.status, t.waits_for,
+                      t.runner_id, t.attempt, t.env, t.timeout_seconds, t.retries, t.working_directory,
+                      t.allow_failure, t.artifacts, t.cache_key, t.cache_paths, t.image, t.network, t.secrets, t.created_at,
+                      t.updated_at
+            "#,
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(task)
+    }
+
+    async fn time_out_stale_tasks(
+        &self,
+        heartbeat_timeout: Duration,
+    ) -> Result<Vec<Task>, DatabaseError> {
+        let tasks = sqlx::query_as::<_, Task>(
+            r#"
+            UPDATE ci.tasks t
+            SET status = 'timed_out', updated_at = NOW()
+            WHERE t.status IN ('assigned', 'running')
+              AND (
+                NOT EXISTS (
+                    SELECT 1 FROM ci.runners r
+                    WHERE r.id = t.runner_id
+                      AND r.last_active > NOW() - make_interval(secs => $1)
+                )
+                OR (
+                    t.timeout_seconds IS NOT NULL
+                    AND t.updated_at < NOW() - make_interval(secs => t.timeout_seconds * (t.retries + 1) + $1)
+                )
+              )
+            RETURNING t.id, t.repository_id, t.build_id, t.s2_uri, t.name, t.command, t.status, t.waits_for,
+                      t.runner_id, t.attempt, t.env, t.timeout_seconds, t.retries, t.working_directory,
+                      t.allow_failure, t.artifacts, t.cache_key, t.cache_paths, t.image, t.network, t.secrets, t.created_at,
+                      t.updated_at
+            "#,
+        )
+        .bind(heartbeat_timeout.as_secs_f64())
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(tasks)
+    }
 }
@@ -1,13 +1,25 @@
+mod branch_protection;
+mod collaborator;
 mod commit;
+mod maintenance;
+mod mirror;
 mod organization;
 mod question;
+mod release;
 mod repository;
 mod review;
+mod team;
 mod user;
 
+pub use branch_protection::{BranchProtectionRepository, PgBranchProtectionRepository};
+pub use collaborator::{CollaboratorRepository, PgCollaboratorRepository};
 pub use commit::{CommitRepository, PgCommitRepository};
+pub use maintenance::{MaintenanceRepository, PgMaintenanceRepository};
+pub use mirror::{MirrorRepository, PgMirrorRepository};
 pub use organization::{OrganizationRepository, PgOrganizationRepository};
 pub use question::{PgQuestionRepository, QuestionRepository};
+pub use release::{PgReleaseRepository, ReleaseRepository};
 pub use repository::{PgRepositoryRepository, RepositoryRepository};
 pub use review::{PgReviewRepository, ReviewRepository};
+pub use team::{PgTeamRepository, TeamRepository};
 pub use user::{PgUserRepository, UserRepository};
@@ -0,0 +1,206 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{
+    error::DatabaseError,
+    model::{BranchProtection, BranchProtectionRules},
+};
+
+/// sqlx data-access layer for branch protection rules in
+/// `core.branch_protections`. Every lookup is scoped by `repository_id`.
+#[async_trait]
+pub trait BranchProtectionRepository: Send + Sync + Clone + 'static {
+    /// Lists every rule for `repository_id`, ordered by `pattern`. Unpaginated:
+    /// the pre-receive check needs the full set to evaluate a push.
+    async fn list(&self, repository_id: Uuid) -> Result<Vec<BranchProtection>, DatabaseError>;
+
+    /// Inserts a row into `core.branch_protections` and returns it
+    /// (`RETURNING` all columns). Fails on the `(repository_id, pattern)`
+    /// unique constraint if the pattern is already protected.
+    async fn create(
+        &self,
+        repository_id: Uuid,
+        pattern: &str,
+        rules: BranchProtectionRules,
+    ) -> Result<BranchProtection, DatabaseError>;
+
+    /// Overwrites `pattern` and every rule flag and bumps `updated_at` on the
+    /// row matching both `id` and `repository_id`. `Ok(None)` when no row
+    /// matches — including when the rule belongs to a different repository.
+    async fn update(
+        &self,
+        repository_id: Uuid,
+        id: Uuid,
+        pattern: &str,
+        rules: BranchProtectionRules,
+    ) -> Result<Option<BranchProtection>, DatabaseError>;
+
+    /// Hard-deletes the row matching both `id` and `repository_id`. Returns
+    /// `true` if a row was removed.
+    async fn delete(&self, repository_id: Uuid, id: Uuid) -> Result<bool, DatabaseError>;
+}
+
+#[derive(Debug, Clone)]
+pub struct PgBranchProtectionRepository {
+    pool: PgPool,
+}
+
+impl PgBranchProtectionRepository {
+    pub fn new(pool: PgPool) -> PgBranchProtectionRepository {
+        PgBranchProtectionRepository { pool }
+    }
+}
+
+#[crate::instrument_all(level = "debug")]
+#[async_trait]
+impl BranchProtectionRepository for PgBranchProtectionRepository {
+    async fn list(&self, repository_id: Uuid) -> Result<Vec<BranchProtection>, DatabaseError> {
+        let protections = sqlx::query_as::<_, BranchProtection>(
+            r#"
+            SELECT id, repository_id, pattern, block_force_push, block_deletion,
+                   require_review, require_green_build, created_at, updated_at
+            FROM core.branch_protections
+            WHERE repository_id = $1
+            ORDER BY pattern
+            "#,
+        )
+        .bind(repository_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(protections)
+    }
+
+    async fn create(
+        &self,
+        repository_id: Uuid,
+        pattern: &str,
+        rules: BranchProtectionRules,
+    ) -> Result<BranchProtection, DatabaseError> {
+        let protection = sqlx::query_as::<_, BranchProtection>(
+            r#"
+            INSERT INTO core.branch_protections (
+                repository_id, pattern, block_force_push, block_deletion,
+                require_review, require_green_build
+            )
+            VALUES ($1, $2, $3, $4, $5, $6)
+            RETURNING id, repository_id, pattern, block_force_push, block_deletion,
+                      require_review, require_green_build, created_at, updated_at
+            "#,
+        )
+        .bind(repository_id)
+        .bind(pattern)
+        .bind(rules.block_force_push)
+        .bind(rules.block_deletion)
+        .bind(rules.require_review)
+        .bind(rules.require_green_build)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(protection)
+    }
+
+    async fn update(
+        &self,
+        repository_id: Uuid,
+        id: Uuid,
+        pattern: &str,
+        rules: BranchProtectionRules,
+    ) -> Result<Option<BranchProtection>, DatabaseError> {
+        let protection = sqlx::query_as::<_, BranchProtection>(
+            r#"
+            UPDATE core.branch_protections
+            SET pattern = $3,
+                block_force_push = $4,
+                block_deletion = $5,
+                require_review = $6,
+                require_green_build = $7,
+                updated_at = NOW()
+            WHERE id = $1 AND repository_id = $2
+            RETURNING id, repository_id, pattern, block_force_push, block_deletion,
+                      require_review, require_green_build, created_at, updated_at
+            "#,
+        )
+        .bind(id)
+        .bind(repository_id)
+        .bind(pattern)
+        .bind(rules.block_force_push)
+        .bind(rules.block_deletion)
+        .bind(rules.require_review)
+        .bind(rules.require_green_build)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(protection)
+    }
+
+    async fn delete(&self, repository_id: Uuid, id: Uuid) -> Result<bool, DatabaseError> {
+        let result =
+            sqlx::query("DELETE FROM core.branch_protections WHERE id = $1 AND repository_id = $2")
+                .bind(id)
+                .bind(repository_id)
+                .execute(&self.pool)
+                .await?;
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+#[cfg(all(test, feature = "db-tests"))]
+mod tests {
+    use sqlx::PgPool;
+    use uuid::Uuid;
+
+    use super::{BranchProtectionRepository, BranchProtectionRules, PgBranchProtectionRepository};
+    use crate::repository::test_common::{insert_user, insert_user_repo};
+
+    const RULES: BranchProtectionRules = BranchProtectionRules {
+        block_force_push: true,
+        block_deletion: true,
+        require_review: false,
+        require_green_build: false,
+    };
+
+    #[sqlx::test]
+    async fn create_list_update_delete_round_trip(pool: PgPool) {
+        let repo = PgBranchProtectionRepository::new(pool.clone());
+        let alice = Uuid::new_v4();
+        let repo_id = Uuid::new_v4();
+        insert_user(&pool, alice, "alice").await;
+        insert_user_repo(&pool, repo_id, "proj", alice, "public").await;
+
+        let main = repo.create(repo_id, "main", RULES).await.unwrap();
+        repo.create(repo_id, "release/*", RULES).await.unwrap();
+        assert!(repo.create(repo_id, "main", RULES).await.is_err());
+
+        let listed = repo.list(repo_id).await.unwrap();
+        assert_eq!(
+            listed
+                .iter()
+                .map(|p| p.pattern.as_str())
+                .collect::<Vec<_>>(),
+            vec!["main", "release/*"]
+        );
+
+        let rules = BranchProtectionRules {
+            require_review: true,
+            ..RULES
+        };
+        let updated = repo
+            .update(repo_id, main.id, "main", rules)
+            .await
+            .unwrap()
+            .expect("rule exists");
+        assert!(updated.require_review);
+        assert!(
+            repo.update(Uuid::new_v4(), main.id, "main", rules)
+                .await
+                .unwrap()
+                .is_none()
+        );
+
+        assert!(repo.delete(repo_id, main.id).await.unwrap());
+        assert!(!repo.delete(repo_id, main.id).await.unwrap());
+        assert_eq!(repo.list(repo_id).await.unwrap().len(), 1);
+    }
+}
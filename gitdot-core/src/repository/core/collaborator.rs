@@ -0,0 +1,476 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{
+    error::DatabaseError,
+    model::{RepositoryCollaborator, RepositoryInvitation, RepositoryRole},
+};
+
+/// sqlx data-access layer for repository collaborators.
+///
+/// Owns `core.repository_collaborators` and `core.repository_invitations`,
+/// joining `core.users` for user names and `core.repositories` for the owner
+/// and name of the repository an invitation is for.
+#[async_trait]
+pub trait CollaboratorRepository: Send + Sync + Clone + 'static {
+    /// Lists the repository's collaborators ordered by user name.
+    async fn list_collaborators(
+        &self,
+        repository_id: Uuid,
+    ) -> Result<Vec<RepositoryCollaborator>, DatabaseError>;
+
+    /// Returns the collaborator row for `(repository_id, user_id)`, or
+    /// `Ok(None)` if the user is not a collaborator.
+    async fn get_collaborator(
+        &self,
+        repository_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Option<RepositoryCollaborator>, DatabaseError>;
+
+    /// Changes an existing collaborator's role. Returns `Ok(None)` if the user
+    /// is not a collaborator.
+    async fn update_collaborator(
+        &self,
+        repository_id: Uuid,
+        user_id: Uuid,
+        role: RepositoryRole,
+    ) -> Result<Option<RepositoryCollaborator>, DatabaseError>;
+
+    /// Deletes a collaborator. Returns whether a row was removed.
+    async fn delete_collaborator(
+        &self,
+        repository_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<bool, DatabaseError>;
+
+    /// Invites `invitee_id` with `role`. A pending invitation for the same
+    /// user is replaced (`ON CONFLICT (repository_id, invitee_id) DO UPDATE`),
+    /// so re-inviting changes the offered role.
+    async fn create_invitation(
+        &self,
+        repository_id: Uuid,
+        invitee_id: Uuid,
+        inviter_id: Uuid,
+        role: RepositoryRole,
+    ) -> Result<RepositoryInvitation, DatabaseError>;
+
+    /// Lists the repository's pending invitations, newest first.
+    async fn list_repository_invitations(
+        &self,
+        repository_id: Uuid,
+    ) -> Result<Vec<RepositoryInvitation>, DatabaseError>;
+
+    /// Lists the invitations addressed to `invitee_id`, newest first.
+    async fn list_user_invitations(
+        &self,
+        invitee_id: Uuid,
+    ) -> Result<Vec<RepositoryInvitation>, DatabaseError>;
+
+    /// Deletes a pending invitation scoped to its repository. Returns whether
+    /// a row was removed.
+    async fn delete_invitation(
+        &self,
+        repository_id: Uuid,
+        invitation_id: Uuid,
+    ) -> Result<bool, DatabaseError>;
+
+    /// Deletes the invitation addressed to `invitee_id` and makes them a
+    /// collaborator with the invited role, in one transaction. An existing
+    /// collaborator takes the invited role. Returns `Ok(None)` if no such
+    /// invitation is addressed to `invitee_id`.
+    async fn accept_invitation(
+        &self,
+        invitation_id: Uuid,
+        invitee_id: Uuid,
+    ) -> Result<Option<RepositoryCollaborator>, DatabaseError>;
+
+    /// Deletes the invitation addressed to `invitee_id` without granting
+    /// anything. Returns whether a row was removed.
+    async fn decline_invitation(
+        &self,
+        invitation_id: Uuid,
+        invitee_id: Uuid,
+    ) -> Result<bool, DatabaseError>;
+}
+
+#[derive(Debug, Clone)]
+pub struct PgCollaboratorRepository {
+    pool: PgPool,
+}
+
+impl PgCollaboratorRepository {
+    pub fn new(pool: PgPool) -> PgCollaboratorRepository {
+        PgCollaboratorRepository { pool }
+    }
+}
+
+#[crate::instrument_all(level = "debug")]
+#[async_trait]
+impl CollaboratorRepository for PgCollaboratorRepository {
+    async fn list_collaborators(
+        &self,
+        repository_id: Uuid,
+    ) -> Result<Vec<RepositoryCollaborator>, DatabaseError> {
+        let collaborators = sqlx::query_as::<_, RepositoryCollaborator>(
+            r#"
+            SELECT c.repository_id, c.user_id, u.name AS user_name, c.role, c.created_at,
+                   u.image_updated_at
+            FROM core.repository_collaborators c
+            JOIN core.users u ON u.id = c.user_id
+            WHERE c.repository_id = $1
+            ORDER BY u.name
+            "#,
+        )
+        .bind(repository_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(collaborators)
+    }
+
+    async fn get_collaborator(
+        &self,
+        repository_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Option<RepositoryCollaborator>, DatabaseError> {
+        let collaborator = sqlx::query_as::<_, RepositoryCollaborator>(
+            r#"
+            SELECT c.repository_id, c.user_id, u.name AS user_name, c.role, c.created_at,
+                   u.image_updated_at
+            FROM core.repository_collaborators c
+            JOIN core.users u ON u.id = c.user_id
+            WHERE c.repository_id = $1 AND c.user_id = $2
+            "#,
+        )
+        .bind(repository_id)
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(collaborator)
+    }
+
+    async fn update_collaborator(
+        &self,
+        repository_id: Uuid,
+        user_id: Uuid,
+        role: RepositoryRole,
+    ) -> Result<Option<RepositoryCollaborator>, DatabaseError> {
+        let collaborator = sqlx::query_as::<_, RepositoryCollaborator>(
+            r#"
+            WITH updated AS (
+                UPDATE core.repository_collaborators
+                SET role = $3
+                WHERE repository_id = $1 AND user_id = $2
+                RETURNING repository_id, user_id, role, created_at
+            )
+            SELECT up.repository_id, up.user_id, u.name AS user_name, up.role, up.created_at,
+                   u.image_updated_at
+            FROM updated up
+            JOIN core.users u ON u.id = up.user_id
+            "#,
+        )
+        .bind(repository_id)
+        .bind(user_id)
+        .bind(role)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(collaborator)
+    }
+
+    async fn delete_collaborator(
+        &self,
+        repository_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<bool, DatabaseError> {
+        let result = sqlx::query(
+            "DELETE FROM core.repository_collaborators WHERE repository_id = $1 AND user_id = $2",
+        )
+        .bind(repository_id)
+        .bind(user_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn create_invitation(
+        &self,
+        repository_id: Uuid,
+        invitee_id: Uuid,
+        inviter_id: Uuid,
+        role: RepositoryRole,
+    ) -> Result<RepositoryInvitation, DatabaseError> {
+        let invitation = sqlx::query_as::<_, RepositoryInvitation>(
+            r#"
+            WITH upserted AS (
+                INSERT INTO core.repository_invitations (repository_id, invitee_id, inviter_id, role)
+                VALUES ($1, $2, $3, $4)
+                ON CONFLICT (repository_id, invitee_id)
+                DO UPDATE SET inviter_id = EXCLUDED.inviter_id, role = EXCLUDED.role, created_at = NOW()
+                RETURNING id, repository_id, invitee_id, inviter_id, role, created_at
+            )
+            SELECT i.id, i.repository_id, COALESCE(ou.name, o.name) AS owner_name,
+                   r.name AS repository_name, i.invitee_id, invitee.name AS invitee_name,
+                   i.inviter_id, inviter.name AS inviter_name, i.role, i.created_at
+            FROM upserted i
+            JOIN core.repositories r ON r.id = i.repository_id
+            LEFT JOIN core.users ou
+              ON r.owner_id = ou.id AND r.owner_type = 'user'
+            LEFT JOIN core.organizations o
+              ON r.owner_id = o.id AND r.owner_type = 'organization'
+            JOIN core.users invitee ON invitee.id = i.invitee_id
+            JOIN core.users inviter ON inviter.id = i.inviter_id
+            "#,
+        )
+        .bind(repository_id)
+        .bind(invitee_id)
+        .bind(inviter_id)
+        .bind(role)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(invitation)
+    }
+
+    async fn list_repository_invitations(
+        &self,
+        repository_id: Uuid,
+    ) -> Result<Vec<RepositoryInvitation>, DatabaseError> {
+        let invitations = sqlx::query_as::<_, RepositoryInvitation>(
+            r#"
+            SELECT i.id, i.repository_id, COALESCE(ou.name, o.name) AS owner_name,
+                   r.name AS repository_name, i.invitee_id, invitee.name AS invitee_name,
+                   i.inviter_id, inviter.name AS inviter_name, i.role, i.created_at
+            FROM core.repository_invitations i
+            JOIN core.repositories r ON r.id = i.repository_id
+            LEFT JOIN core.users ou
+              ON r.owner_id = ou.id AND r.owner_type = 'user'
+            LEFT JOIN core.organizations o
+              ON r.owner_id = o.id AND r.owner_type = 'organization'
+            JOIN core.users invitee ON invitee.id = i.invitee_id
+            JOIN core.users inviter ON inviter.id = i.inviter_id
+            WHERE i.repository_id = $1
+            ORDER BY i.created_at DESC, i.id DESC
+            "#,
+        )
+        .bind(repository_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(invitations)
+    }
+
+    async fn list_user_invitations(
+        &self,
+        invitee_id: Uuid,
+    ) -> Result<Vec<RepositoryInvitation>, DatabaseError> {
+        let invitations = sqlx::query_as::<_, RepositoryInvitation>(
+            r#"
+            SELECT i.id, i.repository_id, COALESCE(ou.name, o.name) AS owner_name,
+                   r.name AS repository_name, i.invitee_id, invitee.name AS invitee_name,
+                   i.inviter_id, inviter.name AS inviter_name, i.role, i.created_at
+            FROM core.repository_invitations i
+            JOIN core.repositories r ON r.id = i.repository_id
+            LEFT JOIN core.users ou
+              ON r.owner_id = ou.id AND r.owner_type = 'user'
+            LEFT JOIN core.organizations o
+              ON r.owner_id = o.id AND r.owner_type = 'organization'
+            JOIN core.users invitee ON invitee.id = i.invitee_id
+            JOIN core.users inviter ON inviter.id = i.inviter_id
+            WHERE i.invitee_id = $1
+            ORDER BY i.created_at DESC, i.id DESC
+            "#,
+        )
+        .bind(invitee_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(invitations)
+    }
+
+    async fn delete_invitation(
+        &self,
+        repository_id: Uuid,
+        invitation_id: Uuid,
+    ) -> Result<bool, DatabaseError> {
+        let result = sqlx::query(
+            "DELETE FROM core.repository_invitations WHERE repository_id = $1 AND id = $2",
+        )
+        .bind(repository_id)
+        .bind(invitation_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn accept_invitation(
+        &self,
+        invitation_id: Uuid,
+        invitee_id: Uuid,
+    ) -> Result<Option<RepositoryCollaborator>, DatabaseError> {
+        let mut tx = self.pool.begin().await?;
+
+        let invitation = sqlx::query_as::<_, (Uuid, RepositoryRole)>(
+            r#"
+            DELETE FROM core.repository_invitations
+            WHERE id = $1 AND invitee_id = $2
+            RETURNING repository_id, role
+            "#,
+        )
+        .bind(invitation_id)
+        .bind(invitee_id)
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        let Some((repository_id, role)) = invitation else {
+            return Ok(None);
+        };
+
+        let collaborator = sqlx::query_as::<_, RepositoryCollaborator>(
+            r#"
+            WITH upserted AS (
+                INSERT INTO core.repository_collaborators (repository_id, user_id, role)
+                VALUES ($1, $2, $3)
+                ON CONFLICT (repository_id, user_id) DO UPDATE SET role = EXCLUDED.role
+                RETURNING repository_id, user_id, role, created_at
+            )
+            SELECT up.repository_id, up.user_id, u.name AS user_name, up.role, up.created_at,
+                   u.image_updated_at
+            FROM upserted up
+            JOIN core.users u ON u.id = up.user_id
+            "#,
+        )
+        .bind(repository_id)
+        .bind(invitee_id)
+        .bind(role)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(Some(collaborator))
+    }
+
+    async fn decline_invitation(
+        &self,
+        invitation_id: Uuid,
+        invitee_id: Uuid,
+    ) -> Result<bool, DatabaseError> {
+        let result = sqlx::query(
+            "DELETE FROM core.repository_invitations WHERE id = $1 AND invitee_id = $2",
+        )
+        .bind(invitation_id)
+        .bind(invitee_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+#[cfg(all(test, feature = "db-tests"))]
+mod tests {
+    use sqlx::PgPool;
+    use uuid::Uuid;
+
+    use super::{CollaboratorRepository, PgCollaboratorRepository};
+    use crate::{
+        model::RepositoryRole,
+        repository::{
+            PgRepositoryRepository, RepositoryRepository,
+            test_common::{insert_user, insert_user_repo},
+        },
+    };
+
+    #[sqlx::test]
+    async fn accepting_an_invitation_grants_the_role(pool: PgPool) {
+        let collaborators = PgCollaboratorRepository::new(pool.clone());
+        let repositories = PgRepositoryRepository::new(pool.clone());
+        let owner = Uuid::new_v4();
+        let invitee = Uuid::new_v4();
+        let repo_id = Uuid::new_v4();
+        insert_user(&pool, owner, "owner").await;
+        insert_user(&pool, invitee, "invitee").await;
+        insert_user_repo(&pool, repo_id, "repo", owner, "private").await;
+
+        let invitation = collaborators
+            .create_invitation(repo_id, invitee, owner, RepositoryRole::Triage)
+            .await
+            .unwrap();
+        assert_eq!(invitation.owner_name, "owner");
+        assert_eq!(invitation.repository_name, "repo");
+        assert_eq!(invitation.inviter_name, "owner");
+
+        // Re-inviting replaces the pending invitation.
+        let invitation = collaborators
+            .create_invitation(repo_id, invitee, owner, RepositoryRole::Write)
+            .await
+            .unwrap();
+        let pending = collaborators.list_user_invitations(invitee).await.unwrap();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].role, RepositoryRole::Write);
+
+        // Nothing is granted until the invitation is accepted.
+        assert!(
+            repositories
+                .get_granted_role(repo_id, invitee)
+                .await
+                .unwrap()
+                .is_none()
+        );
+        assert!(
+            collaborators
+                .accept_invitation(invitation.id, owner)
+                .await
+                .unwrap()
+                .is_none()
+        );
+
+        let collaborator = collaborators
+            .accept_invitation(invitation.id, invitee)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(collaborator.user_name, "invitee");
+        assert_eq!(collaborator.role, RepositoryRole::Write);
+        assert_eq!(
+            repositories
+                .get_granted_role(repo_id, invitee)
+                .await
+                .unwrap(),
+            Some(RepositoryRole::Write)
+        );
+        assert!(
+            collaborators
+                .list_repository_invitations(repo_id)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+
+        // The private repo is now listed for the collaborator.
+        let (rows, _) = repositories
+            .list_by_owner("owner", Some(invitee), None, 20)
+            .await
+            .unwrap();
+        assert_eq!(rows.len(), 1);
+
+        assert!(
+            collaborators
+                .delete_collaborator(repo_id, invitee)
+                .await
+                .unwrap()
+        );
+        assert!(
+            collaborators
+                .get_collaborator(repo_id, invitee)
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+}
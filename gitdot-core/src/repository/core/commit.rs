@@ -6,13 +6,13 @@ use uuid::Uuid;
 use crate::{
     dto::Cursor,
     error::DatabaseError,
-    model::{Commit, CommitDiff},
+    model::{Commit, CommitDiff, CommitSignatureStatus},
 };
 
 const COMMIT_PROJECTION_QUERY: &str = "
     c.id, c.repo_id, c.author_id, c.git_author_name, c.git_author_email,
     c.ref_name, c.sha, c.parent_sha, c.message, c.created_at,
-    c.review_number, c.diff_position, c.diffs,
+    c.review_number, c.diff_position, c.diffs, c.signature_status,
     au.name AS author_name,
     au.image_updated_at AS author_image_updated_at,
     json_build_object(
@@ -93,7 +93,16 @@ pub trait CommitRepository: Send + Sync + Clone + 'static {
         diffs: &[Vec<CommitDiff>],
         review_numbers: &[Option<i32>],
         diff_positions: &[Option<i32>],
+        signature_statuses: &[Option<CommitSignatureStatus>],
     ) -> Result<Vec<Commit>, DatabaseError>;
+
+    /// Sets the `signature_status` of the commit with id `id`, as re-checked
+    /// after ingestion.
+    async fn set_signature_status(
+        &self,
+        id: Uuid,
+        status: Option<CommitSignatureStatus>,
+    ) -> Result<(), DatabaseError>;
 }
 
 #[derive(Debug, Clone)]
@@ -267,6 +276,7 @@ impl CommitRepository for PgCommitRepository {
         diffs: &[Vec<CommitDiff>],
         review_numbers: &[Option<i32>],
         diff_positions: &[Option<i32>],
+        signature_statuses: &[Option<CommitSignatureStatus>],
     ) -> Result<Vec<Commit>, DatabaseError> {
         if shas.is_empty() {
             return Ok(Vec::new());
@@ -276,6 +286,10 @@ impl CommitRepository for PgCommitRepository {
             .iter()
             .map(|d| serde_json::to_value(d).unwrap_or(serde_json::Value::Array(vec![])))
             .collect();
+        let signature_statuses: Vec<Option<&str>> = signature_statuses
+            .iter()
+            .map(|status| status.as_ref().map(CommitSignatureStatus::as_str))
+            .collect();
 
         let query = format!(
             r#"
@@ -283,12 +297,13 @@ impl CommitRepository for PgCommitRepository {
                 INSERT INTO core.commits (
                     author_id, git_author_name, git_author_email, repo_id,
                     ref_name, sha, parent_sha, message, created_at, diffs,
-                    review_number, diff_position
+                    review_number, diff_position, signature_status
                 )
                 SELECT * FROM UNNEST(
                     $1::uuid[], $2::text[], $3::text[], $4::uuid[],
                     $5::varchar[], $6::varchar[], $7::varchar[], $8::text[],
-                    $9::timestamptz[], $10::jsonb[], $11::int[], $12::int[]
+                    $9::timestamptz[], $10::jsonb[], $11::int[], $12::int[],
+                    $13::text[]::core.commit_signature_status[]
                 )
                 ON CONFLICT (repo_id, sha) DO NOTHING
                 RETURNING *
@@ -314,11 +329,26 @@ impl CommitRepository for PgCommitRepository {
             .bind(diffs_json)
             .bind(review_numbers)
             .bind(diff_positions)
+            .bind(signature_statuses)
             .fetch_all(&self.pool)
             .await?;
 
         Ok(rows)
     }
+
+    async fn set_signature_status(
+        &self,
+        id: Uuid,
+        status: Option<CommitSignatureStatus>,
+    ) -> Result<(), DatabaseError> {
+        sqlx::query("UPDATE core.commits SET signature_status = $2 WHERE id = $1")
+            .bind(id)
+            .bind(status)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
 }
 
 #[cfg(all(test, feature = "db-tests"))]
@@ -329,7 +359,7 @@ mod tests {
     use sqlx::PgPool;
     use uuid::Uuid;
 
-    use super::{Commit, CommitDiff, CommitRepository, PgCommitRepository};
+    use super::{Commit, CommitDiff, CommitRepository, CommitSignatureStatus, PgCommitRepository};
     use crate::{
         model::OrganizationRole,
         repository::test_common::{
@@ -688,6 +718,7 @@ mod tests {
                 &[diffs_a.clone(), vec![]],
                 &[None, None],
                 &[None, None],
+                &[Some(CommitSignatureStatus::Verified), None],
             )
             .await
             .unwrap();
@@ -701,6 +732,13 @@ mod tests {
         assert_eq!(a.repository.name, "proj");
         assert_eq!(a.diffs.len(), 1);
         assert_eq!(a.diffs[0].path, "src/lib.rs");
+        assert_eq!(a.signature_status, Some(CommitSignatureStatus::Verified));
+
+        repo.set_signature_status(a.id, Some(CommitSignatureStatus::BadEmail))
+            .await
+            .unwrap();
+        let a = repo.get_commit(repo_id, &sha_a).await.unwrap().unwrap();
+        assert_eq!(a.signature_status, Some(CommitSignatureStatus::BadEmail));
 
         // Re-running with one existing sha and one new sha inserts only the new one.
         let sha_c = "c".repeat(40);
@@ -721,6 +759,7 @@ mod tests {
                 &[vec![], vec![]],
                 &[None, None],
                 &[None, None],
+                &[None, None],
             )
             .await
             .unwrap();
@@ -729,7 +768,21 @@ mod tests {
 
         // Empty input is a no-op.
         let empty = repo
-            .create_bulk(&[], &[], &[], &[], &[], &[], &[], &[], &[], &[], &[], &[])
+            .create_bulk(
+                &[],
+                &[],
+                &[],
+                &[],
+                &[],
+                &[],
+                &[],
+                &[],
+                &[],
+                &[],
+                &[],
+                &[],
+                &[],
+            )
             .await
             .unwrap();
         assert!(empty.is_empty());
@@ -0,0 +1,281 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{
+    error::DatabaseError,
+    model::{MaintenanceStatus, RepositoryMaintenance},
+};
+
+/// sqlx data-access layer for `core.repository_maintenance`, the per-repo push
+/// counter and run state the maintenance scheduler works from. Rows are
+/// created on first push or request; owner and repository names are joined in
+/// on every read.
+#[async_trait]
+pub trait MaintenanceRepository: Send + Sync + Clone + 'static {
+    /// Returns the repository's maintenance row, or `Ok(None)` if it has never
+    /// been pushed to or had maintenance requested.
+    async fn get(
+        &self,
+        repository_id: Uuid,
+    ) -> Result<Option<RepositoryMaintenance>, DatabaseError>;
+
+    /// Counts a push against the repository and stamps `last_pushed_at`,
+    /// creating its row if needed.
+    async fn record_push(&self, repository_id: Uuid) -> Result<(), DatabaseError>;
+
+    /// Marks a run as requested, keeping the earlier timestamp if one is
+    /// already pending, and returns the row.
+    async fn request(&self, repository_id: Uuid) -> Result<RepositoryMaintenance, DatabaseError>;
+
+    /// Atomically claims up to `limit` repositories due for maintenance by
+    /// stamping `started_at` and clearing `requested_at`. A repository is due
+    /// when a run was requested, when it has `push_threshold` pending pushes,
+    /// or when it has any pending pushes and has been idle for `idle_after`.
+    /// Requested runs go first, then the busiest repositories. Rows locked by
+    /// another claimer are skipped, and a run started more than `stale_after`
+    /// ago is considered abandoned and may be claimed again.
+    async fn claim_due(
+        &self,
+        push_threshold: i32,
+        idle_after: Duration,
+        stale_after: Duration,
+        limit: i64,
+    ) -> Result<Vec<RepositoryMaintenance>, DatabaseError>;
+
+    /// Records the outcome of a claimed run and subtracts the `claimed_pushes`
+    /// it covered, so pushes that landed during the run stay pending.
+    async fn finish(
+        &self,
+        repository_id: Uuid,
+        claimed_pushes: i32,
+        status: MaintenanceStatus,
+        error: Option<String>,
+    ) -> Result<RepositoryMaintenance, DatabaseError>;
+}
+
+#[derive(Debug, Clone)]
+pub struct PgMaintenanceRepository {
+    pool: PgPool,
+}
+
+impl PgMaintenanceRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[crate::instrument_all(level = "debug")]
+#[async_trait]
+impl MaintenanceRepository for PgMaintenanceRepository {
+    async fn get(
+        &self,
+        repository_id: Uuid,
+    ) -> Result<Option<RepositoryMaintenance>, DatabaseError> {
+        let maintenance = sqlx::query_as::<_, RepositoryMaintenance>(
+            r#"
+            SELECT m.repository_id, COALESCE(u.name, o.name) AS owner_name,
+                   r.name AS repository_name, m.pending_pushes, m.last_pushed_at,
+                   m.requested_at, m.started_at, m.finished_at, m.last_status, m.last_error
+            FROM core.repository_maintenance m
+            JOIN core.repositories r ON r.id = m.repository_id
+            LEFT JOIN core.users u
+              ON r.owner_id = u.id AND r.owner_type = 'user'
+            LEFT JOIN core.organizations o
+              ON r.owner_id = o.id AND r.owner_type = 'organization'
+            WHERE m.repository_id = $1
+            "#,
+        )
+        .bind(repository_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(maintenance)
+    }
+
+    async fn record_push(&self, repository_id: Uuid) -> Result<(), DatabaseError> {
+        sqlx::query(
+            r#"
+            INSERT INTO core.repository_maintenance (repository_id, pending_pushes, last_pushed_at)
+            VALUES ($1, 1, NOW())
+            ON CONFLICT (repository_id) DO UPDATE
+            SET pending_pushes = core.repository_maintenance.pending_pushes + 1,
+                last_pushed_at = NOW()
+            "#,
+        )
+        .bind(repository_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn request(&self, repository_id: Uuid) -> Result<RepositoryMaintenance, DatabaseError> {
+        let maintenance = sqlx::query_as::<_, RepositoryMaintenance>(
+            r#"
+            WITH m AS (
+                INSERT INTO core.repository_maintenance (repository_id, requested_at)
+                VALUES ($1, NOW())
+                ON CONFLICT (repository_id) DO UPDATE
+                SET requested_at = COALESCE(core.repository_maintenance.requested_at, NOW())
+                RETURNING *
+            )
+            SELECT m.repository_id, COALESCE(u.name, o.name) AS owner_name,
+                   r.name AS repository_name, m.pending_pushes, m.last_pushed_at,
+                   m.requested_at, m.started_at, m.finished_at, m.last_status, m.last_error
+            FROM m
+            JOIN core.repositories r ON r.id = m.repository_id
+            LEFT JOIN core.users u
+              ON r.owner_id = u.id AND r.owner_type = 'user'
+            LEFT JOIN core.organizations o
+              ON r.owner_id = o.id AND r.owner_type = 'organization'
+            "#,
+        )
+        .bind(repository_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(maintenance)
+    }
+
+    async fn claim_due(
+        &self,
+        push_threshold: i32,
+        idle_after: Duration,
+        stale_after: Duration,
+        limit: i64,
+    ) -> Result<Vec<RepositoryMaintenance>, DatabaseError> {
+        let claimed = sqlx::query_as::<_, RepositoryMaintenance>(
+            r#"
+            WITH due AS (
+                SELECT repository_id FROM core.repository_maintenance
+                WHERE (started_at IS NULL OR started_at < NOW() - make_interval(secs => $3))
+                  AND (
+                    requested_at IS NOT NULL
+                    OR pending_pushes >= $1
+                    OR (pending_pushes > 0 AND last_pushed_at < NOW() - make_interval(secs => $2))
+                  )
+                ORDER BY requested_at ASC NULLS LAST, pending_pushes DESC
+                LIMIT $4
+                FOR UPDATE SKIP LOCKED
+            ), m AS (
+                UPDATE core.repository_maintenance m
+                SET started_at = NOW(), requested_at = NULL
+                FROM due
+                WHERE m.repository_id = due.repository_id
+                RETURNING m.*
+            )
+            SELECT m.repository_id, COALESCE(u.name, o.name) AS owner_name,
+                   r.name AS repository_name, m.pending_pushes, m.last_pushed_at,
+                   m.requested_at, m.started_at, m.finished_at, m.last_status, m.last_error
+            FROM m
+            JOIN core.repositories r ON r.id = m.repository_id
+            LEFT JOIN core.users u
+              ON r.owner_id = u.id AND r.owner_type = 'user'
+            LEFT JOIN core.organizations o
+              ON r.owner_id = o.id AND r.owner_type = 'organization'
+            "#,
+        )
+        .bind(push_threshold)
+        .bind(idle_after.as_secs_f64())
+        .bind(stale_after.as_secs_f64())
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(claimed)
+    }
+
+    async fn finish(
+        &self,
+        repository_id: Uuid,
+        claimed_pushes: i32,
+        status: MaintenanceStatus,
+        error: Option<String>,
+    ) -> Result<RepositoryMaintenance, DatabaseError> {
+        let maintenance = sqlx::query_as::<_, RepositoryMaintenance>(
+            r#"
+            WITH m AS (
+                UPDATE core.repository_maintenance
+                SET started_at = NULL, finished_at = NOW(), last_status = $3, last_error = $4,
+                    pending_pushes = GREATEST(pending_pushes - $2, 0)
+                WHERE repository_id = $1
+                RETURNING *
+            )
+            SELECT m.repository_id, COALESCE(u.name, o.name) AS owner_name,
+                   r.name AS repository_name, m.pending_pushes, m.last_pushed_at,
+                   m.requested_at, m.started_at, m.finished_at, m.last_status, m.last_error
+            FROM m
+            JOIN core.repositories r ON r.id = m.repository_id
+            LEFT JOIN core.users u
+              ON r.owner_id = u.id AND r.owner_type = 'user'
+            LEFT JOIN core.organizations o
+              ON r.owner_id = o.id AND r.owner_type = 'organization'
+            "#,
+        )
+        .bind(repository_id)
+        .bind(claimed_pushes)
+        .bind(status)
+        .bind(error)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(maintenance)
+    }
+}
+
+#[cfg(all(test, feature = "db-tests"))]
+mod tests {
+    use std::time::Duration;
+
+    use sqlx::PgPool;
+    use uuid::Uuid;
+
+    use super::{MaintenanceRepository, PgMaintenanceRepository};
+    use crate::{
+        model::MaintenanceStatus,
+        repository::test_common::{insert_user, insert_user_repo},
+    };
+
+    const HOUR: Duration = Duration::from_secs(3600);
+
+    #[sqlx::test]
+    async fn claims_busy_and_requested_repositories(pool: PgPool) {
+        let repo = PgMaintenanceRepository::new(pool.clone());
+        let alice = Uuid::new_v4();
+        insert_user(&pool, alice, "alice").await;
+        let (busy, quiet, requested) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        insert_user_repo(&pool, busy, "busy", alice, "public").await;
+        insert_user_repo(&pool, quiet, "quiet", alice, "public").await;
+        insert_user_repo(&pool, requested, "requested", alice, "public").await;
+
+        for _ in 0..3 {
+            repo.record_push(busy).await.unwrap();
+        }
+        repo.record_push(quiet).await.unwrap();
+        repo.request(requested).await.unwrap();
+
+        let claimed = repo.claim_due(3, HOUR, HOUR, 10).await.unwrap();
+        let mut names: Vec<_> = claimed.iter().map(|m| m.repository_name.as_str()).collect();
+        names.sort();
+        assert_eq!(names, ["busy", "requested"]);
+        assert!(claimed.iter().all(|m| m.started_at.is_some()));
+        assert!(claimed.iter().all(|m| m.requested_at.is_none()));
+
+        // Running repositories are not claimed twice.
+        assert!(repo.claim_due(3, HOUR, HOUR, 10).await.unwrap().is_empty());
+
+        // A push during the run stays pending once the run finishes.
+        repo.record_push(busy).await.unwrap();
+        let finished = repo
+            .finish(busy, 3, MaintenanceStatus::Success, None)
+            .await
+            .unwrap();
+        assert_eq!(finished.pending_pushes, 1);
+        assert_eq!(finished.last_status, Some(MaintenanceStatus::Success));
+        assert!(finished.started_at.is_none());
+        assert_eq!(finished.owner_name, "alice");
+    }
+}
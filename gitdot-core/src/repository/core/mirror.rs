@@ -0,0 +1,444 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{
+    error::DatabaseError,
+    model::{MirrorDirection, MirrorRunStatus, RepositoryMirror, RepositoryMirrorRun},
+};
+
+/// Selects the mirror rows of a preceding `m` CTE with their repository's
+/// owner and name joined in.
+const MIRROR_QUERY: &str = "\
+    SELECT m.id, m.repository_id, COALESCE(u.name, o.name) AS owner_name, \
+           r.name AS repository_name, m.direction, m.url, m.username, \
+           m.password_ciphertext, m.interval_seconds, m.requested_at, m.started_at, \
+           m.finished_at, m.last_synced_at, m.last_status, m.last_error, m.was_readonly, \
+           m.created_at \
+    FROM m \
+    JOIN core.repositories r ON r.id = m.repository_id \
+    LEFT JOIN core.users u ON r.owner_id = u.id AND r.owner_type = 'user' \
+    LEFT JOIN core.organizations o ON r.owner_id = o.id AND r.owner_type = 'organization'";
+
+/// Finished runs kept per mirror; older ones are dropped as new ones land.
+const RUN_HISTORY_LIMIT: i64 = 50;
+
+/// sqlx data-access layer for `core.repository_mirrors` and their run history
+/// in `core.repository_mirror_runs`. Owner and repository names are joined in
+/// on every read.
+#[async_trait]
+pub trait MirrorRepository: Send + Sync + Clone + 'static {
+    /// Lists a repository's mirrors, pull mirror first, then oldest first.
+    async fn list(&self, repository_id: Uuid) -> Result<Vec<RepositoryMirror>, DatabaseError>;
+
+    /// Returns the mirror `id` of the repository, or `Ok(None)` if it does
+    /// not exist or belongs to another repository.
+    async fn get(
+        &self,
+        repository_id: Uuid,
+        id: Uuid,
+    ) -> Result<Option<RepositoryMirror>, DatabaseError>;
+
+    /// Inserts a mirror with a sync already requested, so the scheduler runs
+    /// it on its next pass. The repository's current `readonly` flag is
+    /// recorded as `was_readonly`.
+    async fn create(
+        &self,
+        repository_id: Uuid,
+        direction: MirrorDirection,
+        url: &str,
+        username: Option<String>,
+        password_ciphertext: Option<Vec<u8>>,
+        interval_seconds: Option<i32>,
+    ) -> Result<RepositoryMirror, DatabaseError>;
+
+    /// Deletes the mirror `id` of the repository with its run history.
+    /// Returns whether a row was deleted.
+    async fn delete(&self, repository_id: Uuid, id: Uuid) -> Result<bool, DatabaseError>;
+
+    /// Marks a sync of the mirror as requested, keeping the earlier timestamp
+    /// if one is already pending. Returns `Ok(None)` if the mirror does not
+    /// exist.
+    async fn request(
+        &self,
+        repository_id: Uuid,
+        id: Uuid,
+    ) -> Result<Option<RepositoryMirror>, DatabaseError>;
+
+    /// Marks a sync as requested on every push mirror of the repository.
+    async fn request_push(&self, repository_id: Uuid) -> Result<(), DatabaseError>;
+
+    /// Atomically claims up to `limit` mirrors due for a sync by stamping
+    /// `started_at` and clearing `requested_at`. A mirror is due when a sync
+    /// was requested, or when it pulls and its last sync finished more than
+    /// `interval_seconds` ago. Requested syncs go first, then the mirrors
+    /// that waited longest. Rows locked by another claimer are skipped, and a
+    /// sync started more than `stale_after` ago is considered abandoned and
+    /// may be claimed again.
+    async fn claim_due(
+        &self,
+        stale_after: Duration,
+        limit: i64,
+    ) -> Result<Vec<RepositoryMirror>, DatabaseError>;
+
+    /// Records the outcome of a claimed sync on the mirror and appends it to
+    /// the mirror's run history. `last_synced_at` only moves on success.
+    async fn finish(
+        &self,
+        id: Uuid,
+        status: MirrorRunStatus,
+        error: Option<String>,
+    ) -> Result<RepositoryMirror, DatabaseError>;
+
+    /// Lists up to `limit` of the mirror's most recent runs, newest first.
+    async fn list_runs(
+        &self,
+        mirror_id: Uuid,
+        limit: i64,
+    ) -> Result<Vec<RepositoryMirrorRun>, DatabaseError>;
+}
+
+#[derive(Debug, Clone)]
+pub struct PgMirrorRepository {
+    pool: PgPool,
+}
+
+impl PgMirrorRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[crate::instrument_all(level = "debug")]
+#[async_trait]
+impl MirrorRepository for PgMirrorRepository {
+    async fn list(&self, repository_id: Uuid) -> Result<Vec<RepositoryMirror>, DatabaseError> {
+        let query = format!(
+            "WITH m AS (SELECT * FROM core.repository_mirrors WHERE repository_id = $1) \
+             {} ORDER BY m.direction, m.created_at",
+            MIRROR_QUERY
+        );
+        let mirrors = sqlx::query_as::<_, RepositoryMirror>(&query)
+            .bind(repository_id)
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(mirrors)
+    }
+
+    async fn get(
+        &self,
+        repository_id: Uuid,
+        id: Uuid,
+    ) -> Result<Option<RepositoryMirror>, DatabaseError> {
+        let query = format!(
+            "WITH m AS ( \
+                SELECT * FROM core.repository_mirrors WHERE repository_id = $1 AND id = $2 \
+             ) {}",
+            MIRROR_QUERY
+        );
+        let mirror = sqlx::query_as::<_, RepositoryMirror>(&query)
+            .bind(repository_id)
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(mirror)
+    }
+
+    async fn create(
+        &self,
+        repository_id: Uuid,
+        direction: MirrorDirection,
+        url: &str,
+        username: Option<String>,
+        password_ciphertext: Option<Vec<u8>>,
+        interval_seconds: Option<i32>,
+    ) -> Result<RepositoryMirror, DatabaseError> {
+        let query = format!(
+            "WITH m AS ( \
+                INSERT INTO core.repository_mirrors \
+                    (repository_id, direction, url, username, password_ciphertext, \
+                     interval_seconds, requested_at, was_readonly) \
+                SELECT $1, $2, $3, $4, $5, $6, NOW(), r.readonly \
+                FROM core.repositories r WHERE r.id = $1 \
+                RETURNING * \
+             ) {}",
+            MIRROR_QUERY
+        );
+        let mirror = sqlx::query_as::<_, RepositoryMirror>(&query)
+            .bind(repository_id)
+            .bind(direction)
+            .bind(url)
+            .bind(username)
+            .bind(password_ciphertext)
+            .bind(interval_seconds)
+            .fetch_one(&self.pool)
+            .await?;
+
+        Ok(mirror)
+    }
+
+    async fn delete(&self, repository_id: Uuid, id: Uuid) -> Result<bool, DatabaseError> {
+        let result =
+            sqlx::query("DELETE FROM core.repository_mirrors WHERE repository_id = $1 AND id = $2")
+                .bind(repository_id)
+                .bind(id)
+                .execute(&self.pool)
+                .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn request(
+        &self,
+        repository_id: Uuid,
+        id: Uuid,
+    ) -> Result<Option<RepositoryMirror>, DatabaseError> {
+        let query = format!(
+            "WITH m AS ( \
+                UPDATE core.repository_mirrors \
+                SET requested_at = COALESCE(requested_at, NOW()) \
+                WHERE repository_id = $1 AND id = $2 \
+                RETURNING * \
+             ) {}",
+            MIRROR_QUERY
+        );
+        let mirror = sqlx::query_as::<_, RepositoryMirror>(&query)
+            .bind(repository_id)
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(mirror)
+    }
+
+    async fn request_push(&self, repository_id: Uuid) -> Result<(), DatabaseError> {
+        sqlx::query(
+            r#"
+            UPDATE core.repository_mirrors
+            SET requested_at = COALESCE(requested_at, NOW())
+            WHERE repository_id = $1 AND direction = 'push'
+            "#,
+        )
+        .bind(repository_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn claim_due(
+        &self,
+        stale_after: Duration,
+        limit: i64,
+    ) -> Result<Vec<RepositoryMirror>, DatabaseError> {
+        let query = format!(
+            "WITH due AS ( \
+                SELECT id FROM core.repository_mirrors \
+                WHERE (started_at IS NULL OR started_at < NOW() - make_interval(secs => $1)) \
+                  AND ( \
+                    requested_at IS NOT NULL \
+                    OR (direction = 'pull' AND (finished_at IS NULL \
+                        OR finished_at < NOW() - make_interval(secs => interval_seconds))) \
+                  ) \
+                ORDER BY requested_at ASC NULLS LAST, finished_at ASC NULLS FIRST \
+                LIMIT $2 \
+                FOR UPDATE SKIP LOCKED \
+             ), m AS ( \
+                UPDATE core.repository_mirrors m \
+                SET started_at = NOW(), requested_at = NULL \
+                FROM due \
+                WHERE m.id = due.id \
+                RETURNING m.* \
+             ) {}",
+            MIRROR_QUERY
+        );
+        let claimed = sqlx::query_as::<_, RepositoryMirror>(&query)
+            .bind(stale_after.as_secs_f64())
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(claimed)
+    }
+
+    async fn finish(
+        &self,
+        id: Uuid,
+        status: MirrorRunStatus,
+        error: Option<String>,
+    ) -> Result<RepositoryMirror, DatabaseError> {
+        // `prev` sees the row as it was before the update, so the run keeps
+        // the claimed start time.
+        let query = format!(
+            "WITH prev AS ( \
+                SELECT started_at FROM core.repository_mirrors WHERE id = $1 \
+             ), run AS ( \
+                INSERT INTO core.repository_mirror_runs (mirror_id, status, error, started_at) \
+                SELECT $1, $2, $3, COALESCE(prev.started_at, NOW()) FROM prev \
+             ), m AS ( \
+                UPDATE core.repository_mirrors \
+                SET started_at = NULL, finished_at = NOW(), last_status = $2, last_error = $3, \
+                    last_synced_at = CASE WHEN $2 = 'success' THEN NOW() ELSE last_synced_at END \
+                WHERE id = $1 \
+                RETURNING * \
+             ) {}",
+            MIRROR_QUERY
+        );
+        let mirror = sqlx::query_as::<_, RepositoryMirror>(&query)
+            .bind(id)
+            .bind(status)
+            .bind(error)
+            .fetch_one(&self.pool)
+            .await?;
+
+        sqlx::query(
+            r#"
+            DELETE FROM core.repository_mirror_runs
+            WHERE mirror_id = $1 AND id NOT IN (
+                SELECT id FROM core.repository_mirror_runs
+                WHERE mirror_id = $1
+                ORDER BY finished_at DESC
+                LIMIT $2
+            )
+            "#,
+        )
+        .bind(id)
+        .bind(RUN_HISTORY_LIMIT)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(mirror)
+    }
+
+    async fn list_runs(
+        &self,
+        mirror_id: Uuid,
+        limit: i64,
+    ) -> Result<Vec<RepositoryMirrorRun>, DatabaseError> {
+        let runs = sqlx::query_as::<_, RepositoryMirrorRun>(
+            r#"
+            SELECT id, mirror_id, status, error, started_at, finished_at
+            FROM core.repository_mirror_runs
+            WHERE mirror_id = $1
+            ORDER BY finished_at DESC
+            LIMIT $2
+            "#,
+        )
+        .bind(mirror_id)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(runs)
+    }
+}
+
+#[cfg(all(test, feature = "db-tests"))]
+mod tests {
+    use std::time::Duration;
+
+    use sqlx::PgPool;
+    use uuid::Uuid;
+
+    use super::{MirrorRepository, PgMirrorRepository};
+    use crate::{
+        model::{MirrorDirection, MirrorRunStatus},
+        repository::test_common::{insert_user, insert_user_repo},
+    };
+
+    const HOUR: Duration = Duration::from_secs(3600);
+
+    #[sqlx::test]
+    async fn claims_requested_and_due_mirrors_and_records_runs(pool: PgPool) {
+        let repo = PgMirrorRepository::new(pool.clone());
+        let alice = Uuid::new_v4();
+        insert_user(&pool, alice, "alice").await;
+        let repository_id = Uuid::new_v4();
+        insert_user_repo(&pool, repository_id, "demo", alice, "public").await;
+
+        let pull = repo
+            .create(
+                repository_id,
+                MirrorDirection::Pull,
+                "https://example.com/demo.git",
+                None,
+                None,
+                Some(3600),
+            )
+            .await
+            .unwrap();
+        let push = repo
+            .create(
+                repository_id,
+                MirrorDirection::Push,
+                "https://example.com/out.git",
+                Some("bot".to_string()),
+                Some(vec![1, 2, 3]),
+                None,
+            )
+            .await
+            .unwrap();
+        assert_eq!(pull.owner_name, "alice");
+        assert!(!pull.was_readonly);
+
+        // The repository's read-only flag is recorded when a mirror is added.
+        sqlx::query("UPDATE core.repositories SET readonly = TRUE WHERE id = $1")
+            .bind(repository_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        let readonly = repo
+            .create(
+                repository_id,
+                MirrorDirection::Push,
+                "https://example.com/backup.git",
+                None,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+        assert!(readonly.was_readonly);
+        assert!(repo.delete(repository_id, readonly.id).await.unwrap());
+
+        // Both are requested on creation.
+        let claimed = repo.claim_due(HOUR, 10).await.unwrap();
+        assert_eq!(claimed.len(), 2);
+        assert!(repo.claim_due(HOUR, 10).await.unwrap().is_empty());
+
+        let finished = repo
+            .finish(pull.id, MirrorRunStatus::Success, None)
+            .await
+            .unwrap();
+        assert!(finished.last_synced_at.is_some());
+        let failed = repo
+            .finish(
+                push.id,
+                MirrorRunStatus::Failure,
+                Some("denied".to_string()),
+            )
+            .await
+            .unwrap();
+        assert!(failed.last_synced_at.is_none());
+        assert_eq!(failed.last_error.as_deref(), Some("denied"));
+
+        // A push only re-requests push mirrors, and the pull mirror is not
+        // due again before its interval.
+        repo.request_push(repository_id).await.unwrap();
+        let claimed = repo.claim_due(HOUR, 10).await.unwrap();
+        assert_eq!(claimed.len(), 1);
+        assert_eq!(claimed[0].id, push.id);
+
+        let runs = repo.list_runs(push.id, 10).await.unwrap();
+        assert_eq!(runs.len(), 1);
+        assert_eq!(runs[0].status, MirrorRunStatus::Failure);
+
+        assert!(repo.delete(repository_id, pull.id).await.unwrap());
+        assert!(repo.get(repository_id, pull.id).await.unwrap().is_none());
+        assert_eq!(repo.list(repository_id).await.unwrap().len(), 1);
+    }
+}
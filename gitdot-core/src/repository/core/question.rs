@@ -5,7 +5,7 @@ use uuid::Uuid;
 use crate::{
     dto::Cursor,
     error::DatabaseError,
-    model::{Answer, Comment, Question, VoteResult, VoteTarget},
+    model::{Answer, Comment, ImportedQuestion, Question, VoteResult, VoteTarget},
 };
 
 const QUESTION_DETAILS_QUERY: &str = r#"
@@ -207,6 +207,16 @@ pub trait QuestionRepository: Send + Sync + Clone + 'static {
         body: &str,
     ) -> Result<Question, DatabaseError>;
 
+    /// Inserts an imported question with its answers and their comments in
+    /// one transaction, keeping the origin `number` and timestamps. Returns the
+    /// new question id, or `Ok(None)` (inserting nothing) if the repository
+    /// already has a question with that number.
+    async fn import_question(
+        &self,
+        repository_id: Uuid,
+        question: &ImportedQuestion,
+    ) -> Result<Option<Uuid>, DatabaseError>;
+
     /// Updates a question's `title`/`body` and `updated_at` by
     /// `(repository_id, number)`. Returns the updated row (nested fields
     /// `NULL`), or `Ok(None)` if no such question.
@@ -374,6 +384,71 @@ impl QuestionRepository for PgQuestionRepository {
         Ok(question)
     }
 
+    async fn import_question(
+        &self,
+        repository_id: Uuid,
+        question: &ImportedQuestion,
+    ) -> Result<Option<Uuid>, DatabaseError> {
+        let mut tx = self.pool.begin().await?;
+
+        let question_id = sqlx::query_scalar::<_, Uuid>(
+            r#"
+            INSERT INTO core.questions (number, author_id, repository_id, title, body, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            ON CONFLICT (repository_id, number) DO NOTHING
+            RETURNING id
+            "#,
+        )
+        .bind(question.number)
+        .bind(question.author_id)
+        .bind(repository_id)
+        .bind(&question.title)
+        .bind(&question.body)
+        .bind(question.created_at)
+        .bind(question.updated_at)
+        .fetch_optional(&mut *tx)
+        .await?;
+        let Some(question_id) = question_id else {
+            return Ok(None);
+        };
+
+        for answer in &question.answers {
+            let answer_id = sqlx::query_scalar::<_, Uuid>(
+                r#"
+                INSERT INTO core.answers (question_id, author_id, body, created_at, updated_at)
+                VALUES ($1, $2, $3, $4, $5)
+                RETURNING id
+                "#,
+            )
+            .bind(question_id)
+            .bind(answer.author_id)
+            .bind(&answer.body)
+            .bind(answer.created_at)
+            .bind(answer.updated_at)
+            .fetch_one(&mut *tx)
+            .await?;
+
+            for comment in &answer.comments {
+                sqlx::query(
+                    r#"
+                    INSERT INTO core.comments (parent_id, author_id, body, created_at, updated_at)
+                    VALUES ($1, $2, $3, $4, $5)
+                    "#,
+                )
+                .bind(answer_id)
+                .bind(comment.author_id)
+                .bind(&comment.body)
+                .bind(comment.created_at)
+                .bind(comment.updated_at)
+                .execute(&mut *tx)
+                .await?;
+            }
+        }
+
+        tx.commit().await?;
+        Ok(Some(question_id))
+    }
+
     async fn update_question(
         &self,
         repository_id: Uuid,
@@ -754,3 +829,77 @@ impl QuestionRepository for PgQuestionRepository {
         })
     }
 }
+
+#[cfg(all(test, feature = "db-tests"))]
+mod tests {
+    use chrono::{DateTime, Duration};
+    use sqlx::PgPool;
+    use uuid::Uuid;
+
+    use super::{PgQuestionRepository, QuestionRepository};
+    use crate::{
+        model::{ImportedAnswer, ImportedComment, ImportedQuestion},
+        repository::test_common::{insert_user, insert_user_repo},
+    };
+
+    #[sqlx::test]
+    async fn import_question_keeps_origin_number_and_skips_existing(pool: PgPool) {
+        let repo = PgQuestionRepository::new(pool.clone());
+        let alice = Uuid::new_v4();
+        let repository_id = Uuid::new_v4();
+        insert_user(&pool, alice, "alice").await;
+        insert_user_repo(&pool, repository_id, "repo", alice, "public").await;
+
+        let asked_at = DateTime::UNIX_EPOCH + Duration::days(1);
+        let question = ImportedQuestion {
+            number: 7,
+            author_id: alice,
+            title: "How do I build it?".to_string(),
+            body: "Details".to_string(),
+            created_at: asked_at,
+            updated_at: asked_at,
+            answers: vec![ImportedAnswer {
+                author_id: alice,
+                body: "Run make.".to_string(),
+                created_at: asked_at + Duration::hours(1),
+                updated_at: asked_at + Duration::hours(1),
+                comments: vec![ImportedComment {
+                    author_id: alice,
+                    body: "Thanks!".to_string(),
+                    created_at: asked_at + Duration::hours(2),
+                    updated_at: asked_at + Duration::hours(2),
+                }],
+            }],
+        };
+
+        let id = repo
+            .import_question(repository_id, &question)
+            .await
+            .unwrap();
+        assert!(id.is_some());
+        assert_eq!(
+            repo.import_question(repository_id, &question)
+                .await
+                .unwrap(),
+            None
+        );
+
+        let imported = repo
+            .get_question(repository_id, 7, None)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(Some(imported.id), id);
+        assert_eq!(imported.created_at, asked_at);
+        let answers = imported.answers.unwrap();
+        assert_eq!(answers.len(), 1);
+        assert_eq!(answers[0].body, "Run make.");
+        assert_eq!(answers[0].comments.as_ref().unwrap()[0].body, "Thanks!");
+
+        let next = repo
+            .create_question(alice, repository_id, "Next", "")
+            .await
+            .unwrap();
+        assert_eq!(next.number, 8);
+    }
+}
@@ -0,0 +1,312 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{
+    error::DatabaseError,
+    model::{Release, ReleaseAsset},
+};
+
+/// sqlx data-access layer for `core.releases` and their files in
+/// `core.release_assets`. Release reads join in the author's name.
+#[async_trait]
+pub trait ReleaseRepository: Send + Sync + Clone + 'static {
+    /// Inserts a release for `tag_name`, stamping `published_at` unless it is
+    /// a draft. Fails with a unique violation if the tag already has one.
+    #[allow(clippy::too_many_arguments)]
+    async fn create(
+        &self,
+        repository_id: Uuid,
+        tag_name: &str,
+        name: &str,
+        body: &str,
+        draft: bool,
+        prerelease: bool,
+        author_id: Uuid,
+    ) -> Result<Release, DatabaseError>;
+
+    /// Returns the release for `tag_name`, or `Ok(None)` if it has none.
+    async fn get_by_tag(
+        &self,
+        repository_id: Uuid,
+        tag_name: &str,
+    ) -> Result<Option<Release>, DatabaseError>;
+
+    /// Lists the repository's releases, newest first. Drafts are only included
+    /// when `include_drafts` is set.
+    async fn list(
+        &self,
+        repository_id: Uuid,
+        include_drafts: bool,
+    ) -> Result<Vec<Release>, DatabaseError>;
+
+    /// Updates the given fields, leaving `None` ones as they are, and bumps
+    /// `updated_at`. `published_at` is stamped the first time the release
+    /// leaves draft. Returns `Ok(None)` if no release has `id`.
+    async fn update(
+        &self,
+        id: Uuid,
+        name: Option<String>,
+        body: Option<String>,
+        draft: Option<bool>,
+        prerelease: Option<bool>,
+    ) -> Result<Option<Release>, DatabaseError>;
+
+    /// Deletes a release together with its asset rows. Returns whether it
+    /// existed.
+    async fn delete(&self, id: Uuid) -> Result<bool, DatabaseError>;
+
+    /// Inserts an asset row for an object already stored at `object_key`.
+    /// Fails with a unique violation if the release has an asset of that name.
+    async fn create_asset(
+        &self,
+        release_id: Uuid,
+        name: &str,
+        content_type: &str,
+        object_key: &str,
+        size_bytes: i64,
+        uploader_id: Uuid,
+    ) -> Result<ReleaseAsset, DatabaseError>;
+
+    /// Returns the release's asset named `name`, or `Ok(None)`.
+    async fn get_asset(
+        &self,
+        release_id: Uuid,
+        name: &str,
+    ) -> Result<Option<ReleaseAsset>, DatabaseError>;
+
+    /// Lists the assets of every release in `release_ids`, oldest first.
+    async fn list_assets(&self, release_ids: &[Uuid]) -> Result<Vec<ReleaseAsset>, DatabaseError>;
+
+    /// Deletes an asset row. Returns whether it existed.
+    async fn delete_asset(&self, id: Uuid) -> Result<bool, DatabaseError>;
+}
+
+#[derive(Debug, Clone)]
+pub struct PgReleaseRepository {
+    pool: PgPool,
+}
+
+impl PgReleaseRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[crate::instrument_all(level = "debug")]
+#[async_trait]
+impl ReleaseRepository for PgReleaseRepository {
+    #[allow(clippy::too_many_arguments)]
+    async fn create(
+        &self,
+        repository_id: Uuid,
+        tag_name: &str,
+        name: &str,
+        body: &str,
+        draft: bool,
+        prerelease: bool,
+        author_id: Uuid,
+    ) -> Result<Release, DatabaseError> {
+        let release = sqlx::query_as::<_, Release>(
+            r#"
+            WITH rel AS (
+                INSERT INTO core.releases
+                    (repository_id, tag_name, name, body, draft, prerelease, author_id, published_at)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, CASE WHEN $5 THEN NULL ELSE NOW() END)
+                RETURNING *
+            )
+            SELECT rel.id, rel.repository_id, rel.tag_name, rel.name, rel.body, rel.draft,
+                   rel.prerelease, rel.author_id, u.name AS author_name, rel.created_at,
+                   rel.updated_at, rel.published_at
+            FROM rel
+            LEFT JOIN core.users u ON u.id = rel.author_id
+            "#,
+        )
+        .bind(repository_id)
+        .bind(tag_name)
+        .bind(name)
+        .bind(body)
+        .bind(draft)
+        .bind(prerelease)
+        .bind(author_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(release)
+    }
+
+    async fn get_by_tag(
+        &self,
+        repository_id: Uuid,
+        tag_name: &str,
+    ) -> Result<Option<Release>, DatabaseError> {
+        let release = sqlx::query_as::<_, Release>(
+            r#"
+            SELECT rel.id, rel.repository_id, rel.tag_name, rel.name, rel.body, rel.draft,
+                   rel.prerelease, rel.author_id, u.name AS author_name, rel.created_at,
+                   rel.updated_at, rel.published_at
+            FROM core.releases rel
+            LEFT JOIN core.users u ON u.id = rel.author_id
+            WHERE rel.repository_id = $1 AND rel.tag_name = $2
+            "#,
+        )
+        .bind(repository_id)
+        .bind(tag_name)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(release)
+    }
+
+    async fn list(
+        &self,
+        repository_id: Uuid,
+        include_drafts: bool,
+    ) -> Result<Vec<Release>, DatabaseError> {
+        let releases = sqlx::query_as::<_, Release>(
+            r#"
+            SELECT rel.id, rel.repository_id, rel.tag_name, rel.name, rel.body, rel.draft,
+                   rel.prerelease, rel.author_id, u.name AS author_name, rel.created_at,
+                   rel.updated_at, rel.published_at
+            FROM core.releases rel
+            LEFT JOIN core.users u ON u.id = rel.author_id
+            WHERE rel.repository_id = $1 AND ($2 OR NOT rel.draft)
+            ORDER BY COALESCE(rel.published_at, rel.created_at) DESC, rel.id DESC
+            "#,
+        )
+        .bind(repository_id)
+        .bind(include_drafts)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(releases)
+    }
+
+    async fn update(
+        &self,
+        id: Uuid,
+        name: Option<String>,
+        body: Option<String>,
+        draft: Option<bool>,
+        prerelease: Option<bool>,
+    ) -> Result<Option<Release>, DatabaseError> {
+        let release = sqlx::query_as::<_, Release>(
+            r#"
+            WITH rel AS (
+                UPDATE core.releases r
+                SET name         = COALESCE($2, r.name),
+                    body         = COALESCE($3, r.body),
+                    draft        = COALESCE($4, r.draft),
+                    prerelease   = COALESCE($5, r.prerelease),
+                    published_at = CASE
+                        WHEN COALESCE($4, r.draft) THEN r.published_at
+                        ELSE COALESCE(r.published_at, NOW())
+                    END,
+                    updated_at   = NOW()
+                WHERE r.id = $1
+                RETURNING r.*
+            )
+            SELECT rel.id, rel.repository_id, rel.tag_name, rel.name, rel.body, rel.draft,
+                   rel.prerelease, rel.author_id, u.name AS author_name, rel.created_at,
+                   rel.updated_at, rel.published_at
+            FROM rel
+            LEFT JOIN core.users u ON u.id = rel.author_id
+            "#,
+        )
+        .bind(id)
+        .bind(name)
+        .bind(body)
+        .bind(draft)
+        .bind(prerelease)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(release)
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<bool, DatabaseError> {
+        let result = sqlx::query("DELETE FROM core.releases WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn create_asset(
+        &self,
+        release_id: Uuid,
+        name: &str,
+        content_type: &str,
+        object_key: &str,
+        size_bytes: i64,
+        uploader_id: Uuid,
+    ) -> Result<ReleaseAsset, DatabaseError> {
+        let asset = sqlx::query_as::<_, ReleaseAsset>(
+            r#"
+            INSERT INTO core.release_assets
+                (release_id, name, content_type, object_key, size_bytes, uploader_id)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            RETURNING id, release_id, name, content_type, object_key, size_bytes, uploader_id,
+                      created_at
+            "#,
+        )
+        .bind(release_id)
+        .bind(name)
+        .bind(content_type)
+        .bind(object_key)
+        .bind(size_bytes)
+        .bind(uploader_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(asset)
+    }
+
+    async fn get_asset(
+        &self,
+        release_id: Uuid,
+        name: &str,
+    ) -> Result<Option<ReleaseAsset>, DatabaseError> {
+        let asset = sqlx::query_as::<_, ReleaseAsset>(
+            r#"
+            SELECT id, release_id, name, content_type, object_key, size_bytes, uploader_id,
+                   created_at
+            FROM core.release_assets
+            WHERE release_id = $1 AND name = $2
+            "#,
+        )
+        .bind(release_id)
+        .bind(name)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(asset)
+    }
+
+    async fn list_assets(&self, release_ids: &[Uuid]) -> Result<Vec<ReleaseAsset>, DatabaseError> {
+        let assets = sqlx::query_as::<_, ReleaseAsset>(
+            r#"
+            SELECT id, release_id, name, content_type, object_key, size_bytes, uploader_id,
+                   created_at
+            FROM core.release_assets
+            WHERE release_id = ANY($1)
+            ORDER BY created_at, name
+            "#,
+        )
+        .bind(release_ids)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(assets)
+    }
+
+    async fn delete_asset(&self, id: Uuid) -> Result<bool, DatabaseError> {
+        let result = sqlx::query("DELETE FROM core.release_assets WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
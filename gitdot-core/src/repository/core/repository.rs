@@ -7,8 +7,8 @@ use crate::{
     dto::Cursor,
     error::DatabaseError,
     model::{
-        AuthProvider, CommitFilter, Repository, RepositoryOwnerType, RepositoryStar,
-        RepositoryVisibility, User, UserEmail,
+        AuthProvider, CommitFilter, Repository, RepositoryOwnerType, RepositoryRole,
+        RepositoryStar, RepositoryVisibility, User, UserEmail,
     },
 };
 
@@ -22,12 +22,16 @@ pub trait RepositoryRepository: Send + Sync + Clone + 'static {
     /// Inserts a row into `core.repositories` (defaulting `created_at` to
     /// `NOW()` when not supplied) and returns it with `owner_name` resolved via
     /// a `LEFT JOIN` against `core.users`/`core.organizations` and `user_star`
-    /// hard-coded to `false`.
+    /// hard-coded to `false`. For an organization-owned repo, `creator_id` is
+    /// granted [`RepositoryRole::Admin`] in `core.repository_collaborators` by
+    /// the same statement; it is ignored for user-owned repos.
+    #[allow(clippy::too_many_arguments)]
     async fn create(
         &self,
         name: &str,
         owner_id: Uuid,
         owner_type: &RepositoryOwnerType,
+        creator_id: Uuid,
         visibility: &RepositoryVisibility,
         description: Option<String>,
         readonly: bool,
@@ -58,11 +62,21 @@ pub trait RepositoryRepository: Send + Sync + Clone + 'static {
         viewer_id: Option<Uuid>,
     ) -> Result<Option<Repository>, DatabaseError>;
 
+    /// Returns the highest role `user_id` was granted on the repository, either
+    /// directly as a collaborator or through any of their teams, or `Ok(None)`
+    /// if they have no grant. Ownership and org membership are not considered.
+    async fn get_granted_role(
+        &self,
+        id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Option<RepositoryRole>, DatabaseError>;
+
     /// Lists repositories owned by the user/organization named `owner_name`,
     /// keyset-paginated newest-first (`ORDER BY created_at DESC, id DESC`).
     /// Visibility-gated to rows that are public, owned by `viewer_id` (for
-    /// user-owned repos), or owned by an org `viewer_id` is a member of (via
-    /// `core.organization_members`). Returns the page plus the next `Cursor`
+    /// user-owned repos), owned by an org `viewer_id` is a member of (via
+    /// `core.organization_members`), or granted to `viewer_id` as a
+    /// collaborator or through a team. Returns the page plus the next `Cursor`
     /// (`None` when no further rows remain).
     async fn list_by_owner(
         &self,
@@ -177,11 +191,13 @@ impl PgRepositoryRepository {
 #[crate::instrument_all(level = "debug")]
 #[async_trait]
 impl RepositoryRepository for PgRepositoryRepository {
+    #[allow(clippy::too_many_arguments)]
     async fn create(
         &self,
         name: &str,
         owner_id: Uuid,
         owner_type: &RepositoryOwnerType,
+        creator_id: Uuid,
         visibility: &RepositoryVisibility,
         description: Option<String>,
         readonly: bool,
@@ -193,6 +209,10 @@ impl RepositoryRepository for PgRepositoryRepository {
                 INSERT INTO core.repositories (name, owner_id, owner_type, visibility, description, readonly, created_at)
                 VALUES ($1, $2, $3, $4, $5, $6, COALESCE($7, NOW()))
                 RETURNING id, name, owner_id, owner_type, visibility, description, stars, readonly, created_at
+            ),
+            creator AS (
+                INSERT INTO core.repository_collaborators (repository_id, user_id, role)
+                SELECT id, $8, 'admin' FROM inserted WHERE owner_type = 'organization'
             )
             SELECT i.id, i.name, i.owner_id, COALESCE(u.name, o.name) AS owner_name,
                    i.owner_type, i.visibility, i.description, i.stars, i.readonly, i.created_at,
@@ -211,6 +231,7 @@ impl RepositoryRepository for PgRepositoryRepository {
         .bind(description)
         .bind(readonly)
         .bind(created_at)
+        .bind(creator_id)
         .fetch_one(&self.pool)
         .await?;
 
@@ -297,6 +318,34 @@ impl RepositoryRepository for PgRepositoryRepository {
         Ok(repository)
     }
 
+    async fn get_granted_role(
+        &self,
+        id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Option<RepositoryRole>, DatabaseError> {
+        let role = sqlx::query_scalar::<_, Option<RepositoryRole>>(
+            r#"
+            SELECT MAX(grants.role)
+            FROM (
+                SELECT c.role
+                FROM core.repository_collaborators c
+                WHERE c.repository_id = $1 AND c.user_id = $2
+                UNION ALL
+                SELECT tr.role
+                FROM core.team_repositories tr
+                JOIN core.team_members tm ON tm.team_id = tr.team_id
+                WHERE tr.repository_id = $1 AND tm.user_id = $2
+            ) grants
+            "#,
+        )
+        .bind(id)
+        .bind(user_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(role)
+    }
+
     async fn list_by_owner(
         &self,
         owner_name: &str,
@@ -330,6 +379,15 @@ impl RepositoryRepository for PgRepositoryRepository {
                   OR (r.owner_type = 'user' AND r.owner_id = $5)
                   OR (r.owner_type = 'organization'
                       AND r.owner_id IN (SELECT organization_id FROM viewer_orgs))
+                  OR EXISTS(
+                      SELECT 1 FROM core.repository_collaborators c
+                      WHERE c.repository_id = r.id AND c.user_id = $5
+                  )
+                  OR EXISTS(
+                      SELECT 1 FROM core.team_repositories tr
+                      JOIN core.team_members tm ON tm.team_id = tr.team_id
+                      WHERE tr.repository_id = r.id AND tm.user_id = $5
+                  )
               )
               AND ($2::timestamptz IS NULL OR (r.created_at, r.id) < ($2, $3))
             ORDER BY r.created_at DESC, r.id DESC
@@ -702,11 +760,17 @@ mod tests {
         RepositoryVisibility,
     };
     use crate::{
-        model::OrganizationRole,
-        repository::test_common::{
-            insert_filter_at, insert_membership_at, insert_org, insert_org_repo, insert_star_at,
-            insert_user, insert_user_repo,
+        dto::{RepositoryAuthorizationRequest, RepositoryPermission},
+        error::AuthorizationError,
+        model::{OrganizationRole, RepositoryRole},
+        repository::{
+            PgOrganizationRepository, PgQuestionRepository, PgReviewRepository, PgUserRepository,
+            test_common::{
+                insert_filter_at, insert_membership_at, insert_org, insert_org_repo,
+                insert_star_at, insert_user, insert_user_repo,
+            },
         },
+        service::{AuthorizationService, AuthorizationServiceImpl},
     };
 
     // The common case: a user-owned, non-readonly repo with no description.
@@ -721,6 +785,7 @@ mod tests {
             name,
             owner,
             &RepositoryOwnerType::User,
+            owner,
             &visibility,
             None,
             false,
@@ -741,6 +806,7 @@ mod tests {
                 "proj",
                 alice,
                 &RepositoryOwnerType::User,
+                alice,
                 &RepositoryVisibility::Public,
                 Some("a project".to_string()),
                 false,
@@ -763,13 +829,16 @@ mod tests {
     async fn create_supports_organization_owner(pool: PgPool) {
         let repo = PgRepositoryRepository::new(pool.clone());
         let org = Uuid::new_v4();
+        let alice = Uuid::new_v4();
         insert_org(&pool, org, "acme").await;
+        insert_user(&pool, alice, "alice").await;
 
         let created = repo
             .create(
                 "proj",
                 org,
                 &RepositoryOwnerType::Organization,
+                alice,
                 &RepositoryVisibility::Public,
                 None,
                 false,
@@ -780,6 +849,62 @@ mod tests {
         // owner_name is resolved via the organizations join branch.
         assert_eq!(created.owner_name, "acme");
         assert_eq!(created.owner_type, RepositoryOwnerType::Organization);
+        assert_eq!(
+            repo.get_granted_role(created.id, alice).await.unwrap(),
+            Some(RepositoryRole::Admin)
+        );
+    }
+
+    #[sqlx::test]
+    async fn org_member_can_push_to_repo_they_create(pool: PgPool) {
+        let repo = PgRepositoryRepository::new(pool.clone());
+        let auth = AuthorizationServiceImpl::new(
+            PgOrganizationRepository::new(pool.clone()),
+            repo.clone(),
+            PgQuestionRepository::new(pool.clone()),
+            PgUserRepository::new(pool.clone()),
+            PgReviewRepository::new(pool.clone()),
+        );
+        let org = Uuid::new_v4();
+        let alice = Uuid::new_v4();
+        let bob = Uuid::new_v4();
+        insert_org(&pool, org, "acme").await;
+        insert_user(&pool, alice, "alice").await;
+        insert_user(&pool, bob, "bob").await;
+        for member in [alice, bob] {
+            insert_membership_at(&pool, member, org, OrganizationRole::Member, Utc::now()).await;
+        }
+
+        repo.create(
+            "proj",
+            org,
+            &RepositoryOwnerType::Organization,
+            alice,
+            &RepositoryVisibility::Private,
+            None,
+            false,
+            None,
+        )
+        .await
+        .unwrap();
+
+        let push = |user_id| {
+            RepositoryAuthorizationRequest::new(
+                Some(user_id),
+                "acme",
+                "proj",
+                RepositoryPermission::Write,
+            )
+            .unwrap()
+        };
+        auth.verify_authorized_for_repository(push(alice))
+            .await
+            .unwrap();
+        // Other members keep the read access membership alone grants.
+        assert!(matches!(
+            auth.verify_authorized_for_repository(push(bob)).await,
+            Err(AuthorizationError::Unauthorized)
+        ));
     }
 
     #[sqlx::test]
@@ -1010,6 +1135,7 @@ mod tests {
                 "proj",
                 alice,
                 &RepositoryOwnerType::User,
+                alice,
                 &RepositoryVisibility::Public,
                 Some("old".to_string()),
                 false,
@@ -1043,6 +1169,7 @@ mod tests {
                 "proj",
                 alice,
                 &RepositoryOwnerType::User,
+                alice,
                 &RepositoryVisibility::Public,
                 None,
                 true,
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use async_trait::async_trait;
 use sqlx::PgPool;
 use uuid::Uuid;
@@ -6,8 +8,8 @@ use crate::{
     dto::Cursor,
     error::DatabaseError,
     model::{
-        CommentSide, Diff, DiffStatus, Review, ReviewComment, ReviewStatus, Reviewer, Revision,
-        Verdict,
+        CommentSide, Diff, DiffStatus, ImportedReview, MergeRequirements, Review, ReviewComment,
+        ReviewStatus, Reviewer, Revision, Verdict,
     },
 };
 
@@ -51,6 +53,12 @@ SELECT
                                     'commit_hash', rev.commit_hash,
                                     'parent_hash', rev.parent_hash,
                                     'created_at', rev.created_at,
+                                    'signature_status', (
+                                        SELECT cm.signature_status
+                                        FROM core.commits cm
+                                        WHERE cm.repo_id = r.repository_id
+                                          AND cm.sha = rev.commit_hash
+                                    ),
                                     'verdicts', COALESCE(
                                         (
                                             SELECT json_agg(
@@ -193,6 +201,18 @@ pub trait ReviewRepository: Send + Sync + Clone + 'static {
         target_branch: &str,
     ) -> Result<Review, DatabaseError>;
 
+    /// Inserts an imported review in one transaction: the `core.reviews` row
+    /// with its origin `number` and timestamps, one diff per entry with a
+    /// single revision, reviewers, verdicts and threaded comments. Holds the
+    /// same advisory lock as [`create_review`](Self::create_review). Returns
+    /// the new review id, or `Ok(None)` (inserting nothing) if the repository
+    /// already has a review with that number.
+    async fn import_review(
+        &self,
+        repository_id: Uuid,
+        review: &ImportedReview,
+    ) -> Result<Option<Uuid>, DatabaseError>;
+
     /// Updates `core.reviews` row `review_id`, coalescing `status`, `title`,
     /// and `description` (only non-`None` args overwrite) and bumping
     /// `updated_at`.
@@ -302,6 +322,21 @@ pub trait ReviewRepository: Send + Sync + Clone + 'static {
     /// Sets `resolved` (and `updated_at`) on the `core.review_comments` row
     /// `comment_id` and all of its replies (`WHERE id = $1 OR parent_id = $1`).
     async fn resolve_comment(&self, comment_id: Uuid, resolved: bool) -> Result<(), DatabaseError>;
+
+    /// Returns the `core.review_merge_requirements` row for `repository_id`,
+    /// or `Ok(None)` if the repository has never configured one.
+    async fn get_merge_requirements(
+        &self,
+        repository_id: Uuid,
+    ) -> Result<Option<MergeRequirements>, DatabaseError>;
+
+    /// Inserts or overwrites (`ON CONFLICT (repository_id) DO UPDATE`) the
+    /// merge requirements for `repository_id`, bumping `updated_at`.
+    async fn upsert_merge_requirements(
+        &self,
+        repository_id: Uuid,
+        requirements: MergeRequirements,
+    ) -> Result<MergeRequirements, DatabaseError>;
 }
 
 #[derive(Debug, Clone)]
@@ -516,6 +551,144 @@ impl ReviewRepository for PgReviewRepository {
         Ok(review)
     }
 
+    async fn import_review(
+        &self,
+        repository_id: Uuid,
+        review: &ImportedReview,
+    ) -> Result<Option<Uuid>, DatabaseError> {
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query("SELECT pg_advisory_xact_lock(hashtext($1::text)::bigint)")
+            .bind(repository_id)
+            .execute(&mut *tx)
+            .await?;
+
+        let review_id = sqlx::query_scalar::<_, Uuid>(
+            r#"
+            INSERT INTO core.reviews (repository_id, number, author_id, title, description, target_branch, status, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            ON CONFLICT (repository_id, number) WHERE number <> -1 DO NOTHING
+            RETURNING id
+            "#,
+        )
+        .bind(repository_id)
+        .bind(review.number)
+        .bind(review.author_id)
+        .bind(&review.title)
+        .bind(&review.description)
+        .bind(&review.target_branch)
+        .bind(&review.status)
+        .bind(review.created_at)
+        .bind(review.updated_at)
+        .fetch_optional(&mut *tx)
+        .await?;
+        let Some(review_id) = review_id else {
+            return Ok(None);
+        };
+
+        let mut revisions = HashMap::new();
+        for diff in &review.diffs {
+            let diff_id = sqlx::query_scalar::<_, Uuid>(
+                r#"
+                INSERT INTO core.diffs (review_id, position, message, status, created_at, updated_at)
+                VALUES ($1, $2, $3, $4, $5, $5)
+                RETURNING id
+                "#,
+            )
+            .bind(review_id)
+            .bind(diff.position)
+            .bind(&diff.message)
+            .bind(&diff.status)
+            .bind(diff.created_at)
+            .fetch_one(&mut *tx)
+            .await?;
+
+            let revision_id = sqlx::query_scalar::<_, Uuid>(
+                r#"
+                INSERT INTO core.revisions (diff_id, number, commit_hash, parent_hash, created_at)
+                VALUES ($1, 1, $2, $3, $4)
+                RETURNING id
+                "#,
+            )
+            .bind(diff_id)
+            .bind(&diff.commit_hash)
+            .bind(&diff.parent_hash)
+            .bind(diff.created_at)
+            .fetch_one(&mut *tx)
+            .await?;
+
+            revisions.insert(diff.position, (diff_id, revision_id));
+        }
+        let revision_of = |position: i32| {
+            revisions
+                .get(&position)
+                .copied()
+                .ok_or(DatabaseError::RowNotFound)
+        };
+
+        for reviewer_id in &review.reviewer_ids {
+            sqlx::query(
+                r#"
+                INSERT INTO core.reviewers (review_id, reviewer_id)
+                VALUES ($1, $2)
+                ON CONFLICT (review_id, reviewer_id) DO NOTHING
+                "#,
+            )
+            .bind(review_id)
+            .bind(reviewer_id)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        for verdict in &review.verdicts {
+            let (diff_id, revision_id) = revision_of(verdict.diff_position)?;
+            sqlx::query(
+                r#"
+                INSERT INTO core.review_verdicts (diff_id, revision_id, reviewer_id, verdict, created_at)
+                VALUES ($1, $2, $3, $4, $5)
+                "#,
+            )
+            .bind(diff_id)
+            .bind(revision_id)
+            .bind(verdict.reviewer_id)
+            .bind(&verdict.verdict)
+            .bind(verdict.created_at)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        let mut comment_ids: Vec<Uuid> = Vec::with_capacity(review.comments.len());
+        for comment in &review.comments {
+            let (diff_id, revision_id) = revision_of(comment.diff_position)?;
+            let parent_id = comment.parent.and_then(|i| comment_ids.get(i).copied());
+            let comment_id = sqlx::query_scalar::<_, Uuid>(
+                r#"
+                INSERT INTO core.review_comments (review_id, diff_id, revision_id, author_id, body, parent_id, file_path, line_number_start, line_number_end, side, created_at, updated_at)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+                RETURNING id
+                "#,
+            )
+            .bind(review_id)
+            .bind(diff_id)
+            .bind(revision_id)
+            .bind(comment.author_id)
+            .bind(&comment.body)
+            .bind(parent_id)
+            .bind(&comment.file_path)
+            .bind(comment.line_number_start)
+            .bind(comment.line_number_end)
+            .bind(&comment.side)
+            .bind(comment.created_at)
+            .bind(comment.updated_at)
+            .fetch_one(&mut *tx)
+            .await?;
+            comment_ids.push(comment_id);
+        }
+
+        tx.commit().await?;
+        Ok(Some(review_id))
+    }
+
     async fn update_review(
         &self,
         review_id: Uuid,
@@ -815,4 +988,56 @@ impl ReviewRepository for PgReviewRepository {
 
         Ok(())
     }
+
+    async fn get_merge_requirements(
+        &self,
+        repository_id: Uuid,
+    ) -> Result<Option<MergeRequirements>, DatabaseError> {
+        let requirements = sqlx::query_as::<_, MergeRequirements>(
+            r#"
+            SELECT required_approvals, block_on_rejection, require_resolved_comments,
+                   require_green_build
+            FROM core.review_merge_requirements
+            WHERE repository_id = $1
+            "#,
+        )
+        .bind(repository_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(requirements)
+    }
+
+    async fn upsert_merge_requirements(
+        &self,
+        repository_id: Uuid,
+        requirements: MergeRequirements,
+    ) -> Result<MergeRequirements, DatabaseError> {
+        let requirements = sqlx::query_as::<_, MergeRequirements>(
+            r#"
+            INSERT INTO core.review_merge_requirements (
+                repository_id, required_approvals, block_on_rejection,
+                require_resolved_comments, require_green_build
+            )
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (repository_id) DO UPDATE
+            SET required_approvals = EXCLUDED.required_approvals,
+                block_on_rejection = EXCLUDED.block_on_rejection,
+                require_resolved_comments = EXCLUDED.require_resolved_comments,
+                require_green_build = EXCLUDED.require_green_build,
+                updated_at = NOW()
+            RETURNING required_approvals, block_on_rejection, require_resolved_comments,
+                      require_green_build
+            "#,
+        )
+        .bind(repository_id)
+        .bind(requirements.required_approvals)
+        .bind(requirements.block_on_rejection)
+        .bind(requirements.require_resolved_comments)
+        .bind(requirements.require_green_build)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(requirements)
+    }
 }
@@ -0,0 +1,333 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{
+    error::DatabaseError,
+    model::{RepositoryRole, Team, TeamMember, TeamRepositoryAccess},
+};
+
+/// sqlx data-access layer for organization teams.
+///
+/// Owns `core.teams`, `core.team_members` and `core.team_repositories`,
+/// joining `core.users` and `core.repositories` to project member and
+/// repository names.
+#[async_trait]
+pub trait TeamRepository: Send + Sync + Clone + 'static {
+    /// Inserts a team and returns it with `members` and `repositories`
+    /// projected as `NULL`.
+    async fn create(
+        &self,
+        organization_id: Uuid,
+        name: &str,
+        description: Option<String>,
+    ) -> Result<Team, DatabaseError>;
+
+    /// Returns the team by name with its `members` (ordered by user name) and
+    /// `repositories` (ordered by repository name) JSON arrays populated, or
+    /// `Ok(None)` if the organization has no such team.
+    async fn get(&self, organization_id: Uuid, name: &str) -> Result<Option<Team>, DatabaseError>;
+
+    /// Lists the organization's teams ordered by name, with `members` and
+    /// `repositories` projected as `NULL`.
+    async fn list(&self, organization_id: Uuid) -> Result<Vec<Team>, DatabaseError>;
+
+    /// Deletes a team along with its memberships and repository grants.
+    /// Returns whether a row was removed.
+    async fn delete(&self, id: Uuid) -> Result<bool, DatabaseError>;
+
+    /// Adds `user_id` to the team with `ON CONFLICT DO NOTHING`. Returns the
+    /// new member, or `Ok(None)` if they already belong to the team.
+    async fn add_member(
+        &self,
+        team_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Option<TeamMember>, DatabaseError>;
+
+    /// Removes `user_id` from the team. Returns whether a row was removed.
+    async fn remove_member(&self, team_id: Uuid, user_id: Uuid) -> Result<bool, DatabaseError>;
+
+    /// Grants the team `role` on a repository, replacing the role of an
+    /// existing grant.
+    async fn set_repository(
+        &self,
+        team_id: Uuid,
+        repository_id: Uuid,
+        role: RepositoryRole,
+    ) -> Result<TeamRepositoryAccess, DatabaseError>;
+
+    /// Revokes the team's grant on a repository. Returns whether a row was
+    /// removed.
+    async fn remove_repository(
+        &self,
+        team_id: Uuid,
+        repository_id: Uuid,
+    ) -> Result<bool, DatabaseError>;
+}
+
+#[derive(Debug, Clone)]
+pub struct PgTeamRepository {
+    pool: PgPool,
+}
+
+impl PgTeamRepository {
+    pub fn new(pool: PgPool) -> PgTeamRepository {
+        PgTeamRepository { pool }
+    }
+}
+
+#[crate::instrument_all(level = "debug")]
+#[async_trait]
+impl TeamRepository for PgTeamRepository {
+    async fn create(
+        &self,
+        organization_id: Uuid,
+        name: &str,
+        description: Option<String>,
+    ) -> Result<Team, DatabaseError> {
+        let team = sqlx::query_as::<_, Team>(
+            r#"
+            INSERT INTO core.teams (organization_id, name, description)
+            VALUES ($1, $2, $3)
+            RETURNING id, organization_id, name, description, created_at,
+                      NULL::json AS members, NULL::json AS repositories
+            "#,
+        )
+        .bind(organization_id)
+        .bind(name)
+        .bind(description)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(team)
+    }
+
+    async fn get(&self, organization_id: Uuid, name: &str) -> Result<Option<Team>, DatabaseError> {
+        let team = sqlx::query_as::<_, Team>(
+            r#"
+            SELECT
+                t.id, t.organization_id, t.name, t.description, t.created_at,
+                COALESCE(
+                    (
+                        SELECT json_agg(
+                            json_build_object(
+                                'team_id', tm.team_id,
+                                'user_id', tm.user_id,
+                                'user_name', u.name,
+                                'created_at', tm.created_at,
+                                'image_updated_at', u.image_updated_at
+                            ) ORDER BY u.name
+                        )
+                        FROM core.team_members tm
+                        JOIN core.users u ON u.id = tm.user_id
+                        WHERE tm.team_id = t.id
+                    ),
+                    '[]'::json
+                ) AS members,
+                COALESCE(
+                    (
+                        SELECT json_agg(
+                            json_build_object(
+                                'team_id', tr.team_id,
+                                'repository_id', tr.repository_id,
+                                'repository_name', r.name,
+                                'role', tr.role,
+                                'created_at', tr.created_at
+                            ) ORDER BY r.name
+                        )
+                        FROM core.team_repositories tr
+                        JOIN core.repositories r ON r.id = tr.repository_id
+                        WHERE tr.team_id = t.id
+                    ),
+                    '[]'::json
+                ) AS repositories
+            FROM core.teams t
+            WHERE t.organization_id = $1 AND t.name = $2
+            "#,
+        )
+        .bind(organization_id)
+        .bind(name)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(team)
+    }
+
+    async fn list(&self, organization_id: Uuid) -> Result<Vec<Team>, DatabaseError> {
+        let teams = sqlx::query_as::<_, Team>(
+            r#"
+            SELECT id, organization_id, name, description, created_at,
+                   NULL::json AS members, NULL::json AS repositories
+            FROM core.teams
+            WHERE organization_id = $1
+            ORDER BY name
+            "#,
+        )
+        .bind(organization_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(teams)
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<bool, DatabaseError> {
+        let result = sqlx::query("DELETE FROM core.teams WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn add_member(
+        &self,
+        team_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Option<TeamMember>, DatabaseError> {
+        let member = sqlx::query_as::<_, TeamMember>(
+            r#"
+            WITH inserted AS (
+                INSERT INTO core.team_members (team_id, user_id)
+                VALUES ($1, $2)
+                ON CONFLICT (team_id, user_id) DO NOTHING
+                RETURNING team_id, user_id, created_at
+            )
+            SELECT i.team_id, i.user_id, u.name AS user_name, i.created_at, u.image_updated_at
+            FROM inserted i
+            JOIN core.users u ON u.id = i.user_id
+            "#,
+        )
+        .bind(team_id)
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(member)
+    }
+
+    async fn remove_member(&self, team_id: Uuid, user_id: Uuid) -> Result<bool, DatabaseError> {
+        let result =
+            sqlx::query("DELETE FROM core.team_members WHERE team_id = $1 AND user_id = $2")
+                .bind(team_id)
+                .bind(user_id)
+                .execute(&self.pool)
+                .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn set_repository(
+        &self,
+        team_id: Uuid,
+        repository_id: Uuid,
+        role: RepositoryRole,
+    ) -> Result<TeamRepositoryAccess, DatabaseError> {
+        let access = sqlx::query_as::<_, TeamRepositoryAccess>(
+            r#"
+            WITH upserted AS (
+                INSERT INTO core.team_repositories (team_id, repository_id, role)
+                VALUES ($1, $2, $3)
+                ON CONFLICT (team_id, repository_id) DO UPDATE SET role = EXCLUDED.role
+                RETURNING team_id, repository_id, role, created_at
+            )
+            SELECT up.team_id, up.repository_id, r.name AS repository_name, up.role, up.created_at
+            FROM upserted up
+            JOIN core.repositories r ON r.id = up.repository_id
+            "#,
+        )
+        .bind(team_id)
+        .bind(repository_id)
+        .bind(role)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(access)
+    }
+
+    async fn remove_repository(
+        &self,
+        team_id: Uuid,
+        repository_id: Uuid,
+    ) -> Result<bool, DatabaseError> {
+        let result = sqlx::query(
+            "DELETE FROM core.team_repositories WHERE team_id = $1 AND repository_id = $2",
+        )
+        .bind(team_id)
+        .bind(repository_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+#[cfg(all(test, feature = "db-tests"))]
+mod tests {
+    use sqlx::PgPool;
+    use uuid::Uuid;
+
+    use super::{PgTeamRepository, TeamRepository};
+    use crate::{
+        model::RepositoryRole,
+        repository::{
+            PgRepositoryRepository, RepositoryRepository,
+            test_common::{insert_org, insert_org_repo, insert_user},
+        },
+    };
+
+    #[sqlx::test]
+    async fn team_grants_reach_members(pool: PgPool) {
+        let teams = PgTeamRepository::new(pool.clone());
+        let repositories = PgRepositoryRepository::new(pool.clone());
+        let alice = Uuid::new_v4();
+        let org_id = Uuid::new_v4();
+        let repo_id = Uuid::new_v4();
+        insert_user(&pool, alice, "alice").await;
+        insert_org(&pool, org_id, "acme").await;
+        insert_org_repo(&pool, repo_id, "api", org_id, "private").await;
+
+        let team = teams
+            .create(org_id, "backend", Some("Backend team".to_string()))
+            .await
+            .unwrap();
+        assert!(teams.add_member(team.id, alice).await.unwrap().is_some());
+        assert!(teams.add_member(team.id, alice).await.unwrap().is_none());
+        teams
+            .set_repository(team.id, repo_id, RepositoryRole::Read)
+            .await
+            .unwrap();
+        let access = teams
+            .set_repository(team.id, repo_id, RepositoryRole::Maintain)
+            .await
+            .unwrap();
+        assert_eq!(access.repository_name, "api");
+
+        let team = teams.get(org_id, "backend").await.unwrap().unwrap();
+        assert_eq!(team.members.unwrap()[0].user_name, "alice");
+        assert_eq!(team.repositories.unwrap()[0].role, RepositoryRole::Maintain);
+        assert_eq!(
+            repositories.get_granted_role(repo_id, alice).await.unwrap(),
+            Some(RepositoryRole::Maintain)
+        );
+
+        // The private org repo is listed for a team member who is not an org
+        // member.
+        let (rows, _) = repositories
+            .list_by_owner("acme", Some(alice), None, 20)
+            .await
+            .unwrap();
+        assert_eq!(rows.len(), 1);
+
+        assert!(teams.remove_member(team.id, alice).await.unwrap());
+        assert!(
+            repositories
+                .get_granted_role(repo_id, alice)
+                .await
+                .unwrap()
+                .is_none()
+        );
+
+        assert!(teams.delete(team.id).await.unwrap());
+        assert!(teams.list(org_id).await.unwrap().is_empty());
+    }
+}
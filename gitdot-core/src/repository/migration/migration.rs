@@ -7,11 +7,35 @@ use crate::{
     dto::Cursor,
     error::DatabaseError,
     model::{
-        Migration, MigrationOriginService, MigrationRepository as MigrationRepositoryModel,
-        MigrationRepositoryStatus, MigrationStatus, RepositoryOwnerType, RepositoryVisibility,
+        Migration, MigrationMirror, MigrationOriginService,
+        MigrationRepository as MigrationRepositoryModel, MigrationRepositoryStatus,
+        MigrationStatus, MirrorCutover, RepositoryOwnerType, RepositoryVisibility,
     },
 };
 
+/// Resolves each read-only destination to its owner name and the newest
+/// installation of the migration's GitHub origin by the migration's author.
+const MIRROR_QUERY: &str = r#"
+    SELECT mr.id AS migration_repository_id, mr.origin_full_name,
+           gi.installation_id, r.id AS repository_id,
+           COALESCE(u.name, o.name) AS owner_name, r.name AS repository_name,
+           ms.status AS sync_status, ms.error AS sync_error,
+           ms.last_attempted_at, ms.last_synced_at
+    FROM migration.migration_repositories mr
+    JOIN migration.migrations m ON m.id = mr.migration_id
+    JOIN core.repositories r ON r.id = mr.destination_repository_id
+    LEFT JOIN core.users u ON r.owner_id = u.id AND r.owner_type = 'user'
+    LEFT JOIN core.organizations o ON r.owner_id = o.id AND r.owner_type = 'organization'
+    LEFT JOIN LATERAL (
+        SELECT installation_id
+        FROM migration.github_installations
+        WHERE owner_id = m.author_id AND github_login = m.origin
+        ORDER BY created_at DESC
+        LIMIT 1
+    ) gi ON TRUE
+    LEFT JOIN migration.mirror_syncs ms ON ms.migration_repository_id = mr.id
+"#;
+
 /// sqlx data-access layer for the `migration.migrations` and
 /// `migration.migration_repositories` tables (repository-import jobs and their
 /// per-repository child rows).
@@ -78,6 +102,15 @@ pub trait MigrationRepository: Send + Sync + Clone + 'static {
         error: Option<&str>,
     ) -> Result<MigrationRepositoryModel, DatabaseError>;
 
+    /// Records how many questions and reviews have been imported so far for a
+    /// migration-repository row (setting `updated_at = NOW()`).
+    async fn update_import_progress(
+        &self,
+        id: Uuid,
+        imported_questions: i32,
+        imported_reviews: i32,
+    ) -> Result<(), DatabaseError>;
+
     /// Sets the `destination_repository_id` (and `updated_at = NOW()`) of a
     /// migration-repository row, linking it to the created gitdot repository.
     async fn set_destination_repository_id(
@@ -93,6 +126,61 @@ pub trait MigrationRepository: Send + Sync + Clone + 'static {
         &self,
         origin_repository_id: i64,
     ) -> Result<Vec<MigrationRepositoryModel>, DatabaseError>;
+
+    /// Lists up to `limit` read-only mirrors whose last sync attempt is older
+    /// than `attempted_before` (or that were never synced), least recently
+    /// attempted first.
+    async fn list_due_mirrors(
+        &self,
+        attempted_before: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<MigrationMirror>, DatabaseError>;
+
+    /// Returns the mirror whose destination is `repository_id`, or `Ok(None)`
+    /// if the repository was not migrated or is no longer read-only.
+    async fn get_mirror(
+        &self,
+        repository_id: Uuid,
+    ) -> Result<Option<MigrationMirror>, DatabaseError>;
+
+    /// Upserts the sync state of a mirror into `migration.mirror_syncs`. A
+    /// `None` error marks it synced and stamps `last_synced_at`; an error marks
+    /// it failed and keeps the previous `last_synced_at`.
+    async fn record_mirror_sync(
+        &self,
+        migration_repository_id: Uuid,
+        error: Option<&str>,
+    ) -> Result<(), DatabaseError>;
+
+    /// Turns the read-only mirror into a live repository in one transaction:
+    /// clears `readonly` on `repository_id`, optionally upserts a push mirror
+    /// to `push_mirror_url` queued for an immediate sync, and records the
+    /// cutover in `migration.mirror_cutovers`. Returns `Ok(None)`, changing
+    /// nothing, if the repository is no longer read-only.
+    #[allow(clippy::too_many_arguments)]
+    async fn cutover_mirror(
+        &self,
+        migration_repository_id: Uuid,
+        repository_id: Uuid,
+        actor_id: Uuid,
+        origin_full_name: &str,
+        push_mirror_url: Option<&str>,
+        push_mirror_username: Option<String>,
+        push_mirror_password_ciphertext: Option<Vec<u8>>,
+    ) -> Result<Option<MirrorCutover>, DatabaseError>;
+
+    /// Atomically claims up to `limit` cutovers whose commit backfill has not
+    /// finished and was last attempted before `attempted_before` (or never),
+    /// oldest first, stamping `backfill_attempted_at`. Rows locked by another
+    /// claimer are skipped.
+    async fn claim_cutover_backfills(
+        &self,
+        attempted_before: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<MirrorCutover>, DatabaseError>;
+
+    /// Stamps `backfilled_at` on the cutover, so it is no longer claimed.
+    async fn finish_cutover_backfill(&self, id: Uuid) -> Result<(), DatabaseError>;
 }
 
 #[derive(Debug, Clone)]
@@ -157,6 +245,8 @@ impl MigrationRepository for PgMigrationRepository {
                            'origin_created_at', mr.origin_created_at,
                            'status', mr.status,
                            'error', mr.error,
+                           'imported_questions', mr.imported_questions,
+                           'imported_reviews', mr.imported_reviews,
                            'created_at', mr.created_at,
                            'updated_at', mr.updated_at
                        ) ORDER BY mr.created_at ASC)
@@ -200,6 +290,8 @@ impl MigrationRepository for PgMigrationRepository {
                            'origin_created_at', mr.origin_created_at,
                            'status', mr.status,
                            'error', mr.error,
+                           'imported_questions', mr.imported_questions,
+                           'imported_reviews', mr.imported_reviews,
                            'created_at', mr.created_at,
                            'updated_at', mr.updated_at
                        ) ORDER BY mr.created_at ASC)
@@ -267,7 +359,7 @@ impl MigrationRepository for PgMigrationRepository {
             INSERT INTO migration.migration_repositories
                 (migration_id, origin_full_name, origin_repository_id, destination_full_name, visibility, origin_created_at)
             VALUES ($1, $2, $3, $4, $5, $6)
-            RETURNING id, migration_id, origin_full_name, origin_repository_id, destination_full_name, destination_repository_id, visibility, origin_created_at, status, error, created_at, updated_at
+            RETURNING id, migration_id, origin_full_name, origin_repository_id, destination_full_name, destination_repository_id, visibility, origin_created_at, status, error, imported_questions, imported_reviews, created_at, updated_at
             "#,
         )
         .bind(migration_id)
@@ -293,7 +385,7 @@ impl MigrationRepository for PgMigrationRepository {
             UPDATE migration.migration_repositories
             SET status = $2, error = $3, updated_at = NOW()
             WHERE id = $1
-            RETURNING id, migration_id, origin_full_name, origin_repository_id, destination_full_name, destination_repository_id, visibility, origin_created_at, status, error, created_at, updated_at
+            RETURNING id, migration_id, origin_full_name, origin_repository_id, destination_full_name, destination_repository_id, visibility, origin_created_at, status, error, imported_questions, imported_reviews, created_at, updated_at
             "#,
         )
         .bind(id)
@@ -305,6 +397,28 @@ impl MigrationRepository for PgMigrationRepository {
         Ok(repo)
     }
 
+    async fn update_import_progress(
+        &self,
+        id: Uuid,
+        imported_questions: i32,
+        imported_reviews: i32,
+    ) -> Result<(), DatabaseError> {
+        sqlx::query(
+            r#"
+            UPDATE migration.migration_repositories
+            SET imported_questions = $2, imported_reviews = $3, updated_at = NOW()
+            WHERE id = $1
+            "#,
+        )
+        .bind(id)
+        .bind(imported_questions)
+        .bind(imported_reviews)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
     async fn set_destination_repository_id(
         &self,
         migration_repository_id: Uuid,
@@ -333,7 +447,8 @@ impl MigrationRepository for PgMigrationRepository {
             r#"
             SELECT id, migration_id, origin_full_name, origin_repository_id,
                    destination_full_name, destination_repository_id, visibility,
-                   origin_created_at, status, error, created_at, updated_at
+                   origin_created_at, status, error, imported_questions, imported_reviews,
+                   created_at, updated_at
             FROM migration.migration_repositories
             WHERE origin_repository_id = $1 AND destination_repository_id IS NOT NULL
             "#,
@@ -344,6 +459,176 @@ impl MigrationRepository for PgMigrationRepository {
 
         Ok(rows)
     }
+
+    async fn list_due_mirrors(
+        &self,
+        attempted_before: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<MigrationMirror>, DatabaseError> {
+        let query = format!(
+            "{} WHERE r.readonly \
+              AND (ms.last_attempted_at IS NULL OR ms.last_attempted_at < $1) \
+            ORDER BY ms.last_attempted_at ASC NULLS FIRST, mr.id \
+            LIMIT $2",
+            MIRROR_QUERY
+        );
+        let mirrors = sqlx::query_as::<_, MigrationMirror>(&query)
+            .bind(attempted_before)
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(mirrors)
+    }
+
+    async fn get_mirror(
+        &self,
+        repository_id: Uuid,
+    ) -> Result<Option<MigrationMirror>, DatabaseError> {
+        let query = format!("{} WHERE r.readonly AND r.id = $1", MIRROR_QUERY);
+        let mirror = sqlx::query_as::<_, MigrationMirror>(&query)
+            .bind(repository_id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(mirror)
+    }
+
+    async fn record_mirror_sync(
+        &self,
+        migration_repository_id: Uuid,
+        error: Option<&str>,
+    ) -> Result<(), DatabaseError> {
+        sqlx::query(
+            r#"
+            INSERT INTO migration.mirror_syncs
+                (migration_repository_id, status, error, last_attempted_at, last_synced_at)
+            VALUES (
+                $1,
+                CASE WHEN $2::text IS NULL THEN 'synced' ELSE 'failed' END::migration.mirror_sync_status,
+                $2, NOW(),
+                CASE WHEN $2::text IS NULL THEN NOW() END
+            )
+            ON CONFLICT (migration_repository_id) DO UPDATE
+            SET status = EXCLUDED.status,
+                error = EXCLUDED.error,
+                last_attempted_at = EXCLUDED.last_attempted_at,
+                last_synced_at = COALESCE(EXCLUDED.last_synced_at, migration.mirror_syncs.last_synced_at)
+            "#,
+        )
+        .bind(migration_repository_id)
+        .bind(error)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn cutover_mirror(
+        &self,
+        migration_repository_id: Uuid,
+        repository_id: Uuid,
+        actor_id: Uuid,
+        origin_full_name: &str,
+        push_mirror_url: Option<&str>,
+        push_mirror_username: Option<String>,
+        push_mirror_password_ciphertext: Option<Vec<u8>>,
+    ) -> Result<Option<MirrorCutover>, DatabaseError> {
+        let mut tx = self.pool.begin().await?;
+
+        let cleared =
+            sqlx::query("UPDATE core.repositories SET readonly = FALSE WHERE id = $1 AND readonly")
+                .bind(repository_id)
+                .execute(&mut *tx)
+                .await?;
+        if cleared.rows_affected() == 0 {
+            return Ok(None);
+        }
+
+        let push_mirror_id = match push_mirror_url {
+            Some(url) => Some(
+                sqlx::query_scalar::<_, Uuid>(
+                    r#"
+                    INSERT INTO core.repository_mirrors
+                        (repository_id, direction, url, username, password_ciphertext, requested_at)
+                    VALUES ($1, 'push', $2, $3, $4, NOW())
+                    ON CONFLICT (repository_id, direction, url) DO UPDATE
+                    SET username = EXCLUDED.username,
+                        password_ciphertext = EXCLUDED.password_ciphertext,
+                        requested_at = COALESCE(core.repository_mirrors.requested_at, NOW())
+                    RETURNING id
+                    "#,
+                )
+                .bind(repository_id)
+                .bind(url)
+                .bind(push_mirror_username)
+                .bind(push_mirror_password_ciphertext)
+                .fetch_one(&mut *tx)
+                .await?,
+            ),
+            None => None,
+        };
+
+        let cutover = sqlx::query_as::<_, MirrorCutover>(
+            r#"
+            INSERT INTO migration.mirror_cutovers
+                (migration_repository_id, repository_id, actor_id, origin_full_name, push_mirror_id)
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING id, migration_repository_id, repository_id, actor_id, origin_full_name,
+                      push_mirror_id, created_at
+            "#,
+        )
+        .bind(migration_repository_id)
+        .bind(repository_id)
+        .bind(actor_id)
+        .bind(origin_full_name)
+        .bind(push_mirror_id)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(Some(cutover))
+    }
+
+    async fn claim_cutover_backfills(
+        &self,
+        attempted_before: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<MirrorCutover>, DatabaseError> {
+        let cutovers = sqlx::query_as::<_, MirrorCutover>(
+            r#"
+            WITH due AS (
+                SELECT id FROM migration.mirror_cutovers
+                WHERE backfilled_at IS NULL
+                  AND (backfill_attempted_at IS NULL OR backfill_attempted_at < $1)
+                ORDER BY created_at
+                LIMIT $2
+                FOR UPDATE SKIP LOCKED
+            )
+            UPDATE migration.mirror_cutovers c
+            SET backfill_attempted_at = NOW()
+            FROM due
+            WHERE c.id = due.id
+            RETURNING c.id, c.migration_repository_id, c.repository_id, c.actor_id,
+                      c.origin_full_name, c.push_mirror_id, c.created_at
+            "#,
+        )
+        .bind(attempted_before)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(cutovers)
+    }
+
+    async fn finish_cutover_backfill(&self, id: Uuid) -> Result<(), DatabaseError> {
+        sqlx::query("UPDATE migration.mirror_cutovers SET backfilled_at = NOW() WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
 }
 
 #[cfg(all(test, feature = "db-tests"))]
@@ -356,7 +641,10 @@ mod tests {
         MigrationOriginService, MigrationRepository, MigrationRepositoryStatus, MigrationStatus,
         PgMigrationRepository, RepositoryOwnerType, RepositoryVisibility,
     };
-    use crate::repository::test_common::{insert_migration_at, insert_user, insert_user_repo};
+    use crate::{
+        model::MirrorSyncStatus,
+        repository::test_common::{insert_migration_at, insert_user, insert_user_repo},
+    };
 
     #[sqlx::test]
     async fn create_assigns_sequential_numbers_per_author(pool: PgPool) {
@@ -552,6 +840,58 @@ mod tests {
         assert_eq!(failed.error.as_deref(), Some("boom"));
     }
 
+    #[sqlx::test]
+    async fn import_progress_is_reported_with_the_migration(pool: PgPool) {
+        let repo = PgMigrationRepository::new(pool.clone());
+        let alice = Uuid::new_v4();
+        insert_user(&pool, alice, "alice").await;
+        let migration = repo
+            .create(
+                alice,
+                MigrationOriginService::GitHub,
+                "o",
+                &RepositoryOwnerType::User,
+                "d",
+                &RepositoryOwnerType::User,
+            )
+            .await
+            .unwrap();
+        let created = repo
+            .create_migration_repository(
+                migration.id,
+                "octo/repo",
+                99,
+                None,
+                "alice/repo",
+                &RepositoryVisibility::Public,
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            (created.imported_questions, created.imported_reviews),
+            (0, 0)
+        );
+
+        let importing = repo
+            .update_migration_repository_status(
+                created.id,
+                MigrationRepositoryStatus::Importing,
+                None,
+            )
+            .await
+            .unwrap();
+        assert_eq!(importing.status, MigrationRepositoryStatus::Importing);
+        repo.update_import_progress(created.id, 3, 2).await.unwrap();
+
+        let fetched = repo.get(alice, migration.number).await.unwrap().unwrap();
+        let repos = fetched.repositories.unwrap();
+        assert_eq!(repos[0].status, MigrationRepositoryStatus::Importing);
+        assert_eq!(
+            (repos[0].imported_questions, repos[0].imported_reviews),
+            (3, 2)
+        );
+    }
+
     #[sqlx::test]
     async fn set_destination_and_list_by_origin_repository_id(pool: PgPool) {
         let repo = PgMigrationRepository::new(pool.clone());
@@ -612,4 +952,184 @@ mod tests {
                 .is_empty()
         );
     }
+
+    #[sqlx::test]
+    async fn mirrors_track_sync_state_and_come_due(pool: PgPool) {
+        let repo = PgMigrationRepository::new(pool.clone());
+        let alice = Uuid::new_v4();
+        insert_user(&pool, alice, "alice").await;
+        let migration = repo
+            .create(
+                alice,
+                MigrationOriginService::GitHub,
+                "octo",
+                &RepositoryOwnerType::User,
+                "alice",
+                &RepositoryOwnerType::User,
+            )
+            .await
+            .unwrap();
+        let linked = repo
+            .create_migration_repository(
+                migration.id,
+                "octo/repo",
+                777,
+                None,
+                "alice/repo",
+                &RepositoryVisibility::Public,
+            )
+            .await
+            .unwrap();
+        let dest_repo = Uuid::new_v4();
+        insert_user_repo(&pool, dest_repo, "repo", alice, "public").await;
+        sqlx::query("UPDATE core.repositories SET readonly = TRUE WHERE id = $1")
+            .bind(dest_repo)
+            .execute(&pool)
+            .await
+            .unwrap();
+        repo.set_destination_repository_id(linked.id, dest_repo)
+            .await
+            .unwrap();
+
+        // Never synced: due immediately, with no installation recorded yet.
+        let due = repo.list_due_mirrors(Utc::now(), 10).await.unwrap();
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].owner_name, "alice");
+        assert_eq!(due[0].repository_name, "repo");
+        assert!(due[0].installation_id.is_none());
+        assert!(due[0].sync_status.is_none());
+
+        repo.record_mirror_sync(linked.id, None).await.unwrap();
+        let synced = repo.get_mirror(dest_repo).await.unwrap().expect("mirror");
+        assert_eq!(synced.sync_status, Some(MirrorSyncStatus::Synced));
+        let last_synced_at = synced.last_synced_at.expect("synced");
+        assert!(
+            repo.list_due_mirrors(Utc::now() - Duration::minutes(5), 10)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+
+        // A failure keeps the last successful sync time.
+        repo.record_mirror_sync(linked.id, Some("fetch failed"))
+            .await
+            .unwrap();
+        let failed = repo.get_mirror(dest_repo).await.unwrap().expect("mirror");
+        assert_eq!(failed.sync_status, Some(MirrorSyncStatus::Failed));
+        assert_eq!(failed.sync_error.as_deref(), Some("fetch failed"));
+        assert_eq!(failed.last_synced_at, Some(last_synced_at));
+
+        // A repository that is no longer read-only is not a mirror.
+        sqlx::query("UPDATE core.repositories SET readonly = FALSE WHERE id = $1")
+            .bind(dest_repo)
+            .execute(&pool)
+            .await
+            .unwrap();
+        assert!(repo.get_mirror(dest_repo).await.unwrap().is_none());
+    }
+
+    #[sqlx::test]
+    async fn cutover_makes_mirror_writable_once_and_keeps_push_mirror(pool: PgPool) {
+        let repo = PgMigrationRepository::new(pool.clone());
+        let alice = Uuid::new_v4();
+        insert_user(&pool, alice, "alice").await;
+        let migration = repo
+            .create(
+                alice,
+                MigrationOriginService::GitHub,
+                "octo",
+                &RepositoryOwnerType::User,
+                "alice",
+                &RepositoryOwnerType::User,
+            )
+            .await
+            .unwrap();
+        let linked = repo
+            .create_migration_repository(
+                migration.id,
+                "octo/repo",
+                777,
+                None,
+                "alice/repo",
+                &RepositoryVisibility::Public,
+            )
+            .await
+            .unwrap();
+        let dest_repo = Uuid::new_v4();
+        insert_user_repo(&pool, dest_repo, "repo", alice, "public").await;
+        sqlx::query("UPDATE core.repositories SET readonly = TRUE WHERE id = $1")
+            .bind(dest_repo)
+            .execute(&pool)
+            .await
+            .unwrap();
+        repo.set_destination_repository_id(linked.id, dest_repo)
+            .await
+            .unwrap();
+
+        let url = "https://github.com/octo/repo.git";
+        let cutover = repo
+            .cutover_mirror(
+                linked.id,
+                dest_repo,
+                alice,
+                "octo/repo",
+                Some(url),
+                None,
+                Some(vec![1, 2, 3]),
+            )
+            .await
+            .unwrap()
+            .expect("cutover");
+        assert_eq!(cutover.actor_id, alice);
+        assert_eq!(cutover.origin_full_name, "octo/repo");
+        assert!(repo.get_mirror(dest_repo).await.unwrap().is_none());
+
+        let (push_mirror_id, requested): (Uuid, bool) = sqlx::query_as(
+            "SELECT id, requested_at IS NOT NULL FROM core.repository_mirrors \
+             WHERE repository_id = $1 AND direction = 'push' AND url = $2",
+        )
+        .bind(dest_repo)
+        .bind(url)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert_eq!(cutover.push_mirror_id, Some(push_mirror_id));
+        assert!(requested);
+
+        // Already live: nothing changes and no second record is written.
+        assert!(
+            repo.cutover_mirror(linked.id, dest_repo, alice, "octo/repo", None, None, None)
+                .await
+                .unwrap()
+                .is_none()
+        );
+        let cutovers: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM migration.mirror_cutovers WHERE repository_id = $1",
+        )
+        .bind(dest_repo)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert_eq!(cutovers, 1);
+
+        // The backfill is claimed once, claimed again once it is overdue, and
+        // not at all after it finished.
+        let claimed = repo.claim_cutover_backfills(Utc::now(), 10).await.unwrap();
+        assert_eq!(claimed.len(), 1);
+        assert_eq!(claimed[0].id, cutover.id);
+        let attempted_before = Utc::now() - Duration::hours(1);
+        assert!(
+            repo.claim_cutover_backfills(attempted_before, 10)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+        repo.finish_cutover_backfill(cutover.id).await.unwrap();
+        assert!(
+            repo.claim_cutover_backfills(Utc::now(), 10)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
 }
@@ -5,11 +5,12 @@ use uuid::Uuid;
 use crate::{
     dto::Cursor,
     error::DatabaseError,
-    model::{Webhook, WebhookEventType},
+    model::{Webhook, WebhookDelivery, WebhookDeliveryStatus, WebhookEventType},
 };
 
 /// sqlx data-access layer for the `webhook.webhooks` table, which stores a
-/// repository's outbound HTTP webhook destinations (url, signing secret, events).
+/// repository's outbound HTTP webhook destinations (url, signing secret, events),
+/// and its `webhook.webhook_deliveries` log of every delivery attempt.
 #[async_trait]
 pub trait WebhookRepository: Send + Sync + Clone + 'static {
     /// Inserts a row into `webhook.webhooks` and returns the created webhook via
@@ -51,6 +52,54 @@ pub trait WebhookRepository: Send + Sync + Clone + 'static {
     /// Hard-deletes the `webhook.webhooks` row with the given `id`. Succeeds even
     /// if no row matched.
     async fn delete(&self, id: Uuid) -> Result<(), DatabaseError>;
+
+    /// Lists `webhook.webhooks` rows for `repository_id` whose `events` array
+    /// contains `event` (`$2 = ANY(events)`), ordered by `created_at` ASC.
+    /// Returns an empty `Vec` when none match.
+    async fn list_by_repository_and_event(
+        &self,
+        repository_id: Uuid,
+        event: WebhookEventType,
+    ) -> Result<Vec<Webhook>, DatabaseError>;
+
+    /// Inserts a `pending` row into `webhook.webhook_deliveries` with zero
+    /// attempts and returns it via `RETURNING`. `redelivery_of` links a manual
+    /// redelivery back to the delivery it replays.
+    async fn create_delivery(
+        &self,
+        webhook_id: Uuid,
+        event: WebhookEventType,
+        payload: &serde_json::Value,
+        redelivery_of: Option<Uuid>,
+    ) -> Result<WebhookDelivery, DatabaseError>;
+
+    /// Records one delivery attempt on the `webhook.webhook_deliveries` row with
+    /// the given `id`: increments `attempts`, overwrites the last response
+    /// fields, sets `status`, and stamps `delivered_at` once the status is no
+    /// longer `pending`. Returns the updated row via `RETURNING`.
+    async fn record_delivery_attempt(
+        &self,
+        id: Uuid,
+        status: WebhookDeliveryStatus,
+        status_code: Option<i32>,
+        duration_ms: i32,
+        response_excerpt: Option<&str>,
+        error: Option<&str>,
+    ) -> Result<WebhookDelivery, DatabaseError>;
+
+    /// Returns the `webhook.webhook_deliveries` row with the given `id`, or
+    /// `Ok(None)` if no such row exists.
+    async fn get_delivery(&self, id: Uuid) -> Result<Option<WebhookDelivery>, DatabaseError>;
+
+    /// Lists `webhook.webhook_deliveries` rows for `webhook_id`, keyset-paginated
+    /// by `(created_at, id)` descending, following the same `limit + 1`
+    /// convention as [`list_by_repo`](WebhookRepository::list_by_repo).
+    async fn list_deliveries(
+        &self,
+        webhook_id: Uuid,
+        cursor: Option<Cursor>,
+        limit: i64,
+    ) -> Result<(Vec<WebhookDelivery>, Option<Cursor>), DatabaseError>;
 }
 
 #[derive(Debug, Clone)]
@@ -180,4 +229,141 @@ impl WebhookRepository for PgWebhookRepository {
 
         Ok(())
     }
+
+    async fn list_by_repository_and_event(
+        &self,
+        repository_id: Uuid,
+        event: WebhookEventType,
+    ) -> Result<Vec<Webhook>, DatabaseError> {
+        let webhooks = sqlx::query_as::<_, Webhook>(
+            r#"
+            SELECT id, repository_id, url, secret, events, created_at, updated_at
+            FROM webhook.webhooks
+            WHERE repository_id = $1 AND $2 = ANY(events)
+            ORDER BY created_at ASC
+            "#,
+        )
+        .bind(repository_id)
+        .bind(event)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(webhooks)
+    }
+
+    async fn create_delivery(
+        &self,
+        webhook_id: Uuid,
+        event: WebhookEventType,
+        payload: &serde_json::Value,
+        redelivery_of: Option<Uuid>,
+    ) -> Result<WebhookDelivery, DatabaseError> {
+        let delivery = sqlx::query_as::<_, WebhookDelivery>(
+            r#"
+            INSERT INTO webhook.webhook_deliveries (webhook_id, event, payload, redelivery_of)
+            VALUES ($1, $2, $3, $4)
+            RETURNING id, webhook_id, event, payload, status, attempts, status_code,
+                duration_ms, response_excerpt, error, redelivery_of, created_at, delivered_at
+            "#,
+        )
+        .bind(webhook_id)
+        .bind(event)
+        .bind(payload)
+        .bind(redelivery_of)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(delivery)
+    }
+
+    async fn record_delivery_attempt(
+        &self,
+        id: Uuid,
+        status: WebhookDeliveryStatus,
+        status_code: Option<i32>,
+        duration_ms: i32,
+        response_excerpt: Option<&str>,
+        error: Option<&str>,
+    ) -> Result<WebhookDelivery, DatabaseError> {
+        let delivery = sqlx::query_as::<_, WebhookDelivery>(
+            r#"
+            UPDATE webhook.webhook_deliveries
+            SET status = $2,
+                attempts = attempts + 1,
+                status_code = $3,
+                duration_ms = $4,
+                response_excerpt = $5,
+                error = $6,
+                delivered_at = CASE WHEN $2 = 'pending' THEN delivered_at ELSE now() END
+            WHERE id = $1
+            RETURNING id, webhook_id, event, payload, status, attempts, status_code,
+                duration_ms, response_excerpt, error, redelivery_of, created_at, delivered_at
+            "#,
+        )
+        .bind(id)
+        .bind(status)
+        .bind(status_code)
+        .bind(duration_ms)
+        .bind(response_excerpt)
+        .bind(error)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(delivery)
+    }
+
+    async fn get_delivery(&self, id: Uuid) -> Result<Option<WebhookDelivery>, DatabaseError> {
+        let delivery = sqlx::query_as::<_, WebhookDelivery>(
+            r#"
+            SELECT id, webhook_id, event, payload, status, attempts, status_code,
+                duration_ms, response_excerpt, error, redelivery_of, created_at, delivered_at
+            FROM webhook.webhook_deliveries WHERE id = $1
+            "#,
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(delivery)
+    }
+
+    async fn list_deliveries(
+        &self,
+        webhook_id: Uuid,
+        cursor: Option<Cursor>,
+        limit: i64,
+    ) -> Result<(Vec<WebhookDelivery>, Option<Cursor>), DatabaseError> {
+        let cursor_created_at = cursor.as_ref().map(|c| c.created_at);
+        let cursor_id = cursor.as_ref().map(|c| c.id);
+
+        let mut deliveries = sqlx::query_as::<_, WebhookDelivery>(
+            r#"
+            SELECT id, webhook_id, event, payload, status, attempts, status_code,
+                duration_ms, response_excerpt, error, redelivery_of, created_at, delivered_at
+            FROM webhook.webhook_deliveries
+            WHERE webhook_id = $1
+              AND ($2::timestamptz IS NULL OR (created_at, id) < ($2, $3))
+            ORDER BY created_at DESC, id DESC
+            LIMIT $4
+            "#,
+        )
+        .bind(webhook_id)
+        .bind(cursor_created_at)
+        .bind(cursor_id)
+        .bind(limit + 1)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let next_cursor = if deliveries.len() as i64 > limit {
+            deliveries.pop();
+            deliveries.last().map(|last| Cursor {
+                created_at: last.created_at,
+                id: last.id,
+            })
+        } else {
+            None
+        };
+
+        Ok((deliveries, next_cursor))
+    }
 }
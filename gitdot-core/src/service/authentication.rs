@@ -1,11 +1,15 @@
 mod account;
 mod device;
 mod session;
+mod signing_key;
 mod slack;
+mod ssh_key;
 mod token;
 
 pub use account::{AccountService, AccountServiceImpl};
 pub use device::{DeviceService, DeviceServiceImpl};
 pub use session::{SessionService, SessionServiceImpl};
+pub use signing_key::{SigningKeyService, SigningKeyServiceImpl};
 pub use slack::{SlackService, SlackServiceImpl};
+pub use ssh_key::{SshKeyService, SshKeyServiceImpl};
 pub use token::{TokenService, TokenServiceImpl};
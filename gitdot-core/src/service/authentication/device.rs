@@ -8,7 +8,7 @@ use crate::{
         TokenResponse,
     },
     error::{DeviceError, InputError, OptionNotFoundExt},
-    model::{DeviceAuthorizationStatus, TokenType},
+    model::{DeviceAuthorizationStatus, TokenScope, TokenType},
     repository::{
         DeviceRepository, PgDeviceRepository, PgTokenRepository, PgUserRepository, TokenRepository,
         UserRepository,
@@ -162,12 +162,15 @@ where
                     .token_client
                     .generate_access_token(&TokenType::Personal);
 
+                // the device flow predates scopes and keeps granting all of them
                 self.token_repo
-                    .create_token(
+                    .create_personal_token(
                         user_id,
                         &device_auth.client_id,
                         &token_hash,
-                        TokenType::Personal,
+                        &device_auth.client_id,
+                        &TokenScope::ALL,
+                        None,
                     )
                     .await?;
 
@@ -206,7 +209,7 @@ mod tests {
     use crate::{
         dto::{AuthorizeDeviceRequest, DeviceCodeRequest, PollTokenRequest},
         error::DeviceError,
-        model::{AccessToken, DeviceAuthorizationStatus, TokenType},
+        model::{AccessToken, DeviceAuthorizationStatus, TokenScope, TokenType},
         service::{
             test_client::MockTokenClient,
             test_common::{create_device_authorization, create_user, create_user_email},
@@ -423,25 +426,31 @@ mod tests {
                 .returning(move |_| Ok(Some(user.clone())));
 
             // Capture the token-creation args to assert the principal, client,
-            // and token type without relying on mockall reference-matcher quirks.
+            // and scopes without relying on mockall reference-matcher quirks.
             let created = Arc::new(Mutex::new(None));
             let recorder = created.clone();
             let mut token_repo = MockTokenRepository::new();
-            token_repo.expect_create_token().times(1).returning(
-                move |principal_id, client_id, token_hash, token_type| {
-                    *recorder.lock().unwrap() =
-                        Some((principal_id, client_id.to_string(), token_type.clone()));
-                    Ok(AccessToken {
-                        id: Uuid::new_v4(),
-                        principal_id,
-                        client_id: client_id.to_string(),
-                        token_hash: token_hash.to_string(),
-                        token_type,
-                        created_at: Utc::now(),
-                        last_used_at: None,
-                    })
-                },
-            );
+            token_repo
+                .expect_create_personal_token()
+                .times(1)
+                .returning(
+                    move |principal_id, client_id, token_hash, name, scopes, expires_at| {
+                        *recorder.lock().unwrap() =
+                            Some((principal_id, client_id.to_string(), scopes.to_vec()));
+                        Ok(AccessToken {
+                            id: Uuid::new_v4(),
+                            principal_id,
+                            client_id: client_id.to_string(),
+                            token_hash: token_hash.to_string(),
+                            token_type: TokenType::Personal,
+                            name: name.to_string(),
+                            scopes: scopes.to_vec(),
+                            expires_at,
+                            created_at: Utc::now(),
+                            last_used_at: None,
+                        })
+                    },
+                );
 
             let service = create_service(device_repo, token_repo, user_repo);
             let response = service.poll_token(poll_request()).await.unwrap();
@@ -450,10 +459,10 @@ mod tests {
             assert_eq!(response.user_name, "alice");
             assert_eq!(response.user_email, "alice@example.com");
 
-            let (principal_id, client_id, token_type) = created.lock().unwrap().clone().unwrap();
+            let (principal_id, client_id, scopes) = created.lock().unwrap().clone().unwrap();
             assert_eq!(principal_id, user_id);
             assert_eq!(client_id, "gitdot-cli");
-            assert_eq!(token_type, TokenType::Personal);
+            assert_eq!(scopes, TokenScope::ALL);
         }
 
         #[tokio::test]
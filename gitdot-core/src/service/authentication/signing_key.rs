@@ -0,0 +1,347 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::{
+    client::{SignatureClient, SignatureClientImpl},
+    dto::{
+        AddSigningKeyRequest, DeleteSigningKeyRequest, SIGNING_KEY_CHALLENGE_NAMESPACE,
+        SigningKeyResponse,
+    },
+    error::{ConflictError, InputError, NotFoundError, SigningKeyError},
+    model::SigningKeyType,
+    repository::{PgSigningKeyRepository, PgUserRepository, SigningKeyRepository, UserRepository},
+};
+
+/// Manages the GPG and SSH public keys users register so that commits they
+/// sign are shown as verified.
+#[async_trait]
+pub trait SigningKeyService: Send + Sync + 'static {
+    /// Registers a signing key for a user, once they have shown it is theirs:
+    /// every email on a GPG key must be one of their verified emails, and an
+    /// SSH key must have signed [`AddSigningKeyRequest::challenge`].
+    ///
+    /// # Errors
+    /// - [`SigningKeyError::Input`] — a GPG key names an email the user has
+    ///   not verified, or the SSH challenge signature does not verify
+    /// - [`SigningKeyError::Conflict`] — the key is already registered, to
+    ///   this or another user
+    async fn add_signing_key(
+        &self,
+        request: AddSigningKeyRequest,
+    ) -> Result<SigningKeyResponse, SigningKeyError>;
+
+    /// Lists a user's signing keys, newest first.
+    async fn list_signing_keys(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Vec<SigningKeyResponse>, SigningKeyError>;
+
+    /// Deletes one of the user's signing keys. Commits it signed read as
+    /// `unknown_key` the next time they are fetched.
+    ///
+    /// # Errors
+    /// - [`SigningKeyError::NotFound`] — the user has no key with that id
+    async fn delete_signing_key(
+        &self,
+        request: DeleteSigningKeyRequest,
+    ) -> Result<(), SigningKeyError>;
+}
+
+#[derive(Debug, Clone)]
+pub struct SigningKeyServiceImpl<K, U, S>
+where
+    K: SigningKeyRepository,
+    U: UserRepository,
+    S: SignatureClient,
+{
+    signing_key_repo: K,
+    user_repo: U,
+    signature_client: S,
+}
+
+impl SigningKeyServiceImpl<PgSigningKeyRepository, PgUserRepository, SignatureClientImpl> {
+    pub fn new(
+        signing_key_repo: PgSigningKeyRepository,
+        user_repo: PgUserRepository,
+        signature_client: SignatureClientImpl,
+    ) -> Self {
+        Self {
+            signing_key_repo,
+            user_repo,
+            signature_client,
+        }
+    }
+}
+
+#[crate::instrument_all(level = "debug")]
+#[async_trait]
+impl<K, U, S> SigningKeyService for SigningKeyServiceImpl<K, U, S>
+where
+    K: SigningKeyRepository,
+    U: UserRepository,
+    S: SignatureClient,
+{
+    async fn add_signing_key(
+        &self,
+        request: AddSigningKeyRequest,
+    ) -> Result<SigningKeyResponse, SigningKeyError> {
+        match request.key_type {
+            SigningKeyType::Gpg => {
+                let verified = self
+                    .user_repo
+                    .list_emails(request.user_id)
+                    .await?
+                    .into_iter()
+                    .filter(|email| email.is_verified)
+                    .collect::<Vec<_>>();
+                if let Some(email) = request.emails.iter().find(|email| {
+                    !verified
+                        .iter()
+                        .any(|verified| verified.email.eq_ignore_ascii_case(email))
+                }) {
+                    return Err(InputError::new(
+                        "public key",
+                        format!("{email} is not one of your verified emails"),
+                    )
+                    .into());
+                }
+            }
+            SigningKeyType::Ssh => {
+                let challenge =
+                    AddSigningKeyRequest::challenge(request.user_id, &request.fingerprint);
+                let valid = self
+                    .signature_client
+                    .verify_ssh_signature(
+                        &request.public_key,
+                        SIGNING_KEY_CHALLENGE_NAMESPACE,
+                        request.signature.as_deref().unwrap_or_default(),
+                        challenge.as_bytes(),
+                    )
+                    .await?;
+                if !valid {
+                    return Err(InputError::new(
+                        "signature",
+                        format!("is not a signature of \"{challenge}\" by this key"),
+                    )
+                    .into());
+                }
+            }
+        }
+
+        let key = self
+            .signing_key_repo
+            .create(
+                request.user_id,
+                &request.name,
+                request.key_type,
+                &request.public_key,
+                &request.fingerprint,
+                &request.key_ids,
+            )
+            .await
+            .map_err(|e| {
+                if e.is_unique_violation() {
+                    ConflictError::new("signing key", &request.fingerprint).into()
+                } else {
+                    SigningKeyError::from(e)
+                }
+            })?;
+
+        Ok(key.into())
+    }
+
+    async fn list_signing_keys(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Vec<SigningKeyResponse>, SigningKeyError> {
+        let keys = self.signing_key_repo.list_by_user(user_id).await?;
+        Ok(keys.into_iter().map(Into::into).collect())
+    }
+
+    async fn delete_signing_key(
+        &self,
+        request: DeleteSigningKeyRequest,
+    ) -> Result<(), SigningKeyError> {
+        let deleted = self
+            .signing_key_repo
+            .delete(request.user_id, request.key_id)
+            .await?;
+        if !deleted {
+            return Err(NotFoundError::new("signing key", request.key_id).into());
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use super::*;
+    use crate::{
+        model::{SigningKey, SigningKeyType},
+        service::{
+            test_client::MockSignatureClient,
+            test_common::create_user_email,
+            test_repository::{MockSigningKeyRepository, MockUserRepository},
+        },
+    };
+
+    const KEY: &str =
+        "ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAINMxyRTl8Mv7fUpeuLSY3VjGDnqJEfl/9ePx4G+jBpYj";
+    const FINGERPRINT: &str = "SHA256:kXVIe/l/3LxNg44nrYwKRaE3bi5OA59ASVDnOw0eKZk";
+    const SIGNATURE: &str = "-----BEGIN SSH SIGNATURE-----\nU1NIU0lH\n-----END SSH SIGNATURE-----";
+    // `gpg --quick-gen-key ed25519` for `Alice <alice@example.com>`.
+    const GPG_KEY: &str = "-----BEGIN PGP PUBLIC KEY BLOCK-----
+
+mDMEatUaJxYJKwYBBAHaRw8BAQdAMnylJbd1nUGM8TrtEMPyH8f8SiPGeUOsnan7
+jiyWVAC0GUFsaWNlIDxhbGljZUBleGFtcGxlLmNvbT6IkAQTFggAOBYhBERg74bA
+CNuAaavNNl3NQNIAVYLIBQJq1RonAhsDBQsJCAcCBhUKCQgLAgQWAgMBAh4BAheA
+AAoJEF3NQNIAVYLIVbYBALftSMnvw1a30jRZmi+mvpFZ9xz3/d1nh1zV83nePenB
+AP9YoZ9nlpz+9gDIoiwAeYltZx9Ixc2EyteNrWfDcts7AbgzBGrVGicWCSsGAQQB
+2kcPAQEHQMsbbmYy+K+jSbfP+PUcgmrZduq5E1Z17R4p1Q5GpyGjiO8EGBYIACAW
+IQREYO+GwAjbgGmrzTZdzUDSAFWCyAUCatUaJwIbAgCBCRBdzUDSAFWCyHYgBBkW
+CAAdFiEEudraX8lWL3bqwfB3QCqt68YAKc8FAmrVGicACgkQQCqt68YAKc88fwD/
+bE72nfHmyb7AOR2jdeXEay3qkIIR9a6+CWPYi7iP+h4BAPoyThFzLdeYFt2FmFaI
+IK2YMa7MbC4qB5ngfo58ku8DsMMBANxOnV3dSA4rQnoM97a5OhgaQeRNn+kL5ef6
+hSFhIGiMAP9DL2rYQPjyShYWGiOPp0bE2vjoWhnfYXbgAf9sgSaVDQ==
+=QZ+4
+-----END PGP PUBLIC KEY BLOCK-----
+";
+
+    type Service =
+        SigningKeyServiceImpl<MockSigningKeyRepository, MockUserRepository, MockSignatureClient>;
+
+    fn create_service(
+        signing_key_repo: MockSigningKeyRepository,
+        user_repo: MockUserRepository,
+        signature_client: MockSignatureClient,
+    ) -> Service {
+        SigningKeyServiceImpl {
+            signing_key_repo,
+            user_repo,
+            signature_client,
+        }
+    }
+
+    fn create_key(user_id: Uuid) -> SigningKey {
+        SigningKey {
+            id: Uuid::new_v4(),
+            user_id,
+            name: "laptop".to_string(),
+            key_type: SigningKeyType::Ssh,
+            public_key: KEY.to_string(),
+            fingerprint: FINGERPRINT.to_string(),
+            key_ids: vec![FINGERPRINT.to_string()],
+            created_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn add_stores_the_key_ids() {
+        let user_id = Uuid::new_v4();
+        let mut signing_key_repo = MockSigningKeyRepository::new();
+        signing_key_repo
+            .expect_create()
+            .withf(move |uid, name, key_type, key, fingerprint, key_ids| {
+                *uid == user_id
+                    && name == "laptop"
+                    && *key_type == SigningKeyType::Ssh
+                    && key == KEY
+                    && fingerprint == FINGERPRINT
+                    && key_ids == [FINGERPRINT]
+            })
+            .returning(move |_, _, _, _, _, _| Ok(create_key(user_id)));
+        let challenge = AddSigningKeyRequest::challenge(user_id, FINGERPRINT);
+        let mut signature_client = MockSignatureClient::new();
+        signature_client
+            .expect_verify_ssh_signature()
+            .withf(move |key, namespace, signature, message| {
+                key == KEY
+                    && namespace == SIGNING_KEY_CHALLENGE_NAMESPACE
+                    && signature == SIGNATURE
+                    && message == challenge.as_bytes()
+            })
+            .returning(|_, _, _, _| Ok(true));
+
+        let service = create_service(
+            signing_key_repo,
+            MockUserRepository::new(),
+            signature_client,
+        );
+        let request =
+            AddSigningKeyRequest::new(user_id, Some("laptop"), KEY, Some(SIGNATURE)).unwrap();
+        let key = service.add_signing_key(request).await.unwrap();
+
+        assert_eq!(key.fingerprint, FINGERPRINT);
+    }
+
+    #[tokio::test]
+    async fn add_ssh_key_without_a_valid_challenge_signature_is_rejected() {
+        let mut signature_client = MockSignatureClient::new();
+        signature_client
+            .expect_verify_ssh_signature()
+            .returning(|_, _, _, _| Ok(false));
+
+        let service = create_service(
+            MockSigningKeyRepository::new(),
+            MockUserRepository::new(),
+            signature_client,
+        );
+        let request =
+            AddSigningKeyRequest::new(Uuid::new_v4(), None, KEY, Some(SIGNATURE)).unwrap();
+        let err = service.add_signing_key(request).await.unwrap_err();
+
+        assert!(matches!(err, SigningKeyError::Input(_)));
+    }
+
+    #[tokio::test]
+    async fn add_gpg_key_needs_every_email_verified() {
+        let user_id = Uuid::new_v4();
+        for (email, is_verified, added) in [
+            ("alice@example.com", true, true),
+            ("Alice@Example.com", true, true),
+            ("alice@example.com", false, false),
+            ("bob@example.com", true, false),
+        ] {
+            let mut user_repo = MockUserRepository::new();
+            user_repo.expect_list_emails().returning(move |uid| {
+                let mut email = create_user_email(uid, email);
+                email.is_verified = is_verified;
+                Ok(vec![email])
+            });
+            let mut signing_key_repo = MockSigningKeyRepository::new();
+            signing_key_repo
+                .expect_create()
+                .times(usize::from(added))
+                .returning(move |_, _, _, _, _, _| Ok(create_key(user_id)));
+
+            let service = create_service(signing_key_repo, user_repo, MockSignatureClient::new());
+            let request = AddSigningKeyRequest::new(user_id, None, GPG_KEY, None).unwrap();
+            let result = service.add_signing_key(request).await;
+
+            if added {
+                assert!(result.is_ok(), "{email}");
+            } else {
+                assert!(matches!(result, Err(SigningKeyError::Input(_))), "{email}");
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn delete_foreign_key_is_not_found() {
+        let mut signing_key_repo = MockSigningKeyRepository::new();
+        signing_key_repo.expect_delete().returning(|_, _| Ok(false));
+
+        let service = create_service(
+            signing_key_repo,
+            MockUserRepository::new(),
+            MockSignatureClient::new(),
+        );
+        let request = DeleteSigningKeyRequest::new(Uuid::new_v4(), Uuid::new_v4());
+        let err = service.delete_signing_key(request).await.unwrap_err();
+
+        assert!(matches!(err, SigningKeyError::NotFound(_)));
+    }
+}
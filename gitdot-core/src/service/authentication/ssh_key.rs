@@ -0,0 +1,223 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::{
+    dto::{AddSshKeyRequest, DeleteSshKeyRequest, FindSshKeyRequest, SshKeyResponse},
+    error::{ConflictError, NotFoundError, OptionNotFoundExt, SshKeyError},
+    repository::{PgSshKeyRepository, SshKeyRepository},
+};
+
+/// Manages the SSH public keys users register for git over SSH, and resolves
+/// the keys presented to the SSH server back to their users.
+#[async_trait]
+pub trait SshKeyService: Send + Sync + 'static {
+    /// Registers a public key for a user.
+    ///
+    /// # Errors
+    /// - [`SshKeyError::Conflict`] — the key is already registered, to this
+    ///   or another user
+    async fn add_ssh_key(&self, request: AddSshKeyRequest) -> Result<SshKeyResponse, SshKeyError>;
+
+    /// Lists a user's SSH keys, newest first.
+    async fn list_ssh_keys(&self, user_id: Uuid) -> Result<Vec<SshKeyResponse>, SshKeyError>;
+
+    /// Deletes one of the user's SSH keys.
+    ///
+    /// # Errors
+    /// - [`SshKeyError::NotFound`] — the user has no key with that id
+    async fn delete_ssh_key(&self, request: DeleteSshKeyRequest) -> Result<(), SshKeyError>;
+
+    /// Finds the registered key matching a key presented to the SSH server.
+    ///
+    /// # Errors
+    /// - [`SshKeyError::NotFound`] — no user has registered the key
+    async fn find_ssh_key(&self, request: FindSshKeyRequest)
+    -> Result<SshKeyResponse, SshKeyError>;
+
+    /// Resolves the key an SSH session authenticated with and records its use.
+    /// Looked up again per command, so a deleted key stops working at once.
+    ///
+    /// # Errors
+    /// - [`SshKeyError::NotFound`] — the key has since been deleted
+    async fn authenticate_ssh_key(&self, key_id: Uuid) -> Result<SshKeyResponse, SshKeyError>;
+}
+
+#[derive(Debug, Clone)]
+pub struct SshKeyServiceImpl<S>
+where
+    S: SshKeyRepository,
+{
+    ssh_key_repo: S,
+}
+
+impl SshKeyServiceImpl<PgSshKeyRepository> {
+    pub fn new(ssh_key_repo: PgSshKeyRepository) -> Self {
+        Self { ssh_key_repo }
+    }
+}
+
+#[crate::instrument_all(level = "debug")]
+#[async_trait]
+impl<S> SshKeyService for SshKeyServiceImpl<S>
+where
+    S: SshKeyRepository,
+{
+    async fn add_ssh_key(&self, request: AddSshKeyRequest) -> Result<SshKeyResponse, SshKeyError> {
+        let fingerprint = request.public_key.fingerprint();
+        let key = self
+            .ssh_key_repo
+            .create(
+                request.user_id,
+                &request.name,
+                request.public_key.key_type(),
+                request.public_key.key(),
+                &fingerprint,
+            )
+            .await
+            .map_err(|e| {
+                if e.is_unique_violation() {
+                    ConflictError::new("ssh key", &fingerprint).into()
+                } else {
+                    SshKeyError::from(e)
+                }
+            })?;
+
+        Ok(key.into())
+    }
+
+    async fn list_ssh_keys(&self, user_id: Uuid) -> Result<Vec<SshKeyResponse>, SshKeyError> {
+        let keys = self.ssh_key_repo.list_by_user(user_id).await?;
+        Ok(keys.into_iter().map(Into::into).collect())
+    }
+
+    async fn delete_ssh_key(&self, request: DeleteSshKeyRequest) -> Result<(), SshKeyError> {
+        let deleted = self
+            .ssh_key_repo
+            .delete(request.user_id, request.key_id)
+            .await?;
+        if !deleted {
+            return Err(NotFoundError::new("ssh key", request.key_id).into());
+        }
+
+        Ok(())
+    }
+
+    async fn find_ssh_key(
+        &self,
+        request: FindSshKeyRequest,
+    ) -> Result<SshKeyResponse, SshKeyError> {
+        let fingerprint = request.public_key.fingerprint();
+        let key = self
+            .ssh_key_repo
+            .get_by_fingerprint(&fingerprint)
+            .await?
+            .or_not_found("ssh key", &fingerprint)?;
+
+        Ok(key.into())
+    }
+
+    async fn authenticate_ssh_key(&self, key_id: Uuid) -> Result<SshKeyResponse, SshKeyError> {
+        let key = self
+            .ssh_key_repo
+            .get(key_id)
+            .await?
+            .or_not_found("ssh key", key_id)?;
+        self.ssh_key_repo.touch(key.id).await?;
+
+        Ok(key.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use mockall::predicate::eq;
+
+    use super::*;
+    use crate::{model::SshKey, service::test_repository::MockSshKeyRepository};
+
+    const KEY: &str = "AAAAC3NzaC1lZDI1NTE5AAAAINMxyRTl8Mv7fUpeuLSY3VjGDnqJEfl/9ePx4G+jBpYj";
+    const FINGERPRINT: &str = "SHA256:kXVIe/l/3LxNg44nrYwKRaE3bi5OA59ASVDnOw0eKZk";
+
+    fn create_key(user_id: Uuid) -> SshKey {
+        SshKey {
+            id: Uuid::new_v4(),
+            user_id,
+            name: "laptop".to_string(),
+            key_type: "ssh-ed25519".to_string(),
+            public_key: KEY.to_string(),
+            fingerprint: FINGERPRINT.to_string(),
+            created_at: Utc::now(),
+            last_used_at: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn add_stores_the_fingerprint() {
+        let user_id = Uuid::new_v4();
+        let mut ssh_key_repo = MockSshKeyRepository::new();
+        ssh_key_repo
+            .expect_create()
+            .withf(move |uid, name, key_type, key, fingerprint| {
+                *uid == user_id
+                    && name == "laptop"
+                    && key_type == "ssh-ed25519"
+                    && key == KEY
+                    && fingerprint == FINGERPRINT
+            })
+            .returning(move |_, _, _, _, _| Ok(create_key(user_id)));
+
+        let service = SshKeyServiceImpl { ssh_key_repo };
+        let request =
+            AddSshKeyRequest::new(user_id, Some("laptop"), &format!("ssh-ed25519 {KEY}")).unwrap();
+        let key = service.add_ssh_key(request).await.unwrap();
+
+        assert_eq!(key.fingerprint, FINGERPRINT);
+    }
+
+    #[tokio::test]
+    async fn find_unknown_key_is_not_found() {
+        let mut ssh_key_repo = MockSshKeyRepository::new();
+        ssh_key_repo
+            .expect_get_by_fingerprint()
+            .with(eq(FINGERPRINT))
+            .returning(|_| Ok(None));
+
+        let service = SshKeyServiceImpl { ssh_key_repo };
+        let request = FindSshKeyRequest::new("ssh-ed25519", KEY).unwrap();
+        let err = service.find_ssh_key(request).await.unwrap_err();
+
+        assert!(matches!(err, SshKeyError::NotFound(_)));
+    }
+
+    #[tokio::test]
+    async fn authenticate_records_use() {
+        let key = create_key(Uuid::new_v4());
+        let key_id = key.id;
+        let mut ssh_key_repo = MockSshKeyRepository::new();
+        ssh_key_repo
+            .expect_get()
+            .with(eq(key_id))
+            .returning(move |_| Ok(Some(key.clone())));
+        ssh_key_repo
+            .expect_touch()
+            .with(eq(key_id))
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let service = SshKeyServiceImpl { ssh_key_repo };
+        service.authenticate_ssh_key(key_id).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn delete_foreign_key_is_not_found() {
+        let mut ssh_key_repo = MockSshKeyRepository::new();
+        ssh_key_repo.expect_delete().returning(|_, _| Ok(false));
+
+        let service = SshKeyServiceImpl { ssh_key_repo };
+        let request = DeleteSshKeyRequest::new(Uuid::new_v4(), Uuid::new_v4());
+        let err = service.delete_ssh_key(request).await.unwrap_err();
+
+        assert!(matches!(err, SshKeyError::NotFound(_)));
+    }
+}
@@ -1,5 +1,6 @@
 use async_trait::async_trait;
 use chrono::Utc;
+use uuid::Uuid;
 
 use crate::{
     client::{
@@ -7,10 +8,13 @@ use crate::{
         TokenClientImpl,
     },
     dto::{
-        IssueTaskJwtRequest, IssueTaskJwtResponse, JwtClaims, ValidateTokenRequest,
-        ValidateTokenResponse, VerifyGithubSignatureRequest, VerifySlackBotSignatureRequest,
+        CreatePersonalTokenRequest, CreatePersonalTokenResponse, IssueTaskJwtRequest,
+        IssueTaskJwtResponse, JwtClaims, PersonalTokenResponse, RevokePersonalTokenRequest,
+        ValidateTokenRequest, ValidateTokenResponse, VerifyGithubSignatureRequest,
+        VerifySlackBotSignatureRequest,
     },
-    error::TokenServiceError,
+    error::{NotFoundError, TokenServiceError},
+    model::TokenType,
     repository::{PgTokenRepository, TokenRepository},
     util::{
         auth::{GITDOT_SERVER_ID, S2_SERVER_ID},
@@ -18,9 +22,13 @@ use crate::{
     },
 };
 
+/// Client id recorded on personal access tokens created through the API rather
+/// than the device flow.
+const PERSONAL_TOKEN_CLIENT_ID: &str = "gitdot-web";
+
 /// Validates and issues machine-facing credentials: opaque access tokens,
 /// internal service-to-service task JWTs, and inbound webhook signatures
-/// (GitHub, Slack bot).
+/// (GitHub, Slack bot). Also manages a user's personal access tokens.
 #[async_trait]
 pub trait TokenService: Send + Sync + 'static {
     /// Validates an opaque access token and resolves its principal.
@@ -31,12 +39,38 @@ pub trait TokenService: Send + Sync + 'static {
     ///
     /// # Errors
     /// - [`TokenServiceError::Unauthorized`] — malformed token, wrong type
-    ///   prefix, or no matching token
+    ///   prefix, no matching token, or an expired token
+    /// - [`TokenServiceError::MissingScope`] — the token lacks the requested
+    ///   scope
     async fn validate_token(
         &self,
         request: ValidateTokenRequest,
     ) -> Result<ValidateTokenResponse, TokenServiceError>;
 
+    /// Creates a named personal access token with the requested scopes and
+    /// optional expiry. The token is returned in the clear only here; it is
+    /// stored as a hash.
+    async fn create_personal_token(
+        &self,
+        request: CreatePersonalTokenRequest,
+    ) -> Result<CreatePersonalTokenResponse, TokenServiceError>;
+
+    /// Lists a user's personal access tokens, newest first, including those
+    /// minted by the device flow.
+    async fn list_personal_tokens(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Vec<PersonalTokenResponse>, TokenServiceError>;
+
+    /// Revokes one of the user's personal access tokens.
+    ///
+    /// # Errors
+    /// - [`TokenServiceError::NotFound`] — the user has no token with that id
+    async fn revoke_personal_token(
+        &self,
+        request: RevokePersonalTokenRequest,
+    ) -> Result<(), TokenServiceError>;
+
     /// Issues a short-lived JWT scoped to a task for internal service-to-service
     /// calls.
     ///
@@ -124,6 +158,14 @@ where
             .get_token_by_hash(&token_hash)
             .await?
             .ok_or(TokenServiceError::Unauthorized)?;
+        if access_token.is_expired() {
+            return Err(TokenServiceError::Unauthorized);
+        }
+        if let Some(scope) = request.scope
+            && !access_token.has_scope(scope)
+        {
+            return Err(TokenServiceError::MissingScope(scope));
+        }
 
         self.token_repo.touch_token(access_token.id).await?;
 
@@ -132,6 +174,54 @@ where
         })
     }
 
+    async fn create_personal_token(
+        &self,
+        request: CreatePersonalTokenRequest,
+    ) -> Result<CreatePersonalTokenResponse, TokenServiceError> {
+        let (token, token_hash) = self
+            .token_client
+            .generate_access_token(&TokenType::Personal);
+        let access_token = self
+            .token_repo
+            .create_personal_token(
+                request.user_id,
+                PERSONAL_TOKEN_CLIENT_ID,
+                &token_hash,
+                &request.name,
+                &request.scopes,
+                request.expires_at,
+            )
+            .await?;
+
+        Ok(CreatePersonalTokenResponse {
+            token,
+            personal_token: access_token.into(),
+        })
+    }
+
+    async fn list_personal_tokens(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Vec<PersonalTokenResponse>, TokenServiceError> {
+        let tokens = self.token_repo.list_personal_tokens(user_id).await?;
+        Ok(tokens.into_iter().map(Into::into).collect())
+    }
+
+    async fn revoke_personal_token(
+        &self,
+        request: RevokePersonalTokenRequest,
+    ) -> Result<(), TokenServiceError> {
+        let revoked = self
+            .token_repo
+            .delete_personal_token(request.user_id, request.token_id)
+            .await?;
+        if !revoked {
+            return Err(NotFoundError::new("token", request.token_id).into());
+        }
+
+        Ok(())
+    }
+
     async fn issue_task_token(
         &self,
         request: IssueTaskJwtRequest,
@@ -1,4 +1,5 @@
 use async_trait::async_trait;
+use uuid::Uuid;
 
 use crate::{
     dto::{
@@ -9,7 +10,7 @@ use crate::{
         ReviewCommentAuthorizationRequest, ReviewingAuthorizationRequest,
     },
     error::{AuthorizationError, OptionNotFoundExt},
-    model::{OrganizationRole, RepositoryOwnerType},
+    model::{OrganizationRole, Repository, RepositoryOwnerType},
     repository::{
         OrganizationRepository, PgOrganizationRepository, PgQuestionRepository,
         PgRepositoryRepository, PgReviewRepository, PgUserRepository, QuestionRepository,
@@ -30,7 +31,8 @@ pub trait AuthorizationService: Send + Sync + 'static {
     /// For a [`RepositoryOwnerType::User`] owner the caller must be the user
     /// whose name (case-insensitively) matches `owner`. For an
     /// [`RepositoryOwnerType::Organization`] owner the caller must be any
-    /// member of that org.
+    /// member of that org. Collaborator and team grants do not apply, since
+    /// they are scoped to existing repositories.
     ///
     /// # Errors
     /// - [`AuthorizationError::Unauthorized`] — the user/org cannot be found,
@@ -42,20 +44,24 @@ pub trait AuthorizationService: Send + Sync + 'static {
 
     /// Asserts the caller has `request.permission` on `owner/repo`.
     ///
-    /// Precedence: a [`RepositoryPermission::Write`] request on a readonly repo
-    /// is rejected outright. A [`RepositoryPermission::Read`] on a public repo
-    /// is allowed for anyone, including anonymous callers. Otherwise a
-    /// `user_id` is required. For user-owned repos the caller must be the
-    /// owner. For org-owned repos, [`RepositoryPermission::Admin`] requires the
-    /// org [`OrganizationRole::Admin`] role while Read/Write require any
-    /// membership.
+    /// Precedence: a [`RepositoryPermission::Write`] or
+    /// [`RepositoryPermission::Maintain`] request on a readonly repo is
+    /// rejected outright. A [`RepositoryPermission::Read`] on a public repo is
+    /// allowed for anyone, including anonymous callers. Otherwise a `user_id`
+    /// is required and the caller's role must be at least the requested
+    /// permission. The role is the highest of:
+    /// - `admin` for the owner of a user-owned repo, or an org
+    ///   [`OrganizationRole::Admin`] of an org-owned repo;
+    /// - `read` for any other member of the owning org;
+    /// - the role granted to the caller as a collaborator or through any of
+    ///   their org teams.
     ///
     /// # Errors
     /// - [`AuthorizationError::NotFound`] — the repository does not exist.
-    /// - [`AuthorizationError::ReadonlyRepository`] — write requested on a
-    ///   readonly repo.
+    /// - [`AuthorizationError::ReadonlyRepository`] — a content write was
+    ///   requested on a readonly repo.
     /// - [`AuthorizationError::Unauthorized`] — no `user_id` for a protected
-    ///   action, or the caller lacks the required ownership/role/membership.
+    ///   action, or the caller's role is below the requested permission.
     async fn verify_authorized_for_repository(
         &self,
         request: RepositoryAuthorizationRequest,
@@ -85,11 +91,12 @@ pub trait AuthorizationService: Send + Sync + 'static {
         request: OrganizationMemberAuthorizationRequest,
     ) -> Result<(), AuthorizationError>;
 
-    /// Asserts the caller authored the question `owner/repo#number`.
+    /// Asserts the caller may edit the question `owner/repo#number`: they
+    /// authored it, or hold [`RepositoryPermission::Triage`] on the repo.
     ///
     /// # Errors
-    /// - [`AuthorizationError::Unauthorized`] — the question does not exist or
-    ///   the caller is not its author.
+    /// - [`AuthorizationError::Unauthorized`] — the question does not exist,
+    ///   or the caller is neither its author nor a triager.
     async fn verify_authorized_for_question(
         &self,
         request: QuestionAuthorizationRequest,
@@ -116,11 +123,12 @@ pub trait AuthorizationService: Send + Sync + 'static {
         request: CommentAuthorizationRequest,
     ) -> Result<(), AuthorizationError>;
 
-    /// Asserts the caller authored the review `owner/repo#number`.
+    /// Asserts the caller may manage the review `owner/repo#number`: they
+    /// authored it, or hold [`RepositoryPermission::Maintain`] on the repo.
     ///
     /// # Errors
-    /// - [`AuthorizationError::Unauthorized`] — the review does not exist or the
-    ///   caller is not its author.
+    /// - [`AuthorizationError::Unauthorized`] — the review does not exist, or
+    ///   the caller is neither its author nor a maintainer.
     async fn verify_authorized_for_review(
         &self,
         request: ReviewAuthorizationRequest,
@@ -139,12 +147,12 @@ pub trait AuthorizationService: Send + Sync + 'static {
 
     /// Asserts the caller may participate in reviewing `owner/repo#number`.
     ///
-    /// Permitted when the caller is the review author or one of its assigned
-    /// reviewers.
+    /// Permitted when the caller is the review author, one of its assigned
+    /// reviewers, or holds [`RepositoryPermission::Write`] on the repo.
     ///
     /// # Errors
     /// - [`AuthorizationError::Unauthorized`] — the review does not exist, or
-    ///   the caller is neither the author nor an assigned reviewer.
+    ///   the caller is neither the author, an assigned reviewer, nor a writer.
     async fn verify_authorized_for_reviewing(
         &self,
         request: ReviewingAuthorizationRequest,
@@ -207,6 +215,65 @@ impl
     }
 }
 
+impl<O, R, Q, U, V> AuthorizationServiceImpl<O, R, Q, U, V>
+where
+    O: OrganizationRepository,
+    R: RepositoryRepository,
+    Q: QuestionRepository,
+    U: UserRepository,
+    V: ReviewRepository,
+{
+    /// Resolves the caller's effective permission on `repository` as
+    /// documented on [`AuthorizationService::verify_authorized_for_repository`],
+    /// or `None` if they have no access beyond what its visibility allows.
+    async fn get_repository_permission(
+        &self,
+        repository: &Repository,
+        user_id: Uuid,
+    ) -> Result<Option<RepositoryPermission>, AuthorizationError> {
+        let base = if repository.is_owned_by_user() {
+            (repository.owner_id == user_id).then_some(RepositoryPermission::Admin)
+        } else {
+            match self
+                .org_repo
+                .get_member_role(&repository.owner_name, user_id)
+                .await?
+            {
+                Some(OrganizationRole::Admin) => Some(RepositoryPermission::Admin),
+                Some(OrganizationRole::Member) => Some(RepositoryPermission::Read),
+                None => None,
+            }
+        };
+        if base == Some(RepositoryPermission::Admin) {
+            return Ok(base);
+        }
+
+        let granted = self
+            .repo_repo
+            .get_granted_role(repository.id, user_id)
+            .await?
+            .map(RepositoryPermission::from);
+        Ok(base.max(granted))
+    }
+
+    /// Whether `user_id` holds at least `permission` on `owner/repo`. A missing
+    /// repository grants nothing.
+    async fn has_repository_permission(
+        &self,
+        owner: &str,
+        repo: &str,
+        user_id: Uuid,
+        permission: RepositoryPermission,
+    ) -> Result<bool, AuthorizationError> {
+        let Some(repository) = self.repo_repo.get(owner, repo, None).await? else {
+            return Ok(false);
+        };
+
+        let granted = self.get_repository_permission(&repository, user_id).await?;
+        Ok(granted >= Some(permission))
+    }
+}
+
 #[crate::instrument_all(level = "debug")]
 #[async_trait]
 impl<O, R, Q, U, V> AuthorizationService for AuthorizationServiceImpl<O, R, Q, U, V>
@@ -262,7 +329,7 @@ where
                 format!("{}/{}", request.owner.as_ref(), request.repo.as_ref()),
             )?;
 
-        if repository.readonly && request.permission == RepositoryPermission::Write {
+        if repository.readonly && request.permission.writes_contents() {
             return Err(AuthorizationError::ReadonlyRepository);
         }
 
@@ -271,26 +338,9 @@ where
         }
 
         let user_id = request.user_id.ok_or(AuthorizationError::Unauthorized)?;
-        if repository.is_owned_by_user() {
-            if repository.owner_id != user_id {
-                return Err(AuthorizationError::Unauthorized);
-            }
-        } else {
-            let authorized = if request.permission == RepositoryPermission::Admin {
-                matches!(
-                    self.org_repo
-                        .get_member_role(request.owner.as_ref(), user_id)
-                        .await?,
-                    Some(OrganizationRole::Admin)
-                )
-            } else {
-                self.org_repo
-                    .is_member(repository.owner_id, user_id)
-                    .await?
-            };
-            if !authorized {
-                return Err(AuthorizationError::Unauthorized);
-            }
+        let permission = self.get_repository_permission(&repository, user_id).await?;
+        if permission < Some(request.permission) {
+            return Err(AuthorizationError::Unauthorized);
         }
 
         Ok(())
@@ -350,7 +400,16 @@ where
             .await?
             .ok_or(AuthorizationError::Unauthorized)?;
 
-        if author_id != request.user_id {
+        if author_id != request.user_id
+            && !self
+                .has_repository_permission(
+                    request.owner.as_ref(),
+                    request.repo.as_ref(),
+                    request.user_id,
+                    RepositoryPermission::Triage,
+                )
+                .await?
+        {
             return Err(AuthorizationError::Unauthorized);
         }
 
@@ -405,7 +464,16 @@ where
             .await?
             .ok_or(AuthorizationError::Unauthorized)?;
 
-        if review.author_id != request.user_id {
+        if review.author_id != request.user_id
+            && !self
+                .has_repository_permission(
+                    request.owner.as_ref(),
+                    request.repo.as_ref(),
+                    request.user_id,
+                    RepositoryPermission::Maintain,
+                )
+                .await?
+        {
             return Err(AuthorizationError::Unauthorized);
         }
 
@@ -445,7 +513,17 @@ where
 
         let is_author = review.author_id == request.user_id;
         let reviewers = review.reviewers.unwrap_or_default();
-        if !is_author && !reviewers.iter().any(|r| r.reviewer_id == request.user_id) {
+        if !is_author
+            && !reviewers.iter().any(|r| r.reviewer_id == request.user_id)
+            && !self
+                .has_repository_permission(
+                    request.owner.as_ref(),
+                    request.repo.as_ref(),
+                    request.user_id,
+                    RepositoryPermission::Write,
+                )
+                .await?
+        {
             return Err(AuthorizationError::Unauthorized);
         }
 
@@ -493,7 +571,7 @@ mod tests {
     use crate::{
         dto::{RepositoryAuthorizationRequest, RepositoryPermission},
         error::AuthorizationError,
-        model::{RepositoryOwnerType, RepositoryVisibility},
+        model::{OrganizationRole, RepositoryOwnerType, RepositoryRole, RepositoryVisibility},
         service::{
             test_common::create_repository,
             test_repository::{
@@ -617,6 +695,9 @@ mod tests {
                 RepositoryVisibility::Private,
             )))
         });
+        repo_repo
+            .expect_get_granted_role()
+            .returning(|_, _| Ok(None));
 
         let service = create_service(MockOrganizationRepository::new(), repo_repo);
         let request = create_repo_auth_request(Some(Uuid::new_v4()), RepositoryPermission::Read);
@@ -662,12 +743,15 @@ mod tests {
                 RepositoryVisibility::Private,
             )))
         });
+        repo_repo
+            .expect_get_granted_role()
+            .returning(|_, _| Ok(None));
 
         let mut org_repo = MockOrganizationRepository::new();
         org_repo
-            .expect_is_member()
-            .withf(move |oid, uid| *oid == owner_id && *uid == user_id)
-            .returning(|_, _| Ok(true));
+            .expect_get_member_role()
+            .withf(move |name, uid| name == "owner" && *uid == user_id)
+            .returning(|_, _| Ok(Some(OrganizationRole::Member)));
 
         let service = create_service(org_repo, repo_repo);
         let request = create_repo_auth_request(Some(user_id), RepositoryPermission::Read);
@@ -689,9 +773,12 @@ mod tests {
                 RepositoryVisibility::Private,
             )))
         });
+        repo_repo
+            .expect_get_granted_role()
+            .returning(|_, _| Ok(None));
 
         let mut org_repo = MockOrganizationRepository::new();
-        org_repo.expect_is_member().returning(|_, _| Ok(false));
+        org_repo.expect_get_member_role().returning(|_, _| Ok(None));
 
         let service = create_service(org_repo, repo_repo);
         let request = create_repo_auth_request(Some(user_id), RepositoryPermission::Read);
@@ -756,6 +843,9 @@ mod tests {
                 RepositoryVisibility::Public,
             )))
         });
+        repo_repo
+            .expect_get_granted_role()
+            .returning(|_, _| Ok(None));
 
         let service = create_service(MockOrganizationRepository::new(), repo_repo);
         let request = create_repo_auth_request(Some(Uuid::new_v4()), RepositoryPermission::Write);
@@ -790,7 +880,7 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn write_org_repo_by_member() {
+    async fn write_org_repo_by_member_without_grant() {
         let owner_id = Uuid::new_v4();
         let user_id = Uuid::new_v4();
         let mut repo_repo = MockRepositoryRepository::new();
@@ -801,19 +891,23 @@ mod tests {
                 RepositoryVisibility::Public,
             )))
         });
+        repo_repo
+            .expect_get_granted_role()
+            .returning(|_, _| Ok(None));
 
         let mut org_repo = MockOrganizationRepository::new();
         org_repo
-            .expect_is_member()
-            .withf(move |oid, uid| *oid == owner_id && *uid == user_id)
-            .returning(|_, _| Ok(true));
+            .expect_get_member_role()
+            .returning(|_, _| Ok(Some(OrganizationRole::Member)));
 
         let service = create_service(org_repo, repo_repo);
         let request = create_repo_auth_request(Some(user_id), RepositoryPermission::Write);
-        service
+        let err = service
             .verify_authorized_for_repository(request)
             .await
-            .unwrap();
+            .unwrap_err();
+
+        assert!(matches!(err, AuthorizationError::Unauthorized));
     }
 
     #[tokio::test]
@@ -828,9 +922,12 @@ mod tests {
                 RepositoryVisibility::Public,
             )))
         });
+        repo_repo
+            .expect_get_granted_role()
+            .returning(|_, _| Ok(None));
 
         let mut org_repo = MockOrganizationRepository::new();
-        org_repo.expect_is_member().returning(|_, _| Ok(false));
+        org_repo.expect_get_member_role().returning(|_, _| Ok(None));
 
         let service = create_service(org_repo, repo_repo);
         let request = create_repo_auth_request(Some(user_id), RepositoryPermission::Write);
@@ -895,6 +992,9 @@ mod tests {
                 RepositoryVisibility::Public,
             )))
         });
+        repo_repo
+            .expect_get_granted_role()
+            .returning(|_, _| Ok(None));
 
         let service = create_service(MockOrganizationRepository::new(), repo_repo);
         let request = create_repo_auth_request(Some(Uuid::new_v4()), RepositoryPermission::Admin);
@@ -945,7 +1045,7 @@ mod tests {
         org_repo
             .expect_get_member_role()
             .withf(move |name, uid| name == "owner" && *uid == user_id)
-            .returning(|_, _| Ok(Some(crate::model::OrganizationRole::Admin)));
+            .returning(|_, _| Ok(Some(OrganizationRole::Admin)));
 
         let service = create_service(org_repo, repo_repo);
         let request = create_repo_auth_request(Some(user_id), RepositoryPermission::Admin);
@@ -967,11 +1067,14 @@ mod tests {
                 RepositoryVisibility::Public,
             )))
         });
+        repo_repo
+            .expect_get_granted_role()
+            .returning(|_, _| Ok(None));
 
         let mut org_repo = MockOrganizationRepository::new();
         org_repo
             .expect_get_member_role()
-            .returning(|_, _| Ok(Some(crate::model::OrganizationRole::Member)));
+            .returning(|_, _| Ok(Some(OrganizationRole::Member)));
 
         let service = create_service(org_repo, repo_repo);
         let request = create_repo_auth_request(Some(user_id), RepositoryPermission::Admin);
@@ -995,6 +1098,9 @@ mod tests {
                 RepositoryVisibility::Public,
             )))
         });
+        repo_repo
+            .expect_get_granted_role()
+            .returning(|_, _| Ok(None));
 
         let mut org_repo = MockOrganizationRepository::new();
         org_repo.expect_get_member_role().returning(|_, _| Ok(None));
@@ -1049,7 +1155,7 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn org_is_member_db_error() {
+    async fn granted_role_db_error() {
         let owner_id = Uuid::new_v4();
         let user_id = Uuid::new_v4();
         let mut repo_repo = MockRepositoryRepository::new();
@@ -1061,11 +1167,13 @@ mod tests {
             )))
         });
 
-        let mut org_repo = MockOrganizationRepository::new();
-        org_repo
-            .expect_is_member()
+        repo_repo
+            .expect_get_granted_role()
             .returning(|_, _| Err(crate::error::DatabaseError::RowNotFound));
 
+        let mut org_repo = MockOrganizationRepository::new();
+        org_repo.expect_get_member_role().returning(|_, _| Ok(None));
+
         let service = create_service(org_repo, repo_repo);
         let request = create_repo_auth_request(Some(user_id), RepositoryPermission::Read);
         let err = service
@@ -1172,4 +1280,220 @@ mod tests {
             .await
             .unwrap();
     }
+
+    #[tokio::test]
+    async fn write_org_repo_by_member_with_team_grant() {
+        let owner_id = Uuid::new_v4();
+        let user_id = Uuid::new_v4();
+        let mut repo_repo = MockRepositoryRepository::new();
+        repo_repo.expect_get().returning(move |_, _, _| {
+            Ok(Some(create_repository(
+                owner_id,
+                RepositoryOwnerType::Organization,
+                RepositoryVisibility::Private,
+            )))
+        });
+        repo_repo
+            .expect_get_granted_role()
+            .withf(move |_, uid| *uid == user_id)
+            .returning(|_, _| Ok(Some(RepositoryRole::Write)));
+
+        let mut org_repo = MockOrganizationRepository::new();
+        org_repo
+            .expect_get_member_role()
+            .returning(|_, _| Ok(Some(OrganizationRole::Member)));
+
+        let service = create_service(org_repo, repo_repo);
+        let request = create_repo_auth_request(Some(user_id), RepositoryPermission::Write);
+        service
+            .verify_authorized_for_repository(request)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn org_admin_skips_grant_lookup() {
+        let owner_id = Uuid::new_v4();
+        let user_id = Uuid::new_v4();
+        let mut repo_repo = MockRepositoryRepository::new();
+        repo_repo.expect_get().returning(move |_, _, _| {
+            Ok(Some(create_repository(
+                owner_id,
+                RepositoryOwnerType::Organization,
+                RepositoryVisibility::Private,
+            )))
+        });
+        repo_repo.expect_get_granted_role().never();
+
+        let mut org_repo = MockOrganizationRepository::new();
+        org_repo
+            .expect_get_member_role()
+            .returning(|_, _| Ok(Some(OrganizationRole::Admin)));
+
+        let service = create_service(org_repo, repo_repo);
+        let request = create_repo_auth_request(Some(user_id), RepositoryPermission::Write);
+        service
+            .verify_authorized_for_repository(request)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn read_private_user_repo_by_collaborator() {
+        let owner_id = Uuid::new_v4();
+        let mut repo_repo = MockRepositoryRepository::new();
+        repo_repo.expect_get().returning(move |_, _, _| {
+            Ok(Some(create_repository(
+                owner_id,
+                RepositoryOwnerType::User,
+                RepositoryVisibility::Private,
+            )))
+        });
+        repo_repo
+            .expect_get_granted_role()
+            .returning(|_, _| Ok(Some(RepositoryRole::Read)));
+
+        let service = create_service(MockOrganizationRepository::new(), repo_repo);
+        let request = create_repo_auth_request(Some(Uuid::new_v4()), RepositoryPermission::Read);
+        service
+            .verify_authorized_for_repository(request)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn write_user_repo_by_write_collaborator() {
+        let owner_id = Uuid::new_v4();
+        let mut repo_repo = MockRepositoryRepository::new();
+        repo_repo.expect_get().returning(move |_, _, _| {
+            Ok(Some(create_repository(
+                owner_id,
+                RepositoryOwnerType::User,
+                RepositoryVisibility::Public,
+            )))
+        });
+        repo_repo
+            .expect_get_granted_role()
+            .returning(|_, _| Ok(Some(RepositoryRole::Write)));
+
+        let service = create_service(MockOrganizationRepository::new(), repo_repo);
+        let request = create_repo_auth_request(Some(Uuid::new_v4()), RepositoryPermission::Write);
+        service
+            .verify_authorized_for_repository(request)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn write_user_repo_by_triage_collaborator() {
+        let owner_id = Uuid::new_v4();
+        let mut repo_repo = MockRepositoryRepository::new();
+        repo_repo.expect_get().returning(move |_, _, _| {
+            Ok(Some(create_repository(
+                owner_id,
+                RepositoryOwnerType::User,
+                RepositoryVisibility::Public,
+            )))
+        });
+        repo_repo
+            .expect_get_granted_role()
+            .returning(|_, _| Ok(Some(RepositoryRole::Triage)));
+
+        let service = create_service(MockOrganizationRepository::new(), repo_repo);
+        let request = create_repo_auth_request(Some(Uuid::new_v4()), RepositoryPermission::Triage);
+        service
+            .verify_authorized_for_repository(request)
+            .await
+            .unwrap();
+
+        let request = create_repo_auth_request(Some(Uuid::new_v4()), RepositoryPermission::Write);
+        let err = service
+            .verify_authorized_for_repository(request)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, AuthorizationError::Unauthorized));
+    }
+
+    #[tokio::test]
+    async fn admin_user_repo_by_maintain_collaborator() {
+        let owner_id = Uuid::new_v4();
+        let mut repo_repo = MockRepositoryRepository::new();
+        repo_repo.expect_get().returning(move |_, _, _| {
+            Ok(Some(create_repository(
+                owner_id,
+                RepositoryOwnerType::User,
+                RepositoryVisibility::Public,
+            )))
+        });
+        repo_repo
+            .expect_get_granted_role()
+            .returning(|_, _| Ok(Some(RepositoryRole::Maintain)));
+
+        let service = create_service(MockOrganizationRepository::new(), repo_repo);
+        let request =
+            create_repo_auth_request(Some(Uuid::new_v4()), RepositoryPermission::Maintain);
+        service
+            .verify_authorized_for_repository(request)
+            .await
+            .unwrap();
+
+        let request = create_repo_auth_request(Some(Uuid::new_v4()), RepositoryPermission::Admin);
+        let err = service
+            .verify_authorized_for_repository(request)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, AuthorizationError::Unauthorized));
+    }
+
+    #[tokio::test]
+    async fn admin_org_repo_by_admin_collaborator() {
+        let owner_id = Uuid::new_v4();
+        let mut repo_repo = MockRepositoryRepository::new();
+        repo_repo.expect_get().returning(move |_, _, _| {
+            Ok(Some(create_repository(
+                owner_id,
+                RepositoryOwnerType::Organization,
+                RepositoryVisibility::Public,
+            )))
+        });
+        repo_repo
+            .expect_get_granted_role()
+            .returning(|_, _| Ok(Some(RepositoryRole::Admin)));
+
+        let mut org_repo = MockOrganizationRepository::new();
+        org_repo.expect_get_member_role().returning(|_, _| Ok(None));
+
+        let service = create_service(org_repo, repo_repo);
+        let request = create_repo_auth_request(Some(Uuid::new_v4()), RepositoryPermission::Admin);
+        service
+            .verify_authorized_for_repository(request)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn maintain_readonly_repo_by_owner() {
+        let owner_id = Uuid::new_v4();
+        let mut repo_repo = MockRepositoryRepository::new();
+        repo_repo.expect_get().returning(move |_, _, _| {
+            let mut repo = create_repository(
+                owner_id,
+                RepositoryOwnerType::User,
+                RepositoryVisibility::Public,
+            );
+            repo.readonly = true;
+            Ok(Some(repo))
+        });
+
+        let service = create_service(MockOrganizationRepository::new(), repo_repo);
+        let request = create_repo_auth_request(Some(owner_id), RepositoryPermission::Maintain);
+        let err = service
+            .verify_authorized_for_repository(request)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, AuthorizationError::ReadonlyRepository));
+    }
 }
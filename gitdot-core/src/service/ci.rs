@@ -1,7 +1,11 @@
+mod artifact;
 mod build;
 mod runner;
+mod secret;
 mod task;
 
+pub use artifact::{ArtifactService, ArtifactServiceImpl};
 pub use build::{BuildService, BuildServiceImpl};
 pub use runner::{RunnerService, RunnerServiceImpl};
+pub use secret::{CiSecretService, CiSecretServiceImpl};
 pub use task::{TaskService, TaskServiceImpl};
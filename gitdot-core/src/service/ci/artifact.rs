@@ -0,0 +1,394 @@
+use async_trait::async_trait;
+use bytes::Bytes;
+use uuid::Uuid;
+
+use crate::{
+    client::{R2Client, R2ClientImpl},
+    dto::{ArtifactDownload, ArtifactResponse, UploadArtifactRequest, UploadCacheRequest},
+    error::{ArtifactError, InputError, OptionNotFoundExt},
+    model::{Build, Task},
+    repository::{
+        ArtifactRepository, BuildRepository, CacheRepository, PgArtifactRepository,
+        PgBuildRepository, PgCacheRepository, PgRepositoryRepository, PgTaskRepository,
+        RepositoryRepository, TaskRepository,
+    },
+};
+
+/// CI artifacts and caches: storing the tarballs runners upload for a task's
+/// `artifacts` paths and `cache` key in object storage, serving artifacts to
+/// build pages and to the tasks that wait for them, and restoring caches into
+/// later builds.
+#[async_trait]
+pub trait ArtifactService: Send + Sync + 'static {
+    /// Stores an artifact archive uploaded by the runner of `request.task_id`.
+    /// Uploading the same name again (a retried task) replaces the previous
+    /// archive.
+    ///
+    /// # Errors
+    /// - [`ArtifactError::NotFound`] if no task has `request.task_id`.
+    /// - [`ArtifactError::Input`] if the task's config does not list
+    ///   `request.name` under `artifacts`.
+    /// - [`ArtifactError::R2Error`] if the archive could not be stored.
+    async fn upload_artifact(
+        &self,
+        request: UploadArtifactRequest,
+    ) -> Result<ArtifactResponse, ArtifactError>;
+
+    /// Lists the artifacts uploaded by the tasks `task_id` waits for, which
+    /// its runner restores into the checkout before running the command.
+    ///
+    /// # Errors
+    /// - [`ArtifactError::NotFound`] if no task has `task_id`.
+    async fn list_task_artifacts(
+        &self,
+        task_id: Uuid,
+    ) -> Result<Vec<ArtifactResponse>, ArtifactError>;
+
+    /// Downloads an artifact listed by
+    /// [`list_task_artifacts`](ArtifactService::list_task_artifacts).
+    ///
+    /// # Errors
+    /// - [`ArtifactError::NotFound`] if no task has `task_id`, or the artifact
+    ///   does not exist or was not uploaded by a task it waits for.
+    /// - [`ArtifactError::R2Error`] if the archive could not be read.
+    async fn download_task_artifact(
+        &self,
+        task_id: Uuid,
+        artifact_id: Uuid,
+    ) -> Result<ArtifactDownload, ArtifactError>;
+
+    /// Stores the cache archive uploaded by the runner of `request.task_id`
+    /// under the task's cache key in its build's
+    /// [cache scope](crate::model::Build::cache_scope), replacing whatever was
+    /// saved there before. Runners only upload caches for successful tasks.
+    ///
+    /// # Errors
+    /// - [`ArtifactError::NotFound`] if no task has `request.task_id`.
+    /// - [`ArtifactError::Input`] if the task's config declares no `cache`.
+    /// - [`ArtifactError::R2Error`] if the archive could not be stored.
+    async fn upload_cache(&self, request: UploadCacheRequest) -> Result<(), ArtifactError>;
+
+    /// Downloads the archive last saved under the cache key of `task_id` in
+    /// its build's cache scope, falling back to the default branch's scope.
+    /// Returns `None` if the task declares no cache or nothing has been saved
+    /// under its key yet.
+    ///
+    /// # Errors
+    /// - [`ArtifactError::NotFound`] if no task has `task_id`.
+    /// - [`ArtifactError::R2Error`] if the archive could not be read.
+    async fn download_cache(&self, task_id: Uuid) -> Result<Option<Bytes>, ArtifactError>;
+
+    /// Lists the artifacts of a build identified by its `number`.
+    ///
+    /// # Errors
+    /// - [`ArtifactError::NotFound`] if the repository or build does not
+    ///   exist.
+    async fn list_build_artifacts(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: i32,
+    ) -> Result<Vec<ArtifactResponse>, ArtifactError>;
+
+    /// Downloads an artifact of a build identified by its `number`.
+    ///
+    /// # Errors
+    /// - [`ArtifactError::NotFound`] if the repository, build, or artifact
+    ///   does not exist, or the artifact belongs to another build.
+    /// - [`ArtifactError::R2Error`] if the archive could not be read.
+    async fn download_build_artifact(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: i32,
+        artifact_id: Uuid,
+    ) -> Result<ArtifactDownload, ArtifactError>;
+}
+
+#[derive(Debug, Clone)]
+pub struct ArtifactServiceImpl<C, A, K, T, B, R>
+where
+    C: R2Client,
+    A: ArtifactRepository,
+    K: CacheRepository,
+    T: TaskRepository,
+    B: BuildRepository,
+    R: RepositoryRepository,
+{
+    r2_client: C,
+    artifact_repo: A,
+    cache_repo: K,
+    task_repo: T,
+    build_repo: B,
+    repo_repo: R,
+}
+
+impl
+    ArtifactServiceImpl<
+        R2ClientImpl,
+        PgArtifactRepository,
+        PgCacheRepository,
+        PgTaskRepository,
+        PgBuildRepository,
+        PgRepositoryRepository,
+    >
+{
+    pub fn new(
+        r2_client: R2ClientImpl,
+        artifact_repo: PgArtifactRepository,
+        cache_repo: PgCacheRepository,
+        task_repo: PgTaskRepository,
+        build_repo: PgBuildRepository,
+        repo_repo: PgRepositoryRepository,
+    ) -> Self {
+        Self {
+            r2_client,
+            artifact_repo,
+            cache_repo,
+            task_repo,
+            build_repo,
+            repo_repo,
+        }
+    }
+}
+
+impl<C, A, K, T, B, R> ArtifactServiceImpl<C, A, K, T, B, R>
+where
+    C: R2Client,
+    A: ArtifactRepository,
+    K: CacheRepository,
+    T: TaskRepository,
+    B: BuildRepository,
+    R: RepositoryRepository,
+{
+    async fn get_task(&self, id: Uuid) -> Result<Task, ArtifactError> {
+        Ok(self
+            .task_repo
+            .get_by_id(id)
+            .await?
+            .or_not_found("task", id)?)
+    }
+
+    async fn get_build_by_id(&self, id: Uuid) -> Result<Build, ArtifactError> {
+        Ok(self
+            .build_repo
+            .get_by_id(id)
+            .await?
+            .or_not_found("build", id)?)
+    }
+
+    async fn get_build(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: i32,
+    ) -> Result<Build, ArtifactError> {
+        let repository = self
+            .repo_repo
+            .get(owner, repo, None)
+            .await?
+            .or_not_found("repository", format!("{owner}/{repo}"))?;
+
+        Ok(self
+            .build_repo
+            .get(repository.id, number)
+            .await?
+            .or_not_found("build", format!("{owner}/{repo}#{number}"))?)
+    }
+
+    async fn download(
+        &self,
+        object_key: &str,
+        name: String,
+    ) -> Result<ArtifactDownload, ArtifactError> {
+        let bytes = self
+            .r2_client
+            .get_object(object_key)
+            .await?
+            .or_not_found("artifact", &name)?;
+
+        Ok(ArtifactDownload { name, bytes })
+    }
+
+    /// Removes an archive that was replaced by a newer upload. The new archive
+    /// is already stored, so a failure only leaves an orphaned object behind.
+    async fn delete_replaced(&self, object_key: &str) {
+        if let Err(e) = self.r2_client.delete_object(object_key).await {
+            tracing::warn!(error = %e, object_key, "failed to delete replaced CI archive");
+        }
+    }
+}
+
+#[crate::instrument_all(level = "debug")]
+#[async_trait]
+impl<C, A, K, T, B, R> ArtifactService for ArtifactServiceImpl<C, A, K, T, B, R>
+where
+    C: R2Client,
+    A: ArtifactRepository,
+    K: CacheRepository,
+    T: TaskRepository,
+    B: BuildRepository,
+    R: RepositoryRepository,
+{
+    async fn upload_artifact(
+        &self,
+        request: UploadArtifactRequest,
+    ) -> Result<ArtifactResponse, ArtifactError> {
+        let task = self.get_task(request.task_id).await?;
+        if !task.options.artifacts.contains(&request.name) {
+            return Err(InputError::new(
+                "artifact",
+                format!("'{}' is not declared by task '{}'", request.name, task.name),
+            )
+            .into());
+        }
+
+        let previous = self
+            .artifact_repo
+            .get_by_task(task.id, &request.name)
+            .await?;
+
+        let object_key = format!(
+            "artifacts/{}/{}/{}.tar.gz",
+            task.repository_id,
+            task.build_id,
+            Uuid::new_v4()
+        );
+        let size_bytes = request.bytes.len() as i64;
+        self.r2_client
+            .upload_object(&object_key, request.bytes)
+            .await?;
+
+        let artifact = self
+            .artifact_repo
+            .upsert(
+                task.repository_id,
+                task.build_id,
+                task.id,
+                &request.name,
+                &object_key,
+                size_bytes,
+            )
+            .await?;
+
+        if let Some(previous) = previous {
+            self.delete_replaced(&previous.object_key).await;
+        }
+
+        Ok(artifact.into())
+    }
+
+    async fn list_task_artifacts(
+        &self,
+        task_id: Uuid,
+    ) -> Result<Vec<ArtifactResponse>, ArtifactError> {
+        let task = self.get_task(task_id).await?;
+        if task.waits_for.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let artifacts = self.artifact_repo.list_by_task_ids(&task.waits_for).await?;
+
+        Ok(artifacts.into_iter().map(Into::into).collect())
+    }
+
+    async fn download_task_artifact(
+        &self,
+        task_id: Uuid,
+        artifact_id: Uuid,
+    ) -> Result<ArtifactDownload, ArtifactError> {
+        let task = self.get_task(task_id).await?;
+        let artifact = self
+            .artifact_repo
+            .get_by_id(artifact_id)
+            .await?
+            .filter(|artifact| task.waits_for.contains(&artifact.task_id))
+            .or_not_found("artifact", artifact_id)?;
+
+        self.download(&artifact.object_key, artifact.name).await
+    }
+
+    async fn upload_cache(&self, request: UploadCacheRequest) -> Result<(), ArtifactError> {
+        let task = self.get_task(request.task_id).await?;
+        let Some(key) = &task.options.cache_key else {
+            return Err(InputError::new(
+                "cache",
+                format!("task '{}' does not declare a cache", task.name),
+            )
+            .into());
+        };
+
+        let scope = self.get_build_by_id(task.build_id).await?.cache_scope();
+        let previous = self.cache_repo.get(task.repository_id, &scope, key).await?;
+
+        let object_key = format!("caches/{}/{}.tar.gz", task.repository_id, Uuid::new_v4());
+        let size_bytes = request.bytes.len() as i64;
+        self.r2_client
+            .upload_object(&object_key, request.bytes)
+            .await?;
+
+        self.cache_repo
+            .upsert(task.repository_id, &scope, key, &object_key, size_bytes)
+            .await?;
+
+        if let Some(previous) = previous {
+            self.delete_replaced(&previous.object_key).await;
+        }
+
+        Ok(())
+    }
+
+    async fn download_cache(&self, task_id: Uuid) -> Result<Option<Bytes>, ArtifactError> {
+        let task = self.get_task(task_id).await?;
+        let Some(key) = &task.options.cache_key else {
+            return Ok(None);
+        };
+
+        // a build falls back to the default branch's cache, never to another
+        // scope's
+        let scope = self.get_build_by_id(task.build_id).await?.cache_scope();
+        let mut cache = self.cache_repo.get(task.repository_id, &scope, key).await?;
+        let default_scope = Build::default_cache_scope();
+        if cache.is_none() && scope != default_scope {
+            cache = self
+                .cache_repo
+                .get(task.repository_id, &default_scope, key)
+                .await?;
+        }
+        let Some(cache) = cache else {
+            return Ok(None);
+        };
+
+        Ok(self.r2_client.get_object(&cache.object_key).await?)
+    }
+
+    async fn list_build_artifacts(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: i32,
+    ) -> Result<Vec<ArtifactResponse>, ArtifactError> {
+        let build = self.get_build(owner, repo, number).await?;
+        let artifacts = self.artifact_repo.list_by_build_id(build.id).await?;
+
+        Ok(artifacts.into_iter().map(Into::into).collect())
+    }
+
+    async fn download_build_artifact(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: i32,
+        artifact_id: Uuid,
+    ) -> Result<ArtifactDownload, ArtifactError> {
+        let build = self.get_build(owner, repo, number).await?;
+        let artifact = self
+            .artifact_repo
+            .get_by_id(artifact_id)
+            .await?
+            .filter(|artifact| artifact.build_id == build.id)
+            .or_not_found("artifact", artifact_id)?;
+
+        self.download(&artifact.object_key, artifact.name).await
+    }
+}
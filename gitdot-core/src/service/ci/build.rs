@@ -1,14 +1,15 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use async_trait::async_trait;
 use futures::future::try_join_all;
+use gitdot_config::ci::{BuildContext, BuildTrigger as CiBuildTrigger, TaskConfig};
 use uuid::Uuid;
 
 use crate::{
     client::{Git2Client, GitClient, S2Client, S2ClientImpl},
     dto::{BuildResponse, CiConfig, CreateBuildRequest, ListBuildsRequest, Page, TaskResponse},
     error::{BuildError, GitError, NotFoundError, OptionNotFoundExt},
-    model::{BuildStatus, TaskStatus},
+    model::{BuildStatus, Task, TaskOptions, TaskStatus},
     repository::{
         BuildRepository, PgBuildRepository, PgRepositoryRepository, PgTaskRepository,
         RepositoryRepository, TaskRepository,
@@ -21,24 +22,31 @@ use crate::{
 /// aggregated from its tasks.
 #[async_trait]
 pub trait BuildService: Send + Sync + 'static {
-    /// Creates a build for `request.commit_sha` and fans it out into tasks.
+    /// Creates a manually dispatched build for `request.commit_sha` and fans
+    /// it out into tasks.
     ///
     /// Resolves the commit, reads `.gitdot-ci.toml` at that commit, and parses
-    /// it. The trigger is [`PushToMain`] when `request.ref_name` is (or ends in)
-    /// the default branch, otherwise [`PullRequest`]; the matching build config
-    /// selects which tasks to create. UUIDs are pre-generated for every task so
-    /// `waits_for` dependencies can reference each other by ID. Each task gets a
-    /// dedicated S2 log stream and is created [`Blocked`] if it has
-    /// dependencies, otherwise [`Pending`]. The returned response always reports
-    /// [`Running`] with `completed_tasks` of 0.
+    /// it. The config's build for `request.trigger` selects which tasks to
+    /// create; a [`Manual`] request against a config without a manual build
+    /// falls back to [`PushToMain`] when `request.ref_name` is (or ends in) the
+    /// default branch, otherwise [`PullRequest`]. The build's `branches`,
+    /// `tags`, and `paths` filters must accept the request, and tasks whose
+    /// `if` condition is false are left out (dependencies on them are
+    /// dropped). UUIDs are pre-generated for every task so `waits_for`
+    /// dependencies can reference each other by ID. Each task gets a dedicated
+    /// S2 log stream, the task options from its config, and is created
+    /// [`Blocked`] if it has dependencies, otherwise [`Pending`]. The returned
+    /// response always reports [`Running`] with `completed_tasks` of 0.
     ///
     /// # Errors
     /// - [`BuildError::NotFound`] if the repository, commit, or `.gitdot-ci.toml`
     ///   (missing, or a folder) is absent.
-    /// - [`BuildError::InvalidConfig`] if the config cannot be parsed or has no
-    ///   matching build config for the trigger.
+    /// - [`BuildError::InvalidConfig`] if the config cannot be parsed, has no
+    ///   matching build config for the trigger, or its filters leave no tasks
+    ///   to run.
     /// - [`BuildError::S2Error`] if a task log stream cannot be created.
     ///
+    /// [`Manual`]: gitdot_config::ci::BuildTrigger::Manual
     /// [`PushToMain`]: gitdot_config::ci::BuildTrigger::PushToMain
     /// [`PullRequest`]: gitdot_config::ci::BuildTrigger::PullRequest
     /// [`Blocked`]: crate::model::TaskStatus::Blocked
@@ -46,7 +54,29 @@ pub trait BuildService: Send + Sync + 'static {
     /// [`Running`]: crate::model::BuildStatus::Running
     async fn create_build(&self, request: CreateBuildRequest) -> Result<BuildResponse, BuildError>;
 
-    /// Lists builds for a repository, newest first, cursor-paginated.
+    /// Starts a build automatically in response to a push or a new review
+    /// revision.
+    ///
+    /// Behaves like [`create_build`](BuildService::create_build), except that
+    /// a commit is skipped rather than rejected when it has no
+    /// `.gitdot-ci.toml`, the config has no build for `request.trigger`, or the
+    /// build's filters leave no tasks to run. When `request.review_link` is
+    /// set the build is linked to that review, diff, and revision, and it is
+    /// skipped if the revision's latest build is already of
+    /// `request.commit_sha`. Path filters diff against `request.base_sha`.
+    /// Returns `Ok(None)` when skipped.
+    ///
+    /// # Errors
+    /// - [`BuildError::NotFound`] if the repository or commit is absent.
+    /// - [`BuildError::InvalidConfig`] if the config cannot be parsed.
+    /// - [`BuildError::S2Error`] if a task log stream cannot be created.
+    async fn trigger_build(
+        &self,
+        request: CreateBuildRequest,
+    ) -> Result<Option<BuildResponse>, BuildError>;
+
+    /// Lists builds for a repository, newest first, cursor-paginated. When
+    /// `request.review_number` is set, only that review's builds are listed.
     ///
     /// # Errors
     /// - [`BuildError::NotFound`] if the repository does not exist.
@@ -58,14 +88,17 @@ pub trait BuildService: Send + Sync + 'static {
     /// Fetches a single build by its per-repository `number`.
     ///
     /// Build status and `updated_at` are derived from the build's tasks:
-    /// [`Failure`] if any task failed, [`Success`] if all tasks succeeded,
-    /// otherwise (including a build with no tasks) [`Running`]; `updated_at` is
+    /// [`Failure`] if any task without `allow_failure` failed or timed out,
+    /// else [`Cancelled`] if any task was cancelled, else [`Success`] if all
+    /// tasks finished, otherwise (including a build with no tasks)
+    /// [`Running`]; `completed_tasks` counts finished tasks; `updated_at` is
     /// the latest task update, falling back to the build's creation time.
     ///
     /// # Errors
     /// - [`BuildError::NotFound`] if the repository or build does not exist.
     ///
     /// [`Failure`]: crate::model::BuildStatus::Failure
+    /// [`Cancelled`]: crate::model::BuildStatus::Cancelled
     /// [`Success`]: crate::model::BuildStatus::Success
     /// [`Running`]: crate::model::BuildStatus::Running
     async fn get_build(
@@ -85,6 +118,49 @@ pub trait BuildService: Send + Sync + 'static {
         repo: &str,
         number: i32,
     ) -> Result<Vec<TaskResponse>, BuildError>;
+
+    /// Cancels a build: every task that has not finished is set to
+    /// [`Cancelled`]. Runners executing a cancelled task see the status on
+    /// their next heartbeat and stop it. Cancelling a finished build changes
+    /// nothing. Returns the build as [`get_build`](BuildService::get_build)
+    /// reports it afterwards.
+    ///
+    /// # Errors
+    /// - [`BuildError::NotFound`] if the repository or build does not exist.
+    ///
+    /// [`Cancelled`]: crate::model::TaskStatus::Cancelled
+    async fn cancel_build(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: i32,
+    ) -> Result<BuildResponse, BuildError>;
+
+    /// Reruns a task of a build that [`Failure`]d, was [`Cancelled`], or
+    /// [`TimedOut`]. The task is requeued as [`Pending`] (or [`Blocked`] while
+    /// a dependency is unfinished) for any runner to claim, with its
+    /// `attempt` incremented; the build reports [`Running`] again until it
+    /// finishes.
+    ///
+    /// # Errors
+    /// - [`BuildError::NotFound`] if the repository or build does not exist,
+    ///   or the build has no task `task_id`.
+    /// - [`BuildError::TaskNotRetryable`] if the task has not ended
+    ///   unsuccessfully.
+    ///
+    /// [`Failure`]: crate::model::TaskStatus::Failure
+    /// [`Cancelled`]: crate::model::TaskStatus::Cancelled
+    /// [`TimedOut`]: crate::model::TaskStatus::TimedOut
+    /// [`Pending`]: crate::model::TaskStatus::Pending
+    /// [`Blocked`]: crate::model::TaskStatus::Blocked
+    /// [`Running`]: crate::model::BuildStatus::Running
+    async fn retry_task(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: i32,
+        task_id: Uuid,
+    ) -> Result<TaskResponse, BuildError>;
 }
 
 #[derive(Debug, Clone)]
@@ -129,9 +205,7 @@ impl
     }
 }
 
-#[crate::instrument_all(level = "debug")]
-#[async_trait]
-impl<G, S, B, T, R> BuildService for BuildServiceImpl<G, S, B, T, R>
+impl<G, S, B, T, R> BuildServiceImpl<G, S, B, T, R>
 where
     G: GitClient,
     S: S2Client,
@@ -139,7 +213,13 @@ where
     T: TaskRepository,
     R: RepositoryRepository,
 {
-    async fn create_build(&self, request: CreateBuildRequest) -> Result<BuildResponse, BuildError> {
+    /// Shared by [`create_build`](BuildService::create_build) and
+    /// [`trigger_build`](BuildService::trigger_build), which decide how a
+    /// [`Skipped`] build is reported.
+    async fn start_build(
+        &self,
+        request: CreateBuildRequest,
+    ) -> Result<Result<BuildResponse, Skipped>, BuildError> {
         let owner = request.repo_owner.as_ref();
         let repo = request.repo_name.as_ref();
 
@@ -155,37 +235,71 @@ where
             .map_err(BuildError::GitError)?;
         let resolved_sha = commit.sha.clone();
 
-        let blob = self
+        let blob = match self
             .git_client
             .get_repo_blob(owner, repo, &resolved_sha, ".gitdot-ci.toml")
             .await
-            .map_err(|e: GitError| match e {
-                GitError::NotFound(_) => {
-                    BuildError::NotFound(NotFoundError::new("config", request.commit_sha.clone()))
-                }
-                other => BuildError::GitError(other),
-            })?;
+        {
+            Ok(blob) => blob,
+            Err(GitError::NotFound(_)) => return Ok(Err(Skipped::NoConfig)),
+            Err(e) => return Err(BuildError::GitError(e)),
+        };
 
         let file_content = blob.content;
 
         let ci_config =
             CiConfig::new(&file_content).map_err(|e| BuildError::InvalidConfig(e.to_string()))?;
-        let ci_trigger = if request.ref_name == DEFAULT_BRANCH
-            || request
-                .ref_name
-                .rsplit('/')
-                .next()
-                .map(|name| name == DEFAULT_BRANCH)
-                .unwrap_or(false)
+
+        // configs without a manual build keep building manual requests by ref
+        let ci_trigger = if request.trigger == CiBuildTrigger::Manual
+            && ci_config.get_build_config(&CiBuildTrigger::Manual).is_err()
         {
-            gitdot_config::ci::BuildTrigger::PushToMain
+            if request.ref_name.rsplit('/').next() == Some(DEFAULT_BRANCH) {
+                CiBuildTrigger::PushToMain
+            } else {
+                CiBuildTrigger::PullRequest
+            }
         } else {
-            gitdot_config::ci::BuildTrigger::PullRequest
+            request.trigger.clone()
         };
 
-        let build_config = ci_config
-            .get_build_config(&ci_trigger)
-            .map_err(|e| BuildError::InvalidConfig(e.to_string()))?;
+        let build_config = match ci_config.get_build_config(&ci_trigger) {
+            Ok(build_config) => build_config,
+            Err(e) => return Ok(Err(Skipped::NoMatchingBuild(e.to_string()))),
+        };
+
+        // path filters need the changed files; when they can't be diffed the
+        // filter is not applied
+        let changed_paths = match (&build_config.paths, &request.base_sha) {
+            (Some(_), Some(base_sha)) => self
+                .git_client
+                .get_repo_commit_diff(owner, repo, Some(base_sha), &resolved_sha)
+                .await
+                .ok()
+                .map(|diffs| diffs.into_iter().map(|d| d.path).collect::<Vec<_>>()),
+            _ => None,
+        };
+        let context = BuildContext {
+            trigger: ci_trigger.clone(),
+            branch: request.branch.as_deref(),
+            tag: request.tag.as_deref(),
+            changed_paths: changed_paths.as_deref(),
+        };
+        if !build_config.matches(&context) {
+            return Ok(Err(Skipped::Filtered));
+        }
+
+        // tasks whose `if` is false are left out; see `resolve_waits_for`
+        let build_tasks = ci_config.get_task_configs(build_config);
+        let task_configs: Vec<_> = build_tasks
+            .iter()
+            .copied()
+            .filter(|t| t.should_run(&context))
+            .collect();
+        if task_configs.is_empty() {
+            return Ok(Err(Skipped::Filtered));
+        }
+
         let build = self
             .build_repo
             .create(
@@ -193,12 +307,12 @@ where
                 crate::model::BuildTrigger::from(ci_trigger),
                 &resolved_sha,
                 &request.ref_name,
+                request.review_link,
             )
             .await?;
 
         // pre-generate UUIDs for all tasks so dependencies can reference each other by ID
         let mut name_to_id: HashMap<String, Uuid> = HashMap::new();
-        let task_configs = ci_config.get_task_configs(build_config);
         for task_config in &task_configs {
             name_to_id.insert(task_config.name.clone(), Uuid::new_v4());
         }
@@ -209,18 +323,16 @@ where
 
         let task_futures = task_configs.iter().map(|task_config| {
             let id = name_to_id[&task_config.name];
-            let waits_for: Vec<Uuid> = task_config
-                .waits_for
-                .as_deref()
-                .unwrap_or(&[])
-                .iter()
-                .filter_map(|dep_name| name_to_id.get(dep_name).copied())
+            let waits_for: Vec<Uuid> = resolve_waits_for(task_config, &build_tasks, &name_to_id)
+                .into_iter()
+                .map(|dep_name| name_to_id[dep_name])
                 .collect();
             let status = if waits_for.is_empty() {
                 TaskStatus::Pending
             } else {
                 TaskStatus::Blocked
             };
+            let options = TaskOptions::from(*task_config);
 
             async move {
                 let s2_uri = s2_client
@@ -238,6 +350,7 @@ where
                         &s2_uri,
                         status,
                         &waits_for,
+                        &options,
                     )
                     .await
                     .map_err(BuildError::DatabaseError)
@@ -247,7 +360,7 @@ where
         try_join_all(task_futures).await?;
 
         let total_tasks = task_configs.len() as i32;
-        Ok(BuildResponse {
+        Ok(Ok(BuildResponse {
             id: build.id,
             number: build.number,
             repository_id: build.repository_id,
@@ -255,11 +368,66 @@ where
             commit_sha: build.commit_sha,
             trigger: build.trigger,
             status: BuildStatus::Running,
+            review_id: build.review_id,
+            diff_id: build.diff_id,
+            revision_id: build.revision_id,
             total_tasks,
             completed_tasks: 0,
             created_at: build.created_at,
             updated_at: build.created_at,
-        })
+        }))
+    }
+}
+
+/// Why [`start_build`](BuildServiceImpl::start_build) created no build.
+enum Skipped {
+    /// The commit has no `.gitdot-ci.toml`.
+    NoConfig,
+    /// The config has no build for the trigger.
+    NoMatchingBuild(String),
+    /// The build's filters or every task's `if` rejected the ref.
+    Filtered,
+}
+
+#[crate::instrument_all(level = "debug")]
+#[async_trait]
+impl<G, S, B, T, R> BuildService for BuildServiceImpl<G, S, B, T, R>
+where
+    G: GitClient,
+    S: S2Client,
+    B: BuildRepository,
+    T: TaskRepository,
+    R: RepositoryRepository,
+{
+    async fn create_build(&self, request: CreateBuildRequest) -> Result<BuildResponse, BuildError> {
+        let commit_sha = request.commit_sha.clone();
+        let ref_name = request.ref_name.clone();
+        self.start_build(request)
+            .await?
+            .map_err(|skipped| match skipped {
+                Skipped::NoConfig => BuildError::NotFound(NotFoundError::new("config", commit_sha)),
+                Skipped::NoMatchingBuild(e) => BuildError::InvalidConfig(e),
+                Skipped::Filtered => {
+                    BuildError::InvalidConfig(format!("no tasks run for {ref_name}"))
+                }
+            })
+    }
+
+    async fn trigger_build(
+        &self,
+        request: CreateBuildRequest,
+    ) -> Result<Option<BuildResponse>, BuildError> {
+        if let Some(link) = request.review_link {
+            let latest = self
+                .build_repo
+                .get_latest_by_revision(link.revision_id)
+                .await?;
+            if latest.is_some_and(|build| build.commit_sha == request.commit_sha) {
+                return Ok(None);
+            }
+        }
+
+        Ok(self.start_build(request).await?.ok())
     }
 
     async fn list_builds(
@@ -277,7 +445,12 @@ where
 
         let (builds, next_cursor) = self
             .build_repo
-            .list_by_repo(repository.id, request.cursor, request.limit as i64)
+            .list_by_repo(
+                repository.id,
+                request.review_number,
+                request.cursor,
+                request.limit as i64,
+            )
             .await?;
 
         Ok(Page {
@@ -307,19 +480,8 @@ where
         let tasks = self.task_repo.list_by_build_id(build.id).await?;
 
         let total_tasks = tasks.len() as i32;
-        let completed_tasks = tasks
-            .iter()
-            .filter(|t| matches!(t.status, TaskStatus::Success))
-            .count() as i32;
-        let status = if tasks.is_empty() {
-            BuildStatus::Running
-        } else if tasks.iter().any(|t| t.status == TaskStatus::Failure) {
-            BuildStatus::Failure
-        } else if tasks.iter().all(|t| t.status == TaskStatus::Success) {
-            BuildStatus::Success
-        } else {
-            BuildStatus::Running
-        };
+        let completed_tasks = tasks.iter().filter(|t| t.status.is_finished()).count() as i32;
+        let status = build_status(&tasks);
 
         let effective_updated_at = tasks
             .iter()
@@ -335,6 +497,9 @@ where
             commit_sha: build.commit_sha,
             trigger: build.trigger,
             status,
+            review_id: build.review_id,
+            diff_id: build.diff_id,
+            revision_id: build.revision_id,
             total_tasks,
             completed_tasks,
             created_at: build.created_at,
@@ -364,4 +529,221 @@ where
 
         Ok(tasks.into_iter().map(Into::into).collect())
     }
+
+    async fn cancel_build(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: i32,
+    ) -> Result<BuildResponse, BuildError> {
+        let repository = self
+            .repo_repo
+            .get(owner, repo, None)
+            .await?
+            .or_not_found("repository", format!("{owner}/{repo}"))?;
+
+        let build = self
+            .build_repo
+            .get(repository.id, number)
+            .await?
+            .or_not_found("build", format!("{owner}/{repo}#{number}"))?;
+
+        self.task_repo.cancel_build_tasks(build.id).await?;
+
+        self.get_build(owner, repo, number).await
+    }
+
+    async fn retry_task(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: i32,
+        task_id: Uuid,
+    ) -> Result<TaskResponse, BuildError> {
+        let repository = self
+            .repo_repo
+            .get(owner, repo, None)
+            .await?
+            .or_not_found("repository", format!("{owner}/{repo}"))?;
+
+        let build = self
+            .build_repo
+            .get(repository.id, number)
+            .await?
+            .or_not_found("build", format!("{owner}/{repo}#{number}"))?;
+
+        let task = self
+            .task_repo
+            .get_by_id(task_id)
+            .await?
+            .filter(|task| task.build_id == build.id)
+            .or_not_found("task", task_id)?;
+
+        match self.task_repo.retry_task(task.id).await? {
+            Some(task) => Ok(task.into()),
+            None => {
+                let status: String = task.status.into();
+                Err(BuildError::TaskNotRetryable(format!(
+                    "task '{}' is {status}",
+                    task.name
+                )))
+            }
+        }
+    }
+}
+
+/// Aggregate build status; see [`BuildService::get_build`].
+/// The tasks `task` waits for among those that run, i.e. have an id in
+/// `running`. A dependency skipped by its `if` counts as resolved: `task`
+/// waits for what the skipped task waited for instead, so it still runs once
+/// everything upstream of it has. Dependencies outside `build_tasks` are
+/// dropped.
+fn resolve_waits_for<'a>(
+    task: &'a TaskConfig,
+    build_tasks: &[&'a TaskConfig],
+    running: &HashMap<String, Uuid>,
+) -> Vec<&'a str> {
+    let mut waits_for = Vec::new();
+    let mut seen = HashSet::new();
+    let mut pending: Vec<&str> = task
+        .waits_for
+        .iter()
+        .flatten()
+        .map(String::as_str)
+        .collect();
+    while let Some(name) = pending.pop() {
+        if !seen.insert(name) {
+            continue;
+        }
+        if running.contains_key(name) {
+            waits_for.push(name);
+        } else if let Some(skipped) = build_tasks.iter().find(|t| t.name == name) {
+            pending.extend(skipped.waits_for.iter().flatten().map(String::as_str));
+        }
+    }
+    waits_for
+}
+
+fn build_status(tasks: &[Task]) -> BuildStatus {
+    let failed = |t: &Task| {
+        matches!(t.status, TaskStatus::Failure | TaskStatus::TimedOut) && !t.options.allow_failure
+    };
+
+    if tasks.is_empty() {
+        BuildStatus::Running
+    } else if tasks.iter().any(failed) {
+        BuildStatus::Failure
+    } else if tasks.iter().any(|t| t.status == TaskStatus::Cancelled) {
+        BuildStatus::Cancelled
+    } else if tasks.iter().all(|t| t.status.is_finished()) {
+        BuildStatus::Success
+    } else {
+        BuildStatus::Running
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use super::*;
+
+    fn task(status: TaskStatus, allow_failure: bool) -> Task {
+        Task {
+            id: Uuid::new_v4(),
+            repository_id: Uuid::new_v4(),
+            build_id: Uuid::new_v4(),
+            s2_uri: String::new(),
+            waits_for: Vec::new(),
+            status,
+            name: "test".to_string(),
+            command: "true".to_string(),
+            runner_id: None,
+            attempt: 1,
+            options: TaskOptions {
+                allow_failure,
+                ..Default::default()
+            },
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn failures_outrank_cancellation() {
+        let tasks = [
+            task(TaskStatus::TimedOut, false),
+            task(TaskStatus::Cancelled, false),
+        ];
+        assert_eq!(build_status(&tasks), BuildStatus::Failure);
+
+        let tasks = [
+            task(TaskStatus::Failure, true),
+            task(TaskStatus::Cancelled, false),
+        ];
+        assert_eq!(build_status(&tasks), BuildStatus::Cancelled);
+    }
+
+    #[test]
+    fn allowed_failures_still_finish_the_build() {
+        let tasks = [
+            task(TaskStatus::Success, false),
+            task(TaskStatus::TimedOut, true),
+        ];
+        assert_eq!(build_status(&tasks), BuildStatus::Success);
+    }
+
+    #[test]
+    fn unfinished_tasks_keep_the_build_running() {
+        let tasks = [
+            task(TaskStatus::Success, false),
+            task(TaskStatus::Blocked, false),
+        ];
+        assert_eq!(build_status(&tasks), BuildStatus::Running);
+        assert_eq!(build_status(&[]), BuildStatus::Running);
+    }
+
+    #[test]
+    fn dependents_of_skipped_tasks_wait_for_what_they_waited_for() {
+        let config = CiConfig::new(
+            r#"
+            [[builds]]
+            trigger = "pull_request"
+            tasks = ["build", "deploy", "smoke", "lint"]
+
+            [[tasks]]
+            name = "build"
+            command = "cargo build"
+
+            [[tasks]]
+            name = "deploy"
+            command = "./deploy.sh"
+            waits_for = ["build"]
+            if = "branch == 'main'"
+
+            [[tasks]]
+            name = "smoke"
+            command = "./smoke.sh"
+            waits_for = ["deploy", "lint"]
+
+            [[tasks]]
+            name = "lint"
+            command = "cargo clippy"
+        "#,
+        )
+        .unwrap();
+        let build_tasks = config.get_task_configs(&config.builds[0]);
+        let running: HashMap<String, Uuid> = ["build", "smoke", "lint"]
+            .into_iter()
+            .map(|name| (name.to_string(), Uuid::new_v4()))
+            .collect();
+
+        let mut waits_for = resolve_waits_for(build_tasks[2], &build_tasks, &running);
+        waits_for.sort_unstable();
+
+        // `smoke` neither waits on the skipped `deploy` forever nor starts
+        // before the `build` that `deploy` was waiting for.
+        assert_eq!(waits_for, vec!["build", "lint"]);
+        assert!(resolve_waits_for(build_tasks[0], &build_tasks, &running).is_empty());
+    }
 }
@@ -0,0 +1,328 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::{
+    dto::{
+        CiSecretResponse, CiSecretScope, DeleteCiSecretRequest, SetCiSecretRequest,
+        TaskSecretsResponse,
+    },
+    error::{CiSecretError, NotFoundError, OptionNotFoundExt},
+    model::{CiSecret, OrganizationRole, Repository, RepositoryRole},
+    repository::{
+        BuildRepository, CiSecretRepository, OrganizationRepository, PgBuildRepository,
+        PgCiSecretRepository, PgOrganizationRepository, PgRepositoryRepository, PgTaskRepository,
+        RepositoryRepository, TaskRepository,
+    },
+    util::crypto::EncryptionKey,
+};
+
+/// CI secrets: values set by repository or organization admins, stored
+/// encrypted, and handed out in the clear only to the runner of a task that
+/// declares them under `secrets` in `.gitdot-ci.toml`.
+///
+/// Authorization (repository or organization admin) is checked by callers;
+/// these methods only resolve the scope.
+#[async_trait]
+pub trait CiSecretService: Send + Sync + 'static {
+    /// Lists the secrets of a repository or organization. Values are never
+    /// returned.
+    ///
+    /// # Errors
+    /// - [`CiSecretError::NotFound`] if the repository or organization does
+    ///   not exist.
+    async fn list_secrets(
+        &self,
+        scope: CiSecretScope,
+    ) -> Result<Vec<CiSecretResponse>, CiSecretError>;
+
+    /// Encrypts and stores a secret, replacing the value of an existing secret
+    /// with the same name.
+    ///
+    /// # Errors
+    /// - [`CiSecretError::NotFound`] if the repository or organization does
+    ///   not exist.
+    async fn set_secret(
+        &self,
+        request: SetCiSecretRequest,
+    ) -> Result<CiSecretResponse, CiSecretError>;
+
+    /// Deletes a secret.
+    ///
+    /// # Errors
+    /// - [`CiSecretError::NotFound`] if the repository, organization, or
+    ///   secret does not exist.
+    async fn delete_secret(&self, request: DeleteCiSecretRequest) -> Result<(), CiSecretError>;
+
+    /// Decrypts the secrets `task_id` declares, for its runner to pass to the
+    /// command as env variables. A repository secret shadows an organization
+    /// secret of the same name; declared names with no secret are left out.
+    ///
+    /// Tasks of a build started for a review get no secrets unless the
+    /// review's author has write access to the repository, so a review cannot
+    /// exfiltrate secrets by changing `.gitdot-ci.toml` or the code it runs.
+    ///
+    /// # Errors
+    /// - [`CiSecretError::NotFound`] if no task has `task_id`.
+    /// - [`CiSecretError::DecryptionError`] if a secret was stored under
+    ///   another encryption key, or sealed for another owner or name.
+    async fn get_task_secrets(&self, task_id: Uuid) -> Result<TaskSecretsResponse, CiSecretError>;
+}
+
+#[derive(Debug, Clone)]
+pub struct CiSecretServiceImpl<S, R, O, T, B>
+where
+    S: CiSecretRepository,
+    R: RepositoryRepository,
+    O: OrganizationRepository,
+    T: TaskRepository,
+    B: BuildRepository,
+{
+    secret_repo: S,
+    repo_repo: R,
+    org_repo: O,
+    task_repo: T,
+    build_repo: B,
+    encryption_key: EncryptionKey,
+}
+
+impl
+    CiSecretServiceImpl<
+        PgCiSecretRepository,
+        PgRepositoryRepository,
+        PgOrganizationRepository,
+        PgTaskRepository,
+        PgBuildRepository,
+    >
+{
+    pub fn new(
+        secret_repo: PgCiSecretRepository,
+        repo_repo: PgRepositoryRepository,
+        org_repo: PgOrganizationRepository,
+        task_repo: PgTaskRepository,
+        build_repo: PgBuildRepository,
+        encryption_key: EncryptionKey,
+    ) -> Self {
+        Self {
+            secret_repo,
+            repo_repo,
+            org_repo,
+            task_repo,
+            build_repo,
+            encryption_key,
+        }
+    }
+}
+
+/// A resolved [`CiSecretScope`].
+enum SecretOwner {
+    Repository(Uuid),
+    Organization(Uuid),
+}
+
+impl SecretOwner {
+    /// Additional authenticated data binding a secret's ciphertext to its
+    /// owner and name, so it cannot be replayed under another secret.
+    fn aad(&self, name: &str) -> Vec<u8> {
+        match self {
+            SecretOwner::Repository(id) => format!("ci-secret:repository:{id}:{name}"),
+            SecretOwner::Organization(id) => format!("ci-secret:organization:{id}:{name}"),
+        }
+        .into_bytes()
+    }
+}
+
+impl<S, R, O, T, B> CiSecretServiceImpl<S, R, O, T, B>
+where
+    S: CiSecretRepository,
+    R: RepositoryRepository,
+    O: OrganizationRepository,
+    T: TaskRepository,
+    B: BuildRepository,
+{
+    async fn resolve(&self, scope: &CiSecretScope) -> Result<SecretOwner, CiSecretError> {
+        match scope {
+            CiSecretScope::Repository {
+                owner_name,
+                repo_name,
+            } => {
+                let id = self
+                    .repo_repo
+                    .get_id(owner_name, repo_name)
+                    .await?
+                    .or_not_found(
+                        "repository",
+                        format!("{}/{}", owner_name.as_ref(), repo_name.as_ref()),
+                    )?;
+                Ok(SecretOwner::Repository(id))
+            }
+            CiSecretScope::Organization { org_name } => {
+                let id = self
+                    .org_repo
+                    .get_id(org_name)
+                    .await?
+                    .or_not_found("organization", org_name.as_ref())?;
+                Ok(SecretOwner::Organization(id))
+            }
+        }
+    }
+
+    async fn upsert(
+        &self,
+        owner: &SecretOwner,
+        name: &str,
+        value: &[u8],
+    ) -> Result<CiSecret, CiSecretError> {
+        let ciphertext = self
+            .encryption_key
+            .encrypt_with_aad(value, &owner.aad(name));
+        let secret = match owner {
+            SecretOwner::Repository(id) => {
+                self.secret_repo
+                    .upsert_repository_secret(*id, name, &ciphertext)
+                    .await?
+            }
+            SecretOwner::Organization(id) => {
+                self.secret_repo
+                    .upsert_organization_secret(*id, name, &ciphertext)
+                    .await?
+            }
+        };
+        Ok(secret)
+    }
+
+    /// Whether `user_id` has write access to `repository`, mirroring
+    /// [`RepositoryPermission::Write`](crate::dto::RepositoryPermission::Write).
+    async fn can_write(
+        &self,
+        repository: &Repository,
+        user_id: Uuid,
+    ) -> Result<bool, CiSecretError> {
+        if repository.readonly {
+            return Ok(false);
+        }
+        if repository.is_owned_by_user() {
+            if repository.owner_id == user_id {
+                return Ok(true);
+            }
+        } else if self
+            .org_repo
+            .get_member_role(&repository.owner_name, user_id)
+            .await?
+            == Some(OrganizationRole::Admin)
+        {
+            return Ok(true);
+        }
+
+        let granted = self
+            .repo_repo
+            .get_granted_role(repository.id, user_id)
+            .await?;
+        Ok(granted >= Some(RepositoryRole::Write))
+    }
+}
+
+#[crate::instrument_all(level = "debug")]
+#[async_trait]
+impl<S, R, O, T, B> CiSecretService for CiSecretServiceImpl<S, R, O, T, B>
+where
+    S: CiSecretRepository,
+    R: RepositoryRepository,
+    O: OrganizationRepository,
+    T: TaskRepository,
+    B: BuildRepository,
+{
+    async fn list_secrets(
+        &self,
+        scope: CiSecretScope,
+    ) -> Result<Vec<CiSecretResponse>, CiSecretError> {
+        let secrets = match self.resolve(&scope).await? {
+            SecretOwner::Repository(id) => self.secret_repo.list_repository_secrets(id).await?,
+            SecretOwner::Organization(id) => self.secret_repo.list_organization_secrets(id).await?,
+        };
+
+        Ok(secrets.into_iter().map(Into::into).collect())
+    }
+
+    async fn set_secret(
+        &self,
+        request: SetCiSecretRequest,
+    ) -> Result<CiSecretResponse, CiSecretError> {
+        let owner = self.resolve(&request.scope).await?;
+        let secret = self
+            .upsert(&owner, &request.name, request.value.as_bytes())
+            .await?;
+
+        Ok(secret.into())
+    }
+
+    async fn delete_secret(&self, request: DeleteCiSecretRequest) -> Result<(), CiSecretError> {
+        let deleted = match self.resolve(&request.scope).await? {
+            SecretOwner::Repository(id) => {
+                self.secret_repo
+                    .delete_repository_secret(id, &request.name)
+                    .await?
+            }
+            SecretOwner::Organization(id) => {
+                self.secret_repo
+                    .delete_organization_secret(id, &request.name)
+                    .await?
+            }
+        };
+        if !deleted {
+            return Err(NotFoundError::new("secret", &request.name).into());
+        }
+
+        Ok(())
+    }
+
+    async fn get_task_secrets(&self, task_id: Uuid) -> Result<TaskSecretsResponse, CiSecretError> {
+        let task = self
+            .task_repo
+            .get_by_id(task_id)
+            .await?
+            .or_not_found("task", task_id)?;
+        if task.options.secrets.is_empty() {
+            return Ok(TaskSecretsResponse::default());
+        }
+
+        let repository = self
+            .repo_repo
+            .get_by_id(task.repository_id, None)
+            .await?
+            .or_not_found("repository", task.repository_id)?;
+
+        if let Some(author_id) = self.build_repo.get_review_author_id(task.build_id).await?
+            && !self.can_write(&repository, author_id).await?
+        {
+            return Ok(TaskSecretsResponse {
+                withheld: true,
+                ..Default::default()
+            });
+        }
+
+        let organization_id = repository
+            .is_owned_by_organization()
+            .then_some(repository.owner_id);
+        let secrets = self
+            .secret_repo
+            .list_by_names(repository.id, organization_id, &task.options.secrets)
+            .await?;
+
+        let mut decrypted = TaskSecretsResponse::default();
+        for secret in secrets {
+            let owner = if secret.owner_id == repository.id {
+                SecretOwner::Repository(secret.owner_id)
+            } else {
+                SecretOwner::Organization(secret.owner_id)
+            };
+            let value = self
+                .encryption_key
+                .decrypt_with_aad(&secret.ciphertext, &owner.aad(&secret.name))
+                .and_then(|value| String::from_utf8(value).ok())
+                .ok_or_else(|| CiSecretError::DecryptionError(secret.name.clone()))?;
+            decrypted.secrets.insert(secret.name, value);
+        }
+
+        Ok(decrypted)
+    }
+}
@@ -1,4 +1,4 @@
-use std::time::Duration;
+use std::{collections::HashSet, time::Duration};
 
 use async_trait::async_trait;
 use tokio::time::{Instant, sleep};
@@ -24,18 +24,44 @@ pub trait TaskService: Send + Sync + 'static {
 
     /// Applies a runner-reported status transition to a task.
     ///
-    /// When the task transitions to [`Success`], sibling tasks in the same build
-    /// that were [`Blocked`] solely on this (and other now-succeeded)
-    /// dependencies are unblocked to [`Pending`].
+    /// Reports for a task that was already [`Cancelled`] or [`TimedOut`] are
+    /// ignored and the task is returned unchanged, so a runner stopping a
+    /// cancelled task cannot overwrite the cancellation. When the task
+    /// finishes, sibling tasks in the same build that were [`Blocked`] solely
+    /// on this (and other now-satisfied) dependencies are unblocked to
+    /// [`Pending`].
     ///
     /// # Errors
     /// - [`TaskError::NotFound`] if no task has `req.id`.
     ///
-    /// [`Success`]: crate::model::TaskStatus::Success
+    /// [`Cancelled`]: crate::model::TaskStatus::Cancelled
+    /// [`TimedOut`]: crate::model::TaskStatus::TimedOut
     /// [`Blocked`]: crate::model::TaskStatus::Blocked
     /// [`Pending`]: crate::model::TaskStatus::Pending
     async fn update_task(&self, req: UpdateTaskRequest) -> Result<TaskResponse, TaskError>;
 
+    /// Records that the runner executing task `id` is still alive and returns
+    /// the task, whose status tells the runner whether it has been
+    /// [`Cancelled`] or [`TimedOut`] and should stop.
+    ///
+    /// # Errors
+    /// - [`TaskError::NotFound`] if no task has `id`, or its runner no longer
+    ///   exists.
+    ///
+    /// [`Cancelled`]: crate::model::TaskStatus::Cancelled
+    /// [`TimedOut`]: crate::model::TaskStatus::TimedOut
+    async fn heartbeat_task(&self, id: Uuid) -> Result<TaskResponse, TaskError>;
+
+    /// Times out [`Assigned`] and [`Running`] tasks whose runner has not
+    /// heartbeated or polled within `heartbeat_timeout`, or that have run past
+    /// their configured timeout for every attempt. Dependents of timed-out
+    /// `allow_failure` tasks are unblocked. Returns the timed-out tasks.
+    ///
+    /// [`Assigned`]: crate::model::TaskStatus::Assigned
+    /// [`Running`]: crate::model::TaskStatus::Running
+    async fn reap_tasks(&self, heartbeat_timeout: Duration)
+    -> Result<Vec<TaskResponse>, TaskError>;
+
     /// Long-polls for the next task a runner may execute, claiming it atomically.
     ///
     /// Heartbeats the runner, then resolves the repositories owned by the
@@ -97,19 +123,66 @@ where
     }
 
     async fn update_task(&self, req: UpdateTaskRequest) -> Result<TaskResponse, TaskError> {
+        let current = self
+            .task_repo
+            .get_by_id(req.id)
+            .await?
+            .or_not_found("task", req.id)?;
+        if matches!(current.status, TaskStatus::Cancelled | TaskStatus::TimedOut) {
+            return Ok(current.into());
+        }
+
         let task = self
             .task_repo
             .update_task(req.id, req.status)
             .await
             .or_not_found::<TaskError>("task", req.id)?;
 
-        if task.status == TaskStatus::Success {
+        if task.status.is_finished() {
             self.task_repo.unblock_tasks(task.build_id).await?;
         }
 
         Ok(task.into())
     }
 
+    async fn heartbeat_task(&self, id: Uuid) -> Result<TaskResponse, TaskError> {
+        let task = self
+            .task_repo
+            .get_by_id(id)
+            .await?
+            .or_not_found("task", id)?;
+
+        if let Some(runner_id) = task.runner_id {
+            self.runner_repo
+                .touch(runner_id)
+                .await
+                .or_not_found::<TaskError>("runner", runner_id)?;
+        }
+
+        Ok(task.into())
+    }
+
+    async fn reap_tasks(
+        &self,
+        heartbeat_timeout: Duration,
+    ) -> Result<Vec<TaskResponse>, TaskError> {
+        let tasks = self
+            .task_repo
+            .time_out_stale_tasks(heartbeat_timeout)
+            .await?;
+
+        let build_ids: HashSet<Uuid> = tasks
+            .iter()
+            .filter(|t| t.options.allow_failure)
+            .map(|t| t.build_id)
+            .collect();
+        for build_id in build_ids {
+            self.task_repo.unblock_tasks(build_id).await?;
+        }
+
+        Ok(tasks.into_iter().map(Into::into).collect())
+    }
+
     async fn poll_task(&self, runner_id: Uuid) -> Result<Option<TaskResponse>, TaskError> {
         self.runner_repo.touch(runner_id).await?;
 
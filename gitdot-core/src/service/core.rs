@@ -1,15 +1,31 @@
+mod branch_protection;
+mod code_search;
+mod collaborator;
 mod commit;
 mod git_http;
+mod maintenance;
+mod mirror;
 mod organization;
 mod question;
+mod release;
 mod repository;
 mod review;
+mod tag;
+mod team;
 mod user;
 
+pub use branch_protection::{BranchProtectionService, BranchProtectionServiceImpl};
+pub use code_search::{CodeSearchService, CodeSearchServiceImpl};
+pub use collaborator::{CollaboratorService, CollaboratorServiceImpl};
 pub use commit::{CommitService, CommitServiceImpl};
 pub use git_http::{GitHttpService, GitHttpServiceImpl};
+pub use maintenance::{MaintenanceService, MaintenanceServiceImpl};
+pub use mirror::{MirrorService, MirrorServiceImpl};
 pub use organization::{OrganizationService, OrganizationServiceImpl};
 pub use question::{QuestionService, QuestionServiceImpl};
+pub use release::{ReleaseService, ReleaseServiceImpl};
 pub use repository::{RepositoryService, RepositoryServiceImpl};
 pub use review::{ReviewService, ReviewServiceImpl};
+pub use tag::{TagService, TagServiceImpl};
+pub use team::{TeamService, TeamServiceImpl};
 pub use user::{UserService, UserServiceImpl};
@@ -0,0 +1,602 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::{
+    dto::{
+        BranchProtectionResponse, CheckRefUpdatesRequest, CreateBranchProtectionRequest,
+        DeleteBranchProtectionRequest, ListBranchProtectionsRequest, RefUpdate, RefUpdateRejection,
+        UpdateBranchProtectionRequest,
+    },
+    error::{BranchProtectionError, ConflictError, NotFoundError, OptionNotFoundExt},
+    model::{BranchProtection, BranchProtectionRules, BuildStatus},
+    repository::{
+        BranchProtectionRepository, BuildRepository, PgBranchProtectionRepository,
+        PgBuildRepository, PgRepositoryRepository, RepositoryRepository,
+    },
+};
+
+/// Manages a repository's branch protection rules and evaluates pushes against
+/// them.
+///
+/// A rule pairs a branch glob (`main`, `release/*`, `**`) with a set of checks.
+/// When several rules match a branch their checks are combined, so the
+/// strictest setting always wins. Only `refs/heads/*` is protected; tags,
+/// review refs and anything else pass through untouched.
+#[async_trait]
+pub trait BranchProtectionService: Send + Sync + 'static {
+    /// Lists every protection rule on a repository, ordered by pattern.
+    ///
+    /// # Errors
+    /// - [`BranchProtectionError::NotFound`] if the repository does not exist.
+    async fn list_branch_protections(
+        &self,
+        request: ListBranchProtectionsRequest,
+    ) -> Result<Vec<BranchProtectionResponse>, BranchProtectionError>;
+
+    /// Protects the branches matching a new pattern.
+    ///
+    /// # Errors
+    /// - [`BranchProtectionError::NotFound`] if the repository does not exist.
+    /// - [`BranchProtectionError::Conflict`] if the pattern is already
+    ///   protected on this repository.
+    async fn create_branch_protection(
+        &self,
+        request: CreateBranchProtectionRequest,
+    ) -> Result<BranchProtectionResponse, BranchProtectionError>;
+
+    /// Replaces a rule's pattern and checks.
+    ///
+    /// # Errors
+    /// - [`BranchProtectionError::NotFound`] if the repository or the rule does
+    ///   not exist.
+    /// - [`BranchProtectionError::Conflict`] if another rule already uses the
+    ///   new pattern.
+    async fn update_branch_protection(
+        &self,
+        request: UpdateBranchProtectionRequest,
+    ) -> Result<BranchProtectionResponse, BranchProtectionError>;
+
+    /// Removes a protection rule.
+    ///
+    /// # Errors
+    /// - [`BranchProtectionError::NotFound`] if the repository or the rule does
+    ///   not exist.
+    async fn delete_branch_protection(
+        &self,
+        request: DeleteBranchProtectionRequest,
+    ) -> Result<(), BranchProtectionError>;
+
+    /// Evaluates the ref updates of a push and returns one rejection per ref
+    /// that violates a rule. An empty result means the push may proceed.
+    ///
+    /// Checks run in order — deletion, force-push, review, green build — and
+    /// only the first failing check is reported for each ref. Because reviews
+    /// are merged server-side without going through the hooks, `require_review`
+    /// refuses every direct push to a matching branch.
+    ///
+    /// # Errors
+    /// - [`BranchProtectionError::NotFound`] if the repository does not exist.
+    async fn check_ref_updates(
+        &self,
+        request: CheckRefUpdatesRequest,
+    ) -> Result<Vec<RefUpdateRejection>, BranchProtectionError>;
+}
+
+#[derive(Debug, Clone)]
+pub struct BranchProtectionServiceImpl<B, R, D>
+where
+    B: BranchProtectionRepository,
+    R: RepositoryRepository,
+    D: BuildRepository,
+{
+    branch_protection_repo: B,
+    repo_repo: R,
+    build_repo: D,
+}
+
+impl
+    BranchProtectionServiceImpl<
+        PgBranchProtectionRepository,
+        PgRepositoryRepository,
+        PgBuildRepository,
+    >
+{
+    pub fn new(
+        branch_protection_repo: PgBranchProtectionRepository,
+        repo_repo: PgRepositoryRepository,
+        build_repo: PgBuildRepository,
+    ) -> Self {
+        Self {
+            branch_protection_repo,
+            repo_repo,
+            build_repo,
+        }
+    }
+}
+
+impl<B, R, D> BranchProtectionServiceImpl<B, R, D>
+where
+    B: BranchProtectionRepository,
+    R: RepositoryRepository,
+    D: BuildRepository,
+{
+    async fn get_repository_id(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Uuid, BranchProtectionError> {
+        let id = self
+            .repo_repo
+            .get_id(owner, repo)
+            .await?
+            .or_not_found("repository", format!("{owner}/{repo}"))?;
+        Ok(id)
+    }
+
+    async fn check_ref_update(
+        &self,
+        repository_id: Uuid,
+        protections: &[BranchProtection],
+        update: &RefUpdate,
+    ) -> Result<Option<String>, BranchProtectionError> {
+        let Some(branch) = update.branch() else {
+            return Ok(None);
+        };
+
+        let rules = protections
+            .iter()
+            .filter(|p| p.matches(branch))
+            .fold(BranchProtectionRules::default(), |acc, p| {
+                acc.union(p.rules())
+            });
+
+        if update.is_deletion() {
+            if rules.block_deletion {
+                return Ok(Some(format!(
+                    "branch '{branch}' is protected and cannot be deleted"
+                )));
+            }
+            return Ok(None);
+        }
+
+        if rules.block_force_push && update.forced && !update.is_creation() {
+            return Ok(Some(format!(
+                "branch '{branch}' is protected and cannot be force-pushed"
+            )));
+        }
+
+        if rules.require_review {
+            return Ok(Some(format!(
+                "branch '{branch}' is protected; changes must land through a review"
+            )));
+        }
+
+        if rules.require_green_build {
+            let build = self
+                .build_repo
+                .get_latest_by_commit(repository_id, &update.new_sha)
+                .await?;
+            let reason = match build.map(|b| b.status) {
+                Some(BuildStatus::Success) => None,
+                Some(BuildStatus::Running) => Some("is still building"),
+                Some(BuildStatus::Failure) => Some("has a failing build"),
+                Some(BuildStatus::Cancelled) => Some("has a cancelled build"),
+                None => Some("has not been built"),
+            };
+            if let Some(reason) = reason {
+                let short_sha = &update.new_sha[..update.new_sha.len().min(7)];
+                return Ok(Some(format!(
+                    "branch '{branch}' requires a green build; commit {short_sha} {reason}"
+                )));
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+#[crate::instrument_all(level = "debug")]
+#[async_trait]
+impl<B, R, D> BranchProtectionService for BranchProtectionServiceImpl<B, R, D>
+where
+    B: BranchProtectionRepository,
+    R: RepositoryRepository,
+    D: BuildRepository,
+{
+    async fn list_branch_protections(
+        &self,
+        request: ListBranchProtectionsRequest,
+    ) -> Result<Vec<BranchProtectionResponse>, BranchProtectionError> {
+        let repository_id = self
+            .get_repository_id(request.owner.as_ref(), request.repo.as_ref())
+            .await?;
+
+        let protections = self.branch_protection_repo.list(repository_id).await?;
+        Ok(protections.into_iter().map(Into::into).collect())
+    }
+
+    async fn create_branch_protection(
+        &self,
+        request: CreateBranchProtectionRequest,
+    ) -> Result<BranchProtectionResponse, BranchProtectionError> {
+        let repository_id = self
+            .get_repository_id(request.owner.as_ref(), request.repo.as_ref())
+            .await?;
+
+        match self
+            .branch_protection_repo
+            .create(repository_id, &request.pattern, request.rules)
+            .await
+        {
+            Ok(protection) => Ok(protection.into()),
+            Err(e) if e.is_unique_violation() => {
+                Err(ConflictError::new("branch protection", request.pattern.as_ref()).into())
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    async fn update_branch_protection(
+        &self,
+        request: UpdateBranchProtectionRequest,
+    ) -> Result<BranchProtectionResponse, BranchProtectionError> {
+        let repository_id = self
+            .get_repository_id(request.owner.as_ref(), request.repo.as_ref())
+            .await?;
+
+        let protection = match self
+            .branch_protection_repo
+            .update(
+                repository_id,
+                request.protection_id,
+                &request.pattern,
+                request.rules,
+            )
+            .await
+        {
+            Ok(protection) => protection,
+            Err(e) if e.is_unique_violation() => {
+                return Err(
+                    ConflictError::new("branch protection", request.pattern.as_ref()).into(),
+                );
+            }
+            Err(e) => return Err(e.into()),
+        };
+
+        let protection = protection.or_not_found("branch protection", request.protection_id)?;
+        Ok(protection.into())
+    }
+
+    async fn delete_branch_protection(
+        &self,
+        request: DeleteBranchProtectionRequest,
+    ) -> Result<(), BranchProtectionError> {
+        let repository_id = self
+            .get_repository_id(request.owner.as_ref(), request.repo.as_ref())
+            .await?;
+
+        let deleted = self
+            .branch_protection_repo
+            .delete(repository_id, request.protection_id)
+            .await?;
+        if !deleted {
+            return Err(NotFoundError::new("branch protection", request.protection_id).into());
+        }
+
+        Ok(())
+    }
+
+    async fn check_ref_updates(
+        &self,
+        request: CheckRefUpdatesRequest,
+    ) -> Result<Vec<RefUpdateRejection>, BranchProtectionError> {
+        let repository_id = self
+            .get_repository_id(request.owner.as_ref(), request.repo.as_ref())
+            .await?;
+
+        let protections = self.branch_protection_repo.list(repository_id).await?;
+        if protections.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut rejections = Vec::new();
+        for update in &request.updates {
+            if let Some(reason) = self
+                .check_ref_update(repository_id, &protections, update)
+                .await?
+            {
+                rejections.push(RefUpdateRejection {
+                    ref_name: update.ref_name.clone(),
+                    reason,
+                });
+            }
+        }
+
+        Ok(rejections)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    use super::{BranchProtectionService, BranchProtectionServiceImpl};
+    use crate::{
+        dto::{CheckRefUpdatesRequest, RefUpdate, UpdateBranchProtectionRequest},
+        error::BranchProtectionError,
+        model::{
+            BranchProtection, BranchProtectionRules, BuildStatus, BuildTrigger, BuildWithStats,
+        },
+        service::test_repository::{
+            MockBranchProtectionRepository, MockBuildRepository, MockRepositoryRepository,
+        },
+    };
+
+    const OLD_SHA: &str = "1111111111111111111111111111111111111111";
+    const NEW_SHA: &str = "2222222222222222222222222222222222222222";
+    const ZERO_SHA: &str = "0000000000000000000000000000000000000000";
+
+    type Service = BranchProtectionServiceImpl<
+        MockBranchProtectionRepository,
+        MockRepositoryRepository,
+        MockBuildRepository,
+    >;
+
+    fn create_service(repository_id: Uuid) -> Service {
+        let mut repo_repo = MockRepositoryRepository::new();
+        repo_repo
+            .expect_get_id()
+            .returning(move |_, _| Ok(Some(repository_id)));
+        BranchProtectionServiceImpl {
+            branch_protection_repo: MockBranchProtectionRepository::new(),
+            repo_repo,
+            build_repo: MockBuildRepository::new(),
+        }
+    }
+
+    fn protection(
+        repository_id: Uuid,
+        pattern: &str,
+        rules: BranchProtectionRules,
+    ) -> BranchProtection {
+        BranchProtection {
+            id: Uuid::new_v4(),
+            repository_id,
+            pattern: pattern.to_string(),
+            block_force_push: rules.block_force_push,
+            block_deletion: rules.block_deletion,
+            require_review: rules.require_review,
+            require_green_build: rules.require_green_build,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn build(repository_id: Uuid, status: BuildStatus) -> BuildWithStats {
+        BuildWithStats {
+            id: Uuid::new_v4(),
+            number: 1,
+            repository_id,
+            ref_name: "refs/heads/main".to_string(),
+            commit_sha: NEW_SHA.to_string(),
+            trigger: BuildTrigger::PushToMain,
+            status,
+            review_id: None,
+            diff_id: None,
+            revision_id: None,
+            total_tasks: 1,
+            completed_tasks: 1,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn update(ref_name: &str, old_sha: &str, new_sha: &str, forced: bool) -> RefUpdate {
+        RefUpdate {
+            ref_name: ref_name.to_string(),
+            old_sha: old_sha.to_string(),
+            new_sha: new_sha.to_string(),
+            forced,
+        }
+    }
+
+    fn check_request(updates: Vec<RefUpdate>) -> CheckRefUpdatesRequest {
+        CheckRefUpdatesRequest::new("alice", "repo", updates).unwrap()
+    }
+
+    fn with_protections(service: &mut Service, protections: Vec<BranchProtection>) {
+        service
+            .branch_protection_repo
+            .expect_list()
+            .returning(move |_| Ok(protections.clone()));
+    }
+
+    #[tokio::test]
+    async fn check_allows_everything_without_rules() {
+        let repository_id = Uuid::new_v4();
+        let mut service = create_service(repository_id);
+        with_protections(&mut service, vec![]);
+
+        let rejections = service
+            .check_ref_updates(check_request(vec![update(
+                "refs/heads/main",
+                OLD_SHA,
+                ZERO_SHA,
+                false,
+            )]))
+            .await
+            .unwrap();
+
+        assert!(rejections.is_empty());
+    }
+
+    #[tokio::test]
+    async fn check_rejects_force_push_and_deletion_of_matching_branch() {
+        let repository_id = Uuid::new_v4();
+        let mut service = create_service(repository_id);
+        let rules = BranchProtectionRules {
+            block_force_push: true,
+            block_deletion: true,
+            ..Default::default()
+        };
+        with_protections(
+            &mut service,
+            vec![protection(repository_id, "release/*", rules)],
+        );
+
+        let rejections = service
+            .check_ref_updates(check_request(vec![
+                update("refs/heads/release/1.0", OLD_SHA, NEW_SHA, true),
+                update("refs/heads/release/2.0", OLD_SHA, ZERO_SHA, false),
+                update("refs/heads/release/3.0", OLD_SHA, NEW_SHA, false),
+                update("refs/heads/feature", OLD_SHA, NEW_SHA, true),
+            ]))
+            .await
+            .unwrap();
+
+        assert_eq!(rejections.len(), 2);
+        assert_eq!(rejections[0].ref_name, "refs/heads/release/1.0");
+        assert!(rejections[0].reason.contains("force-pushed"));
+        assert_eq!(rejections[1].ref_name, "refs/heads/release/2.0");
+        assert!(rejections[1].reason.contains("deleted"));
+    }
+
+    #[tokio::test]
+    async fn check_ignores_refs_outside_heads() {
+        let repository_id = Uuid::new_v4();
+        let mut service = create_service(repository_id);
+        let rules = BranchProtectionRules {
+            block_deletion: true,
+            require_review: true,
+            ..Default::default()
+        };
+        with_protections(&mut service, vec![protection(repository_id, "**", rules)]);
+
+        let rejections = service
+            .check_ref_updates(check_request(vec![update(
+                "refs/tags/v1.0",
+                OLD_SHA,
+                ZERO_SHA,
+                false,
+            )]))
+            .await
+            .unwrap();
+
+        assert!(rejections.is_empty());
+    }
+
+    #[tokio::test]
+    async fn check_unions_rules_from_every_matching_pattern() {
+        let repository_id = Uuid::new_v4();
+        let mut service = create_service(repository_id);
+        let force = BranchProtectionRules {
+            block_force_push: true,
+            ..Default::default()
+        };
+        let review = BranchProtectionRules {
+            require_review: true,
+            ..Default::default()
+        };
+        with_protections(
+            &mut service,
+            vec![
+                protection(repository_id, "**", force),
+                protection(repository_id, "main", review),
+            ],
+        );
+
+        let rejections = service
+            .check_ref_updates(check_request(vec![update(
+                "refs/heads/main",
+                OLD_SHA,
+                NEW_SHA,
+                false,
+            )]))
+            .await
+            .unwrap();
+
+        assert_eq!(rejections.len(), 1);
+        assert!(rejections[0].reason.contains("through a review"));
+    }
+
+    #[tokio::test]
+    async fn check_requires_successful_build_of_new_commit() {
+        let repository_id = Uuid::new_v4();
+        let rules = BranchProtectionRules {
+            require_green_build: true,
+            ..Default::default()
+        };
+
+        for (status, expected) in [
+            (Some(BuildStatus::Success), None),
+            (Some(BuildStatus::Running), Some("still building")),
+            (Some(BuildStatus::Failure), Some("failing build")),
+            (None, Some("has not been built")),
+        ] {
+            let mut service = create_service(repository_id);
+            with_protections(&mut service, vec![protection(repository_id, "main", rules)]);
+            service
+                .build_repo
+                .expect_get_latest_by_commit()
+                .withf(|_, sha| sha == NEW_SHA)
+                .returning(move |id, _| Ok(status.clone().map(|s| build(id, s))));
+
+            let rejections = service
+                .check_ref_updates(check_request(vec![update(
+                    "refs/heads/main",
+                    OLD_SHA,
+                    NEW_SHA,
+                    false,
+                )]))
+                .await
+                .unwrap();
+
+            match expected {
+                None => assert!(rejections.is_empty()),
+                Some(reason) => {
+                    assert_eq!(rejections.len(), 1);
+                    assert!(rejections[0].reason.contains(reason));
+                    assert!(rejections[0].reason.contains("2222222"));
+                }
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn check_returns_not_found_for_missing_repository() {
+        let mut service = create_service(Uuid::new_v4());
+        service.repo_repo = MockRepositoryRepository::new();
+        service.repo_repo.expect_get_id().returning(|_, _| Ok(None));
+
+        let err = service
+            .check_ref_updates(check_request(vec![]))
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, BranchProtectionError::NotFound(_)));
+    }
+
+    #[tokio::test]
+    async fn update_returns_not_found_for_rule_in_other_repository() {
+        let repository_id = Uuid::new_v4();
+        let mut service = create_service(repository_id);
+        service
+            .branch_protection_repo
+            .expect_update()
+            .withf(move |repo_id, _, _, _| *repo_id == repository_id)
+            .returning(|_, _, _, _| Ok(None));
+
+        let request = UpdateBranchProtectionRequest::new(
+            "alice",
+            "repo",
+            Uuid::new_v4(),
+            "main",
+            BranchProtectionRules::default(),
+        )
+        .unwrap();
+        let err = service.update_branch_protection(request).await.unwrap_err();
+
+        assert!(matches!(err, BranchProtectionError::NotFound(_)));
+    }
+}
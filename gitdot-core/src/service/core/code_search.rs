@@ -0,0 +1,128 @@
+use async_trait::async_trait;
+
+use crate::{
+    client::{Git2Client, GitClient},
+    dto::{CodeSearchResponse, IndexCodeRequest, SearchCodeRequest},
+    error::CodeSearchError,
+};
+
+/// Searches the code on a repository's default branch.
+///
+/// Each bare repository keeps a trigram index of the text files at `HEAD`.
+/// Pushes to the default branch rebuild it in the background, and repository
+/// maintenance rebuilds one that is missing or behind. Searches only read the
+/// last index built, so they never wait on a rebuild.
+#[async_trait]
+pub trait CodeSearchService: Send + Sync + 'static {
+    /// Rebuilds the index when `ref_name` is the repository's default branch;
+    /// pushes to other refs and branch deletions are ignored.
+    ///
+    /// # Errors
+    /// - [`CodeSearchError::NotFound`] if the repository does not exist.
+    async fn index_code(&self, request: IndexCodeRequest) -> Result<(), CodeSearchError>;
+
+    /// Returns the files on the default branch that match the query, each with
+    /// its matching lines and surrounding context.
+    ///
+    /// # Errors
+    /// - [`CodeSearchError::NotFound`] if the repository does not exist.
+    async fn search_code(
+        &self,
+        request: SearchCodeRequest,
+    ) -> Result<CodeSearchResponse, CodeSearchError>;
+}
+
+#[derive(Debug, Clone)]
+pub struct CodeSearchServiceImpl<G>
+where
+    G: GitClient,
+{
+    git_client: G,
+}
+
+impl CodeSearchServiceImpl<Git2Client> {
+    pub fn new(git_client: Git2Client) -> Self {
+        Self { git_client }
+    }
+}
+
+#[crate::instrument_all(level = "debug")]
+#[async_trait]
+impl<G> CodeSearchService for CodeSearchServiceImpl<G>
+where
+    G: GitClient,
+{
+    async fn index_code(&self, request: IndexCodeRequest) -> Result<(), CodeSearchError> {
+        if request.is_delete() {
+            return Ok(());
+        }
+        let default_ref = self
+            .git_client
+            .get_default_ref(&request.owner_name, &request.name)
+            .await?;
+        if request.ref_name != default_ref {
+            return Ok(());
+        }
+
+        self.git_client
+            .index_code(&request.owner_name, &request.name)
+            .await?;
+        Ok(())
+    }
+
+    async fn search_code(
+        &self,
+        request: SearchCodeRequest,
+    ) -> Result<CodeSearchResponse, CodeSearchError> {
+        self.git_client
+            .search_code(
+                &request.owner_name,
+                &request.name,
+                request.query,
+                request.limit as usize,
+            )
+            .await
+            .map_err(Into::into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::service::test_client::MockGitClient;
+
+    fn service(git_client: MockGitClient) -> CodeSearchServiceImpl<MockGitClient> {
+        CodeSearchServiceImpl { git_client }
+    }
+
+    #[tokio::test]
+    async fn index_code_only_indexes_default_branch_pushes() {
+        let git_client = MockGitClient::default();
+        let service = service(git_client.clone());
+        let sha = "b".repeat(40);
+
+        for ref_name in ["refs/heads/feature", "refs/tags/v1.0"] {
+            let request =
+                IndexCodeRequest::new("dotfiles", "alice", ref_name.to_string(), sha.clone())
+                    .unwrap();
+            service.index_code(request).await.unwrap();
+        }
+        let request = IndexCodeRequest::new(
+            "dotfiles",
+            "alice",
+            "refs/heads/main".to_string(),
+            "0".repeat(40),
+        )
+        .unwrap();
+        service.index_code(request).await.unwrap();
+        assert!(git_client.indexed_repos().is_empty());
+
+        let request =
+            IndexCodeRequest::new("dotfiles", "alice", "refs/heads/main".to_string(), sha).unwrap();
+        service.index_code(request).await.unwrap();
+        assert_eq!(
+            git_client.indexed_repos(),
+            [("alice".to_string(), "dotfiles".to_string())]
+        );
+    }
+}
@@ -0,0 +1,473 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::{
+    dto::{
+        CancelInvitationRequest, CollaboratorResponse, InviteCollaboratorRequest,
+        ListCollaboratorsRequest, ListRepositoryInvitationsRequest, RemoveCollaboratorRequest,
+        RepositoryInvitationResponse, RespondToInvitationRequest, UpdateCollaboratorRequest,
+    },
+    error::{CollaboratorError, ConflictError, InputError, NotFoundError, OptionNotFoundExt},
+    model::User,
+    repository::{
+        CollaboratorRepository, PgCollaboratorRepository, PgRepositoryRepository, PgUserRepository,
+        RepositoryRepository, UserRepository,
+    },
+};
+
+/// Manages the users granted a role on a single repository.
+///
+/// Nobody becomes a collaborator without consent: a repository admin invites
+/// a user with a role, and the grant only takes effect once the invitee
+/// accepts. Authorization (repository admin for the repository-scoped
+/// methods) is checked by callers; the invitee-scoped methods only act on
+/// invitations addressed to `request.user_id`.
+#[async_trait]
+pub trait CollaboratorService: Send + Sync + 'static {
+    /// Lists a repository's collaborators, ordered by user name.
+    ///
+    /// # Errors
+    /// - [`CollaboratorError::NotFound`] if the repository does not exist.
+    async fn list_collaborators(
+        &self,
+        request: ListCollaboratorsRequest,
+    ) -> Result<Vec<CollaboratorResponse>, CollaboratorError>;
+
+    /// Invites a user to collaborate on a repository. Re-inviting a user with
+    /// a pending invitation replaces its role.
+    ///
+    /// # Errors
+    /// - [`CollaboratorError::NotFound`] if the repository or user does not
+    ///   exist.
+    /// - [`CollaboratorError::Input`] if the user owns the repository.
+    /// - [`CollaboratorError::Conflict`] if the user is already a
+    ///   collaborator; change their role with `update_collaborator` instead.
+    async fn invite_collaborator(
+        &self,
+        request: InviteCollaboratorRequest,
+    ) -> Result<RepositoryInvitationResponse, CollaboratorError>;
+
+    /// Lists a repository's pending invitations, newest first.
+    ///
+    /// # Errors
+    /// - [`CollaboratorError::NotFound`] if the repository does not exist.
+    async fn list_repository_invitations(
+        &self,
+        request: ListRepositoryInvitationsRequest,
+    ) -> Result<Vec<RepositoryInvitationResponse>, CollaboratorError>;
+
+    /// Withdraws a pending invitation.
+    ///
+    /// # Errors
+    /// - [`CollaboratorError::NotFound`] if the repository or invitation does
+    ///   not exist.
+    async fn cancel_invitation(
+        &self,
+        request: CancelInvitationRequest,
+    ) -> Result<(), CollaboratorError>;
+
+    /// Changes a collaborator's role.
+    ///
+    /// # Errors
+    /// - [`CollaboratorError::NotFound`] if the repository or user does not
+    ///   exist, or the user is not a collaborator.
+    async fn update_collaborator(
+        &self,
+        request: UpdateCollaboratorRequest,
+    ) -> Result<CollaboratorResponse, CollaboratorError>;
+
+    /// Revokes a collaborator's access.
+    ///
+    /// # Errors
+    /// - [`CollaboratorError::NotFound`] if the repository or user does not
+    ///   exist, or the user is not a collaborator.
+    async fn remove_collaborator(
+        &self,
+        request: RemoveCollaboratorRequest,
+    ) -> Result<(), CollaboratorError>;
+
+    /// Lists the invitations addressed to `user_id`, newest first.
+    async fn list_user_invitations(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Vec<RepositoryInvitationResponse>, CollaboratorError>;
+
+    /// Accepts an invitation, making the invitee a collaborator with the
+    /// invited role.
+    ///
+    /// # Errors
+    /// - [`CollaboratorError::NotFound`] if no such invitation is addressed
+    ///   to the user.
+    async fn accept_invitation(
+        &self,
+        request: RespondToInvitationRequest,
+    ) -> Result<CollaboratorResponse, CollaboratorError>;
+
+    /// Declines an invitation without granting anything.
+    ///
+    /// # Errors
+    /// - [`CollaboratorError::NotFound`] if no such invitation is addressed
+    ///   to the user.
+    async fn decline_invitation(
+        &self,
+        request: RespondToInvitationRequest,
+    ) -> Result<(), CollaboratorError>;
+}
+
+#[derive(Debug, Clone)]
+pub struct CollaboratorServiceImpl<C, R, U>
+where
+    C: CollaboratorRepository,
+    R: RepositoryRepository,
+    U: UserRepository,
+{
+    collaborator_repo: C,
+    repo_repo: R,
+    user_repo: U,
+}
+
+impl CollaboratorServiceImpl<PgCollaboratorRepository, PgRepositoryRepository, PgUserRepository> {
+    pub fn new(
+        collaborator_repo: PgCollaboratorRepository,
+        repo_repo: PgRepositoryRepository,
+        user_repo: PgUserRepository,
+    ) -> Self {
+        Self {
+            collaborator_repo,
+            repo_repo,
+            user_repo,
+        }
+    }
+}
+
+impl<C, R, U> CollaboratorServiceImpl<C, R, U>
+where
+    C: CollaboratorRepository,
+    R: RepositoryRepository,
+    U: UserRepository,
+{
+    async fn get_repository_id(&self, owner: &str, repo: &str) -> Result<Uuid, CollaboratorError> {
+        let id = self
+            .repo_repo
+            .get_id(owner, repo)
+            .await?
+            .or_not_found("repository", format!("{owner}/{repo}"))?;
+        Ok(id)
+    }
+
+    async fn get_user(&self, user_name: &str) -> Result<User, CollaboratorError> {
+        let user = self
+            .user_repo
+            .get(user_name)
+            .await?
+            .or_not_found("user", user_name)?;
+        Ok(user)
+    }
+}
+
+#[crate::instrument_all(level = "debug")]
+#[async_trait]
+impl<C, R, U> CollaboratorService for CollaboratorServiceImpl<C, R, U>
+where
+    C: CollaboratorRepository,
+    R: RepositoryRepository,
+    U: UserRepository,
+{
+    async fn list_collaborators(
+        &self,
+        request: ListCollaboratorsRequest,
+    ) -> Result<Vec<CollaboratorResponse>, CollaboratorError> {
+        let repository_id = self
+            .get_repository_id(request.owner.as_ref(), request.repo.as_ref())
+            .await?;
+
+        let collaborators = self
+            .collaborator_repo
+            .list_collaborators(repository_id)
+            .await?;
+        Ok(collaborators.into_iter().map(Into::into).collect())
+    }
+
+    async fn invite_collaborator(
+        &self,
+        request: InviteCollaboratorRequest,
+    ) -> Result<RepositoryInvitationResponse, CollaboratorError> {
+        let repository = self
+            .repo_repo
+            .get(request.owner.as_ref(), request.repo.as_ref(), None)
+            .await?
+            .or_not_found(
+                "repository",
+                format!("{}/{}", request.owner.as_ref(), request.repo.as_ref()),
+            )?;
+        let user = self.get_user(request.user_name.as_ref()).await?;
+
+        if repository.is_owned_by_user() && repository.owner_id == user.id {
+            return Err(InputError::new("user name", "already owns the repository").into());
+        }
+        if self
+            .collaborator_repo
+            .get_collaborator(repository.id, user.id)
+            .await?
+            .is_some()
+        {
+            return Err(ConflictError::new("collaborator", &user.name).into());
+        }
+
+        let invitation = self
+            .collaborator_repo
+            .create_invitation(repository.id, user.id, request.inviter_id, request.role)
+            .await?;
+        Ok(invitation.into())
+    }
+
+    async fn list_repository_invitations(
+        &self,
+        request: ListRepositoryInvitationsRequest,
+    ) -> Result<Vec<RepositoryInvitationResponse>, CollaboratorError> {
+        let repository_id = self
+            .get_repository_id(request.owner.as_ref(), request.repo.as_ref())
+            .await?;
+
+        let invitations = self
+            .collaborator_repo
+            .list_repository_invitations(repository_id)
+            .await?;
+        Ok(invitations.into_iter().map(Into::into).collect())
+    }
+
+    async fn cancel_invitation(
+        &self,
+        request: CancelInvitationRequest,
+    ) -> Result<(), CollaboratorError> {
+        let repository_id = self
+            .get_repository_id(request.owner.as_ref(), request.repo.as_ref())
+            .await?;
+
+        let deleted = self
+            .collaborator_repo
+            .delete_invitation(repository_id, request.invitation_id)
+            .await?;
+        if !deleted {
+            return Err(NotFoundError::new("invitation", request.invitation_id).into());
+        }
+
+        Ok(())
+    }
+
+    async fn update_collaborator(
+        &self,
+        request: UpdateCollaboratorRequest,
+    ) -> Result<CollaboratorResponse, CollaboratorError> {
+        let repository_id = self
+            .get_repository_id(request.owner.as_ref(), request.repo.as_ref())
+            .await?;
+        let user = self.get_user(request.user_name.as_ref()).await?;
+
+        let collaborator = self
+            .collaborator_repo
+            .update_collaborator(repository_id, user.id, request.role)
+            .await?
+            .or_not_found("collaborator", &user.name)?;
+        Ok(collaborator.into())
+    }
+
+    async fn remove_collaborator(
+        &self,
+        request: RemoveCollaboratorRequest,
+    ) -> Result<(), CollaboratorError> {
+        let repository_id = self
+            .get_repository_id(request.owner.as_ref(), request.repo.as_ref())
+            .await?;
+        let user = self.get_user(request.user_name.as_ref()).await?;
+
+        let deleted = self
+            .collaborator_repo
+            .delete_collaborator(repository_id, user.id)
+            .await?;
+        if !deleted {
+            return Err(NotFoundError::new("collaborator", &user.name).into());
+        }
+
+        Ok(())
+    }
+
+    async fn list_user_invitations(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Vec<RepositoryInvitationResponse>, CollaboratorError> {
+        let invitations = self
+            .collaborator_repo
+            .list_user_invitations(user_id)
+            .await?;
+        Ok(invitations.into_iter().map(Into::into).collect())
+    }
+
+    async fn accept_invitation(
+        &self,
+        request: RespondToInvitationRequest,
+    ) -> Result<CollaboratorResponse, CollaboratorError> {
+        let collaborator = self
+            .collaborator_repo
+            .accept_invitation(request.invitation_id, request.user_id)
+            .await?
+            .or_not_found("invitation", request.invitation_id)?;
+        Ok(collaborator.into())
+    }
+
+    async fn decline_invitation(
+        &self,
+        request: RespondToInvitationRequest,
+    ) -> Result<(), CollaboratorError> {
+        let deleted = self
+            .collaborator_repo
+            .decline_invitation(request.invitation_id, request.user_id)
+            .await?;
+        if !deleted {
+            return Err(NotFoundError::new("invitation", request.invitation_id).into());
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    use super::{CollaboratorService, CollaboratorServiceImpl};
+    use crate::{
+        dto::{InviteCollaboratorRequest, RespondToInvitationRequest},
+        error::CollaboratorError,
+        model::{
+            RepositoryCollaborator, RepositoryInvitation, RepositoryOwnerType, RepositoryRole,
+            RepositoryVisibility,
+        },
+        service::{
+            test_common::{create_repository, create_user},
+            test_repository::{
+                MockCollaboratorRepository, MockRepositoryRepository, MockUserRepository,
+            },
+        },
+    };
+
+    type Service = CollaboratorServiceImpl<
+        MockCollaboratorRepository,
+        MockRepositoryRepository,
+        MockUserRepository,
+    >;
+
+    fn create_service(owner_id: Uuid, invitee_id: Uuid) -> Service {
+        let mut repo_repo = MockRepositoryRepository::new();
+        repo_repo.expect_get().returning(move |_, _, _| {
+            Ok(Some(create_repository(
+                owner_id,
+                RepositoryOwnerType::User,
+                RepositoryVisibility::Private,
+            )))
+        });
+        let mut user_repo = MockUserRepository::new();
+        user_repo.expect_get().returning(move |name| {
+            let mut user = create_user(name);
+            user.id = invitee_id;
+            Ok(Some(user))
+        });
+
+        CollaboratorServiceImpl {
+            collaborator_repo: MockCollaboratorRepository::new(),
+            repo_repo,
+            user_repo,
+        }
+    }
+
+    fn create_invitation(role: RepositoryRole) -> RepositoryInvitation {
+        RepositoryInvitation {
+            id: Uuid::new_v4(),
+            repository_id: Uuid::new_v4(),
+            owner_name: "owner".to_string(),
+            repository_name: "myrepo".to_string(),
+            invitee_id: Uuid::new_v4(),
+            invitee_name: "bob".to_string(),
+            inviter_id: Uuid::new_v4(),
+            inviter_name: "owner".to_string(),
+            role,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn invite_creates_invitation() {
+        let mut service = create_service(Uuid::new_v4(), Uuid::new_v4());
+        service
+            .collaborator_repo
+            .expect_get_collaborator()
+            .returning(|_, _| Ok(None));
+        service
+            .collaborator_repo
+            .expect_create_invitation()
+            .returning(|_, _, _, role| Ok(create_invitation(role)));
+
+        let request =
+            InviteCollaboratorRequest::new("owner", "myrepo", Uuid::new_v4(), "bob", "triage")
+                .unwrap();
+        let invitation = service.invite_collaborator(request).await.unwrap();
+        assert_eq!(invitation.role, RepositoryRole::Triage);
+    }
+
+    #[tokio::test]
+    async fn invite_owner_is_rejected() {
+        let owner_id = Uuid::new_v4();
+        let service = create_service(owner_id, owner_id);
+
+        let request =
+            InviteCollaboratorRequest::new("owner", "myrepo", owner_id, "owner", "write").unwrap();
+        let err = service.invite_collaborator(request).await.unwrap_err();
+        assert!(matches!(err, CollaboratorError::Input(_)));
+    }
+
+    #[tokio::test]
+    async fn invite_existing_collaborator_conflicts() {
+        let invitee_id = Uuid::new_v4();
+        let mut service = create_service(Uuid::new_v4(), invitee_id);
+        service
+            .collaborator_repo
+            .expect_get_collaborator()
+            .returning(move |repository_id, user_id| {
+                Ok(Some(RepositoryCollaborator {
+                    repository_id,
+                    user_id,
+                    user_name: "bob".to_string(),
+                    role: RepositoryRole::Read,
+                    created_at: Utc::now(),
+                    image_updated_at: Utc::now(),
+                }))
+            });
+
+        let request =
+            InviteCollaboratorRequest::new("owner", "myrepo", Uuid::new_v4(), "bob", "admin")
+                .unwrap();
+        let err = service.invite_collaborator(request).await.unwrap_err();
+        assert!(matches!(err, CollaboratorError::Conflict(_)));
+    }
+
+    #[test]
+    fn invite_rejects_unknown_role() {
+        let err = InviteCollaboratorRequest::new("owner", "myrepo", Uuid::new_v4(), "bob", "owner")
+            .unwrap_err();
+        assert!(matches!(err, CollaboratorError::Input(_)));
+    }
+
+    #[tokio::test]
+    async fn accept_missing_invitation_is_not_found() {
+        let mut service = create_service(Uuid::new_v4(), Uuid::new_v4());
+        service
+            .collaborator_repo
+            .expect_accept_invitation()
+            .returning(|_, _| Ok(None));
+
+        let request = RespondToInvitationRequest::new(Uuid::new_v4(), Uuid::new_v4());
+        let err = service.accept_invitation(request).await.unwrap_err();
+        assert!(matches!(err, CollaboratorError::NotFound(_)));
+    }
+}
@@ -2,19 +2,27 @@ use std::collections::HashMap;
 
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
+use futures::{StreamExt, stream};
 use uuid::Uuid;
 
 use crate::{
-    client::{Git2Client, GitClient},
+    client::{Git2Client, GitClient, SignatureClient, SignatureClientImpl},
     dto::{CommitResponse, CreateCommitsRequest},
-    error::{CommitError, OptionNotFoundExt},
-    model,
+    error::{CommitError, DatabaseError, GitError, OptionNotFoundExt, SignatureError},
+    model::{self, CommitSignatureStatus},
     repository::{
-        CommitRepository, PgCommitRepository, PgRepositoryRepository, PgUserRepository,
-        RepositoryRepository, UserRepository,
+        CommitRepository, PgCommitRepository, PgRepositoryRepository, PgSigningKeyRepository,
+        PgUserRepository, RepositoryRepository, SigningKeyRepository, UserRepository,
+    },
+    util::{
+        git::{TAG_PREFIX, ZERO_SHA},
+        signature::signature_key_id,
     },
 };
 
+/// How many commit signatures are checked at once after a push.
+const SIGNATURE_CHECK_CONCURRENCY: usize = 4;
+
 /// Ingests pushed git history into the database, materializing commit rows (with
 /// per-file diff stats) for a repository's ref.
 #[async_trait]
@@ -22,15 +30,22 @@ pub trait CommitService: Send + Sync + 'static {
     /// Records every commit in the range `old_sha..new_sha` on `request.ref_name`
     /// for the owner/repo as `commits` rows.
     ///
-    /// Resolves the range via `git rev-list`; if it is empty (no new commits)
-    /// returns an empty `Vec` without touching the database. For each commit it
-    /// computes per-file diff stats against the parent and bulk-inserts the
-    /// commits in one call. Author emails are matched against existing users so
+    /// Newly created tag refs and ref deletions record nothing; a tag moved
+    /// from a known commit records the commits past it. Otherwise resolves the range
+    /// via `git rev-list`; if it is empty (no new commits) returns an empty
+    /// `Vec` without touching the database. For each commit it computes
+    /// per-file diff stats against the parent and bulk-inserts the commits in
+    /// one call. Author emails are matched against existing users so
     /// `author_id` is populated when the committer has a gitdot account
     /// (otherwise null, keeping the git author name/email). A missing parent
     /// (root commit) is stored as the all-zero SHA. `request.review_number` and
     /// `request.diff_positions` (keyed by SHA) are attached when present.
     ///
+    /// Commits are stored without a `signature_status`. Their signatures are
+    /// then checked against the users' registered signing keys in a background
+    /// task, since each check runs `gpg` or `ssh-keygen`, and the statuses of
+    /// signed commits stored as they finish.
+    ///
     /// # Errors
     /// - [`CommitError`] wrapping a not-found error when the owner/repo does not
     ///   resolve to a repository.
@@ -41,48 +56,74 @@ pub trait CommitService: Send + Sync + 'static {
 }
 
 #[derive(Debug, Clone)]
-pub struct CommitServiceImpl<C, R, U, G>
+pub struct CommitServiceImpl<C, R, U, K, G, S>
 where
     C: CommitRepository,
     R: RepositoryRepository,
     U: UserRepository,
+    K: SigningKeyRepository,
     G: GitClient,
+    S: SignatureClient,
 {
     commit_repo: C,
     repo_repo: R,
     user_repo: U,
+    signing_key_repo: K,
     git_client: G,
+    signature_client: S,
 }
 
-impl CommitServiceImpl<PgCommitRepository, PgRepositoryRepository, PgUserRepository, Git2Client> {
+impl
+    CommitServiceImpl<
+        PgCommitRepository,
+        PgRepositoryRepository,
+        PgUserRepository,
+        PgSigningKeyRepository,
+        Git2Client,
+        SignatureClientImpl,
+    >
+{
     pub fn new(
         commit_repo: PgCommitRepository,
         repo_repo: PgRepositoryRepository,
         user_repo: PgUserRepository,
+        signing_key_repo: PgSigningKeyRepository,
         git_client: Git2Client,
+        signature_client: SignatureClientImpl,
     ) -> Self {
         Self {
             commit_repo,
             repo_repo,
             user_repo,
+            signing_key_repo,
             git_client,
+            signature_client,
         }
     }
 }
 
 #[crate::instrument_all(level = "debug")]
 #[async_trait]
-impl<C, R, U, G> CommitService for CommitServiceImpl<C, R, U, G>
+impl<C, R, U, K, G, S> CommitService for CommitServiceImpl<C, R, U, K, G, S>
 where
     C: CommitRepository,
     R: RepositoryRepository,
     U: UserRepository,
+    K: SigningKeyRepository,
     G: GitClient,
+    S: SignatureClient,
 {
     async fn create_commits(
         &self,
         request: CreateCommitsRequest,
     ) -> Result<Vec<CommitResponse>, CommitError> {
+        // New tags point at history already ingested through branches, and
+        // deletions introduce no commits.
+        let new_tag = request.ref_name.starts_with(TAG_PREFIX) && request.old_sha == ZERO_SHA;
+        if new_tag || request.new_sha == ZERO_SHA {
+            return Ok(Vec::new());
+        }
+
         let owner = request.owner.to_string();
         let repo_name = request.repo.to_string();
 
@@ -124,6 +165,7 @@ where
         let mut messages = Vec::new();
         let mut created_ats: Vec<DateTime<Utc>> = Vec::new();
         let mut diffs_per_commit: Vec<Vec<model::CommitDiff>> = Vec::new();
+        let signature_statuses = vec![None; git_commits.len()];
 
         for commit in &git_commits {
             let stats = self
@@ -182,8 +224,374 @@ where
                 &diffs_per_commit,
                 &review_numbers,
                 &diff_positions,
+                &signature_statuses,
             )
             .await?;
-        Ok(commits.into_iter().map(|c| c.into()).collect())
+        let commits: Vec<CommitResponse> = commits.into_iter().map(|c| c.into()).collect();
+
+        let service = self.clone();
+        let pending = commits
+            .iter()
+            .map(|c| (c.id, c.sha.clone(), c.author_id))
+            .collect();
+        tokio::spawn(async move {
+            service
+                .store_signature_statuses(&owner, &repo_name, pending)
+                .await;
+        });
+
+        Ok(commits)
+    }
+}
+
+impl<C, R, U, K, G, S> CommitServiceImpl<C, R, U, K, G, S>
+where
+    C: CommitRepository,
+    R: RepositoryRepository,
+    U: UserRepository,
+    K: SigningKeyRepository,
+    G: GitClient,
+    S: SignatureClient,
+{
+    /// Checks the signatures of freshly ingested `(id, sha, author_id)`
+    /// commits and stores the status of each signed one. A check that fails
+    /// is logged and left for [`RepositoryService::get_repository_commit`]
+    /// to redo.
+    ///
+    /// [`RepositoryService::get_repository_commit`]: crate::service::RepositoryService::get_repository_commit
+    async fn store_signature_statuses(
+        &self,
+        owner: &str,
+        repo: &str,
+        commits: Vec<(Uuid, String, Option<Uuid>)>,
+    ) {
+        stream::iter(commits)
+            .for_each_concurrent(
+                SIGNATURE_CHECK_CONCURRENCY,
+                |(id, sha, author_id)| async move {
+                    let status = match verify_commit_signature::<_, _, _, CommitError>(
+                        &self.git_client,
+                        &self.signing_key_repo,
+                        &self.signature_client,
+                        owner,
+                        repo,
+                        &sha,
+                        author_id,
+                    )
+                    .await
+                    {
+                        Ok(Some(status)) => status,
+                        Ok(None) => return,
+                        Err(e) => {
+                            tracing::warn!(error = %e, sha, "failed to check commit signature");
+                            return;
+                        }
+                    };
+
+                    if let Err(e) = self
+                        .commit_repo
+                        .set_signature_status(id, Some(status))
+                        .await
+                    {
+                        tracing::warn!(error = %e, sha, "failed to store commit signature status");
+                    }
+                },
+            )
+            .await;
+    }
+}
+
+/// Checks the signature of the commit `sha` against the signing keys users
+/// have registered, or returns `None` for an unsigned commit. `author_id` is
+/// the user whose verified email authored the commit; a good signature by
+/// anyone else's key is [`CommitSignatureStatus::BadEmail`].
+///
+/// Shared by ingestion and [`RepositoryService::get_repository_commit`]
+/// (which re-checks, as keys may have been registered since the push).
+///
+/// [`RepositoryService::get_repository_commit`]: crate::service::RepositoryService::get_repository_commit
+pub(crate) async fn verify_commit_signature<G, K, S, E>(
+    git_client: &G,
+    signing_key_repo: &K,
+    signature_client: &S,
+    owner: &str,
+    repo: &str,
+    sha: &str,
+    author_id: Option<Uuid>,
+) -> Result<Option<CommitSignatureStatus>, E>
+where
+    G: GitClient,
+    K: SigningKeyRepository,
+    S: SignatureClient,
+    E: From<GitError> + From<DatabaseError> + From<SignatureError>,
+{
+    let Some((signature, payload)) = git_client.get_commit_signature(owner, repo, sha).await?
+    else {
+        return Ok(None);
+    };
+    let Some((key_type, key_id)) = signature_key_id(&signature) else {
+        return Ok(Some(CommitSignatureStatus::Unverified));
+    };
+    let mut keys = signing_key_repo.list_by_key_id(&key_id).await?;
+    keys.retain(|key| key.key_type == key_type);
+    if keys.is_empty() {
+        return Ok(Some(CommitSignatureStatus::UnknownKey));
+    }
+
+    // A short GPG key id can name several keys. The signature decides which
+    // one made it, trying the author's own keys first.
+    keys.sort_by_key(|key| author_id != Some(key.user_id));
+    for key in &keys {
+        if signature_client
+            .verify_signature(key, &signature, &payload)
+            .await?
+        {
+            let status = if author_id == Some(key.user_id) {
+                CommitSignatureStatus::Verified
+            } else {
+                CommitSignatureStatus::BadEmail
+            };
+            return Ok(Some(status));
+        }
+    }
+
+    Ok(Some(CommitSignatureStatus::Unverified))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use mockall::predicate::eq;
+
+    use super::*;
+    use crate::{
+        model::{SigningKey, SigningKeyType},
+        service::{
+            test_client::{MockGitClient, MockSignatureClient},
+            test_repository::{
+                MockCommitRepository, MockRepositoryRepository, MockSigningKeyRepository,
+                MockUserRepository,
+            },
+        },
+    };
+
+    const SHA: &str = "1111111111111111111111111111111111111111";
+
+    // `ssh-keygen -Y sign -n git`; the key embedded in it has this fingerprint.
+    const SSH_SIGNATURE: &str = "-----BEGIN SSH SIGNATURE-----
+U1NIU0lHAAAAAQAAADMAAAALc3NoLWVkMjU1MTkAAAAgVzhxqEnGydlvBTtSsMyWpoDDQM
+apOdRNeOYRFsuMLaUAAAADZ2l0AAAAAAAAAAZzaGE1MTIAAABTAAAAC3NzaC1lZDI1NTE5
+AAAAQKExOepW5bZorNFN1KoMj79Dxrx6nHHNLOzGYZ724sk4wM42s3bJJXLFi+sd/mKjLM
+1TTZIQ4kE/TYN/nGE2dws=
+-----END SSH SIGNATURE-----
+";
+    const SSH_FINGERPRINT: &str = "SHA256:KiC09V0FHSf11LDskwMpSTTaeCgv/aKlrwbYSh16s38";
+
+    fn signing_key(user_id: Uuid) -> SigningKey {
+        SigningKey {
+            id: Uuid::new_v4(),
+            user_id,
+            name: "laptop".to_string(),
+            key_type: SigningKeyType::Ssh,
+            public_key: "ssh-ed25519 AAAA".to_string(),
+            fingerprint: SSH_FINGERPRINT.to_string(),
+            key_ids: vec![SSH_FINGERPRINT.to_string()],
+            created_at: Utc::now(),
+        }
+    }
+
+    async fn verify(
+        git_client: MockGitClient,
+        signing_key_repo: MockSigningKeyRepository,
+        signature_client: MockSignatureClient,
+        author_id: Option<Uuid>,
+    ) -> Option<CommitSignatureStatus> {
+        verify_commit_signature::<_, _, _, CommitError>(
+            &git_client,
+            &signing_key_repo,
+            &signature_client,
+            "alice",
+            "repo",
+            SHA,
+            author_id,
+        )
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn unsigned_commit_has_no_status() {
+        let status = verify(
+            MockGitClient::default(),
+            MockSigningKeyRepository::new(),
+            MockSignatureClient::new(),
+            None,
+        )
+        .await;
+
+        assert_eq!(status, None);
+    }
+
+    #[tokio::test]
+    async fn unsupported_signature_is_unverified() {
+        let git_client = MockGitClient::default().with_signature(
+            SHA,
+            "-----BEGIN SIGNED MESSAGE-----\nMIAG\n-----END SIGNED MESSAGE-----",
+            b"tree",
+        );
+        let status = verify(
+            git_client,
+            MockSigningKeyRepository::new(),
+            MockSignatureClient::new(),
+            None,
+        )
+        .await;
+
+        assert_eq!(status, Some(CommitSignatureStatus::Unverified));
+    }
+
+    #[tokio::test]
+    async fn unregistered_key_is_unknown() {
+        let git_client = MockGitClient::default().with_signature(SHA, SSH_SIGNATURE, b"tree");
+        let mut signing_key_repo = MockSigningKeyRepository::new();
+        signing_key_repo
+            .expect_list_by_key_id()
+            .with(eq(SSH_FINGERPRINT))
+            .returning(|_| Ok(vec![]));
+
+        let status = verify(
+            git_client,
+            signing_key_repo,
+            MockSignatureClient::new(),
+            Some(Uuid::new_v4()),
+        )
+        .await;
+
+        assert_eq!(status, Some(CommitSignatureStatus::UnknownKey));
+    }
+
+    #[tokio::test]
+    async fn status_depends_on_signature_and_author() {
+        let owner = Uuid::new_v4();
+        for (valid, author_id, expected) in [
+            (true, Some(owner), CommitSignatureStatus::Verified),
+            (true, Some(Uuid::new_v4()), CommitSignatureStatus::BadEmail),
+            (true, None, CommitSignatureStatus::BadEmail),
+            (false, Some(owner), CommitSignatureStatus::Unverified),
+        ] {
+            let git_client = MockGitClient::default().with_signature(SHA, SSH_SIGNATURE, b"tree");
+            let mut signing_key_repo = MockSigningKeyRepository::new();
+            signing_key_repo
+                .expect_list_by_key_id()
+                .returning(move |_| Ok(vec![signing_key(owner)]));
+            let mut signature_client = MockSignatureClient::new();
+            signature_client
+                .expect_verify_signature()
+                .withf(|_, signature, payload| signature == SSH_SIGNATURE && payload == b"tree")
+                .returning(move |_, _, _| Ok(valid));
+
+            let status = verify(git_client, signing_key_repo, signature_client, author_id).await;
+
+            assert_eq!(status, Some(expected));
+        }
+    }
+
+    #[tokio::test]
+    async fn colliding_key_ids_resolve_to_the_key_that_made_the_signature() {
+        let author = Uuid::new_v4();
+        let squatter = signing_key(Uuid::new_v4());
+        let owned = signing_key(author);
+        let owned_id = owned.id;
+
+        let git_client = MockGitClient::default().with_signature(SHA, SSH_SIGNATURE, b"tree");
+        let mut signing_key_repo = MockSigningKeyRepository::new();
+        signing_key_repo
+            .expect_list_by_key_id()
+            .returning(move |_| Ok(vec![squatter.clone(), owned.clone()]));
+        let mut signature_client = MockSignatureClient::new();
+        signature_client
+            .expect_verify_signature()
+            .returning(move |key, _, _| Ok(key.id == owned_id));
+
+        let status = verify(git_client, signing_key_repo, signature_client, Some(author)).await;
+
+        assert_eq!(status, Some(CommitSignatureStatus::Verified));
+    }
+    #[tokio::test]
+    async fn new_tags_record_nothing_but_moved_tags_walk_their_range() {
+        let mut repo_repo = MockRepositoryRepository::new();
+        repo_repo
+            .expect_get()
+            .times(1)
+            .returning(|_, _, _| Ok(None));
+        let service = CommitServiceImpl {
+            commit_repo: MockCommitRepository::new(),
+            repo_repo,
+            user_repo: MockUserRepository::new(),
+            signing_key_repo: MockSigningKeyRepository::new(),
+            git_client: MockGitClient::default(),
+            signature_client: MockSignatureClient::new(),
+        };
+        let request = |old_sha: &str| {
+            CreateCommitsRequest::new(
+                "alice",
+                "repo",
+                old_sha.to_string(),
+                SHA.to_string(),
+                "refs/tags/v1.0.0".to_string(),
+                None,
+                Default::default(),
+            )
+            .unwrap()
+        };
+
+        let created = service.create_commits(request(ZERO_SHA)).await.unwrap();
+        assert!(created.is_empty());
+        let err = service
+            .create_commits(request(&"2".repeat(40)))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, CommitError::NotFound(_)));
+    }
+
+    #[tokio::test]
+    async fn stores_the_status_of_signed_commits_only() {
+        let author = Uuid::new_v4();
+        let signed = Uuid::new_v4();
+        let git_client = MockGitClient::default().with_signature(SHA, SSH_SIGNATURE, b"tree");
+        let mut signing_key_repo = MockSigningKeyRepository::new();
+        signing_key_repo
+            .expect_list_by_key_id()
+            .returning(move |_| Ok(vec![signing_key(author)]));
+        let mut signature_client = MockSignatureClient::new();
+        signature_client
+            .expect_verify_signature()
+            .times(1)
+            .returning(|_, _, _| Ok(true));
+        let mut commit_repo = MockCommitRepository::new();
+        commit_repo
+            .expect_set_signature_status()
+            .with(eq(signed), eq(Some(CommitSignatureStatus::Verified)))
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let service = CommitServiceImpl {
+            commit_repo,
+            repo_repo: MockRepositoryRepository::new(),
+            user_repo: MockUserRepository::new(),
+            signing_key_repo,
+            git_client,
+            signature_client,
+        };
+        service
+            .store_signature_statuses(
+                "alice",
+                "repo",
+                vec![
+                    (signed, SHA.to_string(), Some(author)),
+                    (Uuid::new_v4(), "2".repeat(40), Some(author)),
+                ],
+            )
+            .await;
     }
 }
@@ -13,12 +13,14 @@ use crate::{
 pub trait GitHttpService: Send + Sync + 'static {
     /// Handles `GET /info/refs?service=...`, the ref-advertisement step of a
     /// clone/fetch/push handshake. Forwards `service` (e.g. `git-upload-pack` or
-    /// `git-receive-pack`) to `git http-backend`.
+    /// `git-receive-pack`) to `git http-backend`, and `git_protocol` as
+    /// `GIT_PROTOCOL` so protocol v2 clients get a v2 capability advertisement.
     async fn info_refs(&self, request: InfoRefsRequest) -> Result<GitHttpResponse, GitHttpError>;
 
     /// Handles the `git-upload-pack` RPC (clone/fetch): streams `request.body`
-    /// into `git http-backend` and returns the packfile response. No extra
-    /// environment is injected.
+    /// into `git http-backend` and returns the packfile response. `git_protocol`
+    /// is exported as `GIT_PROTOCOL`, which enables protocol v2 commands and,
+    /// with them, partial-clone filters and shallow fetches.
     async fn upload_pack(
         &self,
         request: UploadPackRequest,
@@ -56,7 +58,12 @@ where
 {
     async fn info_refs(&self, request: InfoRefsRequest) -> Result<GitHttpResponse, GitHttpError> {
         self.git_http_client
-            .info_refs(&request.owner, &request.repo, &request.service)
+            .info_refs(
+                &request.owner,
+                &request.repo,
+                &request.service,
+                git_protocol_env(request.git_protocol.as_deref()),
+            )
             .await
     }
 
@@ -71,7 +78,7 @@ where
                 "upload-pack",
                 &request.content_type,
                 request.body,
-                vec![],
+                git_protocol_env(request.git_protocol.as_deref()),
             )
             .await
     }
@@ -97,3 +104,9 @@ where
             .await
     }
 }
+
+fn git_protocol_env(git_protocol: Option<&str>) -> Vec<(String, String)> {
+    git_protocol
+        .map(|p| vec![("GIT_PROTOCOL".to_string(), p.to_string())])
+        .unwrap_or_default()
+}
@@ -0,0 +1,358 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::{
+    client::{Git2Client, GitClient},
+    dto::{
+        GetMaintenanceRequest, MaintenanceResponse, RecordPushRequest, RequestMaintenanceRequest,
+    },
+    error::{MaintenanceError, OptionNotFoundExt},
+    model::MaintenanceStatus,
+    repository::{
+        MaintenanceRepository, PgMaintenanceRepository, PgRepositoryRepository,
+        RepositoryRepository,
+    },
+};
+
+/// Pending pushes at which a repository is maintained right away.
+const PUSH_THRESHOLD: i32 = 50;
+
+/// How long a repository with fewer pending pushes must go without a push
+/// before it is maintained.
+const IDLE_AFTER: Duration = Duration::from_secs(60 * 60);
+
+/// How long a run may stay marked as started before it is presumed abandoned
+/// (e.g. the server restarted mid-run) and claimed again.
+const STALE_AFTER: Duration = Duration::from_secs(2 * 60 * 60);
+
+/// Keeps bare repositories packed and indexed as they accumulate pushes.
+///
+/// Every push and review ref update is counted against its repository. A
+/// background scheduler periodically claims the repositories that are due —
+/// busy ones first — and runs [`GitClient::maintain_repo`] on each, recording
+/// the outcome. Repository admins can also request a run by hand.
+#[async_trait]
+pub trait MaintenanceService: Send + Sync + 'static {
+    /// Counts a push against a repository, making it due for maintenance
+    /// sooner.
+    ///
+    /// # Errors
+    /// - [`MaintenanceError::NotFound`] if the repository does not exist.
+    async fn record_push(&self, request: RecordPushRequest) -> Result<(), MaintenanceError>;
+
+    /// Returns a repository's maintenance state. A repository that was never
+    /// pushed to reports no pending pushes and no runs.
+    ///
+    /// # Errors
+    /// - [`MaintenanceError::NotFound`] if the repository does not exist.
+    async fn get_maintenance(
+        &self,
+        request: GetMaintenanceRequest,
+    ) -> Result<MaintenanceResponse, MaintenanceError>;
+
+    /// Queues a run for the repository on the scheduler's next pass. A request
+    /// made while one is already queued keeps its place.
+    ///
+    /// # Errors
+    /// - [`MaintenanceError::NotFound`] if the repository does not exist.
+    async fn request_maintenance(
+        &self,
+        request: RequestMaintenanceRequest,
+    ) -> Result<MaintenanceResponse, MaintenanceError>;
+
+    /// Claims up to `limit` due repositories and maintains them one at a time,
+    /// returning their final state. A failed git step is recorded on the
+    /// repository as a `failure` rather than returned as an error.
+    ///
+    /// # Errors
+    /// - [`MaintenanceError::DatabaseError`] if claiming or recording a run
+    ///   failed.
+    async fn run_due_maintenance(
+        &self,
+        limit: i64,
+    ) -> Result<Vec<MaintenanceResponse>, MaintenanceError>;
+}
+
+#[derive(Debug, Clone)]
+pub struct MaintenanceServiceImpl<M, R, G>
+where
+    M: MaintenanceRepository,
+    R: RepositoryRepository,
+    G: GitClient,
+{
+    maintenance_repo: M,
+    repo_repo: R,
+    git_client: G,
+}
+
+impl MaintenanceServiceImpl<PgMaintenanceRepository, PgRepositoryRepository, Git2Client> {
+    pub fn new(
+        maintenance_repo: PgMaintenanceRepository,
+        repo_repo: PgRepositoryRepository,
+        git_client: Git2Client,
+    ) -> Self {
+        Self {
+            maintenance_repo,
+            repo_repo,
+            git_client,
+        }
+    }
+}
+
+impl<M, R, G> MaintenanceServiceImpl<M, R, G>
+where
+    M: MaintenanceRepository,
+    R: RepositoryRepository,
+    G: GitClient,
+{
+    async fn get_repository_id(&self, owner: &str, repo: &str) -> Result<Uuid, MaintenanceError> {
+        let id = self
+            .repo_repo
+            .get_id(owner, repo)
+            .await?
+            .or_not_found("repository", format!("{owner}/{repo}"))?;
+        Ok(id)
+    }
+}
+
+#[crate::instrument_all(level = "debug")]
+#[async_trait]
+impl<M, R, G> MaintenanceService for MaintenanceServiceImpl<M, R, G>
+where
+    M: MaintenanceRepository,
+    R: RepositoryRepository,
+    G: GitClient,
+{
+    async fn record_push(&self, request: RecordPushRequest) -> Result<(), MaintenanceError> {
+        let repository_id = self
+            .get_repository_id(&request.owner, &request.repo)
+            .await?;
+        self.maintenance_repo.record_push(repository_id).await?;
+        Ok(())
+    }
+
+    async fn get_maintenance(
+        &self,
+        request: GetMaintenanceRequest,
+    ) -> Result<MaintenanceResponse, MaintenanceError> {
+        let repository_id = self
+            .get_repository_id(&request.owner, &request.repo)
+            .await?;
+        let maintenance = match self.maintenance_repo.get(repository_id).await? {
+            Some(maintenance) => maintenance.into(),
+            None => MaintenanceResponse {
+                repository_id,
+                owner_name: request.owner.to_string(),
+                repository_name: request.repo.to_string(),
+                pending_pushes: 0,
+                last_pushed_at: None,
+                requested_at: None,
+                started_at: None,
+                finished_at: None,
+                last_status: None,
+                last_error: None,
+            },
+        };
+
+        Ok(maintenance)
+    }
+
+    async fn request_maintenance(
+        &self,
+        request: RequestMaintenanceRequest,
+    ) -> Result<MaintenanceResponse, MaintenanceError> {
+        let repository_id = self
+            .get_repository_id(&request.owner, &request.repo)
+            .await?;
+        let maintenance = self.maintenance_repo.request(repository_id).await?;
+        Ok(maintenance.into())
+    }
+
+    async fn run_due_maintenance(
+        &self,
+        limit: i64,
+    ) -> Result<Vec<MaintenanceResponse>, MaintenanceError> {
+        let claimed = self
+            .maintenance_repo
+            .claim_due(PUSH_THRESHOLD, IDLE_AFTER, STALE_AFTER, limit)
+            .await?;
+
+        let mut results = Vec::with_capacity(claimed.len());
+        for maintenance in claimed {
+            let (status, error) = match self
+                .git_client
+                .maintain_repo(&maintenance.owner_name, &maintenance.repository_name)
+                .await
+            {
+                Ok(()) => (MaintenanceStatus::Success, None),
+                Err(e) => {
+                    tracing::warn!(
+                        owner = %maintenance.owner_name,
+                        repo = %maintenance.repository_name,
+                        "repository maintenance failed: {e}",
+                    );
+                    (MaintenanceStatus::Failure, Some(e.to_string()))
+                }
+            };
+
+            let finished = self
+                .maintenance_repo
+                .finish(
+                    maintenance.repository_id,
+                    maintenance.pending_pushes,
+                    status,
+                    error,
+                )
+                .await?;
+            results.push(finished.into());
+        }
+
+        Ok(results)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use mockall::predicate::eq;
+
+    use super::*;
+    use crate::{
+        model::RepositoryMaintenance,
+        service::{
+            test_client::MockGitClient,
+            test_repository::{MockMaintenanceRepository, MockRepositoryRepository},
+        },
+    };
+
+    fn create_maintenance(repository_id: Uuid, pending_pushes: i32) -> RepositoryMaintenance {
+        RepositoryMaintenance {
+            repository_id,
+            owner_name: "alice".to_string(),
+            repository_name: "dotfiles".to_string(),
+            pending_pushes,
+            last_pushed_at: Some(Utc::now()),
+            requested_at: None,
+            started_at: Some(Utc::now()),
+            finished_at: None,
+            last_status: None,
+            last_error: None,
+        }
+    }
+
+    fn repo_repo_with_id(repository_id: Uuid) -> MockRepositoryRepository {
+        let mut repo_repo = MockRepositoryRepository::new();
+        repo_repo
+            .expect_get_id()
+            .returning(move |_, _| Ok(Some(repository_id)));
+        repo_repo
+    }
+
+    #[tokio::test]
+    async fn record_push_unknown_repository_is_not_found() {
+        let mut repo_repo = MockRepositoryRepository::new();
+        repo_repo.expect_get_id().returning(|_, _| Ok(None));
+
+        let service = MaintenanceServiceImpl {
+            maintenance_repo: MockMaintenanceRepository::new(),
+            repo_repo,
+            git_client: MockGitClient::default(),
+        };
+        let request = RecordPushRequest::new("alice", "dotfiles").unwrap();
+        let err = service.record_push(request).await.unwrap_err();
+
+        assert!(matches!(err, MaintenanceError::NotFound(_)));
+    }
+
+    #[tokio::test]
+    async fn get_never_pushed_repository_is_idle() {
+        let repository_id = Uuid::new_v4();
+        let mut maintenance_repo = MockMaintenanceRepository::new();
+        maintenance_repo
+            .expect_get()
+            .with(eq(repository_id))
+            .returning(|_| Ok(None));
+
+        let service = MaintenanceServiceImpl {
+            maintenance_repo,
+            repo_repo: repo_repo_with_id(repository_id),
+            git_client: MockGitClient::default(),
+        };
+        let request = GetMaintenanceRequest::new("alice", "dotfiles").unwrap();
+        let maintenance = service.get_maintenance(request).await.unwrap();
+
+        assert_eq!(maintenance.repository_id, repository_id);
+        assert_eq!(maintenance.pending_pushes, 0);
+        assert!(maintenance.last_status.is_none());
+    }
+
+    #[tokio::test]
+    async fn run_maintains_claimed_repositories() {
+        let repository_id = Uuid::new_v4();
+        let mut maintenance_repo = MockMaintenanceRepository::new();
+        maintenance_repo
+            .expect_claim_due()
+            .withf(|threshold, _, _, limit| *threshold == PUSH_THRESHOLD && *limit == 4)
+            .returning(move |_, _, _, _| Ok(vec![create_maintenance(repository_id, 7)]));
+        maintenance_repo
+            .expect_finish()
+            .withf(move |id, pushes, status, error| {
+                *id == repository_id
+                    && *pushes == 7
+                    && *status == MaintenanceStatus::Success
+                    && error.is_none()
+            })
+            .times(1)
+            .returning(move |_, _, status, _| {
+                let mut maintenance = create_maintenance(repository_id, 0);
+                maintenance.last_status = Some(status);
+                Ok(maintenance)
+            });
+
+        let git_client = MockGitClient::default();
+        let service = MaintenanceServiceImpl {
+            maintenance_repo,
+            repo_repo: MockRepositoryRepository::new(),
+            git_client: git_client.clone(),
+        };
+        let results = service.run_due_maintenance(4).await.unwrap();
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].last_status, Some(MaintenanceStatus::Success));
+        assert_eq!(
+            git_client.maintained_repos(),
+            [("alice".to_string(), "dotfiles".to_string())]
+        );
+    }
+
+    #[tokio::test]
+    async fn run_records_git_failures() {
+        let repository_id = Uuid::new_v4();
+        let mut maintenance_repo = MockMaintenanceRepository::new();
+        maintenance_repo
+            .expect_claim_due()
+            .returning(move |_, _, _, _| Ok(vec![create_maintenance(repository_id, 1)]));
+        maintenance_repo
+            .expect_finish()
+            .withf(|_, _, status, error| *status == MaintenanceStatus::Failure && error.is_some())
+            .times(1)
+            .returning(move |_, _, status, error| {
+                let mut maintenance = create_maintenance(repository_id, 0);
+                maintenance.last_status = Some(status);
+                maintenance.last_error = error;
+                Ok(maintenance)
+            });
+
+        let service = MaintenanceServiceImpl {
+            maintenance_repo,
+            repo_repo: MockRepositoryRepository::new(),
+            git_client: MockGitClient::default().with_maintain_failing(),
+        };
+        let results = service.run_due_maintenance(4).await.unwrap();
+
+        assert_eq!(results[0].last_status, Some(MaintenanceStatus::Failure));
+        assert!(results[0].last_error.is_some());
+    }
+}
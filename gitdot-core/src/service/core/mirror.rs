@@ -0,0 +1,791 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::{
+    client::{Git2Client, GitClient},
+    dto::{
+        CreateMirrorRequest, DeleteMirrorRequest, GetMirrorRequest, ListMirrorsRequest,
+        MirrorCredentials, MirrorRefUpdate, MirrorResponse, MirrorSyncResponse,
+        RequestMirrorSyncRequest, RequestPushMirrorsRequest,
+    },
+    error::{ConflictError, InputError, MirrorError, NotFoundError, OptionNotFoundExt},
+    model::{MirrorDirection, MirrorRunStatus, RepositoryMirror},
+    repository::{
+        MigrationRepository, MirrorRepository, PgMigrationRepository, PgMirrorRepository,
+        PgRepositoryRepository, RepositoryRepository,
+    },
+    util::{
+        crypto::EncryptionKey,
+        mirror::{DEFAULT_MIRROR_USERNAME, get_mirror_password_aad},
+    },
+};
+
+/// How long a sync may stay marked as started before it is presumed abandoned
+/// (e.g. the server restarted mid-sync) and claimed again.
+const STALE_AFTER: Duration = Duration::from_secs(60 * 60);
+
+/// Runs returned with a single mirror.
+const RUN_LIMIT: i64 = 20;
+
+/// Keeps repositories in step with remotes on other git hosts.
+///
+/// A repository has at most one pull mirror, which fetches every branch and
+/// tag of an upstream URL on an interval and keeps the repository read-only
+/// so the two cannot diverge. It may have any number of push mirrors, which
+/// replicate its branches and tags to a remote after every push. Passwords are
+/// stored encrypted and only embedded in the URL for the duration of a sync.
+///
+/// A background scheduler claims the mirrors that are due and syncs them one
+/// at a time, recording each outcome in the mirror's run history. Repository
+/// admins can also request a sync by hand.
+///
+/// Authorization (repository admin) is checked by callers.
+#[async_trait]
+pub trait MirrorService: Send + Sync + 'static {
+    /// Lists a repository's mirrors, pull mirror first.
+    ///
+    /// # Errors
+    /// - [`MirrorError::NotFound`] if the repository does not exist.
+    async fn list_mirrors(
+        &self,
+        request: ListMirrorsRequest,
+    ) -> Result<Vec<MirrorResponse>, MirrorError>;
+
+    /// Returns a mirror with its recent runs.
+    ///
+    /// # Errors
+    /// - [`MirrorError::NotFound`] if the repository or mirror does not exist.
+    async fn get_mirror(&self, request: GetMirrorRequest) -> Result<MirrorResponse, MirrorError>;
+
+    /// Adds a mirror and queues its first sync. Adding a pull mirror makes
+    /// the repository read-only. Since a pull mirror's syncs delete the
+    /// branches and tags the upstream does not have, adding one to a
+    /// repository that already has refs needs `replace_refs`.
+    ///
+    /// # Errors
+    /// - [`MirrorError::Input`] if a pull mirror is added to a repository
+    ///   with branches or tags without `replace_refs`.
+    /// - [`MirrorError::NotFound`] if the repository does not exist.
+    /// - [`MirrorError::Conflict`] if the repository already has a pull
+    ///   mirror, is still mirrored from its GitHub migration and a pull
+    ///   mirror is added, or has a push mirror to the same URL.
+    /// - [`MirrorError::GitError`] if the repository's refs could not be read.
+    async fn create_mirror(
+        &self,
+        request: CreateMirrorRequest,
+    ) -> Result<MirrorResponse, MirrorError>;
+
+    /// Removes a mirror with its run history. Removing the pull mirror makes
+    /// the repository writable again, unless it was read-only before the
+    /// mirror was added.
+    ///
+    /// # Errors
+    /// - [`MirrorError::NotFound`] if the repository or mirror does not exist.
+    async fn delete_mirror(&self, request: DeleteMirrorRequest) -> Result<(), MirrorError>;
+
+    /// Queues a sync of the mirror on the scheduler's next pass. A request
+    /// made while one is already queued keeps its place.
+    ///
+    /// # Errors
+    /// - [`MirrorError::NotFound`] if the repository or mirror does not exist.
+    async fn request_mirror_sync(
+        &self,
+        request: RequestMirrorSyncRequest,
+    ) -> Result<MirrorResponse, MirrorError>;
+
+    /// Queues a sync of every push mirror of the repository, after a push.
+    ///
+    /// # Errors
+    /// - [`MirrorError::NotFound`] if the repository does not exist.
+    async fn request_push_mirrors(
+        &self,
+        request: RequestPushMirrorsRequest,
+    ) -> Result<(), MirrorError>;
+
+    /// Claims up to `limit` due mirrors and syncs them one at a time,
+    /// returning their final state and, for pull mirrors, the refs the fetch
+    /// moved. A failed sync is recorded on the mirror as a `failure` rather
+    /// than returned as an error.
+    ///
+    /// # Errors
+    /// - [`MirrorError::DatabaseError`] if claiming or recording a sync failed.
+    async fn run_due_mirrors(&self, limit: i64) -> Result<Vec<MirrorSyncResponse>, MirrorError>;
+}
+
+#[derive(Debug, Clone)]
+pub struct MirrorServiceImpl<M, R, MR, G>
+where
+    M: MirrorRepository,
+    R: RepositoryRepository,
+    MR: MigrationRepository,
+    G: GitClient,
+{
+    mirror_repo: M,
+    repo_repo: R,
+    migration_repo: MR,
+    git_client: G,
+    encryption_key: EncryptionKey,
+}
+
+impl
+    MirrorServiceImpl<PgMirrorRepository, PgRepositoryRepository, PgMigrationRepository, Git2Client>
+{
+    pub fn new(
+        mirror_repo: PgMirrorRepository,
+        repo_repo: PgRepositoryRepository,
+        migration_repo: PgMigrationRepository,
+        git_client: Git2Client,
+        encryption_key: EncryptionKey,
+    ) -> Self {
+        Self {
+            mirror_repo,
+            repo_repo,
+            migration_repo,
+            git_client,
+            encryption_key,
+        }
+    }
+}
+
+impl<M, R, MR, G> MirrorServiceImpl<M, R, MR, G>
+where
+    M: MirrorRepository,
+    R: RepositoryRepository,
+    MR: MigrationRepository,
+    G: GitClient,
+{
+    async fn get_repository_id(&self, owner: &str, repo: &str) -> Result<Uuid, MirrorError> {
+        let id = self
+            .repo_repo
+            .get_id(owner, repo)
+            .await?
+            .or_not_found("repository", format!("{owner}/{repo}"))?;
+        Ok(id)
+    }
+
+    /// Fetches from or pushes to the mirror's remote, returning the refs a
+    /// pull moved. Errors are returned as messages with the password redacted.
+    async fn sync(&self, mirror: &RepositoryMirror) -> Result<Vec<MirrorRefUpdate>, String> {
+        let credentials = match &mirror.password_ciphertext {
+            Some(sealed) => {
+                let aad =
+                    get_mirror_password_aad(mirror.repository_id, mirror.direction, &mirror.url);
+                let password = self
+                    .encryption_key
+                    .decrypt_with_aad(sealed, &aad)
+                    .and_then(|bytes| String::from_utf8(bytes).ok())
+                    .ok_or("stored password could not be decrypted")?;
+                Some(MirrorCredentials {
+                    username: mirror
+                        .username
+                        .clone()
+                        .unwrap_or_else(|| DEFAULT_MIRROR_USERNAME.to_string()),
+                    password,
+                })
+            }
+            None => None,
+        };
+
+        let (owner, repo, url) = (&mirror.owner_name, &mirror.repository_name, &mirror.url);
+        let result = match mirror.direction {
+            MirrorDirection::Pull => {
+                self.git_client
+                    .sync_mirror(owner, repo, url, credentials.as_ref())
+                    .await
+            }
+            MirrorDirection::Push => self
+                .git_client
+                .push_mirror(owner, repo, url, credentials.as_ref())
+                .await
+                .map(|()| Vec::new()),
+        };
+        result.map_err(|e| {
+            let message = e.to_string();
+            match credentials.filter(|c| !c.password.is_empty()) {
+                Some(credentials) => message.replace(&credentials.password, "***"),
+                None => message,
+            }
+        })
+    }
+}
+
+#[crate::instrument_all(level = "debug")]
+#[async_trait]
+impl<M, R, MR, G> MirrorService for MirrorServiceImpl<M, R, MR, G>
+where
+    M: MirrorRepository,
+    R: RepositoryRepository,
+    MR: MigrationRepository,
+    G: GitClient,
+{
+    async fn list_mirrors(
+        &self,
+        request: ListMirrorsRequest,
+    ) -> Result<Vec<MirrorResponse>, MirrorError> {
+        let repository_id = self
+            .get_repository_id(&request.owner, &request.repo)
+            .await?;
+        let mirrors = self.mirror_repo.list(repository_id).await?;
+        Ok(mirrors.into_iter().map(Into::into).collect())
+    }
+
+    async fn get_mirror(&self, request: GetMirrorRequest) -> Result<MirrorResponse, MirrorError> {
+        let repository_id = self
+            .get_repository_id(&request.owner, &request.repo)
+            .await?;
+        let mirror = self
+            .mirror_repo
+            .get(repository_id, request.id)
+            .await?
+            .or_not_found("mirror", request.id)?;
+        let runs = self.mirror_repo.list_runs(mirror.id, RUN_LIMIT).await?;
+
+        let mut response: MirrorResponse = mirror.into();
+        response.runs = runs.into_iter().map(Into::into).collect();
+        Ok(response)
+    }
+
+    async fn create_mirror(
+        &self,
+        request: CreateMirrorRequest,
+    ) -> Result<MirrorResponse, MirrorError> {
+        let repository_id = self
+            .get_repository_id(&request.owner, &request.repo)
+            .await?;
+        let existing = self.mirror_repo.list(repository_id).await?;
+        if let Some(pull) = existing
+            .iter()
+            .find(|m| m.direction == MirrorDirection::Pull)
+            .filter(|_| request.direction == MirrorDirection::Pull)
+        {
+            return Err(ConflictError::new("pull mirror", &pull.url).into());
+        }
+        if existing
+            .iter()
+            .any(|m| m.direction == request.direction && m.url == *request.url)
+        {
+            return Err(ConflictError::new("mirror", &*request.url).into());
+        }
+        if request.direction == MirrorDirection::Pull {
+            if let Some(migrated) = self.migration_repo.get_mirror(repository_id).await? {
+                return Err(
+                    ConflictError::new("migration mirror", migrated.origin_full_name).into(),
+                );
+            }
+            let (owner, repo) = (&request.owner, &request.repo);
+            let has_refs = !self.git_client.list_branches(owner, repo).await?.is_empty()
+                || !self.git_client.list_tags(owner, repo).await?.is_empty();
+            if has_refs && !request.replace_refs {
+                return Err(InputError::new(
+                    "replace_refs",
+                    "the repository has branches or tags, which the pull mirror's syncs replace",
+                )
+                .into());
+            }
+        }
+
+        let password_ciphertext = request.password.as_deref().map(|password| {
+            let aad = get_mirror_password_aad(repository_id, request.direction, &request.url);
+            self.encryption_key
+                .encrypt_with_aad(password.as_bytes(), &aad)
+        });
+        let mirror = self
+            .mirror_repo
+            .create(
+                repository_id,
+                request.direction,
+                &request.url,
+                request.username,
+                password_ciphertext,
+                request.interval_seconds,
+            )
+            .await?;
+        if mirror.direction == MirrorDirection::Pull {
+            self.repo_repo
+                .update(repository_id, None, Some(true))
+                .await?;
+        }
+
+        Ok(mirror.into())
+    }
+
+    async fn delete_mirror(&self, request: DeleteMirrorRequest) -> Result<(), MirrorError> {
+        let repository_id = self
+            .get_repository_id(&request.owner, &request.repo)
+            .await?;
+        let mirror = self
+            .mirror_repo
+            .get(repository_id, request.id)
+            .await?
+            .or_not_found("mirror", request.id)?;
+        if !self.mirror_repo.delete(repository_id, mirror.id).await? {
+            return Err(NotFoundError::new("mirror", request.id).into());
+        }
+        if mirror.direction == MirrorDirection::Pull {
+            self.repo_repo
+                .update(repository_id, None, Some(mirror.was_readonly))
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn request_mirror_sync(
+        &self,
+        request: RequestMirrorSyncRequest,
+    ) -> Result<MirrorResponse, MirrorError> {
+        let repository_id = self
+            .get_repository_id(&request.owner, &request.repo)
+            .await?;
+        let mirror = self
+            .mirror_repo
+            .request(repository_id, request.id)
+            .await?
+            .or_not_found("mirror", request.id)?;
+        Ok(mirror.into())
+    }
+
+    async fn request_push_mirrors(
+        &self,
+        request: RequestPushMirrorsRequest,
+    ) -> Result<(), MirrorError> {
+        let repository_id = self
+            .get_repository_id(&request.owner, &request.repo)
+            .await?;
+        self.mirror_repo.request_push(repository_id).await?;
+        Ok(())
+    }
+
+    async fn run_due_mirrors(&self, limit: i64) -> Result<Vec<MirrorSyncResponse>, MirrorError> {
+        let claimed = self.mirror_repo.claim_due(STALE_AFTER, limit).await?;
+
+        let mut results = Vec::with_capacity(claimed.len());
+        for mirror in claimed {
+            let (status, error, ref_updates) = match self.sync(&mirror).await {
+                Ok(ref_updates) => (MirrorRunStatus::Success, None, ref_updates),
+                Err(e) => {
+                    tracing::warn!(
+                        owner = %mirror.owner_name,
+                        repo = %mirror.repository_name,
+                        direction = mirror.direction.as_str(),
+                        "mirror sync failed: {e}",
+                    );
+                    (MirrorRunStatus::Failure, Some(e), Vec::new())
+                }
+            };
+
+            let finished = self.mirror_repo.finish(mirror.id, status, error).await?;
+            results.push(MirrorSyncResponse {
+                mirror: finished.into(),
+                ref_updates,
+            });
+        }
+
+        Ok(results)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use mockall::predicate::eq;
+
+    use super::*;
+    use crate::{
+        model::MigrationMirror,
+        service::{
+            test_client::MockGitClient,
+            test_repository::{
+                MockMigrationRepository, MockMirrorRepository, MockRepositoryRepository,
+            },
+        },
+    };
+
+    fn encryption_key() -> EncryptionKey {
+        EncryptionKey::new([7; 32])
+    }
+
+    fn seal_password(mirror: &RepositoryMirror, password: &[u8]) -> Vec<u8> {
+        let aad = get_mirror_password_aad(mirror.repository_id, mirror.direction, &mirror.url);
+        encryption_key().encrypt_with_aad(password, &aad)
+    }
+
+    fn create_mirror(repository_id: Uuid, direction: MirrorDirection) -> RepositoryMirror {
+        RepositoryMirror {
+            id: Uuid::new_v4(),
+            repository_id,
+            owner_name: "alice".to_string(),
+            repository_name: "dotfiles".to_string(),
+            direction,
+            url: "https://codeberg.org/alice/dotfiles.git".to_string(),
+            username: None,
+            password_ciphertext: None,
+            interval_seconds: None,
+            requested_at: None,
+            started_at: Some(Utc::now()),
+            finished_at: None,
+            last_synced_at: None,
+            last_status: None,
+            last_error: None,
+            was_readonly: false,
+            created_at: Utc::now(),
+        }
+    }
+
+    fn pull_request(replace_refs: bool) -> CreateMirrorRequest {
+        CreateMirrorRequest::new(
+            "alice",
+            "dotfiles",
+            "pull",
+            "https://gitlab.com/alice/dotfiles.git",
+            None,
+            None,
+            None,
+            replace_refs,
+        )
+        .unwrap()
+    }
+
+    fn repo_repo_with_id(repository_id: Uuid) -> MockRepositoryRepository {
+        let mut repo_repo = MockRepositoryRepository::new();
+        repo_repo
+            .expect_get_id()
+            .returning(move |_, _| Ok(Some(repository_id)));
+        repo_repo
+    }
+
+    fn finish_returning(mirror_repo: &mut MockMirrorRepository, mirror: RepositoryMirror) {
+        mirror_repo
+            .expect_finish()
+            .times(1)
+            .returning(move |_, status, error| {
+                let mut mirror = mirror.clone();
+                mirror.last_status = Some(status);
+                mirror.last_error = error;
+                Ok(mirror)
+            });
+    }
+
+    #[tokio::test]
+    async fn create_pull_mirror_seals_password_and_makes_repository_readonly() {
+        let repository_id = Uuid::new_v4();
+        let mut mirror_repo = MockMirrorRepository::new();
+        mirror_repo.expect_list().returning(|_| Ok(Vec::new()));
+        mirror_repo
+            .expect_create()
+            .withf(move |id, direction, url, username, sealed, interval| {
+                *id == repository_id
+                    && *direction == MirrorDirection::Pull
+                    && url == "https://codeberg.org/alice/dotfiles.git"
+                    && username.as_deref() == Some("bot")
+                    && sealed.as_deref().and_then(|s| {
+                        let aad = get_mirror_password_aad(repository_id, *direction, url);
+                        encryption_key().decrypt_with_aad(s, &aad)
+                    }) == Some(b"s3cret".to_vec())
+                    && *interval == Some(3600)
+            })
+            .returning(move |_, _, _, username, sealed, interval| {
+                let mut mirror = create_mirror(repository_id, MirrorDirection::Pull);
+                mirror.username = username;
+                mirror.password_ciphertext = sealed;
+                mirror.interval_seconds = interval;
+                Ok(mirror)
+            });
+        let mut repo_repo = repo_repo_with_id(repository_id);
+        repo_repo
+            .expect_update()
+            .with(eq(repository_id), eq(None), eq(Some(true)))
+            .times(1)
+            .returning(|_, _, _| Ok(None));
+
+        let service = MirrorServiceImpl {
+            mirror_repo,
+            repo_repo,
+            migration_repo: MockMigrationRepository::default(),
+            git_client: MockGitClient::default(),
+            encryption_key: encryption_key(),
+        };
+        let request = CreateMirrorRequest::new(
+            "alice",
+            "dotfiles",
+            "pull",
+            "https://codeberg.org/alice/dotfiles.git",
+            Some("bot".to_string()),
+            Some("s3cret".to_string()),
+            None,
+            false,
+        )
+        .unwrap();
+        let mirror = service.create_mirror(request).await.unwrap();
+
+        assert!(mirror.has_password);
+        assert_eq!(mirror.interval_seconds, Some(3600));
+    }
+
+    #[tokio::test]
+    async fn create_second_pull_mirror_conflicts() {
+        let repository_id = Uuid::new_v4();
+        let mut mirror_repo = MockMirrorRepository::new();
+        mirror_repo
+            .expect_list()
+            .returning(move |_| Ok(vec![create_mirror(repository_id, MirrorDirection::Pull)]));
+        mirror_repo.expect_create().never();
+
+        let service = MirrorServiceImpl {
+            mirror_repo,
+            repo_repo: repo_repo_with_id(repository_id),
+            migration_repo: MockMigrationRepository::default(),
+            git_client: MockGitClient::default(),
+            encryption_key: encryption_key(),
+        };
+        let err = service
+            .create_mirror(pull_request(false))
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, MirrorError::Conflict(_)));
+    }
+
+    #[tokio::test]
+    async fn create_pull_mirror_on_migrated_repository_conflicts() {
+        let repository_id = Uuid::new_v4();
+        let mut mirror_repo = MockMirrorRepository::new();
+        mirror_repo.expect_list().returning(|_| Ok(Vec::new()));
+        mirror_repo.expect_create().never();
+        let migration_repo = MockMigrationRepository::default().with_mirror(MigrationMirror {
+            migration_repository_id: Uuid::new_v4(),
+            origin_full_name: "alice/dotfiles".to_string(),
+            installation_id: Some(1),
+            repository_id,
+            owner_name: "alice".to_string(),
+            repository_name: "dotfiles".to_string(),
+            sync_status: None,
+            sync_error: None,
+            last_attempted_at: None,
+            last_synced_at: None,
+        });
+
+        let service = MirrorServiceImpl {
+            mirror_repo,
+            repo_repo: repo_repo_with_id(repository_id),
+            migration_repo,
+            git_client: MockGitClient::default(),
+            encryption_key: encryption_key(),
+        };
+        let err = service.create_mirror(pull_request(true)).await.unwrap_err();
+
+        assert!(matches!(err, MirrorError::Conflict(_)));
+    }
+
+    #[tokio::test]
+    async fn create_pull_mirror_over_existing_refs_needs_confirmation() {
+        let repository_id = Uuid::new_v4();
+        let mut mirror_repo = MockMirrorRepository::new();
+        mirror_repo.expect_list().returning(|_| Ok(Vec::new()));
+        mirror_repo
+            .expect_create()
+            .times(1)
+            .returning(move |_, _, _, _, _, _| {
+                Ok(create_mirror(repository_id, MirrorDirection::Pull))
+            });
+        let mut repo_repo = repo_repo_with_id(repository_id);
+        repo_repo.expect_update().returning(|_, _, _| Ok(None));
+
+        let service = MirrorServiceImpl {
+            mirror_repo,
+            repo_repo,
+            migration_repo: MockMigrationRepository::default(),
+            git_client: MockGitClient::default().with_branch(
+                "refs/heads/main",
+                "1111111111111111111111111111111111111111",
+            ),
+            encryption_key: encryption_key(),
+        };
+        let err = service
+            .create_mirror(pull_request(false))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, MirrorError::Input(ref e) if e.field == "replace_refs"));
+
+        service.create_mirror(pull_request(true)).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn delete_pull_mirror_restores_previous_readonly_state() {
+        let repository_id = Uuid::new_v4();
+        let mut mirror = create_mirror(repository_id, MirrorDirection::Pull);
+        mirror.was_readonly = true;
+        let mut mirror_repo = MockMirrorRepository::new();
+        mirror_repo
+            .expect_get()
+            .returning(move |_, _| Ok(Some(mirror.clone())));
+        mirror_repo.expect_delete().returning(|_, _| Ok(true));
+        let mut repo_repo = repo_repo_with_id(repository_id);
+        repo_repo
+            .expect_update()
+            .with(eq(repository_id), eq(None), eq(Some(true)))
+            .times(1)
+            .returning(|_, _, _| Ok(None));
+
+        let service = MirrorServiceImpl {
+            mirror_repo,
+            repo_repo,
+            migration_repo: MockMigrationRepository::default(),
+            git_client: MockGitClient::default(),
+            encryption_key: encryption_key(),
+        };
+        let request = DeleteMirrorRequest::new("alice", "dotfiles", Uuid::new_v4()).unwrap();
+        service.delete_mirror(request).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn delete_unknown_mirror_is_not_found() {
+        let repository_id = Uuid::new_v4();
+        let mut mirror_repo = MockMirrorRepository::new();
+        mirror_repo.expect_get().returning(|_, _| Ok(None));
+        mirror_repo.expect_delete().never();
+
+        let service = MirrorServiceImpl {
+            mirror_repo,
+            repo_repo: repo_repo_with_id(repository_id),
+            migration_repo: MockMigrationRepository::default(),
+            git_client: MockGitClient::default(),
+            encryption_key: encryption_key(),
+        };
+        let request = DeleteMirrorRequest::new("alice", "dotfiles", Uuid::new_v4()).unwrap();
+        let err = service.delete_mirror(request).await.unwrap_err();
+
+        assert!(matches!(err, MirrorError::NotFound(_)));
+    }
+
+    #[tokio::test]
+    async fn run_syncs_claimed_mirrors_with_credentials() {
+        let repository_id = Uuid::new_v4();
+        let mut pull = create_mirror(repository_id, MirrorDirection::Pull);
+        pull.username = Some("bot".to_string());
+        pull.password_ciphertext = Some(seal_password(&pull, b"s3cret"));
+        let push = create_mirror(repository_id, MirrorDirection::Push);
+
+        let mut mirror_repo = MockMirrorRepository::new();
+        let claimed = vec![pull.clone(), push.clone()];
+        mirror_repo
+            .expect_claim_due()
+            .withf(|stale_after, limit| *stale_after == STALE_AFTER && *limit == 4)
+            .returning(move |_, _| Ok(claimed.clone()));
+        mirror_repo
+            .expect_finish()
+            .withf(|_, status, error| *status == MirrorRunStatus::Success && error.is_none())
+            .times(2)
+            .returning(move |id, status, _| {
+                let mut mirror = create_mirror(repository_id, MirrorDirection::Pull);
+                mirror.id = id;
+                mirror.last_status = Some(status);
+                Ok(mirror)
+            });
+
+        let git_client = MockGitClient::default().with_mirror_update(
+            "refs/heads/main",
+            "1111111111111111111111111111111111111111",
+            "2222222222222222222222222222222222222222",
+        );
+        let service = MirrorServiceImpl {
+            mirror_repo,
+            repo_repo: MockRepositoryRepository::new(),
+            migration_repo: MockMigrationRepository::default(),
+            git_client: git_client.clone(),
+            encryption_key: encryption_key(),
+        };
+        let results = service.run_due_mirrors(4).await.unwrap();
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].ref_updates.len(), 1);
+        assert!(results[1].ref_updates.is_empty());
+        assert_eq!(
+            git_client.synced_mirrors(),
+            [(
+                "alice/dotfiles".to_string(),
+                pull.url,
+                Some(MirrorCredentials {
+                    username: "bot".to_string(),
+                    password: "s3cret".to_string(),
+                })
+            )]
+        );
+        assert_eq!(
+            git_client.pushed_mirrors(),
+            [("alice/dotfiles".to_string(), push.url, None)]
+        );
+    }
+
+    #[tokio::test]
+    async fn run_records_failure_without_leaking_password() {
+        let repository_id = Uuid::new_v4();
+        let mut pull = create_mirror(repository_id, MirrorDirection::Pull);
+        pull.password_ciphertext = Some(seal_password(&pull, b"s3cret"));
+
+        let mut mirror_repo = MockMirrorRepository::new();
+        let claimed = vec![pull.clone()];
+        mirror_repo
+            .expect_claim_due()
+            .returning(move |_, _| Ok(claimed.clone()));
+        finish_returning(&mut mirror_repo, pull);
+
+        let service = MirrorServiceImpl {
+            mirror_repo,
+            repo_repo: MockRepositoryRepository::new(),
+            migration_repo: MockMigrationRepository::default(),
+            git_client: MockGitClient::default().with_mirror_failing(),
+            encryption_key: encryption_key(),
+        };
+        let results = service.run_due_mirrors(4).await.unwrap();
+
+        assert_eq!(
+            results[0].mirror.last_status,
+            Some(MirrorRunStatus::Failure)
+        );
+        let error = results[0].mirror.last_error.as_deref().unwrap();
+        assert!(!error.contains("s3cret"));
+        assert!(results[0].ref_updates.is_empty());
+    }
+
+    #[tokio::test]
+    async fn run_records_failure_for_password_under_another_key_or_mirror() {
+        let repository_id = Uuid::new_v4();
+        let push = create_mirror(repository_id, MirrorDirection::Push);
+        let aad = get_mirror_password_aad(repository_id, push.direction, &push.url);
+        let mut elsewhere = push.clone();
+        elsewhere.url = "https://attacker.example/dotfiles.git".to_string();
+
+        for sealed in [
+            EncryptionKey::new([8; 32]).encrypt_with_aad(b"s3cret", &aad),
+            seal_password(&elsewhere, b"s3cret"),
+        ] {
+            let mut push = push.clone();
+            push.password_ciphertext = Some(sealed);
+
+            let mut mirror_repo = MockMirrorRepository::new();
+            let claimed = vec![push.clone()];
+            mirror_repo
+                .expect_claim_due()
+                .returning(move |_, _| Ok(claimed.clone()));
+            finish_returning(&mut mirror_repo, push);
+
+            let git_client = MockGitClient::default();
+            let service = MirrorServiceImpl {
+                mirror_repo,
+                repo_repo: MockRepositoryRepository::new(),
+                migration_repo: MockMigrationRepository::default(),
+                git_client: git_client.clone(),
+                encryption_key: encryption_key(),
+            };
+            let results = service.run_due_mirrors(4).await.unwrap();
+
+            assert_eq!(
+                results[0].mirror.last_status,
+                Some(MirrorRunStatus::Failure)
+            );
+            assert!(git_client.pushed_mirrors().is_empty());
+        }
+    }
+}
@@ -0,0 +1,636 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::{
+    client::{Git2Client, GitClient, R2Client, R2ClientImpl},
+    dto::{
+        CreateReleaseRequest, DeleteReleaseAssetRequest, DeleteReleaseRequest,
+        DownloadReleaseAssetRequest, GetReleaseRequest, ListReleasesRequest, ReleaseAssetDownload,
+        ReleaseAssetResponse, ReleaseResponse, UpdateReleaseRequest, UploadReleaseAssetRequest,
+    },
+    error::{ConflictError, OptionNotFoundExt, ReleaseError},
+    model::{Release, ReleaseAsset},
+    repository::{
+        PgReleaseRepository, PgRepositoryRepository, ReleaseRepository, RepositoryRepository,
+    },
+};
+
+/// Releases: named, markdown-annotated snapshots of a repository at one of its
+/// tags, with files attached in object storage.
+///
+/// A release is keyed by its tag name. The tag must exist when the release is
+/// created, but deleting the tag later leaves the release in place. Drafts are
+/// only visible to callers that can write to the repository, which the
+/// requests carry as `include_drafts`.
+#[async_trait]
+pub trait ReleaseService: Send + Sync + 'static {
+    /// Creates a release for an existing tag.
+    ///
+    /// # Errors
+    /// - [`ReleaseError::NotFound`] if the repository or tag does not exist.
+    /// - [`ReleaseError::Conflict`] if the tag already has a release.
+    /// - [`ReleaseError::GitError`] if reading the tag fails.
+    async fn create_release(
+        &self,
+        request: CreateReleaseRequest,
+    ) -> Result<ReleaseResponse, ReleaseError>;
+
+    /// Lists the repository's releases with their assets, newest first.
+    ///
+    /// # Errors
+    /// - [`ReleaseError::NotFound`] if the repository does not exist.
+    async fn list_releases(
+        &self,
+        request: ListReleasesRequest,
+    ) -> Result<Vec<ReleaseResponse>, ReleaseError>;
+
+    /// Returns the release of a tag with its assets.
+    ///
+    /// # Errors
+    /// - [`ReleaseError::NotFound`] if the repository or release does not
+    ///   exist, or the release is a draft the caller may not see.
+    async fn get_release(
+        &self,
+        request: GetReleaseRequest,
+    ) -> Result<ReleaseResponse, ReleaseError>;
+
+    /// Updates a release's name, notes or flags. Publishing a draft stamps
+    /// `published_at`.
+    ///
+    /// # Errors
+    /// - [`ReleaseError::NotFound`] if the repository or release does not
+    ///   exist.
+    async fn update_release(
+        &self,
+        request: UpdateReleaseRequest,
+    ) -> Result<ReleaseResponse, ReleaseError>;
+
+    /// Deletes a release and its stored assets. The tag is kept.
+    ///
+    /// # Errors
+    /// - [`ReleaseError::NotFound`] if the repository or release does not
+    ///   exist.
+    async fn delete_release(&self, request: DeleteReleaseRequest) -> Result<(), ReleaseError>;
+
+    /// Stores a file and attaches it to a release.
+    ///
+    /// # Errors
+    /// - [`ReleaseError::NotFound`] if the repository or release does not
+    ///   exist.
+    /// - [`ReleaseError::Conflict`] if the release has an asset of that name.
+    /// - [`ReleaseError::R2Error`] if the file could not be stored.
+    async fn upload_release_asset(
+        &self,
+        request: UploadReleaseAssetRequest,
+    ) -> Result<ReleaseAssetResponse, ReleaseError>;
+
+    /// Downloads an asset of a release.
+    ///
+    /// # Errors
+    /// - [`ReleaseError::NotFound`] if the repository, release or asset does
+    ///   not exist, or the release is a draft the caller may not see.
+    /// - [`ReleaseError::R2Error`] if the file could not be read.
+    async fn download_release_asset(
+        &self,
+        request: DownloadReleaseAssetRequest,
+    ) -> Result<ReleaseAssetDownload, ReleaseError>;
+
+    /// Removes an asset from a release and deletes its file.
+    ///
+    /// # Errors
+    /// - [`ReleaseError::NotFound`] if the repository, release or asset does
+    ///   not exist.
+    async fn delete_release_asset(
+        &self,
+        request: DeleteReleaseAssetRequest,
+    ) -> Result<(), ReleaseError>;
+}
+
+#[derive(Debug, Clone)]
+pub struct ReleaseServiceImpl<L, R, G, C>
+where
+    L: ReleaseRepository,
+    R: RepositoryRepository,
+    G: GitClient,
+    C: R2Client,
+{
+    release_repo: L,
+    repo_repo: R,
+    git_client: G,
+    r2_client: C,
+}
+
+impl ReleaseServiceImpl<PgReleaseRepository, PgRepositoryRepository, Git2Client, R2ClientImpl> {
+    pub fn new(
+        release_repo: PgReleaseRepository,
+        repo_repo: PgRepositoryRepository,
+        git_client: Git2Client,
+        r2_client: R2ClientImpl,
+    ) -> Self {
+        Self {
+            release_repo,
+            repo_repo,
+            git_client,
+            r2_client,
+        }
+    }
+}
+
+impl<L, R, G, C> ReleaseServiceImpl<L, R, G, C>
+where
+    L: ReleaseRepository,
+    R: RepositoryRepository,
+    G: GitClient,
+    C: R2Client,
+{
+    async fn get_repository_id(&self, owner: &str, repo: &str) -> Result<Uuid, ReleaseError> {
+        Ok(self
+            .repo_repo
+            .get_id(owner, repo)
+            .await?
+            .or_not_found("repository", format!("{owner}/{repo}"))?)
+    }
+
+    async fn get_release_by_tag(
+        &self,
+        owner: &str,
+        repo: &str,
+        tag: &str,
+        include_drafts: bool,
+    ) -> Result<Release, ReleaseError> {
+        let repository_id = self.get_repository_id(owner, repo).await?;
+        Ok(self
+            .release_repo
+            .get_by_tag(repository_id, tag)
+            .await?
+            .filter(|release| include_drafts || !release.draft)
+            .or_not_found("release", format!("{owner}/{repo}@{tag}"))?)
+    }
+
+    async fn get_asset(&self, release: &Release, name: &str) -> Result<ReleaseAsset, ReleaseError> {
+        Ok(self
+            .release_repo
+            .get_asset(release.id, name)
+            .await?
+            .or_not_found("release asset", format!("{}/{name}", release.tag_name))?)
+    }
+
+    async fn with_assets(&self, release: Release) -> Result<ReleaseResponse, ReleaseError> {
+        let assets = self.release_repo.list_assets(&[release.id]).await?;
+        Ok(ReleaseResponse::new(release, assets))
+    }
+
+    /// Removes a stored file whose row is already gone, so a failure only
+    /// leaves an orphaned object behind.
+    async fn delete_object(&self, object_key: &str) {
+        if let Err(e) = self.r2_client.delete_object(object_key).await {
+            tracing::warn!(error = %e, object_key, "failed to delete release asset");
+        }
+    }
+}
+
+#[crate::instrument_all(level = "debug")]
+#[async_trait]
+impl<L, R, G, C> ReleaseService for ReleaseServiceImpl<L, R, G, C>
+where
+    L: ReleaseRepository,
+    R: RepositoryRepository,
+    G: GitClient,
+    C: R2Client,
+{
+    async fn create_release(
+        &self,
+        request: CreateReleaseRequest,
+    ) -> Result<ReleaseResponse, ReleaseError> {
+        let owner = request.owner_name.as_ref();
+        let repo = request.name.as_ref();
+        let repository_id = self.get_repository_id(owner, repo).await?;
+
+        self.git_client
+            .get_tag(owner, repo, &request.tag)
+            .await?
+            .or_not_found("tag", format!("{owner}/{repo}@{}", request.tag.as_ref()))?;
+
+        let release = self
+            .release_repo
+            .create(
+                repository_id,
+                &request.tag,
+                &request.release_name,
+                &request.body,
+                request.draft,
+                request.prerelease,
+                request.user_id,
+            )
+            .await
+            .map_err(|e| {
+                if e.is_unique_violation() {
+                    ConflictError::new("release", request.tag.as_ref()).into()
+                } else {
+                    ReleaseError::from(e)
+                }
+            })?;
+
+        Ok(ReleaseResponse::new(release, Vec::new()))
+    }
+
+    async fn list_releases(
+        &self,
+        request: ListReleasesRequest,
+    ) -> Result<Vec<ReleaseResponse>, ReleaseError> {
+        let repository_id = self
+            .get_repository_id(&request.owner_name, &request.name)
+            .await?;
+        let releases = self
+            .release_repo
+            .list(repository_id, request.include_drafts)
+            .await?;
+        if releases.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let release_ids: Vec<Uuid> = releases.iter().map(|r| r.id).collect();
+        let mut assets: HashMap<Uuid, Vec<ReleaseAsset>> = HashMap::new();
+        for asset in self.release_repo.list_assets(&release_ids).await? {
+            assets.entry(asset.release_id).or_default().push(asset);
+        }
+
+        Ok(releases
+            .into_iter()
+            .map(|release| {
+                let assets = assets.remove(&release.id).unwrap_or_default();
+                ReleaseResponse::new(release, assets)
+            })
+            .collect())
+    }
+
+    async fn get_release(
+        &self,
+        request: GetReleaseRequest,
+    ) -> Result<ReleaseResponse, ReleaseError> {
+        let release = self
+            .get_release_by_tag(
+                &request.owner_name,
+                &request.name,
+                &request.tag,
+                request.include_drafts,
+            )
+            .await?;
+        self.with_assets(release).await
+    }
+
+    async fn update_release(
+        &self,
+        request: UpdateReleaseRequest,
+    ) -> Result<ReleaseResponse, ReleaseError> {
+        let release = self
+            .get_release_by_tag(&request.owner_name, &request.name, &request.tag, true)
+            .await?;
+        let release = self
+            .release_repo
+            .update(
+                release.id,
+                request.release_name,
+                request.body,
+                request.draft,
+                request.prerelease,
+            )
+            .await?
+            .or_not_found("release", release.id)?;
+        self.with_assets(release).await
+    }
+
+    async fn delete_release(&self, request: DeleteReleaseRequest) -> Result<(), ReleaseError> {
+        let release = self
+            .get_release_by_tag(&request.owner_name, &request.name, &request.tag, true)
+            .await?;
+        let assets = self.release_repo.list_assets(&[release.id]).await?;
+
+        if !self.release_repo.delete(release.id).await? {
+            return Err(crate::error::NotFoundError::new("release", release.id).into());
+        }
+        for asset in assets {
+            self.delete_object(&asset.object_key).await;
+        }
+
+        Ok(())
+    }
+
+    async fn upload_release_asset(
+        &self,
+        request: UploadReleaseAssetRequest,
+    ) -> Result<ReleaseAssetResponse, ReleaseError> {
+        let release = self
+            .get_release_by_tag(&request.owner_name, &request.name, &request.tag, true)
+            .await?;
+        if self
+            .release_repo
+            .get_asset(release.id, &request.asset_name)
+            .await?
+            .is_some()
+        {
+            return Err(ConflictError::new("release asset", &request.asset_name).into());
+        }
+
+        let object_key = format!(
+            "releases/{}/{}/{}",
+            release.repository_id,
+            release.id,
+            Uuid::new_v4()
+        );
+        let size_bytes = request.bytes.len() as i64;
+        self.r2_client
+            .upload_object(&object_key, request.bytes)
+            .await?;
+
+        let asset = match self
+            .release_repo
+            .create_asset(
+                release.id,
+                &request.asset_name,
+                &request.content_type,
+                &object_key,
+                size_bytes,
+                request.user_id,
+            )
+            .await
+        {
+            Ok(asset) => asset,
+            Err(e) => {
+                // Lost a race with a concurrent upload of the same name, or the
+                // row could not be written; either way the object is orphaned.
+                self.delete_object(&object_key).await;
+                if e.is_unique_violation() {
+                    return Err(ConflictError::new("release asset", &request.asset_name).into());
+                }
+                return Err(e.into());
+            }
+        };
+
+        Ok(asset.into())
+    }
+
+    async fn download_release_asset(
+        &self,
+        request: DownloadReleaseAssetRequest,
+    ) -> Result<ReleaseAssetDownload, ReleaseError> {
+        let release = self
+            .get_release_by_tag(
+                &request.owner_name,
+                &request.name,
+                &request.tag,
+                request.include_drafts,
+            )
+            .await?;
+        let asset = self.get_asset(&release, &request.asset_name).await?;
+
+        let bytes = self
+            .r2_client
+            .get_object(&asset.object_key)
+            .await?
+            .or_not_found("release asset", &asset.name)?;
+
+        Ok(ReleaseAssetDownload {
+            name: asset.name,
+            content_type: asset.content_type,
+            bytes,
+        })
+    }
+
+    async fn delete_release_asset(
+        &self,
+        request: DeleteReleaseAssetRequest,
+    ) -> Result<(), ReleaseError> {
+        let release = self
+            .get_release_by_tag(&request.owner_name, &request.name, &request.tag, true)
+            .await?;
+        let asset = self.get_asset(&release, &request.asset_name).await?;
+
+        if self.release_repo.delete_asset(asset.id).await? {
+            self.delete_object(&asset.object_key).await;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::Bytes;
+    use chrono::Utc;
+
+    use super::*;
+    use crate::service::{
+        test_client::{MockGitClient, MockR2Client},
+        test_repository::{MockReleaseRepository, MockRepositoryRepository},
+    };
+
+    const COMMIT_SHA: &str = "1111111111111111111111111111111111111111";
+
+    fn create_release(repository_id: Uuid, tag_name: &str, draft: bool) -> Release {
+        Release {
+            id: Uuid::new_v4(),
+            repository_id,
+            tag_name: tag_name.to_string(),
+            name: tag_name.to_string(),
+            body: String::new(),
+            draft,
+            prerelease: false,
+            author_id: None,
+            author_name: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            published_at: (!draft).then(Utc::now),
+        }
+    }
+
+    fn create_asset(release_id: Uuid, name: &str) -> ReleaseAsset {
+        ReleaseAsset {
+            id: Uuid::new_v4(),
+            release_id,
+            name: name.to_string(),
+            content_type: "application/gzip".to_string(),
+            object_key: format!("releases/{release_id}/{name}"),
+            size_bytes: 6,
+            uploader_id: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    fn repo_repo_with_id(repository_id: Uuid) -> MockRepositoryRepository {
+        let mut repo_repo = MockRepositoryRepository::new();
+        repo_repo
+            .expect_get_id()
+            .returning(move |_, _| Ok(Some(repository_id)));
+        repo_repo
+    }
+
+    #[tokio::test]
+    async fn create_release_requires_existing_tag() {
+        let service = ReleaseServiceImpl {
+            release_repo: MockReleaseRepository::new(),
+            repo_repo: repo_repo_with_id(Uuid::new_v4()),
+            git_client: MockGitClient::default(),
+            r2_client: MockR2Client::new(),
+        };
+        let request = CreateReleaseRequest::new(
+            "dotfiles",
+            "alice",
+            "v1.0.0",
+            None,
+            None,
+            None,
+            None,
+            Uuid::new_v4(),
+        )
+        .unwrap();
+        let err = service.create_release(request).await.unwrap_err();
+
+        assert!(matches!(err, ReleaseError::NotFound(_)));
+    }
+
+    #[tokio::test]
+    async fn create_release_defaults_name_to_tag() {
+        let repository_id = Uuid::new_v4();
+        let mut release_repo = MockReleaseRepository::new();
+        release_repo
+            .expect_create()
+            .withf(|_, tag, name, _, draft, _, _| tag == "v1.0.0" && name == "v1.0.0" && *draft)
+            .returning(move |repository_id, tag, _, _, draft, _, _| {
+                Ok(create_release(repository_id, tag, draft))
+            });
+
+        let service = ReleaseServiceImpl {
+            release_repo,
+            repo_repo: repo_repo_with_id(repository_id),
+            git_client: MockGitClient::default().with_tag("v1.0.0", COMMIT_SHA),
+            r2_client: MockR2Client::new(),
+        };
+        let request = CreateReleaseRequest::new(
+            "dotfiles",
+            "alice",
+            "refs/tags/v1.0.0",
+            None,
+            Some("## Notes".to_string()),
+            Some(true),
+            None,
+            Uuid::new_v4(),
+        )
+        .unwrap();
+        let release = service.create_release(request).await.unwrap();
+
+        assert_eq!(release.tag_name, "v1.0.0");
+        assert!(release.draft);
+        assert!(release.published_at.is_none());
+    }
+
+    #[tokio::test]
+    async fn drafts_are_hidden_without_write_access() {
+        let repository_id = Uuid::new_v4();
+        let mut release_repo = MockReleaseRepository::new();
+        release_repo
+            .expect_get_by_tag()
+            .returning(move |repository_id, tag| {
+                Ok(Some(create_release(repository_id, tag, true)))
+            });
+
+        let service = ReleaseServiceImpl {
+            release_repo,
+            repo_repo: repo_repo_with_id(repository_id),
+            git_client: MockGitClient::default(),
+            r2_client: MockR2Client::new(),
+        };
+        let request = GetReleaseRequest::new("dotfiles", "alice", "v1.0.0", false).unwrap();
+        let err = service.get_release(request).await.unwrap_err();
+
+        assert!(matches!(err, ReleaseError::NotFound(_)));
+    }
+
+    #[tokio::test]
+    async fn upload_asset_stores_object_under_release() {
+        let repository_id = Uuid::new_v4();
+        let release = create_release(repository_id, "v1.0.0", false);
+        let release_id = release.id;
+
+        let mut release_repo = MockReleaseRepository::new();
+        release_repo
+            .expect_get_by_tag()
+            .returning(move |_, _| Ok(Some(release.clone())));
+        release_repo.expect_get_asset().returning(|_, _| Ok(None));
+        release_repo
+            .expect_create_asset()
+            .withf(move |id, name, content_type, key, size, _| {
+                *id == release_id
+                    && name == "app.tar.gz"
+                    && content_type == "application/gzip"
+                    && key.starts_with(&format!("releases/{repository_id}/{release_id}/"))
+                    && *size == 6
+            })
+            .times(1)
+            .returning(|release_id, name, _, _, _, _| Ok(create_asset(release_id, name)));
+
+        let mut r2_client = MockR2Client::new();
+        r2_client
+            .expect_upload_object()
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let service = ReleaseServiceImpl {
+            release_repo,
+            repo_repo: repo_repo_with_id(repository_id),
+            git_client: MockGitClient::default(),
+            r2_client,
+        };
+        let request = UploadReleaseAssetRequest::new(
+            "dotfiles",
+            "alice",
+            "v1.0.0",
+            "app.tar.gz",
+            Some("application/gzip"),
+            Bytes::from_static(b"binary"),
+            Uuid::new_v4(),
+        )
+        .unwrap();
+        let asset = service.upload_release_asset(request).await.unwrap();
+
+        assert_eq!(asset.name, "app.tar.gz");
+    }
+
+    #[tokio::test]
+    async fn delete_release_removes_stored_assets() {
+        let repository_id = Uuid::new_v4();
+        let release = create_release(repository_id, "v1.0.0", false);
+        let asset = create_asset(release.id, "app.tar.gz");
+        let object_key = asset.object_key.clone();
+
+        let mut release_repo = MockReleaseRepository::new();
+        release_repo
+            .expect_get_by_tag()
+            .returning(move |_, _| Ok(Some(release.clone())));
+        release_repo
+            .expect_list_assets()
+            .returning(move |_| Ok(vec![asset.clone()]));
+        release_repo
+            .expect_delete()
+            .times(1)
+            .returning(|_| Ok(true));
+
+        let mut r2_client = MockR2Client::new();
+        r2_client
+            .expect_delete_object()
+            .withf(move |key| key == object_key)
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let service = ReleaseServiceImpl {
+            release_repo,
+            repo_repo: repo_repo_with_id(repository_id),
+            git_client: MockGitClient::default(),
+            r2_client,
+        };
+        let request = DeleteReleaseRequest::new("dotfiles", "alice", "v1.0.0").unwrap();
+        service.delete_release(request).await.unwrap();
+    }
+}
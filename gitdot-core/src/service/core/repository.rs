@@ -1,17 +1,21 @@
+use std::collections::{HashMap, HashSet};
+
 use async_trait::async_trait;
 use chrono::Utc;
 use uuid::Uuid;
 
 use crate::{
-    client::{Git2Client, GitClient},
+    client::{Git2Client, GitClient, SignatureClient, SignatureClientImpl},
     dto::{
-        CommitResponse, CreateRepositoryCommitFilterRequest, CreateRepositoryRequest,
-        DeleteRepositoryCommitFilterRequest, DeleteRepositoryRequest, GetRepositoryActivityRequest,
+        CommitResponse, CompareRepositoryRefsRequest, CreateRepositoryCommitFilterRequest,
+        CreateRepositoryRequest, DeleteRepositoryCommitFilterRequest, DeleteRepositoryRequest,
+        GetRepositoryActivityRequest, GetRepositoryArchiveRequest, GetRepositoryBlameRequest,
         GetRepositoryBlobRequest, GetRepositoryBlobsRequest, GetRepositoryCommitBlobsRequest,
         GetRepositoryCommitRequest, GetRepositoryPathsRequest, GetRepositoryRequest,
         InitialCommitFile, ListRepositoryCommitFiltersRequest, ListRepositoryCommitsRequest,
-        MAX_PER_PAGE_LIMIT, Page, RepositoryActivityEvent, RepositoryBlobPairResponse,
-        RepositoryBlobResponse, RepositoryBlobsResponse, RepositoryCommitFilterResponse,
+        MAX_PER_PAGE_LIMIT, Page, RepositoryActivityEvent, RepositoryArchiveResponse,
+        RepositoryBlameResponse, RepositoryBlobPairResponse, RepositoryBlobResponse,
+        RepositoryBlobsResponse, RepositoryCommitFilterResponse, RepositoryCompareResponse,
         RepositoryPathsResponse, RepositoryResponse, StarRepositoryRequest,
         UnstarRepositoryRequest, UpdateRepositoryCommitFilterRequest, UpdateRepositoryRequest,
     },
@@ -19,8 +23,10 @@ use crate::{
     model::{CommitDiff, RepositoryOwnerType},
     repository::{
         CommitRepository, OrganizationRepository, PgCommitRepository, PgOrganizationRepository,
-        PgRepositoryRepository, PgUserRepository, RepositoryRepository, UserRepository,
+        PgRepositoryRepository, PgSigningKeyRepository, PgUserRepository, RepositoryRepository,
+        SigningKeyRepository, UserRepository,
     },
+    service::core::commit::verify_commit_signature,
     util::{
         auth::is_offensive_name,
         cursor,
@@ -43,7 +49,8 @@ pub trait RepositoryService: Send + Sync + 'static {
     /// Creates the bare git repo under `GIT_PROJECT_ROOT` and installs the
     /// pre-receive, post-receive and proc-receive hooks, then inserts the
     /// `repositories` row (owner resolved to a user or organization id by
-    /// `owner_type`). If any requested seed files are present (README from
+    /// `owner_type`). The creator of an organization-owned repo is made its
+    /// admin collaborator, since org membership alone only grants read. If any requested seed files are present (README from
     /// `init_readme`, `.gitignore`, `LICENSE`), an initial commit is written on
     /// the default branch and recorded.
     ///
@@ -91,6 +98,41 @@ pub trait RepositoryService: Send + Sync + 'static {
         request: GetRepositoryPathsRequest,
     ) -> Result<RepositoryPathsResponse, RepositoryError>;
 
+    /// Returns who last changed each line of the file at `path` in
+    /// `ref_name`, as line ranges mapped to commits. Commit authors are
+    /// resolved to gitdot users by verified email, as for stored commits.
+    ///
+    /// # Errors
+    /// - [`RepositoryError::NotFound`] if the repository, ref or path does not
+    ///   exist.
+    /// - [`RepositoryError::NotAFile`] if `path` is a folder.
+    async fn get_repository_blame(
+        &self,
+        request: GetRepositoryBlameRequest,
+    ) -> Result<RepositoryBlameResponse, RepositoryError>;
+
+    /// Streams a `.tar.gz` or `.zip` archive of the tree at `ref_name`, with
+    /// every entry under `{repo}-{shortsha}/`.
+    ///
+    /// # Errors
+    /// - [`RepositoryError::NotFound`] if the repository or ref does not exist.
+    async fn get_repository_archive(
+        &self,
+        request: GetRepositoryArchiveRequest,
+    ) -> Result<RepositoryArchiveResponse, RepositoryError>;
+
+    /// Compares `head` against `base` GitHub-style: lists the commits on `head`
+    /// that are not on `base` and diffs `head` against their merge base. Both
+    /// lists are paged together by a shared cursor.
+    ///
+    /// # Errors
+    /// - [`RepositoryError::NotFound`] if the repository, `base` or `head` does
+    ///   not exist.
+    async fn compare_repository_refs(
+        &self,
+        request: CompareRepositoryRefsRequest,
+    ) -> Result<RepositoryCompareResponse, RepositoryError>;
+
     /// Returns repository metadata by id, unscoped (no visibility filtering).
     ///
     /// # Errors
@@ -129,7 +171,8 @@ pub trait RepositoryService: Send + Sync + 'static {
     ) -> Result<String, RepositoryError>;
 
     /// Returns a single stored commit (metadata plus its per-file diff stats)
-    /// for `owner/repo` at `sha`, read from the database.
+    /// for `owner/repo` at `sha`, read from the database. The commit's
+    /// signature is checked again, and a changed `signature_status` stored.
     ///
     /// # Errors
     /// - [`RepositoryError::NotFound`] if the repository or the commit is absent.
@@ -240,19 +283,23 @@ pub trait RepositoryService: Send + Sync + 'static {
 }
 
 #[derive(Debug, Clone)]
-pub struct RepositoryServiceImpl<G, O, R, C, U>
+pub struct RepositoryServiceImpl<G, O, R, C, U, K, S>
 where
     G: GitClient,
     O: OrganizationRepository,
     R: RepositoryRepository,
     C: CommitRepository,
     U: UserRepository,
+    K: SigningKeyRepository,
+    S: SignatureClient,
 {
     git_client: G,
     org_repo: O,
     repo_repo: R,
     commit_repo: C,
     user_repo: U,
+    signing_key_repo: K,
+    signature_client: S,
 }
 
 impl
@@ -262,6 +309,8 @@ impl
         PgRepositoryRepository,
         PgCommitRepository,
         PgUserRepository,
+        PgSigningKeyRepository,
+        SignatureClientImpl,
     >
 {
     pub fn new(
@@ -270,6 +319,8 @@ impl
         repo_repo: PgRepositoryRepository,
         commit_repo: PgCommitRepository,
         user_repo: PgUserRepository,
+        signing_key_repo: PgSigningKeyRepository,
+        signature_client: SignatureClientImpl,
     ) -> Self {
         Self {
             git_client,
@@ -277,17 +328,21 @@ impl
             repo_repo,
             commit_repo,
             user_repo,
+            signing_key_repo,
+            signature_client,
         }
     }
 }
 
-impl<G, O, R, C, U> RepositoryServiceImpl<G, O, R, C, U>
+impl<G, O, R, C, U, K, S> RepositoryServiceImpl<G, O, R, C, U, K, S>
 where
     G: GitClient,
     O: OrganizationRepository,
     R: RepositoryRepository,
     C: CommitRepository,
     U: UserRepository,
+    K: SigningKeyRepository,
+    S: SignatureClient,
 {
     fn get_initial_files(
         &self,
@@ -371,6 +426,7 @@ where
                 &[diffs],
                 &[None],
                 &[None],
+                &[None],
             )
             .await?;
 
@@ -380,13 +436,15 @@ where
 
 #[crate::instrument_all(level = "debug")]
 #[async_trait]
-impl<G, O, R, C, U> RepositoryService for RepositoryServiceImpl<G, O, R, C, U>
+impl<G, O, R, C, U, K, S> RepositoryService for RepositoryServiceImpl<G, O, R, C, U, K, S>
 where
     G: GitClient,
     O: OrganizationRepository,
     R: RepositoryRepository,
     C: CommitRepository,
     U: UserRepository,
+    K: SigningKeyRepository,
+    S: SignatureClient,
 {
     async fn create_repository(
         &self,
@@ -455,6 +513,7 @@ where
                 &repo_name,
                 owner_id,
                 &request.owner_type,
+                request.user_id,
                 &request.visibility,
                 request.description.clone(),
                 false,
@@ -549,6 +608,141 @@ where
             .map_err(Into::into)
     }
 
+    async fn get_repository_blame(
+        &self,
+        request: GetRepositoryBlameRequest,
+    ) -> Result<RepositoryBlameResponse, RepositoryError> {
+        let mut blame = self
+            .git_client
+            .blame(
+                &request.owner_name,
+                &request.name,
+                &request.ref_name,
+                &request.path,
+                request.ignore_revs_file.as_deref(),
+            )
+            .await?;
+
+        let emails: Vec<String> = blame
+            .commits
+            .iter()
+            .map(|c| c.git_author_email.clone())
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        let email_to_id: HashMap<String, Uuid> = self
+            .user_repo
+            .get_by_emails(&emails)
+            .await?
+            .into_iter()
+            .collect();
+
+        let mut users = HashMap::new();
+        for id in email_to_id.values().collect::<HashSet<_>>() {
+            if let Some(user) = self.user_repo.get_by_id(*id).await? {
+                users.insert(user.id, user);
+            }
+        }
+        for commit in &mut blame.commits {
+            let Some(user) = email_to_id
+                .get(&commit.git_author_email)
+                .and_then(|id| users.get(id))
+            else {
+                continue;
+            };
+            commit.author_id = Some(user.id);
+            commit.author_name = Some(user.name.clone());
+            commit.author_image_updated_at = Some(user.image_updated_at);
+        }
+
+        Ok(blame)
+    }
+
+    async fn get_repository_archive(
+        &self,
+        request: GetRepositoryArchiveRequest,
+    ) -> Result<RepositoryArchiveResponse, RepositoryError> {
+        self.git_client
+            .get_repo_archive(
+                &request.owner_name,
+                &request.name,
+                &request.ref_name,
+                request.format,
+            )
+            .await
+            .map_err(Into::into)
+    }
+
+    async fn compare_repository_refs(
+        &self,
+        request: CompareRepositoryRefsRequest,
+    ) -> Result<RepositoryCompareResponse, RepositoryError> {
+        let owner = request.owner_name.as_ref();
+        let repo = request.name.as_ref();
+        let base_sha = self
+            .git_client
+            .resolve_ref_sha(owner, repo, &request.base)
+            .await?;
+        let head_sha = self
+            .git_client
+            .resolve_ref_sha(owner, repo, &request.head)
+            .await?;
+        let merge_base_sha = self
+            .git_client
+            .merge_base(owner, repo, &base_sha, &head_sha)
+            .await?;
+
+        let offset = request.offset as usize;
+        let limit = request.limit as usize;
+        let all_commits = self
+            .git_client
+            .rev_list(owner, repo, &base_sha, &head_sha)
+            .await?;
+        let total_commits = all_commits.len();
+        let mut commits: Vec<_> = all_commits.into_iter().skip(offset).take(limit).collect();
+
+        let emails: Vec<String> = commits
+            .iter()
+            .map(|c| c.author.email.clone())
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        let email_to_id: HashMap<String, Uuid> = self
+            .user_repo
+            .get_by_emails(&emails)
+            .await?
+            .into_iter()
+            .collect();
+        for commit in &mut commits {
+            commit.author.id = email_to_id.get(&commit.author.email).copied();
+        }
+
+        let diff = self
+            .git_client
+            .get_repo_diff(
+                owner,
+                repo,
+                merge_base_sha.as_deref(),
+                &head_sha,
+                request.options,
+                offset,
+                limit,
+            )
+            .await?;
+
+        let has_more = offset + limit < total_commits || offset + limit < diff.total_files;
+        Ok(RepositoryCompareResponse {
+            base_sha,
+            head_sha,
+            merge_base_sha,
+            commits,
+            total_commits,
+            files: diff.files,
+            total_files: diff.total_files,
+            next_cursor: has_more.then(|| request.next_cursor()),
+        })
+    }
+
     async fn get_repository_by_id(&self, id: Uuid) -> Result<RepositoryResponse, RepositoryError> {
         let repository = self
             .repo_repo
@@ -625,13 +819,32 @@ where
             .await?
             .or_not_found("repository", format!("{}/{}", owner, repo_name))?;
 
-        let commit = self
+        let mut commit: CommitResponse = self
             .commit_repo
             .get_commit(repository.id, &request.sha)
             .await?
             .map(Into::into)
             .or_not_found("commit", &request.sha)?;
 
+        // Re-check rather than trust the stored status: the signing key may
+        // have been registered (or deleted) since the commit was pushed.
+        let signature_status = verify_commit_signature::<_, _, _, RepositoryError>(
+            &self.git_client,
+            &self.signing_key_repo,
+            &self.signature_client,
+            &owner,
+            &repo_name,
+            &commit.sha,
+            commit.author_id,
+        )
+        .await?;
+        if signature_status != commit.signature_status {
+            self.commit_repo
+                .set_signature_status(commit.id, signature_status)
+                .await?;
+            commit.signature_status = signature_status;
+        }
+
         Ok(commit)
     }
 
@@ -956,21 +1169,24 @@ mod tests {
     use super::{RepositoryService, RepositoryServiceImpl};
     use crate::{
         dto::{
-            CreateRepositoryCommitFilterRequest, CreateRepositoryRequest,
+            BlameCommitResponse, CreateRepositoryCommitFilterRequest, CreateRepositoryRequest,
             DeleteRepositoryCommitFilterRequest, DeleteRepositoryRequest,
-            GetRepositoryActivityRequest, GetRepositoryCommitRequest, GetRepositoryRequest,
-            ListRepositoryCommitFiltersRequest, ListRepositoryCommitsRequest,
-            StarRepositoryRequest, UnstarRepositoryRequest, UpdateRepositoryCommitFilterRequest,
-            UpdateRepositoryRequest,
+            GetRepositoryActivityRequest, GetRepositoryBlameRequest, GetRepositoryCommitRequest,
+            GetRepositoryRequest, ListRepositoryCommitFiltersRequest, ListRepositoryCommitsRequest,
+            RepositoryBlameResponse, StarRepositoryRequest, UnstarRepositoryRequest,
+            UpdateRepositoryCommitFilterRequest, UpdateRepositoryRequest,
         },
         error::{DatabaseError, RepositoryError},
-        model::{CommitFilter, RepositoryOwnerType, RepositoryStar, RepositoryVisibility},
+        model::{
+            CommitFilter, CommitSignatureStatus, RepositoryOwnerType, RepositoryStar,
+            RepositoryVisibility,
+        },
         service::{
-            test_client::MockGitClient,
-            test_common::{create_commit, create_repository, create_user},
+            test_client::{MockGitClient, MockSignatureClient},
+            test_common::{create_commit, create_organization, create_repository, create_user},
             test_repository::{
                 MockCommitRepository, MockOrganizationRepository, MockRepositoryRepository,
-                MockUserRepository,
+                MockSigningKeyRepository, MockUserRepository,
             },
         },
     };
@@ -981,6 +1197,8 @@ mod tests {
         MockRepositoryRepository,
         MockCommitRepository,
         MockUserRepository,
+        MockSigningKeyRepository,
+        MockSignatureClient,
     >;
 
     fn create_service() -> Service {
@@ -990,6 +1208,8 @@ mod tests {
             repo_repo: MockRepositoryRepository::new(),
             commit_repo: MockCommitRepository::new(),
             user_repo: MockUserRepository::new(),
+            signing_key_repo: MockSigningKeyRepository::new(),
+            signature_client: MockSignatureClient::new(),
         }
     }
 
@@ -1064,7 +1284,7 @@ mod tests {
         service
             .repo_repo
             .expect_create()
-            .returning(|_, _, _, _, _, _, _| Ok(public_repo()));
+            .returning(|_, _, _, _, _, _, _, _| Ok(public_repo()));
 
         let req = CreateRepositoryRequest::new(
             "my-repo",
@@ -1093,7 +1313,7 @@ mod tests {
         service
             .repo_repo
             .expect_create()
-            .returning(|_, _, _, _, _, _, _| Err(DatabaseError::RowNotFound));
+            .returning(|_, _, _, _, _, _, _, _| Err(DatabaseError::RowNotFound));
 
         let req = CreateRepositoryRequest::new(
             "my-repo",
@@ -1116,6 +1336,42 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn create_repository_org_owner_passes_creator_for_admin_grant() {
+        let mut service = create_service();
+        let org = create_organization("acme");
+        let org_id = org.id;
+        let user_id = Uuid::new_v4();
+        service
+            .org_repo
+            .expect_get()
+            .returning(move |_| Ok(Some(org.clone())));
+        service
+            .repo_repo
+            .expect_create()
+            .withf(move |_, owner_id, owner_type, creator_id, _, _, _, _| {
+                *owner_id == org_id
+                    && *owner_type == RepositoryOwnerType::Organization
+                    && *creator_id == user_id
+            })
+            .times(1)
+            .returning(|_, _, _, _, _, _, _, _| Ok(public_repo()));
+
+        let req = CreateRepositoryRequest::new(
+            "my-repo",
+            user_id,
+            "acme",
+            "organization",
+            "public",
+            None,
+            false,
+            None,
+            None,
+        )
+        .unwrap();
+        service.create_repository(req).await.unwrap();
+    }
+
     // --- get_repository ---
 
     #[tokio::test]
@@ -1242,6 +1498,34 @@ mod tests {
         assert_eq!(resp.sha, "abc123");
     }
 
+    #[tokio::test]
+    async fn get_repository_commit_stores_a_changed_signature_status() {
+        let mut service = create_service();
+        service.git_client =
+            MockGitClient::default().with_signature("abc123", "not a signature", b"tree\n");
+        service
+            .repo_repo
+            .expect_get()
+            .returning(|_, _, _| Ok(Some(public_repo())));
+        service
+            .commit_repo
+            .expect_get_commit()
+            .returning(|_, _| Ok(Some(create_commit("abc123"))));
+        service
+            .commit_repo
+            .expect_set_signature_status()
+            .withf(|_, status| *status == Some(CommitSignatureStatus::Unverified))
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let req = GetRepositoryCommitRequest::new("alice", "myrepo", "abc123".to_string()).unwrap();
+        let resp = service.get_repository_commit(req).await.unwrap();
+        assert_eq!(
+            resp.signature_status,
+            Some(CommitSignatureStatus::Unverified)
+        );
+    }
+
     #[tokio::test]
     async fn get_repository_commit_missing_commit_is_not_found() {
         let mut service = create_service();
@@ -1546,4 +1830,57 @@ mod tests {
             .unwrap_err();
         assert!(matches!(err, RepositoryError::NotFound(_)));
     }
+
+    #[tokio::test]
+    async fn get_repository_blame_resolves_authors_by_email() {
+        let alice = create_user("alice");
+        let alice_id = alice.id;
+        let commit = |sha: &str, email: &str| BlameCommitResponse {
+            sha: sha.to_string(),
+            parent_sha: None,
+            message: "change\n".to_string(),
+            date: Utc::now(),
+            author_id: None,
+            author_name: None,
+            author_image_updated_at: None,
+            git_author_name: email.to_string(),
+            git_author_email: email.to_string(),
+        };
+        let blame = RepositoryBlameResponse {
+            ref_name: "main".to_string(),
+            commit_sha: "a".repeat(40),
+            path: "README.md".to_string(),
+            ranges: vec![],
+            commits: vec![
+                commit(&"a".repeat(40), "alice@example.com"),
+                commit(&"b".repeat(40), "stranger@example.com"),
+            ],
+        };
+
+        let mut service = create_service();
+        service.git_client = MockGitClient::default().with_blame(blame);
+        service
+            .user_repo
+            .expect_get_by_emails()
+            .returning(move |_| Ok(vec![("alice@example.com".to_string(), alice_id)]));
+        service
+            .user_repo
+            .expect_get_by_id()
+            .times(1)
+            .returning(move |_| Ok(Some(alice.clone())));
+
+        let req = GetRepositoryBlameRequest::new(
+            "myrepo",
+            "alice",
+            "main".to_string(),
+            "README.md".to_string(),
+            None,
+        )
+        .unwrap();
+        let blame = service.get_repository_blame(req).await.unwrap();
+
+        assert_eq!(blame.commits[0].author_id, Some(alice_id));
+        assert_eq!(blame.commits[0].author_name.as_deref(), Some("alice"));
+        assert_eq!(blame.commits[1].author_id, None);
+    }
 }
@@ -1,20 +1,28 @@
+use std::collections::HashMap;
+
 use async_trait::async_trait;
+use uuid::Uuid;
 
 use crate::{
     client::{Git2Client, GitClient},
     dto::{
-        AddReviewReviewerReqeuest, GetReviewDiffBlobsRequest, GetReviewRequest, ListReviewsRequest,
-        MergeReviewDiffRequest, Page, ProcessReviewRequest, PublishReviewDiffRequest,
-        PublishReviewRequest, RemoveReviewReviewerRequest, ReplyToReviewCommentRequest,
-        RepositoryBlobPairResponse, ResolveReviewCommentRequest, ReviewAction,
-        ReviewCommentResponse, ReviewResponse, ReviewReviewDiffRequest, ReviewerResponse,
-        UpdateReviewCommentRequest, UpdateReviewDiffRequest, UpdateReviewRequest,
+        AddReviewReviewerReqeuest, GetReviewDiffBlobsRequest, GetReviewDiffMergeabilityRequest,
+        GetReviewMergeRequirementsRequest, GetReviewRequest, ListReviewsRequest,
+        MergeRequirementsResponse, MergeReviewDiffRequest, Page, ProcessReviewRequest,
+        PublishReviewDiffRequest, PublishReviewRequest, RemoveReviewReviewerRequest,
+        ReplyToReviewCommentRequest, RepositoryBlobPairResponse, ResolveReviewCommentRequest,
+        ReviewAction, ReviewCommentResponse, ReviewMergeabilityResponse, ReviewResponse,
+        ReviewReviewDiffRequest, ReviewerResponse, UpdateReviewCommentRequest,
+        UpdateReviewDiffRequest, UpdateReviewMergeRequirementsRequest, UpdateReviewRequest,
     },
     error::{ConflictError, InputError, NotFoundError, OptionNotFoundExt, ReviewError},
-    model::{DiffStatus, Review, ReviewStatus, Verdict},
+    model::{
+        BuildStatus, Diff, DiffStatus, MergeCheck, MergeCheckKind, MergeRequirements, Review,
+        ReviewStatus, Revision, Verdict,
+    },
     repository::{
-        PgRepositoryRepository, PgReviewRepository, PgUserRepository, RepositoryRepository,
-        ReviewRepository, UserRepository,
+        BuildRepository, PgBuildRepository, PgRepositoryRepository, PgReviewRepository,
+        PgUserRepository, RepositoryRepository, ReviewRepository, UserRepository,
     },
     util::{
         cursor,
@@ -166,10 +174,21 @@ pub trait ReviewService: Send + Sync + 'static {
     /// merged diff is marked `merged`. The review is closed once all of its
     /// diffs are merged.
     ///
+    /// Before anything is written the repository's merge requirements are
+    /// evaluated (see
+    /// [`get_review_diff_mergeability`](ReviewService::get_review_diff_mergeability)).
+    /// A required green build is checked against the commit that would land.
+    /// Rebased commits cannot have been built yet, so when the target has
+    /// advanced the merge is rejected without rebasing anything; the failed
+    /// [`GreenBuild`](crate::model::MergeCheckKind::GreenBuild) check tells
+    /// the author to rebase and push the review so its new revisions build.
+    ///
     /// # Errors
     /// - [`ReviewError::DiffNotMergeable`] if the review is not `open`, or a
     ///   cherry-pick hits a merge conflict (the review must be updated to
     ///   resolve conflicts first).
+    /// - [`ReviewError::MergeRequirementsNotMet`] with the full checklist if
+    ///   any merge requirement is unmet.
     /// - [`NotFoundError`] (via [`ReviewError::NotFound`]) if there are no
     ///   unmerged diffs at or before the position, or a diff has no revisions.
     async fn merge_review_diff(
@@ -177,6 +196,45 @@ pub trait ReviewService: Send + Sync + 'static {
         request: MergeReviewDiffRequest,
     ) -> Result<ReviewResponse, ReviewError>;
 
+    /// Returns the checklist [`merge_review_diff`](ReviewService::merge_review_diff)
+    /// would enforce for merging up to the given position, without merging.
+    ///
+    /// The review must be open, and depending on the repository's
+    /// [`MergeRequirements`]: every diff's latest revision needs the required
+    /// number of approvals and no rejections, every top-level comment on the
+    /// merged diffs must be resolved, and the commit that would land must have
+    /// a successful build.
+    ///
+    /// # Errors
+    /// - [`NotFoundError`] (via [`ReviewError::NotFound`]) if the review does
+    ///   not exist, there are no unmerged diffs at or before the position, or a
+    ///   diff has no revisions.
+    async fn get_review_diff_mergeability(
+        &self,
+        request: GetReviewDiffMergeabilityRequest,
+    ) -> Result<ReviewMergeabilityResponse, ReviewError>;
+
+    /// Returns a repository's merge requirements. Repositories that never
+    /// configured any get the defaults, which require nothing.
+    ///
+    /// # Errors
+    /// - [`NotFoundError`] (via [`ReviewError::NotFound`]) if the repository
+    ///   does not exist.
+    async fn get_review_merge_requirements(
+        &self,
+        request: GetReviewMergeRequirementsRequest,
+    ) -> Result<MergeRequirementsResponse, ReviewError>;
+
+    /// Replaces a repository's merge requirements.
+    ///
+    /// # Errors
+    /// - [`NotFoundError`] (via [`ReviewError::NotFound`]) if the repository
+    ///   does not exist.
+    async fn update_review_merge_requirements(
+        &self,
+        request: UpdateReviewMergeRequirementsRequest,
+    ) -> Result<MergeRequirementsResponse, ReviewError>;
+
     /// Adds a user as a reviewer on a review.
     ///
     /// The review author cannot be added as a reviewer of their own review.
@@ -259,41 +317,54 @@ pub trait ReviewService: Send + Sync + 'static {
 }
 
 #[derive(Debug, Clone)]
-pub struct ReviewServiceImpl<V, R, U, G>
+pub struct ReviewServiceImpl<V, R, U, G, B>
 where
     V: ReviewRepository,
     R: RepositoryRepository,
     U: UserRepository,
     G: GitClient,
+    B: BuildRepository,
 {
     review_repo: V,
     repo_repo: R,
     user_repo: U,
     git_client: G,
+    build_repo: B,
 }
 
-impl ReviewServiceImpl<PgReviewRepository, PgRepositoryRepository, PgUserRepository, Git2Client> {
+impl
+    ReviewServiceImpl<
+        PgReviewRepository,
+        PgRepositoryRepository,
+        PgUserRepository,
+        Git2Client,
+        PgBuildRepository,
+    >
+{
     pub fn new(
         review_repo: PgReviewRepository,
         repo_repo: PgRepositoryRepository,
         user_repo: PgUserRepository,
         git_client: Git2Client,
+        build_repo: PgBuildRepository,
     ) -> Self {
         Self {
             review_repo,
             repo_repo,
             user_repo,
             git_client,
+            build_repo,
         }
     }
 }
 
-impl<V, R, U, G> ReviewServiceImpl<V, R, U, G>
+impl<V, R, U, G, B> ReviewServiceImpl<V, R, U, G, B>
 where
     V: ReviewRepository,
     R: RepositoryRepository,
     U: UserRepository,
     G: GitClient,
+    B: BuildRepository,
 {
     async fn get_review_by_id(
         &self,
@@ -307,16 +378,201 @@ where
             .await?
             .or_not_found("review", format!("{}/{}/review/{}", owner, repo, number))?)
     }
+
+    async fn get_merge_requirements(
+        &self,
+        repository_id: Uuid,
+    ) -> Result<MergeRequirements, ReviewError> {
+        Ok(self
+            .review_repo
+            .get_merge_requirements(repository_id)
+            .await?
+            .unwrap_or_default())
+    }
+
+    async fn get_green_build_check(
+        &self,
+        repository_id: Uuid,
+        commit_sha: &str,
+    ) -> Result<MergeCheck, ReviewError> {
+        let build = self
+            .build_repo
+            .get_latest_by_commit(repository_id, commit_sha)
+            .await?;
+        Ok(green_build_check(commit_sha, build.map(|b| b.status)))
+    }
+}
+
+/// Collects every unmerged diff at or before `position`, each paired with its
+/// latest revision.
+fn diffs_to_merge(diffs: &[Diff], position: i32) -> Result<Vec<(&Diff, Revision)>, ReviewError> {
+    let diffs_to_merge: Vec<_> = diffs
+        .iter()
+        .filter(|d| d.position <= position && d.status != DiffStatus::Merged)
+        .collect();
+
+    if diffs_to_merge.is_empty() {
+        return Err(NotFoundError::new(
+            "diff",
+            format!("no open diffs found at or before position {}", position),
+        )
+        .into());
+    }
+
+    let mut diff_revisions = Vec::new();
+    for diff in diffs_to_merge {
+        let revisions = diff.revisions.as_ref().cloned().unwrap_or_default();
+        let revision = revisions.first().or_not_found(
+            "revision",
+            format!("diff at position {} has no revisions", diff.position),
+        )?;
+        diff_revisions.push((diff, revision.clone()));
+    }
+    Ok(diff_revisions)
+}
+
+/// Evaluates the review-state requirements (open status, approvals,
+/// rejections, resolved comments) for merging `diff_revisions`. The build
+/// requirement depends on the commit that would land and is checked
+/// separately.
+///
+/// Verdicts only count on a diff's latest revision, and only a reviewer's most
+/// recent verdict there counts, so pushing a new revision clears both
+/// approvals and rejections.
+fn review_merge_checks(
+    review: &Review,
+    diff_revisions: &[(&Diff, Revision)],
+    requirements: &MergeRequirements,
+) -> Vec<MergeCheck> {
+    let mut checks = vec![if review.status == ReviewStatus::Open {
+        MergeCheck {
+            kind: MergeCheckKind::ReviewOpen,
+            passed: true,
+            message: "review is open".to_string(),
+        }
+    } else {
+        MergeCheck {
+            kind: MergeCheckKind::ReviewOpen,
+            passed: false,
+            message: "review must be open to merge diffs".to_string(),
+        }
+    }];
+
+    let latest_verdicts: Vec<(i32, HashMap<Uuid, Verdict>)> = diff_revisions
+        .iter()
+        .map(|(diff, revision)| {
+            let verdicts = revision
+                .verdicts
+                .iter()
+                .flatten()
+                .map(|v| (v.reviewer_id, v.verdict.clone()))
+                .collect();
+            (diff.position, verdicts)
+        })
+        .collect();
+    let count = |verdicts: &HashMap<Uuid, Verdict>, verdict: Verdict| {
+        verdicts.values().filter(|v| **v == verdict).count()
+    };
+
+    if requirements.required_approvals > 0 {
+        let required = requirements.required_approvals as usize;
+        let unmet: Vec<String> = latest_verdicts
+            .iter()
+            .filter_map(|(position, verdicts)| {
+                let approvals = count(verdicts, Verdict::Approved);
+                (approvals < required).then(|| {
+                    format!("diff {position} has {approvals} of {required} required approvals")
+                })
+            })
+            .collect();
+        checks.push(MergeCheck {
+            kind: MergeCheckKind::Approvals,
+            passed: unmet.is_empty(),
+            message: if unmet.is_empty() {
+                format!("every diff has {required} required approvals")
+            } else {
+                unmet.join(", ")
+            },
+        });
+    }
+
+    if requirements.block_on_rejection {
+        let unmet: Vec<String> = latest_verdicts
+            .iter()
+            .filter_map(|(position, verdicts)| {
+                let rejections = count(verdicts, Verdict::Rejected);
+                (rejections > 0)
+                    .then(|| format!("diff {position} is rejected by {rejections} reviewer(s)"))
+            })
+            .collect();
+        checks.push(MergeCheck {
+            kind: MergeCheckKind::NoRejections,
+            passed: unmet.is_empty(),
+            message: if unmet.is_empty() {
+                "no outstanding rejections".to_string()
+            } else {
+                unmet.join(", ")
+            },
+        });
+    }
+
+    if requirements.require_resolved_comments {
+        let diff_ids: Vec<Uuid> = diff_revisions.iter().map(|(d, _)| d.id).collect();
+        let unresolved = review
+            .comments
+            .iter()
+            .flatten()
+            .filter(|c| c.parent_id.is_none() && !c.resolved && diff_ids.contains(&c.diff_id))
+            .count();
+        checks.push(MergeCheck {
+            kind: MergeCheckKind::CommentsResolved,
+            passed: unresolved == 0,
+            message: if unresolved == 0 {
+                "all comment threads are resolved".to_string()
+            } else {
+                format!("{unresolved} comment thread(s) are unresolved")
+            },
+        });
+    }
+
+    checks
+}
+
+fn green_build_check(commit_sha: &str, status: Option<BuildStatus>) -> MergeCheck {
+    let short_sha = &commit_sha[..commit_sha.len().min(7)];
+    let (passed, outcome) = match status {
+        Some(BuildStatus::Success) => (true, "built successfully"),
+        Some(BuildStatus::Running) => (false, "is still building"),
+        Some(BuildStatus::Failure) => (false, "has a failing build"),
+        Some(BuildStatus::Cancelled) => (false, "has a cancelled build"),
+        None => (false, "has not been built"),
+    };
+    MergeCheck {
+        kind: MergeCheckKind::GreenBuild,
+        passed,
+        message: format!("commit {short_sha} {outcome}"),
+    }
+}
+
+fn pending_rebase_build_check() -> MergeCheck {
+    MergeCheck {
+        kind: MergeCheckKind::GreenBuild,
+        passed: false,
+        message: "target branch has advanced; rebase the review onto it and let the \
+                  rebased stack build successfully before merging"
+            .to_string(),
+    }
 }
 
 #[crate::instrument_all(level = "debug")]
 #[async_trait]
-impl<V, R, U, G> ReviewService for ReviewServiceImpl<V, R, U, G>
+impl<V, R, U, G, B> ReviewService for ReviewServiceImpl<V, R, U, G, B>
 where
     V: ReviewRepository,
     R: RepositoryRepository,
     U: UserRepository,
     G: GitClient,
+    B: BuildRepository,
 {
     async fn get_review(&self, request: GetReviewRequest) -> Result<ReviewResponse, ReviewError> {
         let review = self
@@ -815,39 +1071,34 @@ where
             ));
         }
 
-        let diffs = review.diffs.unwrap_or_default();
-        let diffs_to_merge: Vec<_> = diffs
-            .iter()
-            .filter(|d| d.position <= request.position && d.status != DiffStatus::Merged)
-            .collect();
-
-        if diffs_to_merge.is_empty() {
-            return Err(NotFoundError::new(
-                "diff",
-                format!(
-                    "no open diffs found at or before position {}",
-                    request.position
-                ),
-            )
-            .into());
-        }
-
-        let mut diff_revisions = Vec::new();
-        for diff in &diffs_to_merge {
-            let revisions = diff.revisions.as_ref().cloned().unwrap_or_default();
-            let revision = revisions.first().or_not_found(
-                "revision",
-                format!("diff at position {} has no revisions", diff.position),
-            )?;
-            diff_revisions.push((diff, revision.clone()));
-        }
+        let diffs = review.diffs.clone().unwrap_or_default();
+        let diff_revisions = diffs_to_merge(&diffs, request.position)?;
         let first_revision = &diff_revisions.first().unwrap().1;
 
+        let requirements = self.get_merge_requirements(review.repository_id).await?;
+        let mut checks = review_merge_checks(&review, &diff_revisions, &requirements);
+
         let target_sha = self
             .git_client
             .resolve_ref_sha(owner, repo, &get_target_ref(&review.target_branch))
             .await?;
-        let merge_commit_sha = if target_sha == first_revision.parent_hash {
+        let fast_forward = target_sha == first_revision.parent_hash;
+
+        if requirements.require_green_build {
+            checks.push(if fast_forward {
+                let landing_sha = &diff_revisions.last().unwrap().1.commit_hash;
+                self.get_green_build_check(review.repository_id, landing_sha)
+                    .await?
+            } else {
+                // Rebasing would land new commits that cannot have been built.
+                pending_rebase_build_check()
+            });
+        }
+        if checks.iter().any(|c| !c.passed) {
+            return Err(ReviewError::MergeRequirementsNotMet(checks));
+        }
+
+        let merge_commit_sha = if fast_forward {
             // Fast-forward: target hasn't moved, use the last diff's commit directly
             diff_revisions.last().unwrap().1.commit_hash.clone()
         } else {
@@ -924,6 +1175,75 @@ where
         Ok(updated.into())
     }
 
+    async fn get_review_diff_mergeability(
+        &self,
+        request: GetReviewDiffMergeabilityRequest,
+    ) -> Result<ReviewMergeabilityResponse, ReviewError> {
+        let owner = request.owner.as_ref();
+        let repo = request.repo.as_ref();
+
+        let review = self.get_review_by_id(owner, repo, request.number).await?;
+        let diffs = review.diffs.clone().unwrap_or_default();
+        let diff_revisions = diffs_to_merge(&diffs, request.position)?;
+
+        let requirements = self.get_merge_requirements(review.repository_id).await?;
+        let mut checks = review_merge_checks(&review, &diff_revisions, &requirements);
+
+        if requirements.require_green_build {
+            let target_sha = self
+                .git_client
+                .resolve_ref_sha(owner, repo, &get_target_ref(&review.target_branch))
+                .await?;
+            if target_sha == diff_revisions.first().unwrap().1.parent_hash {
+                let landing_sha = &diff_revisions.last().unwrap().1.commit_hash;
+                checks.push(
+                    self.get_green_build_check(review.repository_id, landing_sha)
+                        .await?,
+                );
+            } else {
+                checks.push(pending_rebase_build_check());
+            }
+        }
+
+        Ok(checks.into())
+    }
+
+    async fn get_review_merge_requirements(
+        &self,
+        request: GetReviewMergeRequirementsRequest,
+    ) -> Result<MergeRequirementsResponse, ReviewError> {
+        let owner = request.owner.as_ref();
+        let repo = request.repo.as_ref();
+
+        let repository_id = self
+            .repo_repo
+            .get_id(owner, repo)
+            .await?
+            .or_not_found("repository", format!("{owner}/{repo}"))?;
+
+        Ok(self.get_merge_requirements(repository_id).await?.into())
+    }
+
+    async fn update_review_merge_requirements(
+        &self,
+        request: UpdateReviewMergeRequirementsRequest,
+    ) -> Result<MergeRequirementsResponse, ReviewError> {
+        let owner = request.owner.as_ref();
+        let repo = request.repo.as_ref();
+
+        let repository_id = self
+            .repo_repo
+            .get_id(owner, repo)
+            .await?
+            .or_not_found("repository", format!("{owner}/{repo}"))?;
+
+        let requirements = self
+            .review_repo
+            .upsert_merge_requirements(repository_id, request.requirements)
+            .await?;
+        Ok(requirements.into())
+    }
+
     async fn update_review_diff(
         &self,
         request: UpdateReviewDiffRequest,
@@ -1174,3 +1494,304 @@ where
         Ok(updated.into())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    use super::{
+        ReviewService, ReviewServiceImpl, diffs_to_merge, green_build_check, review_merge_checks,
+    };
+    use crate::{
+        dto::MergeReviewDiffRequest,
+        error::ReviewError,
+        model::{
+            BuildStatus, Diff, DiffStatus, MergeCheckKind, MergeRequirements, Review,
+            ReviewComment, ReviewStatus, ReviewVerdict, Revision, Verdict,
+        },
+        service::{
+            test_client::MockGitClient,
+            test_repository::{
+                MockBuildRepository, MockRepositoryRepository, MockReviewRepository,
+                MockUserRepository,
+            },
+        },
+    };
+
+    fn verdict(diff_id: Uuid, reviewer_id: Uuid, verdict: Verdict) -> ReviewVerdict {
+        ReviewVerdict {
+            id: Uuid::new_v4(),
+            diff_id,
+            revision_id: Uuid::new_v4(),
+            reviewer_id,
+            verdict,
+            created_at: Utc::now(),
+        }
+    }
+
+    fn diff(position: i32, status: DiffStatus, verdicts: Vec<(Uuid, Verdict)>) -> Diff {
+        let id = Uuid::new_v4();
+        Diff {
+            id,
+            review_id: Uuid::new_v4(),
+            position,
+            message: format!("diff {position}"),
+            status,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            revisions: Some(vec![Revision {
+                id: Uuid::new_v4(),
+                diff_id: id,
+                number: 1,
+                commit_hash: format!("{position:040}"),
+                parent_hash: format!("{:040}", position - 1),
+                created_at: Utc::now(),
+                signature_status: None,
+                verdicts: Some(
+                    verdicts
+                        .into_iter()
+                        .map(|(reviewer, v)| verdict(id, reviewer, v))
+                        .collect(),
+                ),
+            }]),
+        }
+    }
+
+    fn comment(diff_id: Uuid, parent_id: Option<Uuid>, resolved: bool) -> ReviewComment {
+        ReviewComment {
+            id: Uuid::new_v4(),
+            review_id: Uuid::new_v4(),
+            diff_id,
+            revision_id: Uuid::new_v4(),
+            author_id: Uuid::new_v4(),
+            parent_id,
+            body: "comment".to_string(),
+            file_path: None,
+            line_number_start: None,
+            line_number_end: None,
+            start_character: None,
+            end_character: None,
+            side: None,
+            resolved,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            author: None,
+        }
+    }
+
+    fn review(status: ReviewStatus, diffs: Vec<Diff>, comments: Vec<ReviewComment>) -> Review {
+        Review {
+            id: Uuid::new_v4(),
+            repository_id: Uuid::new_v4(),
+            number: 1,
+            author_id: Uuid::new_v4(),
+            title: "title".to_string(),
+            description: String::new(),
+            target_branch: "main".to_string(),
+            status,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            author: None,
+            diffs: Some(diffs),
+            reviewers: None,
+            comments: Some(comments),
+        }
+    }
+
+    fn strict() -> MergeRequirements {
+        MergeRequirements {
+            required_approvals: 1,
+            block_on_rejection: true,
+            require_resolved_comments: true,
+            require_green_build: true,
+        }
+    }
+
+    #[test]
+    fn default_requirements_only_check_review_status() {
+        let review = review(
+            ReviewStatus::Open,
+            vec![diff(1, DiffStatus::Open, vec![])],
+            vec![],
+        );
+        let diffs = review.diffs.clone().unwrap();
+        let to_merge = diffs_to_merge(&diffs, 1).unwrap();
+
+        let checks = review_merge_checks(&review, &to_merge, &MergeRequirements::default());
+
+        assert_eq!(checks.len(), 1);
+        assert_eq!(checks[0].kind, MergeCheckKind::ReviewOpen);
+        assert!(checks[0].passed);
+    }
+
+    #[test]
+    fn reports_every_unmet_condition() {
+        let (alice, bob) = (Uuid::new_v4(), Uuid::new_v4());
+        let first = diff(1, DiffStatus::Open, vec![(alice, Verdict::Approved)]);
+        let second = diff(2, DiffStatus::Open, vec![(bob, Verdict::Rejected)]);
+        let thread = comment(second.id, None, false);
+        let reply = comment(second.id, Some(thread.id), false);
+        let review = review(
+            ReviewStatus::Closed,
+            vec![first, second],
+            vec![thread, reply],
+        );
+        let diffs = review.diffs.clone().unwrap();
+        let to_merge = diffs_to_merge(&diffs, 2).unwrap();
+
+        let checks = review_merge_checks(&review, &to_merge, &strict());
+        let failed: Vec<_> = checks.iter().filter(|c| !c.passed).collect();
+
+        assert_eq!(failed.len(), 4);
+        assert_eq!(failed[0].kind, MergeCheckKind::ReviewOpen);
+        assert_eq!(failed[1].kind, MergeCheckKind::Approvals);
+        assert_eq!(failed[1].message, "diff 2 has 0 of 1 required approvals");
+        assert_eq!(failed[2].kind, MergeCheckKind::NoRejections);
+        assert_eq!(failed[2].message, "diff 2 is rejected by 1 reviewer(s)");
+        assert_eq!(failed[3].kind, MergeCheckKind::CommentsResolved);
+        assert_eq!(failed[3].message, "1 comment thread(s) are unresolved");
+    }
+
+    #[test]
+    fn only_latest_verdict_per_reviewer_counts() {
+        let alice = Uuid::new_v4();
+        let review = review(
+            ReviewStatus::Open,
+            vec![diff(
+                1,
+                DiffStatus::Open,
+                vec![(alice, Verdict::Rejected), (alice, Verdict::Approved)],
+            )],
+            vec![],
+        );
+        let diffs = review.diffs.clone().unwrap();
+        let to_merge = diffs_to_merge(&diffs, 1).unwrap();
+
+        let checks = review_merge_checks(&review, &to_merge, &strict());
+
+        assert!(
+            checks
+                .iter()
+                .filter(|c| c.kind != MergeCheckKind::GreenBuild)
+                .all(|c| c.passed)
+        );
+    }
+
+    #[test]
+    fn ignores_merged_diffs_and_diffs_above_position() {
+        let merged = diff(1, DiffStatus::Merged, vec![]);
+        let merged_comment = comment(merged.id, None, false);
+        let above = diff(3, DiffStatus::Open, vec![]);
+        let review = review(
+            ReviewStatus::Open,
+            vec![
+                merged,
+                diff(
+                    2,
+                    DiffStatus::Open,
+                    vec![(Uuid::new_v4(), Verdict::Approved)],
+                ),
+                above,
+            ],
+            vec![merged_comment],
+        );
+        let diffs = review.diffs.clone().unwrap();
+        let to_merge = diffs_to_merge(&diffs, 2).unwrap();
+
+        assert_eq!(to_merge.len(), 1);
+        assert!(
+            review_merge_checks(&review, &to_merge, &strict())
+                .iter()
+                .all(|c| c.passed)
+        );
+    }
+
+    #[test]
+    fn diffs_to_merge_requires_an_unmerged_diff() {
+        let diffs = vec![diff(1, DiffStatus::Merged, vec![])];
+        let err = diffs_to_merge(&diffs, 1).unwrap_err();
+        assert!(matches!(err, ReviewError::NotFound(_)));
+    }
+
+    #[test]
+    fn green_build_check_reflects_build_status() {
+        let sha = "abcdef0123456789abcdef0123456789abcdef01";
+        let check = green_build_check(sha, Some(BuildStatus::Success));
+        assert!(check.passed);
+        assert_eq!(check.message, "commit abcdef0 built successfully");
+
+        for (status, message) in [
+            (
+                Some(BuildStatus::Running),
+                "commit abcdef0 is still building",
+            ),
+            (
+                Some(BuildStatus::Failure),
+                "commit abcdef0 has a failing build",
+            ),
+            (None, "commit abcdef0 has not been built"),
+        ] {
+            let check = green_build_check(sha, status);
+            assert!(!check.passed);
+            assert_eq!(check.message, message);
+        }
+    }
+
+    #[test]
+    fn error_lists_only_unmet_conditions() {
+        let checks = vec![
+            green_build_check("abcdef0", Some(BuildStatus::Success)),
+            green_build_check("1234567", None),
+            green_build_check("7654321", Some(BuildStatus::Failure)),
+        ];
+        let err = ReviewError::MergeRequirementsNotMet(checks);
+        assert_eq!(
+            err.to_string(),
+            "Diff is not mergeable: commit 1234567 has not been built; \
+             commit 7654321 has a failing build"
+        );
+    }
+
+    #[tokio::test]
+    async fn merge_onto_advanced_target_is_rejected_without_rebasing() {
+        let diff = diff(1, DiffStatus::Open, vec![]);
+        let review = review(ReviewStatus::Open, vec![diff], vec![]);
+
+        let mut review_repo = MockReviewRepository::new();
+        review_repo
+            .expect_get_review_by_number()
+            .returning(move |_, _, _| Ok(Some(review.clone())));
+        review_repo.expect_get_merge_requirements().returning(|_| {
+            Ok(Some(MergeRequirements {
+                require_green_build: true,
+                ..MergeRequirements::default()
+            }))
+        });
+        review_repo.expect_update_revision_sha().never();
+        review_repo.expect_update_diff().never();
+
+        // The target has moved past the diff's parent, so merging would rebase.
+        let git_client = MockGitClient::default().with_resolved_sha(&format!("{:040}", 9));
+        let service = ReviewServiceImpl {
+            review_repo,
+            repo_repo: MockRepositoryRepository::new(),
+            user_repo: MockUserRepository::new(),
+            git_client: git_client.clone(),
+            build_repo: MockBuildRepository::new(),
+        };
+        let request = MergeReviewDiffRequest::new("alice", "repo", 1, 1).unwrap();
+
+        let err = service.merge_review_diff(request).await.unwrap_err();
+        let ReviewError::MergeRequirementsNotMet(checks) = err else {
+            panic!("expected unmet merge requirements, got {err:?}");
+        };
+        let build = checks
+            .iter()
+            .find(|c| c.kind == MergeCheckKind::GreenBuild)
+            .unwrap();
+        assert!(!build.passed);
+        // Neither the diff's current ref nor the target branch was moved.
+        assert!(git_client.updated_refs().is_empty());
+    }
+}
@@ -0,0 +1,302 @@
+use async_trait::async_trait;
+
+use crate::{
+    client::{Git2Client, GitClient},
+    dto::{CreateTagRequest, DeleteTagRequest, GetTagRequest, ListTagsRequest, TagResponse},
+    error::{ConflictError, GitError, InputError, OptionNotFoundExt, TagError},
+    repository::{PgRepositoryRepository, PgUserRepository, RepositoryRepository, UserRepository},
+};
+
+/// Reads and writes a repository's git tags. Tags live only in git; releases
+/// built on them are managed by [`ReleaseService`](crate::service::ReleaseService).
+///
+/// Tags created or deleted here bypass the receive hooks, so callers publish
+/// the tag event and trigger CI themselves, as post-receive does for pushes.
+#[async_trait]
+pub trait TagService: Send + Sync + 'static {
+    /// Lists the repository's tags, newest first.
+    ///
+    /// # Errors
+    /// - [`TagError::NotFound`] if the repository does not exist.
+    /// - [`TagError::GitError`] if reading the tags fails.
+    async fn list_tags(&self, request: ListTagsRequest) -> Result<Vec<TagResponse>, TagError>;
+
+    /// Returns one tag, with its tagger and signature when annotated.
+    ///
+    /// # Errors
+    /// - [`TagError::NotFound`] if the repository or tag does not exist.
+    /// - [`TagError::GitError`] if reading the tag fails.
+    async fn get_tag(&self, request: GetTagRequest) -> Result<TagResponse, TagError>;
+
+    /// Tags the commit `request.target` resolves to. With a message the tag
+    /// is annotated and tagged by the requesting user under their primary
+    /// email; otherwise it is lightweight.
+    ///
+    /// # Errors
+    /// - [`TagError::NotFound`] if the repository or user does not exist, or
+    ///   the user has no primary email for an annotated tag.
+    /// - [`TagError::Input`] if the target does not resolve to a commit.
+    /// - [`TagError::Conflict`] if the tag already exists.
+    /// - [`TagError::GitError`] if creating the tag fails.
+    async fn create_tag(&self, request: CreateTagRequest) -> Result<TagResponse, TagError>;
+
+    /// Deletes a tag and returns it as it was. Releases of the tag are kept.
+    ///
+    /// # Errors
+    /// - [`TagError::NotFound`] if the repository or tag does not exist.
+    /// - [`TagError::GitError`] if deleting the tag fails.
+    async fn delete_tag(&self, request: DeleteTagRequest) -> Result<TagResponse, TagError>;
+}
+
+#[derive(Debug, Clone)]
+pub struct TagServiceImpl<R, U, G>
+where
+    R: RepositoryRepository,
+    U: UserRepository,
+    G: GitClient,
+{
+    repo_repo: R,
+    user_repo: U,
+    git_client: G,
+}
+
+impl TagServiceImpl<PgRepositoryRepository, PgUserRepository, Git2Client> {
+    pub fn new(
+        repo_repo: PgRepositoryRepository,
+        user_repo: PgUserRepository,
+        git_client: Git2Client,
+    ) -> Self {
+        Self {
+            repo_repo,
+            user_repo,
+            git_client,
+        }
+    }
+}
+
+impl<R, U, G> TagServiceImpl<R, U, G>
+where
+    R: RepositoryRepository,
+    U: UserRepository,
+    G: GitClient,
+{
+    async fn verify_repository(&self, owner: &str, repo: &str) -> Result<(), TagError> {
+        self.repo_repo
+            .get_id(owner, repo)
+            .await?
+            .or_not_found("repository", format!("{owner}/{repo}"))?;
+        Ok(())
+    }
+
+    async fn find_tag(&self, owner: &str, repo: &str, tag: &str) -> Result<TagResponse, TagError> {
+        Ok(self
+            .git_client
+            .get_tag(owner, repo, tag)
+            .await?
+            .or_not_found("tag", format!("{owner}/{repo}@{tag}"))?)
+    }
+}
+
+#[crate::instrument_all(level = "debug")]
+#[async_trait]
+impl<R, U, G> TagService for TagServiceImpl<R, U, G>
+where
+    R: RepositoryRepository,
+    U: UserRepository,
+    G: GitClient,
+{
+    async fn list_tags(&self, request: ListTagsRequest) -> Result<Vec<TagResponse>, TagError> {
+        let owner = request.owner_name.as_ref();
+        let repo = request.name.as_ref();
+        self.verify_repository(owner, repo).await?;
+
+        Ok(self.git_client.list_tags(owner, repo).await?)
+    }
+
+    async fn get_tag(&self, request: GetTagRequest) -> Result<TagResponse, TagError> {
+        let owner = request.owner_name.as_ref();
+        let repo = request.name.as_ref();
+        self.verify_repository(owner, repo).await?;
+
+        self.find_tag(owner, repo, &request.tag).await
+    }
+
+    async fn create_tag(&self, request: CreateTagRequest) -> Result<TagResponse, TagError> {
+        let owner = request.owner_name.as_ref();
+        let repo = request.name.as_ref();
+        self.verify_repository(owner, repo).await?;
+
+        if self
+            .git_client
+            .get_tag(owner, repo, &request.tag)
+            .await?
+            .is_some()
+        {
+            return Err(ConflictError::new("tag", request.tag.as_ref()).into());
+        }
+
+        let sha = match self
+            .git_client
+            .resolve_ref_sha(owner, repo, &request.target)
+            .await
+        {
+            Ok(sha) => sha,
+            Err(GitError::NotFound(_)) => {
+                return Err(InputError::new(
+                    "target",
+                    format!("'{}' does not resolve to a commit", request.target),
+                )
+                .into());
+            }
+            Err(e) => return Err(e.into()),
+        };
+
+        let user = self
+            .user_repo
+            .get_by_id(request.user_id)
+            .await?
+            .or_not_found("user", request.user_id)?;
+        let email = match request.message {
+            Some(_) => user
+                .primary_email()
+                .or_not_found("user_email", request.user_id)?
+                .email
+                .clone(),
+            None => String::new(),
+        };
+
+        Ok(self
+            .git_client
+            .create_tag(
+                owner,
+                repo,
+                &request.tag,
+                &sha,
+                request.message.as_deref(),
+                &user.name,
+                &email,
+            )
+            .await?)
+    }
+
+    async fn delete_tag(&self, request: DeleteTagRequest) -> Result<TagResponse, TagError> {
+        let owner = request.owner_name.as_ref();
+        let repo = request.name.as_ref();
+        self.verify_repository(owner, repo).await?;
+
+        let tag = self.find_tag(owner, repo, &request.tag).await?;
+        self.git_client.delete_tag(owner, repo, &tag.name).await?;
+
+        Ok(tag)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::{
+        model::User,
+        service::{
+            test_client::MockGitClient,
+            test_common::{create_user, create_user_email},
+            test_repository::{MockRepositoryRepository, MockUserRepository},
+        },
+    };
+
+    const COMMIT_SHA: &str = "1111111111111111111111111111111111111111";
+
+    fn repo_repo() -> MockRepositoryRepository {
+        let mut repo_repo = MockRepositoryRepository::new();
+        repo_repo
+            .expect_get_id()
+            .returning(|_, _| Ok(Some(Uuid::new_v4())));
+        repo_repo
+    }
+
+    fn user_repo_with(user: User) -> MockUserRepository {
+        let mut user_repo = MockUserRepository::new();
+        user_repo
+            .expect_get_by_id()
+            .returning(move |_| Ok(Some(user.clone())));
+        user_repo
+    }
+
+    #[tokio::test]
+    async fn create_annotated_tag_uses_primary_email() {
+        let mut user = create_user("alice");
+        let mut email = create_user_email(user.id, "alice@example.com");
+        email.is_primary = true;
+        user.emails.push(email);
+
+        let git_client = MockGitClient::default().with_resolved_sha(COMMIT_SHA);
+        let service = TagServiceImpl {
+            repo_repo: repo_repo(),
+            user_repo: user_repo_with(user.clone()),
+            git_client: git_client.clone(),
+        };
+        let request = CreateTagRequest::new(
+            "dotfiles",
+            "alice",
+            "v1.0.0",
+            "main",
+            Some("First release".to_string()),
+            user.id,
+        )
+        .unwrap();
+        let tag = service.create_tag(request).await.unwrap();
+
+        assert!(tag.annotated);
+        assert_eq!(tag.commit.sha, COMMIT_SHA);
+        let tagger = tag.tagger.unwrap();
+        assert_eq!(tagger.name, "alice");
+        assert_eq!(tagger.email, "alice@example.com");
+        assert_eq!(git_client.tags().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn create_existing_tag_is_conflict() {
+        let user = create_user("alice");
+        let service = TagServiceImpl {
+            repo_repo: repo_repo(),
+            user_repo: user_repo_with(user.clone()),
+            git_client: MockGitClient::default()
+                .with_resolved_sha(COMMIT_SHA)
+                .with_tag("v1.0.0", COMMIT_SHA),
+        };
+        let request =
+            CreateTagRequest::new("dotfiles", "alice", "v1.0.0", "main", None, user.id).unwrap();
+        let err = service.create_tag(request).await.unwrap_err();
+
+        assert!(matches!(err, TagError::Conflict(_)));
+    }
+
+    #[tokio::test]
+    async fn create_tag_of_unknown_target_is_input_error() {
+        let user = create_user("alice");
+        let service = TagServiceImpl {
+            repo_repo: repo_repo(),
+            user_repo: user_repo_with(user.clone()),
+            git_client: MockGitClient::default(),
+        };
+        let request =
+            CreateTagRequest::new("dotfiles", "alice", "v1.0.0", "nope", None, user.id).unwrap();
+        let err = service.create_tag(request).await.unwrap_err();
+
+        assert!(matches!(err, TagError::Input(_)));
+    }
+
+    #[tokio::test]
+    async fn delete_missing_tag_is_not_found() {
+        let service = TagServiceImpl {
+            repo_repo: repo_repo(),
+            user_repo: MockUserRepository::new(),
+            git_client: MockGitClient::default().with_tag("v1.0.0", COMMIT_SHA),
+        };
+        let request = DeleteTagRequest::new("dotfiles", "alice", "v2.0.0").unwrap();
+        let err = service.delete_tag(request).await.unwrap_err();
+
+        assert!(matches!(err, TagError::NotFound(_)));
+    }
+}
@@ -0,0 +1,430 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::{
+    dto::{
+        AddTeamMemberRequest, CreateTeamRequest, DeleteTeamRequest, GetTeamRequest,
+        ListTeamsRequest, RemoveTeamMemberRequest, RemoveTeamRepositoryRequest,
+        SetTeamRepositoryRequest, TeamMemberResponse, TeamRepositoryResponse, TeamResponse,
+    },
+    error::{ConflictError, InputError, NotFoundError, OptionNotFoundExt, TeamError},
+    model::{Team, User},
+    repository::{
+        OrganizationRepository, PgOrganizationRepository, PgRepositoryRepository, PgTeamRepository,
+        PgUserRepository, RepositoryRepository, TeamRepository, UserRepository,
+    },
+};
+
+/// Manages an organization's teams: named groups of org members that are
+/// granted a role on a set of the org's repositories.
+///
+/// A member's access to an org repository is the highest role granted to any
+/// of their teams or to them as a collaborator. Authorization (organization
+/// admin for everything but reads) is checked by callers.
+#[async_trait]
+pub trait TeamService: Send + Sync + 'static {
+    /// Creates an empty team.
+    ///
+    /// # Errors
+    /// - [`TeamError::NotFound`] if the organization does not exist.
+    /// - [`TeamError::Conflict`] if the organization already has a team with
+    ///   this name.
+    async fn create_team(&self, request: CreateTeamRequest) -> Result<TeamResponse, TeamError>;
+
+    /// Lists an organization's teams ordered by name, without their members or
+    /// repositories.
+    ///
+    /// # Errors
+    /// - [`TeamError::NotFound`] if the organization does not exist.
+    async fn list_teams(&self, request: ListTeamsRequest) -> Result<Vec<TeamResponse>, TeamError>;
+
+    /// Returns a team with its members and repositories.
+    ///
+    /// # Errors
+    /// - [`TeamError::NotFound`] if the organization or team does not exist.
+    async fn get_team(&self, request: GetTeamRequest) -> Result<TeamResponse, TeamError>;
+
+    /// Deletes a team, revoking every grant it carried.
+    ///
+    /// # Errors
+    /// - [`TeamError::NotFound`] if the organization or team does not exist.
+    async fn delete_team(&self, request: DeleteTeamRequest) -> Result<(), TeamError>;
+
+    /// Adds an org member to a team.
+    ///
+    /// # Errors
+    /// - [`TeamError::NotFound`] if the organization, team, or user does not
+    ///   exist.
+    /// - [`TeamError::Input`] if the user is not a member of the organization.
+    /// - [`TeamError::Conflict`] if the user already belongs to the team.
+    async fn add_team_member(
+        &self,
+        request: AddTeamMemberRequest,
+    ) -> Result<TeamMemberResponse, TeamError>;
+
+    /// Removes a user from a team.
+    ///
+    /// # Errors
+    /// - [`TeamError::NotFound`] if the organization, team, or user does not
+    ///   exist, or the user does not belong to the team.
+    async fn remove_team_member(&self, request: RemoveTeamMemberRequest) -> Result<(), TeamError>;
+
+    /// Grants a team a role on one of the organization's repositories,
+    /// replacing the role of an existing grant.
+    ///
+    /// # Errors
+    /// - [`TeamError::NotFound`] if the organization, team, or repository does
+    ///   not exist. Repositories of other owners are never found.
+    async fn set_team_repository(
+        &self,
+        request: SetTeamRepositoryRequest,
+    ) -> Result<TeamRepositoryResponse, TeamError>;
+
+    /// Revokes a team's grant on a repository.
+    ///
+    /// # Errors
+    /// - [`TeamError::NotFound`] if the organization, team, or repository does
+    ///   not exist, or the team has no grant on it.
+    async fn remove_team_repository(
+        &self,
+        request: RemoveTeamRepositoryRequest,
+    ) -> Result<(), TeamError>;
+}
+
+#[derive(Debug, Clone)]
+pub struct TeamServiceImpl<T, O, R, U>
+where
+    T: TeamRepository,
+    O: OrganizationRepository,
+    R: RepositoryRepository,
+    U: UserRepository,
+{
+    team_repo: T,
+    org_repo: O,
+    repo_repo: R,
+    user_repo: U,
+}
+
+impl
+    TeamServiceImpl<
+        PgTeamRepository,
+        PgOrganizationRepository,
+        PgRepositoryRepository,
+        PgUserRepository,
+    >
+{
+    pub fn new(
+        team_repo: PgTeamRepository,
+        org_repo: PgOrganizationRepository,
+        repo_repo: PgRepositoryRepository,
+        user_repo: PgUserRepository,
+    ) -> Self {
+        Self {
+            team_repo,
+            org_repo,
+            repo_repo,
+            user_repo,
+        }
+    }
+}
+
+impl<T, O, R, U> TeamServiceImpl<T, O, R, U>
+where
+    T: TeamRepository,
+    O: OrganizationRepository,
+    R: RepositoryRepository,
+    U: UserRepository,
+{
+    async fn get_org_id(&self, org_name: &str) -> Result<Uuid, TeamError> {
+        let id = self
+            .org_repo
+            .get_id(org_name)
+            .await?
+            .or_not_found("organization", org_name)?;
+        Ok(id)
+    }
+
+    async fn get_team_in(&self, org_name: &str, team_name: &str) -> Result<Team, TeamError> {
+        let org_id = self.get_org_id(org_name).await?;
+        let team = self
+            .team_repo
+            .get(org_id, team_name)
+            .await?
+            .or_not_found("team", format!("{org_name}/{team_name}"))?;
+        Ok(team)
+    }
+
+    async fn get_user(&self, user_name: &str) -> Result<User, TeamError> {
+        let user = self
+            .user_repo
+            .get(user_name)
+            .await?
+            .or_not_found("user", user_name)?;
+        Ok(user)
+    }
+
+    async fn get_repository_id(&self, org_name: &str, repo: &str) -> Result<Uuid, TeamError> {
+        let id = self
+            .repo_repo
+            .get_id(org_name, repo)
+            .await?
+            .or_not_found("repository", format!("{org_name}/{repo}"))?;
+        Ok(id)
+    }
+}
+
+#[crate::instrument_all(level = "debug")]
+#[async_trait]
+impl<T, O, R, U> TeamService for TeamServiceImpl<T, O, R, U>
+where
+    T: TeamRepository,
+    O: OrganizationRepository,
+    R: RepositoryRepository,
+    U: UserRepository,
+{
+    async fn create_team(&self, request: CreateTeamRequest) -> Result<TeamResponse, TeamError> {
+        let org_id = self.get_org_id(request.org_name.as_ref()).await?;
+
+        match self
+            .team_repo
+            .create(org_id, request.name.as_ref(), request.description)
+            .await
+        {
+            Ok(team) => Ok(team.into()),
+            Err(e) if e.is_unique_violation() => {
+                Err(ConflictError::new("team", request.name.as_ref()).into())
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    async fn list_teams(&self, request: ListTeamsRequest) -> Result<Vec<TeamResponse>, TeamError> {
+        let org_id = self.get_org_id(request.org_name.as_ref()).await?;
+
+        let teams = self.team_repo.list(org_id).await?;
+        Ok(teams.into_iter().map(Into::into).collect())
+    }
+
+    async fn get_team(&self, request: GetTeamRequest) -> Result<TeamResponse, TeamError> {
+        let team = self
+            .get_team_in(request.org_name.as_ref(), request.team_name.as_ref())
+            .await?;
+        Ok(team.into())
+    }
+
+    async fn delete_team(&self, request: DeleteTeamRequest) -> Result<(), TeamError> {
+        let team = self
+            .get_team_in(request.org_name.as_ref(), request.team_name.as_ref())
+            .await?;
+
+        self.team_repo.delete(team.id).await?;
+        Ok(())
+    }
+
+    async fn add_team_member(
+        &self,
+        request: AddTeamMemberRequest,
+    ) -> Result<TeamMemberResponse, TeamError> {
+        let team = self
+            .get_team_in(request.org_name.as_ref(), request.team_name.as_ref())
+            .await?;
+        let user = self.get_user(request.user_name.as_ref()).await?;
+
+        if !self
+            .org_repo
+            .is_member(team.organization_id, user.id)
+            .await?
+        {
+            return Err(InputError::new("user name", "is not a member of the organization").into());
+        }
+
+        let member = self
+            .team_repo
+            .add_member(team.id, user.id)
+            .await?
+            .ok_or_else(|| ConflictError::new("team member", &user.name))?;
+        Ok(member.into())
+    }
+
+    async fn remove_team_member(&self, request: RemoveTeamMemberRequest) -> Result<(), TeamError> {
+        let team = self
+            .get_team_in(request.org_name.as_ref(), request.team_name.as_ref())
+            .await?;
+        let user = self.get_user(request.user_name.as_ref()).await?;
+
+        let removed = self.team_repo.remove_member(team.id, user.id).await?;
+        if !removed {
+            return Err(NotFoundError::new("team member", &user.name).into());
+        }
+
+        Ok(())
+    }
+
+    async fn set_team_repository(
+        &self,
+        request: SetTeamRepositoryRequest,
+    ) -> Result<TeamRepositoryResponse, TeamError> {
+        let team = self
+            .get_team_in(request.org_name.as_ref(), request.team_name.as_ref())
+            .await?;
+        let repository_id = self
+            .get_repository_id(request.org_name.as_ref(), request.repo.as_ref())
+            .await?;
+
+        let access = self
+            .team_repo
+            .set_repository(team.id, repository_id, request.role)
+            .await?;
+        Ok(access.into())
+    }
+
+    async fn remove_team_repository(
+        &self,
+        request: RemoveTeamRepositoryRequest,
+    ) -> Result<(), TeamError> {
+        let team = self
+            .get_team_in(request.org_name.as_ref(), request.team_name.as_ref())
+            .await?;
+        let repository_id = self
+            .get_repository_id(request.org_name.as_ref(), request.repo.as_ref())
+            .await?;
+
+        let removed = self
+            .team_repo
+            .remove_repository(team.id, repository_id)
+            .await?;
+        if !removed {
+            return Err(NotFoundError::new("team repository", request.repo.as_ref()).into());
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    use super::{TeamService, TeamServiceImpl};
+    use crate::{
+        dto::{AddTeamMemberRequest, CreateTeamRequest},
+        error::TeamError,
+        model::{Team, TeamMember},
+        service::{
+            test_common::create_user,
+            test_repository::{
+                MockOrganizationRepository, MockRepositoryRepository, MockTeamRepository,
+                MockUserRepository,
+            },
+        },
+    };
+
+    type Service = TeamServiceImpl<
+        MockTeamRepository,
+        MockOrganizationRepository,
+        MockRepositoryRepository,
+        MockUserRepository,
+    >;
+
+    fn create_team(organization_id: Uuid, name: &str) -> Team {
+        Team {
+            id: Uuid::new_v4(),
+            organization_id,
+            name: name.to_string(),
+            description: None,
+            created_at: Utc::now(),
+            members: Some(Vec::new()),
+            repositories: Some(Vec::new()),
+        }
+    }
+
+    fn create_service() -> Service {
+        let org_id = Uuid::new_v4();
+        let mut org_repo = MockOrganizationRepository::new();
+        org_repo
+            .expect_get_id()
+            .returning(move |_| Ok(Some(org_id)));
+        let mut team_repo = MockTeamRepository::new();
+        team_repo
+            .expect_get()
+            .returning(|org_id, name| Ok(Some(create_team(org_id, name))));
+        let mut user_repo = MockUserRepository::new();
+        user_repo
+            .expect_get()
+            .returning(|name| Ok(Some(create_user(name))));
+
+        TeamServiceImpl {
+            team_repo,
+            org_repo,
+            repo_repo: MockRepositoryRepository::new(),
+            user_repo,
+        }
+    }
+
+    #[tokio::test]
+    async fn create_team_in_missing_org_is_not_found() {
+        let mut service = create_service();
+        service.org_repo.checkpoint();
+        service.org_repo.expect_get_id().returning(|_| Ok(None));
+
+        let request = CreateTeamRequest::new("ghost", "backend", None).unwrap();
+        let err = service.create_team(request).await.unwrap_err();
+        assert!(matches!(err, TeamError::NotFound(_)));
+    }
+
+    #[tokio::test]
+    async fn add_member_requires_org_membership() {
+        let mut service = create_service();
+        service
+            .org_repo
+            .expect_is_member()
+            .returning(|_, _| Ok(false));
+
+        let request = AddTeamMemberRequest::new("acme", "backend", "bob").unwrap();
+        let err = service.add_team_member(request).await.unwrap_err();
+        assert!(matches!(err, TeamError::Input(_)));
+    }
+
+    #[tokio::test]
+    async fn add_member_succeeds_for_org_member() {
+        let mut service = create_service();
+        service
+            .org_repo
+            .expect_is_member()
+            .returning(|_, _| Ok(true));
+        service
+            .team_repo
+            .expect_add_member()
+            .returning(|team_id, user_id| {
+                Ok(Some(TeamMember {
+                    team_id,
+                    user_id,
+                    user_name: "bob".to_string(),
+                    created_at: Utc::now(),
+                    image_updated_at: Utc::now(),
+                }))
+            });
+
+        let request = AddTeamMemberRequest::new("acme", "backend", "bob").unwrap();
+        let member = service.add_team_member(request).await.unwrap();
+        assert_eq!(member.user_name, "bob");
+    }
+
+    #[tokio::test]
+    async fn add_existing_member_conflicts() {
+        let mut service = create_service();
+        service
+            .org_repo
+            .expect_is_member()
+            .returning(|_, _| Ok(true));
+        service
+            .team_repo
+            .expect_add_member()
+            .returning(|_, _| Ok(None));
+
+        let request = AddTeamMemberRequest::new("acme", "backend", "bob").unwrap();
+        let err = service.add_team_member(request).await.unwrap_err();
+        assert!(matches!(err, TeamError::Conflict(_)));
+    }
+}
@@ -1,3 +1,5 @@
+use std::{collections::HashMap, time::Duration};
+
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use uuid::Uuid;
@@ -6,29 +8,57 @@ use crate::{
     client::{Git2Client, GitClient, GitHubClient, OctocrabClient},
     dto::{
         CreateGitHubInstallationRequest, CreateGitHubInstallationResponse,
-        CreateGitHubMigrationRequest, CreateGitHubMigrationResponse, GetGitHubAppInstallUrlRequest,
-        GetGitHubAppInstallUrlResponse, GetMigrationRequest, GitHubInstallationResponse,
-        ListGitHubInstallationRepositoriesRequest, ListGitHubInstallationRepositoriesResponse,
-        ListGitHubInstallationsRequest, ListMigrationsRequest, MigrateGitHubRepositoriesRequest,
-        MigrateGitHubRepositoriesResponse, MigratedRepositoryInfo, MigrationResponse, Page,
+        CreateGitHubMigrationRequest, CreateGitHubMigrationResponse,
+        CutoverRepositoryMirrorRequest, GetGitHubAppInstallUrlRequest,
+        GetGitHubAppInstallUrlResponse, GetMigrationRequest, GetRepositoryMirrorRequest,
+        GitHubAccount, GitHubDiscussion, GitHubInstallationResponse, GitHubIssue,
+        GitHubIssueComment, GitHubPullRequest, GitHubPullRequestCommit, GitHubPullRequestReview,
+        GitHubReviewComment, ListGitHubInstallationRepositoriesRequest,
+        ListGitHubInstallationRepositoriesResponse, ListGitHubInstallationsRequest,
+        ListMigrationsRequest, MigrateGitHubRepositoriesRequest, MigrateGitHubRepositoriesResponse,
+        MigratedRepositoryInfo, MigrationResponse, MirrorCutoverBackfill, MirrorCutoverResponse,
+        MirrorRefUpdate, Page, RepositoryMirrorResponse, SyncedRepositoryInfo,
     },
-    error::{ConflictError, GitHubError, InputError, MigrationError, OptionNotFoundExt},
+    error::{ConflictError, GitError, GitHubError, InputError, MigrationError, OptionNotFoundExt},
     model::{
-        GitHubInstallationType, MigrationOriginService, MigrationRepositoryStatus, MigrationStatus,
-        Repository, RepositoryOwnerType, RepositoryVisibility,
+        CommentSide, DiffStatus, GitHubInstallationType, ImportedAnswer, ImportedComment,
+        ImportedDiff, ImportedQuestion, ImportedReview, ImportedReviewComment, ImportedVerdict,
+        MigrationOriginService, MigrationRepositoryStatus, MigrationStatus, MirrorDirection,
+        Repository, RepositoryOwnerType, RepositoryVisibility, ReviewStatus, Verdict,
     },
     repository::{
         GitHubRepository, MigrationRepository, OrganizationRepository, PgGitHubRepository,
-        PgMigrationRepository, PgOrganizationRepository, PgRepositoryRepository, PgUserRepository,
-        RepositoryRepository, UserRepository,
+        PgMigrationRepository, PgOrganizationRepository, PgQuestionRepository,
+        PgRepositoryRepository, PgReviewRepository, PgUserRepository, QuestionRepository,
+        RepositoryRepository, ReviewRepository, UserRepository,
     },
     util::{
+        crypto::EncryptionKey,
         cursor,
-        git::{GitHookType, POST_RECEIVE_SCRIPT, PRE_RECEIVE_SCRIPT, PROC_RECEIVE_SCRIPT},
-        github::get_github_clone_url,
+        git::{
+            GitHookType, POST_RECEIVE_SCRIPT, PRE_RECEIVE_SCRIPT, PROC_RECEIVE_SCRIPT, TAG_PREFIX,
+            ZERO_SHA,
+        },
+        github::{
+            attribute_github_post, get_github_clone_url, get_github_noreply_emails,
+            get_github_repository_url,
+        },
+        mirror::get_mirror_password_aad,
+        review::{get_current_ref, get_head_ref, get_revision_ref},
     },
 };
 
+/// `core.questions.title` and `core.reviews.title` are `VARCHAR(255)`, one
+/// short of GitHub's limit.
+const IMPORTED_TITLE_MAX_CHARS: usize = 255;
+
+/// The login GitHub shows in place of deleted accounts.
+const GITHUB_GHOST_LOGIN: &str = "ghost";
+
+/// How long a claimed cutover backfill may go unfinished (it failed, or the
+/// server stopped mid-way) before it is claimed again.
+const BACKFILL_RETRY_AFTER: Duration = Duration::from_secs(60 * 60);
+
 /// Importing repositories into gitdot from external services. Currently covers
 /// the GitHub flow end to end: app installation, browsing installation repos,
 /// planning a migration, and mirroring the selected repos into gitdot.
@@ -50,6 +80,54 @@ pub trait MigrationService: Send + Sync + 'static {
         request: ListMigrationsRequest,
     ) -> Result<Page<MigrationResponse>, MigrationError>;
 
+    /// Returns the sync state of a repository that mirrors its GitHub origin.
+    ///
+    /// # Errors
+    /// - [`MigrationError::NotFound`] if the repository does not exist or is
+    ///   not a read-only mirror.
+    async fn get_repository_mirror(
+        &self,
+        request: GetRepositoryMirrorRequest,
+    ) -> Result<RepositoryMirrorResponse, MigrationError>;
+
+    /// Turns a repository that mirrors its GitHub origin into a live gitdot
+    /// repository. Its git hooks are reinstalled, then in one transaction it
+    /// stops being read-only (which ends webhook and scheduled syncs), a push
+    /// mirror back to the origin is added when `keep_push_mirror` is set (its
+    /// password encrypted and its first sync queued), and the switch is
+    /// recorded with the caller as its actor, due for a commit backfill.
+    ///
+    /// Reinstalling hooks replaces them outright, so a cutover that failed
+    /// after it can simply be retried.
+    ///
+    /// # Errors
+    /// - [`MigrationError::NotFound`] if the repository does not exist or is
+    ///   not a read-only mirror.
+    async fn cutover_repository_mirror(
+        &self,
+        request: CutoverRepositoryMirrorRequest,
+    ) -> Result<MirrorCutoverResponse, MigrationError>;
+
+    /// Claims up to `limit` cutovers whose history has not been recorded as
+    /// commits yet, listing every branch and tag of each for the caller to
+    /// record. A claimed backfill that is not finished with
+    /// [`finish_cutover_backfill`](Self::finish_cutover_backfill) is claimed
+    /// again an hour later. Repositories whose refs cannot be read are
+    /// skipped until then.
+    ///
+    /// # Errors
+    /// - [`MigrationError::DatabaseError`] if claiming failed.
+    async fn claim_cutover_backfills(
+        &self,
+        limit: i64,
+    ) -> Result<Vec<MirrorCutoverBackfill>, MigrationError>;
+
+    /// Marks a cutover's history as recorded.
+    ///
+    /// # Errors
+    /// - [`MigrationError::DatabaseError`] if the update failed.
+    async fn finish_cutover_backfill(&self, cutover_id: Uuid) -> Result<(), MigrationError>;
+
     /// Completes a GitHub App installation OAuth callback and records it.
     ///
     /// Verifies the signed `state` and that its `user_id` matches
@@ -134,7 +212,17 @@ pub trait MigrationService: Send + Sync + 'static {
     /// A repo whose name already exists for the destination owner fails with a
     /// conflict recorded on that repo's row (it does not abort the others).
     ///
+    /// With `import_history`, a mirrored repo moves to [`Importing`] while its
+    /// GitHub issues and discussions become questions and its merged or open
+    /// pull requests become reviews, keeping their numbers and timestamps.
+    /// Authors are matched to gitdot users through verified emails (their
+    /// GitHub noreply, commit and public addresses); posts by anyone else are
+    /// imported under the migration author and credited in their body. The
+    /// row's import counts advance as items land, and a failed import leaves
+    /// the repo [`Completed`] with the error recorded.
+    ///
     /// [`Running`]: crate::model::MigrationRepositoryStatus::Running
+    /// [`Importing`]: crate::model::MigrationRepositoryStatus::Importing
     /// [`Failed`]: crate::model::MigrationRepositoryStatus::Failed
     /// [`Completed`]: crate::model::MigrationRepositoryStatus::Completed
     /// [`MigrationStatus::Running`]: crate::model::MigrationStatus::Running
@@ -147,7 +235,7 @@ pub trait MigrationService: Send + Sync + 'static {
 }
 
 #[derive(Debug, Clone)]
-pub struct MigrationServiceImpl<G, GH, RR, MR, OR, GHR, UR>
+pub struct MigrationServiceImpl<G, GH, RR, MR, OR, GHR, UR, QR, RVR>
 where
     G: GitClient,
     GH: GitHubClient,
@@ -156,6 +244,8 @@ where
     OR: OrganizationRepository,
     GHR: GitHubRepository,
     UR: UserRepository,
+    QR: QuestionRepository,
+    RVR: ReviewRepository,
 {
     git_client: G,
     github_client: GH,
@@ -164,6 +254,9 @@ where
     org_repo: OR,
     github_repo: GHR,
     user_repo: UR,
+    question_repo: QR,
+    review_repo: RVR,
+    encryption_key: EncryptionKey,
 }
 
 impl
@@ -175,8 +268,11 @@ impl
         PgOrganizationRepository,
         PgGitHubRepository,
         PgUserRepository,
+        PgQuestionRepository,
+        PgReviewRepository,
     >
 {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         git_client: Git2Client,
         github_client: OctocrabClient,
@@ -185,6 +281,9 @@ impl
         org_repo: PgOrganizationRepository,
         github_repo: PgGitHubRepository,
         user_repo: PgUserRepository,
+        question_repo: PgQuestionRepository,
+        review_repo: PgReviewRepository,
+        encryption_key: EncryptionKey,
     ) -> Self {
         Self {
             git_client,
@@ -194,11 +293,14 @@ impl
             org_repo,
             github_repo,
             user_repo,
+            question_repo,
+            review_repo,
+            encryption_key,
         }
     }
 }
 
-impl<G, GH, RR, MR, OR, GHR, UR> MigrationServiceImpl<G, GH, RR, MR, OR, GHR, UR>
+impl<G, GH, RR, MR, OR, GHR, UR, QR, RVR> MigrationServiceImpl<G, GH, RR, MR, OR, GHR, UR, QR, RVR>
 where
     G: GitClient,
     GH: GitHubClient,
@@ -207,12 +309,15 @@ where
     OR: OrganizationRepository,
     GHR: GitHubRepository,
     UR: UserRepository,
+    QR: QuestionRepository,
+    RVR: ReviewRepository,
 {
     async fn migrate_single_repository(
         &self,
         owner_name: &str,
         owner_id: Uuid,
         owner_type: &RepositoryOwnerType,
+        author_id: Uuid,
         full_name: &str,
         visibility: &RepositoryVisibility,
         token: &str,
@@ -242,7 +347,8 @@ where
 
         let result = self
             .setup_mirrored_repository(
-                owner_name, repo_name, owner_id, owner_type, visibility, readonly, created_at,
+                owner_name, repo_name, owner_id, owner_type, author_id, visibility, readonly,
+                created_at,
             )
             .await;
         if result.is_err() {
@@ -252,16 +358,8 @@ where
         result
     }
 
-    async fn setup_mirrored_repository(
-        &self,
-        owner_name: &str,
-        repo_name: &str,
-        owner_id: Uuid,
-        owner_type: &RepositoryOwnerType,
-        visibility: &RepositoryVisibility,
-        readonly: bool,
-        created_at: Option<DateTime<Utc>>,
-    ) -> Result<(Repository, Option<String>, String), MigrationError> {
+    /// Replaces the repo's hooks with gitdot's own.
+    async fn install_hooks(&self, owner_name: &str, repo_name: &str) -> Result<(), MigrationError> {
         self.git_client.empty_hooks(owner_name, repo_name).await?;
         self.git_client
             .install_hook(
@@ -287,11 +385,72 @@ where
                 PROC_RECEIVE_SCRIPT,
             )
             .await?;
+        Ok(())
+    }
+
+    /// Lists every branch and tag as a created ref, walking shared history
+    /// once: the default branch from the root, every other ref only past the
+    /// default branch's head.
+    async fn list_history_updates(
+        &self,
+        owner_name: &str,
+        repo_name: &str,
+    ) -> Result<Vec<MirrorRefUpdate>, GitError> {
+        let default_ref_name = self
+            .git_client
+            .get_default_ref(owner_name, repo_name)
+            .await
+            .unwrap_or_else(|_| "refs/heads/main".to_string());
+        let (default_branch, branches): (Vec<_>, Vec<_>) = self
+            .git_client
+            .list_branches(owner_name, repo_name)
+            .await?
+            .into_iter()
+            .partition(|(ref_name, _)| *ref_name == default_ref_name);
+        let tags = self
+            .git_client
+            .list_tags(owner_name, repo_name)
+            .await?
+            .into_iter()
+            .map(|tag| (format!("{TAG_PREFIX}{}", tag.name), tag.commit.sha));
+        let base_sha = default_branch
+            .first()
+            .map_or_else(|| ZERO_SHA.to_string(), |(_, sha)| sha.clone());
+
+        Ok(default_branch
+            .into_iter()
+            .map(|(ref_name, sha)| (ref_name, ZERO_SHA.to_string(), sha))
+            .chain(
+                branches
+                    .into_iter()
+                    .chain(tags)
+                    .map(|(ref_name, sha)| (ref_name, base_sha.clone(), sha)),
+            )
+            .map(|(ref_name, old_sha, new_sha)| MirrorRefUpdate {
+                ref_name,
+                old_sha,
+                new_sha,
+            })
+            .collect())
+    }
+
+    async fn setup_mirrored_repository(
+        &self,
+        owner_name: &str,
+        repo_name: &str,
+        owner_id: Uuid,
+        owner_type: &RepositoryOwnerType,
+        author_id: Uuid,
+        visibility: &RepositoryVisibility,
+        readonly: bool,
+        created_at: Option<DateTime<Utc>>,
+    ) -> Result<(Repository, Option<String>, String), MigrationError> {
+        self.install_hooks(owner_name, repo_name).await?;
 
         let repository = self
             .repo_repo
             .create(
-                repo_name, owner_id, owner_type, visibility, None, readonly, created_at,
+                repo_name, owner_id, owner_type, author_id, visibility, None, readonly, created_at,
             )
             .await?;
 
@@ -310,12 +469,248 @@ where
 
         Ok((repository, head_sha, default_ref_name))
     }
+
+    /// Imports the issues, discussions and pull requests of `full_name` into
+    /// `repository`, recording progress on the migration-repository row after
+    /// every imported item. Pull requests closed without merging are skipped.
+    ///
+    /// Each pull request's `refs/pull/<number>/head` is fetched first, since
+    /// the commits of fork, squash-merged and rebase-merged pull requests are
+    /// not on any branch. A pull request whose commits are still missing is
+    /// not imported, and its number is returned. Review refs are force-written,
+    /// including for reviews a previous run already imported, so a rerun
+    /// completes an import that failed part-way.
+    async fn import_history(
+        &self,
+        migration_repository_id: Uuid,
+        token: &str,
+        full_name: &str,
+        owner_name: &str,
+        repository: &Repository,
+        fallback_author_id: Uuid,
+    ) -> Result<Vec<i32>, MigrationError> {
+        let issues = self.github_client.list_issues(token, full_name).await?;
+        let mut issue_comments = Vec::with_capacity(issues.len());
+        for issue in &issues {
+            let comments = self
+                .github_client
+                .list_issue_comments(token, full_name, issue.number)
+                .await?;
+            issue_comments.push(comments);
+        }
+        let discussions = self
+            .github_client
+            .list_discussions(token, full_name)
+            .await?;
+
+        let mut pull_requests = Vec::new();
+        for pull_request in self
+            .github_client
+            .list_pull_requests(token, full_name)
+            .await?
+        {
+            if pull_request.state != "open" && pull_request.merged_at.is_none() {
+                continue;
+            }
+            let number = pull_request.number;
+            pull_requests.push(PullRequestHistory {
+                commits: self
+                    .github_client
+                    .list_pull_request_commits(token, full_name, number)
+                    .await?,
+                reviews: self
+                    .github_client
+                    .list_pull_request_reviews(token, full_name, number)
+                    .await?,
+                comments: self
+                    .github_client
+                    .list_pull_request_comments(token, full_name, number)
+                    .await?,
+                conversation: self
+                    .github_client
+                    .list_issue_comments(token, full_name, number)
+                    .await?,
+                pull_request,
+            });
+        }
+
+        let candidates =
+            github_author_candidates(&issues, &issue_comments, &discussions, &pull_requests);
+        let authors = GitHubAuthors {
+            users: self.resolve_github_authors(token, candidates).await?,
+            fallback_id: fallback_author_id,
+        };
+
+        let mut questions: Vec<ImportedQuestion> = issues
+            .iter()
+            .zip(&issue_comments)
+            .map(|(issue, comments)| issue_question(issue, comments, &authors))
+            .chain(discussions.iter().map(|d| discussion_question(d, &authors)))
+            .collect();
+        questions.sort_by_key(|q| q.created_at);
+
+        let (mut imported_questions, mut imported_reviews) = (0, 0);
+        for question in &questions {
+            if self
+                .question_repo
+                .import_question(repository.id, question)
+                .await?
+                .is_some()
+            {
+                imported_questions += 1;
+                self.migration_repo
+                    .update_import_progress(
+                        migration_repository_id,
+                        imported_questions,
+                        imported_reviews,
+                    )
+                    .await?;
+            }
+        }
+
+        let clone_url = get_github_clone_url(token, full_name);
+        let mut skipped = Vec::new();
+        for history in &pull_requests {
+            let Some(review) = pull_request_review(history, &authors) else {
+                continue;
+            };
+
+            let head_ref = format!("refs/pull/{}/head", review.number);
+            // A pull request whose head is gone can still have its commits on
+            // a branch; the check below decides.
+            let _ = self
+                .git_client
+                .fetch_ref(
+                    owner_name,
+                    &repository.name,
+                    &clone_url,
+                    &head_ref,
+                    &head_ref,
+                )
+                .await;
+            let mut missing = false;
+            for diff in &review.diffs {
+                if !self
+                    .git_client
+                    .has_commit(owner_name, &repository.name, &diff.commit_hash)
+                    .await?
+                {
+                    missing = true;
+                    break;
+                }
+            }
+            if missing {
+                skipped.push(review.number);
+                continue;
+            }
+
+            let review_id = match self
+                .review_repo
+                .import_review(repository.id, &review)
+                .await?
+            {
+                Some(review_id) => {
+                    imported_reviews += 1;
+                    review_id
+                }
+                None => {
+                    let Some(existing) = self
+                        .review_repo
+                        .get_review_by_number(owner_name, &repository.name, review.number)
+                        .await?
+                    else {
+                        continue;
+                    };
+                    existing.id
+                }
+            };
+
+            let review_ref_id = &review_id.to_string()[..8];
+            for diff in &review.diffs {
+                for ref_name in [
+                    get_revision_ref(review_ref_id, diff.position, 1),
+                    get_current_ref(review_ref_id, diff.position),
+                ] {
+                    self.git_client
+                        .update_ref(owner_name, &repository.name, &ref_name, &diff.commit_hash)
+                        .await?;
+                }
+            }
+            if let Some(head) = review.diffs.last() {
+                self.git_client
+                    .update_ref(
+                        owner_name,
+                        &repository.name,
+                        &get_head_ref(review_ref_id),
+                        &head.commit_hash,
+                    )
+                    .await?;
+            }
+
+            self.migration_repo
+                .update_import_progress(
+                    migration_repository_id,
+                    imported_questions,
+                    imported_reviews,
+                )
+                .await?;
+        }
+
+        Ok(skipped)
+    }
+
+    /// Matches GitHub logins to gitdot users through verified emails. Each
+    /// login's noreply and commit emails are tried first; logins left over
+    /// are retried with their public profile email.
+    async fn resolve_github_authors(
+        &self,
+        token: &str,
+        candidates: HashMap<String, Vec<String>>,
+    ) -> Result<HashMap<String, Uuid>, MigrationError> {
+        let mut users = self.match_github_emails(&candidates).await?;
+
+        let mut public_emails = HashMap::new();
+        for login in candidates
+            .keys()
+            .filter(|login| !users.contains_key(*login))
+        {
+            if let Ok(Some(email)) = self.github_client.get_user_public_email(token, login).await {
+                public_emails.insert(login.clone(), vec![email]);
+            }
+        }
+        users.extend(self.match_github_emails(&public_emails).await?);
+
+        Ok(users)
+    }
+
+    async fn match_github_emails(
+        &self,
+        candidates: &HashMap<String, Vec<String>>,
+    ) -> Result<HashMap<String, Uuid>, MigrationError> {
+        let emails: Vec<String> = candidates.values().flatten().cloned().collect();
+        let owners: HashMap<String, Uuid> = self
+            .user_repo
+            .get_by_emails(&emails)
+            .await?
+            .into_iter()
+            .collect();
+
+        Ok(candidates
+            .iter()
+            .filter_map(|(login, emails)| {
+                emails
+                    .iter()
+                    .find_map(|email| owners.get(email))
+                    .map(|user_id| (login.clone(), *user_id))
+            })
+            .collect())
+    }
 }
 
 #[crate::instrument_all(level = "debug")]
 #[async_trait]
-impl<G, GH, RR, MR, OR, GHR, UR> MigrationService
-    for MigrationServiceImpl<G, GH, RR, MR, OR, GHR, UR>
+impl<G, GH, RR, MR, OR, GHR, UR, QR, RVR> MigrationService
+    for MigrationServiceImpl<G, GH, RR, MR, OR, GHR, UR, QR, RVR>
 where
     G: GitClient,
     GH: GitHubClient,
@@ -324,6 +719,8 @@ where
     OR: OrganizationRepository,
     GHR: GitHubRepository,
     UR: UserRepository,
+    QR: QuestionRepository,
+    RVR: ReviewRepository,
 {
     async fn get_migration(
         &self,
@@ -353,6 +750,123 @@ where
         })
     }
 
+    async fn get_repository_mirror(
+        &self,
+        request: GetRepositoryMirrorRequest,
+    ) -> Result<RepositoryMirrorResponse, MigrationError> {
+        let owner = request.owner_name.to_string();
+        let name = request.name.to_string();
+        let repository_id = self
+            .repo_repo
+            .get_id(&owner, &name)
+            .await?
+            .or_not_found("repository", format!("{owner}/{name}"))?;
+
+        let mirror = self
+            .migration_repo
+            .get_mirror(repository_id)
+            .await?
+            .or_not_found("mirror", format!("{owner}/{name}"))?;
+
+        Ok(mirror.into())
+    }
+
+    async fn cutover_repository_mirror(
+        &self,
+        request: CutoverRepositoryMirrorRequest,
+    ) -> Result<MirrorCutoverResponse, MigrationError> {
+        let owner = request.owner_name.to_string();
+        let name = request.name.to_string();
+        let repository_id = self
+            .repo_repo
+            .get_id(&owner, &name)
+            .await?
+            .or_not_found("repository", format!("{owner}/{name}"))?;
+        let mirror = self
+            .migration_repo
+            .get_mirror(repository_id)
+            .await?
+            .or_not_found("mirror", format!("{owner}/{name}"))?;
+
+        self.install_hooks(&owner, &name).await?;
+
+        let push_mirror_url = request
+            .keep_push_mirror
+            .then(|| get_github_repository_url(&mirror.origin_full_name));
+        let password_ciphertext = request
+            .password
+            .as_deref()
+            .zip(push_mirror_url.as_deref())
+            .map(|(password, url)| {
+                let aad = get_mirror_password_aad(repository_id, MirrorDirection::Push, url);
+                self.encryption_key
+                    .encrypt_with_aad(password.as_bytes(), &aad)
+            });
+        let cutover = self
+            .migration_repo
+            .cutover_mirror(
+                mirror.migration_repository_id,
+                repository_id,
+                request.actor_id,
+                &mirror.origin_full_name,
+                push_mirror_url.as_deref(),
+                request.username,
+                password_ciphertext,
+            )
+            .await?
+            .or_not_found("mirror", format!("{owner}/{name}"))?;
+
+        Ok(cutover.into())
+    }
+
+    async fn claim_cutover_backfills(
+        &self,
+        limit: i64,
+    ) -> Result<Vec<MirrorCutoverBackfill>, MigrationError> {
+        let cutovers = self
+            .migration_repo
+            .claim_cutover_backfills(Utc::now() - BACKFILL_RETRY_AFTER, limit)
+            .await?;
+
+        let mut backfills = Vec::with_capacity(cutovers.len());
+        for cutover in cutovers {
+            let Some(repository) = self
+                .repo_repo
+                .get_by_id(cutover.repository_id, None)
+                .await?
+            else {
+                continue;
+            };
+            match self
+                .list_history_updates(&repository.owner_name, &repository.name)
+                .await
+            {
+                Ok(ref_updates) => backfills.push(MirrorCutoverBackfill {
+                    cutover_id: cutover.id,
+                    repository: SyncedRepositoryInfo {
+                        owner_name: repository.owner_name,
+                        repo_name: repository.name,
+                        ref_updates,
+                    },
+                }),
+                Err(e) => tracing::warn!(
+                    owner = %repository.owner_name,
+                    repo = %repository.name,
+                    "failed to list refs for cutover backfill: {e}"
+                ),
+            }
+        }
+
+        Ok(backfills)
+    }
+
+    async fn finish_cutover_backfill(&self, cutover_id: Uuid) -> Result<(), MigrationError> {
+        self.migration_repo
+            .finish_cutover_backfill(cutover_id)
+            .await?;
+        Ok(())
+    }
+
     async fn create_github_installation(
         &self,
         request: CreateGitHubInstallationRequest,
@@ -559,12 +1073,14 @@ where
             .await?;
 
         let readonly = request.readonly;
+        let import_history = request.import_history;
         let mut handles = Vec::new();
         for migration_repo_entry in request.migration_repositories {
             let service = self.clone();
             let token = token.clone();
             let owner_name = request.owner_name.to_string();
             let owner_id = request.owner_id;
+            let author_id = request.author_id;
             let owner_type = request.owner_type.clone();
             let migration_repo_id = migration_repo_entry.id;
             let full_name = migration_repo_entry.origin_full_name.clone();
@@ -586,6 +1102,7 @@ where
                         &owner_name,
                         owner_id,
                         &owner_type,
+                        author_id,
                         &full_name,
                         &visibility,
                         &token,
@@ -595,18 +1112,52 @@ where
                     .await
                 {
                     Ok((repository, head_sha, default_ref_name)) => {
+                        let _ = service
+                            .migration_repo
+                            .set_destination_repository_id(migration_repo_id, repository.id)
+                            .await;
+
+                        let mut import_error = None;
+                        if import_history {
+                            let _ = service
+                                .migration_repo
+                                .update_migration_repository_status(
+                                    migration_repo_id,
+                                    MigrationRepositoryStatus::Importing,
+                                    None,
+                                )
+                                .await;
+                            import_error = match service
+                                .import_history(
+                                    migration_repo_id,
+                                    &token,
+                                    &full_name,
+                                    &owner_name,
+                                    &repository,
+                                    author_id,
+                                )
+                                .await
+                            {
+                                Ok(skipped) if skipped.is_empty() => None,
+                                Ok(skipped) => Some(format!(
+                                    "pull requests skipped because their commits could not be fetched: {}",
+                                    skipped
+                                        .iter()
+                                        .map(|number| format!("#{number}"))
+                                        .collect::<Vec<_>>()
+                                        .join(", ")
+                                )),
+                                Err(e) => Some(format!("history import failed: {e}")),
+                            };
+                        }
                         let _ = service
                             .migration_repo
                             .update_migration_repository_status(
                                 migration_repo_id,
                                 MigrationRepositoryStatus::Completed,
-                                None,
+                                import_error.as_deref(),
                             )
                             .await;
-                        let _ = service
-                            .migration_repo
-                            .set_destination_repository_id(migration_repo_id, repository.id)
-                            .await;
 
                         Some(MigratedRepositoryInfo {
                             owner_name: owner_name.clone(),
@@ -658,6 +1209,357 @@ where
     }
 }
 
+/// The GitHub side of an imported pull request.
+struct PullRequestHistory {
+    pull_request: GitHubPullRequest,
+    commits: Vec<GitHubPullRequestCommit>,
+    reviews: Vec<GitHubPullRequestReview>,
+    comments: Vec<GitHubReviewComment>,
+    conversation: Vec<GitHubIssueComment>,
+}
+
+/// Gitdot users matched to GitHub logins. Posts by anyone else are imported
+/// under `fallback_id` (the migration author) and credited in their body.
+struct GitHubAuthors {
+    users: HashMap<String, Uuid>,
+    fallback_id: Uuid,
+}
+
+impl GitHubAuthors {
+    fn user(&self, account: Option<&GitHubAccount>) -> Option<Uuid> {
+        account.and_then(|a| self.users.get(&a.login).copied())
+    }
+
+    fn post(&self, account: Option<&GitHubAccount>, body: Option<&str>) -> (Uuid, String) {
+        let body = body.unwrap_or_default();
+        match self.user(account) {
+            Some(user_id) => (user_id, body.to_string()),
+            None => {
+                let login = account.map_or(GITHUB_GHOST_LOGIN, |a| a.login.as_str());
+                (self.fallback_id, attribute_github_post(login, body))
+            }
+        }
+    }
+}
+
+/// Candidate emails for every GitHub login that authored something being
+/// imported: its noreply addresses, then the emails of its pull request
+/// commits.
+fn github_author_candidates(
+    issues: &[GitHubIssue],
+    issue_comments: &[Vec<GitHubIssueComment>],
+    discussions: &[GitHubDiscussion],
+    pull_requests: &[PullRequestHistory],
+) -> HashMap<String, Vec<String>> {
+    fn add(candidates: &mut HashMap<String, Vec<String>>, account: Option<&GitHubAccount>) {
+        if let Some(account) = account {
+            candidates
+                .entry(account.login.clone())
+                .or_insert_with(|| get_github_noreply_emails(account.id, &account.login).to_vec());
+        }
+    }
+
+    let mut candidates = HashMap::new();
+    for issue in issues {
+        add(&mut candidates, issue.user.as_ref());
+    }
+    for comment in issue_comments.iter().flatten() {
+        add(&mut candidates, comment.user.as_ref());
+    }
+    for discussion in discussions {
+        add(&mut candidates, discussion.author.as_ref());
+        for comment in &discussion.comments {
+            add(&mut candidates, comment.author.as_ref());
+            for reply in &comment.replies {
+                add(&mut candidates, reply.author.as_ref());
+            }
+        }
+    }
+    for history in pull_requests {
+        add(&mut candidates, history.pull_request.user.as_ref());
+        for review in &history.reviews {
+            add(&mut candidates, review.user.as_ref());
+        }
+        for comment in &history.comments {
+            add(&mut candidates, comment.user.as_ref());
+        }
+        for comment in &history.conversation {
+            add(&mut candidates, comment.user.as_ref());
+        }
+        for commit in &history.commits {
+            if let (Some(account), Some(author)) = (&commit.author, &commit.commit.author) {
+                add(&mut candidates, Some(account));
+                let emails = candidates.get_mut(&account.login).expect("added above");
+                if !emails.contains(&author.email) {
+                    emails.push(author.email.clone());
+                }
+            }
+        }
+    }
+    candidates
+}
+
+fn truncate_title(title: &str) -> String {
+    title.chars().take(IMPORTED_TITLE_MAX_CHARS).collect()
+}
+
+/// An issue becomes a question; its comments become answers.
+fn issue_question(
+    issue: &GitHubIssue,
+    comments: &[GitHubIssueComment],
+    authors: &GitHubAuthors,
+) -> ImportedQuestion {
+    let (author_id, body) = authors.post(issue.user.as_ref(), issue.body.as_deref());
+    ImportedQuestion {
+        number: issue.number,
+        author_id,
+        title: truncate_title(&issue.title),
+        body,
+        created_at: issue.created_at,
+        updated_at: issue.updated_at,
+        answers: comments
+            .iter()
+            .map(|comment| {
+                let (author_id, body) =
+                    authors.post(comment.user.as_ref(), comment.body.as_deref());
+                ImportedAnswer {
+                    author_id,
+                    body,
+                    created_at: comment.created_at,
+                    updated_at: comment.updated_at,
+                    comments: Vec::new(),
+                }
+            })
+            .collect(),
+    }
+}
+
+/// A discussion becomes a question; its comments become answers and their
+/// replies comments on those answers.
+fn discussion_question(discussion: &GitHubDiscussion, authors: &GitHubAuthors) -> ImportedQuestion {
+    let (author_id, body) = authors.post(discussion.author.as_ref(), Some(&discussion.body));
+    ImportedQuestion {
+        number: discussion.number,
+        author_id,
+        title: truncate_title(&discussion.title),
+        body,
+        created_at: discussion.created_at,
+        updated_at: discussion.updated_at,
+        answers: discussion
+            .comments
+            .iter()
+            .map(|comment| {
+                let (author_id, body) = authors.post(comment.author.as_ref(), Some(&comment.body));
+                ImportedAnswer {
+                    author_id,
+                    body,
+                    created_at: comment.created_at,
+                    updated_at: comment.updated_at,
+                    comments: comment
+                        .replies
+                        .iter()
+                        .map(|reply| {
+                            let (author_id, body) =
+                                authors.post(reply.author.as_ref(), Some(&reply.body));
+                            ImportedComment {
+                                author_id,
+                                body,
+                                created_at: reply.created_at,
+                                updated_at: reply.updated_at,
+                            }
+                        })
+                        .collect(),
+                }
+            })
+            .collect(),
+    }
+}
+
+/// A pull request becomes a review with one diff per commit. Approvals and
+/// change requests are kept as verdicts on every diff, but only for reviewers
+/// matched to a gitdot user. Conversation comments and review summaries attach
+/// to the diff of the commit they were made on (else the last one), as do
+/// inline comments with their file, lines and reply threads. Returns `None`
+/// for a pull request without commits.
+fn pull_request_review(
+    history: &PullRequestHistory,
+    authors: &GitHubAuthors,
+) -> Option<ImportedReview> {
+    let pull_request = &history.pull_request;
+    let (status, diff_status) = if pull_request.merged_at.is_some() {
+        (ReviewStatus::Closed, DiffStatus::Merged)
+    } else if pull_request.draft {
+        (ReviewStatus::Draft, DiffStatus::Draft)
+    } else {
+        (ReviewStatus::Open, DiffStatus::Open)
+    };
+
+    let diffs: Vec<ImportedDiff> = history
+        .commits
+        .iter()
+        .zip(1..)
+        .map(|(commit, position)| ImportedDiff {
+            position,
+            message: commit.commit.message.clone(),
+            status: diff_status.clone(),
+            commit_hash: commit.sha.clone(),
+            parent_hash: commit
+                .parents
+                .first()
+                .map(|p| p.sha.clone())
+                .unwrap_or_default(),
+            created_at: commit
+                .commit
+                .author
+                .as_ref()
+                .map_or(pull_request.created_at, |a| a.date),
+        })
+        .collect();
+    let last_position = diffs.last()?.position;
+    let position_of = |sha: Option<&str>| {
+        diffs
+            .iter()
+            .find(|d| Some(d.commit_hash.as_str()) == sha)
+            .map_or(last_position, |d| d.position)
+    };
+
+    let (author_id, description) =
+        authors.post(pull_request.user.as_ref(), pull_request.body.as_deref());
+
+    let mut reviewer_ids = Vec::new();
+    let mut latest_verdicts: Vec<(Uuid, Verdict, DateTime<Utc>)> = Vec::new();
+    for review in &history.reviews {
+        let Some(reviewer_id) = authors.user(review.user.as_ref()) else {
+            continue;
+        };
+        if reviewer_id != author_id && !reviewer_ids.contains(&reviewer_id) {
+            reviewer_ids.push(reviewer_id);
+        }
+        let verdict = match review.state.as_str() {
+            "APPROVED" => Verdict::Approved,
+            "CHANGES_REQUESTED" => Verdict::Rejected,
+            _ => continue,
+        };
+        let submitted_at = review.submitted_at.unwrap_or(pull_request.updated_at);
+        latest_verdicts.retain(|(id, _, _)| *id != reviewer_id);
+        latest_verdicts.push((reviewer_id, verdict, submitted_at));
+    }
+    let verdicts = latest_verdicts
+        .into_iter()
+        .flat_map(|(reviewer_id, verdict, created_at)| {
+            diffs.iter().map(move |diff| ImportedVerdict {
+                diff_position: diff.position,
+                reviewer_id,
+                verdict: verdict.clone(),
+                created_at,
+            })
+        })
+        .collect();
+
+    // (GitHub id of an inline comment, the id it replies to, the comment)
+    let mut pending: Vec<(Option<i64>, Option<i64>, ImportedReviewComment)> = Vec::new();
+    let mut top_level = |account: Option<&GitHubAccount>,
+                         body: Option<&str>,
+                         diff_position: i32,
+                         created_at: DateTime<Utc>,
+                         updated_at: DateTime<Utc>| {
+        let (author_id, body) = authors.post(account, body);
+        pending.push((
+            None,
+            None,
+            ImportedReviewComment {
+                diff_position,
+                author_id,
+                parent: None,
+                body,
+                file_path: None,
+                line_number_start: None,
+                line_number_end: None,
+                side: None,
+                created_at,
+                updated_at,
+            },
+        ));
+    };
+    for comment in &history.conversation {
+        top_level(
+            comment.user.as_ref(),
+            comment.body.as_deref(),
+            last_position,
+            comment.created_at,
+            comment.updated_at,
+        );
+    }
+    for review in &history.reviews {
+        let (Some(body), Some(submitted_at)) = (review.body.as_deref(), review.submitted_at) else {
+            continue;
+        };
+        if body.trim().is_empty() {
+            continue;
+        }
+        top_level(
+            review.user.as_ref(),
+            Some(body),
+            position_of(review.commit_id.as_deref()),
+            submitted_at,
+            submitted_at,
+        );
+    }
+    for comment in &history.comments {
+        let (author_id, body) = authors.post(comment.user.as_ref(), Some(&comment.body));
+        pending.push((
+            Some(comment.id),
+            comment.in_reply_to_id,
+            ImportedReviewComment {
+                diff_position: position_of(Some(&comment.commit_id)),
+                author_id,
+                parent: None,
+                body,
+                file_path: Some(comment.path.clone()),
+                line_number_start: comment.start_line.or(comment.line),
+                line_number_end: comment.line,
+                side: match comment.side.as_deref() {
+                    Some("LEFT") => Some(CommentSide::Old),
+                    Some("RIGHT") => Some(CommentSide::New),
+                    _ => None,
+                },
+                created_at: comment.created_at,
+                updated_at: comment.updated_at,
+            },
+        ));
+    }
+
+    // Replies always follow what they reply to once sorted by creation time.
+    pending.sort_by_key(|(_, _, comment)| comment.created_at);
+    let indexes: HashMap<i64, usize> = pending
+        .iter()
+        .enumerate()
+        .filter_map(|(i, (id, _, _))| id.map(|id| (id, i)))
+        .collect();
+    let comments = pending
+        .into_iter()
+        .map(|(_, reply_to, mut comment)| {
+            comment.parent = reply_to.and_then(|id| indexes.get(&id).copied());
+            comment
+        })
+        .collect();
+
+    Some(ImportedReview {
+        number: pull_request.number,
+        author_id,
+        title: truncate_title(&pull_request.title),
+        description,
+        target_branch: pull_request.base.ref_name.clone(),
+        status,
+        created_at: pull_request.created_at,
+        updated_at: pull_request.updated_at,
+        diffs,
+        reviewer_ids,
+        verdicts,
+        comments,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use chrono::Utc;
@@ -668,23 +1570,26 @@ mod tests {
     use crate::{
         dto::{
             CreateGitHubInstallationRequest, CreateGitHubMigrationRequest, Cursor,
-            GetGitHubAppInstallUrlRequest, GetMigrationRequest, GitHubAppInstallAction,
-            GitHubEmail, GitHubMembership, GitHubUser, InstallStatePayload,
-            ListGitHubInstallationRepositoriesRequest, ListGitHubInstallationsRequest,
-            ListMigrationsRequest,
+            CutoverRepositoryMirrorRequest, GetGitHubAppInstallUrlRequest, GetMigrationRequest,
+            GetRepositoryMirrorRequest, GitHubAppInstallAction, GitHubEmail, GitHubMembership,
+            GitHubUser, InstallStatePayload, ListGitHubInstallationRepositoriesRequest,
+            ListGitHubInstallationsRequest, ListMigrationsRequest,
         },
         error::{GitHubError, MigrationError},
         model::{
-            GitHubInstallationType, MigrationStatus, RepositoryOwnerType, RepositoryVisibility,
+            GitHubInstallationType, MigrationMirror, MigrationStatus, MirrorDirection,
+            MirrorSyncStatus, RepositoryOwnerType, RepositoryVisibility,
         },
         service::{
             test_client::{MockGitClient, MockGitHubClient},
             test_common::{create_github_installation, create_migration},
             test_repository::{
                 MockGitHubRepository, MockMigrationRepository, MockOrganizationRepository,
-                MockRepositoryRepository, MockUserRepository,
+                MockQuestionRepository, MockRepositoryRepository, MockReviewRepository,
+                MockUserRepository,
             },
         },
+        util::{crypto::EncryptionKey, git::ZERO_SHA, mirror::get_mirror_password_aad},
     };
 
     type Service = MigrationServiceImpl<
@@ -695,6 +1600,8 @@ mod tests {
         MockOrganizationRepository,
         MockGitHubRepository,
         MockUserRepository,
+        MockQuestionRepository,
+        MockReviewRepository,
     >;
 
     fn create_service() -> Service {
@@ -706,9 +1613,31 @@ mod tests {
             org_repo: MockOrganizationRepository::new(),
             github_repo: MockGitHubRepository::new(),
             user_repo: MockUserRepository::new(),
+            question_repo: MockQuestionRepository::new(),
+            review_repo: MockReviewRepository::new(),
+            encryption_key: encryption_key(),
+        }
+    }
+
+    fn mirror_of(repository_id: Uuid) -> MigrationMirror {
+        MigrationMirror {
+            migration_repository_id: Uuid::new_v4(),
+            origin_full_name: "octocat/hello".to_string(),
+            installation_id: Some(12345),
+            repository_id,
+            owner_name: "owner".to_string(),
+            repository_name: "myrepo".to_string(),
+            sync_status: Some(MirrorSyncStatus::Failed),
+            sync_error: Some("git fetch failed".to_string()),
+            last_attempted_at: Some(Utc::now()),
+            last_synced_at: None,
         }
     }
 
+    fn encryption_key() -> EncryptionKey {
+        EncryptionKey::new([7; 32])
+    }
+
     fn cursor() -> Cursor {
         Cursor {
             created_at: Utc::now(),
@@ -812,6 +1741,198 @@ mod tests {
         }
     }
 
+    mod get_repository_mirror {
+        use super::*;
+
+        #[tokio::test]
+        async fn mirror_returns_sync_state() {
+            let mut service = create_service();
+            let repository_id = Uuid::new_v4();
+            service.migration_repo =
+                MockMigrationRepository::default().with_mirror(mirror_of(repository_id));
+            service
+                .repo_repo
+                .expect_get_id()
+                .returning(move |_, _| Ok(Some(repository_id)));
+
+            let response = service
+                .get_repository_mirror(GetRepositoryMirrorRequest::new("myrepo", "owner").unwrap())
+                .await
+                .unwrap();
+
+            assert_eq!(response.origin_full_name, "octocat/hello");
+            assert_eq!(response.status, Some(MirrorSyncStatus::Failed));
+            assert_eq!(response.error.as_deref(), Some("git fetch failed"));
+        }
+
+        #[tokio::test]
+        async fn repository_without_mirror_is_not_found() {
+            let mut service = create_service();
+            service.migration_repo =
+                MockMigrationRepository::default().with_mirror(mirror_of(Uuid::new_v4()));
+            service
+                .repo_repo
+                .expect_get_id()
+                .returning(|_, _| Ok(Some(Uuid::new_v4())));
+
+            let err = service
+                .get_repository_mirror(GetRepositoryMirrorRequest::new("myrepo", "owner").unwrap())
+                .await
+                .unwrap_err();
+
+            assert!(matches!(err, MigrationError::NotFound(_)));
+        }
+    }
+
+    mod cutover_repository_mirror {
+        use super::*;
+
+        fn service_with_mirror(repository_id: Uuid) -> Service {
+            let mut service = create_service();
+            service.migration_repo =
+                MockMigrationRepository::default().with_mirror(mirror_of(repository_id));
+            service
+                .repo_repo
+                .expect_get_id()
+                .returning(move |_, _| Ok(Some(repository_id)));
+            service
+        }
+
+        fn request(
+            actor_id: Uuid,
+            keep_push_mirror: bool,
+            password: Option<&str>,
+        ) -> CutoverRepositoryMirrorRequest {
+            CutoverRepositoryMirrorRequest::new(
+                "myrepo",
+                "owner",
+                actor_id,
+                keep_push_mirror,
+                None,
+                password.map(str::to_string),
+            )
+            .unwrap()
+        }
+
+        #[tokio::test]
+        async fn cutover_installs_hooks_and_keeps_push_mirror() {
+            let repository_id = Uuid::new_v4();
+            let actor_id = Uuid::new_v4();
+            let service = service_with_mirror(repository_id);
+
+            let cutover = service
+                .cutover_repository_mirror(request(actor_id, true, Some("token")))
+                .await
+                .unwrap();
+
+            assert_eq!(
+                service.git_client.installed_hooks(),
+                ["pre-receive", "post-receive", "proc-receive"]
+                    .map(|hook| ("myrepo".to_string(), hook.to_string()))
+            );
+
+            let cutovers = service.migration_repo.cutovers();
+            assert_eq!(cutovers.len(), 1);
+            assert_eq!(cutovers[0].repository_id, repository_id);
+            assert_eq!(cutovers[0].actor_id, actor_id);
+            assert_eq!(cutover.origin_full_name, "octocat/hello");
+            assert_eq!(cutover.push_mirror_id, cutovers[0].push_mirror_id);
+
+            let push_mirrors = service.migration_repo.cutover_push_mirrors();
+            assert_eq!(push_mirrors.len(), 1);
+            let (url, username, ciphertext) = &push_mirrors[0];
+            assert_eq!(url, "https://github.com/octocat/hello.git");
+            assert!(username.is_none());
+            let aad = get_mirror_password_aad(repository_id, MirrorDirection::Push, url);
+            assert_eq!(
+                encryption_key().decrypt_with_aad(ciphertext.as_ref().unwrap(), &aad),
+                Some(b"token".to_vec())
+            );
+            assert_eq!(encryption_key().decrypt(ciphertext.as_ref().unwrap()), None);
+        }
+
+        #[tokio::test]
+        async fn backfill_lists_branches_and_tags_until_finished() {
+            let repository_id = Uuid::new_v4();
+            let (main, feature, tagged) = ("a".repeat(40), "b".repeat(40), "c".repeat(40));
+            let mut service = service_with_mirror(repository_id);
+            service.git_client = MockGitClient::default()
+                .with_branch("refs/heads/feature", &feature)
+                .with_branch("refs/heads/main", &main)
+                .with_tag("v1.0.0", &tagged);
+            service
+                .repo_repo
+                .expect_get_by_id()
+                .withf(move |id, viewer_id| *id == repository_id && viewer_id.is_none())
+                .returning(move |id, _| {
+                    Ok(Some(crate::model::Repository {
+                        id,
+                        name: "myrepo".to_string(),
+                        owner_id: Uuid::new_v4(),
+                        owner_name: "owner".to_string(),
+                        owner_type: RepositoryOwnerType::User,
+                        visibility: RepositoryVisibility::Public,
+                        description: None,
+                        stars: 0,
+                        user_star: false,
+                        readonly: false,
+                        created_at: Utc::now(),
+                    }))
+                });
+            let cutover = service
+                .cutover_repository_mirror(request(Uuid::new_v4(), false, None))
+                .await
+                .unwrap();
+
+            let backfills = service.claim_cutover_backfills(8).await.unwrap();
+            assert_eq!(backfills.len(), 1);
+            assert_eq!(backfills[0].cutover_id, cutover.id);
+            let repository = &backfills[0].repository;
+            assert_eq!(repository.owner_name, "owner");
+            assert_eq!(repository.repo_name, "myrepo");
+
+            // The default branch is walked from its root, other refs from its
+            // head.
+            let updates: Vec<_> = repository
+                .ref_updates
+                .iter()
+                .map(|u| (u.ref_name.as_str(), u.old_sha.as_str(), u.new_sha.as_str()))
+                .collect();
+            assert_eq!(
+                updates,
+                [
+                    ("refs/heads/main", ZERO_SHA, main.as_str()),
+                    ("refs/heads/feature", main.as_str(), feature.as_str()),
+                    ("refs/tags/v1.0.0", main.as_str(), tagged.as_str()),
+                ]
+            );
+
+            service.finish_cutover_backfill(cutover.id).await.unwrap();
+            assert_eq!(service.migration_repo.finished_backfills(), [cutover.id]);
+            assert!(service.claim_cutover_backfills(8).await.unwrap().is_empty());
+        }
+
+        #[tokio::test]
+        async fn cutover_without_push_mirror_happens_once() {
+            let repository_id = Uuid::new_v4();
+            let service = service_with_mirror(repository_id);
+
+            let cutover = service
+                .cutover_repository_mirror(request(Uuid::new_v4(), false, None))
+                .await
+                .unwrap();
+            assert!(cutover.push_mirror_id.is_none());
+            assert!(service.migration_repo.cutover_push_mirrors().is_empty());
+
+            let err = service
+                .cutover_repository_mirror(request(Uuid::new_v4(), false, None))
+                .await
+                .unwrap_err();
+            assert!(matches!(err, MigrationError::NotFound(_)));
+            assert_eq!(service.migration_repo.cutovers().len(), 1);
+        }
+    }
+
     mod list_migrations {
         use super::*;
 
@@ -1306,4 +2427,576 @@ mod tests {
             );
         }
     }
+
+    mod import_history {
+        use std::sync::{Arc, Mutex};
+
+        use chrono::{DateTime, Duration};
+
+        use super::*;
+        use crate::{
+            dto::{GitHubAccount, GitHubDiscussion, GitHubDiscussionComment},
+            model::{
+                CommentSide, DiffStatus, ImportedQuestion, ImportedReview, Review, ReviewStatus,
+                Verdict,
+            },
+            service::{test_client::MockGitClient, test_common::create_repository},
+        };
+
+        fn at(minutes: i64) -> DateTime<Utc> {
+            DateTime::UNIX_EPOCH + Duration::minutes(minutes)
+        }
+
+        fn account(id: i64, login: &str) -> GitHubAccount {
+            GitHubAccount {
+                id,
+                login: login.to_string(),
+            }
+        }
+
+        fn user_json(id: i64, login: &str) -> serde_json::Value {
+            json!({ "id": id, "login": login })
+        }
+
+        /// Matches `octocat` (id 1) through its noreply address and whoever
+        /// committed as `dev@example.com`; nobody has a public email.
+        fn service_with_users(octocat_id: Uuid, developer_id: Uuid) -> Service {
+            let mut service = create_service();
+            service
+                .user_repo
+                .expect_get_by_emails()
+                .returning(move |emails| {
+                    Ok(emails
+                        .iter()
+                        .filter_map(|email| match email.as_str() {
+                            "1+octocat@users.noreply.github.com" => {
+                                Some((email.clone(), octocat_id))
+                            }
+                            "dev@example.com" => Some((email.clone(), developer_id)),
+                            _ => None,
+                        })
+                        .collect())
+                });
+            service
+                .github_client
+                .expect_get_user_public_email()
+                .returning(|_, _| Ok(None));
+            service
+        }
+
+        #[tokio::test]
+        async fn issues_and_discussions_become_questions_crediting_unmatched_authors() {
+            let octocat_id = Uuid::new_v4();
+            let migrator_id = Uuid::new_v4();
+            let mut service = service_with_users(octocat_id, Uuid::new_v4());
+            service
+                .github_client
+                .expect_list_issues()
+                .returning(|_, _| {
+                    Ok(vec![
+                        serde_json::from_value(json!({
+                            "number": 3,
+                            "title": "Crash on start",
+                            "body": "It crashes.",
+                            "user": user_json(1, "octocat"),
+                            "created_at": at(20),
+                            "updated_at": at(21),
+                        }))
+                        .unwrap(),
+                    ])
+                });
+            service
+                .github_client
+                .expect_list_issue_comments()
+                .returning(|_, _, _| {
+                    Ok(vec![
+                        serde_json::from_value(json!({
+                            "body": "Same here.",
+                            "user": user_json(2, "stranger"),
+                            "created_at": at(22),
+                            "updated_at": at(22),
+                        }))
+                        .unwrap(),
+                    ])
+                });
+            service
+                .github_client
+                .expect_list_discussions()
+                .returning(|_, _| {
+                    Ok(vec![GitHubDiscussion {
+                        number: 1,
+                        title: "x".repeat(300),
+                        body: "How do I build it?".to_string(),
+                        author: None,
+                        created_at: at(10),
+                        updated_at: at(10),
+                        comments: vec![GitHubDiscussionComment {
+                            body: "Run make.".to_string(),
+                            author: Some(account(1, "octocat")),
+                            created_at: at(11),
+                            updated_at: at(11),
+                            replies: vec![GitHubDiscussionComment {
+                                body: "Thanks!".to_string(),
+                                author: Some(account(2, "stranger")),
+                                created_at: at(12),
+                                updated_at: at(12),
+                                replies: Vec::new(),
+                            }],
+                        }],
+                    }])
+                });
+            service
+                .github_client
+                .expect_list_pull_requests()
+                .returning(|_, _| Ok(Vec::new()));
+            let imported: Arc<Mutex<Vec<ImportedQuestion>>> = Arc::default();
+            let recorded = imported.clone();
+            service
+                .question_repo
+                .expect_import_question()
+                .returning(move |_, question| {
+                    recorded.lock().unwrap().push(question.clone());
+                    Ok(Some(Uuid::new_v4()))
+                });
+
+            let repository = create_repository(
+                migrator_id,
+                RepositoryOwnerType::User,
+                RepositoryVisibility::Public,
+            );
+            service
+                .import_history(
+                    Uuid::new_v4(),
+                    "token",
+                    "octocat/hello",
+                    "owner",
+                    &repository,
+                    migrator_id,
+                )
+                .await
+                .unwrap();
+
+            let questions = imported.lock().unwrap().clone();
+            // Oldest first: the discussion, then the issue.
+            assert_eq!(
+                questions.iter().map(|q| q.number).collect::<Vec<_>>(),
+                vec![1, 3]
+            );
+
+            let discussion = &questions[0];
+            assert_eq!(discussion.title.chars().count(), 255);
+            assert_eq!(discussion.author_id, migrator_id);
+            assert_eq!(
+                discussion.body,
+                "*Originally posted by @ghost on GitHub*\n\nHow do I build it?"
+            );
+            assert_eq!(discussion.answers[0].author_id, octocat_id);
+            assert_eq!(discussion.answers[0].body, "Run make.");
+            let reply = &discussion.answers[0].comments[0];
+            assert_eq!(reply.author_id, migrator_id);
+            assert_eq!(
+                reply.body,
+                "*Originally posted by @stranger on GitHub*\n\nThanks!"
+            );
+
+            let issue = &questions[1];
+            assert_eq!(issue.author_id, octocat_id);
+            assert_eq!(issue.body, "It crashes.");
+            assert_eq!(issue.created_at, at(20));
+            assert_eq!(issue.answers.len(), 1);
+            assert_eq!(issue.answers[0].author_id, migrator_id);
+            assert_eq!(service.migration_repo.import_progress(), Some((2, 0)));
+        }
+
+        #[tokio::test]
+        async fn merged_pull_request_becomes_closed_review_with_verdicts_and_threads() {
+            let octocat_id = Uuid::new_v4();
+            let developer_id = Uuid::new_v4();
+            let migrator_id = Uuid::new_v4();
+            let (first, second) = ("a".repeat(40), "b".repeat(40));
+            let mut service = service_with_users(octocat_id, developer_id);
+            service
+                .github_client
+                .expect_list_issues()
+                .returning(|_, _| Ok(Vec::new()));
+            service
+                .github_client
+                .expect_list_discussions()
+                .returning(|_, _| Ok(Vec::new()));
+            service
+                .github_client
+                .expect_list_pull_requests()
+                .returning(|_, _| {
+                    let pull_request = |number: i32, merged_at: Option<DateTime<Utc>>| {
+                        serde_json::from_value(json!({
+                            "number": number,
+                            "title": "Add feature",
+                            "body": "Adds it.",
+                            "user": user_json(7, "developer"),
+                            "state": "closed",
+                            "draft": false,
+                            "merged_at": merged_at,
+                            "base": { "ref": "main" },
+                            "created_at": at(0),
+                            "updated_at": at(60),
+                        }))
+                        .unwrap()
+                    };
+                    // #4 was closed without merging and is skipped.
+                    Ok(vec![pull_request(4, None), pull_request(5, Some(at(60)))])
+                });
+            let (first_sha, second_sha) = (first.clone(), second.clone());
+            service
+                .github_client
+                .expect_list_pull_request_commits()
+                .withf(|_, _, number| *number == 5)
+                .returning(move |_, _, _| {
+                    Ok([
+                        (&first_sha, "c".repeat(40)),
+                        (&second_sha, first_sha.clone()),
+                    ]
+                    .into_iter()
+                    .map(|(sha, parent)| {
+                        serde_json::from_value(json!({
+                            "sha": sha,
+                            "commit": {
+                                "message": format!("commit {sha}"),
+                                "author": { "email": "dev@example.com", "date": at(1) },
+                            },
+                            "author": user_json(7, "developer"),
+                            "parents": [{ "sha": parent }],
+                        }))
+                        .unwrap()
+                    })
+                    .collect())
+                });
+            let review_at_second = second.clone();
+            service
+                .github_client
+                .expect_list_pull_request_reviews()
+                .returning(move |_, _, _| {
+                    let review = |state: &str, body: &str, minutes: i64| {
+                        serde_json::from_value(json!({
+                            "user": user_json(1, "octocat"),
+                            "body": body,
+                            "state": state,
+                            "commit_id": review_at_second,
+                            "submitted_at": at(minutes),
+                        }))
+                        .unwrap()
+                    };
+                    Ok(vec![
+                        review("CHANGES_REQUESTED", "Needs tests.", 30),
+                        review("APPROVED", "", 40),
+                    ])
+                });
+            let commented_on = first.clone();
+            service
+                .github_client
+                .expect_list_pull_request_comments()
+                .returning(move |_, _, _| {
+                    Ok(vec![
+                        serde_json::from_value(json!({
+                            "id": 11,
+                            "in_reply_to_id": 10,
+                            "user": user_json(7, "developer"),
+                            "body": "Fixed.",
+                            "path": "src/lib.rs",
+                            "commit_id": commented_on,
+                            "line": 4,
+                            "start_line": null,
+                            "side": "RIGHT",
+                            "created_at": at(25),
+                            "updated_at": at(25),
+                        }))
+                        .unwrap(),
+                        serde_json::from_value(json!({
+                            "id": 10,
+                            "in_reply_to_id": null,
+                            "user": user_json(1, "octocat"),
+                            "body": "Off by one?",
+                            "path": "src/lib.rs",
+                            "commit_id": commented_on,
+                            "line": 4,
+                            "start_line": 2,
+                            "side": "LEFT",
+                            "created_at": at(20),
+                            "updated_at": at(20),
+                        }))
+                        .unwrap(),
+                    ])
+                });
+            service
+                .github_client
+                .expect_list_issue_comments()
+                .returning(|_, _, _| {
+                    Ok(vec![
+                        serde_json::from_value(json!({
+                            "body": "Ship it",
+                            "user": user_json(9, "bystander"),
+                            "created_at": at(50),
+                            "updated_at": at(50),
+                        }))
+                        .unwrap(),
+                    ])
+                });
+            let review_id = Uuid::new_v4();
+            let imported: Arc<Mutex<Vec<ImportedReview>>> = Arc::default();
+            let recorded = imported.clone();
+            service
+                .review_repo
+                .expect_import_review()
+                .returning(move |_, review| {
+                    recorded.lock().unwrap().push(review.clone());
+                    Ok(Some(review_id))
+                });
+
+            let repository = create_repository(
+                migrator_id,
+                RepositoryOwnerType::User,
+                RepositoryVisibility::Public,
+            );
+            service
+                .import_history(
+                    Uuid::new_v4(),
+                    "token",
+                    "octocat/hello",
+                    "owner",
+                    &repository,
+                    migrator_id,
+                )
+                .await
+                .unwrap();
+
+            let reviews = imported.lock().unwrap().clone();
+            assert_eq!(reviews.len(), 1);
+            let review = &reviews[0];
+            assert_eq!(review.number, 5);
+            assert_eq!(review.author_id, developer_id);
+            assert_eq!(review.status, ReviewStatus::Closed);
+            assert_eq!(review.target_branch, "main");
+            assert_eq!(
+                review
+                    .diffs
+                    .iter()
+                    .map(|d| (d.position, d.commit_hash.clone(), d.parent_hash.clone()))
+                    .collect::<Vec<_>>(),
+                vec![
+                    (1, first.clone(), "c".repeat(40)),
+                    (2, second.clone(), first.clone()),
+                ]
+            );
+            assert!(review.diffs.iter().all(|d| d.status == DiffStatus::Merged));
+
+            // Only the latest verdict counts, on every diff.
+            assert_eq!(review.reviewer_ids, vec![octocat_id]);
+            assert_eq!(review.verdicts.len(), 2);
+            assert!(
+                review
+                    .verdicts
+                    .iter()
+                    .all(|v| v.reviewer_id == octocat_id && v.verdict == Verdict::Approved)
+            );
+
+            // Inline thread, review summary, then the conversation comment.
+            let bodies: Vec<_> = review.comments.iter().map(|c| c.body.as_str()).collect();
+            assert_eq!(
+                bodies,
+                vec![
+                    "Off by one?",
+                    "Fixed.",
+                    "Needs tests.",
+                    "*Originally posted by @bystander on GitHub*\n\nShip it",
+                ]
+            );
+            let thread = &review.comments[0];
+            assert_eq!(thread.diff_position, 1);
+            assert_eq!(thread.file_path.as_deref(), Some("src/lib.rs"));
+            assert_eq!(
+                (thread.line_number_start, thread.line_number_end),
+                (Some(2), Some(4))
+            );
+            assert_eq!(thread.side, Some(CommentSide::Old));
+            assert_eq!(review.comments[1].parent, Some(0));
+            assert_eq!(review.comments[1].author_id, developer_id);
+            assert_eq!(review.comments[2].diff_position, 2);
+            assert_eq!(review.comments[3].author_id, migrator_id);
+
+            assert_eq!(
+                service.git_client.fetched_refs(),
+                vec![(
+                    "owner/myrepo".to_string(),
+                    "refs/pull/5/head".to_string(),
+                    "refs/pull/5/head".to_string()
+                )]
+            );
+            let review_ref_id = &review_id.to_string()[..8];
+            assert_eq!(
+                service.git_client.updated_refs(),
+                vec![
+                    (
+                        format!("refs/reviews/{review_ref_id}/diffs/1/revisions/1"),
+                        first.clone()
+                    ),
+                    (
+                        format!("refs/reviews/{review_ref_id}/diffs/1/current"),
+                        first.clone()
+                    ),
+                    (
+                        format!("refs/reviews/{review_ref_id}/diffs/2/revisions/1"),
+                        second.clone()
+                    ),
+                    (
+                        format!("refs/reviews/{review_ref_id}/diffs/2/current"),
+                        second.clone()
+                    ),
+                    (format!("refs/reviews/{review_ref_id}/head"), second.clone()),
+                ]
+            );
+            assert_eq!(service.migration_repo.import_progress(), Some((0, 1)));
+        }
+
+        #[tokio::test]
+        async fn pull_requests_with_missing_commits_are_skipped_and_reruns_restore_refs() {
+            let migrator_id = Uuid::new_v4();
+            let (missing, present) = ("a".repeat(40), "b".repeat(40));
+            let mut service = service_with_users(Uuid::new_v4(), Uuid::new_v4());
+            service.git_client = MockGitClient::default().with_missing_commit(&missing);
+            service
+                .github_client
+                .expect_list_issues()
+                .returning(|_, _| Ok(Vec::new()));
+            service
+                .github_client
+                .expect_list_discussions()
+                .returning(|_, _| Ok(Vec::new()));
+            service
+                .github_client
+                .expect_list_pull_requests()
+                .returning(|_, _| {
+                    Ok([5, 6]
+                        .into_iter()
+                        .map(|number| {
+                            serde_json::from_value(json!({
+                                "number": number,
+                                "title": "Add feature",
+                                "body": "",
+                                "user": user_json(7, "developer"),
+                                "state": "open",
+                                "draft": false,
+                                "merged_at": null,
+                                "base": { "ref": "main" },
+                                "created_at": at(0),
+                                "updated_at": at(0),
+                            }))
+                            .unwrap()
+                        })
+                        .collect())
+                });
+            let (missing_sha, present_sha) = (missing.clone(), present.clone());
+            service
+                .github_client
+                .expect_list_pull_request_commits()
+                .returning(move |_, _, number| {
+                    let sha = if number == 5 {
+                        &missing_sha
+                    } else {
+                        &present_sha
+                    };
+                    Ok(vec![
+                        serde_json::from_value(json!({
+                            "sha": sha,
+                            "commit": {
+                                "message": "change",
+                                "author": { "email": "dev@example.com", "date": at(1) },
+                            },
+                            "author": user_json(7, "developer"),
+                            "parents": [{ "sha": "c".repeat(40) }],
+                        }))
+                        .unwrap(),
+                    ])
+                });
+            service
+                .github_client
+                .expect_list_pull_request_reviews()
+                .returning(|_, _, _| Ok(Vec::new()));
+            service
+                .github_client
+                .expect_list_pull_request_comments()
+                .returning(|_, _, _| Ok(Vec::new()));
+            service
+                .github_client
+                .expect_list_issue_comments()
+                .returning(|_, _, _| Ok(Vec::new()));
+
+            // #6 was imported by an earlier run that failed before its refs.
+            service
+                .review_repo
+                .expect_import_review()
+                .withf(|_, review| review.number == 6)
+                .times(1)
+                .returning(|_, _| Ok(None));
+            let review_id = Uuid::new_v4();
+            service
+                .review_repo
+                .expect_get_review_by_number()
+                .withf(|_, _, number| *number == 6)
+                .returning(move |_, _, number| {
+                    Ok(Some(Review {
+                        id: review_id,
+                        repository_id: Uuid::new_v4(),
+                        number,
+                        author_id: Uuid::new_v4(),
+                        title: "Add feature".to_string(),
+                        description: String::new(),
+                        target_branch: "main".to_string(),
+                        status: ReviewStatus::Open,
+                        created_at: at(0),
+                        updated_at: at(0),
+                        author: None,
+                        diffs: None,
+                        reviewers: None,
+                        comments: None,
+                    }))
+                });
+
+            let repository = create_repository(
+                migrator_id,
+                RepositoryOwnerType::User,
+                RepositoryVisibility::Public,
+            );
+            let skipped = service
+                .import_history(
+                    Uuid::new_v4(),
+                    "token",
+                    "octocat/hello",
+                    "owner",
+                    &repository,
+                    migrator_id,
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(skipped, vec![5]);
+            let review_ref_id = &review_id.to_string()[..8];
+            assert_eq!(
+                service.git_client.updated_refs(),
+                vec![
+                    (
+                        format!("refs/reviews/{review_ref_id}/diffs/1/revisions/1"),
+                        present.clone()
+                    ),
+                    (
+                        format!("refs/reviews/{review_ref_id}/diffs/1/current"),
+                        present.clone()
+                    ),
+                    (
+                        format!("refs/reviews/{review_ref_id}/head"),
+                        present.clone()
+                    ),
+                ]
+            );
+            assert_eq!(service.migration_repo.import_progress(), Some((0, 0)));
+        }
+    }
 }
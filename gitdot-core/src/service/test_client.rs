@@ -14,12 +14,15 @@ use uuid::Uuid;
 use crate::{
     client::{GitClient, RedisClient, TokenClient},
     dto::{
-        CommitDiffResponse, InitialCommitFile, RepositoryBlobResponse, RepositoryCommitResponse,
-        RepositoryPathsResponse,
+        ArchiveFormat, CodeSearchQuery, CodeSearchResponse, CommitAuthorResponse,
+        CommitDiffResponse, DiffOptions, InitialCommitFile, MirrorCredentials, MirrorRefUpdate,
+        RepositoryArchiveResponse, RepositoryBlameResponse, RepositoryBlobResponse,
+        RepositoryCommitResponse, RepositoryDiffResponse, RepositoryPathsResponse, TagResponse,
+        TaggerResponse,
     },
     error::{GitError, RedisError, TokenError},
     model::TokenType,
-    util::git::GitHookType,
+    util::git::{DEFAULT_BRANCH, GitHookType},
 };
 
 mock! {
@@ -50,6 +53,14 @@ mock! {
         async fn get_installation(&self, installation_id: u64) -> Result<octocrab::models::Installation, crate::error::GitHubError>;
         async fn get_installation_access_token(&self, installation_id: u64) -> Result<String, crate::error::GitHubError>;
         async fn list_installation_repositories(&self, installation_id: u64) -> Result<octocrab::models::InstallationRepositories, crate::error::GitHubError>;
+        async fn list_issues(&self, token: &str, full_name: &str) -> Result<Vec<crate::dto::GitHubIssue>, crate::error::GitHubError>;
+        async fn list_issue_comments(&self, token: &str, full_name: &str, number: i32) -> Result<Vec<crate::dto::GitHubIssueComment>, crate::error::GitHubError>;
+        async fn list_discussions(&self, token: &str, full_name: &str) -> Result<Vec<crate::dto::GitHubDiscussion>, crate::error::GitHubError>;
+        async fn list_pull_requests(&self, token: &str, full_name: &str) -> Result<Vec<crate::dto::GitHubPullRequest>, crate::error::GitHubError>;
+        async fn list_pull_request_commits(&self, token: &str, full_name: &str, number: i32) -> Result<Vec<crate::dto::GitHubPullRequestCommit>, crate::error::GitHubError>;
+        async fn list_pull_request_reviews(&self, token: &str, full_name: &str, number: i32) -> Result<Vec<crate::dto::GitHubPullRequestReview>, crate::error::GitHubError>;
+        async fn list_pull_request_comments(&self, token: &str, full_name: &str, number: i32) -> Result<Vec<crate::dto::GitHubReviewComment>, crate::error::GitHubError>;
+        async fn get_user_public_email(&self, token: &str, login: &str) -> Result<Option<String>, crate::error::GitHubError>;
         fn verify_webhook_signature(&self, body: &[u8], signature_header: &str) -> Result<(), crate::error::GitHubError>;
     }
 }
@@ -75,18 +86,52 @@ mock! {
     #[async_trait]
     impl crate::client::R2Client for R2Client {
         async fn upload_object(&self, key: &str, body: Bytes) -> Result<(), crate::error::R2Error>;
+        async fn get_object(&self, key: &str) -> Result<Option<Bytes>, crate::error::R2Error>;
+        async fn delete_object(&self, key: &str) -> Result<(), crate::error::R2Error>;
+    }
+}
+
+mock! {
+    pub SignatureClient {}
+    impl Clone for SignatureClient {
+        fn clone(&self) -> Self;
+    }
+    #[async_trait]
+    impl crate::client::SignatureClient for SignatureClient {
+        async fn verify_signature(&self, key: &crate::model::SigningKey, signature: &str, payload: &[u8]) -> Result<bool, crate::error::SignatureError>;
+        async fn verify_ssh_signature(&self, public_key: &str, namespace: &str, signature: &str, message: &[u8]) -> Result<bool, crate::error::SignatureError>;
     }
 }
 
 /// `mockall` can't generate a mock for this trait: several methods take
 /// `Option<&str>`, which neither elides nor accepts an explicit lifetime through
 /// `async_trait` + `mock!`.
+/// `(repo, url, credentials)` of a mirror sync or push.
+type MirrorCall = (String, String, Option<MirrorCredentials>);
+
 #[derive(Clone, Default)]
 pub struct MockGitClient {
     renames: Arc<Mutex<Vec<(String, String)>>>,
     repo_exists: bool,
     created_repos: Arc<Mutex<Vec<(String, String)>>>,
     deleted_repos: Arc<Mutex<Vec<(String, String)>>>,
+    maintained_repos: Arc<Mutex<Vec<(String, String)>>>,
+    maintain_fails: bool,
+    indexed_repos: Arc<Mutex<Vec<(String, String)>>>,
+    blame: Option<RepositoryBlameResponse>,
+    resolved_sha: Option<String>,
+    tags: Arc<Mutex<Vec<TagResponse>>>,
+    mirror_updates: Vec<MirrorRefUpdate>,
+    mirror_fails: bool,
+    fetched_refs: Arc<Mutex<Vec<(String, String, String)>>>,
+    deleted_refs: Arc<Mutex<Vec<(String, String)>>>,
+    updated_refs: Arc<Mutex<Vec<(String, String)>>>,
+    missing_commits: Vec<String>,
+    synced_mirrors: Arc<Mutex<Vec<MirrorCall>>>,
+    pushed_mirrors: Arc<Mutex<Vec<MirrorCall>>>,
+    branches: Vec<(String, String)>,
+    installed_hooks: Arc<Mutex<Vec<(String, String)>>>,
+    signatures: HashMap<String, (String, Vec<u8>)>,
 }
 
 impl MockGitClient {
@@ -107,6 +152,149 @@ impl MockGitClient {
     pub fn deleted_repos(&self) -> Vec<(String, String)> {
         self.deleted_repos.lock().unwrap().clone()
     }
+
+    /// Makes [`GitClient::maintain_repo`] fail for every repo.
+    pub fn with_maintain_failing(mut self) -> Self {
+        self.maintain_fails = true;
+        self
+    }
+
+    pub fn maintained_repos(&self) -> Vec<(String, String)> {
+        self.maintained_repos.lock().unwrap().clone()
+    }
+
+    pub fn indexed_repos(&self) -> Vec<(String, String)> {
+        self.indexed_repos.lock().unwrap().clone()
+    }
+
+    /// Sets what [`GitClient::blame`] returns for every file.
+    pub fn with_blame(mut self, blame: RepositoryBlameResponse) -> Self {
+        self.blame = Some(blame);
+        self
+    }
+
+    /// Sets what [`GitClient::resolve_ref_sha`] returns for every ref.
+    pub fn with_resolved_sha(mut self, sha: &str) -> Self {
+        self.resolved_sha = Some(sha.to_string());
+        self
+    }
+
+    /// Adds a lightweight tag of `commit_sha` to every repo.
+    pub fn with_tag(self, name: &str, commit_sha: &str) -> Self {
+        self.tags
+            .lock()
+            .unwrap()
+            .push(mock_tag(name, commit_sha, None, None));
+        self
+    }
+
+    pub fn tags(&self) -> Vec<TagResponse> {
+        self.tags.lock().unwrap().clone()
+    }
+
+    /// Sets what [`GitClient::sync_mirror`] reports changed for every repo.
+    pub fn with_mirror_update(mut self, ref_name: &str, old_sha: &str, new_sha: &str) -> Self {
+        self.mirror_updates.push(MirrorRefUpdate {
+            ref_name: ref_name.to_string(),
+            old_sha: old_sha.to_string(),
+            new_sha: new_sha.to_string(),
+        });
+        self
+    }
+
+    /// Makes [`GitClient::sync_mirror`], [`GitClient::push_mirror`] and
+    /// [`GitClient::fetch_ref`] fail for every repo.
+    pub fn with_mirror_failing(mut self) -> Self {
+        self.mirror_fails = true;
+        self
+    }
+
+    /// `(repo, ref_name, source)` for every [`GitClient::fetch_ref`] call.
+    pub fn fetched_refs(&self) -> Vec<(String, String, String)> {
+        self.fetched_refs.lock().unwrap().clone()
+    }
+
+    /// `(repo, ref_name)` for every [`GitClient::delete_ref`] call.
+    pub fn deleted_refs(&self) -> Vec<(String, String)> {
+        self.deleted_refs.lock().unwrap().clone()
+    }
+
+    /// `(ref_name, sha)` for every [`GitClient::update_ref`] call.
+    pub fn updated_refs(&self) -> Vec<(String, String)> {
+        self.updated_refs.lock().unwrap().clone()
+    }
+
+    /// Makes [`GitClient::has_commit`] report `sha` as missing from every
+    /// repo; all other commits exist.
+    pub fn with_missing_commit(mut self, sha: &str) -> Self {
+        self.missing_commits.push(sha.to_string());
+        self
+    }
+
+    /// `(repo, url, credentials)` for every successful
+    /// [`GitClient::sync_mirror`] call.
+    pub fn synced_mirrors(&self) -> Vec<MirrorCall> {
+        self.synced_mirrors.lock().unwrap().clone()
+    }
+
+    /// `(repo, url, credentials)` for every successful
+    /// [`GitClient::push_mirror`] call.
+    pub fn pushed_mirrors(&self) -> Vec<MirrorCall> {
+        self.pushed_mirrors.lock().unwrap().clone()
+    }
+
+    /// Adds a branch `ref_name` at `sha` to what [`GitClient::list_branches`]
+    /// returns for every repo.
+    pub fn with_branch(mut self, ref_name: &str, sha: &str) -> Self {
+        self.branches.push((ref_name.to_string(), sha.to_string()));
+        self
+    }
+
+    /// `(repo, hook)` for every [`GitClient::install_hook`] call since the
+    /// repo's hooks were last emptied.
+    pub fn installed_hooks(&self) -> Vec<(String, String)> {
+        self.installed_hooks.lock().unwrap().clone()
+    }
+
+    /// Signs the commit `sha` in every repo with `signature` over `payload`,
+    /// as read by [`GitClient::get_commit_signature`].
+    pub fn with_signature(mut self, sha: &str, signature: &str, payload: &[u8]) -> Self {
+        self.signatures
+            .insert(sha.to_string(), (signature.to_string(), payload.to_vec()));
+        self
+    }
+}
+
+fn mock_tag(
+    name: &str,
+    commit_sha: &str,
+    message: Option<&str>,
+    tagger: Option<(&str, &str)>,
+) -> TagResponse {
+    let now = Utc::now();
+    TagResponse {
+        name: name.to_string(),
+        sha: commit_sha.to_string(),
+        commit: RepositoryCommitResponse {
+            sha: commit_sha.to_string(),
+            parent_sha: None,
+            message: "commit".to_string(),
+            date: now,
+            author: CommitAuthorResponse {
+                id: None,
+                name: "alice".to_string(),
+                email: "alice@example.com".to_string(),
+            },
+        },
+        annotated: message.is_some(),
+        message: message.map(str::to_string),
+        tagger: tagger.map(|(name, email)| TaggerResponse {
+            name: name.to_string(),
+            email: email.to_string(),
+            date: now,
+        }),
+        signature: None,
+    }
 }
 
 #[async_trait]
@@ -139,18 +327,80 @@ impl GitClient for MockGitClient {
     async fn mirror_repo(&self, _owner: &str, _repo: &str, _url: &str) -> Result<(), GitError> {
         unimplemented!("MockGitClient::mirror_repo is not stubbed")
     }
+    async fn maintain_repo(&self, owner: &str, repo: &str) -> Result<(), GitError> {
+        self.maintained_repos
+            .lock()
+            .unwrap()
+            .push((owner.to_string(), repo.to_string()));
+        if self.maintain_fails {
+            return Err(GitError::NotFound(format!("{owner}/{repo}")));
+        }
+        Ok(())
+    }
     async fn get_default_ref(&self, _owner: &str, _repo: &str) -> Result<String, GitError> {
-        unimplemented!("MockGitClient::get_default_ref is not stubbed")
+        Ok(format!("refs/heads/{DEFAULT_BRANCH}"))
     }
     async fn fetch_ref(
         &self,
-        _owner: &str,
-        _repo: &str,
+        owner: &str,
+        repo: &str,
         _url: &str,
-        _ref_name: &str,
-        _sha: &str,
+        ref_name: &str,
+        source: &str,
+    ) -> Result<(), GitError> {
+        if self.mirror_fails {
+            return Err(GitError::NotFound(format!("{owner}/{repo}")));
+        }
+        self.fetched_refs.lock().unwrap().push((
+            format!("{owner}/{repo}"),
+            ref_name.to_string(),
+            source.to_string(),
+        ));
+        Ok(())
+    }
+    async fn has_commit(&self, _owner: &str, _repo: &str, sha: &str) -> Result<bool, GitError> {
+        Ok(!self.missing_commits.iter().any(|missing| missing == sha))
+    }
+    async fn sync_mirror(
+        &self,
+        owner: &str,
+        repo: &str,
+        url: &str,
+        credentials: Option<&MirrorCredentials>,
+    ) -> Result<Vec<MirrorRefUpdate>, GitError> {
+        if self.mirror_fails {
+            return Err(GitError::NotFound(format!("{owner}/{repo}")));
+        }
+        self.synced_mirrors.lock().unwrap().push((
+            format!("{owner}/{repo}"),
+            url.to_string(),
+            credentials.cloned(),
+        ));
+        Ok(self.mirror_updates.clone())
+    }
+    async fn push_mirror(
+        &self,
+        owner: &str,
+        repo: &str,
+        url: &str,
+        credentials: Option<&MirrorCredentials>,
     ) -> Result<(), GitError> {
-        unimplemented!("MockGitClient::fetch_ref is not stubbed")
+        if self.mirror_fails {
+            return Err(GitError::NotFound(format!("{owner}/{repo}")));
+        }
+        self.pushed_mirrors.lock().unwrap().push((
+            format!("{owner}/{repo}"),
+            url.to_string(),
+            credentials.cloned(),
+        ));
+        Ok(())
+    }
+    async fn delete_ref(&self, owner: &str, repo: &str, ref_name: &str) -> Result<(), GitError> {
+        self.deleted_refs
+            .lock()
+            .unwrap()
+            .push((format!("{owner}/{repo}"), ref_name.to_string()));
+        Ok(())
     }
     async fn create_ref(
         &self,
@@ -159,16 +409,62 @@ impl GitClient for MockGitClient {
         _ref_name: &str,
         _sha: &str,
     ) -> Result<(), GitError> {
-        unimplemented!("MockGitClient::create_ref is not stubbed")
+        Ok(())
     }
     async fn update_ref(
         &self,
         _owner: &str,
         _repo: &str,
-        _ref_name: &str,
-        _sha: &str,
+        ref_name: &str,
+        sha: &str,
     ) -> Result<(), GitError> {
-        unimplemented!("MockGitClient::update_ref is not stubbed")
+        self.updated_refs
+            .lock()
+            .unwrap()
+            .push((ref_name.to_string(), sha.to_string()));
+        Ok(())
+    }
+    async fn list_branches(
+        &self,
+        _owner: &str,
+        _repo: &str,
+    ) -> Result<Vec<(String, String)>, GitError> {
+        Ok(self.branches.clone())
+    }
+    async fn list_tags(&self, _owner: &str, _repo: &str) -> Result<Vec<TagResponse>, GitError> {
+        Ok(self.tags())
+    }
+    async fn get_tag(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        name: &str,
+    ) -> Result<Option<TagResponse>, GitError> {
+        Ok(self.tags().into_iter().find(|tag| tag.name == name))
+    }
+    async fn create_tag(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        name: &str,
+        sha: &str,
+        message: Option<&str>,
+        tagger_name: &str,
+        tagger_email: &str,
+    ) -> Result<TagResponse, GitError> {
+        let tagger = message.map(|_| (tagger_name, tagger_email));
+        let tag = mock_tag(name, sha, message, tagger);
+        self.tags.lock().unwrap().push(tag.clone());
+        Ok(tag)
+    }
+    async fn delete_tag(&self, _owner: &str, _repo: &str, name: &str) -> Result<(), GitError> {
+        let mut tags = self.tags.lock().unwrap();
+        let count = tags.len();
+        tags.retain(|tag| tag.name != name);
+        if tags.len() == count {
+            return Err(GitError::NotFound(name.to_string()));
+        }
+        Ok(())
     }
     async fn get_repo_blob(
         &self,
@@ -196,6 +492,28 @@ impl GitClient for MockGitClient {
     ) -> Result<RepositoryPathsResponse, GitError> {
         unimplemented!("MockGitClient::get_repo_paths is not stubbed")
     }
+    async fn blame(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _ref_name: &str,
+        _path: &str,
+        _ignore_revs_file: Option<&str>,
+    ) -> Result<RepositoryBlameResponse, GitError> {
+        Ok(self
+            .blame
+            .clone()
+            .expect("MockGitClient::blame is not stubbed"))
+    }
+    async fn get_repo_archive(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _ref_name: &str,
+        _format: ArchiveFormat,
+    ) -> Result<RepositoryArchiveResponse, GitError> {
+        unimplemented!("MockGitClient::get_repo_archive is not stubbed")
+    }
     async fn get_repo_commit(
         &self,
         _owner: &str,
@@ -222,13 +540,60 @@ impl GitClient for MockGitClient {
     ) -> Result<Vec<RepositoryCommitResponse>, GitError> {
         unimplemented!("MockGitClient::rev_list is not stubbed")
     }
+    async fn get_commit_signature(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        sha: &str,
+    ) -> Result<Option<(String, Vec<u8>)>, GitError> {
+        Ok(self.signatures.get(sha).cloned())
+    }
+    async fn index_code(&self, owner: &str, repo: &str) -> Result<Option<String>, GitError> {
+        self.indexed_repos
+            .lock()
+            .unwrap()
+            .push((owner.to_string(), repo.to_string()));
+        Ok(Some("a".repeat(40)))
+    }
+    async fn search_code(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _query: CodeSearchQuery,
+        _limit: usize,
+    ) -> Result<CodeSearchResponse, GitError> {
+        unimplemented!("MockGitClient::search_code is not stubbed")
+    }
+    async fn merge_base(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _left_sha: &str,
+        _right_sha: &str,
+    ) -> Result<Option<String>, GitError> {
+        unimplemented!("MockGitClient::merge_base is not stubbed")
+    }
+    async fn get_repo_diff(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _left_sha: Option<&str>,
+        _right_sha: &str,
+        _options: DiffOptions,
+        _offset: usize,
+        _limit: usize,
+    ) -> Result<RepositoryDiffResponse, GitError> {
+        unimplemented!("MockGitClient::get_repo_diff is not stubbed")
+    }
     async fn resolve_ref_sha(
         &self,
         _owner: &str,
         _repo: &str,
-        _ref_name: &str,
+        ref_name: &str,
     ) -> Result<String, GitError> {
-        unimplemented!("MockGitClient::resolve_ref_sha is not stubbed")
+        self.resolved_sha
+            .clone()
+            .ok_or_else(|| GitError::NotFound(ref_name.to_string()))
     }
     async fn get_commit_patch_id(
         &self,
@@ -261,14 +626,22 @@ impl GitClient for MockGitClient {
     async fn install_hook(
         &self,
         _owner: &str,
-        _repo: &str,
-        _hook_type: GitHookType,
+        repo: &str,
+        hook_type: GitHookType,
         _hook_script: &str,
     ) -> Result<(), GitError> {
+        self.installed_hooks
+            .lock()
+            .unwrap()
+            .push((repo.to_string(), hook_type.as_str().to_string()));
         Ok(())
     }
-    async fn empty_hooks(&self, _owner: &str, _repo: &str) -> Result<(), GitError> {
-        unimplemented!("MockGitClient::empty_hooks is not stubbed")
+    async fn empty_hooks(&self, _owner: &str, repo: &str) -> Result<(), GitError> {
+        self.installed_hooks
+            .lock()
+            .unwrap()
+            .retain(|(hooked_repo, _)| hooked_repo != repo);
+        Ok(())
     }
 }
 
@@ -114,6 +114,7 @@ pub fn create_commit(sha: &str) -> Commit {
         created_at: Utc::now(),
         review_number: None,
         diff_position: None,
+        signature_status: None,
         diffs: vec![],
         repository: CommitRepository {
             id: Uuid::new_v4(),
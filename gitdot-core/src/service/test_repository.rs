@@ -1,4 +1,7 @@
-use std::sync::{Arc, Mutex};
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
 
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
@@ -8,14 +11,20 @@ use uuid::Uuid;
 
 use crate::{
     model::{
-        AccessToken, Answer, AuthCode, AuthProvider, Comment, CommentSide, Commit, CommitDiff,
-        DeviceAuthorization, Diff, DiffStatus, EmailVerificationCode, GitHubInstallation,
-        GitHubInstallationType, Migration, MigrationOriginService,
-        MigrationRepository as MigrationRepositoryModel, MigrationRepositoryStatus,
-        MigrationStatus, Organization, OrganizationMember, OrganizationRole, Question, Repository,
-        RepositoryOwnerType, RepositoryStar, RepositoryVisibility, Review, ReviewComment,
-        ReviewStatus, Reviewer, Revision, Session, TokenType, User, UserEmail, UserOrganization,
-        Verdict, VoteResult, VoteTarget,
+        AccessToken, Answer, AuthCode, AuthProvider, BranchProtection, BranchProtectionRules,
+        Build, BuildReviewLink, BuildTrigger, BuildWithStats, Comment, CommentSide, Commit,
+        CommitDiff, CommitSignatureStatus, DeviceAuthorization, Diff, DiffStatus,
+        EmailVerificationCode, GitHubInstallation, GitHubInstallationType, ImportedQuestion,
+        ImportedReview, MaintenanceStatus, MergeRequirements, Migration, MigrationMirror,
+        MigrationOriginService, MigrationRepository as MigrationRepositoryModel,
+        MigrationRepositoryStatus, MigrationStatus, MirrorCutover, MirrorDirection,
+        MirrorRunStatus, Organization, OrganizationMember, OrganizationRole, Question, Release,
+        ReleaseAsset, Repository, RepositoryCollaborator, RepositoryInvitation,
+        RepositoryMaintenance, RepositoryMirror, RepositoryMirrorRun, RepositoryOwnerType,
+        RepositoryRole, RepositoryStar, RepositoryVisibility, Review, ReviewComment, ReviewStatus,
+        Reviewer, Revision, Session, SigningKey, SigningKeyType, SshKey, Team, TeamMember,
+        TeamRepositoryAccess, TokenScope, TokenType, User, UserEmail, UserOrganization, Verdict,
+        VoteResult, VoteTarget,
     },
     repository::{AuthCodeVerification, EmailCodeVerification},
 };
@@ -43,6 +52,128 @@ mock! {
     }
 }
 
+mock! {
+    pub BranchProtectionRepository {}
+    impl Clone for BranchProtectionRepository {
+        fn clone(&self) -> Self;
+    }
+    #[async_trait]
+    impl crate::repository::BranchProtectionRepository for BranchProtectionRepository {
+        async fn list(&self, repository_id: Uuid) -> Result<Vec<BranchProtection>, crate::error::DatabaseError>;
+        async fn create(&self, repository_id: Uuid, pattern: &str, rules: BranchProtectionRules) -> Result<BranchProtection, crate::error::DatabaseError>;
+        async fn update(&self, repository_id: Uuid, id: Uuid, pattern: &str, rules: BranchProtectionRules) -> Result<Option<BranchProtection>, crate::error::DatabaseError>;
+        async fn delete(&self, repository_id: Uuid, id: Uuid) -> Result<bool, crate::error::DatabaseError>;
+    }
+}
+
+mock! {
+    pub MaintenanceRepository {}
+    impl Clone for MaintenanceRepository {
+        fn clone(&self) -> Self;
+    }
+    #[async_trait]
+    impl crate::repository::MaintenanceRepository for MaintenanceRepository {
+        async fn get(&self, repository_id: Uuid) -> Result<Option<RepositoryMaintenance>, crate::error::DatabaseError>;
+        async fn record_push(&self, repository_id: Uuid) -> Result<(), crate::error::DatabaseError>;
+        async fn request(&self, repository_id: Uuid) -> Result<RepositoryMaintenance, crate::error::DatabaseError>;
+        async fn claim_due(&self, push_threshold: i32, idle_after: Duration, stale_after: Duration, limit: i64) -> Result<Vec<RepositoryMaintenance>, crate::error::DatabaseError>;
+        async fn finish(&self, repository_id: Uuid, claimed_pushes: i32, status: MaintenanceStatus, error: Option<String>) -> Result<RepositoryMaintenance, crate::error::DatabaseError>;
+    }
+}
+
+mock! {
+    pub MirrorRepository {}
+    impl Clone for MirrorRepository {
+        fn clone(&self) -> Self;
+    }
+    #[async_trait]
+    impl crate::repository::MirrorRepository for MirrorRepository {
+        async fn list(&self, repository_id: Uuid) -> Result<Vec<RepositoryMirror>, crate::error::DatabaseError>;
+        async fn get(&self, repository_id: Uuid, id: Uuid) -> Result<Option<RepositoryMirror>, crate::error::DatabaseError>;
+        async fn create(&self, repository_id: Uuid, direction: MirrorDirection, url: &str, username: Option<String>, password_ciphertext: Option<Vec<u8>>, interval_seconds: Option<i32>) -> Result<RepositoryMirror, crate::error::DatabaseError>;
+        async fn delete(&self, repository_id: Uuid, id: Uuid) -> Result<bool, crate::error::DatabaseError>;
+        async fn request(&self, repository_id: Uuid, id: Uuid) -> Result<Option<RepositoryMirror>, crate::error::DatabaseError>;
+        async fn request_push(&self, repository_id: Uuid) -> Result<(), crate::error::DatabaseError>;
+        async fn claim_due(&self, stale_after: Duration, limit: i64) -> Result<Vec<RepositoryMirror>, crate::error::DatabaseError>;
+        async fn finish(&self, id: Uuid, status: MirrorRunStatus, error: Option<String>) -> Result<RepositoryMirror, crate::error::DatabaseError>;
+        async fn list_runs(&self, mirror_id: Uuid, limit: i64) -> Result<Vec<RepositoryMirrorRun>, crate::error::DatabaseError>;
+    }
+}
+
+mock! {
+    pub CollaboratorRepository {}
+    impl Clone for CollaboratorRepository {
+        fn clone(&self) -> Self;
+    }
+    #[async_trait]
+    impl crate::repository::CollaboratorRepository for CollaboratorRepository {
+        async fn list_collaborators(&self, repository_id: Uuid) -> Result<Vec<RepositoryCollaborator>, crate::error::DatabaseError>;
+        async fn get_collaborator(&self, repository_id: Uuid, user_id: Uuid) -> Result<Option<RepositoryCollaborator>, crate::error::DatabaseError>;
+        async fn update_collaborator(&self, repository_id: Uuid, user_id: Uuid, role: RepositoryRole) -> Result<Option<RepositoryCollaborator>, crate::error::DatabaseError>;
+        async fn delete_collaborator(&self, repository_id: Uuid, user_id: Uuid) -> Result<bool, crate::error::DatabaseError>;
+        async fn create_invitation(&self, repository_id: Uuid, invitee_id: Uuid, inviter_id: Uuid, role: RepositoryRole) -> Result<RepositoryInvitation, crate::error::DatabaseError>;
+        async fn list_repository_invitations(&self, repository_id: Uuid) -> Result<Vec<RepositoryInvitation>, crate::error::DatabaseError>;
+        async fn list_user_invitations(&self, invitee_id: Uuid) -> Result<Vec<RepositoryInvitation>, crate::error::DatabaseError>;
+        async fn delete_invitation(&self, repository_id: Uuid, invitation_id: Uuid) -> Result<bool, crate::error::DatabaseError>;
+        async fn accept_invitation(&self, invitation_id: Uuid, invitee_id: Uuid) -> Result<Option<RepositoryCollaborator>, crate::error::DatabaseError>;
+        async fn decline_invitation(&self, invitation_id: Uuid, invitee_id: Uuid) -> Result<bool, crate::error::DatabaseError>;
+    }
+}
+
+mock! {
+    pub TeamRepository {}
+    impl Clone for TeamRepository {
+        fn clone(&self) -> Self;
+    }
+    #[async_trait]
+    impl crate::repository::TeamRepository for TeamRepository {
+        async fn create(&self, organization_id: Uuid, name: &str, description: Option<String>) -> Result<Team, crate::error::DatabaseError>;
+        async fn get(&self, organization_id: Uuid, name: &str) -> Result<Option<Team>, crate::error::DatabaseError>;
+        async fn list(&self, organization_id: Uuid) -> Result<Vec<Team>, crate::error::DatabaseError>;
+        async fn delete(&self, id: Uuid) -> Result<bool, crate::error::DatabaseError>;
+        async fn add_member(&self, team_id: Uuid, user_id: Uuid) -> Result<Option<TeamMember>, crate::error::DatabaseError>;
+        async fn remove_member(&self, team_id: Uuid, user_id: Uuid) -> Result<bool, crate::error::DatabaseError>;
+        async fn set_repository(&self, team_id: Uuid, repository_id: Uuid, role: RepositoryRole) -> Result<TeamRepositoryAccess, crate::error::DatabaseError>;
+        async fn remove_repository(&self, team_id: Uuid, repository_id: Uuid) -> Result<bool, crate::error::DatabaseError>;
+    }
+}
+
+mock! {
+    pub BuildRepository {}
+    impl Clone for BuildRepository {
+        fn clone(&self) -> Self;
+    }
+    #[async_trait]
+    impl crate::repository::BuildRepository for BuildRepository {
+        async fn create(&self, repository_id: Uuid, trigger: BuildTrigger, commit_sha: &str, ref_name: &str, review_link: Option<BuildReviewLink>) -> Result<Build, crate::error::DatabaseError>;
+        async fn get(&self, repository_id: Uuid, number: i32) -> Result<Option<Build>, crate::error::DatabaseError>;
+        async fn get_by_id(&self, id: Uuid) -> Result<Option<Build>, crate::error::DatabaseError>;
+        async fn list_by_repo(&self, repository_id: Uuid, review_number: Option<i32>, cursor: Option<crate::dto::Cursor>, limit: i64) -> Result<(Vec<BuildWithStats>, Option<crate::dto::Cursor>), crate::error::DatabaseError>;
+        async fn get_latest_by_commit(&self, repository_id: Uuid, commit_sha: &str) -> Result<Option<BuildWithStats>, crate::error::DatabaseError>;
+        async fn get_latest_by_revision(&self, revision_id: Uuid) -> Result<Option<Build>, crate::error::DatabaseError>;
+        async fn get_review_author_id(&self, build_id: Uuid) -> Result<Option<Uuid>, crate::error::DatabaseError>;
+    }
+}
+
+mock! {
+    pub ReleaseRepository {}
+    impl Clone for ReleaseRepository {
+        fn clone(&self) -> Self;
+    }
+    #[async_trait]
+    impl crate::repository::ReleaseRepository for ReleaseRepository {
+        async fn create(&self, repository_id: Uuid, tag_name: &str, name: &str, body: &str, draft: bool, prerelease: bool, author_id: Uuid) -> Result<Release, crate::error::DatabaseError>;
+        async fn get_by_tag(&self, repository_id: Uuid, tag_name: &str) -> Result<Option<Release>, crate::error::DatabaseError>;
+        async fn list(&self, repository_id: Uuid, include_drafts: bool) -> Result<Vec<Release>, crate::error::DatabaseError>;
+        async fn update(&self, id: Uuid, name: Option<String>, body: Option<String>, draft: Option<bool>, prerelease: Option<bool>) -> Result<Option<Release>, crate::error::DatabaseError>;
+        async fn delete(&self, id: Uuid) -> Result<bool, crate::error::DatabaseError>;
+        async fn create_asset(&self, release_id: Uuid, name: &str, content_type: &str, object_key: &str, size_bytes: i64, uploader_id: Uuid) -> Result<ReleaseAsset, crate::error::DatabaseError>;
+        async fn get_asset(&self, release_id: Uuid, name: &str) -> Result<Option<ReleaseAsset>, crate::error::DatabaseError>;
+        async fn list_assets(&self, release_ids: &[Uuid]) -> Result<Vec<ReleaseAsset>, crate::error::DatabaseError>;
+        async fn delete_asset(&self, id: Uuid) -> Result<bool, crate::error::DatabaseError>;
+    }
+}
+
 mock! {
     pub RepositoryRepository {}
     impl Clone for RepositoryRepository {
@@ -50,10 +181,11 @@ mock! {
     }
     #[async_trait]
     impl crate::repository::RepositoryRepository for RepositoryRepository {
-        async fn create(&self, name: &str, owner_id: Uuid, owner_type: &RepositoryOwnerType, visibility: &RepositoryVisibility, description: Option<String>, readonly: bool, created_at: Option<chrono::DateTime<chrono::Utc>>) -> Result<Repository, crate::error::DatabaseError>;
+        async fn create(&self, name: &str, owner_id: Uuid, owner_type: &RepositoryOwnerType, creator_id: Uuid, visibility: &RepositoryVisibility, description: Option<String>, readonly: bool, created_at: Option<chrono::DateTime<chrono::Utc>>) -> Result<Repository, crate::error::DatabaseError>;
         async fn get(&self, owner: &str, repo: &str, viewer_id: Option<Uuid>) -> Result<Option<Repository>, crate::error::DatabaseError>;
         async fn get_id(&self, owner: &str, repo: &str) -> Result<Option<Uuid>, crate::error::DatabaseError>;
         async fn get_by_id(&self, id: Uuid, viewer_id: Option<Uuid>) -> Result<Option<Repository>, crate::error::DatabaseError>;
+        async fn get_granted_role(&self, id: Uuid, user_id: Uuid) -> Result<Option<RepositoryRole>, crate::error::DatabaseError>;
         async fn list_by_owner(&self, owner_name: &str, viewer_id: Option<Uuid>, cursor: Option<crate::dto::Cursor>, limit: i64) -> Result<(Vec<Repository>, Option<crate::dto::Cursor>), crate::error::DatabaseError>;
         async fn list_latest(&self, limit: i64) -> Result<Vec<Repository>, crate::error::DatabaseError>;
         async fn list_trending(&self, limit: i64) -> Result<Vec<Repository>, crate::error::DatabaseError>;
@@ -77,6 +209,7 @@ mock! {
     #[async_trait]
     impl crate::repository::QuestionRepository for QuestionRepository {
         async fn create_question(&self, author_id: Uuid, repository_id: Uuid, title: &str, body: &str) -> Result<Question, crate::error::DatabaseError>;
+        async fn import_question(&self, repository_id: Uuid, question: &ImportedQuestion) -> Result<Option<Uuid>, crate::error::DatabaseError>;
         async fn update_question(&self, repository_id: Uuid, number: i32, title: &str, body: &str) -> Result<Option<Question>, crate::error::DatabaseError>;
         async fn get_question(&self, repository_id: Uuid, number: i32, user_id: Option<Uuid>) -> Result<Option<Question>, crate::error::DatabaseError>;
         async fn get_question_id(&self, owner: &str, repo: &str, question_number: i32) -> Result<Option<Uuid>, crate::error::DatabaseError>;
@@ -130,6 +263,7 @@ mock! {
         async fn list_reviews(&self, owner: &str, repo: &str, viewer_id: Option<Uuid>, cursor: Option<crate::dto::Cursor>, limit: i64) -> Result<(Vec<Review>, Option<crate::dto::Cursor>), crate::error::DatabaseError>;
         async fn list_reviews_by_user(&self, user_name: &str, viewer_id: Option<Uuid>, status: Option<String>, owner: Option<String>, repo: Option<String>, cursor: Option<crate::dto::Cursor>, limit: i64) -> Result<(Vec<Review>, Option<crate::dto::Cursor>), crate::error::DatabaseError>;
         async fn create_review(&self, repository_id: Uuid, author_id: Uuid, target_branch: &str) -> Result<Review, crate::error::DatabaseError>;
+        async fn import_review(&self, repository_id: Uuid, review: &ImportedReview) -> Result<Option<Uuid>, crate::error::DatabaseError>;
         async fn update_review(&self, review_id: Uuid, status: Option<ReviewStatus>, title: Option<String>, description: Option<String>) -> Result<(), crate::error::DatabaseError>;
         async fn create_diff(&self, review_id: Uuid, position: i32, message: &str) -> Result<Diff, crate::error::DatabaseError>;
         async fn update_diff(&self, diff_id: Uuid, status: Option<DiffStatus>, message: Option<String>) -> Result<(), crate::error::DatabaseError>;
@@ -142,6 +276,8 @@ mock! {
         async fn get_comment(&self, comment_id: Uuid) -> Result<Option<ReviewComment>, crate::error::DatabaseError>;
         async fn update_comment(&self, comment_id: Uuid, body: &str) -> Result<ReviewComment, crate::error::DatabaseError>;
         async fn resolve_comment(&self, comment_id: Uuid, resolved: bool) -> Result<(), crate::error::DatabaseError>;
+        async fn get_merge_requirements(&self, repository_id: Uuid) -> Result<Option<MergeRequirements>, crate::error::DatabaseError>;
+        async fn upsert_merge_requirements(&self, repository_id: Uuid, requirements: MergeRequirements) -> Result<MergeRequirements, crate::error::DatabaseError>;
     }
 }
 
@@ -155,7 +291,8 @@ mock! {
         async fn get_commit(&self, repo_id: Uuid, sha: &str) -> Result<Option<Commit>, crate::error::DatabaseError>;
         async fn list_by_repository(&self, repo_id: Uuid, ref_name: &str, from: DateTime<Utc>, to: DateTime<Utc>, cursor: Option<crate::dto::Cursor>, limit: i64) -> Result<(Vec<Commit>, Option<crate::dto::Cursor>), crate::error::DatabaseError>;
         async fn list_by_user(&self, author_id: Uuid, viewer_id: Option<Uuid>, from: DateTime<Utc>, to: DateTime<Utc>, cursor: Option<crate::dto::Cursor>, limit: i64) -> Result<(Vec<(Commit, bool)>, Option<crate::dto::Cursor>), crate::error::DatabaseError>;
-        async fn create_bulk(&self, author_ids: &[Option<Uuid>], git_author_names: &[String], git_author_emails: &[String], repo_ids: &[Uuid], ref_names: &[String], shas: &[String], parent_shas: &[String], messages: &[String], created_ats: &[DateTime<Utc>], diffs: &[Vec<CommitDiff>], review_numbers: &[Option<i32>], diff_positions: &[Option<i32>]) -> Result<Vec<Commit>, crate::error::DatabaseError>;
+        async fn create_bulk(&self, author_ids: &[Option<Uuid>], git_author_names: &[String], git_author_emails: &[String], repo_ids: &[Uuid], ref_names: &[String], shas: &[String], parent_shas: &[String], messages: &[String], created_ats: &[DateTime<Utc>], diffs: &[Vec<CommitDiff>], review_numbers: &[Option<i32>], diff_positions: &[Option<i32>], signature_statuses: &[Option<CommitSignatureStatus>]) -> Result<Vec<Commit>, crate::error::DatabaseError>;
+        async fn set_signature_status(&self, id: Uuid, status: Option<CommitSignatureStatus>) -> Result<(), crate::error::DatabaseError>;
     }
 }
 
@@ -183,13 +320,46 @@ mock! {
     #[async_trait]
     impl crate::repository::TokenRepository for TokenRepository {
         async fn create_token(&self, principal_id: Uuid, client_id: &str, token_hash: &str, token_type: TokenType) -> Result<AccessToken, crate::error::DatabaseError>;
+        async fn create_personal_token(&self, principal_id: Uuid, client_id: &str, token_hash: &str, name: &str, scopes: &[TokenScope], expires_at: Option<DateTime<Utc>>) -> Result<AccessToken, crate::error::DatabaseError>;
         async fn get_token_by_hash(&self, token_hash: &str) -> Result<Option<AccessToken>, crate::error::DatabaseError>;
         async fn touch_token(&self, id: Uuid) -> Result<(), crate::error::DatabaseError>;
+        async fn list_personal_tokens(&self, principal_id: Uuid) -> Result<Vec<AccessToken>, crate::error::DatabaseError>;
         async fn delete_token(&self, id: Uuid) -> Result<(), crate::error::DatabaseError>;
+        async fn delete_personal_token(&self, principal_id: Uuid, id: Uuid) -> Result<bool, crate::error::DatabaseError>;
         async fn delete_token_by_principal(&self, principal_id: Uuid) -> Result<(), crate::error::DatabaseError>;
     }
 }
 
+mock! {
+    pub SigningKeyRepository {}
+    impl Clone for SigningKeyRepository {
+        fn clone(&self) -> Self;
+    }
+    #[async_trait]
+    impl crate::repository::SigningKeyRepository for SigningKeyRepository {
+        async fn create(&self, user_id: Uuid, name: &str, key_type: SigningKeyType, public_key: &str, fingerprint: &str, key_ids: &[String]) -> Result<SigningKey, crate::error::DatabaseError>;
+        async fn list_by_user(&self, user_id: Uuid) -> Result<Vec<SigningKey>, crate::error::DatabaseError>;
+        async fn list_by_key_id(&self, key_id: &str) -> Result<Vec<SigningKey>, crate::error::DatabaseError>;
+        async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<bool, crate::error::DatabaseError>;
+    }
+}
+
+mock! {
+    pub SshKeyRepository {}
+    impl Clone for SshKeyRepository {
+        fn clone(&self) -> Self;
+    }
+    #[async_trait]
+    impl crate::repository::SshKeyRepository for SshKeyRepository {
+        async fn create(&self, user_id: Uuid, name: &str, key_type: &str, public_key: &str, fingerprint: &str) -> Result<SshKey, crate::error::DatabaseError>;
+        async fn list_by_user(&self, user_id: Uuid) -> Result<Vec<SshKey>, crate::error::DatabaseError>;
+        async fn get(&self, id: Uuid) -> Result<Option<SshKey>, crate::error::DatabaseError>;
+        async fn get_by_fingerprint(&self, fingerprint: &str) -> Result<Option<SshKey>, crate::error::DatabaseError>;
+        async fn touch(&self, id: Uuid) -> Result<(), crate::error::DatabaseError>;
+        async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<bool, crate::error::DatabaseError>;
+    }
+}
+
 mock! {
     pub GitHubRepository {}
     impl Clone for GitHubRepository {
@@ -459,6 +629,11 @@ impl crate::repository::EmailVerificationRepository for MockEmailVerificationRep
     }
 }
 
+/// Sync outcomes recorded by [`MockMigrationRepository`], as
+/// `(migration_repository_id, error)`.
+type MirrorSyncs = Arc<Mutex<Vec<(Uuid, Option<String>)>>>;
+type CutoverPushMirrors = Arc<Mutex<Vec<(String, Option<String>, Option<Vec<u8>>)>>>;
+
 /// Hand-written because [`MigrationRepository::update_migration_repository_status`]
 /// takes `Option<&str>`, which `mockall` + `async_trait` can't generate a mock
 /// for (the same limitation as [`MockSessionRepository`]).
@@ -467,6 +642,13 @@ pub struct MockMigrationRepository {
     migration: Option<Migration>,
     list: (Vec<Migration>, Option<crate::dto::Cursor>),
     created_visibility: Arc<Mutex<Option<RepositoryVisibility>>>,
+    mirrors: Vec<MigrationMirror>,
+    linked: Vec<MigrationRepositoryModel>,
+    mirror_syncs: MirrorSyncs,
+    import_progress: Arc<Mutex<Option<(i32, i32)>>>,
+    cutovers: Arc<Mutex<Vec<MirrorCutover>>>,
+    cutover_push_mirrors: CutoverPushMirrors,
+    finished_backfills: Arc<Mutex<Vec<Uuid>>>,
 }
 
 impl MockMigrationRepository {
@@ -483,6 +665,41 @@ impl MockMigrationRepository {
     pub fn created_visibility(&self) -> Option<RepositoryVisibility> {
         self.created_visibility.lock().unwrap().clone()
     }
+
+    pub fn with_mirror(mut self, mirror: MigrationMirror) -> Self {
+        self.mirrors.push(mirror);
+        self
+    }
+
+    pub fn with_linked(mut self, linked: MigrationRepositoryModel) -> Self {
+        self.linked.push(linked);
+        self
+    }
+
+    /// Sync outcomes recorded so far, as `(migration_repository_id, error)`.
+    pub fn mirror_syncs(&self) -> Vec<(Uuid, Option<String>)> {
+        self.mirror_syncs.lock().unwrap().clone()
+    }
+
+    /// The last import progress recorded, as `(questions, reviews)`.
+    pub fn import_progress(&self) -> Option<(i32, i32)> {
+        *self.import_progress.lock().unwrap()
+    }
+
+    /// Cutovers recorded so far.
+    pub fn cutovers(&self) -> Vec<MirrorCutover> {
+        self.cutovers.lock().unwrap().clone()
+    }
+
+    /// Push mirrors kept by cutovers, as `(url, username, password_ciphertext)`.
+    pub fn cutover_push_mirrors(&self) -> Vec<(String, Option<String>, Option<Vec<u8>>)> {
+        self.cutover_push_mirrors.lock().unwrap().clone()
+    }
+
+    /// Cutovers whose commit backfill was marked finished.
+    pub fn finished_backfills(&self) -> Vec<Uuid> {
+        self.finished_backfills.lock().unwrap().clone()
+    }
 }
 
 #[async_trait]
@@ -547,6 +764,8 @@ impl crate::repository::MigrationRepository for MockMigrationRepository {
             visibility: visibility.clone(),
             status: MigrationRepositoryStatus::Pending,
             error: None,
+            imported_questions: 0,
+            imported_reviews: 0,
             created_at: Utc::now(),
             updated_at: Utc::now(),
         })
@@ -561,6 +780,16 @@ impl crate::repository::MigrationRepository for MockMigrationRepository {
         unimplemented!("MockMigrationRepository::update_migration_repository_status is not stubbed")
     }
 
+    async fn update_import_progress(
+        &self,
+        _id: Uuid,
+        imported_questions: i32,
+        imported_reviews: i32,
+    ) -> Result<(), crate::error::DatabaseError> {
+        *self.import_progress.lock().unwrap() = Some((imported_questions, imported_reviews));
+        Ok(())
+    }
+
     async fn set_destination_repository_id(
         &self,
         _migration_repository_id: Uuid,
@@ -573,6 +802,95 @@ impl crate::repository::MigrationRepository for MockMigrationRepository {
         &self,
         _origin_repository_id: i64,
     ) -> Result<Vec<MigrationRepositoryModel>, crate::error::DatabaseError> {
-        unimplemented!("MockMigrationRepository::list_by_origin_repository_id is not stubbed")
+        Ok(self.linked.clone())
+    }
+
+    async fn list_due_mirrors(
+        &self,
+        _attempted_before: DateTime<Utc>,
+        _limit: i64,
+    ) -> Result<Vec<MigrationMirror>, crate::error::DatabaseError> {
+        Ok(self.mirrors.clone())
+    }
+
+    async fn get_mirror(
+        &self,
+        repository_id: Uuid,
+    ) -> Result<Option<MigrationMirror>, crate::error::DatabaseError> {
+        Ok(self
+            .mirrors
+            .iter()
+            .find(|m| m.repository_id == repository_id)
+            .cloned())
+    }
+
+    async fn record_mirror_sync(
+        &self,
+        migration_repository_id: Uuid,
+        error: Option<&str>,
+    ) -> Result<(), crate::error::DatabaseError> {
+        self.mirror_syncs
+            .lock()
+            .unwrap()
+            .push((migration_repository_id, error.map(str::to_string)));
+        Ok(())
+    }
+
+    async fn cutover_mirror(
+        &self,
+        migration_repository_id: Uuid,
+        repository_id: Uuid,
+        actor_id: Uuid,
+        origin_full_name: &str,
+        push_mirror_url: Option<&str>,
+        push_mirror_username: Option<String>,
+        push_mirror_password_ciphertext: Option<Vec<u8>>,
+    ) -> Result<Option<MirrorCutover>, crate::error::DatabaseError> {
+        let mut cutovers = self.cutovers.lock().unwrap();
+        if cutovers.iter().any(|c| c.repository_id == repository_id) {
+            return Ok(None);
+        }
+
+        let push_mirror_id = push_mirror_url.map(|url| {
+            self.cutover_push_mirrors.lock().unwrap().push((
+                url.to_string(),
+                push_mirror_username,
+                push_mirror_password_ciphertext,
+            ));
+            Uuid::new_v4()
+        });
+        let cutover = MirrorCutover {
+            id: Uuid::new_v4(),
+            migration_repository_id,
+            repository_id,
+            actor_id,
+            origin_full_name: origin_full_name.to_string(),
+            push_mirror_id,
+            created_at: Utc::now(),
+        };
+        cutovers.push(cutover.clone());
+        Ok(Some(cutover))
+    }
+
+    async fn claim_cutover_backfills(
+        &self,
+        _attempted_before: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<MirrorCutover>, crate::error::DatabaseError> {
+        let finished = self.finished_backfills.lock().unwrap();
+        Ok(self
+            .cutovers
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|c| !finished.contains(&c.id))
+            .take(limit as usize)
+            .cloned()
+            .collect())
+    }
+
+    async fn finish_cutover_backfill(&self, id: Uuid) -> Result<(), crate::error::DatabaseError> {
+        self.finished_backfills.lock().unwrap().push(id);
+        Ok(())
     }
 }
@@ -3,9 +3,12 @@ use chrono::Utc;
 
 use crate::{
     client::{Git2Client, GitClient, KafkaClient, KafkaClientImpl},
-    dto::{PublishRepoPushRequest, RepoPushCommit, RepoPushEvent},
+    dto::{
+        PublishRepoPushRequest, PublishReviewEventRequest, PublishTagEventRequest, RepoPushCommit,
+        RepoPushEvent, ReviewEvent, ReviewResponse, TagEvent,
+    },
     error::{OptionNotFoundExt, WebhookError},
-    repository::{PgUserRepository, UserRepository},
+    repository::{PgReviewRepository, PgUserRepository, ReviewRepository, UserRepository},
 };
 
 /// Publishes domain events for repository activity onto Kafka so downstream
@@ -24,28 +27,60 @@ pub trait EventService: Send + Sync + 'static {
     /// - [`WebhookError::GitError`] if listing the pushed commits fails.
     /// - [`WebhookError::KafkaError`] if publishing the event fails.
     async fn publish_repo_push(&self, request: PublishRepoPushRequest) -> Result<(), WebhookError>;
+
+    /// Builds a [`ReviewEvent`] for a review lifecycle action and publishes it
+    /// to Kafka.
+    ///
+    /// Reloads the review by number so the event reflects its state after the
+    /// action (title, target branch, status), and looks up the acting user to
+    /// attach their display name. The event type seen by subscribers is derived
+    /// from the action: publishing maps to `review_publish`, everything else to
+    /// `review_update`.
+    ///
+    /// # Errors
+    /// - [`WebhookError::NotFound`] if the review or the acting user does not exist.
+    /// - [`WebhookError::KafkaError`] if publishing the event fails.
+    async fn publish_review_event(
+        &self,
+        request: PublishReviewEventRequest,
+    ) -> Result<(), WebhookError>;
+
+    /// Builds a [`TagEvent`] for a tag ref update and publishes it to Kafka.
+    ///
+    /// Covers both pushed tags and tags created or deleted through the API.
+    /// The action is derived from the zero sha on either side of the update;
+    /// the acting user is looked up to attach their display name.
+    ///
+    /// # Errors
+    /// - [`WebhookError::NotFound`] if no user matches `actor_id`.
+    /// - [`WebhookError::KafkaError`] if publishing the event fails.
+    async fn publish_tag_event(&self, request: PublishTagEventRequest) -> Result<(), WebhookError>;
 }
 
 #[derive(Debug, Clone)]
-pub struct EventServiceImpl<U, G, K>
+pub struct EventServiceImpl<U, V, G, K>
 where
     U: UserRepository,
+    V: ReviewRepository,
     G: GitClient,
     K: KafkaClient,
 {
     user_repo: U,
+    review_repo: V,
     git_client: G,
     kafka_client: K,
 }
 
-impl EventServiceImpl<PgUserRepository, Git2Client, KafkaClientImpl> {
+impl EventServiceImpl<PgUserRepository, PgReviewRepository, Git2Client, KafkaClientImpl> {
     pub fn new(
         user_repo: PgUserRepository,
+        review_repo: PgReviewRepository,
         git_client: Git2Client,
         kafka_client: KafkaClientImpl,
     ) -> Self {
         Self {
             user_repo,
+            review_repo,
             git_client,
             kafka_client,
         }
@@ -54,9 +89,10 @@ impl EventServiceImpl<PgUserRepository, Git2Client, KafkaClientImpl> {
 
 #[crate::instrument_all(level = "debug")]
 #[async_trait]
-impl<U, G, K> EventService for EventServiceImpl<U, G, K>
+impl<U, V, G, K> EventService for EventServiceImpl<U, V, G, K>
 where
     U: UserRepository,
+    V: ReviewRepository,
     G: GitClient,
     K: KafkaClient,
 {
@@ -101,4 +137,71 @@ where
 
         Ok(())
     }
+
+    async fn publish_review_event(
+        &self,
+        request: PublishReviewEventRequest,
+    ) -> Result<(), WebhookError> {
+        let owner = request.owner.as_ref();
+        let repo = request.repo.as_ref();
+
+        let review: ReviewResponse = self
+            .review_repo
+            .get_review_by_number(owner, repo, request.number)
+            .await?
+            .or_not_found(
+                "review",
+                format!("{owner}/{repo}/review/{}", request.number),
+            )?
+            .into();
+
+        let actor = self
+            .user_repo
+            .get_by_id(request.actor_id)
+            .await?
+            .or_not_found("user", request.actor_id)?;
+
+        let event = ReviewEvent {
+            owner: request.owner.into_inner(),
+            repo: request.repo.into_inner(),
+            review_id: review.id,
+            number: review.number,
+            title: review.title,
+            target_branch: review.target_branch,
+            status: review.status,
+            author_name: review.author.map(|a| a.name),
+            actor_id: request.actor_id,
+            actor_name: actor.name,
+            action: request.action,
+            occurred_at: Utc::now(),
+        };
+
+        self.kafka_client.publish_review_event(event).await?;
+
+        Ok(())
+    }
+
+    async fn publish_tag_event(&self, request: PublishTagEventRequest) -> Result<(), WebhookError> {
+        let actor = self
+            .user_repo
+            .get_by_id(request.actor_id)
+            .await?
+            .or_not_found("user", request.actor_id)?;
+
+        let event = TagEvent {
+            action: request.action(),
+            owner: request.owner.into_inner(),
+            repo: request.repo.into_inner(),
+            tag_name: request.tag_name,
+            old_sha: request.old_sha,
+            new_sha: request.new_sha,
+            actor_id: request.actor_id,
+            actor_name: actor.name,
+            occurred_at: Utc::now(),
+        };
+
+        self.kafka_client.publish_tag_event(event).await?;
+
+        Ok(())
+    }
 }
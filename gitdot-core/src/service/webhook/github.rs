@@ -1,19 +1,35 @@
+use std::{collections::HashMap, time::Duration};
+
 use async_trait::async_trait;
+use chrono::Utc;
 
 use crate::{
     client::{Git2Client, GitClient, GitHubClient, OctocrabClient},
     dto::{
-        ProcessGithubInstallationRequest, ProcessGithubPushRequest, ProcessGithubPushResponse,
-        SyncedRepositoryInfo,
+        MirrorCredentials, MirrorRefUpdate, ProcessGithubInstallationRequest,
+        ProcessGithubPushRequest, ProcessGithubPushResponse, SyncedRepositoryInfo,
     },
     error::WebhookError,
+    model::MigrationMirror,
     repository::{
         GitHubRepository, MigrationRepository, PgGitHubRepository, PgMigrationRepository,
         PgRepositoryRepository, RepositoryRepository,
     },
-    util::{git::ZERO_SHA, github::get_github_clone_url},
+    util::{
+        git::{BRANCH_PREFIX, TAG_PREFIX, ZERO_SHA},
+        github::{get_github_clone_url, get_github_repository_url},
+    },
 };
 
+/// How long a mirror goes without a sync attempt before reconciliation
+/// re-fetches it. Pushes keep mirrors current in between; this catches
+/// webhook deliveries that were dropped or failed.
+const RECONCILE_AFTER: Duration = Duration::from_secs(30 * 60);
+
+/// Recorded on mirrors whose GitHub App installation was removed, since there
+/// is no longer a token to fetch with.
+const INSTALLATION_REMOVED_ERROR: &str = "GitHub App installation was removed";
+
 /// Handles inbound GitHub App webhook events that keep migrated repositories in
 /// sync with their GitHub origin and track the App installation lifecycle.
 #[async_trait]
@@ -21,23 +37,42 @@ pub trait GithubWebhookService: Send + Sync + 'static {
     /// Mirrors a GitHub push into any readonly destination repositories migrated
     /// from the origin.
     ///
-    /// Only acts on pushes to the origin's default branch with a non-zero
-    /// `after` sha; anything else returns an empty response without side
-    /// effects. For each migration of the origin repository it resolves the
-    /// destination, skips it unless it exists and is readonly, then fetches the
-    /// pushed ref into the destination using an installation access token to
-    /// authenticate the clone URL. Per-destination fetch failures are logged and
-    /// skipped (not propagated); only successfully synced repositories are
+    /// Acts on every branch and tag ref; pushes to other refs return an empty
+    /// response without side effects. For each migration of the origin
+    /// repository it resolves the destination and skips it unless it exists and
+    /// is readonly. A zero `after` sha deletes the ref from the destination;
+    /// anything else fetches the pushed sha into it using an installation
+    /// access token to authenticate the clone URL. The outcome is recorded as
+    /// the mirror's sync state. Per-destination git failures are logged and
+    /// recorded (not propagated); only successfully synced repositories are
     /// returned.
     ///
     /// # Errors
     /// - [`WebhookError::GitHubError`] if obtaining the installation access token fails.
-    /// - [`WebhookError::DatabaseError`] if listing migrations or loading destinations fails.
+    /// - [`WebhookError::DatabaseError`] if listing migrations, loading
+    ///   destinations or recording sync state fails.
     async fn process_github_push(
         &self,
         request: ProcessGithubPushRequest,
     ) -> Result<ProcessGithubPushResponse, WebhookError>;
 
+    /// Re-syncs up to `limit` mirrors that have not been attempted recently,
+    /// least recently attempted first.
+    ///
+    /// Each mirror fetches every branch and tag from its origin, pruning the
+    /// ones the origin deleted, and records the outcome as its sync state. A
+    /// mirror whose installation was removed, or whose token or fetch fails, is
+    /// recorded as failed and skipped. Returns the mirrors that synced, with
+    /// the refs that moved.
+    ///
+    /// # Errors
+    /// - [`WebhookError::DatabaseError`] if listing mirrors or recording sync
+    ///   state fails.
+    async fn reconcile_github_mirrors(
+        &self,
+        limit: i64,
+    ) -> Result<Vec<SyncedRepositoryInfo>, WebhookError>;
+
     /// Reacts to GitHub App installation events.
     ///
     /// On the `deleted` action, removes all GitHub repository records tied to the
@@ -93,6 +128,55 @@ impl
     }
 }
 
+impl<R, MR, GHR, G, GH> GithubWebhookServiceImpl<R, MR, GHR, G, GH>
+where
+    R: RepositoryRepository,
+    MR: MigrationRepository,
+    GHR: GitHubRepository,
+    G: GitClient,
+    GH: GitHubClient,
+{
+    /// Fetches the mirror's origin into it, reusing installation tokens across
+    /// mirrors in the same pass. Errors are returned as the message recorded on
+    /// the mirror.
+    async fn sync_mirror(
+        &self,
+        mirror: &MigrationMirror,
+        tokens: &mut HashMap<i64, String>,
+    ) -> Result<Vec<MirrorRefUpdate>, String> {
+        let Some(installation_id) = mirror.installation_id else {
+            return Err(INSTALLATION_REMOVED_ERROR.to_string());
+        };
+        let token = match tokens.get(&installation_id) {
+            Some(token) => token.clone(),
+            None => {
+                let token = self
+                    .github_client
+                    .get_installation_access_token(installation_id as u64)
+                    .await
+                    .map_err(|e| e.to_string())?;
+                tokens.insert(installation_id, token.clone());
+                token
+            }
+        };
+
+        let url = get_github_repository_url(&mirror.origin_full_name);
+        let credentials = MirrorCredentials {
+            username: "x-access-token".to_string(),
+            password: token,
+        };
+        self.git_client
+            .sync_mirror(
+                &mirror.owner_name,
+                &mirror.repository_name,
+                &url,
+                Some(&credentials),
+            )
+            .await
+            .map_err(|e| e.to_string())
+    }
+}
+
 #[crate::instrument_all(level = "debug")]
 #[async_trait]
 impl<R, MR, GHR, G, GH> GithubWebhookService for GithubWebhookServiceImpl<R, MR, GHR, G, GH>
@@ -111,12 +195,8 @@ where
             synced_repositories: Vec::new(),
         };
 
-        let default_ref = format!("refs/heads/{}", request.repository.default_branch);
-        if request.ref_name != default_ref {
-            // only sync pushes to the default branch
-            return Ok(empty_response);
-        }
-        if request.after == ZERO_SHA {
+        if !request.ref_name.starts_with(BRANCH_PREFIX) && !request.ref_name.starts_with(TAG_PREFIX)
+        {
             return Ok(empty_response);
         }
 
@@ -150,29 +230,47 @@ where
                 continue;
             }
 
-            match self
-                .git_client
-                .fetch_ref(
-                    &dest.owner_name,
-                    &dest.name,
-                    &url,
-                    &request.ref_name,
-                    &request.after,
-                )
-                .await
-            {
-                Ok(()) => synced_repositories.push(SyncedRepositoryInfo {
-                    owner_name: dest.owner_name,
-                    repo_name: dest.name,
-                    head_sha: request.after.clone(),
-                }),
+            let result = if request.after == ZERO_SHA {
+                self.git_client
+                    .delete_ref(&dest.owner_name, &dest.name, &request.ref_name)
+                    .await
+            } else {
+                self.git_client
+                    .fetch_ref(
+                        &dest.owner_name,
+                        &dest.name,
+                        &url,
+                        &request.ref_name,
+                        &request.after,
+                    )
+                    .await
+            };
+
+            match result {
+                Ok(()) => {
+                    self.migration_repo
+                        .record_mirror_sync(migration_repository.id, None)
+                        .await?;
+                    synced_repositories.push(SyncedRepositoryInfo {
+                        owner_name: dest.owner_name,
+                        repo_name: dest.name,
+                        ref_updates: vec![MirrorRefUpdate {
+                            ref_name: request.ref_name.clone(),
+                            old_sha: request.before.clone(),
+                            new_sha: request.after.clone(),
+                        }],
+                    });
+                }
                 Err(e) => {
                     tracing::error!(
                         ?e,
                         owner = %dest.owner_name,
                         repo = %dest.name,
-                        "failed to fetch ref into readonly mirror",
+                        "failed to mirror ref update into readonly mirror",
                     );
+                    self.migration_repo
+                        .record_mirror_sync(migration_repository.id, Some(&e.to_string()))
+                        .await?;
                 }
             }
         }
@@ -182,6 +280,45 @@ where
         })
     }
 
+    async fn reconcile_github_mirrors(
+        &self,
+        limit: i64,
+    ) -> Result<Vec<SyncedRepositoryInfo>, WebhookError> {
+        let mirrors = self
+            .migration_repo
+            .list_due_mirrors(Utc::now() - RECONCILE_AFTER, limit)
+            .await?;
+
+        let mut tokens = HashMap::new();
+        let mut synced_repositories = Vec::new();
+        for mirror in mirrors {
+            match self.sync_mirror(&mirror, &mut tokens).await {
+                Ok(ref_updates) => {
+                    self.migration_repo
+                        .record_mirror_sync(mirror.migration_repository_id, None)
+                        .await?;
+                    synced_repositories.push(SyncedRepositoryInfo {
+                        owner_name: mirror.owner_name,
+                        repo_name: mirror.repository_name,
+                        ref_updates,
+                    });
+                }
+                Err(error) => {
+                    tracing::warn!(
+                        owner = %mirror.owner_name,
+                        repo = %mirror.repository_name,
+                        "mirror reconciliation failed: {error}",
+                    );
+                    self.migration_repo
+                        .record_mirror_sync(mirror.migration_repository_id, Some(&error))
+                        .await?;
+                }
+            }
+        }
+
+        Ok(synced_repositories)
+    }
+
     async fn process_github_installation(
         &self,
         request: ProcessGithubInstallationRequest,
@@ -203,3 +340,251 @@ where
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use mockall::predicate::eq;
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::{
+        model::{
+            MigrationRepository as MigrationRepositoryModel, MigrationRepositoryStatus,
+            RepositoryOwnerType, RepositoryVisibility,
+        },
+        service::{
+            test_client::{MockGitClient, MockGitHubClient},
+            test_common::create_repository,
+            test_repository::{
+                MockGitHubRepository, MockMigrationRepository, MockRepositoryRepository,
+            },
+        },
+    };
+
+    const OLD_SHA: &str = "1111111111111111111111111111111111111111";
+    const NEW_SHA: &str = "2222222222222222222222222222222222222222";
+
+    fn create_service(
+        repo_repo: MockRepositoryRepository,
+        migration_repo: MockMigrationRepository,
+        git_client: MockGitClient,
+    ) -> GithubWebhookServiceImpl<
+        MockRepositoryRepository,
+        MockMigrationRepository,
+        MockGitHubRepository,
+        MockGitClient,
+        MockGitHubClient,
+    > {
+        let mut github_client = MockGitHubClient::new();
+        github_client
+            .expect_get_installation_access_token()
+            .returning(|_| Ok("token".to_string()));
+
+        GithubWebhookServiceImpl {
+            repo_repo,
+            migration_repo,
+            github_repo: MockGitHubRepository::new(),
+            git_client,
+            github_client,
+        }
+    }
+
+    fn create_linked(destination_repository_id: Uuid) -> MigrationRepositoryModel {
+        MigrationRepositoryModel {
+            id: Uuid::new_v4(),
+            migration_id: Uuid::new_v4(),
+            origin_full_name: "octocat/hello".to_string(),
+            origin_repository_id: 42,
+            origin_created_at: None,
+            destination_full_name: "owner/myrepo".to_string(),
+            destination_repository_id: Some(destination_repository_id),
+            visibility: RepositoryVisibility::Public,
+            status: MigrationRepositoryStatus::Completed,
+            error: None,
+            imported_questions: 0,
+            imported_reviews: 0,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn create_mirror(installation_id: Option<i64>) -> MigrationMirror {
+        MigrationMirror {
+            migration_repository_id: Uuid::new_v4(),
+            origin_full_name: "octocat/hello".to_string(),
+            installation_id,
+            repository_id: Uuid::new_v4(),
+            owner_name: "owner".to_string(),
+            repository_name: "myrepo".to_string(),
+            sync_status: None,
+            sync_error: None,
+            last_attempted_at: None,
+            last_synced_at: None,
+        }
+    }
+
+    fn push_request(ref_name: &str, after: &str) -> ProcessGithubPushRequest {
+        let body = serde_json::json!({
+            "ref": ref_name,
+            "before": OLD_SHA,
+            "after": after,
+            "repository": {
+                "id": 42,
+                "name": "hello",
+                "owner": { "login": "octocat" },
+                "default_branch": "main",
+            },
+            "pusher": { "name": "octocat" },
+            "installation": { "id": 7 },
+            "commits": [],
+        });
+        ProcessGithubPushRequest::new(body.to_string().as_bytes()).unwrap()
+    }
+
+    fn readonly_destination() -> (MockRepositoryRepository, Uuid) {
+        let mut dest = create_repository(
+            Uuid::new_v4(),
+            RepositoryOwnerType::User,
+            RepositoryVisibility::Public,
+        );
+        dest.readonly = true;
+        let dest_id = dest.id;
+
+        let mut repo_repo = MockRepositoryRepository::new();
+        repo_repo
+            .expect_get_by_id()
+            .with(eq(dest_id), eq(None))
+            .returning(move |_, _| Ok(Some(dest.clone())));
+        (repo_repo, dest_id)
+    }
+
+    #[tokio::test]
+    async fn process_github_push_mirrors_non_default_branches_and_tags() {
+        let (repo_repo, dest_id) = readonly_destination();
+        let linked = create_linked(dest_id);
+        let migration_repo = MockMigrationRepository::default().with_linked(linked.clone());
+        let git_client = MockGitClient::default();
+        let service = create_service(repo_repo, migration_repo.clone(), git_client.clone());
+
+        for ref_name in ["refs/heads/feature", "refs/tags/v1.0.0"] {
+            let response = service
+                .process_github_push(push_request(ref_name, NEW_SHA))
+                .await
+                .unwrap();
+            let synced = &response.synced_repositories[0];
+            assert_eq!(synced.ref_updates[0].ref_name, ref_name);
+            assert_eq!(synced.ref_updates[0].new_sha, NEW_SHA);
+        }
+
+        assert_eq!(
+            git_client.fetched_refs(),
+            [
+                (
+                    "owner/myrepo".to_string(),
+                    "refs/heads/feature".to_string(),
+                    NEW_SHA.to_string()
+                ),
+                (
+                    "owner/myrepo".to_string(),
+                    "refs/tags/v1.0.0".to_string(),
+                    NEW_SHA.to_string()
+                ),
+            ]
+        );
+        assert_eq!(
+            migration_repo.mirror_syncs(),
+            [(linked.id, None), (linked.id, None)]
+        );
+    }
+
+    #[tokio::test]
+    async fn process_github_push_deletes_removed_refs() {
+        let (repo_repo, dest_id) = readonly_destination();
+        let migration_repo = MockMigrationRepository::default().with_linked(create_linked(dest_id));
+        let git_client = MockGitClient::default();
+        let service = create_service(repo_repo, migration_repo, git_client.clone());
+
+        let response = service
+            .process_github_push(push_request("refs/heads/feature", ZERO_SHA))
+            .await
+            .unwrap();
+
+        assert_eq!(response.synced_repositories.len(), 1);
+        assert!(git_client.fetched_refs().is_empty());
+        assert_eq!(
+            git_client.deleted_refs(),
+            [("owner/myrepo".to_string(), "refs/heads/feature".to_string())]
+        );
+    }
+
+    #[tokio::test]
+    async fn process_github_push_records_fetch_failures() {
+        let (repo_repo, dest_id) = readonly_destination();
+        let linked = create_linked(dest_id);
+        let migration_repo = MockMigrationRepository::default().with_linked(linked.clone());
+        let git_client = MockGitClient::default().with_mirror_failing();
+        let service = create_service(repo_repo, migration_repo.clone(), git_client);
+
+        let response = service
+            .process_github_push(push_request("refs/heads/main", NEW_SHA))
+            .await
+            .unwrap();
+
+        assert!(response.synced_repositories.is_empty());
+        let syncs = migration_repo.mirror_syncs();
+        assert_eq!(syncs.len(), 1);
+        assert_eq!(syncs[0].0, linked.id);
+        assert!(syncs[0].1.is_some());
+    }
+
+    #[tokio::test]
+    async fn process_github_push_ignores_other_refs() {
+        let linked = create_linked(Uuid::new_v4());
+        let migration_repo = MockMigrationRepository::default().with_linked(linked);
+        let service = create_service(
+            MockRepositoryRepository::new(),
+            migration_repo.clone(),
+            MockGitClient::default(),
+        );
+
+        let response = service
+            .process_github_push(push_request("refs/pull/1/head", NEW_SHA))
+            .await
+            .unwrap();
+
+        assert!(response.synced_repositories.is_empty());
+        assert!(migration_repo.mirror_syncs().is_empty());
+    }
+
+    #[tokio::test]
+    async fn reconcile_github_mirrors_syncs_due_mirrors_and_records_failures() {
+        let installed = create_mirror(Some(7));
+        let uninstalled = create_mirror(None);
+        let migration_repo = MockMigrationRepository::default()
+            .with_mirror(installed.clone())
+            .with_mirror(uninstalled.clone());
+        let git_client =
+            MockGitClient::default().with_mirror_update("refs/heads/main", OLD_SHA, NEW_SHA);
+        let service = create_service(
+            MockRepositoryRepository::new(),
+            migration_repo.clone(),
+            git_client,
+        );
+
+        let synced = service.reconcile_github_mirrors(10).await.unwrap();
+
+        assert_eq!(synced.len(), 1);
+        assert_eq!(synced[0].ref_updates[0].new_sha, NEW_SHA);
+        assert_eq!(
+            migration_repo.mirror_syncs(),
+            [
+                (installed.migration_repository_id, None),
+                (
+                    uninstalled.migration_repository_id,
+                    Some(INSTALLATION_REMOVED_ERROR.to_string())
+                ),
+            ]
+        );
+    }
+}
@@ -3,8 +3,8 @@ use async_trait::async_trait;
 use crate::{
     client::{SlackBotClient, SlackBotClientImpl},
     dto::{
-        ListSlackWebhooksRequest, NotifyRepoPushRequest, SlackWebhookResponse,
-        SubscribeSlackWebhookRequest, UnsubscribeSlackWebhookRequest,
+        ListSlackWebhooksRequest, NotifyRepoPushRequest, NotifyReviewEventRequest,
+        SlackWebhookResponse, SubscribeSlackWebhookRequest, UnsubscribeSlackWebhookRequest,
     },
     error::{NotFoundError, OptionNotFoundExt, WebhookError},
     model::WebhookEventType,
@@ -22,7 +22,7 @@ pub trait SlackWebhookService: Send + Sync + 'static {
     ///
     /// Resolves the repository by owner/name, then creates a Slack webhook row
     /// tying the subscribing user and the Slack user/team/channel to it and
-    /// returns it. Subscriptions are currently fixed to the `Push` event type.
+    /// returns it. Subscriptions are currently fixed to push and review events.
     ///
     /// # Errors
     /// - [`WebhookError::NotFound`] if no repository matches owner/name.
@@ -65,6 +65,16 @@ pub trait SlackWebhookService: Send + Sync + 'static {
         &self,
         request: NotifyRepoPushRequest,
     ) -> Result<(), WebhookError>;
+
+    /// Delivers a review-activity notification to Slack via the bot client,
+    /// tagged with the event type derived from the review action.
+    ///
+    /// # Errors
+    /// - [`WebhookError::SlackBotError`] if the Slack delivery fails.
+    async fn notify_slack_of_review_event(
+        &self,
+        request: NotifyReviewEventRequest,
+    ) -> Result<(), WebhookError>;
 }
 
 #[derive(Debug, Clone)]
@@ -114,8 +124,12 @@ where
             .await?
             .or_not_found("repository", format!("{owner}/{repo}"))?;
 
-        // TODO: support configurable event subscriptions; default to push only.
-        let events = vec![WebhookEventType::Push];
+        // TODO: support configurable event subscriptions; default to push and reviews.
+        let events = vec![
+            WebhookEventType::Push,
+            WebhookEventType::ReviewPublish,
+            WebhookEventType::ReviewUpdate,
+        ];
         let webhook = self
             .slack_webhook_repo
             .create(
@@ -193,4 +207,14 @@ where
             .await?;
         Ok(())
     }
+
+    async fn notify_slack_of_review_event(
+        &self,
+        request: NotifyReviewEventRequest,
+    ) -> Result<(), WebhookError> {
+        self.slack_bot_client
+            .notify_event(request.action.event_type(), &request)
+            .await?;
+        Ok(())
+    }
 }
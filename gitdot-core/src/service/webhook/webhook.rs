@@ -1,20 +1,34 @@
+use std::time::{Duration, Instant};
+
 use async_trait::async_trait;
+use futures::future::join_all;
+use uuid::Uuid;
 
 use crate::{
+    client::{WebhookClient, WebhookClientImpl},
     dto::{
-        CreateWebhookRequest, DeleteWebhookRequest, GetWebhookRequest, ListWebhooksRequest, Page,
-        UpdateWebhookRequest, WebhookResponse,
+        CreateWebhookRequest, DeleteWebhookRequest, DeliverWebhookEventRequest, GetWebhookRequest,
+        ListWebhookDeliveriesRequest, ListWebhooksRequest, Page, RedeliverWebhookDeliveryRequest,
+        UpdateWebhookRequest, WebhookDeliveryResponse, WebhookResponse,
     },
     error::{NotFoundError, OptionNotFoundExt, WebhookError},
+    model::{Repository, Webhook, WebhookDelivery, WebhookDeliveryStatus},
     repository::{
         PgRepositoryRepository, PgWebhookRepository, RepositoryRepository, WebhookRepository,
     },
     util::cursor,
 };
 
+/// Attempts per event delivery before it is marked `failure`.
+const MAX_DELIVERY_ATTEMPTS: u32 = 5;
+
+/// Wait before the first retry; doubled after every failed attempt
+/// (1s, 2s, 4s, 8s), so a delivery settles within ~15s plus request time.
+const INITIAL_RETRY_BACKOFF: Duration = Duration::from_secs(1);
+
 /// Manages a repository's outbound HTTP webhooks (url, secret, and subscribed
-/// event types). Every operation is scoped to the repository resolved from the
-/// request's owner/name.
+/// event types) and delivers events to them. Every operation is scoped to the
+/// repository resolved from the request's owner/name.
 #[async_trait]
 pub trait WebhookService: Send + Sync + 'static {
     /// Creates a webhook on the resolved repository with the given url, secret,
@@ -77,33 +91,201 @@ pub trait WebhookService: Send + Sync + 'static {
     /// - [`WebhookError::NotFound`] if the repository or webhook does not exist, or the webhook belongs to another repository.
     /// - [`WebhookError::DatabaseError`] if deleting the webhook fails.
     async fn delete_webhook(&self, request: DeleteWebhookRequest) -> Result<(), WebhookError>;
+
+    /// Delivers an event to every webhook on the repository subscribed to its
+    /// event type.
+    ///
+    /// Each webhook gets its own delivery row and is delivered to concurrently.
+    /// A delivery is a signed JSON `POST` of the payload; transport errors,
+    /// `408`, `429` and `5xx` responses are retried with exponential backoff up
+    /// to a fixed number of attempts, while other non-2xx responses fail
+    /// immediately. Every attempt is recorded on the delivery row, so receiver
+    /// failures never surface as errors here. Returns the settled deliveries.
+    ///
+    /// # Errors
+    /// - [`WebhookError::NotFound`] if no repository matches owner/name.
+    /// - [`WebhookError::DatabaseError`] if listing webhooks or recording a delivery fails.
+    async fn deliver_webhook_event(
+        &self,
+        request: DeliverWebhookEventRequest,
+    ) -> Result<Vec<WebhookDeliveryResponse>, WebhookError>;
+
+    /// Lists a webhook's deliveries, newest first, as a cursor-paginated page.
+    ///
+    /// # Errors
+    /// - [`WebhookError::NotFound`] if the repository or webhook does not exist, or the webhook belongs to another repository.
+    /// - [`WebhookError::DatabaseError`] if the query fails.
+    async fn list_webhook_deliveries(
+        &self,
+        request: ListWebhookDeliveriesRequest,
+    ) -> Result<Page<WebhookDeliveryResponse>, WebhookError>;
+
+    /// Replays a past delivery against the webhook's current url and secret.
+    ///
+    /// Creates a new delivery row linked to the original through
+    /// `redelivery_of` and makes a single attempt, returning its outcome.
+    ///
+    /// # Errors
+    /// - [`WebhookError::NotFound`] if the repository, webhook, or delivery does not exist, or they do not belong together.
+    /// - [`WebhookError::DatabaseError`] if recording the delivery fails.
+    async fn redeliver_webhook_delivery(
+        &self,
+        request: RedeliverWebhookDeliveryRequest,
+    ) -> Result<WebhookDeliveryResponse, WebhookError>;
 }
 
 #[derive(Debug, Clone)]
-pub struct WebhookServiceImpl<W, R>
+pub struct WebhookServiceImpl<W, R, C>
 where
     W: WebhookRepository,
     R: RepositoryRepository,
+    C: WebhookClient,
 {
     webhook_repo: W,
     repo_repo: R,
+    webhook_client: C,
 }
 
-impl WebhookServiceImpl<PgWebhookRepository, PgRepositoryRepository> {
-    pub fn new(webhook_repo: PgWebhookRepository, repo_repo: PgRepositoryRepository) -> Self {
+impl WebhookServiceImpl<PgWebhookRepository, PgRepositoryRepository, WebhookClientImpl> {
+    pub fn new(
+        webhook_repo: PgWebhookRepository,
+        repo_repo: PgRepositoryRepository,
+        webhook_client: WebhookClientImpl,
+    ) -> Self {
         Self {
             webhook_repo,
             repo_repo,
+            webhook_client,
         }
     }
 }
 
+impl<W, R, C> WebhookServiceImpl<W, R, C>
+where
+    W: WebhookRepository,
+    R: RepositoryRepository,
+    C: WebhookClient,
+{
+    async fn get_repository(&self, owner: &str, repo: &str) -> Result<Repository, WebhookError> {
+        Ok(self
+            .repo_repo
+            .get(owner, repo, None)
+            .await?
+            .or_not_found("repository", format!("{owner}/{repo}"))?)
+    }
+
+    async fn get_repository_webhook(
+        &self,
+        repository: &Repository,
+        webhook_id: Uuid,
+    ) -> Result<Webhook, WebhookError> {
+        let webhook = self
+            .webhook_repo
+            .get(webhook_id)
+            .await?
+            .or_not_found("webhook", webhook_id)?;
+
+        if webhook.repository_id != repository.id {
+            return Err(NotFoundError::new("webhook", webhook_id).into());
+        }
+
+        Ok(webhook)
+    }
+
+    /// Posts a delivery's payload to the webhook, retrying retryable failures
+    /// with exponential backoff until it succeeds or `max_attempts` is reached.
+    /// Each attempt is written to the delivery row; the settled row is returned.
+    async fn attempt_delivery(
+        &self,
+        webhook: &Webhook,
+        mut delivery: WebhookDelivery,
+        max_attempts: u32,
+    ) -> Result<WebhookDelivery, WebhookError> {
+        let body = delivery.payload.to_string().into_bytes();
+        let mut backoff = INITIAL_RETRY_BACKOFF;
+        let mut attempt = 1;
+
+        loop {
+            let started_at = Instant::now();
+            let result = self
+                .webhook_client
+                .deliver(
+                    &webhook.url,
+                    &webhook.secret,
+                    delivery.event,
+                    delivery.id,
+                    &body,
+                )
+                .await;
+            let duration_ms = started_at.elapsed().as_millis().min(i32::MAX as u128) as i32;
+
+            let (succeeded, retryable, status_code, excerpt, error) = match result {
+                Ok(response) => {
+                    let succeeded = (200..300).contains(&response.status);
+                    let retryable = !succeeded && is_retryable_status(response.status);
+                    (
+                        succeeded,
+                        retryable,
+                        Some(response.status as i32),
+                        Some(response.body_excerpt),
+                        None,
+                    )
+                }
+                Err(e) => (false, true, None, None, Some(e.to_string())),
+            };
+
+            let settled = succeeded || !retryable || attempt >= max_attempts;
+            let status = match (succeeded, settled) {
+                (true, _) => WebhookDeliveryStatus::Success,
+                (false, true) => WebhookDeliveryStatus::Failure,
+                (false, false) => WebhookDeliveryStatus::Pending,
+            };
+
+            delivery = self
+                .webhook_repo
+                .record_delivery_attempt(
+                    delivery.id,
+                    status,
+                    status_code,
+                    duration_ms,
+                    excerpt.as_deref(),
+                    error.as_deref(),
+                )
+                .await?;
+
+            if settled {
+                if !succeeded {
+                    tracing::warn!(
+                        webhook_id = %webhook.id,
+                        delivery_id = %delivery.id,
+                        attempts = delivery.attempts,
+                        status_code,
+                        "webhook delivery failed",
+                    );
+                }
+                return Ok(delivery);
+            }
+
+            tokio::time::sleep(backoff).await;
+            backoff *= 2;
+            attempt += 1;
+        }
+    }
+}
+
+/// Request timeouts, rate limiting and server errors are worth retrying; any
+/// other non-2xx answer means the receiver rejected the payload.
+fn is_retryable_status(status: u16) -> bool {
+    status == 408 || status == 429 || status >= 500
+}
+
 #[crate::instrument_all(level = "debug")]
 #[async_trait]
-impl<W, R> WebhookService for WebhookServiceImpl<W, R>
+impl<W, R, C> WebhookService for WebhookServiceImpl<W, R, C>
 where
     W: WebhookRepository,
     R: RepositoryRepository,
+    C: WebhookClient,
 {
     async fn create_webhook(
         &self,
@@ -241,4 +423,89 @@ where
 
         Ok(())
     }
+
+    async fn deliver_webhook_event(
+        &self,
+        request: DeliverWebhookEventRequest,
+    ) -> Result<Vec<WebhookDeliveryResponse>, WebhookError> {
+        let repository = self
+            .get_repository(request.owner_name.as_ref(), request.repo_name.as_ref())
+            .await?;
+
+        let webhooks = self
+            .webhook_repo
+            .list_by_repository_and_event(repository.id, request.event)
+            .await?;
+
+        let deliveries = join_all(webhooks.iter().map(|webhook| async {
+            let delivery = self
+                .webhook_repo
+                .create_delivery(webhook.id, request.event, &request.payload, None)
+                .await?;
+            self.attempt_delivery(webhook, delivery, MAX_DELIVERY_ATTEMPTS)
+                .await
+        }))
+        .await;
+
+        deliveries
+            .into_iter()
+            .map(|delivery| delivery.map(Into::into))
+            .collect()
+    }
+
+    async fn list_webhook_deliveries(
+        &self,
+        request: ListWebhookDeliveriesRequest,
+    ) -> Result<Page<WebhookDeliveryResponse>, WebhookError> {
+        let repository = self
+            .get_repository(request.owner_name.as_ref(), request.repo_name.as_ref())
+            .await?;
+        let webhook = self
+            .get_repository_webhook(&repository, request.webhook_id)
+            .await?;
+
+        let (deliveries, next_cursor) = self
+            .webhook_repo
+            .list_deliveries(webhook.id, request.cursor, request.limit as i64)
+            .await?;
+
+        Ok(Page {
+            data: deliveries.into_iter().map(Into::into).collect(),
+            next_cursor: next_cursor.as_ref().map(cursor::encode),
+        })
+    }
+
+    async fn redeliver_webhook_delivery(
+        &self,
+        request: RedeliverWebhookDeliveryRequest,
+    ) -> Result<WebhookDeliveryResponse, WebhookError> {
+        let repository = self
+            .get_repository(request.owner_name.as_ref(), request.repo_name.as_ref())
+            .await?;
+        let webhook = self
+            .get_repository_webhook(&repository, request.webhook_id)
+            .await?;
+
+        let original = self
+            .webhook_repo
+            .get_delivery(request.delivery_id)
+            .await?
+            .or_not_found("webhook_delivery", request.delivery_id)?;
+        if original.webhook_id != webhook.id {
+            return Err(NotFoundError::new("webhook_delivery", request.delivery_id).into());
+        }
+
+        let delivery = self
+            .webhook_repo
+            .create_delivery(
+                webhook.id,
+                original.event,
+                &original.payload,
+                Some(original.id),
+            )
+            .await?;
+        let delivery = self.attempt_delivery(&webhook, delivery, 1).await?;
+
+        Ok(delivery.into())
+    }
 }
@@ -1,12 +1,17 @@
 //! Stateless helpers shared across the crate's services and repositories.
 //!
 //! - `auth` — reserved-name checks, server identifiers, and auth email bodies
-//! - `crypto` — string hashing for codes and tokens
+//! - `crypto` — string hashing for codes and tokens, and encryption at rest
 //! - `cursor` — encode/decode keyset-pagination cursors
-//! - `git` — git constants (default branch, zero SHA) and receive-hook scripts
-//! - `github` — GitHub clone-URL construction
+//! - `git` — git constants (default branch, zero SHA), receive-hook scripts, and
+//!   branch glob matching
+//! - `github` — GitHub clone-URL construction, noreply emails, and credit for
+//!   imported posts
 //! - `image` — deterministic identicon/avatar generation
+//! - `mirror` — credentialed remote URLs for repository mirrors
 //! - `review` — `refs/for` magic-ref naming for the review protocol
+//! - `search` — trigram index and language detection for code search
+//! - `signature` — OpenPGP and SSH signature parsing for commit verification
 //! - `template` — bundled gitignore/license templates
 //! - `user` — default user profile content
 
@@ -16,6 +21,9 @@ pub mod cursor;
 pub mod git;
 pub mod github;
 pub mod image;
+pub mod mirror;
 pub mod review;
+pub mod search;
+pub mod signature;
 pub mod template;
 pub mod user;
@@ -1,3 +1,8 @@
+use std::fmt;
+
+use base64::{Engine, engine::general_purpose::STANDARD};
+use rand::RngExt as _;
+use ring::aead::{AES_256_GCM, Aad, LessSafeKey, NONCE_LEN, Nonce, UnboundKey};
 use sha2::{Digest, Sha256};
 
 pub fn hash_string(string: &str) -> String {
@@ -5,3 +10,131 @@ pub fn hash_string(string: &str) -> String {
     hasher.update(string.as_bytes());
     hex::encode(hasher.finalize())
 }
+
+/// An AES-256-GCM key for values stored encrypted at rest, such as CI
+/// secrets and mirror passwords.
+#[derive(Clone)]
+pub struct EncryptionKey([u8; 32]);
+
+impl EncryptionKey {
+    pub fn new(bytes: [u8; 32]) -> Self {
+        Self(bytes)
+    }
+
+    /// Parses a standard base64 encoded 32-byte key, as generated by
+    /// `openssl rand -base64 32`.
+    pub fn from_base64(encoded: &str) -> Option<Self> {
+        let bytes = STANDARD.decode(encoded.trim()).ok()?;
+        Some(Self(bytes.try_into().ok()?))
+    }
+
+    /// Encrypts `plaintext` under a fresh random nonce, which is prepended to
+    /// the returned ciphertext.
+    pub fn encrypt(&self, plaintext: &[u8]) -> Vec<u8> {
+        self.encrypt_with_aad(plaintext, &[])
+    }
+
+    /// Like [`encrypt`](Self::encrypt), but also authenticates `aad`: the
+    /// value only decrypts when the same `aad` is passed back, so a ciphertext
+    /// copied to another row fails instead of yielding that row's secret.
+    pub fn encrypt_with_aad(&self, plaintext: &[u8], aad: &[u8]) -> Vec<u8> {
+        let nonce: [u8; NONCE_LEN] = rand::rng().random();
+        let mut in_out = plaintext.to_vec();
+        self.key()
+            .seal_in_place_append_tag(
+                Nonce::assume_unique_for_key(nonce),
+                Aad::from(aad),
+                &mut in_out,
+            )
+            .expect("plaintext fits in a single AES-GCM message");
+
+        let mut sealed = nonce.to_vec();
+        sealed.append(&mut in_out);
+        sealed
+    }
+
+    /// Decrypts a value produced by [`encrypt`](Self::encrypt). Returns `None`
+    /// if it was encrypted under another key or has been tampered with.
+    pub fn decrypt(&self, sealed: &[u8]) -> Option<Vec<u8>> {
+        self.decrypt_with_aad(sealed, &[])
+    }
+
+    /// Decrypts a value produced by [`encrypt_with_aad`](Self::encrypt_with_aad).
+    /// Returns `None` if `aad` differs from the one it was sealed with, or on
+    /// any failure [`decrypt`](Self::decrypt) reports.
+    pub fn decrypt_with_aad(&self, sealed: &[u8], aad: &[u8]) -> Option<Vec<u8>> {
+        if sealed.len() < NONCE_LEN {
+            return None;
+        }
+        let (nonce, ciphertext) = sealed.split_at(NONCE_LEN);
+        let nonce = Nonce::try_assume_unique_for_key(nonce).ok()?;
+
+        let mut in_out = ciphertext.to_vec();
+        let plaintext = self
+            .key()
+            .open_in_place(nonce, Aad::from(aad), &mut in_out)
+            .ok()?;
+        Some(plaintext.to_vec())
+    }
+
+    fn key(&self) -> LessSafeKey {
+        LessSafeKey::new(UnboundKey::new(&AES_256_GCM, &self.0).expect("key is 32 bytes"))
+    }
+}
+
+impl fmt::Debug for EncryptionKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("EncryptionKey(..)")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encrypt_round_trips() {
+        let key = EncryptionKey::new([7; 32]);
+        let sealed = key.encrypt(b"hunter2");
+        assert_ne!(&sealed[NONCE_LEN..], b"hunter2");
+        assert_eq!(key.decrypt(&sealed).as_deref(), Some(&b"hunter2"[..]));
+    }
+
+    #[test]
+    fn encrypt_uses_a_fresh_nonce() {
+        let key = EncryptionKey::new([7; 32]);
+        assert_ne!(key.encrypt(b"hunter2"), key.encrypt(b"hunter2"));
+    }
+
+    #[test]
+    fn decrypt_rejects_other_keys_and_tampering() {
+        let key = EncryptionKey::new([7; 32]);
+        let mut sealed = key.encrypt(b"hunter2");
+        assert_eq!(EncryptionKey::new([8; 32]).decrypt(&sealed), None);
+
+        let last = sealed.len() - 1;
+        sealed[last] ^= 1;
+        assert_eq!(key.decrypt(&sealed), None);
+        assert_eq!(key.decrypt(&[0; 4]), None);
+    }
+
+    #[test]
+    fn decrypt_with_aad_requires_the_same_aad() {
+        let key = EncryptionKey::new([7; 32]);
+        let sealed = key.encrypt_with_aad(b"hunter2", b"repository:1:TOKEN");
+        assert_eq!(
+            key.decrypt_with_aad(&sealed, b"repository:1:TOKEN")
+                .as_deref(),
+            Some(&b"hunter2"[..])
+        );
+        assert_eq!(key.decrypt_with_aad(&sealed, b"repository:2:TOKEN"), None);
+        assert_eq!(key.decrypt(&sealed), None);
+    }
+
+    #[test]
+    fn from_base64_requires_32_bytes() {
+        assert!(EncryptionKey::from_base64(&STANDARD.encode([1; 32])).is_some());
+        assert!(EncryptionKey::from_base64(&STANDARD.encode([1; 16])).is_none());
+        assert!(EncryptionKey::from_base64("not base64!").is_none());
+    }
+}
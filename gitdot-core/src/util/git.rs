@@ -1,6 +1,8 @@
 pub const REPO_SUFFIX: &str = ".git";
 pub const DEFAULT_BRANCH: &str = "main";
 pub const ZERO_SHA: &str = "0000000000000000000000000000000000000000";
+pub const BRANCH_PREFIX: &str = "refs/heads/";
+pub const TAG_PREFIX: &str = "refs/tags/";
 
 /// include git hook scripts in the binary during compilation
 pub const PRE_RECEIVE_SCRIPT: &str = include_str!("../../hooks/pre-receive");
@@ -26,3 +28,58 @@ impl GitHookType {
         }
     }
 }
+
+/// Matches a short branch name (e.g. `release/1.0`) against a glob pattern:
+/// `*` matches within a single path segment, `**` matches across segments,
+/// and `?` matches one non-`/` character.
+pub fn matches_branch_pattern(pattern: &str, branch: &str) -> bool {
+    gitdot_config::glob::matches(pattern, branch)
+}
+
+/// Armor headers git appends to the message of a signed tag, for PGP, SSH
+/// and X.509 (`gpgsm`) signatures.
+const SIGNATURE_HEADERS: [&str; 3] = [
+    "-----BEGIN PGP SIGNATURE-----",
+    "-----BEGIN SSH SIGNATURE-----",
+    "-----BEGIN SIGNED MESSAGE-----",
+];
+
+/// Splits the signature git appends to a signed tag off its message,
+/// returning the message and the armored signature, if any.
+pub fn split_tag_signature(message: &str) -> (&str, Option<&str>) {
+    let start = SIGNATURE_HEADERS
+        .iter()
+        .filter_map(|header| {
+            message
+                .match_indices(header)
+                .map(|(i, _)| i)
+                .find(|&i| i == 0 || message.as_bytes()[i - 1] == b'\n')
+        })
+        .min();
+
+    match start {
+        Some(i) => (&message[..i], Some(&message[i..])),
+        None => (message, None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_tag_signature_separates_armored_block() {
+        let message = "Release 1.0\n\nNotes\n-----BEGIN PGP SIGNATURE-----\nabc\n-----END PGP SIGNATURE-----\n";
+        let (body, signature) = split_tag_signature(message);
+        assert_eq!(body, "Release 1.0\n\nNotes\n");
+        assert_eq!(
+            signature,
+            Some("-----BEGIN PGP SIGNATURE-----\nabc\n-----END PGP SIGNATURE-----\n")
+        );
+
+        assert_eq!(
+            split_tag_signature("mentions -----BEGIN SSH SIGNATURE----- inline\n"),
+            ("mentions -----BEGIN SSH SIGNATURE----- inline\n", None)
+        );
+    }
+}
@@ -4,3 +4,23 @@ pub fn get_github_clone_url(access_token: &str, full_name: &str) -> String {
         access_token, full_name
     )
 }
+
+/// The anonymous https remote of a GitHub repository, for credentials that are
+/// supplied separately.
+pub fn get_github_repository_url(full_name: &str) -> String {
+    format!("https://github.com/{}.git", full_name)
+}
+
+/// The `noreply` addresses GitHub verifies for `login` (account `id`): the
+/// current `{id}+{login}` form and the legacy one without the id.
+pub fn get_github_noreply_emails(id: i64, login: &str) -> [String; 2] {
+    [
+        format!("{id}+{login}@users.noreply.github.com"),
+        format!("{login}@users.noreply.github.com"),
+    ]
+}
+
+/// Credits a post imported from GitHub whose author has no gitdot account.
+pub fn attribute_github_post(login: &str, body: &str) -> String {
+    format!("*Originally posted by @{login} on GitHub*\n\n{body}")
+}
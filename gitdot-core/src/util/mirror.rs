@@ -0,0 +1,22 @@
+use uuid::Uuid;
+
+use crate::model::MirrorDirection;
+
+/// Username sent with a mirror password when none was configured. Hosts that
+/// authenticate with access tokens accept any username alongside the token.
+pub const DEFAULT_MIRROR_USERNAME: &str = "git";
+
+/// Additional authenticated data a mirror's password is sealed with. It binds
+/// the ciphertext to the mirror's repository, direction and URL, which are
+/// unique per mirror, so it cannot be moved onto a mirror pointing elsewhere.
+pub fn get_mirror_password_aad(
+    repository_id: Uuid,
+    direction: MirrorDirection,
+    url: &str,
+) -> Vec<u8> {
+    format!(
+        "mirror-password:{repository_id}:{}:{url}",
+        direction.as_str()
+    )
+    .into_bytes()
+}
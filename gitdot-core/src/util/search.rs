@@ -0,0 +1,375 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs::File,
+    io::{self, BufWriter, Write},
+    os::unix::fs::FileExt,
+    path::Path,
+};
+
+/// Directory inside a bare repository that holds its code search index.
+pub const SEARCH_INDEX_DIR: &str = "gitdot-search";
+
+/// File name of the index inside [`SEARCH_INDEX_DIR`].
+pub const SEARCH_INDEX_FILE: &str = "index";
+
+/// Blobs larger than this are left out of the index.
+pub const MAX_INDEXED_FILE_SIZE: usize = 1024 * 1024;
+
+/// Identifies the on-disk format; bumped whenever the layout changes, so an
+/// index in an older format is treated as missing.
+const MAGIC: &[u8; 8] = b"GDSRCH01";
+
+/// Magic, file count, trigram count and commit SHA length.
+const HEADER_LEN: u64 = 8 + 4 + 4 + 2;
+
+/// Trigram, posting count and posting offset.
+const TRIGRAM_ENTRY_LEN: u64 = 4 + 4 + 8;
+
+/// Offset of the file's record.
+const FILE_ENTRY_LEN: u64 = 8;
+
+/// A trigram index over the text files of one commit, as it is built.
+///
+/// Trigrams are taken from lowercased content, so the postings for a query
+/// are a superset of the files that contain it in any case. Candidates must
+/// still be confirmed against the blob contents.
+///
+/// [`write_to`](Self::write_to) lays it out as a header, a sorted trigram
+/// table, a file table, the posting lists and the file records, so a
+/// [`SearchIndexReader`] can look up a query's trigrams and candidate files
+/// with a few reads instead of loading the whole index.
+#[derive(Debug, Clone, Default)]
+pub struct SearchIndex {
+    pub commit_sha: String,
+    pub files: Vec<IndexedFile>,
+    /// Packed trigram to the sorted indices of the `files` that contain it.
+    pub postings: BTreeMap<u32, Vec<u32>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexedFile {
+    pub path: String,
+    pub blob_sha: String,
+}
+
+impl SearchIndex {
+    pub fn new(commit_sha: String) -> Self {
+        Self {
+            commit_sha,
+            ..Default::default()
+        }
+    }
+
+    pub fn add_file(&mut self, path: String, blob_sha: String, content: &[u8]) {
+        let id = self.files.len() as u32;
+        self.files.push(IndexedFile { path, blob_sha });
+        for trigram in trigrams(content) {
+            self.postings.entry(trigram).or_default().push(id);
+        }
+    }
+
+    /// Writes the index in its on-disk format. All integers are little
+    /// endian and all offsets absolute.
+    pub fn write_to(&self, writer: impl Write) -> io::Result<()> {
+        let mut writer = BufWriter::new(writer);
+        let trigram_table = HEADER_LEN + self.commit_sha.len() as u64;
+        let file_table = trigram_table + TRIGRAM_ENTRY_LEN * self.postings.len() as u64;
+        let postings_start = file_table + FILE_ENTRY_LEN * self.files.len() as u64;
+
+        writer.write_all(MAGIC)?;
+        writer.write_all(&(self.files.len() as u32).to_le_bytes())?;
+        writer.write_all(&(self.postings.len() as u32).to_le_bytes())?;
+        writer.write_all(&(self.commit_sha.len() as u16).to_le_bytes())?;
+        writer.write_all(self.commit_sha.as_bytes())?;
+
+        let mut offset = postings_start;
+        for (trigram, ids) in &self.postings {
+            writer.write_all(&trigram.to_le_bytes())?;
+            writer.write_all(&(ids.len() as u32).to_le_bytes())?;
+            writer.write_all(&offset.to_le_bytes())?;
+            offset += 4 * ids.len() as u64;
+        }
+        for file in &self.files {
+            writer.write_all(&offset.to_le_bytes())?;
+            offset += 4 + file.path.len() as u64 + 1 + file.blob_sha.len() as u64;
+        }
+
+        for ids in self.postings.values() {
+            for id in ids {
+                writer.write_all(&id.to_le_bytes())?;
+            }
+        }
+        for file in &self.files {
+            writer.write_all(&(file.path.len() as u32).to_le_bytes())?;
+            writer.write_all(file.path.as_bytes())?;
+            writer.write_all(&[file.blob_sha.len() as u8])?;
+            writer.write_all(file.blob_sha.as_bytes())?;
+        }
+        writer.flush()
+    }
+}
+
+/// Reads a [`SearchIndex`] written by [`SearchIndex::write_to`] in place,
+/// loading only the parts a query touches.
+#[derive(Debug)]
+pub struct SearchIndexReader {
+    file: File,
+    len: u64,
+    commit_sha: String,
+    file_count: u32,
+    trigram_count: u32,
+}
+
+impl SearchIndexReader {
+    /// Opens the index at `path`, or returns `Ok(None)` if there is none or
+    /// it was written in another format.
+    pub fn open(path: &Path) -> io::Result<Option<Self>> {
+        let file = match File::open(path) {
+            Ok(file) => file,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e),
+        };
+        let len = file.metadata()?.len();
+        if len < HEADER_LEN {
+            return Ok(None);
+        }
+        let mut header = [0; HEADER_LEN as usize];
+        file.read_exact_at(&mut header, 0)?;
+        if &header[..8] != MAGIC {
+            return Ok(None);
+        }
+
+        let mut reader = Self {
+            file,
+            len,
+            commit_sha: String::new(),
+            file_count: u32::from_le_bytes(header[8..12].try_into().unwrap()),
+            trigram_count: u32::from_le_bytes(header[12..16].try_into().unwrap()),
+        };
+        let sha_len = u16::from_le_bytes(header[16..18].try_into().unwrap());
+        let sha = reader.read_at(HEADER_LEN, sha_len as u64)?;
+        reader.commit_sha = String::from_utf8(sha).map_err(invalid)?;
+        Ok(Some(reader))
+    }
+
+    /// The commit the index was built from.
+    pub fn commit_sha(&self) -> &str {
+        &self.commit_sha
+    }
+
+    pub fn file_count(&self) -> u32 {
+        self.file_count
+    }
+
+    /// Returns the indices of files that may contain `literal`, or `None` when
+    /// it is too short to narrow the search.
+    pub fn candidates(&self, literal: &str) -> io::Result<Option<Vec<u32>>> {
+        let wanted = trigrams(literal.as_bytes());
+        if wanted.is_empty() {
+            return Ok(None);
+        }
+
+        let mut lists = Vec::with_capacity(wanted.len());
+        for trigram in wanted {
+            match self.find_trigram(trigram)? {
+                Some(entry) => lists.push(entry),
+                None => return Ok(Some(Vec::new())),
+            }
+        }
+        lists.sort_by_key(|(count, _)| *count);
+
+        let mut result = self.read_postings(lists[0])?;
+        for &entry in &lists[1..] {
+            if result.is_empty() {
+                break;
+            }
+            let list = self.read_postings(entry)?;
+            result.retain(|id| list.binary_search(id).is_ok());
+        }
+        Ok(Some(result))
+    }
+
+    /// Reads the path and blob of file `id`.
+    pub fn file(&self, id: u32) -> io::Result<IndexedFile> {
+        if id >= self.file_count {
+            return Err(invalid("file id out of range"));
+        }
+        let entry = self.file_table() + FILE_ENTRY_LEN * id as u64;
+        let offset = u64::from_le_bytes(self.read_array(entry)?);
+        let path_len = u32::from_le_bytes(self.read_array(offset)?) as u64;
+        let path = self.read_at(offset + 4, path_len)?;
+        let [sha_len] = self.read_array(offset + 4 + path_len)?;
+        let blob_sha = self.read_at(offset + 4 + path_len + 1, sha_len as u64)?;
+
+        Ok(IndexedFile {
+            path: String::from_utf8(path).map_err(invalid)?,
+            blob_sha: String::from_utf8(blob_sha).map_err(invalid)?,
+        })
+    }
+
+    fn trigram_table(&self) -> u64 {
+        HEADER_LEN + self.commit_sha.len() as u64
+    }
+
+    fn file_table(&self) -> u64 {
+        self.trigram_table() + TRIGRAM_ENTRY_LEN * self.trigram_count as u64
+    }
+
+    /// Binary searches the trigram table, returning the posting count and
+    /// offset of `trigram`.
+    fn find_trigram(&self, trigram: u32) -> io::Result<Option<(u32, u64)>> {
+        let (mut low, mut high) = (0, self.trigram_count as u64);
+        while low < high {
+            let mid = low + (high - low) / 2;
+            let entry: [u8; TRIGRAM_ENTRY_LEN as usize] =
+                self.read_array(self.trigram_table() + TRIGRAM_ENTRY_LEN * mid)?;
+            let found = u32::from_le_bytes(entry[..4].try_into().unwrap());
+            match found.cmp(&trigram) {
+                std::cmp::Ordering::Less => low = mid + 1,
+                std::cmp::Ordering::Greater => high = mid,
+                std::cmp::Ordering::Equal => {
+                    let count = u32::from_le_bytes(entry[4..8].try_into().unwrap());
+                    let offset = u64::from_le_bytes(entry[8..].try_into().unwrap());
+                    return Ok(Some((count, offset)));
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    fn read_postings(&self, (count, offset): (u32, u64)) -> io::Result<Vec<u32>> {
+        let bytes = self.read_at(offset, 4 * count as u64)?;
+        Ok(bytes
+            .chunks_exact(4)
+            .map(|id| u32::from_le_bytes(id.try_into().unwrap()))
+            .collect())
+    }
+
+    fn read_array<const N: usize>(&self, offset: u64) -> io::Result<[u8; N]> {
+        let mut buf = [0; N];
+        self.check_range(offset, N as u64)?;
+        self.file.read_exact_at(&mut buf, offset)?;
+        Ok(buf)
+    }
+
+    fn read_at(&self, offset: u64, len: u64) -> io::Result<Vec<u8>> {
+        self.check_range(offset, len)?;
+        let mut buf = vec![0; len as usize];
+        self.file.read_exact_at(&mut buf, offset)?;
+        Ok(buf)
+    }
+
+    /// Rejects reads past the end of the file, so a corrupt length cannot
+    /// cause a huge allocation.
+    fn check_range(&self, offset: u64, len: u64) -> io::Result<()> {
+        match offset.checked_add(len) {
+            Some(end) if end <= self.len => Ok(()),
+            _ => Err(invalid("read past the end of the index")),
+        }
+    }
+}
+
+fn invalid(e: impl Into<Box<dyn std::error::Error + Send + Sync>>) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, e)
+}
+
+/// Packs every lowercased three-byte window of `content`.
+pub fn trigrams(content: &[u8]) -> BTreeSet<u32> {
+    content
+        .windows(3)
+        .map(|w| {
+            let [a, b, c] = [w[0], w[1], w[2]].map(|b| b.to_ascii_lowercase());
+            u32::from_be_bytes([0, a, b, c])
+        })
+        .collect()
+}
+
+/// Guesses a file's language from its name, for filtering search results.
+pub fn language_for_path(path: &str) -> Option<&'static str> {
+    let name = path.rsplit('/').next().unwrap_or(path);
+    match name {
+        "Dockerfile" => return Some("dockerfile"),
+        "Makefile" | "makefile" | "GNUmakefile" => return Some("makefile"),
+        _ => {}
+    }
+
+    let extension = name.rsplit_once('.')?.1.to_ascii_lowercase();
+    let language = match extension.as_str() {
+        "rs" => "rust",
+        "go" => "go",
+        "py" => "python",
+        "rb" => "ruby",
+        "js" | "mjs" | "cjs" | "jsx" => "javascript",
+        "ts" | "tsx" | "mts" | "cts" => "typescript",
+        "java" => "java",
+        "kt" | "kts" => "kotlin",
+        "swift" => "swift",
+        "c" | "h" => "c",
+        "cc" | "cpp" | "cxx" | "hh" | "hpp" | "hxx" => "cpp",
+        "cs" => "csharp",
+        "php" => "php",
+        "scala" => "scala",
+        "hs" => "haskell",
+        "ex" | "exs" => "elixir",
+        "erl" | "hrl" => "erlang",
+        "lua" => "lua",
+        "sh" | "bash" | "zsh" => "shell",
+        "sql" => "sql",
+        "html" | "htm" => "html",
+        "css" | "scss" | "sass" => "css",
+        "json" => "json",
+        "yaml" | "yml" => "yaml",
+        "toml" => "toml",
+        "md" | "markdown" => "markdown",
+        "proto" => "protobuf",
+        "nix" => "nix",
+        "zig" => "zig",
+        _ => return None,
+    };
+    Some(language)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reader_looks_up_candidates_and_files_on_disk() {
+        let mut index = SearchIndex::new("abc".to_string());
+        index.add_file("a.rs".into(), "1".into(), b"fn parse_config() {}");
+        index.add_file("b.rs".into(), "2".into(), b"let Config = load();");
+        index.add_file("c.rs".into(), "3".into(), b"nothing here");
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(SEARCH_INDEX_FILE);
+        index.write_to(File::create(&path).unwrap()).unwrap();
+        let reader = SearchIndexReader::open(&path).unwrap().unwrap();
+
+        assert_eq!(reader.commit_sha(), "abc");
+        assert_eq!(reader.file_count(), 3);
+        assert_eq!(reader.candidates("config").unwrap(), Some(vec![0, 1]));
+        assert_eq!(reader.candidates("parse_config").unwrap(), Some(vec![0]));
+        assert_eq!(reader.candidates("missing").unwrap(), Some(vec![]));
+        assert_eq!(reader.candidates("fn").unwrap(), None);
+        assert_eq!(reader.file(1).unwrap(), index.files[1]);
+        assert!(reader.file(3).is_err());
+    }
+
+    #[test]
+    fn reader_ignores_missing_and_foreign_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(SEARCH_INDEX_FILE);
+        assert!(SearchIndexReader::open(&path).unwrap().is_none());
+
+        std::fs::write(&path, br#"{"commit_sha":"abc","files":[],"postings":{}}"#).unwrap();
+        assert!(SearchIndexReader::open(&path).unwrap().is_none());
+    }
+
+    #[test]
+    fn language_for_path_uses_name_and_extension() {
+        assert_eq!(language_for_path("src/main.rs"), Some("rust"));
+        assert_eq!(language_for_path("web/App.TSX"), Some("typescript"));
+        assert_eq!(language_for_path("docker/Dockerfile"), Some("dockerfile"));
+        assert_eq!(language_for_path("LICENSE"), None);
+    }
+}
@@ -0,0 +1,223 @@
+use base64::{Engine, engine::general_purpose::STANDARD};
+
+use crate::{dto::SshPublicKey, model::SigningKeyType};
+
+/// OpenPGP packet tags (RFC 4880 §4.3).
+pub(crate) const PGP_SIGNATURE_TAG: u8 = 2;
+pub(crate) const PGP_PUBLIC_KEY_TAG: u8 = 6;
+pub(crate) const PGP_USER_ID_TAG: u8 = 13;
+pub(crate) const PGP_PUBLIC_SUBKEY_TAG: u8 = 14;
+
+/// OpenPGP signature subpacket types (RFC 4880 §5.2.3.1, RFC 9580 §5.2.3.7).
+const PGP_ISSUER_SUBPACKET: u8 = 16;
+const PGP_ISSUER_FINGERPRINT_SUBPACKET: u8 = 33;
+
+const SSHSIG_MAGIC: &[u8] = b"SSHSIG";
+
+/// Names the key a commit signature was made with, in the form stored in
+/// `auth.signing_keys.key_ids`: the issuer fingerprint of a GPG signature
+/// (or only its 16-hex-digit key id, for signatures that lack one), or the
+/// `SHA256:` fingerprint of the key embedded in an SSH signature. `None` for malformed signatures and unsupported formats such as
+/// X.509.
+pub fn signature_key_id(signature: &str) -> Option<(SigningKeyType, String)> {
+    if let Some(data) = dearmor(signature, "PGP SIGNATURE") {
+        let (_, body) = pgp_packets(&data)?
+            .into_iter()
+            .find(|(tag, _)| *tag == PGP_SIGNATURE_TAG)?;
+        return pgp_issuer(body).map(|key_id| (SigningKeyType::Gpg, key_id));
+    }
+
+    let data = dearmor(signature, "SSH SIGNATURE")?;
+    let rest = data.strip_prefix(SSHSIG_MAGIC)?;
+    // A u32 version precedes the signer's public key blob.
+    let (key, _) = ssh_string(rest.get(4..)?)?;
+    let (key_type, _) = ssh_string(key)?;
+    let key = SshPublicKey::from_parts(
+        std::str::from_utf8(key_type).ok()?,
+        &STANDARD.encode(key),
+        "signature",
+    )
+    .ok()?;
+    Some((SigningKeyType::Ssh, key.fingerprint()))
+}
+
+/// Decodes an ASCII-armored block labelled `label` (e.g. `PGP SIGNATURE`),
+/// skipping any armor headers and the OpenPGP CRC24 checksum line.
+pub(crate) fn dearmor(armored: &str, label: &str) -> Option<Vec<u8>> {
+    let body = armored
+        .trim()
+        .strip_prefix(&format!("-----BEGIN {label}-----"))?
+        .strip_suffix(&format!("-----END {label}-----"))?;
+
+    let mut lines: Vec<&str> = body.lines().map(str::trim).collect();
+    // Armor headers (`Key: value`) are separated from the data by a blank line.
+    if let Some(blank) = lines.iter().position(|line| line.is_empty())
+        && lines[..blank].iter().all(|line| line.contains(": "))
+    {
+        lines.drain(..=blank);
+    }
+    let data: String = lines
+        .into_iter()
+        .filter(|line| !line.is_empty() && !line.starts_with('='))
+        .collect();
+
+    STANDARD.decode(data).ok()
+}
+
+/// Splits OpenPGP data into `(tag, body)` packets. Partial body lengths, only
+/// used for streamed literal data, are not supported.
+pub(crate) fn pgp_packets(mut data: &[u8]) -> Option<Vec<(u8, &[u8])>> {
+    let mut packets = Vec::new();
+    while let Some((&header, rest)) = data.split_first() {
+        if header & 0x80 == 0 {
+            return None;
+        }
+
+        let (tag, len, rest) = if header & 0x40 != 0 {
+            let (len, rest) = pgp_length(rest)?;
+            (header & 0x3f, len, rest)
+        } else {
+            let size = match header & 0x03 {
+                0 => 1,
+                1 => 2,
+                2 => 4,
+                _ => return None,
+            };
+            let (len, rest) = rest.split_at_checked(size)?;
+            let len = len.iter().fold(0, |len, &b| (len << 8) | b as usize);
+            ((header >> 2) & 0x0f, len, rest)
+        };
+
+        let (body, rest) = rest.split_at_checked(len)?;
+        packets.push((tag, body));
+        data = rest;
+    }
+
+    Some(packets)
+}
+
+/// Reads a new-format packet or subpacket length.
+fn pgp_length(data: &[u8]) -> Option<(usize, &[u8])> {
+    let (&first, rest) = data.split_first()?;
+    match first {
+        0..=191 => Some((first as usize, rest)),
+        192..=223 => {
+            let (&second, rest) = rest.split_first()?;
+            Some((((first as usize - 192) << 8) + second as usize + 192, rest))
+        }
+        255 => {
+            let (len, rest) = rest.split_at_checked(4)?;
+            Some((u32::from_be_bytes(len.try_into().ok()?) as usize, rest))
+        }
+        _ => None,
+    }
+}
+
+/// The issuer of a v3 or v4 signature packet: the fingerprint from the issuer
+/// fingerprint subpacket, or else the key id from the issuer subpacket.
+fn pgp_issuer(body: &[u8]) -> Option<String> {
+    match *body.first()? {
+        3 => body.get(7..15).map(hex::encode_upper),
+        4 => {
+            let hashed_len = u16::from_be_bytes(body.get(4..6)?.try_into().ok()?) as usize;
+            let hashed = body.get(6..6 + hashed_len)?;
+            let at = 6 + hashed_len;
+            let unhashed_len = u16::from_be_bytes(body.get(at..at + 2)?.try_into().ok()?) as usize;
+            let unhashed = body.get(at + 2..at + 2 + unhashed_len)?;
+
+            let subpackets = pgp_subpackets(hashed)?
+                .into_iter()
+                .chain(pgp_subpackets(unhashed)?)
+                .collect::<Vec<_>>();
+            let fingerprint = subpackets.iter().find_map(|(kind, data)| {
+                // A version byte precedes the 20-byte v4 fingerprint.
+                (*kind == PGP_ISSUER_FINGERPRINT_SUBPACKET && data.len() == 21)
+                    .then(|| hex::encode_upper(&data[1..]))
+            });
+            fingerprint.or_else(|| {
+                subpackets.iter().find_map(|(kind, data)| {
+                    (*kind == PGP_ISSUER_SUBPACKET && data.len() == 8)
+                        .then(|| hex::encode_upper(data))
+                })
+            })
+        }
+        _ => None,
+    }
+}
+
+fn pgp_subpackets(mut data: &[u8]) -> Option<Vec<(u8, &[u8])>> {
+    let mut subpackets = Vec::new();
+    while !data.is_empty() {
+        let (len, rest) = pgp_length(data)?;
+        let (subpacket, rest) = rest.split_at_checked(len)?;
+        let (&kind, body) = subpacket.split_first()?;
+        // The high bit marks the subpacket critical.
+        subpackets.push((kind & 0x7f, body));
+        data = rest;
+    }
+
+    Some(subpackets)
+}
+
+/// Reads an SSH wire-format string (u32 length + bytes).
+fn ssh_string(data: &[u8]) -> Option<(&[u8], &[u8])> {
+    let (len, rest) = data.split_at_checked(4)?;
+    rest.split_at_checked(u32::from_be_bytes(len.try_into().ok()?) as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // `gpg --detach-sign` with the ed25519 signing subkey 402AADEBC60029CF.
+    const GPG_SIGNATURE: &str = "-----BEGIN PGP SIGNATURE-----
+
+iHUEABYIAB0WIQS52tpfyVYvdurB8HdAKq3rxgApzwUCatUaJwAKCRBAKq3rxgAp
+z7NFAQCJzS4dZpp/cZOgZavqsB+51l0UYWsYbRXdhtBqEo9MfAEAjVVc8yvAZdpe
+Sl+gYeWItCGN+e0JvOe/kcS+bEeDXwk=
+=RClh
+-----END PGP SIGNATURE-----
+";
+
+    // `ssh-keygen -Y sign -n git`, fingerprint as printed by `ssh-keygen -l`.
+    const SSH_SIGNATURE: &str = "-----BEGIN SSH SIGNATURE-----
+U1NIU0lHAAAAAQAAADMAAAALc3NoLWVkMjU1MTkAAAAgVzhxqEnGydlvBTtSsMyWpoDDQM
+apOdRNeOYRFsuMLaUAAAADZ2l0AAAAAAAAAAZzaGE1MTIAAABTAAAAC3NzaC1lZDI1NTE5
+AAAAQKExOepW5bZorNFN1KoMj79Dxrx6nHHNLOzGYZ724sk4wM42s3bJJXLFi+sd/mKjLM
+1TTZIQ4kE/TYN/nGE2dws=
+-----END SSH SIGNATURE-----
+";
+    const SSH_FINGERPRINT: &str = "SHA256:KiC09V0FHSf11LDskwMpSTTaeCgv/aKlrwbYSh16s38";
+
+    #[test]
+    fn names_the_gpg_issuer() {
+        assert_eq!(
+            signature_key_id(GPG_SIGNATURE),
+            Some((
+                SigningKeyType::Gpg,
+                "B9DADA5FC9562F76EAC1F077402AADEBC60029CF".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn names_the_ssh_signer() {
+        assert_eq!(
+            signature_key_id(SSH_SIGNATURE),
+            Some((SigningKeyType::Ssh, SSH_FINGERPRINT.to_string()))
+        );
+    }
+
+    #[test]
+    fn rejects_unsupported_signatures() {
+        for signature in [
+            "",
+            "not a signature",
+            "-----BEGIN SIGNED MESSAGE-----\nMIAGCSqGSIb3DQEHAqCAMIACAQEx\n-----END SIGNED MESSAGE-----",
+            "-----BEGIN PGP SIGNATURE-----\n\nAAAA\n-----END PGP SIGNATURE-----",
+            "-----BEGIN SSH SIGNATURE-----\nU1NIU0lH\n-----END SSH SIGNATURE-----",
+        ] {
+            assert_eq!(signature_key_id(signature), None, "{signature}");
+        }
+    }
+}
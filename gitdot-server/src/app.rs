@@ -1,5 +1,8 @@
 mod bootstrap;
 mod error;
+mod maintenance;
+mod mirror;
+mod reaper;
 mod response;
 mod settings;
 mod state;
@@ -14,7 +17,10 @@ use sqlx::PgPool;
 use tokio::net;
 use tower::ServiceBuilder;
 use tower_http::{
-    compression::CompressionLayer,
+    compression::{
+        CompressionLayer,
+        predicate::{DefaultPredicate, NotForContentType, Predicate},
+    },
     cors::{AllowOrigin, CorsLayer},
     request_id::{MakeRequestUuid, PropagateRequestIdLayer, SetRequestIdLayer},
     timeout::TimeoutLayer,
@@ -24,12 +30,15 @@ use tower_http::{
 use gitdot_axum::middleware::{create_rate_limiter, log_request};
 
 use crate::handler::{
-    create_git_http_router, create_internal_router, create_migration_router,
-    create_organization_router, create_repository_router, create_user_router,
-    create_webhook_router,
+    create_branch_protection_router, create_code_search_router, create_collaborator_router,
+    create_git_http_router, create_internal_router, create_maintenance_router,
+    create_migration_router, create_mirror_router, create_organization_router,
+    create_release_router, create_repository_router, create_tag_router, create_team_router,
+    create_user_router, create_webhook_router,
 };
 
 pub use error::AppError;
+pub use mirror::create_mirror_commits;
 pub use response::AppResponse;
 pub use settings::Settings;
 pub use state::AppState;
@@ -49,6 +58,22 @@ impl GitdotServer {
         let settings = Arc::new(Settings::new()?);
         let pool = PgPool::connect(settings.database_url.expose_secret()).await?;
         let state = AppState::new(settings.clone(), pool).await?;
+        tokio::spawn(reaper::run_task_reaper(state.task_service.clone()));
+        tokio::spawn(maintenance::run_maintenance_scheduler(
+            state.maintenance_service.clone(),
+        ));
+        tokio::spawn(mirror::run_mirror_scheduler(
+            state.github_webhook_service.clone(),
+            state.commit_service.clone(),
+        ));
+        tokio::spawn(mirror::run_remote_mirror_scheduler(
+            state.mirror_service.clone(),
+            state.commit_service.clone(),
+        ));
+        tokio::spawn(mirror::run_cutover_backfill_scheduler(
+            state.migration_service.clone(),
+            state.commit_service.clone(),
+        ));
         let router = create_router(state);
         let listener = tokio::net::TcpListener::bind(&settings.get_server_address())
             .await
@@ -81,13 +106,21 @@ fn create_router(app_state: AppState) -> Router {
         .layer(SetRequestIdLayer::x_request_id(MakeRequestUuid))
         .layer(TraceLayer::new_for_http())
         .layer(from_fn(log_request))
-        .layer(CompressionLayer::new())
+        .layer(
+            // Archives are already compressed.
+            CompressionLayer::new().compress_when(
+                DefaultPredicate::new()
+                    .and(NotForContentType::const_new("application/gzip"))
+                    .and(NotForContentType::const_new("application/zip")),
+            ),
+        )
         .layer(
             CorsLayer::new()
                 .allow_origin(AllowOrigin::list([web_origin]))
                 .allow_methods([
                     http::Method::GET,
                     http::Method::POST,
+                    http::Method::PUT,
                     http::Method::PATCH,
                     http::Method::DELETE,
                     http::Method::HEAD,
@@ -109,7 +142,15 @@ fn create_router(app_state: AppState) -> Router {
         .merge(create_user_router())
         .merge(create_organization_router())
         .merge(create_repository_router())
+        .merge(create_collaborator_router())
+        .merge(create_team_router())
+        .merge(create_branch_protection_router())
+        .merge(create_maintenance_router())
+        .merge(create_code_search_router())
+        .merge(create_tag_router())
+        .merge(create_release_router())
         .merge(create_migration_router())
+        .merge(create_mirror_router())
         .merge(create_webhook_router())
         .layer(api_middleware);
 
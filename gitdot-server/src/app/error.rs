@@ -8,9 +8,11 @@ use thiserror::Error;
 use gitdot_api::ApiResource;
 use gitdot_axum::error::TokenExtractionError;
 use gitdot_core::error::{
-    AuthorizationError, BuildError, CommitError, GitHttpError, MigrationError, OrganizationError,
-    QuestionError, RepositoryError, ReviewError, RunnerError, TaskError, TokenServiceError,
-    UserError, WebhookError,
+    ArtifactError, AuthorizationError, BranchProtectionError, BuildError, CiSecretError,
+    CodeSearchError, CollaboratorError, CommitError, GitHttpError, MaintenanceError,
+    MigrationError, MirrorError, OrganizationError, QuestionError, ReleaseError, RepositoryError,
+    ReviewError, RunnerError, SigningKeyError, SshKeyError, TagError, TaskError, TeamError,
+    TokenServiceError, UserError, WebhookError,
 };
 
 use super::AppResponse;
@@ -23,6 +25,12 @@ pub enum AppError {
     #[error(transparent)]
     TokenService(#[from] TokenServiceError),
 
+    #[error(transparent)]
+    SshKey(#[from] SshKeyError),
+
+    #[error(transparent)]
+    SigningKey(#[from] SigningKeyError),
+
     #[error(transparent)]
     Authorization(#[from] AuthorizationError),
 
@@ -35,6 +43,12 @@ pub enum AppError {
     #[error(transparent)]
     Repository(#[from] RepositoryError),
 
+    #[error(transparent)]
+    Collaborator(#[from] CollaboratorError),
+
+    #[error(transparent)]
+    Team(#[from] TeamError),
+
     #[error(transparent)]
     Commit(#[from] CommitError),
 
@@ -44,18 +58,42 @@ pub enum AppError {
     #[error(transparent)]
     Review(#[from] ReviewError),
 
+    #[error(transparent)]
+    BranchProtection(#[from] BranchProtectionError),
+
+    #[error(transparent)]
+    Maintenance(#[from] MaintenanceError),
+
+    #[error(transparent)]
+    CodeSearch(#[from] CodeSearchError),
+
+    #[error(transparent)]
+    Tag(#[from] TagError),
+
+    #[error(transparent)]
+    Release(#[from] ReleaseError),
+
     #[error(transparent)]
     Migration(#[from] MigrationError),
 
+    #[error(transparent)]
+    Mirror(#[from] MirrorError),
+
     #[error(transparent)]
     GitHttp(#[from] GitHttpError),
 
     #[error(transparent)]
     Runner(#[from] RunnerError),
 
+    #[error(transparent)]
+    Artifact(#[from] ArtifactError),
+
     #[error(transparent)]
     Build(#[from] BuildError),
 
+    #[error(transparent)]
+    CiSecret(#[from] CiSecretError),
+
     #[error(transparent)]
     Task(#[from] TaskError),
 
@@ -85,6 +123,29 @@ impl HttpStatus for TokenExtractionError {
             | Self::InvalidPublicKey(_)
             | Self::InvalidToken(_)
             | Self::Unauthorized => StatusCode::UNAUTHORIZED,
+            Self::Forbidden(_) => StatusCode::FORBIDDEN,
+        }
+    }
+}
+
+impl HttpStatus for SshKeyError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Self::Input(_) => StatusCode::BAD_REQUEST,
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::Conflict(_) => StatusCode::CONFLICT,
+            Self::DatabaseError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl HttpStatus for SigningKeyError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Self::Input(_) => StatusCode::BAD_REQUEST,
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::Conflict(_) => StatusCode::CONFLICT,
+            Self::DatabaseError(_) | Self::SignatureError(_) => StatusCode::INTERNAL_SERVER_ERROR,
         }
     }
 }
@@ -93,6 +154,9 @@ impl HttpStatus for TokenServiceError {
     fn status_code(&self) -> StatusCode {
         match self {
             Self::Unauthorized => StatusCode::UNAUTHORIZED,
+            Self::MissingScope(_) => StatusCode::FORBIDDEN,
+            Self::Input(_) => StatusCode::BAD_REQUEST,
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
             Self::TokenError(_) | Self::DatabaseError(_) => StatusCode::INTERNAL_SERVER_ERROR,
         }
     }
@@ -143,7 +207,9 @@ impl HttpStatus for RepositoryError {
             Self::Input(_) | Self::TooManyPaths => StatusCode::BAD_REQUEST,
             Self::NotFound(_) | Self::NotAFile(_) => StatusCode::NOT_FOUND,
             Self::Conflict(_) => StatusCode::CONFLICT,
-            Self::GitError(_) | Self::DatabaseError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            Self::GitError(_) | Self::SignatureError(_) | Self::DatabaseError(_) => {
+                StatusCode::INTERNAL_SERVER_ERROR
+            }
         }
     }
 }
@@ -153,7 +219,9 @@ impl HttpStatus for CommitError {
         match self {
             Self::Input(_) => StatusCode::BAD_REQUEST,
             Self::NotFound(_) => StatusCode::NOT_FOUND,
-            Self::GitError(_) | Self::DatabaseError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            Self::GitError(_) | Self::SignatureError(_) | Self::DatabaseError(_) => {
+                StatusCode::INTERNAL_SERVER_ERROR
+            }
         }
     }
 }
@@ -181,13 +249,70 @@ impl HttpStatus for ReviewError {
             | Self::CommitsNotFound
             | Self::InvalidIdentifier => StatusCode::BAD_REQUEST,
             Self::NotFound(_) => StatusCode::NOT_FOUND,
-            Self::Conflict(_) | Self::DiffNotMergeable(_) => StatusCode::CONFLICT,
+            Self::Conflict(_) | Self::DiffNotMergeable(_) | Self::MergeRequirementsNotMet(_) => {
+                StatusCode::CONFLICT
+            }
             Self::NotOrgAdmin(_) => StatusCode::FORBIDDEN,
             Self::GitError(_) | Self::DatabaseError(_) => StatusCode::INTERNAL_SERVER_ERROR,
         }
     }
 }
 
+impl HttpStatus for BranchProtectionError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Self::Input(_) => StatusCode::BAD_REQUEST,
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::Conflict(_) => StatusCode::CONFLICT,
+            Self::DatabaseError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl HttpStatus for MaintenanceError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Self::Input(_) => StatusCode::BAD_REQUEST,
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::GitError(_) | Self::DatabaseError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl HttpStatus for CodeSearchError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Self::Input(_) => StatusCode::BAD_REQUEST,
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::GitError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl HttpStatus for TagError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Self::Input(_) => StatusCode::BAD_REQUEST,
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::Conflict(_) => StatusCode::CONFLICT,
+            Self::GitError(_) | Self::DatabaseError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl HttpStatus for ReleaseError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Self::Input(_) => StatusCode::BAD_REQUEST,
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::Conflict(_) => StatusCode::CONFLICT,
+            Self::GitError(_) | Self::R2Error(_) | Self::DatabaseError(_) => {
+                StatusCode::INTERNAL_SERVER_ERROR
+            }
+        }
+    }
+}
+
 impl HttpStatus for MigrationError {
     fn status_code(&self) -> StatusCode {
         match self {
@@ -201,6 +326,17 @@ impl HttpStatus for MigrationError {
     }
 }
 
+impl HttpStatus for MirrorError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Self::Input(_) => StatusCode::BAD_REQUEST,
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::Conflict(_) => StatusCode::CONFLICT,
+            Self::GitError(_) | Self::DatabaseError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
 impl HttpStatus for GitHttpError {
     fn status_code(&self) -> StatusCode {
         match self {
@@ -221,12 +357,55 @@ impl HttpStatus for RunnerError {
     }
 }
 
+impl HttpStatus for ArtifactError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Self::Input(_) => StatusCode::BAD_REQUEST,
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::DatabaseError(_) | Self::R2Error(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl HttpStatus for CiSecretError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Self::Input(_) => StatusCode::BAD_REQUEST,
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::DatabaseError(_) | Self::DecryptionError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl HttpStatus for CollaboratorError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Self::Input(_) => StatusCode::BAD_REQUEST,
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::Conflict(_) => StatusCode::CONFLICT,
+            Self::DatabaseError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl HttpStatus for TeamError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Self::Input(_) => StatusCode::BAD_REQUEST,
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::Conflict(_) => StatusCode::CONFLICT,
+            Self::DatabaseError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
 impl HttpStatus for BuildError {
     fn status_code(&self) -> StatusCode {
         match self {
             Self::Input(_) => StatusCode::BAD_REQUEST,
             Self::NotFound(_) => StatusCode::NOT_FOUND,
             Self::InvalidConfig(_) => StatusCode::UNPROCESSABLE_ENTITY,
+            Self::TaskNotRetryable(_) => StatusCode::CONFLICT,
             Self::GitError(_) | Self::JoinError(_) | Self::DatabaseError(_) | Self::S2Error(_) => {
                 StatusCode::INTERNAL_SERVER_ERROR
             }
@@ -264,17 +443,29 @@ impl HttpStatus for AppError {
         match self {
             AppError::TokenExtraction(e) => e.status_code(),
             AppError::TokenService(e) => e.status_code(),
+            AppError::SshKey(e) => e.status_code(),
+            AppError::SigningKey(e) => e.status_code(),
             AppError::Authorization(e) => e.status_code(),
             AppError::User(e) => e.status_code(),
             AppError::Organization(e) => e.status_code(),
             AppError::Repository(e) => e.status_code(),
+            AppError::Collaborator(e) => e.status_code(),
+            AppError::Team(e) => e.status_code(),
             AppError::Commit(e) => e.status_code(),
             AppError::Question(e) => e.status_code(),
             AppError::Review(e) => e.status_code(),
+            AppError::BranchProtection(e) => e.status_code(),
+            AppError::Maintenance(e) => e.status_code(),
+            AppError::CodeSearch(e) => e.status_code(),
+            AppError::Tag(e) => e.status_code(),
+            AppError::Release(e) => e.status_code(),
             AppError::Migration(e) => e.status_code(),
+            AppError::Mirror(e) => e.status_code(),
             AppError::GitHttp(e) => e.status_code(),
             AppError::Runner(e) => e.status_code(),
+            AppError::Artifact(e) => e.status_code(),
             AppError::Build(e) => e.status_code(),
+            AppError::CiSecret(e) => e.status_code(),
             AppError::Task(e) => e.status_code(),
             AppError::Webhook(e) => e.status_code(),
             AppError::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
@@ -0,0 +1,35 @@
+use std::{sync::Arc, time::Duration};
+
+use tokio::time::MissedTickBehavior;
+
+use gitdot_core::{model::MaintenanceStatus, service::MaintenanceService};
+
+const SCHEDULE_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Repositories maintained per pass. Runs are sequential and can take a while
+/// on large repositories, so a small batch keeps each pass short.
+const BATCH_SIZE: i64 = 4;
+
+/// Repacks and reindexes repositories that have accumulated pushes or had
+/// maintenance requested, so clones and fetches stay fast.
+pub async fn run_maintenance_scheduler(maintenance_service: Arc<dyn MaintenanceService>) {
+    let mut interval = tokio::time::interval(SCHEDULE_INTERVAL);
+    interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    loop {
+        interval.tick().await;
+        match maintenance_service.run_due_maintenance(BATCH_SIZE).await {
+            Ok(runs) => {
+                for run in runs {
+                    if run.last_status == Some(MaintenanceStatus::Success) {
+                        tracing::info!(
+                            owner = %run.owner_name,
+                            repo = %run.repository_name,
+                            "maintained repository"
+                        );
+                    }
+                }
+            }
+            Err(e) => tracing::error!("Failed to run repository maintenance: {e}"),
+        }
+    }
+}
@@ -0,0 +1,149 @@
+use std::{sync::Arc, time::Duration};
+
+use tokio::time::MissedTickBehavior;
+
+use gitdot_core::{
+    dto::{CreateCommitsRequest, SyncedRepositoryInfo},
+    model::MirrorDirection,
+    service::{CommitService, GithubWebhookService, MigrationService, MirrorService},
+};
+
+const SCHEDULE_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Mirrors re-fetched per pass. Each fetch goes out to GitHub, so a small
+/// batch keeps a pass short; mirrors that are not reached wait for the next.
+const BATCH_SIZE: i64 = 8;
+
+/// Shorter than [`SCHEDULE_INTERVAL`] so a push reaches push mirrors soon
+/// after it lands.
+const REMOTE_SCHEDULE_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Re-fetches GitHub mirrors that have not synced recently, catching up on
+/// pushes whose webhooks were dropped or failed.
+pub async fn run_mirror_scheduler(
+    github_webhook_service: Arc<dyn GithubWebhookService>,
+    commit_service: Arc<dyn CommitService>,
+) {
+    let mut interval = tokio::time::interval(SCHEDULE_INTERVAL);
+    interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    loop {
+        interval.tick().await;
+        match github_webhook_service
+            .reconcile_github_mirrors(BATCH_SIZE)
+            .await
+        {
+            Ok(synced) => {
+                for info in synced {
+                    if !info.ref_updates.is_empty() {
+                        tracing::info!(
+                            owner = %info.owner_name,
+                            repo = %info.repo_name,
+                            refs = info.ref_updates.len(),
+                            "reconciled mirror"
+                        );
+                    }
+                    create_mirror_commits(commit_service.as_ref(), info).await;
+                }
+            }
+            Err(e) => tracing::error!("Failed to reconcile mirrors: {e}"),
+        }
+    }
+}
+
+/// Syncs the pull and push mirrors that are due: pull mirrors on their
+/// interval, push mirrors after a push, and either when a sync was requested.
+pub async fn run_remote_mirror_scheduler(
+    mirror_service: Arc<dyn MirrorService>,
+    commit_service: Arc<dyn CommitService>,
+) {
+    let mut interval = tokio::time::interval(REMOTE_SCHEDULE_INTERVAL);
+    interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    loop {
+        interval.tick().await;
+        match mirror_service.run_due_mirrors(BATCH_SIZE).await {
+            Ok(synced) => {
+                for result in synced {
+                    let mirror = result.mirror;
+                    if mirror.direction != MirrorDirection::Pull || result.ref_updates.is_empty() {
+                        continue;
+                    }
+                    tracing::info!(
+                        owner = %mirror.owner_name,
+                        repo = %mirror.repository_name,
+                        refs = result.ref_updates.len(),
+                        "pulled mirror"
+                    );
+                    let info = SyncedRepositoryInfo {
+                        owner_name: mirror.owner_name,
+                        repo_name: mirror.repository_name,
+                        ref_updates: result.ref_updates,
+                    };
+                    create_mirror_commits(commit_service.as_ref(), info).await;
+                }
+            }
+            Err(e) => tracing::error!("Failed to run mirror syncs: {e}"),
+        }
+    }
+}
+
+/// Records the history of repositories cut over from GitHub mirrors as
+/// commits. A backfill is only marked finished once every ref was recorded,
+/// so one that failed or was cut short by a restart is claimed again later.
+pub async fn run_cutover_backfill_scheduler(
+    migration_service: Arc<dyn MigrationService>,
+    commit_service: Arc<dyn CommitService>,
+) {
+    let mut interval = tokio::time::interval(SCHEDULE_INTERVAL);
+    interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    loop {
+        interval.tick().await;
+        match migration_service.claim_cutover_backfills(BATCH_SIZE).await {
+            Ok(backfills) => {
+                for backfill in backfills {
+                    let info = backfill.repository;
+                    let (owner, repo) = (info.owner_name.clone(), info.repo_name.clone());
+                    if !create_mirror_commits(commit_service.as_ref(), info).await {
+                        tracing::warn!(%owner, %repo, "cutover backfill incomplete, will retry");
+                        continue;
+                    }
+                    if let Err(e) = migration_service
+                        .finish_cutover_backfill(backfill.cutover_id)
+                        .await
+                    {
+                        tracing::error!(%owner, %repo, "Failed to finish cutover backfill: {e}");
+                    }
+                }
+            }
+            Err(e) => tracing::error!("Failed to claim cutover backfills: {e}"),
+        }
+    }
+}
+
+/// Records the commits a mirror sync brought in, one ref update at a time.
+/// Returns whether every ref update was recorded.
+pub async fn create_mirror_commits(
+    commit_service: &dyn CommitService,
+    info: SyncedRepositoryInfo,
+) -> bool {
+    let mut recorded = true;
+    for update in info.ref_updates {
+        if let Ok(req) = CreateCommitsRequest::new(
+            &info.owner_name,
+            &info.repo_name,
+            update.old_sha,
+            update.new_sha,
+            update.ref_name,
+            None,
+            Default::default(),
+        ) && let Err(e) = commit_service.create_commits(req).await
+        {
+            tracing::warn!(
+                owner = %info.owner_name,
+                repo = %info.repo_name,
+                "failed to record mirrored commits: {e}"
+            );
+            recorded = false;
+        }
+    }
+    recorded
+}
@@ -0,0 +1,25 @@
+use std::{sync::Arc, time::Duration};
+
+use gitdot_core::service::TaskService;
+
+const REAP_INTERVAL: Duration = Duration::from_secs(30);
+
+/// How long a runner may go without heartbeating or polling before its tasks
+/// are timed out. Runners heartbeat every 15 seconds while executing a task.
+const HEARTBEAT_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Times out CI tasks whose runner died or that overran their timeout, so
+/// their builds finish instead of staying running forever.
+pub async fn run_task_reaper(task_service: Arc<dyn TaskService>) {
+    let mut interval = tokio::time::interval(REAP_INTERVAL);
+    loop {
+        interval.tick().await;
+        match task_service.reap_tasks(HEARTBEAT_TIMEOUT).await {
+            Ok(tasks) if !tasks.is_empty() => {
+                tracing::warn!(count = tasks.len(), "timed out stale CI tasks");
+            }
+            Ok(_) => {}
+            Err(e) => tracing::error!("Failed to reap stale CI tasks: {e}"),
+        }
+    }
+}
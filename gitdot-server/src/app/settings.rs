@@ -22,6 +22,7 @@ pub struct Settings {
     pub gitdot_private_key: SecretString,
     pub gitdot_slack_secret: SecretString,
     pub gitdot_github_secret: SecretString,
+    pub gitdot_ci_secrets_key: SecretString,
 
     // app URLs
     #[serde(default = "default_web_url")]
@@ -42,10 +43,16 @@ pub struct Settings {
     pub github_client_id: String,
     pub github_client_secret: SecretString,
 
-    // cloudflare
+    // object storage: a local directory when `object_storage_path` is set
+    // (self-hosting), otherwise cloudflare r2
+    pub object_storage_path: Option<String>,
+    #[serde(default)]
     pub cloudflare_account_id: String,
+    #[serde(default)]
     pub cloudflare_r2_bucket_name: String,
+    #[serde(default)]
     pub cloudflare_r2_access_key_id: String,
+    #[serde(default = "default_secret")]
     pub cloudflare_r2_secret_access_key: SecretString,
 }
 
@@ -75,6 +82,10 @@ fn default_slack_bot_url() -> String {
     "http://localhost:3001".into()
 }
 
+fn default_secret() -> SecretString {
+    SecretString::from("")
+}
+
 fn default_kafka_bootstrap_servers() -> String {
     "localhost:9092".into()
 }
@@ -1,5 +1,6 @@
 use std::sync::Arc;
 
+use anyhow::Context;
 use axum::extract::FromRef;
 use jsonwebtoken::jwk::JwkSet;
 use secrecy::ExposeSecret;
@@ -8,24 +9,35 @@ use sqlx::PgPool;
 use gitdot_core::{
     client::{
         Git2Client, GitHttpClientImpl, ImageClientImpl, KafkaClientImpl, OctocrabClient,
-        R2ClientImpl, S2ClientImpl, SlackBotClientImpl, TokenClientImpl,
+        R2ClientImpl, S2ClientImpl, SignatureClientImpl, SlackBotClientImpl, TokenClientImpl,
+        WebhookClientImpl,
     },
     repository::{
-        PgBuildRepository, PgCommitRepository, PgGitHubRepository, PgMigrationRepository,
-        PgOrganizationRepository, PgQuestionRepository, PgRepositoryRepository, PgReviewRepository,
-        PgRunnerRepository, PgSlackWebhookRepository, PgTaskRepository, PgTokenRepository,
-        PgUserRepository, PgWebhookRepository,
+        PgArtifactRepository, PgBranchProtectionRepository, PgBuildRepository, PgCacheRepository,
+        PgCiSecretRepository, PgCollaboratorRepository, PgCommitRepository, PgGitHubRepository,
+        PgMaintenanceRepository, PgMigrationRepository, PgMirrorRepository,
+        PgOrganizationRepository, PgQuestionRepository, PgReleaseRepository,
+        PgRepositoryRepository, PgReviewRepository, PgRunnerRepository, PgSigningKeyRepository,
+        PgSlackWebhookRepository, PgSshKeyRepository, PgTaskRepository, PgTeamRepository,
+        PgTokenRepository, PgUserRepository, PgWebhookRepository,
     },
     service::{
-        AuthorizationService, AuthorizationServiceImpl, BuildService, BuildServiceImpl,
-        CommitService, CommitServiceImpl, EventService, EventServiceImpl, GitHttpService,
-        GitHttpServiceImpl, GithubWebhookService, GithubWebhookServiceImpl, MigrationService,
-        MigrationServiceImpl, OrganizationService, OrganizationServiceImpl, QuestionService,
-        QuestionServiceImpl, RepositoryService, RepositoryServiceImpl, ReviewService,
-        ReviewServiceImpl, RunnerService, RunnerServiceImpl, SlackWebhookService,
-        SlackWebhookServiceImpl, TaskService, TaskServiceImpl, TokenService, TokenServiceImpl,
-        UserService, UserServiceImpl, WebhookService, WebhookServiceImpl,
+        ArtifactService, ArtifactServiceImpl, AuthorizationService, AuthorizationServiceImpl,
+        BranchProtectionService, BranchProtectionServiceImpl, BuildService, BuildServiceImpl,
+        CiSecretService, CiSecretServiceImpl, CodeSearchService, CodeSearchServiceImpl,
+        CollaboratorService, CollaboratorServiceImpl, CommitService, CommitServiceImpl,
+        EventService, EventServiceImpl, GitHttpService, GitHttpServiceImpl, GithubWebhookService,
+        GithubWebhookServiceImpl, MaintenanceService, MaintenanceServiceImpl, MigrationService,
+        MigrationServiceImpl, MirrorService, MirrorServiceImpl, OrganizationService,
+        OrganizationServiceImpl, QuestionService, QuestionServiceImpl, ReleaseService,
+        ReleaseServiceImpl, RepositoryService, RepositoryServiceImpl, ReviewService,
+        ReviewServiceImpl, RunnerService, RunnerServiceImpl, SigningKeyService,
+        SigningKeyServiceImpl, SlackWebhookService, SlackWebhookServiceImpl, SshKeyService,
+        SshKeyServiceImpl, TagService, TagServiceImpl, TaskService, TaskServiceImpl, TeamService,
+        TeamServiceImpl, TokenService, TokenServiceImpl, UserService, UserServiceImpl,
+        WebhookService, WebhookServiceImpl,
     },
+    util::crypto::EncryptionKey,
 };
 
 use super::Settings;
@@ -36,6 +48,8 @@ pub struct AppState {
 
     // auth + authz
     pub token_service: Arc<dyn TokenService>,
+    pub ssh_key_service: Arc<dyn SshKeyService>,
+    pub signing_key_service: Arc<dyn SigningKeyService>,
     pub authorization_service: Arc<dyn AuthorizationService>,
 
     // core services
@@ -43,12 +57,20 @@ pub struct AppState {
     pub org_service: Arc<dyn OrganizationService>,
     pub git_http_service: Arc<dyn GitHttpService>,
     pub repo_service: Arc<dyn RepositoryService>,
+    pub collaborator_service: Arc<dyn CollaboratorService>,
+    pub team_service: Arc<dyn TeamService>,
     pub question_service: Arc<dyn QuestionService>,
     pub review_service: Arc<dyn ReviewService>,
     pub commit_service: Arc<dyn CommitService>,
+    pub branch_protection_service: Arc<dyn BranchProtectionService>,
+    pub maintenance_service: Arc<dyn MaintenanceService>,
+    pub code_search_service: Arc<dyn CodeSearchService>,
+    pub tag_service: Arc<dyn TagService>,
+    pub release_service: Arc<dyn ReleaseService>,
 
     // migration services
     pub migration_service: Arc<dyn MigrationService>,
+    pub mirror_service: Arc<dyn MirrorService>,
 
     // webhook services
     pub webhook_service: Arc<dyn WebhookService>,
@@ -57,7 +79,9 @@ pub struct AppState {
     pub event_service: Arc<dyn EventService>,
 
     // ci services
+    pub artifact_service: Arc<dyn ArtifactService>,
     pub build_service: Arc<dyn BuildService>,
+    pub ci_secret_service: Arc<dyn CiSecretService>,
     pub runner_service: Arc<dyn RunnerService>,
     pub task_service: Arc<dyn TaskService>,
 
@@ -67,19 +91,30 @@ pub struct AppState {
 impl AppState {
     pub async fn new(settings: Arc<Settings>, pool: PgPool) -> anyhow::Result<Self> {
         let token_repo = PgTokenRepository::new(pool.clone());
+        let ssh_key_repo = PgSshKeyRepository::new(pool.clone());
+        let signing_key_repo = PgSigningKeyRepository::new(pool.clone());
         let user_repo = PgUserRepository::new(pool.clone());
         let org_repo = PgOrganizationRepository::new(pool.clone());
         let repo_repo = PgRepositoryRepository::new(pool.clone());
+        let collaborator_repo = PgCollaboratorRepository::new(pool.clone());
+        let team_repo = PgTeamRepository::new(pool.clone());
         let question_repo = PgQuestionRepository::new(pool.clone());
         let review_repo = PgReviewRepository::new(pool.clone());
         let commit_repo = PgCommitRepository::new(pool.clone());
         let github_repo = PgGitHubRepository::new(pool.clone());
         let migration_repo = PgMigrationRepository::new(pool.clone());
+        let mirror_repo = PgMirrorRepository::new(pool.clone());
         let webhook_repo = PgWebhookRepository::new(pool.clone());
         let build_repo = PgBuildRepository::new(pool.clone());
         let runner_repo = PgRunnerRepository::new(pool.clone());
         let task_repo = PgTaskRepository::new(pool.clone());
+        let artifact_repo = PgArtifactRepository::new(pool.clone());
+        let cache_repo = PgCacheRepository::new(pool.clone());
+        let ci_secret_repo = PgCiSecretRepository::new(pool.clone());
         let slack_webhook_repo = PgSlackWebhookRepository::new(pool.clone());
+        let branch_protection_repo = PgBranchProtectionRepository::new(pool.clone());
+        let maintenance_repo = PgMaintenanceRepository::new(pool.clone());
+        let release_repo = PgReleaseRepository::new(pool.clone());
 
         let git_client = Git2Client::new(settings.git_project_root.clone());
         let git_http_client = GitHttpClientImpl::new(settings.git_project_root.clone());
@@ -104,16 +139,27 @@ impl AppState {
         let kafka_client =
             KafkaClientImpl::new(&settings.kafka_bootstrap_servers, settings.kafka_auth).await?;
         let image_client = ImageClientImpl::new();
-        let r2_client = R2ClientImpl::new(
-            settings.cloudflare_account_id.clone(),
-            settings.cloudflare_r2_bucket_name.clone(),
-            settings.cloudflare_r2_access_key_id.clone(),
-            settings
-                .cloudflare_r2_secret_access_key
-                .expose_secret()
-                .to_string(),
-        )
-        .await;
+        let webhook_client = WebhookClientImpl::new();
+        let signature_client = SignatureClientImpl::new();
+        let r2_client = match &settings.object_storage_path {
+            Some(path) => R2ClientImpl::filesystem(path),
+            None => {
+                R2ClientImpl::new(
+                    settings.cloudflare_account_id.clone(),
+                    settings.cloudflare_r2_bucket_name.clone(),
+                    settings.cloudflare_r2_access_key_id.clone(),
+                    settings
+                        .cloudflare_r2_secret_access_key
+                        .expose_secret()
+                        .to_string(),
+                )
+                .await
+            }
+        };
+
+        let encryption_key =
+            EncryptionKey::from_base64(settings.gitdot_ci_secrets_key.expose_secret())
+                .context("GITDOT_CI_SECRETS_KEY must be a base64 encoded 32-byte key")?;
 
         let vercel_jwks = {
             let jwks_url = format!("{}/.well-known/jwks", settings.vercel_oidc_url);
@@ -128,6 +174,12 @@ impl AppState {
                 slack_bot_client.clone(),
                 token_client.clone(),
             )),
+            ssh_key_service: Arc::new(SshKeyServiceImpl::new(ssh_key_repo)),
+            signing_key_service: Arc::new(SigningKeyServiceImpl::new(
+                signing_key_repo.clone(),
+                user_repo.clone(),
+                signature_client.clone(),
+            )),
             authorization_service: Arc::new(AuthorizationServiceImpl::new(
                 org_repo.clone(),
                 repo_repo.clone(),
@@ -158,6 +210,19 @@ impl AppState {
                 repo_repo.clone(),
                 commit_repo.clone(),
                 user_repo.clone(),
+                signing_key_repo.clone(),
+                signature_client.clone(),
+            )),
+            collaborator_service: Arc::new(CollaboratorServiceImpl::new(
+                collaborator_repo.clone(),
+                repo_repo.clone(),
+                user_repo.clone(),
+            )),
+            team_service: Arc::new(TeamServiceImpl::new(
+                team_repo.clone(),
+                org_repo.clone(),
+                repo_repo.clone(),
+                user_repo.clone(),
             )),
             git_http_service: Arc::new(GitHttpServiceImpl::new(git_http_client.clone())),
             question_service: Arc::new(QuestionServiceImpl::new(
@@ -169,13 +234,38 @@ impl AppState {
                 repo_repo.clone(),
                 user_repo.clone(),
                 git_client.clone(),
+                build_repo.clone(),
             )),
             commit_service: Arc::new(CommitServiceImpl::new(
                 commit_repo.clone(),
+                repo_repo.clone(),
+                user_repo.clone(),
+                signing_key_repo.clone(),
+                git_client.clone(),
+                signature_client.clone(),
+            )),
+            branch_protection_service: Arc::new(BranchProtectionServiceImpl::new(
+                branch_protection_repo.clone(),
+                repo_repo.clone(),
+                build_repo.clone(),
+            )),
+            maintenance_service: Arc::new(MaintenanceServiceImpl::new(
+                maintenance_repo.clone(),
+                repo_repo.clone(),
+                git_client.clone(),
+            )),
+            code_search_service: Arc::new(CodeSearchServiceImpl::new(git_client.clone())),
+            tag_service: Arc::new(TagServiceImpl::new(
                 repo_repo.clone(),
                 user_repo.clone(),
                 git_client.clone(),
             )),
+            release_service: Arc::new(ReleaseServiceImpl::new(
+                release_repo.clone(),
+                repo_repo.clone(),
+                git_client.clone(),
+                r2_client.clone(),
+            )),
             migration_service: Arc::new(MigrationServiceImpl::new(
                 git_client.clone(),
                 github_client.clone(),
@@ -184,10 +274,21 @@ impl AppState {
                 org_repo.clone(),
                 github_repo.clone(),
                 user_repo.clone(),
+                question_repo.clone(),
+                review_repo.clone(),
+                encryption_key.clone(),
+            )),
+            mirror_service: Arc::new(MirrorServiceImpl::new(
+                mirror_repo.clone(),
+                repo_repo.clone(),
+                migration_repo.clone(),
+                git_client.clone(),
+                encryption_key.clone(),
             )),
             webhook_service: Arc::new(WebhookServiceImpl::new(
                 webhook_repo.clone(),
                 repo_repo.clone(),
+                webhook_client.clone(),
             )),
             slack_webhook_service: Arc::new(SlackWebhookServiceImpl::new(
                 slack_webhook_repo.clone(),
@@ -203,9 +304,18 @@ impl AppState {
             )),
             event_service: Arc::new(EventServiceImpl::new(
                 user_repo.clone(),
+                review_repo.clone(),
                 git_client.clone(),
                 kafka_client.clone(),
             )),
+            artifact_service: Arc::new(ArtifactServiceImpl::new(
+                r2_client.clone(),
+                artifact_repo.clone(),
+                cache_repo.clone(),
+                task_repo.clone(),
+                build_repo.clone(),
+                repo_repo.clone(),
+            )),
             build_service: Arc::new(BuildServiceImpl::new(
                 git_client.clone(),
                 s2_client.clone(),
@@ -213,6 +323,14 @@ impl AppState {
                 task_repo.clone(),
                 repo_repo.clone(),
             )),
+            ci_secret_service: Arc::new(CiSecretServiceImpl::new(
+                ci_secret_repo.clone(),
+                repo_repo.clone(),
+                org_repo.clone(),
+                task_repo.clone(),
+                build_repo.clone(),
+                encryption_key,
+            )),
 
             runner_service: Arc::new(RunnerServiceImpl::new(
                 runner_repo.clone(),
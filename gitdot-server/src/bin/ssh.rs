@@ -0,0 +1,125 @@
+//! Glue between OpenSSH's sshd and gitdot, in the style of gitlab-shell.
+//!
+//! - `gitdot-ssh authorized-keys <type> <key>` is the `AuthorizedKeysCommand`:
+//!   it prints an `authorized_keys` line for a registered key that forces
+//!   every session through `serve`.
+//! - `gitdot-ssh serve <key_id>` is that forced command: it authorizes
+//!   `SSH_ORIGINAL_COMMAND` against the server and execs the git service.
+//!
+//! Both talk to the server's localhost-only internal API, so all parsing and
+//! permission checks stay in the server.
+
+use std::{env, os::unix::process::CommandExt, process::Command};
+
+use anyhow::{Context, bail};
+use serde::{Deserialize, de::DeserializeOwned};
+use serde_json::json;
+use uuid::Uuid;
+
+const DEFAULT_INTERNAL_URL: &str = "http://127.0.0.1:8080";
+
+#[derive(Deserialize)]
+struct FindSshKeyResponse {
+    key_id: Uuid,
+}
+
+#[derive(Deserialize)]
+struct AuthorizeSshCommandResponse {
+    user_id: Uuid,
+    service: String,
+    repository_path: String,
+}
+
+#[derive(Deserialize)]
+struct ErrorResponse {
+    message: String,
+}
+
+#[tokio::main]
+async fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let result = match args.iter().map(String::as_str).collect::<Vec<_>>()[..] {
+        ["authorized-keys", key_type, key] => authorized_keys(key_type, key).await,
+        ["serve", key_id] => serve(key_id).await,
+        _ => Err(anyhow::anyhow!(
+            "usage: gitdot-ssh authorized-keys <type> <key> | gitdot-ssh serve <key_id>"
+        )),
+    };
+
+    if let Err(e) = result {
+        eprintln!("gitdot: {e:#}");
+        std::process::exit(1);
+    }
+}
+
+/// Prints the `authorized_keys` line for a registered key, or nothing if no
+/// user has registered it, which makes sshd reject the key.
+async fn authorized_keys(key_type: &str, key: &str) -> anyhow::Result<()> {
+    let response = post(
+        "/internal/ssh/find-key",
+        json!({ "key_type": key_type, "key": key }),
+    )
+    .await?;
+    if response.status() == reqwest::StatusCode::NOT_FOUND {
+        return Ok(());
+    }
+    let response: FindSshKeyResponse = read(response).await?;
+
+    let exe = env::current_exe().context("failed to resolve gitdot-ssh path")?;
+    println!(
+        "restrict,command=\"{} serve {}\" {key_type} {key}",
+        exe.display(),
+        response.key_id
+    );
+    Ok(())
+}
+
+async fn serve(key_id: &str) -> anyhow::Result<()> {
+    let key_id: Uuid = key_id.parse().context("invalid key id")?;
+    let Ok(command) = env::var("SSH_ORIGINAL_COMMAND") else {
+        bail!("interactive shells are not supported; use git to push and fetch");
+    };
+
+    let response = post(
+        "/internal/ssh/authorize-command",
+        json!({ "key_id": key_id, "command": command }),
+    )
+    .await?;
+    let response: AuthorizeSshCommandResponse = read(response).await?;
+
+    // `exec` only returns on failure. `GIT_PROTOCOL` is passed through from
+    // sshd's `AcceptEnv` untouched.
+    let subcommand = response.service.trim_start_matches("git-");
+    let err = Command::new("git")
+        .arg(subcommand)
+        .arg(&response.repository_path)
+        .env("GITDOT_PUSHER_ID", response.user_id.to_string())
+        .exec();
+    Err(err).context("failed to run git")
+}
+
+async fn post(path: &str, body: serde_json::Value) -> anyhow::Result<reqwest::Response> {
+    let base_url =
+        env::var("GITDOT_SSH_INTERNAL_URL").unwrap_or_else(|_| DEFAULT_INTERNAL_URL.to_string());
+    reqwest::Client::new()
+        .post(format!("{base_url}{path}"))
+        .json(&body)
+        .send()
+        .await
+        .context("failed to reach gitdot server")
+}
+
+/// Decodes a successful response, or fails with the server's error message.
+async fn read<T: DeserializeOwned>(response: reqwest::Response) -> anyhow::Result<T> {
+    let status = response.status();
+    if !status.is_success() {
+        let message = response
+            .json::<ErrorResponse>()
+            .await
+            .map(|e| e.message)
+            .unwrap_or_else(|_| status.to_string());
+        bail!(message);
+    }
+
+    Ok(response.json().await?)
+}
@@ -1,18 +1,31 @@
+mod artifact;
+mod branch_protection;
 mod build;
+mod code_search;
+mod collaborator;
 mod git_http;
 mod internal;
+mod maintenance;
 mod migration;
+mod mirror;
 mod organization;
 mod question;
+mod release;
 mod repository;
 mod review;
 mod runner;
+mod secret;
+mod tag;
 mod task;
+mod team;
 mod user;
 mod webhook;
 
+pub use artifact::ArtifactServerResponse;
 pub use git_http::*;
 pub use internal::*;
+pub use release::ReleaseAssetServerResponse;
+pub use repository::RepositoryArchiveServerResponse;
 pub use webhook::*;
 
 pub trait IntoApi {
@@ -0,0 +1,66 @@
+use axum::{
+    body::Body,
+    http::{StatusCode, header},
+    response::{IntoResponse, Response},
+};
+
+use gitdot_api::resource::build as api;
+use gitdot_core::dto::{ArtifactDownload, ArtifactResponse};
+
+use super::IntoApi;
+
+impl IntoApi for ArtifactResponse {
+    type ApiType = api::ArtifactResource;
+    fn into_api(self) -> Self::ApiType {
+        api::ArtifactResource {
+            id: self.id,
+            build_id: self.build_id,
+            task_id: self.task_id,
+            name: self.name,
+            size_bytes: self.size_bytes,
+            created_at: self.created_at,
+        }
+    }
+}
+
+/// A gzipped tarball sent as an attachment named after the archived path.
+pub struct ArtifactServerResponse(ArtifactDownload);
+
+impl From<ArtifactDownload> for ArtifactServerResponse {
+    fn from(download: ArtifactDownload) -> Self {
+        Self(download)
+    }
+}
+
+impl IntoResponse for ArtifactServerResponse {
+    fn into_response(self) -> Response {
+        let file_name: String = self
+            .0
+            .name
+            .trim_end_matches('/')
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-') {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+
+        Response::builder()
+            .status(StatusCode::OK)
+            .header(header::CONTENT_TYPE, "application/gzip")
+            .header(
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{file_name}.tar.gz\""),
+            )
+            .body(Body::from(self.0.bytes))
+            .unwrap_or_else(|_| {
+                Response::builder()
+                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                    .body(Body::empty())
+                    .unwrap()
+            })
+    }
+}
@@ -0,0 +1,21 @@
+use gitdot_api::resource::branch_protection as api;
+use gitdot_core::dto::BranchProtectionResponse;
+
+use super::IntoApi;
+
+impl IntoApi for BranchProtectionResponse {
+    type ApiType = api::BranchProtectionResource;
+    fn into_api(self) -> Self::ApiType {
+        api::BranchProtectionResource {
+            id: self.id,
+            repository_id: self.repository_id,
+            pattern: self.pattern,
+            block_force_push: self.block_force_push,
+            block_deletion: self.block_deletion,
+            require_review: self.require_review,
+            require_green_build: self.require_green_build,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        }
+    }
+}
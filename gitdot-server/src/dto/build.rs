@@ -14,6 +14,9 @@ impl IntoApi for BuildResponse {
             trigger: self.trigger.into(),
             commit_sha: self.commit_sha,
             status: self.status.into(),
+            review_id: self.review_id,
+            diff_id: self.diff_id,
+            revision_id: self.revision_id,
             total_tasks: self.total_tasks,
             completed_tasks: self.completed_tasks,
             created_at: self.created_at,
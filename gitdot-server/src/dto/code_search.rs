@@ -0,0 +1,51 @@
+use gitdot_api::resource::code_search as api;
+use gitdot_core::dto::{CodeSearchFileResponse, CodeSearchMatch, CodeSearchResponse, MatchRange};
+
+use super::IntoApi;
+
+impl IntoApi for CodeSearchResponse {
+    type ApiType = api::CodeSearchResource;
+    fn into_api(self) -> Self::ApiType {
+        api::CodeSearchResource {
+            commit_sha: self.commit_sha,
+            files: self.files.into_api(),
+            truncated: self.truncated,
+        }
+    }
+}
+
+impl IntoApi for CodeSearchFileResponse {
+    type ApiType = api::CodeSearchFileResource;
+    fn into_api(self) -> Self::ApiType {
+        api::CodeSearchFileResource {
+            path: self.path,
+            blob_sha: self.blob_sha,
+            language: self.language,
+            match_count: self.match_count,
+            matches: self.matches.into_api(),
+        }
+    }
+}
+
+impl IntoApi for CodeSearchMatch {
+    type ApiType = api::CodeSearchMatchResource;
+    fn into_api(self) -> Self::ApiType {
+        api::CodeSearchMatchResource {
+            line_number: self.line_number,
+            content: self.content,
+            ranges: self.ranges.into_api(),
+            before: self.before,
+            after: self.after,
+        }
+    }
+}
+
+impl IntoApi for MatchRange {
+    type ApiType = api::MatchRangeResource;
+    fn into_api(self) -> Self::ApiType {
+        api::MatchRangeResource {
+            start: self.start,
+            end: self.end,
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use gitdot_api::resource::collaborator as api;
+use gitdot_core::dto::{CollaboratorResponse, RepositoryInvitationResponse};
+
+use super::IntoApi;
+
+impl IntoApi for CollaboratorResponse {
+    type ApiType = api::CollaboratorResource;
+    fn into_api(self) -> Self::ApiType {
+        api::CollaboratorResource {
+            user_id: self.user_id,
+            user_name: self.user_name,
+            role: self.role.into(),
+            created_at: self.created_at,
+            image_updated_at: self.image_updated_at,
+        }
+    }
+}
+
+impl IntoApi for RepositoryInvitationResponse {
+    type ApiType = api::RepositoryInvitationResource;
+    fn into_api(self) -> Self::ApiType {
+        api::RepositoryInvitationResource {
+            id: self.id,
+            owner_name: self.owner_name,
+            repository_name: self.repository_name,
+            invitee_name: self.invitee_name,
+            inviter_name: self.inviter_name,
+            role: self.role.into(),
+            created_at: self.created_at,
+        }
+    }
+}
@@ -1,6 +1,12 @@
+use axum::{
+    http::header,
+    response::{IntoResponse, Response},
+};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use gitdot_core::dto::RefUpdateRejection;
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ProcessPostReceiveServerRequest {
     pub old_sha: String,
@@ -30,3 +36,55 @@ pub struct ProcessReviewServerResponse {
 }
 
 impl gitdot_api::ApiResource for ProcessReviewServerResponse {}
+
+/// The hook's reply: one rejection reason per line, or an empty body when the
+/// push may proceed. Plain text keeps the hook free of any JSON handling.
+pub struct ProcessPreReceiveServerResponse(pub Vec<RefUpdateRejection>);
+
+impl IntoResponse for ProcessPreReceiveServerResponse {
+    fn into_response(self) -> Response {
+        let body: String = self
+            .0
+            .into_iter()
+            .map(|r| {
+                let reason: String = r
+                    .reason
+                    .chars()
+                    .map(|c| if c.is_control() { ' ' } else { c })
+                    .collect();
+                format!("{reason}\n")
+            })
+            .collect();
+
+        ([(header::CONTENT_TYPE, "text/plain; charset=utf-8")], body).into_response()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FindSshKeyServerRequest {
+    pub key_type: String,
+    pub key: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FindSshKeyServerResponse {
+    pub key_id: Uuid,
+    pub user_id: Uuid,
+}
+
+impl gitdot_api::ApiResource for FindSshKeyServerResponse {}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AuthorizeSshCommandServerRequest {
+    pub key_id: Uuid,
+    pub command: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuthorizeSshCommandServerResponse {
+    pub user_id: Uuid,
+    pub service: String,
+    pub repository_path: String,
+}
+
+impl gitdot_api::ApiResource for AuthorizeSshCommandServerResponse {}
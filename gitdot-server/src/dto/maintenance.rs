@@ -0,0 +1,20 @@
+use gitdot_api::resource::maintenance as api;
+use gitdot_core::dto::MaintenanceResponse;
+
+use super::IntoApi;
+
+impl IntoApi for MaintenanceResponse {
+    type ApiType = api::MaintenanceResource;
+    fn into_api(self) -> Self::ApiType {
+        api::MaintenanceResource {
+            repository_id: self.repository_id,
+            pending_pushes: self.pending_pushes,
+            last_pushed_at: self.last_pushed_at,
+            requested_at: self.requested_at,
+            started_at: self.started_at,
+            finished_at: self.finished_at,
+            last_status: self.last_status.map(Into::into),
+            last_error: self.last_error,
+        }
+    }
+}
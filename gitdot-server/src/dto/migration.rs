@@ -6,10 +6,11 @@ use gitdot_core::{
     dto::{
         CreateGitHubInstallationResponse, GetGitHubAppInstallUrlResponse, GitHubAppInstallAction,
         GitHubInstallationResponse, GitHubRepositoryResponse, MigrationRepositoryResponse,
-        MigrationResponse,
+        MigrationResponse, MirrorCutoverResponse, RepositoryMirrorResponse,
     },
     model::{
         GitHubInstallationType, MigrationOriginService, MigrationRepositoryStatus, MigrationStatus,
+        MirrorSyncStatus,
     },
 };
 
@@ -111,12 +112,44 @@ impl IntoApi for MigrationRepositoryResponse {
             status: match self.status {
                 MigrationRepositoryStatus::Pending => "pending".to_string(),
                 MigrationRepositoryStatus::Running => "running".to_string(),
+                MigrationRepositoryStatus::Importing => "importing".to_string(),
                 MigrationRepositoryStatus::Completed => "completed".to_string(),
                 MigrationRepositoryStatus::Failed => "failed".to_string(),
             },
             error: self.error,
+            imported_questions: self.imported_questions,
+            imported_reviews: self.imported_reviews,
             created_at: self.created_at,
             updated_at: self.updated_at,
         }
     }
 }
+
+impl IntoApi for RepositoryMirrorResponse {
+    type ApiType = api::RepositoryMirrorResource;
+    fn into_api(self) -> Self::ApiType {
+        api::RepositoryMirrorResource {
+            origin_full_name: self.origin_full_name,
+            status: self.status.map(|status| match status {
+                MirrorSyncStatus::Synced => "synced".to_string(),
+                MirrorSyncStatus::Failed => "failed".to_string(),
+            }),
+            error: self.error,
+            last_attempted_at: self.last_attempted_at,
+            last_synced_at: self.last_synced_at,
+        }
+    }
+}
+
+impl IntoApi for MirrorCutoverResponse {
+    type ApiType = api::MirrorCutoverResource;
+    fn into_api(self) -> Self::ApiType {
+        api::MirrorCutoverResource {
+            id: self.id,
+            origin_full_name: self.origin_full_name,
+            actor_id: self.actor_id,
+            push_mirror_id: self.push_mirror_id,
+            created_at: self.created_at,
+        }
+    }
+}
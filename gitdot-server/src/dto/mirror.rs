@@ -0,0 +1,40 @@
+use gitdot_api::resource::mirror as api;
+use gitdot_core::dto::{MirrorResponse, MirrorRunResponse};
+
+use super::IntoApi;
+
+impl IntoApi for MirrorResponse {
+    type ApiType = api::MirrorResource;
+    fn into_api(self) -> Self::ApiType {
+        api::MirrorResource {
+            id: self.id,
+            repository_id: self.repository_id,
+            direction: self.direction.into(),
+            url: self.url,
+            username: self.username,
+            has_password: self.has_password,
+            interval_seconds: self.interval_seconds,
+            requested_at: self.requested_at,
+            started_at: self.started_at,
+            finished_at: self.finished_at,
+            last_synced_at: self.last_synced_at,
+            last_status: self.last_status.map(Into::into),
+            last_error: self.last_error,
+            created_at: self.created_at,
+            runs: self.runs.into_api(),
+        }
+    }
+}
+
+impl IntoApi for MirrorRunResponse {
+    type ApiType = api::MirrorRunResource;
+    fn into_api(self) -> Self::ApiType {
+        api::MirrorRunResource {
+            id: self.id,
+            status: self.status.into(),
+            error: self.error,
+            started_at: self.started_at,
+            finished_at: self.finished_at,
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use axum::{
+    body::Body,
+    http::{StatusCode, header},
+    response::{IntoResponse, Response},
+};
+
+use gitdot_api::resource::release as api;
+use gitdot_core::dto::{ReleaseAssetDownload, ReleaseAssetResponse, ReleaseResponse};
+
+use super::IntoApi;
+
+impl IntoApi for ReleaseResponse {
+    type ApiType = api::ReleaseResource;
+    fn into_api(self) -> Self::ApiType {
+        api::ReleaseResource {
+            id: self.id,
+            tag_name: self.tag_name,
+            name: self.name,
+            body: self.body,
+            draft: self.draft,
+            prerelease: self.prerelease,
+            author_name: self.author_name,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+            published_at: self.published_at,
+            assets: self.assets.into_api(),
+        }
+    }
+}
+
+impl IntoApi for ReleaseAssetResponse {
+    type ApiType = api::ReleaseAssetResource;
+    fn into_api(self) -> Self::ApiType {
+        api::ReleaseAssetResource {
+            id: self.id,
+            name: self.name,
+            content_type: self.content_type,
+            size_bytes: self.size_bytes,
+            created_at: self.created_at,
+        }
+    }
+}
+
+/// A release asset sent as an attachment under its uploaded name and content
+/// type.
+pub struct ReleaseAssetServerResponse(ReleaseAssetDownload);
+
+impl From<ReleaseAssetDownload> for ReleaseAssetServerResponse {
+    fn from(download: ReleaseAssetDownload) -> Self {
+        Self(download)
+    }
+}
+
+impl IntoResponse for ReleaseAssetServerResponse {
+    fn into_response(self) -> Response {
+        let file_name: String = self
+            .0
+            .name
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-') {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+
+        Response::builder()
+            .status(StatusCode::OK)
+            .header(header::CONTENT_TYPE, self.0.content_type)
+            .header(
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{file_name}\""),
+            )
+            .body(Body::from(self.0.bytes))
+            .unwrap_or_else(|_| {
+                Response::builder()
+                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                    .body(Body::empty())
+                    .unwrap()
+            })
+    }
+}
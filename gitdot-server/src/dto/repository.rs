@@ -1,9 +1,18 @@
+use axum::{
+    body::Body,
+    http::{HeaderMap, StatusCode, header},
+    response::{IntoResponse, Response},
+};
+use futures::TryStreamExt;
+
 use gitdot_api::resource::repository as api;
 use gitdot_core::{
     dto::{
-        CommitAuthorResponse, CommitResponse, PathType, RepositoryActivityEvent,
-        RepositoryBlobPairResponse, RepositoryBlobResponse, RepositoryBlobsResponse,
-        RepositoryCommitFilterResponse, RepositoryCommitResponse, RepositoryCommitsResponse,
+        BlameCommitResponse, BlameRange, CommitAuthorResponse, CommitResponse, DiffHunkResponse,
+        DiffLineResponse, FileDiffResponse, PathType, RepositoryActivityEvent,
+        RepositoryArchiveResponse, RepositoryBlameResponse, RepositoryBlobPairResponse,
+        RepositoryBlobResponse, RepositoryBlobsResponse, RepositoryCommitFilterResponse,
+        RepositoryCommitResponse, RepositoryCommitsResponse, RepositoryCompareResponse,
         RepositoryPath, RepositoryPathsResponse, RepositoryResponse,
     },
     model::CommitDiff,
@@ -56,6 +65,7 @@ impl IntoApi for CommitResponse {
             review_number: self.review_number,
             diff_position: self.diff_position,
             diffs: self.diffs.into_iter().map(|d| d.into_api()).collect(),
+            signature_status: self.signature_status.map(|s| s.as_str().to_string()),
         }
     }
 }
@@ -76,6 +86,7 @@ impl IntoApi for RepositoryCommitResponse {
             review_number: None,
             diff_position: None,
             diffs: vec![],
+            signature_status: None,
         }
     }
 }
@@ -92,6 +103,113 @@ impl IntoApi for CommitAuthorResponse {
     }
 }
 
+impl IntoApi for RepositoryBlameResponse {
+    type ApiType = api::RepositoryBlameResource;
+    fn into_api(self) -> Self::ApiType {
+        api::RepositoryBlameResource {
+            ref_name: self.ref_name,
+            commit_sha: self.commit_sha,
+            path: self.path,
+            ranges: self.ranges.into_api(),
+            commits: self.commits.into_api(),
+        }
+    }
+}
+
+impl IntoApi for BlameRange {
+    type ApiType = api::BlameRangeResource;
+    fn into_api(self) -> Self::ApiType {
+        api::BlameRangeResource {
+            start_line: self.start_line,
+            line_count: self.line_count,
+            commit_sha: self.commit_sha,
+        }
+    }
+}
+
+impl IntoApi for BlameCommitResponse {
+    type ApiType = api::RepositoryCommitResource;
+    fn into_api(self) -> Self::ApiType {
+        api::RepositoryCommitResource {
+            owner_name: String::new(),
+            repo_name: String::new(),
+            sha: self.sha,
+            parent_sha: self
+                .parent_sha
+                .unwrap_or_else(|| "0000000000000000000000000000000000000000".to_string()),
+            message: self.message,
+            date: self.date,
+            author: api::CommitAuthorResource {
+                id: self.author_id,
+                name: self.author_name,
+                git_name: self.git_author_name,
+                image_updated_at: self.author_image_updated_at,
+            },
+            review_number: None,
+            diff_position: None,
+            diffs: vec![],
+            signature_status: None,
+        }
+    }
+}
+
+impl IntoApi for RepositoryCompareResponse {
+    type ApiType = api::RepositoryCompareResource;
+    fn into_api(self) -> Self::ApiType {
+        api::RepositoryCompareResource {
+            base_sha: self.base_sha,
+            head_sha: self.head_sha,
+            merge_base_sha: self.merge_base_sha,
+            commits: self.commits.into_api(),
+            total_commits: self.total_commits,
+            files: self.files.into_api(),
+            total_files: self.total_files,
+            next_cursor: self.next_cursor,
+        }
+    }
+}
+
+impl IntoApi for FileDiffResponse {
+    type ApiType = api::FileDiffResource;
+    fn into_api(self) -> Self::ApiType {
+        api::FileDiffResource {
+            path: self.path,
+            old_path: self.old_path,
+            status: self.status.into(),
+            binary: self.binary,
+            lines_added: self.lines_added,
+            lines_removed: self.lines_removed,
+            hunks: self.hunks.into_api(),
+        }
+    }
+}
+
+impl IntoApi for DiffHunkResponse {
+    type ApiType = api::DiffHunkResource;
+    fn into_api(self) -> Self::ApiType {
+        api::DiffHunkResource {
+            header: self.header,
+            old_start: self.old_start,
+            old_lines: self.old_lines,
+            new_start: self.new_start,
+            new_lines: self.new_lines,
+            lines: self.lines.into_api(),
+        }
+    }
+}
+
+impl IntoApi for DiffLineResponse {
+    type ApiType = api::DiffLineResource;
+    fn into_api(self) -> Self::ApiType {
+        api::DiffLineResource {
+            origin: self.origin.to_string(),
+            old_lineno: self.old_lineno,
+            new_lineno: self.new_lineno,
+            content: self.content,
+        }
+    }
+}
+
 impl IntoApi for RepositoryBlobResponse {
     type ApiType = api::RepositoryBlobResource;
     fn into_api(self) -> Self::ApiType {
@@ -200,3 +318,65 @@ impl IntoApi for RepositoryCommitFilterResponse {
         }
     }
 }
+
+/// A streamed repository archive sent as an attachment. The ETag is derived
+/// from the tree SHA and file name, so a client holding the same archive gets
+/// a `304 Not Modified` without a body.
+pub struct RepositoryArchiveServerResponse {
+    archive: RepositoryArchiveResponse,
+    not_modified: bool,
+}
+
+impl RepositoryArchiveServerResponse {
+    pub fn new(archive: RepositoryArchiveResponse, headers: &HeaderMap) -> Self {
+        let etag = Self::etag(&archive);
+        let not_modified = headers
+            .get_all(header::IF_NONE_MATCH)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(','))
+            .any(|tag| {
+                let tag = tag.trim();
+                tag == "*" || tag.trim_start_matches("W/") == etag
+            });
+        Self {
+            archive,
+            not_modified,
+        }
+    }
+
+    fn etag(archive: &RepositoryArchiveResponse) -> String {
+        format!("\"{}-{}\"", archive.tree_sha, archive.file_name)
+    }
+}
+
+impl IntoResponse for RepositoryArchiveServerResponse {
+    fn into_response(self) -> Response {
+        let etag = Self::etag(&self.archive);
+        let builder = Response::builder().header(header::ETAG, etag);
+
+        let response = if self.not_modified {
+            builder.status(StatusCode::NOT_MODIFIED).body(Body::empty())
+        } else {
+            let archive = self.archive;
+            builder
+                .status(StatusCode::OK)
+                .header(header::CONTENT_TYPE, archive.format.content_type())
+                .header(header::CONTENT_LENGTH, archive.size)
+                .header(
+                    header::CONTENT_DISPOSITION,
+                    format!("attachment; filename=\"{}\"", archive.file_name),
+                )
+                .body(Body::from_stream(
+                    archive.body.map_ok(axum::body::Bytes::from),
+                ))
+        };
+
+        response.unwrap_or_else(|_| {
+            Response::builder()
+                .status(StatusCode::INTERNAL_SERVER_ERROR)
+                .body(Body::empty())
+                .unwrap()
+        })
+    }
+}
@@ -1,7 +1,8 @@
 use gitdot_api::resource::review as api;
 use gitdot_core::dto::{
-    DiffResponse, ReviewAuthorResponse, ReviewCommentResponse, ReviewResponse,
-    ReviewVerdictResponse, ReviewerResponse, RevisionResponse,
+    DiffResponse, MergeCheckResponse, MergeRequirementsResponse, ReviewAuthorResponse,
+    ReviewCommentResponse, ReviewMergeabilityResponse, ReviewResponse, ReviewVerdictResponse,
+    ReviewerResponse, RevisionResponse,
 };
 
 use super::IntoApi;
@@ -66,6 +67,7 @@ impl IntoApi for RevisionResponse {
             parent_hash: self.parent_hash,
             created_at: self.created_at,
             verdicts: self.verdicts.into_api(),
+            signature_status: self.signature_status.map(|s| s.as_str().to_string()),
         }
     }
 }
@@ -121,3 +123,36 @@ impl IntoApi for ReviewCommentResponse {
         }
     }
 }
+
+impl IntoApi for MergeRequirementsResponse {
+    type ApiType = api::MergeRequirementsResource;
+    fn into_api(self) -> Self::ApiType {
+        api::MergeRequirementsResource {
+            required_approvals: self.required_approvals,
+            block_on_rejection: self.block_on_rejection,
+            require_resolved_comments: self.require_resolved_comments,
+            require_green_build: self.require_green_build,
+        }
+    }
+}
+
+impl IntoApi for ReviewMergeabilityResponse {
+    type ApiType = api::ReviewMergeabilityResource;
+    fn into_api(self) -> Self::ApiType {
+        api::ReviewMergeabilityResource {
+            mergeable: self.mergeable,
+            checks: self.checks.into_api(),
+        }
+    }
+}
+
+impl IntoApi for MergeCheckResponse {
+    type ApiType = api::MergeCheckResource;
+    fn into_api(self) -> Self::ApiType {
+        api::MergeCheckResource {
+            kind: self.kind,
+            passed: self.passed,
+            message: self.message,
+        }
+    }
+}
@@ -0,0 +1,15 @@
+use gitdot_api::resource::secret as api;
+use gitdot_core::dto::CiSecretResponse;
+
+use super::IntoApi;
+
+impl IntoApi for CiSecretResponse {
+    type ApiType = api::SecretResource;
+    fn into_api(self) -> Self::ApiType {
+        api::SecretResource {
+            name: self.name,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use gitdot_api::resource::tag as api;
+use gitdot_core::dto::{TagResponse, TaggerResponse};
+
+use super::IntoApi;
+
+impl IntoApi for TagResponse {
+    type ApiType = api::TagResource;
+    fn into_api(self) -> Self::ApiType {
+        api::TagResource {
+            name: self.name,
+            sha: self.sha,
+            commit: self.commit.into_api(),
+            annotated: self.annotated,
+            message: self.message,
+            tagger: self.tagger.into_api(),
+            signature: self.signature,
+        }
+    }
+}
+
+impl IntoApi for TaggerResponse {
+    type ApiType = api::TaggerResource;
+    fn into_api(self) -> Self::ApiType {
+        api::TaggerResource {
+            name: self.name,
+            email: self.email,
+            date: self.date,
+        }
+    }
+}
@@ -15,6 +15,18 @@ impl IntoApi for TaskResponse {
             command: self.command,
             status: self.status.into(),
             waits_for: self.waits_for,
+            attempt: self.attempt,
+            env: self.options.env,
+            timeout_seconds: self.options.timeout_seconds,
+            retries: self.options.retries,
+            working_directory: self.options.working_directory,
+            allow_failure: self.options.allow_failure,
+            artifacts: self.options.artifacts,
+            cache_key: self.options.cache_key,
+            cache_paths: self.options.cache_paths,
+            image: self.options.image,
+            network: self.options.network.into(),
+            secrets: self.options.secrets,
             created_at: self.created_at,
             updated_at: self.updated_at,
         }
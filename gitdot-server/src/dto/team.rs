@@ -0,0 +1,42 @@
+use gitdot_api::resource::team as api;
+use gitdot_core::dto::{TeamMemberResponse, TeamRepositoryResponse, TeamResponse};
+
+use super::IntoApi;
+
+impl IntoApi for TeamResponse {
+    type ApiType = api::TeamResource;
+    fn into_api(self) -> Self::ApiType {
+        api::TeamResource {
+            id: self.id,
+            name: self.name,
+            description: self.description,
+            created_at: self.created_at,
+            members: self.members.into_api(),
+            repositories: self.repositories.into_api(),
+        }
+    }
+}
+
+impl IntoApi for TeamMemberResponse {
+    type ApiType = api::TeamMemberResource;
+    fn into_api(self) -> Self::ApiType {
+        api::TeamMemberResource {
+            user_id: self.user_id,
+            user_name: self.user_name,
+            created_at: self.created_at,
+            image_updated_at: self.image_updated_at,
+        }
+    }
+}
+
+impl IntoApi for TeamRepositoryResponse {
+    type ApiType = api::TeamRepositoryResource;
+    fn into_api(self) -> Self::ApiType {
+        api::TeamRepositoryResource {
+            repository_id: self.repository_id,
+            repository_name: self.repository_name,
+            role: self.role.into(),
+            created_at: self.created_at,
+        }
+    }
+}
@@ -1,8 +1,9 @@
 use gitdot_api::resource::{repository as repo_api, user as api};
 use gitdot_core::{
     dto::{
-        GetCurrentUserResponse, UserCommitResponse, UserEmailResponse, UserOrganizationResponse,
-        UserRepositoryResponse, UserResponse,
+        CreatePersonalTokenResponse, GetCurrentUserResponse, PersonalTokenResponse,
+        SigningKeyResponse, SshKeyResponse, UserCommitResponse, UserEmailResponse,
+        UserOrganizationResponse, UserRepositoryResponse, UserResponse,
     },
     model::OrganizationRole,
 };
@@ -116,3 +117,54 @@ impl IntoApi for UserCommitResponse {
         }
     }
 }
+
+impl IntoApi for PersonalTokenResponse {
+    type ApiType = api::PersonalTokenResource;
+    fn into_api(self) -> Self::ApiType {
+        api::PersonalTokenResource {
+            id: self.id,
+            name: self.name,
+            scopes: self.scopes.into_iter().map(Into::into).collect(),
+            expires_at: self.expires_at,
+            created_at: self.created_at,
+            last_used_at: self.last_used_at,
+        }
+    }
+}
+
+impl IntoApi for CreatePersonalTokenResponse {
+    type ApiType = api::NewPersonalTokenResource;
+    fn into_api(self) -> Self::ApiType {
+        api::NewPersonalTokenResource {
+            token: self.token,
+            personal_token: self.personal_token.into_api(),
+        }
+    }
+}
+
+impl IntoApi for SshKeyResponse {
+    type ApiType = api::SshKeyResource;
+    fn into_api(self) -> Self::ApiType {
+        api::SshKeyResource {
+            id: self.id,
+            name: self.name,
+            key_type: self.key_type,
+            fingerprint: self.fingerprint,
+            created_at: self.created_at,
+            last_used_at: self.last_used_at,
+        }
+    }
+}
+
+impl IntoApi for SigningKeyResponse {
+    type ApiType = api::SigningKeyResource;
+    fn into_api(self) -> Self::ApiType {
+        api::SigningKeyResource {
+            id: self.id,
+            name: self.name,
+            key_type: self.key_type.as_str().to_string(),
+            fingerprint: self.fingerprint,
+            created_at: self.created_at,
+        }
+    }
+}
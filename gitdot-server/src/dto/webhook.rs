@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use gitdot_api::resource::webhook as api;
-use gitdot_core::dto::WebhookResponse;
+use gitdot_core::dto::{WebhookDeliveryResponse, WebhookResponse};
 
 use super::IntoApi;
 
@@ -21,6 +21,27 @@ impl IntoApi for WebhookResponse {
     }
 }
 
+impl IntoApi for WebhookDeliveryResponse {
+    type ApiType = api::WebhookDeliveryResource;
+    fn into_api(self) -> Self::ApiType {
+        api::WebhookDeliveryResource {
+            id: self.id,
+            webhook_id: self.webhook_id,
+            event: self.event.into(),
+            payload: self.payload,
+            status: self.status.into(),
+            attempts: self.attempts,
+            status_code: self.status_code,
+            duration_ms: self.duration_ms,
+            response_excerpt: self.response_excerpt,
+            error: self.error,
+            redelivery_of: self.redelivery_of,
+            created_at: self.created_at,
+            delivered_at: self.delivered_at,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SubscribeSlackWebhookRequest {
     pub gitdot_user_id: Uuid,
@@ -4,7 +4,7 @@ mod github;
 mod service;
 mod slack_bot;
 
-pub use auth::{Principal, RunnerToken, TaskJwt, User, UserJwt};
+pub use auth::{Principal, RunnerToken, TaskJwt, TokenScopePolicy, User, UserJwt};
 pub use content_type::ContentType;
 pub use github::{GithubEvent, GithubSigned};
 pub use service::{Service, Vercel};
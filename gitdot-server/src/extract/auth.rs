@@ -2,17 +2,20 @@ use std::{convert::Infallible, marker::PhantomData};
 
 use async_trait::async_trait;
 use axum::{
+    Extension,
     extract::{FromRef, FromRequestParts, OptionalFromRequestParts},
-    http::request::Parts,
+    http::{Method, request::Parts},
 };
 use base64::Engine;
 use jsonwebtoken::{Algorithm, DecodingKey, Validation, decode};
+use tower::Layer;
 use uuid::Uuid;
 
 use gitdot_axum::error::TokenExtractionError;
 use gitdot_core::{
     dto::{JwtClaims, ValidateTokenRequest},
-    model::TokenType,
+    error::TokenServiceError,
+    model::{TokenScope, TokenType},
     util::auth::GITDOT_SERVER_ID,
 };
 
@@ -136,21 +139,84 @@ impl Authenticator for UserToken {
     ) -> Result<Principal<Self>, TokenExtractionError> {
         let header = extract_auth_header(parts)?;
         let token = extract_token(header)?;
+        let scope = parts
+            .extensions
+            .get::<TokenScopePolicy>()
+            .and_then(|policy| policy.required_scope(&parts.method))
+            .ok_or_else(|| {
+                TokenExtractionError::Forbidden(
+                    "Personal access tokens cannot be used here".to_string(),
+                )
+            })?;
 
         let request = ValidateTokenRequest {
             token: token.to_owned(),
             token_type: TokenType::Personal,
+            scope: Some(scope),
         };
         let response = app_state
             .token_service
             .validate_token(request)
             .await
-            .map_err(|_| TokenExtractionError::Unauthorized)?;
+            .map_err(|e| match e {
+                TokenServiceError::MissingScope(_) => {
+                    TokenExtractionError::Forbidden(e.to_string())
+                }
+                _ => TokenExtractionError::Unauthorized,
+            })?;
 
         Ok(Principal::new(response.principal_id))
     }
 }
 
+/// The scopes a personal access token needs on the routes this is layered on:
+/// `GET` and `HEAD` requests need `read`, all others need `write`, and `None`
+/// keeps personal access tokens out. Routes without a policy reject them.
+/// A policy layered on a single route overrides the router's.
+#[derive(Debug, Clone, Copy)]
+pub struct TokenScopePolicy {
+    read: Option<TokenScope>,
+    write: Option<TokenScope>,
+}
+
+impl TokenScopePolicy {
+    pub const fn new(read: TokenScope, write: TokenScope) -> Self {
+        Self {
+            read: Some(read),
+            write: Some(write),
+        }
+    }
+
+    /// Every method needs `scope`.
+    pub const fn only(scope: TokenScope) -> Self {
+        Self::new(scope, scope)
+    }
+
+    /// Tokens may read with `read` but not write.
+    pub const fn read_only(read: TokenScope) -> Self {
+        Self {
+            read: Some(read),
+            write: None,
+        }
+    }
+
+    fn required_scope(&self, method: &Method) -> Option<TokenScope> {
+        if method == Method::GET || method == Method::HEAD {
+            self.read
+        } else {
+            self.write
+        }
+    }
+}
+
+impl<S> Layer<S> for TokenScopePolicy {
+    type Service = <Extension<Self> as Layer<S>>::Service;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        Extension(*self).layer(inner)
+    }
+}
+
 pub struct RunnerToken;
 
 #[async_trait]
@@ -165,6 +231,7 @@ impl Authenticator for RunnerToken {
         let request = ValidateTokenRequest {
             token: token.to_owned(),
             token_type: TokenType::Runner,
+            scope: None,
         };
         let response = app_state
             .token_service
@@ -1,23 +1,40 @@
 // TODO: remove it once unlaunched handlers are wired back up
 #![allow(dead_code)]
 
+mod branch_protection;
 mod build;
+mod code_search;
+mod collaborator;
 mod git_http;
 mod internal;
+mod maintenance;
 mod migration;
+mod mirror;
 mod organization;
 mod question;
+mod release;
 mod repository;
 mod review;
 mod runner;
+mod secret;
+mod tag;
 mod task;
+mod team;
 mod user;
 mod webhook;
 
+pub use branch_protection::*;
+pub use code_search::*;
+pub use collaborator::*;
 pub use git_http::*;
 pub use internal::*;
+pub use maintenance::*;
 pub use migration::*;
+pub use mirror::*;
 pub use organization::*;
+pub use release::*;
 pub use repository::*;
+pub use tag::*;
+pub use team::*;
 pub use user::*;
 pub use webhook::*;
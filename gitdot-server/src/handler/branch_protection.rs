@@ -0,0 +1,34 @@
+mod create_branch_protection;
+mod delete_branch_protection;
+mod list_branch_protections;
+mod update_branch_protection;
+
+use axum::{
+    Router,
+    routing::{patch, post},
+};
+
+use gitdot_core::model::TokenScope;
+
+use crate::{app::AppState, extract::TokenScopePolicy};
+
+use create_branch_protection::create_branch_protection;
+use delete_branch_protection::delete_branch_protection;
+use list_branch_protections::list_branch_protections;
+use update_branch_protection::update_branch_protection;
+
+pub fn create_branch_protection_router() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/repository/{owner}/{repo}/branch-protections",
+            post(create_branch_protection).get(list_branch_protections),
+        )
+        .route(
+            "/repository/{owner}/{repo}/branch-protections/{protection_id}",
+            patch(update_branch_protection).delete(delete_branch_protection),
+        )
+        .layer(TokenScopePolicy::new(
+            TokenScope::RepoRead,
+            TokenScope::RepoWrite,
+        ))
+}
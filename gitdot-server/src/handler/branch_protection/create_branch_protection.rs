@@ -0,0 +1,47 @@
+use axum::extract::{Json, Path, State};
+use http::StatusCode;
+
+use gitdot_api::endpoint::branch_protection::create_branch_protection as api;
+use gitdot_core::{
+    dto::{CreateBranchProtectionRequest, RepositoryAuthorizationRequest, RepositoryPermission},
+    model::BranchProtectionRules,
+};
+
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    dto::IntoApi,
+    extract::{Principal, User},
+};
+
+#[axum::debug_handler]
+pub async fn create_branch_protection(
+    auth_user: Principal<User>,
+    State(state): State<AppState>,
+    Path((owner, repo)): Path<(String, String)>,
+    Json(request): Json<api::CreateBranchProtectionRequest>,
+) -> Result<AppResponse<api::CreateBranchProtectionResponse>, AppError> {
+    let auth_request = RepositoryAuthorizationRequest::new(
+        Some(auth_user.id),
+        &owner,
+        &repo,
+        RepositoryPermission::Admin,
+    )?;
+    state
+        .authorization_service
+        .verify_authorized_for_repository(auth_request)
+        .await?;
+
+    let rules = BranchProtectionRules {
+        block_force_push: request.block_force_push,
+        block_deletion: request.block_deletion,
+        require_review: request.require_review,
+        require_green_build: request.require_green_build,
+    };
+    let request = CreateBranchProtectionRequest::new(&owner, &repo, &request.pattern, rules)?;
+    state
+        .branch_protection_service
+        .create_branch_protection(request)
+        .await
+        .map_err(AppError::from)
+        .map(|p| AppResponse::new(StatusCode::CREATED, p.into_api()))
+}
@@ -0,0 +1,37 @@
+use axum::extract::{Path, State};
+use http::StatusCode;
+use uuid::Uuid;
+
+use gitdot_core::dto::{
+    DeleteBranchProtectionRequest, RepositoryAuthorizationRequest, RepositoryPermission,
+};
+
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    extract::{Principal, User},
+};
+
+#[axum::debug_handler]
+pub async fn delete_branch_protection(
+    auth_user: Principal<User>,
+    State(state): State<AppState>,
+    Path((owner, repo, protection_id)): Path<(String, String, Uuid)>,
+) -> Result<AppResponse<()>, AppError> {
+    let auth_request = RepositoryAuthorizationRequest::new(
+        Some(auth_user.id),
+        &owner,
+        &repo,
+        RepositoryPermission::Admin,
+    )?;
+    state
+        .authorization_service
+        .verify_authorized_for_repository(auth_request)
+        .await?;
+
+    let request = DeleteBranchProtectionRequest::new(&owner, &repo, protection_id)?;
+    state
+        .branch_protection_service
+        .delete_branch_protection(request)
+        .await?;
+    Ok(AppResponse::new(StatusCode::OK, ()))
+}
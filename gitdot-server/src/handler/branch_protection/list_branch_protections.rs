@@ -0,0 +1,39 @@
+use axum::extract::{Path, State};
+use http::StatusCode;
+
+use gitdot_api::endpoint::branch_protection::list_branch_protections as api;
+use gitdot_core::dto::{
+    ListBranchProtectionsRequest, RepositoryAuthorizationRequest, RepositoryPermission,
+};
+
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    dto::IntoApi,
+    extract::{Principal, User},
+};
+
+#[axum::debug_handler]
+pub async fn list_branch_protections(
+    auth_user: Principal<User>,
+    State(state): State<AppState>,
+    Path((owner, repo)): Path<(String, String)>,
+) -> Result<AppResponse<api::ListBranchProtectionsResponse>, AppError> {
+    let auth_request = RepositoryAuthorizationRequest::new(
+        Some(auth_user.id),
+        &owner,
+        &repo,
+        RepositoryPermission::Read,
+    )?;
+    state
+        .authorization_service
+        .verify_authorized_for_repository(auth_request)
+        .await?;
+
+    let request = ListBranchProtectionsRequest::new(&owner, &repo)?;
+    state
+        .branch_protection_service
+        .list_branch_protections(request)
+        .await
+        .map_err(AppError::from)
+        .map(|protections| AppResponse::new(StatusCode::OK, protections.into_api()))
+}
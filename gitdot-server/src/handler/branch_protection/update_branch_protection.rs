@@ -0,0 +1,49 @@
+use axum::extract::{Json, Path, State};
+use http::StatusCode;
+use uuid::Uuid;
+
+use gitdot_api::endpoint::branch_protection::update_branch_protection as api;
+use gitdot_core::{
+    dto::{RepositoryAuthorizationRequest, RepositoryPermission, UpdateBranchProtectionRequest},
+    model::BranchProtectionRules,
+};
+
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    dto::IntoApi,
+    extract::{Principal, User},
+};
+
+#[axum::debug_handler]
+pub async fn update_branch_protection(
+    auth_user: Principal<User>,
+    State(state): State<AppState>,
+    Path((owner, repo, protection_id)): Path<(String, String, Uuid)>,
+    Json(request): Json<api::UpdateBranchProtectionRequest>,
+) -> Result<AppResponse<api::UpdateBranchProtectionResponse>, AppError> {
+    let auth_request = RepositoryAuthorizationRequest::new(
+        Some(auth_user.id),
+        &owner,
+        &repo,
+        RepositoryPermission::Admin,
+    )?;
+    state
+        .authorization_service
+        .verify_authorized_for_repository(auth_request)
+        .await?;
+
+    let rules = BranchProtectionRules {
+        block_force_push: request.block_force_push,
+        block_deletion: request.block_deletion,
+        require_review: request.require_review,
+        require_green_build: request.require_green_build,
+    };
+    let request =
+        UpdateBranchProtectionRequest::new(&owner, &repo, protection_id, &request.pattern, rules)?;
+    state
+        .branch_protection_service
+        .update_branch_protection(request)
+        .await
+        .map_err(AppError::from)
+        .map(|p| AppResponse::new(StatusCode::OK, p.into_api()))
+}
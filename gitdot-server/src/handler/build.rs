@@ -1,19 +1,29 @@
+mod cancel_build;
 mod create_build;
+mod download_build_artifact;
 mod get_build;
+mod list_build_artifacts;
 mod list_build_tasks;
 mod list_builds;
+mod retry_build_task;
 
 use axum::{
     Router,
     routing::{get, post},
 };
 
-use crate::app::AppState;
+use gitdot_core::model::TokenScope;
 
+use crate::{app::AppState, extract::TokenScopePolicy};
+
+use cancel_build::cancel_build;
 use create_build::create_build;
+use download_build_artifact::download_build_artifact;
 use get_build::get_build;
+use list_build_artifacts::list_build_artifacts;
 use list_build_tasks::list_build_tasks;
 use list_builds::list_builds;
+use retry_build_task::retry_build_task;
 
 pub fn create_build_router() -> Router<AppState> {
     Router::new()
@@ -24,4 +34,24 @@ pub fn create_build_router() -> Router<AppState> {
             "/repository/{owner}/{repo}/build/{number}/tasks",
             get(list_build_tasks),
         )
+        .route(
+            "/repository/{owner}/{repo}/build/{number}/artifacts",
+            get(list_build_artifacts),
+        )
+        .route(
+            "/repository/{owner}/{repo}/build/{number}/artifact/{id}",
+            get(download_build_artifact),
+        )
+        .route(
+            "/repository/{owner}/{repo}/build/{number}/cancel",
+            post(cancel_build),
+        )
+        .route(
+            "/repository/{owner}/{repo}/build/{number}/task/{task_id}/retry",
+            post(retry_build_task),
+        )
+        .layer(TokenScopePolicy::new(
+            TokenScope::RepoRead,
+            TokenScope::RepoWrite,
+        ))
 }
@@ -0,0 +1,39 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+};
+
+use gitdot_api::endpoint::build::cancel_build as api;
+use gitdot_core::dto::{RepositoryAuthorizationRequest, RepositoryPermission};
+
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    dto::IntoApi,
+    extract::{Principal, User},
+};
+
+#[axum::debug_handler]
+pub async fn cancel_build(
+    auth_user: Principal<User>,
+    State(state): State<AppState>,
+    Path((owner, repo, number)): Path<(String, String, i32)>,
+) -> Result<AppResponse<api::CancelBuildResponse>, AppError> {
+    let auth_request = RepositoryAuthorizationRequest::new(
+        Some(auth_user.id),
+        &owner,
+        &repo,
+        RepositoryPermission::Write,
+    )?;
+    state
+        .authorization_service
+        .verify_authorized_for_repository(auth_request)
+        .await?;
+
+    let build = state
+        .build_service
+        .cancel_build(&owner, &repo, number)
+        .await
+        .map_err(AppError::from)?;
+
+    Ok(AppResponse::new(StatusCode::OK, build.into_api()))
+}
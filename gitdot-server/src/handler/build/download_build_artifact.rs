@@ -0,0 +1,35 @@
+use axum::extract::{Path, State};
+use uuid::Uuid;
+
+use gitdot_core::dto::{RepositoryAuthorizationRequest, RepositoryPermission};
+
+use crate::{
+    app::{AppError, AppState},
+    dto::ArtifactServerResponse,
+    extract::{Principal, User},
+};
+
+#[axum::debug_handler]
+pub async fn download_build_artifact(
+    auth_user: Principal<User>,
+    State(state): State<AppState>,
+    Path((owner, repo, number, id)): Path<(String, String, i32, Uuid)>,
+) -> Result<ArtifactServerResponse, AppError> {
+    let auth_request = RepositoryAuthorizationRequest::new(
+        Some(auth_user.id),
+        &owner,
+        &repo,
+        RepositoryPermission::Read,
+    )?;
+    state
+        .authorization_service
+        .verify_authorized_for_repository(auth_request)
+        .await?;
+
+    state
+        .artifact_service
+        .download_build_artifact(&owner, &repo, number, id)
+        .await
+        .map_err(AppError::from)
+        .map(Into::into)
+}
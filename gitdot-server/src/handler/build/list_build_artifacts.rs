@@ -0,0 +1,38 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+};
+
+use gitdot_api::endpoint::build::list_build_artifacts as api;
+use gitdot_core::dto::{RepositoryAuthorizationRequest, RepositoryPermission};
+
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    dto::IntoApi,
+    extract::{Principal, User},
+};
+
+#[axum::debug_handler]
+pub async fn list_build_artifacts(
+    auth_user: Principal<User>,
+    State(state): State<AppState>,
+    Path((owner, repo, number)): Path<(String, String, i32)>,
+) -> Result<AppResponse<api::ListBuildArtifactsResponse>, AppError> {
+    let auth_request = RepositoryAuthorizationRequest::new(
+        Some(auth_user.id),
+        &owner,
+        &repo,
+        RepositoryPermission::Read,
+    )?;
+    state
+        .authorization_service
+        .verify_authorized_for_repository(auth_request)
+        .await?;
+
+    state
+        .artifact_service
+        .list_build_artifacts(&owner, &repo, number)
+        .await
+        .map_err(AppError::from)
+        .map(|artifacts| AppResponse::new(StatusCode::OK, artifacts.into_api()))
+}
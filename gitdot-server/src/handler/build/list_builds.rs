@@ -19,7 +19,13 @@ pub async fn list_builds(
     Path((owner, repo)): Path<(String, String)>,
     Query(query): Query<api::ListBuildsRequest>,
 ) -> Result<AppResponse<api::ListBuildsResponse>, AppError> {
-    let request = ListBuildsRequest::new(&owner, &repo, query.cursor.as_deref(), query.limit)?;
+    let request = ListBuildsRequest::new(
+        &owner,
+        &repo,
+        query.review,
+        query.cursor.as_deref(),
+        query.limit,
+    )?;
     state
         .build_service
         .list_builds(request)
@@ -0,0 +1,40 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+};
+use uuid::Uuid;
+
+use gitdot_api::endpoint::build::retry_build_task as api;
+use gitdot_core::dto::{RepositoryAuthorizationRequest, RepositoryPermission};
+
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    dto::IntoApi,
+    extract::{Principal, User},
+};
+
+#[axum::debug_handler]
+pub async fn retry_build_task(
+    auth_user: Principal<User>,
+    State(state): State<AppState>,
+    Path((owner, repo, number, task_id)): Path<(String, String, i32, Uuid)>,
+) -> Result<AppResponse<api::RetryBuildTaskResponse>, AppError> {
+    let auth_request = RepositoryAuthorizationRequest::new(
+        Some(auth_user.id),
+        &owner,
+        &repo,
+        RepositoryPermission::Write,
+    )?;
+    state
+        .authorization_service
+        .verify_authorized_for_repository(auth_request)
+        .await?;
+
+    let task = state
+        .build_service
+        .retry_task(&owner, &repo, number, task_id)
+        .await
+        .map_err(AppError::from)?;
+
+    Ok(AppResponse::new(StatusCode::OK, task.into_api()))
+}
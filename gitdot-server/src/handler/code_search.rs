@@ -0,0 +1,15 @@
+mod search_code;
+
+use axum::{Router, routing::get};
+
+use gitdot_core::model::TokenScope;
+
+use crate::{app::AppState, extract::TokenScopePolicy};
+
+use search_code::search_code;
+
+pub fn create_code_search_router() -> Router<AppState> {
+    Router::new()
+        .route("/repository/{owner}/{repo}/search", get(search_code))
+        .layer(TokenScopePolicy::read_only(TokenScope::RepoRead))
+}
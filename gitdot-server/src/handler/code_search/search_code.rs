@@ -0,0 +1,48 @@
+use axum::extract::{Path, Query, State};
+use http::StatusCode;
+
+use gitdot_api::endpoint::code_search::search_code as api;
+use gitdot_core::dto::{RepositoryAuthorizationRequest, RepositoryPermission, SearchCodeRequest};
+
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    dto::IntoApi,
+    extract::{Principal, User},
+};
+
+#[axum::debug_handler]
+pub async fn search_code(
+    auth_user: Option<Principal<User>>,
+    State(state): State<AppState>,
+    Path((owner, repo)): Path<(String, String)>,
+    Query(params): Query<api::SearchCodeRequest>,
+) -> Result<AppResponse<api::SearchCodeResponse>, AppError> {
+    let auth_request = RepositoryAuthorizationRequest::new(
+        auth_user.map(|u| u.id),
+        &owner,
+        &repo,
+        RepositoryPermission::Read,
+    )?;
+    state
+        .authorization_service
+        .verify_authorized_for_repository(auth_request)
+        .await?;
+
+    let request = SearchCodeRequest::new(
+        &repo,
+        &owner,
+        &params.q,
+        params.regex,
+        params.case_sensitive,
+        params.path,
+        params.language,
+        params.context,
+        params.limit,
+    )?;
+    state
+        .code_search_service
+        .search_code(request)
+        .await
+        .map_err(AppError::from)
+        .map(|result| AppResponse::new(StatusCode::OK, result.into_api()))
+}
@@ -0,0 +1,70 @@
+mod accept_repository_invitation;
+mod cancel_repository_invitation;
+mod decline_repository_invitation;
+mod invite_repository_collaborator;
+mod list_repository_collaborators;
+mod list_repository_invitations;
+mod list_user_invitations;
+mod remove_repository_collaborator;
+mod update_repository_collaborator;
+
+use axum::{
+    Router,
+    routing::{delete, get, patch, post},
+};
+
+use gitdot_core::model::TokenScope;
+
+use crate::{app::AppState, extract::TokenScopePolicy};
+
+use accept_repository_invitation::accept_repository_invitation;
+use cancel_repository_invitation::cancel_repository_invitation;
+use decline_repository_invitation::decline_repository_invitation;
+use invite_repository_collaborator::invite_repository_collaborator;
+use list_repository_collaborators::list_repository_collaborators;
+use list_repository_invitations::list_repository_invitations;
+use list_user_invitations::list_user_invitations;
+use remove_repository_collaborator::remove_repository_collaborator;
+use update_repository_collaborator::update_repository_collaborator;
+
+const REPOSITORY_COLLABORATOR_SCOPES: TokenScopePolicy =
+    TokenScopePolicy::new(TokenScope::RepoRead, TokenScope::RepoWrite);
+const USER_INVITATION_SCOPES: TokenScopePolicy = TokenScopePolicy::read_only(TokenScope::RepoRead);
+
+pub fn create_collaborator_router() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/repository/{owner}/{repo}/collaborators",
+            get(list_repository_collaborators).layer(REPOSITORY_COLLABORATOR_SCOPES),
+        )
+        .route(
+            "/repository/{owner}/{repo}/collaborator",
+            post(invite_repository_collaborator).layer(REPOSITORY_COLLABORATOR_SCOPES),
+        )
+        .route(
+            "/repository/{owner}/{repo}/collaborator/{user_name}",
+            patch(update_repository_collaborator)
+                .delete(remove_repository_collaborator)
+                .layer(REPOSITORY_COLLABORATOR_SCOPES),
+        )
+        .route(
+            "/repository/{owner}/{repo}/invitations",
+            get(list_repository_invitations).layer(REPOSITORY_COLLABORATOR_SCOPES),
+        )
+        .route(
+            "/repository/{owner}/{repo}/invitation/{invitation_id}",
+            delete(cancel_repository_invitation).layer(REPOSITORY_COLLABORATOR_SCOPES),
+        )
+        .route(
+            "/user/invitations",
+            get(list_user_invitations).layer(USER_INVITATION_SCOPES),
+        )
+        .route(
+            "/user/invitation/{invitation_id}/accept",
+            post(accept_repository_invitation).layer(USER_INVITATION_SCOPES),
+        )
+        .route(
+            "/user/invitation/{invitation_id}/decline",
+            post(decline_repository_invitation).layer(USER_INVITATION_SCOPES),
+        )
+}
@@ -0,0 +1,29 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+};
+use uuid::Uuid;
+
+use gitdot_api::endpoint::accept_repository_invitation as api;
+use gitdot_core::dto::RespondToInvitationRequest;
+
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    dto::IntoApi,
+    extract::{Principal, UserJwt},
+};
+
+#[axum::debug_handler]
+pub async fn accept_repository_invitation(
+    auth_user: Principal<UserJwt>,
+    State(state): State<AppState>,
+    Path(invitation_id): Path<Uuid>,
+) -> Result<AppResponse<api::AcceptRepositoryInvitationResponse>, AppError> {
+    let request = RespondToInvitationRequest::new(auth_user.id, invitation_id);
+    state
+        .collaborator_service
+        .accept_invitation(request)
+        .await
+        .map_err(AppError::from)
+        .map(|collaborator| AppResponse::new(StatusCode::OK, collaborator.into_api()))
+}
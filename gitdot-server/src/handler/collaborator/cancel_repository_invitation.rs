@@ -0,0 +1,39 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+};
+use uuid::Uuid;
+
+use gitdot_core::dto::{
+    CancelInvitationRequest, RepositoryAuthorizationRequest, RepositoryPermission,
+};
+
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    extract::{Principal, User},
+};
+
+#[axum::debug_handler]
+pub async fn cancel_repository_invitation(
+    auth_user: Principal<User>,
+    State(state): State<AppState>,
+    Path((owner, repo, invitation_id)): Path<(String, String, Uuid)>,
+) -> Result<AppResponse<()>, AppError> {
+    let auth_request = RepositoryAuthorizationRequest::new(
+        Some(auth_user.id),
+        &owner,
+        &repo,
+        RepositoryPermission::Admin,
+    )?;
+    state
+        .authorization_service
+        .verify_authorized_for_repository(auth_request)
+        .await?;
+
+    let request = CancelInvitationRequest::new(&owner, &repo, invitation_id)?;
+    state
+        .collaborator_service
+        .cancel_invitation(request)
+        .await?;
+    Ok(AppResponse::new(StatusCode::NO_CONTENT, ()))
+}
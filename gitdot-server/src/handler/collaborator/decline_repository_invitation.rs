@@ -0,0 +1,26 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+};
+use uuid::Uuid;
+
+use gitdot_core::dto::RespondToInvitationRequest;
+
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    extract::{Principal, UserJwt},
+};
+
+#[axum::debug_handler]
+pub async fn decline_repository_invitation(
+    auth_user: Principal<UserJwt>,
+    State(state): State<AppState>,
+    Path(invitation_id): Path<Uuid>,
+) -> Result<AppResponse<()>, AppError> {
+    let request = RespondToInvitationRequest::new(auth_user.id, invitation_id);
+    state
+        .collaborator_service
+        .decline_invitation(request)
+        .await?;
+    Ok(AppResponse::new(StatusCode::NO_CONTENT, ()))
+}
@@ -0,0 +1,49 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+
+use gitdot_api::endpoint::invite_repository_collaborator as api;
+use gitdot_core::dto::{
+    InviteCollaboratorRequest, RepositoryAuthorizationRequest, RepositoryPermission,
+};
+
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    dto::IntoApi,
+    extract::{Principal, User},
+};
+
+#[axum::debug_handler]
+pub async fn invite_repository_collaborator(
+    auth_user: Principal<User>,
+    State(state): State<AppState>,
+    Path((owner, repo)): Path<(String, String)>,
+    Json(request): Json<api::InviteRepositoryCollaboratorRequest>,
+) -> Result<AppResponse<api::InviteRepositoryCollaboratorResponse>, AppError> {
+    let auth_request = RepositoryAuthorizationRequest::new(
+        Some(auth_user.id),
+        &owner,
+        &repo,
+        RepositoryPermission::Admin,
+    )?;
+    state
+        .authorization_service
+        .verify_authorized_for_repository(auth_request)
+        .await?;
+
+    let request = InviteCollaboratorRequest::new(
+        &owner,
+        &repo,
+        auth_user.id,
+        &request.user_name,
+        &request.role,
+    )?;
+    state
+        .collaborator_service
+        .invite_collaborator(request)
+        .await
+        .map_err(AppError::from)
+        .map(|invitation| AppResponse::new(StatusCode::CREATED, invitation.into_api()))
+}
@@ -0,0 +1,41 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+};
+
+use gitdot_api::endpoint::list_repository_collaborators as api;
+use gitdot_core::dto::{
+    ListCollaboratorsRequest, RepositoryAuthorizationRequest, RepositoryPermission,
+};
+
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    dto::IntoApi,
+    extract::{Principal, User},
+};
+
+#[axum::debug_handler]
+pub async fn list_repository_collaborators(
+    auth_user: Principal<User>,
+    State(state): State<AppState>,
+    Path((owner, repo)): Path<(String, String)>,
+) -> Result<AppResponse<api::ListRepositoryCollaboratorsResponse>, AppError> {
+    let auth_request = RepositoryAuthorizationRequest::new(
+        Some(auth_user.id),
+        &owner,
+        &repo,
+        RepositoryPermission::Read,
+    )?;
+    state
+        .authorization_service
+        .verify_authorized_for_repository(auth_request)
+        .await?;
+
+    let request = ListCollaboratorsRequest::new(&owner, &repo)?;
+    state
+        .collaborator_service
+        .list_collaborators(request)
+        .await
+        .map_err(AppError::from)
+        .map(|collaborators| AppResponse::new(StatusCode::OK, collaborators.into_api()))
+}
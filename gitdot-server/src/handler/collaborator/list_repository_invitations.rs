@@ -0,0 +1,41 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+};
+
+use gitdot_api::endpoint::list_repository_invitations as api;
+use gitdot_core::dto::{
+    ListRepositoryInvitationsRequest, RepositoryAuthorizationRequest, RepositoryPermission,
+};
+
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    dto::IntoApi,
+    extract::{Principal, User},
+};
+
+#[axum::debug_handler]
+pub async fn list_repository_invitations(
+    auth_user: Principal<User>,
+    State(state): State<AppState>,
+    Path((owner, repo)): Path<(String, String)>,
+) -> Result<AppResponse<api::ListRepositoryInvitationsResponse>, AppError> {
+    let auth_request = RepositoryAuthorizationRequest::new(
+        Some(auth_user.id),
+        &owner,
+        &repo,
+        RepositoryPermission::Admin,
+    )?;
+    state
+        .authorization_service
+        .verify_authorized_for_repository(auth_request)
+        .await?;
+
+    let request = ListRepositoryInvitationsRequest::new(&owner, &repo)?;
+    state
+        .collaborator_service
+        .list_repository_invitations(request)
+        .await
+        .map_err(AppError::from)
+        .map(|invitations| AppResponse::new(StatusCode::OK, invitations.into_api()))
+}
@@ -0,0 +1,22 @@
+use axum::{extract::State, http::StatusCode};
+
+use gitdot_api::endpoint::list_user_invitations as api;
+
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    dto::IntoApi,
+    extract::{Principal, User},
+};
+
+#[axum::debug_handler]
+pub async fn list_user_invitations(
+    auth_user: Principal<User>,
+    State(state): State<AppState>,
+) -> Result<AppResponse<api::ListUserInvitationsResponse>, AppError> {
+    state
+        .collaborator_service
+        .list_user_invitations(auth_user.id)
+        .await
+        .map_err(AppError::from)
+        .map(|invitations| AppResponse::new(StatusCode::OK, invitations.into_api()))
+}
@@ -0,0 +1,38 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+};
+
+use gitdot_core::dto::{
+    RemoveCollaboratorRequest, RepositoryAuthorizationRequest, RepositoryPermission,
+};
+
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    extract::{Principal, User},
+};
+
+#[axum::debug_handler]
+pub async fn remove_repository_collaborator(
+    auth_user: Principal<User>,
+    State(state): State<AppState>,
+    Path((owner, repo, user_name)): Path<(String, String, String)>,
+) -> Result<AppResponse<()>, AppError> {
+    let auth_request = RepositoryAuthorizationRequest::new(
+        Some(auth_user.id),
+        &owner,
+        &repo,
+        RepositoryPermission::Admin,
+    )?;
+    state
+        .authorization_service
+        .verify_authorized_for_repository(auth_request)
+        .await?;
+
+    let request = RemoveCollaboratorRequest::new(&owner, &repo, &user_name)?;
+    state
+        .collaborator_service
+        .remove_collaborator(request)
+        .await?;
+    Ok(AppResponse::new(StatusCode::NO_CONTENT, ()))
+}
@@ -0,0 +1,43 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+
+use gitdot_api::endpoint::update_repository_collaborator as api;
+use gitdot_core::dto::{
+    RepositoryAuthorizationRequest, RepositoryPermission, UpdateCollaboratorRequest,
+};
+
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    dto::IntoApi,
+    extract::{Principal, User},
+};
+
+#[axum::debug_handler]
+pub async fn update_repository_collaborator(
+    auth_user: Principal<User>,
+    State(state): State<AppState>,
+    Path((owner, repo, user_name)): Path<(String, String, String)>,
+    Json(request): Json<api::UpdateRepositoryCollaboratorRequest>,
+) -> Result<AppResponse<api::UpdateRepositoryCollaboratorResponse>, AppError> {
+    let auth_request = RepositoryAuthorizationRequest::new(
+        Some(auth_user.id),
+        &owner,
+        &repo,
+        RepositoryPermission::Admin,
+    )?;
+    state
+        .authorization_service
+        .verify_authorized_for_repository(auth_request)
+        .await?;
+
+    let request = UpdateCollaboratorRequest::new(&owner, &repo, &user_name, &request.role)?;
+    state
+        .collaborator_service
+        .update_collaborator(request)
+        .await
+        .map_err(AppError::from)
+        .map(|collaborator| AppResponse::new(StatusCode::OK, collaborator.into_api()))
+}
@@ -22,8 +22,9 @@ use tower_http::{
 };
 
 use gitdot_axum::middleware::create_rate_limiter;
+use gitdot_core::model::TokenScope;
 
-use crate::{app::AppState, util::LimitedReader};
+use crate::{app::AppState, extract::TokenScopePolicy, util::LimitedReader};
 
 use git_info_refs::git_info_refs;
 use git_receive_pack::git_receive_pack;
@@ -40,14 +41,21 @@ const GIT_RATE_LIMIT_BURST: u32 = 40;
 
 pub fn create_git_http_router() -> Router<AppState> {
     Router::new()
-        .route("/{owner}/{repo}/info/refs", get(git_info_refs))
+        .route(
+            "/{owner}/{repo}/info/refs",
+            get(git_info_refs).layer(TokenScopePolicy::only(TokenScope::RepoRead)),
+        )
         .route(
             "/{owner}/{repo}/git-upload-pack",
-            post(git_upload_pack).layer(RequestBodyLimitLayer::new(GIT_UPLOAD_PACK_BODY_LIMIT)),
+            post(git_upload_pack)
+                .route_layer(TokenScopePolicy::only(TokenScope::RepoRead))
+                .layer(RequestBodyLimitLayer::new(GIT_UPLOAD_PACK_BODY_LIMIT)),
         )
         .route(
             "/{owner}/{repo}/git-receive-pack",
-            post(git_receive_pack).layer(RequestBodyLimitLayer::new(GIT_RECEIVE_PACK_BODY_LIMIT)),
+            post(git_receive_pack)
+                .route_layer(TokenScopePolicy::only(TokenScope::RepoWrite))
+                .layer(RequestBodyLimitLayer::new(GIT_RECEIVE_PACK_BODY_LIMIT)),
         )
         .layer(middleware::from_fn(add_www_authenticate_header))
         .layer(create_rate_limiter(
@@ -70,6 +78,15 @@ async fn add_www_authenticate_header(request: Request<Body>, next: Next) -> Resp
     response
 }
 
+/// The `Git-Protocol` header a client sends to request protocol v2
+/// (`version=2`). A non-ASCII value is passed on as-is and rejected by
+/// validation.
+fn git_protocol(headers: &HeaderMap) -> Option<&str> {
+    headers
+        .get("git-protocol")
+        .map(|v| v.to_str().unwrap_or_default())
+}
+
 async fn create_body_reader(
     headers: &HeaderMap,
     body: Body,
@@ -1,4 +1,7 @@
-use axum::extract::{Path, Query, State};
+use axum::{
+    extract::{Path, Query, State},
+    http::HeaderMap,
+};
 
 use gitdot_core::{
     dto::{
@@ -8,6 +11,7 @@ use gitdot_core::{
     error::AuthorizationError,
 };
 
+use super::git_protocol;
 use crate::{
     app::{AppError, AppState},
     dto::{GitHttpServerResponse, InfoRefsQuery},
@@ -20,6 +24,7 @@ pub async fn git_info_refs(
     State(state): State<AppState>,
     Path((owner, repo)): Path<(String, String)>,
     Query(params): Query<InfoRefsQuery>,
+    headers: HeaderMap,
 ) -> Result<GitHttpServerResponse, AppError> {
     let user_id = auth_user.map(|u| u.id);
     let permission = match params.service.as_str() {
@@ -44,7 +49,7 @@ pub async fn git_info_refs(
         Err(e) => return Err(e.into()),
     }
 
-    let request = InfoRefsRequest::new(&owner, &repo, &params.service)?;
+    let request = InfoRefsRequest::new(&owner, &repo, &params.service, git_protocol(&headers))?;
     let response = state.git_http_service.info_refs(request).await?;
     Ok(response.into())
 }
@@ -6,7 +6,7 @@ use axum::{
 
 use gitdot_core::dto::{RepositoryAuthorizationRequest, RepositoryPermission, UploadPackRequest};
 
-use super::{GIT_UPLOAD_PACK_DECOMPRESSED_LIMIT, create_body_reader};
+use super::{GIT_UPLOAD_PACK_DECOMPRESSED_LIMIT, create_body_reader, git_protocol};
 use crate::{
     app::{AppError, AppState},
     dto::GitHttpServerResponse,
@@ -31,7 +31,13 @@ pub async fn git_upload_pack(
         .await?;
 
     let body_reader = create_body_reader(&headers, body, GIT_UPLOAD_PACK_DECOMPRESSED_LIMIT).await;
-    let request = UploadPackRequest::new(&owner, &repo, &content_type, body_reader)?;
+    let request = UploadPackRequest::new(
+        &owner,
+        &repo,
+        &content_type,
+        git_protocol(&headers),
+        body_reader,
+    )?;
     let response = state.git_http_service.upload_pack(request).await?;
     Ok(response.into())
 }
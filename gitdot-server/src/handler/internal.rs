@@ -1,4 +1,7 @@
+mod authorize_ssh_command;
+mod find_ssh_key;
 mod process_post_receive;
+mod process_pre_receive;
 mod process_review;
 
 use std::{net::SocketAddr, time::Duration};
@@ -20,11 +23,18 @@ use tower_http::{
 
 use crate::app::AppState;
 
+use authorize_ssh_command::authorize_ssh_command;
+use find_ssh_key::find_ssh_key;
 use process_post_receive::process_post_receive;
+use process_pre_receive::process_pre_receive;
 use process_review::process_review;
 
 pub fn create_internal_router() -> Router<AppState> {
     Router::new()
+        .route(
+            "/internal/{owner}/{repo}/process-pre-receive",
+            post(process_pre_receive),
+        )
         .route(
             "/internal/{owner}/{repo}/process-post-receive",
             post(process_post_receive),
@@ -33,6 +43,11 @@ pub fn create_internal_router() -> Router<AppState> {
             "/internal/{owner}/{repo}/process-review",
             post(process_review),
         )
+        .route("/internal/ssh/find-key", post(find_ssh_key))
+        .route(
+            "/internal/ssh/authorize-command",
+            post(authorize_ssh_command),
+        )
         .layer(middleware::from_fn(require_localhost))
         .layer(SetRequestIdLayer::x_request_id(MakeRequestUuid))
         .layer(TraceLayer::new_for_http())
@@ -0,0 +1,55 @@
+use axum::{
+    extract::{Json, State},
+    http::StatusCode,
+};
+
+use gitdot_core::{
+    dto::{AuthorizeSshCommandRequest, RepositoryAuthorizationRequest},
+    util::git::REPO_SUFFIX,
+};
+
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    dto::{AuthorizeSshCommandServerRequest, AuthorizeSshCommandServerResponse},
+};
+
+/// Backs the forced command of an SSH session: checks that the key's owner
+/// may run the requested git service against the repository, and tells the
+/// caller which bare repository to serve.
+#[axum::debug_handler]
+pub async fn authorize_ssh_command(
+    State(state): State<AppState>,
+    Json(request): Json<AuthorizeSshCommandServerRequest>,
+) -> Result<AppResponse<AuthorizeSshCommandServerResponse>, AppError> {
+    let request = AuthorizeSshCommandRequest::new(request.key_id, &request.command)?;
+    let key = state
+        .ssh_key_service
+        .authenticate_ssh_key(request.key_id)
+        .await?;
+
+    let auth_request = RepositoryAuthorizationRequest::new(
+        Some(key.user_id),
+        request.owner.as_ref(),
+        request.repo.as_ref(),
+        request.permission(),
+    )?;
+    state
+        .authorization_service
+        .verify_authorized_for_repository(auth_request)
+        .await?;
+
+    Ok(AppResponse::new(
+        StatusCode::OK,
+        AuthorizeSshCommandServerResponse {
+            user_id: key.user_id,
+            service: request.service.as_ref().to_string(),
+            repository_path: format!(
+                "{}/{}/{}{}",
+                state.settings.git_project_root,
+                request.owner.as_ref(),
+                request.repo.as_ref(),
+                REPO_SUFFIX
+            ),
+        },
+    ))
+}
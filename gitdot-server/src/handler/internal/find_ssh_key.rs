@@ -0,0 +1,30 @@
+use axum::{
+    extract::{Json, State},
+    http::StatusCode,
+};
+
+use gitdot_core::dto::FindSshKeyRequest;
+
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    dto::{FindSshKeyServerRequest, FindSshKeyServerResponse},
+};
+
+/// Backs sshd's `AuthorizedKeysCommand`: resolves the key a client offers to
+/// the user who registered it.
+#[axum::debug_handler]
+pub async fn find_ssh_key(
+    State(state): State<AppState>,
+    Json(request): Json<FindSshKeyServerRequest>,
+) -> Result<AppResponse<FindSshKeyServerResponse>, AppError> {
+    let request = FindSshKeyRequest::new(&request.key_type, &request.key)?;
+    let key = state.ssh_key_service.find_ssh_key(request).await?;
+
+    Ok(AppResponse::new(
+        StatusCode::OK,
+        FindSshKeyServerResponse {
+            key_id: key.id,
+            user_id: key.user_id,
+        },
+    ))
+}
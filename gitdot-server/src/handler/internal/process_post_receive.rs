@@ -3,7 +3,13 @@ use axum::{
     http::StatusCode,
 };
 
-use gitdot_core::dto::{CreateCommitsRequest, PublishRepoPushRequest};
+use gitdot_core::{
+    dto::{
+        CreateBuildRequest, CreateCommitsRequest, IndexCodeRequest, PublishRepoPushRequest,
+        PublishTagEventRequest, RecordPushRequest, RequestPushMirrorsRequest,
+    },
+    util::git::TAG_PREFIX,
+};
 
 use crate::{
     app::{AppError, AppResponse, AppState},
@@ -16,13 +22,38 @@ pub async fn process_post_receive(
     Path((owner, repo)): Path<(String, String)>,
     Json(request): Json<ProcessPostReceiveServerRequest>,
 ) -> Result<AppResponse<()>, AppError> {
-    let publish_request = PublishRepoPushRequest::new(
+    // Tag pushes are reported as tag events rather than branch pushes.
+    let publish_request = if request.ref_name.starts_with(TAG_PREFIX) {
+        PublishEvent::Tag(PublishTagEventRequest::new(
+            &owner,
+            &repo,
+            &request.ref_name,
+            request.old_sha.clone(),
+            request.new_sha.clone(),
+            request.pusher_id,
+        )?)
+    } else {
+        PublishEvent::Push(PublishRepoPushRequest::new(
+            &owner,
+            &repo,
+            request.ref_name.clone(),
+            request.old_sha.clone(),
+            request.new_sha.clone(),
+            request.pusher_id,
+        )?)
+    };
+    let build_request = CreateBuildRequest::for_push(
         &owner,
         &repo,
         request.ref_name.clone(),
         request.old_sha.clone(),
         request.new_sha.clone(),
-        request.pusher_id,
+    )?;
+    let index_request = IndexCodeRequest::new(
+        &repo,
+        &owner,
+        request.ref_name.clone(),
+        request.new_sha.clone(),
     )?;
     let commit_request = CreateCommitsRequest::new(
         &owner,
@@ -33,17 +64,59 @@ pub async fn process_post_receive(
         None,
         Default::default(),
     )?;
+    let record_push_request = RecordPushRequest::new(&owner, &repo)?;
+    let push_mirrors_request = RequestPushMirrorsRequest::new(&owner, &repo)?;
 
     // execute in the background to avoid blocking push operation
     tokio::spawn(async move {
-        if let Err(e) = state.event_service.publish_repo_push(publish_request).await {
-            tracing::error!("Failed to publish repo push event: {e}");
+        match publish_request {
+            PublishEvent::Push(request) => {
+                if let Err(e) = state.event_service.publish_repo_push(request).await {
+                    tracing::error!("Failed to publish repo push event: {e}");
+                }
+            }
+            PublishEvent::Tag(request) => {
+                if let Err(e) = state.event_service.publish_tag_event(request).await {
+                    tracing::error!("Failed to publish tag event: {e}");
+                }
+            }
         }
 
         if let Err(e) = state.commit_service.create_commits(commit_request).await {
             tracing::error!("Failed to create commits in post-receive: {e}");
         }
+
+        if let Some(build_request) = build_request
+            && let Err(e) = state.build_service.trigger_build(build_request).await
+        {
+            tracing::error!("Failed to trigger build in post-receive: {e}");
+        }
+
+        if let Err(e) = state
+            .maintenance_service
+            .record_push(record_push_request)
+            .await
+        {
+            tracing::error!("Failed to record push for maintenance: {e}");
+        }
+
+        if let Err(e) = state
+            .mirror_service
+            .request_push_mirrors(push_mirrors_request)
+            .await
+        {
+            tracing::error!("Failed to request push mirror syncs: {e}");
+        }
+
+        if let Err(e) = state.code_search_service.index_code(index_request).await {
+            tracing::error!("Failed to index code in post-receive: {e}");
+        }
     });
 
     Ok(AppResponse::new(StatusCode::OK, ()))
 }
+
+enum PublishEvent {
+    Push(PublishRepoPushRequest),
+    Tag(PublishTagEventRequest),
+}
@@ -0,0 +1,27 @@
+use axum::extract::{Path, State};
+
+use gitdot_core::dto::CheckRefUpdatesRequest;
+
+use crate::{
+    app::{AppError, AppState},
+    dto::ProcessPreReceiveServerResponse,
+};
+
+/// Evaluates a push against the repository's branch protection rules. The
+/// hook posts its `<old> <new> <ref> <forced>` lines as-is, and any line in
+/// the reply is a rejection that refuses the whole push.
+#[axum::debug_handler]
+pub async fn process_pre_receive(
+    State(state): State<AppState>,
+    Path((owner, repo)): Path<(String, String)>,
+    body: String,
+) -> Result<ProcessPreReceiveServerResponse, AppError> {
+    let check_request = CheckRefUpdatesRequest::from_hook_input(&owner, &repo, &body)?;
+
+    let rejections = state
+        .branch_protection_service
+        .check_ref_updates(check_request)
+        .await?;
+
+    Ok(ProcessPreReceiveServerResponse(rejections))
+}
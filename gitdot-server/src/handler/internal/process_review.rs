@@ -3,11 +3,15 @@ use axum::{
     http::StatusCode,
 };
 
-use gitdot_core::dto::{ProcessReviewRequest, ReviewAuthorizationRequest};
+use gitdot_core::dto::{
+    ProcessReviewRequest, PublishReviewEventRequest, RecordPushRequest, ReviewAuthorizationRequest,
+    ReviewEventAction,
+};
 
 use crate::{
     app::{AppError, AppResponse, AppState},
     dto::{ProcessReviewServerRequest, ProcessReviewServerResponse, ReviewAction},
+    handler::review::trigger_review_builds,
 };
 
 #[axum::debug_handler]
@@ -23,6 +27,7 @@ pub async fn process_review(
         request.new_sha,
         request.pusher_id,
     )?;
+    let record_push_request = RecordPushRequest::new(&owner, &repo)?;
 
     let (action, review_number) = if review_request.is_new() {
         let review = state.review_service.create_review(review_request).await?;
@@ -40,9 +45,32 @@ pub async fn process_review(
             .review_service
             .process_review_update(review_request)
             .await?;
+
+        let event_request = PublishReviewEventRequest::new(
+            &owner,
+            &repo,
+            review.number,
+            request.pusher_id,
+            ReviewEventAction::RevisionPushed,
+        )?;
+        let event_service = state.event_service.clone();
+        tokio::spawn(async move {
+            if let Err(e) = event_service.publish_review_event(event_request).await {
+                tracing::error!("Failed to publish review event: {e}");
+            }
+        });
+
         (ReviewAction::Updated, review.number)
     };
 
+    let maintenance_service = state.maintenance_service.clone();
+    tokio::spawn(async move {
+        if let Err(e) = maintenance_service.record_push(record_push_request).await {
+            tracing::error!("Failed to record review push for maintenance: {e}");
+        }
+    });
+    tokio::spawn(trigger_review_builds(state, owner, repo, review_number));
+
     Ok(AppResponse::new(
         StatusCode::OK,
         ProcessReviewServerResponse {
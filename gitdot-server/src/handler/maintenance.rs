@@ -0,0 +1,23 @@
+mod get_maintenance;
+mod request_maintenance;
+
+use axum::{Router, routing::get};
+
+use gitdot_core::model::TokenScope;
+
+use crate::{app::AppState, extract::TokenScopePolicy};
+
+use get_maintenance::get_maintenance;
+use request_maintenance::request_maintenance;
+
+pub fn create_maintenance_router() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/repository/{owner}/{repo}/maintenance",
+            get(get_maintenance).post(request_maintenance),
+        )
+        .layer(TokenScopePolicy::new(
+            TokenScope::RepoRead,
+            TokenScope::RepoWrite,
+        ))
+}
@@ -0,0 +1,39 @@
+use axum::extract::{Path, State};
+use http::StatusCode;
+
+use gitdot_api::endpoint::maintenance::get_maintenance as api;
+use gitdot_core::dto::{
+    GetMaintenanceRequest, RepositoryAuthorizationRequest, RepositoryPermission,
+};
+
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    dto::IntoApi,
+    extract::{Principal, User},
+};
+
+#[axum::debug_handler]
+pub async fn get_maintenance(
+    auth_user: Principal<User>,
+    State(state): State<AppState>,
+    Path((owner, repo)): Path<(String, String)>,
+) -> Result<AppResponse<api::GetMaintenanceResponse>, AppError> {
+    let auth_request = RepositoryAuthorizationRequest::new(
+        Some(auth_user.id),
+        &owner,
+        &repo,
+        RepositoryPermission::Admin,
+    )?;
+    state
+        .authorization_service
+        .verify_authorized_for_repository(auth_request)
+        .await?;
+
+    let request = GetMaintenanceRequest::new(&owner, &repo)?;
+    state
+        .maintenance_service
+        .get_maintenance(request)
+        .await
+        .map_err(AppError::from)
+        .map(|m| AppResponse::new(StatusCode::OK, m.into_api()))
+}
@@ -0,0 +1,40 @@
+use axum::extract::{Path, State};
+use http::StatusCode;
+
+use gitdot_api::endpoint::maintenance::request_maintenance as api;
+use gitdot_core::dto::{
+    RepositoryAuthorizationRequest, RepositoryPermission, RequestMaintenanceRequest,
+};
+
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    dto::IntoApi,
+    extract::{Principal, User},
+};
+
+/// Queues a maintenance run; the scheduler picks it up on its next pass.
+#[axum::debug_handler]
+pub async fn request_maintenance(
+    auth_user: Principal<User>,
+    State(state): State<AppState>,
+    Path((owner, repo)): Path<(String, String)>,
+) -> Result<AppResponse<api::RequestMaintenanceResponse>, AppError> {
+    let auth_request = RepositoryAuthorizationRequest::new(
+        Some(auth_user.id),
+        &owner,
+        &repo,
+        RepositoryPermission::Admin,
+    )?;
+    state
+        .authorization_service
+        .verify_authorized_for_repository(auth_request)
+        .await?;
+
+    let request = RequestMaintenanceRequest::new(&owner, &repo)?;
+    state
+        .maintenance_service
+        .request_maintenance(request)
+        .await
+        .map_err(AppError::from)
+        .map(|m| AppResponse::new(StatusCode::ACCEPTED, m.into_api()))
+}
@@ -1,12 +1,21 @@
+mod cutover_repository_mirror;
 mod get_migration;
+mod get_repository_mirror;
 mod github;
 mod list_migrations;
 
-use axum::{Router, routing::get};
+use axum::{
+    Router,
+    routing::{get, post},
+};
 
-use crate::app::AppState;
+use gitdot_core::model::TokenScope;
 
+use crate::{app::AppState, extract::TokenScopePolicy};
+
+use cutover_repository_mirror::cutover_repository_mirror;
 use get_migration::get_migration;
+use get_repository_mirror::get_repository_mirror;
 use github::create_github_migration_router;
 use list_migrations::list_migrations;
 
@@ -14,5 +23,17 @@ pub fn create_migration_router() -> Router<AppState> {
     Router::new()
         .route("/migrations", get(list_migrations))
         .route("/migration/{number}", get(get_migration))
+        .route(
+            "/repository/{owner}/{repo}/mirror",
+            get(get_repository_mirror),
+        )
+        .route(
+            "/repository/{owner}/{repo}/mirror/cutover",
+            post(cutover_repository_mirror),
+        )
         .merge(create_github_migration_router())
+        .layer(TokenScopePolicy::new(
+            TokenScope::RepoRead,
+            TokenScope::RepoWrite,
+        ))
 }
@@ -0,0 +1,47 @@
+use axum::extract::{Json, Path, State};
+use http::StatusCode;
+
+use gitdot_api::endpoint::migration::cutover_repository_mirror as api;
+use gitdot_core::dto::{
+    CutoverRepositoryMirrorRequest, RepositoryAuthorizationRequest, RepositoryPermission,
+};
+
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    dto::IntoApi,
+    extract::{Principal, User},
+};
+
+#[axum::debug_handler]
+pub async fn cutover_repository_mirror(
+    auth_user: Principal<User>,
+    State(state): State<AppState>,
+    Path((owner, repo)): Path<(String, String)>,
+    Json(request): Json<api::CutoverRepositoryMirrorRequest>,
+) -> Result<AppResponse<api::CutoverRepositoryMirrorResponse>, AppError> {
+    let auth_request = RepositoryAuthorizationRequest::new(
+        Some(auth_user.id),
+        &owner,
+        &repo,
+        RepositoryPermission::Admin,
+    )?;
+    state
+        .authorization_service
+        .verify_authorized_for_repository(auth_request)
+        .await?;
+
+    let request = CutoverRepositoryMirrorRequest::new(
+        &repo,
+        &owner,
+        auth_user.id,
+        request.keep_push_mirror,
+        request.username,
+        request.password,
+    )?;
+    state
+        .migration_service
+        .cutover_repository_mirror(request)
+        .await
+        .map_err(AppError::from)
+        .map(|cutover| AppResponse::new(StatusCode::OK, cutover.into_api()))
+}
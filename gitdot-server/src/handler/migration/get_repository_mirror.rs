@@ -0,0 +1,41 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+};
+
+use gitdot_api::endpoint::migration::get_repository_mirror as api;
+use gitdot_core::dto::{
+    GetRepositoryMirrorRequest, RepositoryAuthorizationRequest, RepositoryPermission,
+};
+
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    dto::IntoApi,
+    extract::{Principal, User},
+};
+
+#[axum::debug_handler]
+pub async fn get_repository_mirror(
+    auth_user: Option<Principal<User>>,
+    State(state): State<AppState>,
+    Path((owner, repo)): Path<(String, String)>,
+) -> Result<AppResponse<api::GetRepositoryMirrorResponse>, AppError> {
+    let auth_request = RepositoryAuthorizationRequest::new(
+        auth_user.map(|u| u.id),
+        &owner,
+        &repo,
+        RepositoryPermission::Read,
+    )?;
+    state
+        .authorization_service
+        .verify_authorized_for_repository(auth_request)
+        .await?;
+
+    let request = GetRepositoryMirrorRequest::new(&repo, &owner)?;
+    state
+        .migration_service
+        .get_repository_mirror(request)
+        .await
+        .map_err(AppError::from)
+        .map(|m| AppResponse::new(StatusCode::OK, m.into_api()))
+}
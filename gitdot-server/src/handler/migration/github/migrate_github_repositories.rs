@@ -33,6 +33,7 @@ pub async fn migrate_github_repositories(
         .await?;
 
     let readonly = request.readonly;
+    let import_history = request.import_history;
     let repositories = request
         .repositories
         .into_iter()
@@ -61,11 +62,13 @@ pub async fn migrate_github_repositories(
         let request = MigrateGitHubRepositoriesRequest {
             migration_id: response.migration.id,
             installation_id,
+            author_id: auth_user.id,
             owner_id: response.owner_id,
             owner_name: response.owner_name,
             owner_type: response.owner_type,
             migration_repositories,
             readonly,
+            import_history,
         };
         let response = migration_service.migrate_github_repositories(request).await;
 
@@ -0,0 +1,40 @@
+mod create_mirror;
+mod delete_mirror;
+mod get_mirror;
+mod list_mirrors;
+mod request_mirror_sync;
+
+use axum::{
+    Router,
+    routing::{get, post},
+};
+
+use gitdot_core::model::TokenScope;
+
+use crate::{app::AppState, extract::TokenScopePolicy};
+
+use create_mirror::create_mirror;
+use delete_mirror::delete_mirror;
+use get_mirror::get_mirror;
+use list_mirrors::list_mirrors;
+use request_mirror_sync::request_mirror_sync;
+
+pub fn create_mirror_router() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/repository/{owner}/{repo}/mirrors",
+            get(list_mirrors).post(create_mirror),
+        )
+        .route(
+            "/repository/{owner}/{repo}/mirrors/{mirror_id}",
+            get(get_mirror).delete(delete_mirror),
+        )
+        .route(
+            "/repository/{owner}/{repo}/mirrors/{mirror_id}/sync",
+            post(request_mirror_sync),
+        )
+        .layer(TokenScopePolicy::new(
+            TokenScope::RepoRead,
+            TokenScope::RepoWrite,
+        ))
+}
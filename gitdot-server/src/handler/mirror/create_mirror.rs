@@ -0,0 +1,47 @@
+use axum::extract::{Json, Path, State};
+use http::StatusCode;
+
+use gitdot_api::endpoint::mirror::create_mirror as api;
+use gitdot_core::dto::{CreateMirrorRequest, RepositoryAuthorizationRequest, RepositoryPermission};
+
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    dto::IntoApi,
+    extract::{Principal, User},
+};
+
+#[axum::debug_handler]
+pub async fn create_mirror(
+    auth_user: Principal<User>,
+    State(state): State<AppState>,
+    Path((owner, repo)): Path<(String, String)>,
+    Json(request): Json<api::CreateMirrorRequest>,
+) -> Result<AppResponse<api::CreateMirrorResponse>, AppError> {
+    let auth_request = RepositoryAuthorizationRequest::new(
+        Some(auth_user.id),
+        &owner,
+        &repo,
+        RepositoryPermission::Admin,
+    )?;
+    state
+        .authorization_service
+        .verify_authorized_for_repository(auth_request)
+        .await?;
+
+    let request = CreateMirrorRequest::new(
+        &owner,
+        &repo,
+        &request.direction,
+        &request.url,
+        request.username,
+        request.password,
+        request.interval_seconds,
+        request.replace_refs,
+    )?;
+    state
+        .mirror_service
+        .create_mirror(request)
+        .await
+        .map_err(AppError::from)
+        .map(|m| AppResponse::new(StatusCode::CREATED, m.into_api()))
+}
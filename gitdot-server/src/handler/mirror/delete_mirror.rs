@@ -0,0 +1,32 @@
+use axum::extract::{Path, State};
+use http::StatusCode;
+use uuid::Uuid;
+
+use gitdot_core::dto::{DeleteMirrorRequest, RepositoryAuthorizationRequest, RepositoryPermission};
+
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    extract::{Principal, User},
+};
+
+#[axum::debug_handler]
+pub async fn delete_mirror(
+    auth_user: Principal<User>,
+    State(state): State<AppState>,
+    Path((owner, repo, mirror_id)): Path<(String, String, Uuid)>,
+) -> Result<AppResponse<()>, AppError> {
+    let auth_request = RepositoryAuthorizationRequest::new(
+        Some(auth_user.id),
+        &owner,
+        &repo,
+        RepositoryPermission::Admin,
+    )?;
+    state
+        .authorization_service
+        .verify_authorized_for_repository(auth_request)
+        .await?;
+
+    let request = DeleteMirrorRequest::new(&owner, &repo, mirror_id)?;
+    state.mirror_service.delete_mirror(request).await?;
+    Ok(AppResponse::new(StatusCode::OK, ()))
+}
@@ -0,0 +1,38 @@
+use axum::extract::{Path, State};
+use http::StatusCode;
+use uuid::Uuid;
+
+use gitdot_api::endpoint::mirror::get_mirror as api;
+use gitdot_core::dto::{GetMirrorRequest, RepositoryAuthorizationRequest, RepositoryPermission};
+
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    dto::IntoApi,
+    extract::{Principal, User},
+};
+
+#[axum::debug_handler]
+pub async fn get_mirror(
+    auth_user: Principal<User>,
+    State(state): State<AppState>,
+    Path((owner, repo, mirror_id)): Path<(String, String, Uuid)>,
+) -> Result<AppResponse<api::GetMirrorResponse>, AppError> {
+    let auth_request = RepositoryAuthorizationRequest::new(
+        Some(auth_user.id),
+        &owner,
+        &repo,
+        RepositoryPermission::Admin,
+    )?;
+    state
+        .authorization_service
+        .verify_authorized_for_repository(auth_request)
+        .await?;
+
+    let request = GetMirrorRequest::new(&owner, &repo, mirror_id)?;
+    state
+        .mirror_service
+        .get_mirror(request)
+        .await
+        .map_err(AppError::from)
+        .map(|m| AppResponse::new(StatusCode::OK, m.into_api()))
+}
@@ -0,0 +1,37 @@
+use axum::extract::{Path, State};
+use http::StatusCode;
+
+use gitdot_api::endpoint::mirror::list_mirrors as api;
+use gitdot_core::dto::{ListMirrorsRequest, RepositoryAuthorizationRequest, RepositoryPermission};
+
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    dto::IntoApi,
+    extract::{Principal, User},
+};
+
+#[axum::debug_handler]
+pub async fn list_mirrors(
+    auth_user: Principal<User>,
+    State(state): State<AppState>,
+    Path((owner, repo)): Path<(String, String)>,
+) -> Result<AppResponse<api::ListMirrorsResponse>, AppError> {
+    let auth_request = RepositoryAuthorizationRequest::new(
+        Some(auth_user.id),
+        &owner,
+        &repo,
+        RepositoryPermission::Admin,
+    )?;
+    state
+        .authorization_service
+        .verify_authorized_for_repository(auth_request)
+        .await?;
+
+    let request = ListMirrorsRequest::new(&owner, &repo)?;
+    state
+        .mirror_service
+        .list_mirrors(request)
+        .await
+        .map_err(AppError::from)
+        .map(|m| AppResponse::new(StatusCode::OK, m.into_api()))
+}
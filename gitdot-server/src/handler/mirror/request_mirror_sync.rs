@@ -0,0 +1,41 @@
+use axum::extract::{Path, State};
+use http::StatusCode;
+use uuid::Uuid;
+
+use gitdot_api::endpoint::mirror::request_mirror_sync as api;
+use gitdot_core::dto::{
+    RepositoryAuthorizationRequest, RepositoryPermission, RequestMirrorSyncRequest,
+};
+
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    dto::IntoApi,
+    extract::{Principal, User},
+};
+
+/// Queues a sync of the mirror; the scheduler picks it up on its next pass.
+#[axum::debug_handler]
+pub async fn request_mirror_sync(
+    auth_user: Principal<User>,
+    State(state): State<AppState>,
+    Path((owner, repo, mirror_id)): Path<(String, String, Uuid)>,
+) -> Result<AppResponse<api::RequestMirrorSyncResponse>, AppError> {
+    let auth_request = RepositoryAuthorizationRequest::new(
+        Some(auth_user.id),
+        &owner,
+        &repo,
+        RepositoryPermission::Admin,
+    )?;
+    state
+        .authorization_service
+        .verify_authorized_for_repository(auth_request)
+        .await?;
+
+    let request = RequestMirrorSyncRequest::new(&owner, &repo, mirror_id)?;
+    state
+        .mirror_service
+        .request_mirror_sync(request)
+        .await
+        .map_err(AppError::from)
+        .map(|m| AppResponse::new(StatusCode::ACCEPTED, m.into_api()))
+}
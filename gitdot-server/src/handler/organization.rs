@@ -13,7 +13,9 @@ use axum::{
     routing::{get, patch, post},
 };
 
-use crate::app::AppState;
+use gitdot_core::model::TokenScope;
+
+use crate::{app::AppState, extract::TokenScopePolicy};
 
 use add_member::add_member;
 use create_organization::create_organization;
@@ -46,4 +48,8 @@ pub fn create_organization_router() -> Router<AppState> {
             "/organization/{org_name}/repositories",
             get(list_organization_repositories),
         )
+        .layer(TokenScopePolicy::new(
+            TokenScope::RepoRead,
+            TokenScope::AdminOrg,
+        ))
 }
@@ -16,7 +16,9 @@ use axum::{
     routing::{get, patch, post},
 };
 
-use crate::app::AppState;
+use gitdot_core::model::TokenScope;
+
+use crate::{app::AppState, extract::TokenScopePolicy};
 
 use create_answer::create_answer;
 use create_answer_comment::create_answer_comment;
@@ -71,4 +73,8 @@ pub fn create_question_router() -> Router<AppState> {
             "/repository/{owner}/{repo}/question/{number}/comment/{comment_id}/vote",
             post(vote_comment),
         )
+        .layer(TokenScopePolicy::new(
+            TokenScope::RepoRead,
+            TokenScope::RepoWrite,
+        ))
 }
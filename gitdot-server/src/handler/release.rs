@@ -0,0 +1,112 @@
+mod create_release;
+mod delete_release;
+mod delete_release_asset;
+mod download_release_asset;
+mod get_release;
+mod list_releases;
+mod update_release;
+mod upload_release_asset;
+
+use axum::{
+    Router,
+    extract::DefaultBodyLimit,
+    routing::{get, post},
+};
+use uuid::Uuid;
+
+use gitdot_core::{
+    dto::{RepositoryAuthorizationRequest, RepositoryPermission},
+    model::TokenScope,
+};
+
+use crate::{
+    app::{AppError, AppState},
+    extract::TokenScopePolicy,
+};
+
+use create_release::create_release;
+use delete_release::delete_release;
+use delete_release_asset::delete_release_asset;
+use download_release_asset::download_release_asset;
+use get_release::get_release;
+use list_releases::list_releases;
+use update_release::update_release;
+use upload_release_asset::upload_release_asset;
+
+const MAX_ASSET_SIZE: usize = 512 * 1024 * 1024;
+
+pub fn create_release_router() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/repository/{owner}/{repo}/releases",
+            get(list_releases).post(create_release),
+        )
+        .route(
+            "/repository/{owner}/{repo}/releases/{tag}",
+            get(get_release)
+                .patch(update_release)
+                .delete(delete_release),
+        )
+        .route(
+            "/repository/{owner}/{repo}/releases/{tag}/assets",
+            post(upload_release_asset).layer(DefaultBodyLimit::max(MAX_ASSET_SIZE)),
+        )
+        .route(
+            "/repository/{owner}/{repo}/releases/{tag}/assets/{name}",
+            get(download_release_asset).delete(delete_release_asset),
+        )
+        .layer(TokenScopePolicy::new(
+            TokenScope::RepoRead,
+            TokenScope::RepoWrite,
+        ))
+}
+
+/// Verifies read access and reports whether the caller may also see drafts,
+/// which are limited to users who can write to the repository.
+async fn authorize_release_read(
+    state: &AppState,
+    user_id: Option<Uuid>,
+    owner: &str,
+    repo: &str,
+) -> Result<bool, AppError> {
+    let auth_request =
+        RepositoryAuthorizationRequest::new(user_id, owner, repo, RepositoryPermission::Read)?;
+    state
+        .authorization_service
+        .verify_authorized_for_repository(auth_request)
+        .await?;
+
+    let Some(user_id) = user_id else {
+        return Ok(false);
+    };
+    let write_request = RepositoryAuthorizationRequest::new(
+        Some(user_id),
+        owner,
+        repo,
+        RepositoryPermission::Write,
+    )?;
+    Ok(state
+        .authorization_service
+        .verify_authorized_for_repository(write_request)
+        .await
+        .is_ok())
+}
+
+async fn authorize_release_write(
+    state: &AppState,
+    user_id: Uuid,
+    owner: &str,
+    repo: &str,
+) -> Result<(), AppError> {
+    let auth_request = RepositoryAuthorizationRequest::new(
+        Some(user_id),
+        owner,
+        repo,
+        RepositoryPermission::Write,
+    )?;
+    state
+        .authorization_service
+        .verify_authorized_for_repository(auth_request)
+        .await?;
+    Ok(())
+}
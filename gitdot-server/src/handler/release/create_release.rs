@@ -0,0 +1,39 @@
+use axum::extract::{Json, Path, State};
+use http::StatusCode;
+
+use gitdot_api::endpoint::release::create_release as api;
+use gitdot_core::dto::CreateReleaseRequest;
+
+use super::authorize_release_write;
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    dto::IntoApi,
+    extract::{Principal, User},
+};
+
+#[axum::debug_handler]
+pub async fn create_release(
+    auth_user: Principal<User>,
+    State(state): State<AppState>,
+    Path((owner, repo)): Path<(String, String)>,
+    Json(request): Json<api::CreateReleaseRequest>,
+) -> Result<AppResponse<api::CreateReleaseResponse>, AppError> {
+    authorize_release_write(&state, auth_user.id, &owner, &repo).await?;
+
+    let request = CreateReleaseRequest::new(
+        &repo,
+        &owner,
+        &request.tag,
+        request.name,
+        request.body,
+        request.draft,
+        request.prerelease,
+        auth_user.id,
+    )?;
+    state
+        .release_service
+        .create_release(request)
+        .await
+        .map_err(AppError::from)
+        .map(|release| AppResponse::new(StatusCode::CREATED, release.into_api()))
+}
@@ -0,0 +1,27 @@
+use axum::extract::{Path, State};
+use http::StatusCode;
+
+use gitdot_core::dto::DeleteReleaseRequest;
+
+use super::authorize_release_write;
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    extract::{Principal, User},
+};
+
+#[axum::debug_handler]
+pub async fn delete_release(
+    auth_user: Principal<User>,
+    State(state): State<AppState>,
+    Path((owner, repo, tag)): Path<(String, String, String)>,
+) -> Result<AppResponse<()>, AppError> {
+    authorize_release_write(&state, auth_user.id, &owner, &repo).await?;
+
+    let request = DeleteReleaseRequest::new(&repo, &owner, &tag)?;
+    state
+        .release_service
+        .delete_release(request)
+        .await
+        .map_err(AppError::from)
+        .map(|_| AppResponse::new(StatusCode::NO_CONTENT, ()))
+}
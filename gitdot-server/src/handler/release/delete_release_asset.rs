@@ -0,0 +1,27 @@
+use axum::extract::{Path, State};
+use http::StatusCode;
+
+use gitdot_core::dto::DeleteReleaseAssetRequest;
+
+use super::authorize_release_write;
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    extract::{Principal, User},
+};
+
+#[axum::debug_handler]
+pub async fn delete_release_asset(
+    auth_user: Principal<User>,
+    State(state): State<AppState>,
+    Path((owner, repo, tag, name)): Path<(String, String, String, String)>,
+) -> Result<AppResponse<()>, AppError> {
+    authorize_release_write(&state, auth_user.id, &owner, &repo).await?;
+
+    let request = DeleteReleaseAssetRequest::new(&repo, &owner, &tag, &name)?;
+    state
+        .release_service
+        .delete_release_asset(request)
+        .await
+        .map_err(AppError::from)
+        .map(|_| AppResponse::new(StatusCode::NO_CONTENT, ()))
+}
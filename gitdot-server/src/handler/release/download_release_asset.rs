@@ -0,0 +1,28 @@
+use axum::extract::{Path, State};
+
+use gitdot_core::dto::DownloadReleaseAssetRequest;
+
+use super::authorize_release_read;
+use crate::{
+    app::{AppError, AppState},
+    dto::ReleaseAssetServerResponse,
+    extract::{Principal, User},
+};
+
+#[axum::debug_handler]
+pub async fn download_release_asset(
+    auth_user: Option<Principal<User>>,
+    State(state): State<AppState>,
+    Path((owner, repo, tag, name)): Path<(String, String, String, String)>,
+) -> Result<ReleaseAssetServerResponse, AppError> {
+    let include_drafts =
+        authorize_release_read(&state, auth_user.map(|u| u.id), &owner, &repo).await?;
+
+    let request = DownloadReleaseAssetRequest::new(&repo, &owner, &tag, &name, include_drafts)?;
+    state
+        .release_service
+        .download_release_asset(request)
+        .await
+        .map_err(AppError::from)
+        .map(Into::into)
+}
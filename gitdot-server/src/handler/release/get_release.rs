@@ -0,0 +1,30 @@
+use axum::extract::{Path, State};
+use http::StatusCode;
+
+use gitdot_api::endpoint::release::get_release as api;
+use gitdot_core::dto::GetReleaseRequest;
+
+use super::authorize_release_read;
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    dto::IntoApi,
+    extract::{Principal, User},
+};
+
+#[axum::debug_handler]
+pub async fn get_release(
+    auth_user: Option<Principal<User>>,
+    State(state): State<AppState>,
+    Path((owner, repo, tag)): Path<(String, String, String)>,
+) -> Result<AppResponse<api::GetReleaseResponse>, AppError> {
+    let include_drafts =
+        authorize_release_read(&state, auth_user.map(|u| u.id), &owner, &repo).await?;
+
+    let request = GetReleaseRequest::new(&repo, &owner, &tag, include_drafts)?;
+    state
+        .release_service
+        .get_release(request)
+        .await
+        .map_err(AppError::from)
+        .map(|release| AppResponse::new(StatusCode::OK, release.into_api()))
+}
@@ -0,0 +1,30 @@
+use axum::extract::{Path, State};
+use http::StatusCode;
+
+use gitdot_api::endpoint::release::list_releases as api;
+use gitdot_core::dto::ListReleasesRequest;
+
+use super::authorize_release_read;
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    dto::IntoApi,
+    extract::{Principal, User},
+};
+
+#[axum::debug_handler]
+pub async fn list_releases(
+    auth_user: Option<Principal<User>>,
+    State(state): State<AppState>,
+    Path((owner, repo)): Path<(String, String)>,
+) -> Result<AppResponse<api::ListReleasesResponse>, AppError> {
+    let include_drafts =
+        authorize_release_read(&state, auth_user.map(|u| u.id), &owner, &repo).await?;
+
+    let request = ListReleasesRequest::new(&repo, &owner, include_drafts)?;
+    state
+        .release_service
+        .list_releases(request)
+        .await
+        .map_err(AppError::from)
+        .map(|releases| AppResponse::new(StatusCode::OK, releases.into_api()))
+}
@@ -0,0 +1,38 @@
+use axum::extract::{Json, Path, State};
+use http::StatusCode;
+
+use gitdot_api::endpoint::release::update_release as api;
+use gitdot_core::dto::UpdateReleaseRequest;
+
+use super::authorize_release_write;
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    dto::IntoApi,
+    extract::{Principal, User},
+};
+
+#[axum::debug_handler]
+pub async fn update_release(
+    auth_user: Principal<User>,
+    State(state): State<AppState>,
+    Path((owner, repo, tag)): Path<(String, String, String)>,
+    Json(request): Json<api::UpdateReleaseRequest>,
+) -> Result<AppResponse<api::UpdateReleaseResponse>, AppError> {
+    authorize_release_write(&state, auth_user.id, &owner, &repo).await?;
+
+    let request = UpdateReleaseRequest::new(
+        &repo,
+        &owner,
+        &tag,
+        request.name,
+        request.body,
+        request.draft,
+        request.prerelease,
+    )?;
+    state
+        .release_service
+        .update_release(request)
+        .await
+        .map_err(AppError::from)
+        .map(|release| AppResponse::new(StatusCode::OK, release.into_api()))
+}
@@ -0,0 +1,46 @@
+use axum::{
+    body::Bytes,
+    extract::{Path, Query, State},
+    http::{HeaderMap, StatusCode, header},
+};
+
+use gitdot_api::endpoint::release::upload_release_asset as api;
+use gitdot_core::dto::UploadReleaseAssetRequest;
+
+use super::authorize_release_write;
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    dto::IntoApi,
+    extract::{Principal, User},
+};
+
+#[axum::debug_handler]
+pub async fn upload_release_asset(
+    auth_user: Principal<User>,
+    State(state): State<AppState>,
+    Path((owner, repo, tag)): Path<(String, String, String)>,
+    Query(query): Query<api::UploadReleaseAssetRequest>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Result<AppResponse<api::UploadReleaseAssetResponse>, AppError> {
+    authorize_release_write(&state, auth_user.id, &owner, &repo).await?;
+
+    let content_type = headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok());
+    let request = UploadReleaseAssetRequest::new(
+        &repo,
+        &owner,
+        &tag,
+        &query.name,
+        content_type,
+        body,
+        auth_user.id,
+    )?;
+    state
+        .release_service
+        .upload_release_asset(request)
+        .await
+        .map_err(AppError::from)
+        .map(|asset| AppResponse::new(StatusCode::CREATED, asset.into_api()))
+}
@@ -1,9 +1,12 @@
+mod compare_repository_refs;
 mod create_repository;
 mod create_repository_commit_filter;
 mod delete_repository;
 mod delete_repository_commit_filter;
 mod get_repository;
 mod get_repository_activity;
+mod get_repository_archive;
+mod get_repository_blame;
 mod get_repository_blob;
 mod get_repository_blobs;
 mod get_repository_commit;
@@ -24,14 +27,19 @@ use axum::{
     routing::{get, patch, post},
 };
 
-use crate::app::AppState;
+use gitdot_core::model::TokenScope;
 
+use crate::{app::AppState, extract::TokenScopePolicy};
+
+use compare_repository_refs::compare_repository_refs;
 use create_repository::create_repository;
 use create_repository_commit_filter::create_repository_commit_filter;
 use delete_repository::delete_repository;
 use delete_repository_commit_filter::delete_repository_commit_filter;
 use get_repository::get_repository;
 use get_repository_activity::get_repository_activity;
+use get_repository_archive::get_repository_archive;
+use get_repository_blame::get_repository_blame;
 use get_repository_blob::get_repository_blob;
 use get_repository_blobs::get_repository_blobs;
 use get_repository_commit::get_repository_commit;
@@ -58,10 +66,22 @@ pub fn create_repository_router() -> Router<AppState> {
                 .patch(update_repository)
                 .delete(delete_repository),
         )
+        .route(
+            "/repository/{owner}/{repo}/blame",
+            get(get_repository_blame),
+        )
         .route("/repository/{owner}/{repo}/blob", get(get_repository_blob))
+        .route(
+            "/repository/{owner}/{repo}/compare/{*range}",
+            get(compare_repository_refs),
+        )
+        .route(
+            "/repository/{owner}/{repo}/archive/{*archive}",
+            get(get_repository_archive),
+        )
         .route(
             "/repository/{owner}/{repo}/blobs",
-            post(get_repository_blobs),
+            post(get_repository_blobs).layer(TokenScopePolicy::only(TokenScope::RepoRead)),
         )
         .route(
             "/repository/{owner}/{repo}/paths",
@@ -81,7 +101,7 @@ pub fn create_repository_router() -> Router<AppState> {
         )
         .route(
             "/repository/{owner}/{repo}/resources",
-            post(get_repository_resources),
+            post(get_repository_resources).layer(TokenScopePolicy::only(TokenScope::RepoRead)),
         )
         .route("/repository/{owner}/{repo}/star", post(star_repository))
         .route("/repository/{owner}/{repo}/unstar", post(unstar_repository))
@@ -97,4 +117,8 @@ pub fn create_repository_router() -> Router<AppState> {
             "/repository/{owner}/{repo}/commit-filters/{filter_id}",
             patch(update_repository_commit_filter).delete(delete_repository_commit_filter),
         )
+        .layer(TokenScopePolicy::new(
+            TokenScope::RepoRead,
+            TokenScope::RepoWrite,
+        ))
 }
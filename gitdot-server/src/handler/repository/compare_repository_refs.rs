@@ -0,0 +1,51 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+};
+
+use gitdot_api::endpoint::compare_repository_refs as api;
+use gitdot_core::dto::{
+    CompareRepositoryRefsRequest, RepositoryAuthorizationRequest, RepositoryPermission,
+};
+
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    dto::IntoApi,
+    extract::{Principal, User},
+};
+
+#[axum::debug_handler]
+pub async fn compare_repository_refs(
+    auth_user: Option<Principal<User>>,
+    State(state): State<AppState>,
+    Path((owner, repo, range)): Path<(String, String, String)>,
+    Query(params): Query<api::CompareRepositoryRefsRequest>,
+) -> Result<AppResponse<api::CompareRepositoryRefsResponse>, AppError> {
+    let auth_request = RepositoryAuthorizationRequest::new(
+        auth_user.map(|u| u.id),
+        &owner,
+        &repo,
+        RepositoryPermission::Read,
+    )?;
+    state
+        .authorization_service
+        .verify_authorized_for_repository(auth_request)
+        .await?;
+
+    let request = CompareRepositoryRefsRequest::new(
+        &repo,
+        &owner,
+        &range,
+        params.ignore_whitespace.as_deref(),
+        params.detect_renames,
+        params.cursor.as_deref(),
+        params.limit,
+    )?;
+
+    state
+        .repo_service
+        .compare_repository_refs(request)
+        .await
+        .map_err(AppError::from)
+        .map(|compare| AppResponse::new(StatusCode::OK, compare.into_api()))
+}
@@ -0,0 +1,41 @@
+use axum::{
+    extract::{Path, State},
+    http::HeaderMap,
+};
+
+use gitdot_core::dto::{
+    GetRepositoryArchiveRequest, RepositoryAuthorizationRequest, RepositoryPermission,
+};
+
+use crate::{
+    app::{AppError, AppState},
+    dto::RepositoryArchiveServerResponse,
+    extract::{Principal, User},
+};
+
+#[axum::debug_handler]
+pub async fn get_repository_archive(
+    auth_user: Option<Principal<User>>,
+    State(state): State<AppState>,
+    Path((owner, repo, archive)): Path<(String, String, String)>,
+    headers: HeaderMap,
+) -> Result<RepositoryArchiveServerResponse, AppError> {
+    let auth_request = RepositoryAuthorizationRequest::new(
+        auth_user.map(|u| u.id),
+        &owner,
+        &repo,
+        RepositoryPermission::Read,
+    )?;
+    state
+        .authorization_service
+        .verify_authorized_for_repository(auth_request)
+        .await?;
+
+    let request = GetRepositoryArchiveRequest::new(&repo, &owner, &archive)?;
+    state
+        .repo_service
+        .get_repository_archive(request)
+        .await
+        .map_err(AppError::from)
+        .map(|archive| RepositoryArchiveServerResponse::new(archive, &headers))
+}
@@ -0,0 +1,49 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+};
+
+use gitdot_api::endpoint::get_repository_blame as api;
+use gitdot_core::dto::{
+    GetRepositoryBlameRequest, RepositoryAuthorizationRequest, RepositoryPermission,
+};
+
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    dto::IntoApi,
+    extract::{Principal, User},
+};
+
+#[axum::debug_handler]
+pub async fn get_repository_blame(
+    auth_user: Option<Principal<User>>,
+    State(state): State<AppState>,
+    Path((owner, repo)): Path<(String, String)>,
+    Query(params): Query<api::GetRepositoryBlameRequest>,
+) -> Result<AppResponse<api::GetRepositoryBlameResponse>, AppError> {
+    let auth_request = RepositoryAuthorizationRequest::new(
+        auth_user.map(|u| u.id),
+        &owner,
+        &repo,
+        RepositoryPermission::Read,
+    )?;
+    state
+        .authorization_service
+        .verify_authorized_for_repository(auth_request)
+        .await?;
+
+    let request = GetRepositoryBlameRequest::new(
+        &repo,
+        &owner,
+        params.ref_name,
+        params.path,
+        params.ignore_revs_file,
+    )?;
+
+    state
+        .repo_service
+        .get_repository_blame(request)
+        .await
+        .map_err(AppError::from)
+        .map(|blame| AppResponse::new(StatusCode::OK, blame.into_api()))
+}
@@ -1,6 +1,8 @@
 mod add_review_reviewer;
 mod get_review;
 mod get_review_diff_blobs;
+mod get_review_diff_mergeability;
+mod get_review_merge_requirements;
 mod list_reviews;
 mod merge_review_diff;
 mod publish_review;
@@ -12,8 +14,14 @@ mod review_review_diff;
 mod update_review;
 mod update_review_comment;
 mod update_review_diff;
+mod update_review_merge_requirements;
 
-use crate::app::AppState;
+use gitdot_core::{
+    dto::{CreateBuildRequest, GetReviewRequest},
+    model::TokenScope,
+};
+
+use crate::{app::AppState, extract::TokenScopePolicy};
 use axum::{
     Router,
     routing::{delete, get, patch, post},
@@ -22,6 +30,8 @@ use axum::{
 use add_review_reviewer::add_review_reviewer;
 use get_review::get_review;
 use get_review_diff_blobs::get_review_diff_blobs;
+use get_review_diff_mergeability::get_review_diff_mergeability;
+use get_review_merge_requirements::get_review_merge_requirements;
 use list_reviews::list_reviews;
 use merge_review_diff::merge_review_diff;
 use publish_review::publish_review;
@@ -33,6 +43,7 @@ use review_review_diff::review_review_diff;
 use update_review::update_review;
 use update_review_comment::update_review_comment;
 use update_review_diff::update_review_diff;
+use update_review_merge_requirements::update_review_merge_requirements;
 
 pub fn create_review_router() -> Router<AppState> {
     Router::new()
@@ -41,6 +52,15 @@ pub fn create_review_router() -> Router<AppState> {
             get(get_review).patch(update_review),
         )
         .route("/repository/{owner}/{repo}/reviews", get(list_reviews))
+        .route(
+            "/repository/{owner}/{repo}/review-merge-requirements",
+            get(get_review_merge_requirements)
+                .put(update_review_merge_requirements)
+                .layer(TokenScopePolicy::new(
+                    TokenScope::RepoRead,
+                    TokenScope::RepoWrite,
+                )),
+        )
         .route(
             "/repository/{owner}/{repo}/review/{number}/publish",
             post(publish_review),
@@ -65,6 +85,10 @@ pub fn create_review_router() -> Router<AppState> {
             "/repository/{owner}/{repo}/review/{number}/diff/{position}/merge",
             post(merge_review_diff),
         )
+        .route(
+            "/repository/{owner}/{repo}/review/{number}/diff/{position}/mergeability",
+            get(get_review_diff_mergeability),
+        )
         .route(
             "/repository/{owner}/{repo}/review/{number}/diff/{position}/publish",
             post(publish_review_diff),
@@ -85,4 +109,43 @@ pub fn create_review_router() -> Router<AppState> {
             "/repository/{owner}/{repo}/review/{number}/comment/{comment_id}/resolve",
             post(resolve_review_comment),
         )
+        .layer(TokenScopePolicy::new(
+            TokenScope::RepoRead,
+            TokenScope::ReviewWrite,
+        ))
+}
+
+/// Starts a build for the latest revision of each diff. Revisions that were
+/// already built at their current commit are skipped by the build service.
+pub(crate) async fn trigger_review_builds(
+    state: AppState,
+    owner: String,
+    repo: String,
+    number: i32,
+) {
+    let review = match GetReviewRequest::new(&owner, &repo, number) {
+        Ok(request) => state.review_service.get_review(request).await,
+        Err(e) => Err(e),
+    };
+    let review = match review {
+        Ok(review) => review,
+        Err(e) => {
+            tracing::error!("Failed to load review for builds: {e}");
+            return;
+        }
+    };
+
+    for diff in &review.diffs {
+        let Some(revision) = diff.revisions.iter().max_by_key(|r| r.number) else {
+            continue;
+        };
+        let result =
+            match CreateBuildRequest::for_review_revision(&owner, &repo, &review, diff, revision) {
+                Ok(request) => state.build_service.trigger_build(request).await,
+                Err(e) => Err(e),
+            };
+        if let Err(e) = result {
+            tracing::error!("Failed to trigger build for review revision: {e}");
+        }
+    }
 }
@@ -0,0 +1,38 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+};
+
+use gitdot_api::endpoint::get_review_diff_mergeability as api;
+use gitdot_core::dto::{
+    GetReviewDiffMergeabilityRequest, RepositoryAuthorizationRequest, RepositoryPermission,
+};
+
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    dto::IntoApi,
+    extract::{Principal, User},
+};
+
+#[axum::debug_handler]
+pub async fn get_review_diff_mergeability(
+    auth_user: Option<Principal<User>>,
+    State(state): State<AppState>,
+    Path((owner, repo, number, position)): Path<(String, String, i32, i32)>,
+) -> Result<AppResponse<api::GetReviewDiffMergeabilityResponse>, AppError> {
+    let user_id = auth_user.as_ref().map(|u| u.id);
+    let auth_request =
+        RepositoryAuthorizationRequest::new(user_id, &owner, &repo, RepositoryPermission::Read)?;
+    state
+        .authorization_service
+        .verify_authorized_for_repository(auth_request)
+        .await?;
+
+    let request = GetReviewDiffMergeabilityRequest::new(&owner, &repo, number, position)?;
+    state
+        .review_service
+        .get_review_diff_mergeability(request)
+        .await
+        .map_err(AppError::from)
+        .map(|m| AppResponse::new(StatusCode::OK, m.into_api()))
+}
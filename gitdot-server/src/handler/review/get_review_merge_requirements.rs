@@ -0,0 +1,38 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+};
+
+use gitdot_api::endpoint::get_review_merge_requirements as api;
+use gitdot_core::dto::{
+    GetReviewMergeRequirementsRequest, RepositoryAuthorizationRequest, RepositoryPermission,
+};
+
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    dto::IntoApi,
+    extract::{Principal, User},
+};
+
+#[axum::debug_handler]
+pub async fn get_review_merge_requirements(
+    auth_user: Option<Principal<User>>,
+    State(state): State<AppState>,
+    Path((owner, repo)): Path<(String, String)>,
+) -> Result<AppResponse<api::GetReviewMergeRequirementsResponse>, AppError> {
+    let user_id = auth_user.as_ref().map(|u| u.id);
+    let auth_request =
+        RepositoryAuthorizationRequest::new(user_id, &owner, &repo, RepositoryPermission::Read)?;
+    state
+        .authorization_service
+        .verify_authorized_for_repository(auth_request)
+        .await?;
+
+    let request = GetReviewMergeRequirementsRequest::new(&owner, &repo)?;
+    state
+        .review_service
+        .get_review_merge_requirements(request)
+        .await
+        .map_err(AppError::from)
+        .map(|r| AppResponse::new(StatusCode::OK, r.into_api()))
+}
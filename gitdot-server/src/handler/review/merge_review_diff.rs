@@ -6,14 +6,20 @@ use axum::{
 };
 
 use gitdot_api::endpoint::merge_review_diff as api;
-use gitdot_core::dto::{
-    CreateCommitsRequest, MergeReviewDiffRequest, ReviewAuthorizationRequest, ReviewResponse,
+use gitdot_core::{
+    dto::{
+        CreateCommitsRequest, MergeReviewDiffRequest, PublishReviewEventRequest,
+        ReviewAuthorizationRequest, ReviewEventAction, ReviewResponse,
+    },
+    error::ReviewError,
+    model::MergeCheckKind,
 };
 
 use crate::{
     app::{AppError, AppResponse, AppState},
     dto::IntoApi,
     extract::{Principal, User},
+    handler::review::trigger_review_builds,
 };
 
 #[axum::debug_handler]
@@ -29,7 +35,27 @@ pub async fn merge_review_diff(
         .await?;
 
     let request = MergeReviewDiffRequest::new(&owner, &repo, number, position)?;
-    let response = state.review_service.merge_review_diff(request).await?;
+    let response = match state.review_service.merge_review_diff(request).await {
+        Ok(response) => response,
+        Err(ReviewError::MergeRequirementsNotMet(checks)) => {
+            // A merge onto an advanced target rebases the stack before stopping
+            // for a green build; build the rebased commits so a later attempt
+            // can land.
+            if checks
+                .iter()
+                .any(|c| c.kind == MergeCheckKind::GreenBuild && !c.passed)
+            {
+                tokio::spawn(trigger_review_builds(
+                    state.clone(),
+                    owner.clone(),
+                    repo.clone(),
+                    number,
+                ));
+            }
+            return Err(ReviewError::MergeRequirementsNotMet(checks).into());
+        }
+        Err(e) => return Err(e.into()),
+    };
 
     // Create commits for all merged diffs, including previously merged ones.
     // ON CONFLICT DO NOTHING in create_bulk skips commits that already exist.
@@ -37,6 +63,23 @@ pub async fn merge_review_diff(
         state.commit_service.create_commits(commit_request).await?;
     }
 
+    let mut actions = vec![ReviewEventAction::DiffMerged { position }];
+    if response.status == "closed" {
+        actions.push(ReviewEventAction::Closed);
+    }
+    let event_requests = actions
+        .into_iter()
+        .map(|action| PublishReviewEventRequest::new(&owner, &repo, number, auth_user.id, action))
+        .collect::<Result<Vec<_>, _>>()?;
+    let event_service = state.event_service.clone();
+    tokio::spawn(async move {
+        for event_request in event_requests {
+            if let Err(e) = event_service.publish_review_event(event_request).await {
+                tracing::error!("Failed to publish review event: {e}");
+            }
+        }
+    });
+
     Ok(AppResponse::new(StatusCode::OK, response.into_api()))
 }
 
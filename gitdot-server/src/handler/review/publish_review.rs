@@ -4,7 +4,9 @@ use axum::{
 };
 
 use gitdot_api::resource::review::ReviewResource;
-use gitdot_core::dto::{PublishReviewRequest, ReviewAuthorizationRequest};
+use gitdot_core::dto::{
+    PublishReviewEventRequest, PublishReviewRequest, ReviewAuthorizationRequest, ReviewEventAction,
+};
 
 use crate::{
     app::{AppError, AppResponse, AppState},
@@ -31,5 +33,22 @@ pub async fn publish_review(
         .await
         .map_err(AppError::from)?;
 
+    let event_request = PublishReviewEventRequest::new(
+        &owner,
+        &repo,
+        number,
+        auth_user.id,
+        ReviewEventAction::Published,
+    )?;
+    tokio::spawn(async move {
+        if let Err(e) = state
+            .event_service
+            .publish_review_event(event_request)
+            .await
+        {
+            tracing::error!("Failed to publish review event: {e}");
+        }
+    });
+
     Ok(AppResponse::new(StatusCode::OK, review.into_api()))
 }
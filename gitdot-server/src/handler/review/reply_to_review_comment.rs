@@ -6,7 +6,10 @@ use axum::{
 use uuid::Uuid;
 
 use gitdot_api::endpoint::reply_to_review_comment as api;
-use gitdot_core::dto::{ReplyToReviewCommentRequest, ReviewingAuthorizationRequest};
+use gitdot_core::dto::{
+    PublishReviewEventRequest, ReplyToReviewCommentRequest, ReviewEventAction,
+    ReviewingAuthorizationRequest,
+};
 
 use crate::{
     app::{AppError, AppResponse, AppState},
@@ -36,10 +39,30 @@ pub async fn reply_to_review_comment(
         request.body,
     )?;
 
-    state
+    let response = state
         .review_service
         .reply_to_review_comment(request)
-        .await
-        .map_err(AppError::from)
-        .map(|response| AppResponse::new(StatusCode::CREATED, response.into_api()))
+        .await?;
+
+    let event_request = PublishReviewEventRequest::new(
+        &owner,
+        &repo,
+        number,
+        auth_user.id,
+        ReviewEventAction::CommentAdded {
+            position: None,
+            comment_count: 1,
+        },
+    )?;
+    tokio::spawn(async move {
+        if let Err(e) = state
+            .event_service
+            .publish_review_event(event_request)
+            .await
+        {
+            tracing::error!("Failed to publish review event: {e}");
+        }
+    });
+
+    Ok(AppResponse::new(StatusCode::CREATED, response.into_api()))
 }
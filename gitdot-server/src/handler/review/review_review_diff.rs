@@ -5,7 +5,10 @@ use axum::{
 };
 
 use gitdot_api::endpoint::review_review_diff as api;
-use gitdot_core::dto::{ReviewReviewDiffRequest, ReviewingAuthorizationRequest};
+use gitdot_core::dto::{
+    PublishReviewEventRequest, ReviewAction, ReviewEventAction, ReviewReviewDiffRequest,
+    ReviewingAuthorizationRequest,
+};
 
 use crate::{
     app::{AppError, AppResponse, AppState},
@@ -55,10 +58,43 @@ pub async fn review_review_diff(
         comments,
     )?;
 
-    state
+    let comment_count = core_request.comments.len();
+    let event_action = match core_request.action {
+        ReviewAction::Comment if comment_count == 0 => None,
+        ReviewAction::Comment => Some(ReviewEventAction::CommentAdded {
+            position: Some(position),
+            comment_count,
+        }),
+        ReviewAction::Approve => Some(ReviewEventAction::VerdictGiven {
+            position,
+            verdict: "approved".to_string(),
+            comment_count,
+        }),
+        ReviewAction::Reject => Some(ReviewEventAction::VerdictGiven {
+            position,
+            verdict: "rejected".to_string(),
+            comment_count,
+        }),
+    };
+
+    let response = state
         .review_service
         .review_review_diff(core_request)
-        .await
-        .map_err(AppError::from)
-        .map(|response| AppResponse::new(StatusCode::OK, response.into_api()))
+        .await?;
+
+    if let Some(action) = event_action {
+        let event_request =
+            PublishReviewEventRequest::new(&owner, &repo, number, auth_user.id, action)?;
+        tokio::spawn(async move {
+            if let Err(e) = state
+                .event_service
+                .publish_review_event(event_request)
+                .await
+            {
+                tracing::error!("Failed to publish review event: {e}");
+            }
+        });
+    }
+
+    Ok(AppResponse::new(StatusCode::OK, response.into_api()))
 }
@@ -0,0 +1,51 @@
+use axum::{
+    extract::{Json, Path, State},
+    http::StatusCode,
+};
+
+use gitdot_api::endpoint::update_review_merge_requirements as api;
+use gitdot_core::{
+    dto::{
+        RepositoryAuthorizationRequest, RepositoryPermission, UpdateReviewMergeRequirementsRequest,
+    },
+    model::MergeRequirements,
+};
+
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    dto::IntoApi,
+    extract::{Principal, User},
+};
+
+#[axum::debug_handler]
+pub async fn update_review_merge_requirements(
+    auth_user: Principal<User>,
+    State(state): State<AppState>,
+    Path((owner, repo)): Path<(String, String)>,
+    Json(request): Json<api::UpdateReviewMergeRequirementsRequest>,
+) -> Result<AppResponse<api::UpdateReviewMergeRequirementsResponse>, AppError> {
+    let auth_request = RepositoryAuthorizationRequest::new(
+        Some(auth_user.id),
+        &owner,
+        &repo,
+        RepositoryPermission::Admin,
+    )?;
+    state
+        .authorization_service
+        .verify_authorized_for_repository(auth_request)
+        .await?;
+
+    let requirements = MergeRequirements {
+        required_approvals: request.required_approvals,
+        block_on_rejection: request.block_on_rejection,
+        require_resolved_comments: request.require_resolved_comments,
+        require_green_build: request.require_green_build,
+    };
+    let request = UpdateReviewMergeRequirementsRequest::new(&owner, &repo, requirements)?;
+    state
+        .review_service
+        .update_review_merge_requirements(request)
+        .await
+        .map_err(AppError::from)
+        .map(|r| AppResponse::new(StatusCode::OK, r.into_api()))
+}
@@ -10,7 +10,9 @@ use axum::{
     routing::{delete, get, post},
 };
 
-use crate::app::AppState;
+use gitdot_core::model::TokenScope;
+
+use crate::{app::AppState, extract::TokenScopePolicy};
 
 use create_runner::create_runner;
 use delete_runner::delete_runner;
@@ -26,4 +28,5 @@ pub fn create_runner_router() -> Router<AppState> {
         .route("/runner/{owner}/{name}", get(get_runner))
         .route("/runner/{owner}/{name}", delete(delete_runner))
         .route("/runner/{owner}/{name}/token", post(refresh_runner_token))
+        .layer(TokenScopePolicy::only(TokenScope::RunnerAdmin))
 }
@@ -0,0 +1,50 @@
+mod delete_organization_secret;
+mod delete_repository_secret;
+mod list_organization_secrets;
+mod list_repository_secrets;
+mod set_organization_secret;
+mod set_repository_secret;
+
+use axum::{
+    Router,
+    routing::{get, put},
+};
+
+use gitdot_core::model::TokenScope;
+
+use crate::{app::AppState, extract::TokenScopePolicy};
+
+use delete_organization_secret::delete_organization_secret;
+use delete_repository_secret::delete_repository_secret;
+use list_organization_secrets::list_organization_secrets;
+use list_repository_secrets::list_repository_secrets;
+use set_organization_secret::set_organization_secret;
+use set_repository_secret::set_repository_secret;
+
+const REPOSITORY_SECRET_SCOPES: TokenScopePolicy =
+    TokenScopePolicy::new(TokenScope::RepoRead, TokenScope::RepoWrite);
+const ORGANIZATION_SECRET_SCOPES: TokenScopePolicy = TokenScopePolicy::only(TokenScope::AdminOrg);
+
+pub fn create_secret_router() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/repository/{owner}/{repo}/secrets",
+            get(list_repository_secrets).layer(REPOSITORY_SECRET_SCOPES),
+        )
+        .route(
+            "/repository/{owner}/{repo}/secret/{name}",
+            put(set_repository_secret)
+                .delete(delete_repository_secret)
+                .layer(REPOSITORY_SECRET_SCOPES),
+        )
+        .route(
+            "/organization/{org_name}/secrets",
+            get(list_organization_secrets).layer(ORGANIZATION_SECRET_SCOPES),
+        )
+        .route(
+            "/organization/{org_name}/secret/{name}",
+            put(set_organization_secret)
+                .delete(delete_organization_secret)
+                .layer(ORGANIZATION_SECRET_SCOPES),
+        )
+}
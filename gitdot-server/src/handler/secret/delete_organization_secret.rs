@@ -0,0 +1,27 @@
+use axum::extract::{Path, State};
+use http::StatusCode;
+
+use gitdot_core::dto::{CiSecretScope, DeleteCiSecretRequest, OrganizationAuthorizationRequest};
+
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    extract::{Principal, User},
+};
+
+#[axum::debug_handler]
+pub async fn delete_organization_secret(
+    auth_user: Principal<User>,
+    State(state): State<AppState>,
+    Path((org_name, name)): Path<(String, String)>,
+) -> Result<AppResponse<()>, AppError> {
+    let auth_request = OrganizationAuthorizationRequest::new(auth_user.id, &org_name)?;
+    state
+        .authorization_service
+        .verify_authorized_for_organization(auth_request)
+        .await?;
+
+    let scope = CiSecretScope::organization(&org_name)?;
+    let request = DeleteCiSecretRequest::new(scope, &name);
+    state.ci_secret_service.delete_secret(request).await?;
+    Ok(AppResponse::new(StatusCode::OK, ()))
+}
@@ -0,0 +1,34 @@
+use axum::extract::{Path, State};
+use http::StatusCode;
+
+use gitdot_core::dto::{
+    CiSecretScope, DeleteCiSecretRequest, RepositoryAuthorizationRequest, RepositoryPermission,
+};
+
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    extract::{Principal, User},
+};
+
+#[axum::debug_handler]
+pub async fn delete_repository_secret(
+    auth_user: Principal<User>,
+    State(state): State<AppState>,
+    Path((owner, repo, name)): Path<(String, String, String)>,
+) -> Result<AppResponse<()>, AppError> {
+    let auth_request = RepositoryAuthorizationRequest::new(
+        Some(auth_user.id),
+        &owner,
+        &repo,
+        RepositoryPermission::Admin,
+    )?;
+    state
+        .authorization_service
+        .verify_authorized_for_repository(auth_request)
+        .await?;
+
+    let scope = CiSecretScope::repository(&owner, &repo)?;
+    let request = DeleteCiSecretRequest::new(scope, &name);
+    state.ci_secret_service.delete_secret(request).await?;
+    Ok(AppResponse::new(StatusCode::OK, ()))
+}
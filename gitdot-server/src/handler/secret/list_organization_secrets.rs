@@ -0,0 +1,34 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+};
+
+use gitdot_api::endpoint::secret::list_organization_secrets as api;
+use gitdot_core::dto::{CiSecretScope, OrganizationAuthorizationRequest};
+
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    dto::IntoApi,
+    extract::{Principal, User},
+};
+
+#[axum::debug_handler]
+pub async fn list_organization_secrets(
+    auth_user: Principal<User>,
+    State(state): State<AppState>,
+    Path(org_name): Path<String>,
+) -> Result<AppResponse<api::ListOrganizationSecretsResponse>, AppError> {
+    let auth_request = OrganizationAuthorizationRequest::new(auth_user.id, &org_name)?;
+    state
+        .authorization_service
+        .verify_authorized_for_organization(auth_request)
+        .await?;
+
+    let scope = CiSecretScope::organization(&org_name)?;
+    state
+        .ci_secret_service
+        .list_secrets(scope)
+        .await
+        .map_err(AppError::from)
+        .map(|secrets| AppResponse::new(StatusCode::OK, secrets.into_api()))
+}
@@ -0,0 +1,37 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+
+use gitdot_api::endpoint::secret::set_organization_secret as api;
+use gitdot_core::dto::{CiSecretScope, OrganizationAuthorizationRequest, SetCiSecretRequest};
+
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    dto::IntoApi,
+    extract::{Principal, User},
+};
+
+#[axum::debug_handler]
+pub async fn set_organization_secret(
+    auth_user: Principal<User>,
+    State(state): State<AppState>,
+    Path((org_name, name)): Path<(String, String)>,
+    Json(request): Json<api::SetOrganizationSecretRequest>,
+) -> Result<AppResponse<api::SetOrganizationSecretResponse>, AppError> {
+    let auth_request = OrganizationAuthorizationRequest::new(auth_user.id, &org_name)?;
+    state
+        .authorization_service
+        .verify_authorized_for_organization(auth_request)
+        .await?;
+
+    let scope = CiSecretScope::organization(&org_name)?;
+    let request = SetCiSecretRequest::new(scope, &name, request.value)?;
+    state
+        .ci_secret_service
+        .set_secret(request)
+        .await
+        .map_err(AppError::from)
+        .map(|secret| AppResponse::new(StatusCode::OK, secret.into_api()))
+}
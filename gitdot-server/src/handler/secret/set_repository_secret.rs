@@ -0,0 +1,44 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+
+use gitdot_api::endpoint::secret::set_repository_secret as api;
+use gitdot_core::dto::{
+    CiSecretScope, RepositoryAuthorizationRequest, RepositoryPermission, SetCiSecretRequest,
+};
+
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    dto::IntoApi,
+    extract::{Principal, User},
+};
+
+#[axum::debug_handler]
+pub async fn set_repository_secret(
+    auth_user: Principal<User>,
+    State(state): State<AppState>,
+    Path((owner, repo, name)): Path<(String, String, String)>,
+    Json(request): Json<api::SetRepositorySecretRequest>,
+) -> Result<AppResponse<api::SetRepositorySecretResponse>, AppError> {
+    let auth_request = RepositoryAuthorizationRequest::new(
+        Some(auth_user.id),
+        &owner,
+        &repo,
+        RepositoryPermission::Admin,
+    )?;
+    state
+        .authorization_service
+        .verify_authorized_for_repository(auth_request)
+        .await?;
+
+    let scope = CiSecretScope::repository(&owner, &repo)?;
+    let request = SetCiSecretRequest::new(scope, &name, request.value)?;
+    state
+        .ci_secret_service
+        .set_secret(request)
+        .await
+        .map_err(AppError::from)
+        .map(|secret| AppResponse::new(StatusCode::OK, secret.into_api()))
+}
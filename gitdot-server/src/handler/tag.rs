@@ -0,0 +1,31 @@
+mod create_tag;
+mod delete_tag;
+mod get_tag;
+mod list_tags;
+
+use axum::{Router, routing::get};
+
+use gitdot_core::model::TokenScope;
+
+use crate::{app::AppState, extract::TokenScopePolicy};
+
+use create_tag::create_tag;
+use delete_tag::delete_tag;
+use get_tag::get_tag;
+use list_tags::list_tags;
+
+pub fn create_tag_router() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/repository/{owner}/{repo}/tags",
+            get(list_tags).post(create_tag),
+        )
+        .route(
+            "/repository/{owner}/{repo}/tags/{tag}",
+            get(get_tag).delete(delete_tag),
+        )
+        .layer(TokenScopePolicy::new(
+            TokenScope::RepoRead,
+            TokenScope::RepoWrite,
+        ))
+}
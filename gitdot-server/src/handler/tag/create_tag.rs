@@ -0,0 +1,82 @@
+use axum::extract::{Json, Path, State};
+use http::StatusCode;
+
+use gitdot_api::endpoint::tag::create_tag as api;
+use gitdot_core::{
+    dto::{
+        CreateBuildRequest, CreateTagRequest, PublishTagEventRequest,
+        RepositoryAuthorizationRequest, RepositoryPermission,
+    },
+    util::git::{TAG_PREFIX, ZERO_SHA},
+};
+
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    dto::IntoApi,
+    extract::{Principal, User},
+};
+
+/// Creates a tag and reports it the way a pushed tag would be: a tag event for
+/// webhooks and a build for any tag-triggered CI.
+#[axum::debug_handler]
+pub async fn create_tag(
+    auth_user: Principal<User>,
+    State(state): State<AppState>,
+    Path((owner, repo)): Path<(String, String)>,
+    Json(request): Json<api::CreateTagRequest>,
+) -> Result<AppResponse<api::CreateTagResponse>, AppError> {
+    let auth_request = RepositoryAuthorizationRequest::new(
+        Some(auth_user.id),
+        &owner,
+        &repo,
+        RepositoryPermission::Write,
+    )?;
+    state
+        .authorization_service
+        .verify_authorized_for_repository(auth_request)
+        .await?;
+
+    let request = CreateTagRequest::new(
+        &repo,
+        &owner,
+        &request.name,
+        &request.target,
+        request.message,
+        auth_user.id,
+    )?;
+    let tag = state
+        .tag_service
+        .create_tag(request)
+        .await
+        .map_err(AppError::from)?;
+
+    let ref_name = format!("{TAG_PREFIX}{}", tag.name);
+    let event_request = PublishTagEventRequest::new(
+        &owner,
+        &repo,
+        &tag.name,
+        ZERO_SHA.to_string(),
+        tag.sha.clone(),
+        auth_user.id,
+    )?;
+    let build_request = CreateBuildRequest::for_push(
+        &owner,
+        &repo,
+        ref_name,
+        ZERO_SHA.to_string(),
+        tag.commit.sha.clone(),
+    )?;
+    tokio::spawn(async move {
+        if let Err(e) = state.event_service.publish_tag_event(event_request).await {
+            tracing::error!("Failed to publish tag event: {e}");
+        }
+
+        if let Some(build_request) = build_request
+            && let Err(e) = state.build_service.trigger_build(build_request).await
+        {
+            tracing::error!("Failed to trigger build for created tag: {e}");
+        }
+    });
+
+    Ok(AppResponse::new(StatusCode::CREATED, tag.into_api()))
+}
@@ -0,0 +1,56 @@
+use axum::extract::{Path, State};
+use http::StatusCode;
+
+use gitdot_core::{
+    dto::{
+        DeleteTagRequest, PublishTagEventRequest, RepositoryAuthorizationRequest,
+        RepositoryPermission,
+    },
+    util::git::ZERO_SHA,
+};
+
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    extract::{Principal, User},
+};
+
+#[axum::debug_handler]
+pub async fn delete_tag(
+    auth_user: Principal<User>,
+    State(state): State<AppState>,
+    Path((owner, repo, tag)): Path<(String, String, String)>,
+) -> Result<AppResponse<()>, AppError> {
+    let auth_request = RepositoryAuthorizationRequest::new(
+        Some(auth_user.id),
+        &owner,
+        &repo,
+        RepositoryPermission::Write,
+    )?;
+    state
+        .authorization_service
+        .verify_authorized_for_repository(auth_request)
+        .await?;
+
+    let request = DeleteTagRequest::new(&repo, &owner, &tag)?;
+    let deleted = state
+        .tag_service
+        .delete_tag(request)
+        .await
+        .map_err(AppError::from)?;
+
+    let event_request = PublishTagEventRequest::new(
+        &owner,
+        &repo,
+        &deleted.name,
+        deleted.sha,
+        ZERO_SHA.to_string(),
+        auth_user.id,
+    )?;
+    tokio::spawn(async move {
+        if let Err(e) = state.event_service.publish_tag_event(event_request).await {
+            tracing::error!("Failed to publish tag event: {e}");
+        }
+    });
+
+    Ok(AppResponse::new(StatusCode::NO_CONTENT, ()))
+}
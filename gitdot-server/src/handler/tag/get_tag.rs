@@ -0,0 +1,37 @@
+use axum::extract::{Path, State};
+use http::StatusCode;
+
+use gitdot_api::endpoint::tag::get_tag as api;
+use gitdot_core::dto::{GetTagRequest, RepositoryAuthorizationRequest, RepositoryPermission};
+
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    dto::IntoApi,
+    extract::{Principal, User},
+};
+
+#[axum::debug_handler]
+pub async fn get_tag(
+    auth_user: Option<Principal<User>>,
+    State(state): State<AppState>,
+    Path((owner, repo, tag)): Path<(String, String, String)>,
+) -> Result<AppResponse<api::GetTagResponse>, AppError> {
+    let auth_request = RepositoryAuthorizationRequest::new(
+        auth_user.map(|u| u.id),
+        &owner,
+        &repo,
+        RepositoryPermission::Read,
+    )?;
+    state
+        .authorization_service
+        .verify_authorized_for_repository(auth_request)
+        .await?;
+
+    let request = GetTagRequest::new(&repo, &owner, &tag)?;
+    state
+        .tag_service
+        .get_tag(request)
+        .await
+        .map_err(AppError::from)
+        .map(|tag| AppResponse::new(StatusCode::OK, tag.into_api()))
+}
@@ -1,21 +1,57 @@
+mod download_task_artifact;
+mod download_task_cache;
+mod heartbeat_task;
 mod issue_task_token;
+mod list_task_artifacts;
 mod poll_task;
 mod update_task;
+mod upload_task_artifact;
+mod upload_task_cache;
 
 use axum::{
     Router,
+    extract::DefaultBodyLimit,
     routing::{get, patch, post},
 };
 
-use crate::app::AppState;
+use gitdot_core::model::TokenScope;
 
+use crate::{app::AppState, extract::TokenScopePolicy};
+
+use download_task_artifact::download_task_artifact;
+use download_task_cache::download_task_cache;
+use heartbeat_task::heartbeat_task;
 use issue_task_token::issue_task_token;
+use list_task_artifacts::list_task_artifacts;
 use poll_task::poll_task;
 use update_task::update_task;
+use upload_task_artifact::upload_task_artifact;
+use upload_task_cache::upload_task_cache;
+
+const MAX_ARCHIVE_SIZE: usize = 512 * 1024 * 1024;
 
 pub fn create_task_router() -> Router<AppState> {
     Router::new()
         .route("/task/poll", get(poll_task))
         .route("/task/{id}", patch(update_task))
-        .route("/task/{id}/token", post(issue_task_token))
+        .route(
+            "/task/{id}/token",
+            post(issue_task_token).layer(TokenScopePolicy::only(TokenScope::RepoRead)),
+        )
+        .route("/task/{id}/heartbeat", post(heartbeat_task))
+        .route(
+            "/task/{id}/artifact",
+            post(upload_task_artifact).layer(DefaultBodyLimit::max(MAX_ARCHIVE_SIZE)),
+        )
+        .route("/task/{id}/artifacts", get(list_task_artifacts))
+        .route(
+            "/task/{id}/artifact/{artifact_id}",
+            get(download_task_artifact),
+        )
+        .route(
+            "/task/{id}/cache",
+            get(download_task_cache)
+                .post(upload_task_cache)
+                .layer(DefaultBodyLimit::max(MAX_ARCHIVE_SIZE)),
+        )
 }
@@ -0,0 +1,28 @@
+use axum::extract::{Path, State};
+use uuid::Uuid;
+
+use gitdot_core::error::AuthorizationError;
+
+use crate::{
+    app::{AppError, AppState},
+    dto::ArtifactServerResponse,
+    extract::{Principal, TaskJwt},
+};
+
+#[axum::debug_handler]
+pub async fn download_task_artifact(
+    State(state): State<AppState>,
+    auth_task: Principal<TaskJwt>,
+    Path((id, artifact_id)): Path<(Uuid, Uuid)>,
+) -> Result<ArtifactServerResponse, AppError> {
+    if auth_task.id != id {
+        return Err(AuthorizationError::Unauthorized.into());
+    }
+
+    state
+        .artifact_service
+        .download_task_artifact(id, artifact_id)
+        .await
+        .map_err(AppError::from)
+        .map(Into::into)
+}
@@ -0,0 +1,31 @@
+use axum::{
+    extract::{Path, State},
+    http::{StatusCode, header},
+    response::{IntoResponse, Response},
+};
+use uuid::Uuid;
+
+use gitdot_core::error::AuthorizationError;
+
+use crate::{
+    app::{AppError, AppState},
+    extract::{Principal, TaskJwt},
+};
+
+#[axum::debug_handler]
+pub async fn download_task_cache(
+    State(state): State<AppState>,
+    auth_task: Principal<TaskJwt>,
+    Path(id): Path<Uuid>,
+) -> Result<Response, AppError> {
+    if auth_task.id != id {
+        return Err(AuthorizationError::Unauthorized.into());
+    }
+
+    let cache = state.artifact_service.download_cache(id).await?;
+
+    Ok(match cache {
+        Some(bytes) => ([(header::CONTENT_TYPE, "application/gzip")], bytes).into_response(),
+        None => StatusCode::NO_CONTENT.into_response(),
+    })
+}
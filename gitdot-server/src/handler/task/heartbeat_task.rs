@@ -0,0 +1,33 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+};
+use uuid::Uuid;
+
+use gitdot_api::endpoint::task::heartbeat_task as api;
+use gitdot_core::error::AuthorizationError;
+
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    dto::IntoApi,
+    extract::{Principal, TaskJwt},
+};
+
+#[axum::debug_handler]
+pub async fn heartbeat_task(
+    State(state): State<AppState>,
+    auth_task: Principal<TaskJwt>,
+    Path(id): Path<Uuid>,
+) -> Result<AppResponse<api::HeartbeatTaskResponse>, AppError> {
+    // a task token only heartbeats the task it was issued for
+    if auth_task.id != id {
+        return Err(AuthorizationError::Unauthorized.into());
+    }
+
+    state
+        .task_service
+        .heartbeat_task(id)
+        .await
+        .map_err(AppError::from)
+        .map(|task| AppResponse::new(StatusCode::OK, task.into_api()))
+}
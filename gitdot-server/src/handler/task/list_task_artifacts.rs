@@ -0,0 +1,32 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+};
+use uuid::Uuid;
+
+use gitdot_api::endpoint::task::list_task_artifacts as api;
+use gitdot_core::error::AuthorizationError;
+
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    dto::IntoApi,
+    extract::{Principal, TaskJwt},
+};
+
+#[axum::debug_handler]
+pub async fn list_task_artifacts(
+    State(state): State<AppState>,
+    auth_task: Principal<TaskJwt>,
+    Path(id): Path<Uuid>,
+) -> Result<AppResponse<api::ListTaskArtifactsResponse>, AppError> {
+    if auth_task.id != id {
+        return Err(AuthorizationError::Unauthorized.into());
+    }
+
+    state
+        .artifact_service
+        .list_task_artifacts(id)
+        .await
+        .map_err(AppError::from)
+        .map(|artifacts| AppResponse::new(StatusCode::OK, artifacts.into_api()))
+}
@@ -28,11 +28,26 @@ pub async fn poll_task(
         .await
         .map_err(AppError::from)?;
 
+    // the token has to outlive every attempt the runner may make
+    let attempts = task.options.retries.max(0) as u64 + 1;
+    let token_secs = task
+        .options
+        .timeout_seconds
+        .map(|secs| secs.max(0) as u64 * attempts + 600)
+        .unwrap_or(0)
+        .max(3600);
+
+    let secrets = state
+        .ci_secret_service
+        .get_task_secrets(task.id)
+        .await
+        .map_err(AppError::from)?;
+
     let jwt = state
         .token_service
         .issue_task_token(IssueTaskJwtRequest {
             task_id: task.id,
-            duration: std::time::Duration::from_secs(3600),
+            duration: std::time::Duration::from_secs(token_secs),
         })
         .await
         .map_err(AppError::from)?;
@@ -48,6 +63,18 @@ pub async fn poll_task(
             name: task.name,
             command: task.command,
             status: task.status.into(),
+            env: task.options.env,
+            timeout_seconds: task.options.timeout_seconds,
+            retries: task.options.retries,
+            working_directory: task.options.working_directory,
+            allow_failure: task.options.allow_failure,
+            artifacts: task.options.artifacts,
+            cache_key: task.options.cache_key,
+            cache_paths: task.options.cache_paths,
+            image: task.options.image,
+            network: task.options.network.into(),
+            secrets: secrets.secrets,
+            secrets_withheld: secrets.withheld,
         }),
     ))
 }
@@ -0,0 +1,35 @@
+use axum::{
+    body::Bytes,
+    extract::{Path, Query, State},
+    http::StatusCode,
+};
+use uuid::Uuid;
+
+use gitdot_api::endpoint::task::upload_task_artifact as api;
+use gitdot_core::{dto::UploadArtifactRequest, error::AuthorizationError};
+
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    dto::IntoApi,
+    extract::{Principal, TaskJwt},
+};
+
+#[axum::debug_handler]
+pub async fn upload_task_artifact(
+    State(state): State<AppState>,
+    auth_task: Principal<TaskJwt>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<api::UploadTaskArtifactRequest>,
+    body: Bytes,
+) -> Result<AppResponse<api::UploadTaskArtifactResponse>, AppError> {
+    if auth_task.id != id {
+        return Err(AuthorizationError::Unauthorized.into());
+    }
+
+    state
+        .artifact_service
+        .upload_artifact(UploadArtifactRequest::new(id, &query.name, body))
+        .await
+        .map_err(AppError::from)
+        .map(|artifact| AppResponse::new(StatusCode::CREATED, artifact.into_api()))
+}
@@ -0,0 +1,32 @@
+use axum::{
+    body::Bytes,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use uuid::Uuid;
+
+use gitdot_core::{dto::UploadCacheRequest, error::AuthorizationError};
+
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    extract::{Principal, TaskJwt},
+};
+
+#[axum::debug_handler]
+pub async fn upload_task_cache(
+    State(state): State<AppState>,
+    auth_task: Principal<TaskJwt>,
+    Path(id): Path<Uuid>,
+    body: Bytes,
+) -> Result<AppResponse<()>, AppError> {
+    if auth_task.id != id {
+        return Err(AuthorizationError::Unauthorized.into());
+    }
+
+    state
+        .artifact_service
+        .upload_cache(UploadCacheRequest::new(id, body))
+        .await?;
+
+    Ok(AppResponse::new(StatusCode::NO_CONTENT, ()))
+}
@@ -0,0 +1,52 @@
+mod add_team_member;
+mod create_team;
+mod delete_team;
+mod get_team;
+mod list_teams;
+mod remove_team_member;
+mod remove_team_repository;
+mod set_team_repository;
+
+use axum::{
+    Router,
+    routing::{delete, get, post, put},
+};
+
+use gitdot_core::model::TokenScope;
+
+use crate::{app::AppState, extract::TokenScopePolicy};
+
+use add_team_member::add_team_member;
+use create_team::create_team;
+use delete_team::delete_team;
+use get_team::get_team;
+use list_teams::list_teams;
+use remove_team_member::remove_team_member;
+use remove_team_repository::remove_team_repository;
+use set_team_repository::set_team_repository;
+
+pub fn create_team_router() -> Router<AppState> {
+    Router::new()
+        .route("/organization/{org_name}/team", post(create_team))
+        .route("/organization/{org_name}/teams", get(list_teams))
+        .route(
+            "/organization/{org_name}/team/{team_name}",
+            get(get_team).delete(delete_team),
+        )
+        .route(
+            "/organization/{org_name}/team/{team_name}/member",
+            post(add_team_member),
+        )
+        .route(
+            "/organization/{org_name}/team/{team_name}/member/{user_name}",
+            delete(remove_team_member),
+        )
+        .route(
+            "/organization/{org_name}/team/{team_name}/repository/{repo}",
+            put(set_team_repository).delete(remove_team_repository),
+        )
+        .layer(TokenScopePolicy::new(
+            TokenScope::RepoRead,
+            TokenScope::AdminOrg,
+        ))
+}
@@ -0,0 +1,36 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+
+use gitdot_api::endpoint::add_team_member as api;
+use gitdot_core::dto::{AddTeamMemberRequest, OrganizationAuthorizationRequest};
+
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    dto::IntoApi,
+    extract::{Principal, User},
+};
+
+#[axum::debug_handler]
+pub async fn add_team_member(
+    auth_user: Principal<User>,
+    State(state): State<AppState>,
+    Path((org_name, team_name)): Path<(String, String)>,
+    Json(request): Json<api::AddTeamMemberRequest>,
+) -> Result<AppResponse<api::AddTeamMemberResponse>, AppError> {
+    let auth_request = OrganizationAuthorizationRequest::new(auth_user.id, &org_name)?;
+    state
+        .authorization_service
+        .verify_authorized_for_organization(auth_request)
+        .await?;
+
+    let request = AddTeamMemberRequest::new(&org_name, &team_name, &request.user_name)?;
+    state
+        .team_service
+        .add_team_member(request)
+        .await
+        .map_err(AppError::from)
+        .map(|member| AppResponse::new(StatusCode::CREATED, member.into_api()))
+}
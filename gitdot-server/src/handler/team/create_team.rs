@@ -0,0 +1,36 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+
+use gitdot_api::endpoint::create_team as api;
+use gitdot_core::dto::{CreateTeamRequest, OrganizationAuthorizationRequest};
+
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    dto::IntoApi,
+    extract::{Principal, User},
+};
+
+#[axum::debug_handler]
+pub async fn create_team(
+    auth_user: Principal<User>,
+    State(state): State<AppState>,
+    Path(org_name): Path<String>,
+    Json(request): Json<api::CreateTeamRequest>,
+) -> Result<AppResponse<api::CreateTeamResponse>, AppError> {
+    let auth_request = OrganizationAuthorizationRequest::new(auth_user.id, &org_name)?;
+    state
+        .authorization_service
+        .verify_authorized_for_organization(auth_request)
+        .await?;
+
+    let request = CreateTeamRequest::new(&org_name, &request.name, request.description)?;
+    state
+        .team_service
+        .create_team(request)
+        .await
+        .map_err(AppError::from)
+        .map(|team| AppResponse::new(StatusCode::CREATED, team.into_api()))
+}
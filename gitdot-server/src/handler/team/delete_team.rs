@@ -0,0 +1,28 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+};
+
+use gitdot_core::dto::{DeleteTeamRequest, OrganizationAuthorizationRequest};
+
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    extract::{Principal, User},
+};
+
+#[axum::debug_handler]
+pub async fn delete_team(
+    auth_user: Principal<User>,
+    State(state): State<AppState>,
+    Path((org_name, team_name)): Path<(String, String)>,
+) -> Result<AppResponse<()>, AppError> {
+    let auth_request = OrganizationAuthorizationRequest::new(auth_user.id, &org_name)?;
+    state
+        .authorization_service
+        .verify_authorized_for_organization(auth_request)
+        .await?;
+
+    let request = DeleteTeamRequest::new(&org_name, &team_name)?;
+    state.team_service.delete_team(request).await?;
+    Ok(AppResponse::new(StatusCode::NO_CONTENT, ()))
+}
@@ -0,0 +1,34 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+};
+
+use gitdot_api::endpoint::get_team as api;
+use gitdot_core::dto::{GetTeamRequest, OrganizationAuthorizationRequest};
+
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    dto::IntoApi,
+    extract::{Principal, User},
+};
+
+#[axum::debug_handler]
+pub async fn get_team(
+    auth_user: Principal<User>,
+    State(state): State<AppState>,
+    Path((org_name, team_name)): Path<(String, String)>,
+) -> Result<AppResponse<api::GetTeamResponse>, AppError> {
+    let auth_request = OrganizationAuthorizationRequest::new(auth_user.id, &org_name)?;
+    state
+        .authorization_service
+        .verify_authorized_for_organization(auth_request)
+        .await?;
+
+    let request = GetTeamRequest::new(&org_name, &team_name)?;
+    state
+        .team_service
+        .get_team(request)
+        .await
+        .map_err(AppError::from)
+        .map(|team| AppResponse::new(StatusCode::OK, team.into_api()))
+}
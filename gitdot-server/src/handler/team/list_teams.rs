@@ -0,0 +1,34 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+};
+
+use gitdot_api::endpoint::list_teams as api;
+use gitdot_core::dto::{ListTeamsRequest, OrganizationAuthorizationRequest};
+
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    dto::IntoApi,
+    extract::{Principal, User},
+};
+
+#[axum::debug_handler]
+pub async fn list_teams(
+    auth_user: Principal<User>,
+    State(state): State<AppState>,
+    Path(org_name): Path<String>,
+) -> Result<AppResponse<api::ListTeamsResponse>, AppError> {
+    let auth_request = OrganizationAuthorizationRequest::new(auth_user.id, &org_name)?;
+    state
+        .authorization_service
+        .verify_authorized_for_organization(auth_request)
+        .await?;
+
+    let request = ListTeamsRequest::new(&org_name)?;
+    state
+        .team_service
+        .list_teams(request)
+        .await
+        .map_err(AppError::from)
+        .map(|teams| AppResponse::new(StatusCode::OK, teams.into_api()))
+}
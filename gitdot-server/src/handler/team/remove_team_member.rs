@@ -0,0 +1,28 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+};
+
+use gitdot_core::dto::{OrganizationAuthorizationRequest, RemoveTeamMemberRequest};
+
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    extract::{Principal, User},
+};
+
+#[axum::debug_handler]
+pub async fn remove_team_member(
+    auth_user: Principal<User>,
+    State(state): State<AppState>,
+    Path((org_name, team_name, user_name)): Path<(String, String, String)>,
+) -> Result<AppResponse<()>, AppError> {
+    let auth_request = OrganizationAuthorizationRequest::new(auth_user.id, &org_name)?;
+    state
+        .authorization_service
+        .verify_authorized_for_organization(auth_request)
+        .await?;
+
+    let request = RemoveTeamMemberRequest::new(&org_name, &team_name, &user_name)?;
+    state.team_service.remove_team_member(request).await?;
+    Ok(AppResponse::new(StatusCode::NO_CONTENT, ()))
+}
@@ -0,0 +1,36 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+
+use gitdot_api::endpoint::set_team_repository as api;
+use gitdot_core::dto::{OrganizationAuthorizationRequest, SetTeamRepositoryRequest};
+
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    dto::IntoApi,
+    extract::{Principal, User},
+};
+
+#[axum::debug_handler]
+pub async fn set_team_repository(
+    auth_user: Principal<User>,
+    State(state): State<AppState>,
+    Path((org_name, team_name, repo)): Path<(String, String, String)>,
+    Json(request): Json<api::SetTeamRepositoryRequest>,
+) -> Result<AppResponse<api::SetTeamRepositoryResponse>, AppError> {
+    let auth_request = OrganizationAuthorizationRequest::new(auth_user.id, &org_name)?;
+    state
+        .authorization_service
+        .verify_authorized_for_organization(auth_request)
+        .await?;
+
+    let request = SetTeamRepositoryRequest::new(&org_name, &team_name, &repo, &request.role)?;
+    state
+        .team_service
+        .set_team_repository(request)
+        .await
+        .map_err(AppError::from)
+        .map(|access| AppResponse::new(StatusCode::OK, access.into_api()))
+}
@@ -1,32 +1,52 @@
+mod add_signing_key;
+mod add_ssh_key;
+mod create_personal_token;
+mod delete_signing_key;
+mod delete_ssh_key;
 mod get_current_user;
 mod get_user;
 mod has_user;
+mod list_personal_tokens;
+mod list_signing_keys;
+mod list_ssh_keys;
 mod list_user_commits;
 mod list_user_organizations;
 mod list_user_repositories;
 mod list_user_repositories_contributed;
 mod list_user_repositories_starred;
 mod list_user_reviews;
+mod revoke_personal_token;
 mod update_current_user;
 mod upload_user_image;
 
 use axum::{
     Router,
     extract::DefaultBodyLimit,
-    routing::{get, post},
+    routing::{delete, get, post},
 };
 
-use crate::app::AppState;
+use gitdot_core::model::TokenScope;
 
+use crate::{app::AppState, extract::TokenScopePolicy};
+
+use add_signing_key::add_signing_key;
+use add_ssh_key::add_ssh_key;
+use create_personal_token::create_personal_token;
+use delete_signing_key::delete_signing_key;
+use delete_ssh_key::delete_ssh_key;
 use get_current_user::get_current_user;
 use get_user::get_user;
 use has_user::has_user;
+use list_personal_tokens::list_personal_tokens;
+use list_signing_keys::list_signing_keys;
+use list_ssh_keys::list_ssh_keys;
 use list_user_commits::list_user_commits;
 use list_user_organizations::list_user_organizations;
 use list_user_repositories::list_user_repositories;
 use list_user_repositories_contributed::list_user_contributed_repositories;
 use list_user_repositories_starred::list_user_starred_repositories;
 use list_user_reviews::list_user_reviews;
+use revoke_personal_token::revoke_personal_token;
 use update_current_user::update_current_user;
 use upload_user_image::upload_user_image;
 
@@ -37,6 +57,15 @@ pub fn create_user_router() -> Router<AppState> {
             "/user/image",
             post(upload_user_image).layer(DefaultBodyLimit::max(5 * 1024 * 1024)),
         )
+        .route("/user/token", post(create_personal_token))
+        .route("/user/tokens", get(list_personal_tokens))
+        .route("/user/token/{token_id}", delete(revoke_personal_token))
+        .route("/user/ssh-key", post(add_ssh_key))
+        .route("/user/ssh-keys", get(list_ssh_keys))
+        .route("/user/ssh-key/{key_id}", delete(delete_ssh_key))
+        .route("/user/signing-key", post(add_signing_key))
+        .route("/user/signing-keys", get(list_signing_keys))
+        .route("/user/signing-key/{key_id}", delete(delete_signing_key))
         .route("/user/{user_name}", get(get_user).head(has_user))
         .route(
             "/user/{user_name}/repositories",
@@ -56,4 +85,5 @@ pub fn create_user_router() -> Router<AppState> {
             "/user/{user_name}/repositories-contributed",
             get(list_user_contributed_repositories),
         )
+        .layer(TokenScopePolicy::read_only(TokenScope::RepoRead))
 }
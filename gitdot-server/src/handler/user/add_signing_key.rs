@@ -0,0 +1,32 @@
+use axum::{Json, extract::State, http::StatusCode};
+
+use gitdot_api::endpoint::add_signing_key as api;
+use gitdot_core::dto::AddSigningKeyRequest;
+
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    dto::IntoApi,
+    extract::{Principal, UserJwt},
+};
+
+/// Only a web session can add keys, so a leaked token cannot be used to get
+/// forged commits shown as verified.
+#[axum::debug_handler]
+pub async fn add_signing_key(
+    auth_user: Principal<UserJwt>,
+    State(state): State<AppState>,
+    Json(request): Json<api::AddSigningKeyRequest>,
+) -> Result<AppResponse<api::AddSigningKeyResponse>, AppError> {
+    let request = AddSigningKeyRequest::new(
+        auth_user.id,
+        request.name.as_deref(),
+        &request.public_key,
+        request.signature.as_deref(),
+    )?;
+    state
+        .signing_key_service
+        .add_signing_key(request)
+        .await
+        .map_err(AppError::from)
+        .map(|key| AppResponse::new(StatusCode::CREATED, key.into_api()))
+}
@@ -0,0 +1,28 @@
+use axum::{Json, extract::State, http::StatusCode};
+
+use gitdot_api::endpoint::add_ssh_key as api;
+use gitdot_core::dto::AddSshKeyRequest;
+
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    dto::IntoApi,
+    extract::{Principal, UserJwt},
+};
+
+/// Only a web session can add keys, so a leaked token cannot be turned into
+/// lasting SSH access.
+#[axum::debug_handler]
+pub async fn add_ssh_key(
+    auth_user: Principal<UserJwt>,
+    State(state): State<AppState>,
+    Json(request): Json<api::AddSshKeyRequest>,
+) -> Result<AppResponse<api::AddSshKeyResponse>, AppError> {
+    let request =
+        AddSshKeyRequest::new(auth_user.id, request.name.as_deref(), &request.public_key)?;
+    state
+        .ssh_key_service
+        .add_ssh_key(request)
+        .await
+        .map_err(AppError::from)
+        .map(|key| AppResponse::new(StatusCode::CREATED, key.into_api()))
+}
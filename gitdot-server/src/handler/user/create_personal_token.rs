@@ -0,0 +1,32 @@
+use axum::{Json, extract::State, http::StatusCode};
+
+use gitdot_api::endpoint::create_personal_token as api;
+use gitdot_core::dto::CreatePersonalTokenRequest;
+
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    dto::IntoApi,
+    extract::{Principal, UserJwt},
+};
+
+/// Only a web session can manage tokens, so a token cannot mint itself a
+/// broader one.
+#[axum::debug_handler]
+pub async fn create_personal_token(
+    auth_user: Principal<UserJwt>,
+    State(state): State<AppState>,
+    Json(request): Json<api::CreatePersonalTokenRequest>,
+) -> Result<AppResponse<api::CreatePersonalTokenResponse>, AppError> {
+    let request = CreatePersonalTokenRequest::new(
+        auth_user.id,
+        &request.name,
+        request.scopes,
+        request.expires_at,
+    )?;
+    state
+        .token_service
+        .create_personal_token(request)
+        .await
+        .map_err(AppError::from)
+        .map(|token| AppResponse::new(StatusCode::CREATED, token.into_api()))
+}
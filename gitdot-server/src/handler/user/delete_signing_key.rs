@@ -0,0 +1,26 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+};
+use uuid::Uuid;
+
+use gitdot_core::dto::DeleteSigningKeyRequest;
+
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    extract::{Principal, UserJwt},
+};
+
+#[axum::debug_handler]
+pub async fn delete_signing_key(
+    auth_user: Principal<UserJwt>,
+    State(state): State<AppState>,
+    Path(key_id): Path<Uuid>,
+) -> Result<AppResponse<()>, AppError> {
+    let request = DeleteSigningKeyRequest::new(auth_user.id, key_id);
+    state
+        .signing_key_service
+        .delete_signing_key(request)
+        .await?;
+    Ok(AppResponse::new(StatusCode::NO_CONTENT, ()))
+}
@@ -0,0 +1,23 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+};
+use uuid::Uuid;
+
+use gitdot_core::dto::DeleteSshKeyRequest;
+
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    extract::{Principal, UserJwt},
+};
+
+#[axum::debug_handler]
+pub async fn delete_ssh_key(
+    auth_user: Principal<UserJwt>,
+    State(state): State<AppState>,
+    Path(key_id): Path<Uuid>,
+) -> Result<AppResponse<()>, AppError> {
+    let request = DeleteSshKeyRequest::new(auth_user.id, key_id);
+    state.ssh_key_service.delete_ssh_key(request).await?;
+    Ok(AppResponse::new(StatusCode::NO_CONTENT, ()))
+}
@@ -0,0 +1,22 @@
+use axum::{extract::State, http::StatusCode};
+
+use gitdot_api::endpoint::list_personal_tokens as api;
+
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    dto::IntoApi,
+    extract::{Principal, UserJwt},
+};
+
+#[axum::debug_handler]
+pub async fn list_personal_tokens(
+    auth_user: Principal<UserJwt>,
+    State(state): State<AppState>,
+) -> Result<AppResponse<api::ListPersonalTokensResponse>, AppError> {
+    state
+        .token_service
+        .list_personal_tokens(auth_user.id)
+        .await
+        .map_err(AppError::from)
+        .map(|tokens| AppResponse::new(StatusCode::OK, tokens.into_api()))
+}
@@ -0,0 +1,22 @@
+use axum::{extract::State, http::StatusCode};
+
+use gitdot_api::endpoint::list_signing_keys as api;
+
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    dto::IntoApi,
+    extract::{Principal, UserJwt},
+};
+
+#[axum::debug_handler]
+pub async fn list_signing_keys(
+    auth_user: Principal<UserJwt>,
+    State(state): State<AppState>,
+) -> Result<AppResponse<api::ListSigningKeysResponse>, AppError> {
+    state
+        .signing_key_service
+        .list_signing_keys(auth_user.id)
+        .await
+        .map_err(AppError::from)
+        .map(|keys| AppResponse::new(StatusCode::OK, keys.into_api()))
+}
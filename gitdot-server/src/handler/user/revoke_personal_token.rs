@@ -0,0 +1,23 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+};
+use uuid::Uuid;
+
+use gitdot_core::dto::RevokePersonalTokenRequest;
+
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    extract::{Principal, UserJwt},
+};
+
+#[axum::debug_handler]
+pub async fn revoke_personal_token(
+    auth_user: Principal<UserJwt>,
+    State(state): State<AppState>,
+    Path(token_id): Path<Uuid>,
+) -> Result<AppResponse<()>, AppError> {
+    let request = RevokePersonalTokenRequest::new(auth_user.id, token_id);
+    state.token_service.revoke_personal_token(request).await?;
+    Ok(AppResponse::new(StatusCode::NO_CONTENT, ()))
+}
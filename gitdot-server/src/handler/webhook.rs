@@ -2,7 +2,9 @@ mod create_webhook;
 mod delete_webhook;
 mod get_webhook;
 mod github;
+mod list_webhook_deliveries;
 mod list_webhooks;
+mod redeliver_webhook_delivery;
 mod slack;
 mod update_webhook;
 
@@ -11,13 +13,17 @@ use axum::{
     routing::{get, post},
 };
 
-use crate::app::AppState;
+use gitdot_core::model::TokenScope;
+
+use crate::{app::AppState, extract::TokenScopePolicy};
 
 use create_webhook::create_webhook;
 use delete_webhook::delete_webhook;
 use get_webhook::get_webhook;
 use github::handle_events::handle_events;
+use list_webhook_deliveries::list_webhook_deliveries;
 use list_webhooks::list_webhooks;
+use redeliver_webhook_delivery::redeliver_webhook_delivery;
 use slack::{subscribe::subscribe_slack_webhook, unsubscribe::unsubscribe_slack_webhook};
 use update_webhook::update_webhook;
 
@@ -31,6 +37,14 @@ pub fn create_webhook_router() -> Router<AppState> {
                 .patch(update_webhook)
                 .delete(delete_webhook),
         )
+        .route(
+            "/repository/{owner}/{repo}/webhook/{webhook_id}/deliveries",
+            get(list_webhook_deliveries),
+        )
+        .route(
+            "/repository/{owner}/{repo}/webhook/{webhook_id}/delivery/{delivery_id}/redeliver",
+            post(redeliver_webhook_delivery),
+        )
         // internal routes intended for communication with Slack bot server
         .route(
             "/repository/{owner}/{repo}/webhook/slack",
@@ -43,4 +57,8 @@ pub fn create_webhook_router() -> Router<AppState> {
         // endpoint for GitHub webhook event handling
         // GitHub webhook does not support dynamic routing, so breaking the path pattern here
         .route("/github/events", post(handle_events))
+        .layer(TokenScopePolicy::new(
+            TokenScope::RepoRead,
+            TokenScope::RepoWrite,
+        ))
 }
@@ -2,12 +2,10 @@ use axum::extract::State;
 use http::StatusCode;
 use uuid::Uuid;
 
-use gitdot_core::dto::{
-    CreateCommitsRequest, ProcessGithubInstallationRequest, ProcessGithubPushRequest,
-};
+use gitdot_core::dto::{ProcessGithubInstallationRequest, ProcessGithubPushRequest};
 
 use crate::{
-    app::{AppError, AppResponse, AppState},
+    app::{AppError, AppResponse, AppState, create_mirror_commits},
     extract::{GithubEvent, GithubSigned},
 };
 
@@ -40,7 +38,7 @@ fn handle_push(state: AppState, delivery: Uuid, body: Vec<u8>) -> Result<(), App
     tokio::spawn(async move {
         let response = match state
             .github_webhook_service
-            .process_github_push(request.clone())
+            .process_github_push(request)
             .await
         {
             Ok(r) => r,
@@ -51,17 +49,7 @@ fn handle_push(state: AppState, delivery: Uuid, body: Vec<u8>) -> Result<(), App
         };
         // TODO: publish push events
         for info in response.synced_repositories {
-            if let Ok(req) = CreateCommitsRequest::new(
-                &info.owner_name,
-                &info.repo_name,
-                request.before.clone(),
-                info.head_sha,
-                request.ref_name.clone(),
-                None,
-                Default::default(),
-            ) {
-                let _ = state.commit_service.create_commits(req).await;
-            }
+            create_mirror_commits(state.commit_service.as_ref(), info).await;
         }
     });
     Ok(())
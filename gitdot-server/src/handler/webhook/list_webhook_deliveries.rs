@@ -0,0 +1,47 @@
+use axum::extract::{Path, Query, State};
+use http::StatusCode;
+use uuid::Uuid;
+
+use gitdot_api::endpoint::webhook::list_webhook_deliveries as api;
+use gitdot_core::dto::{
+    ListWebhookDeliveriesRequest, RepositoryAuthorizationRequest, RepositoryPermission,
+};
+
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    dto::IntoApi,
+    extract::{Principal, User},
+};
+
+#[axum::debug_handler]
+pub async fn list_webhook_deliveries(
+    auth_user: Principal<User>,
+    State(state): State<AppState>,
+    Path((owner, repo, webhook_id)): Path<(String, String, Uuid)>,
+    Query(query): Query<api::ListWebhookDeliveriesRequest>,
+) -> Result<AppResponse<api::ListWebhookDeliveriesResponse>, AppError> {
+    let auth_request = RepositoryAuthorizationRequest::new(
+        Some(auth_user.id),
+        &owner,
+        &repo,
+        RepositoryPermission::Admin,
+    )?;
+    state
+        .authorization_service
+        .verify_authorized_for_repository(auth_request)
+        .await?;
+
+    let request = ListWebhookDeliveriesRequest::new(
+        &owner,
+        &repo,
+        webhook_id,
+        query.cursor.as_deref(),
+        query.limit,
+    )?;
+    state
+        .webhook_service
+        .list_webhook_deliveries(request)
+        .await
+        .map_err(AppError::from)
+        .map(|page| AppResponse::new(StatusCode::OK, page.into_api()))
+}
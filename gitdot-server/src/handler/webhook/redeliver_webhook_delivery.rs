@@ -0,0 +1,40 @@
+use axum::extract::{Path, State};
+use http::StatusCode;
+use uuid::Uuid;
+
+use gitdot_api::endpoint::webhook::redeliver_webhook_delivery as api;
+use gitdot_core::dto::{
+    RedeliverWebhookDeliveryRequest, RepositoryAuthorizationRequest, RepositoryPermission,
+};
+
+use crate::{
+    app::{AppError, AppResponse, AppState},
+    dto::IntoApi,
+    extract::{Principal, User},
+};
+
+#[axum::debug_handler]
+pub async fn redeliver_webhook_delivery(
+    auth_user: Principal<User>,
+    State(state): State<AppState>,
+    Path((owner, repo, webhook_id, delivery_id)): Path<(String, String, Uuid, Uuid)>,
+) -> Result<AppResponse<api::RedeliverWebhookDeliveryResponse>, AppError> {
+    let auth_request = RepositoryAuthorizationRequest::new(
+        Some(auth_user.id),
+        &owner,
+        &repo,
+        RepositoryPermission::Admin,
+    )?;
+    state
+        .authorization_service
+        .verify_authorized_for_repository(auth_request)
+        .await?;
+
+    let request = RedeliverWebhookDeliveryRequest::new(&owner, &repo, webhook_id, delivery_id)?;
+    state
+        .webhook_service
+        .redeliver_webhook_delivery(request)
+        .await
+        .map_err(AppError::from)
+        .map(|d| AppResponse::new(StatusCode::CREATED, d.into_api()))
+}
@@ -1,5 +1,5 @@
 import type { BuildResource, RepositoryCommitResource } from "gitdot-api";
-import { Check, Loader2, X } from "lucide-react";
+import { Check, CircleSlash, Loader2, X } from "lucide-react";
 import Link from "@/ui/link";
 import { formatDuration, pluralize, timeAgo } from "@/util";
 
@@ -7,6 +7,7 @@ const STATUS_ICONS = {
   running: <Loader2 className="size-3.5 animate-spin text-muted-foreground" />,
   success: <Check className="size-3.5 text-green-600" />,
   failure: <X className="size-3.5 text-red-600" />,
+  cancelled: <CircleSlash className="size-3.5 text-muted-foreground" />,
 };
 
 export function BuildRow({
@@ -1,6 +1,10 @@
 "use client";
 
-import type { BuildResource, TaskResource } from "gitdot-api";
+import type {
+  ArtifactResource,
+  BuildResource,
+  TaskResource,
+} from "gitdot-api";
 import { type ResourceResultType, useResources } from "gitdot-dal/client";
 import { Suspense, use } from "react";
 import type { S2Record } from "@/lib/s2/shared";
@@ -15,6 +19,7 @@ export function PageClient({
   resources,
   build,
   tasks,
+  artifacts,
   tokens,
   taskLogs,
 }: {
@@ -23,6 +28,7 @@ export function PageClient({
   resources: ResourceResultType<Resources>;
   build: BuildResource;
   tasks: TaskResource[];
+  artifacts: ArtifactResource[];
   tokens: (string | null)[];
   taskLogs: S2Record[][];
 }) {
@@ -35,6 +41,7 @@ export function PageClient({
         build={build}
         commitPromise={resourcePromises.commit}
         tasks={tasks}
+        artifacts={artifacts}
         tokens={tokens}
         taskLogs={taskLogs}
       />
@@ -48,6 +55,7 @@ function PageContent({
   build,
   commitPromise,
   tasks,
+  artifacts,
   tokens,
   taskLogs,
 }: {
@@ -56,6 +64,7 @@ function PageContent({
   build: BuildResource;
   commitPromise: Promise<Resources["commit"]>;
   tasks: TaskResource[];
+  artifacts: ArtifactResource[];
   tokens: (string | null)[];
   taskLogs: S2Record[][];
 }) {
@@ -63,7 +72,14 @@ function PageContent({
 
   return (
     <div className="flex flex-col w-full flex-1 min-w-0 overflow-y-auto scrollbar-thin">
-      <BuildHeader build={build} commit={commit} tasks={tasks} />
+      <BuildHeader
+        owner={owner}
+        repo={repo}
+        build={build}
+        commit={commit}
+        tasks={tasks}
+        artifacts={artifacts}
+      />
       {tasks.map((task, i) => (
         <BuildTask
           key={task.id}
@@ -1,5 +1,10 @@
 import type { RepositoryCommitResource } from "gitdot-api";
-import { getBuild, getBuildTasks, issueTaskToken } from "gitdot-client";
+import {
+  getBuild,
+  getBuildArtifacts,
+  getBuildTasks,
+  issueTaskToken,
+} from "gitdot-client";
 import { fetchResources } from "gitdot-dal/server";
 import { getTaskLogs } from "@/lib/s2/server";
 import { PageClient } from "./page.client";
@@ -24,7 +29,10 @@ export default async function Page({
     commit: (p) => p.getCommit(owner, repo, build.commit_sha),
   });
 
-  const tasks = await getBuildTasks(owner, repo, number);
+  const [tasks, artifacts] = await Promise.all([
+    getBuildTasks(owner, repo, number),
+    getBuildArtifacts(owner, repo, number),
+  ]);
   if (!tasks) return null;
 
   const tokens = await Promise.all(
@@ -47,6 +55,7 @@ export default async function Page({
       resources={resources}
       build={build}
       tasks={tasks}
+      artifacts={artifacts ?? []}
       tokens={tokens}
       taskLogs={taskLogs}
     />
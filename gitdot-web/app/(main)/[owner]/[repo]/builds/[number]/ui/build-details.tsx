@@ -1,16 +1,29 @@
 "use client";
 
-import type { BuildResource, RepositoryCommitResource } from "gitdot-api";
+import type {
+  ArtifactResource,
+  BuildResource,
+  RepositoryCommitResource,
+  TaskResource,
+} from "gitdot-api";
 import { useTimezone } from "@/(main)/context/timezone";
-import { formatDateTime } from "@/util/date";
+import { formatBytes, formatDateTime } from "@/util";
 import { JobTimer } from "./job-timer";
 
 export function BuildDetails({
+  owner,
+  repo,
   build,
   commit,
+  tasks,
+  artifacts,
 }: {
+  owner: string;
+  repo: string;
   build: BuildResource;
   commit: RepositoryCommitResource | null;
+  tasks: TaskResource[];
+  artifacts: ArtifactResource[];
 }) {
   const tz = useTimezone();
   const createdAt = new Date(build.created_at);
@@ -19,7 +32,7 @@ export function BuildDetails({
 
   return (
     <div className="flex h-full w-1/4 flex-col border-l">
-      <div className="space-y-2 p-2">
+      <div className="space-y-2 overflow-y-auto p-2 scrollbar-thin">
         <div>
           <div className="text-xs text-muted-foreground">Commit</div>
           <div className="truncate text-sm">
@@ -46,7 +59,41 @@ export function BuildDetails({
             />
           </div>
         </div>
+        {artifacts.length > 0 && (
+          <div>
+            <div className="text-xs text-muted-foreground">Artifacts</div>
+            {artifacts.map((artifact) => (
+              <a
+                key={artifact.id}
+                href={artifactUrl(owner, repo, build.number, artifact.id)}
+                className="flex items-center justify-between gap-2 text-sm hover:underline"
+                download
+              >
+                <span className="truncate">
+                  {taskName(tasks, artifact.task_id)}: {artifact.name}
+                </span>
+                <span className="shrink-0 text-xs text-muted-foreground">
+                  {formatBytes(artifact.size_bytes)}
+                </span>
+              </a>
+            ))}
+          </div>
+        )}
       </div>
     </div>
   );
 }
+
+function artifactUrl(owner: string, repo: string, number: number, id: string) {
+  const params = new URLSearchParams({
+    owner,
+    repo,
+    number: String(number),
+    id,
+  });
+  return `/api/repository/build/artifact?${params}`;
+}
+
+function taskName(tasks: TaskResource[], taskId: string) {
+  return tasks.find((task) => task.id === taskId)?.name ?? "task";
+}
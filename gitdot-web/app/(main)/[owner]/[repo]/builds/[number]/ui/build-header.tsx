@@ -1,4 +1,5 @@
 import type {
+  ArtifactResource,
   BuildResource,
   RepositoryCommitResource,
   TaskResource,
@@ -7,20 +8,33 @@ import { BuildDag } from "./build-dag";
 import { BuildDetails } from "./build-details";
 
 export function BuildHeader({
+  owner,
+  repo,
   build,
   commit,
   tasks,
+  artifacts,
 }: {
+  owner: string;
+  repo: string;
   build: BuildResource;
   commit: RepositoryCommitResource | null;
   tasks: TaskResource[];
+  artifacts: ArtifactResource[];
 }) {
   return (
     <div className="flex h-56 border-b">
       <div className="flex w-3/4 items-center justify-center">
         <BuildDag tasks={tasks} />
       </div>
-      <BuildDetails build={build} commit={commit} />
+      <BuildDetails
+        owner={owner}
+        repo={repo}
+        build={build}
+        commit={commit}
+        tasks={tasks}
+        artifacts={artifacts}
+      />
     </div>
   );
 }
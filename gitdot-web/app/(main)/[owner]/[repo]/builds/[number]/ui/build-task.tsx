@@ -92,7 +92,7 @@ function StatusIcon({ status }: { status: TaskStatus }) {
   if (status === "success") {
     return <Check className="size-3 text-green-600" />;
   }
-  if (status === "failure") {
+  if (status === "failure" || status === "timed_out") {
     return <X className="size-3 text-red-600" />;
   }
   return <CircleSlash className="size-3 text-muted-foreground" />;
@@ -8,6 +8,8 @@ const STATUS_STYLES: Record<string, string> = {
   running: "bg-blue-400",
   success: "bg-green-500",
   failure: "bg-red-500",
+  timed_out: "bg-red-500",
+  cancelled: "bg-muted-foreground",
 };
 
 export function TaskRow({ task }: { task: TaskResource }) {
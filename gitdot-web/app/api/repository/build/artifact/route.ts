@@ -0,0 +1,22 @@
+import { downloadBuildArtifact } from "gitdot-client";
+import type { NextRequest } from "next/server";
+
+export async function GET(request: NextRequest) {
+  const { searchParams } = request.nextUrl;
+  const owner = searchParams.get("owner") ?? "";
+  const repo = searchParams.get("repo") ?? "";
+  const number = Number(searchParams.get("number"));
+  const id = searchParams.get("id") ?? "";
+  if (Number.isNaN(number)) return new Response(null, { status: 400 });
+
+  const response = await downloadBuildArtifact(owner, repo, number, id);
+  if (!response.ok) return new Response(null, { status: response.status });
+
+  const headers = response.headers;
+  return new Response(response.body, {
+    headers: {
+      "Content-Type": headers.get("Content-Type") ?? "application/gzip",
+      "Content-Disposition": headers.get("Content-Disposition") ?? "attachment",
+    },
+  });
+}
@@ -0,0 +1,27 @@
+import { z } from "zod";
+import { BranchProtectionResource } from "../../resource";
+import type { Endpoint } from "../endpoint";
+
+export const CreateBranchProtectionRequest = z.object({
+  pattern: z.string(),
+  block_force_push: z.boolean().optional(),
+  block_deletion: z.boolean().optional(),
+  require_review: z.boolean().optional(),
+  require_green_build: z.boolean().optional(),
+});
+export type CreateBranchProtectionRequest = z.infer<
+  typeof CreateBranchProtectionRequest
+>;
+
+export const CreateBranchProtectionResponse = BranchProtectionResource;
+export type CreateBranchProtectionResponse = z.infer<
+  typeof CreateBranchProtectionResponse
+>;
+
+export const CreateBranchProtection = {
+  path: "/repository/{owner}/{repo}/branch-protections",
+  method: "POST",
+  request: CreateBranchProtectionRequest,
+  response: CreateBranchProtectionResponse,
+} as const satisfies Endpoint;
+export type CreateBranchProtection = typeof CreateBranchProtection;
@@ -0,0 +1,10 @@
+import { z } from "zod";
+import type { Endpoint } from "../endpoint";
+
+export const DeleteBranchProtection = {
+  path: "/repository/{owner}/{repo}/branch-protections/{protection_id}",
+  method: "DELETE",
+  request: z.object({}),
+  response: z.object({}),
+} as const satisfies Endpoint;
+export type DeleteBranchProtection = typeof DeleteBranchProtection;
@@ -0,0 +1,4 @@
+export * from "./create-branch-protection";
+export * from "./delete-branch-protection";
+export * from "./list-branch-protections";
+export * from "./update-branch-protection";
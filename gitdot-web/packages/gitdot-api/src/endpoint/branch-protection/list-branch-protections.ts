@@ -0,0 +1,21 @@
+import { z } from "zod";
+import { BranchProtectionResource } from "../../resource";
+import type { Endpoint } from "../endpoint";
+
+export const ListBranchProtectionsRequest = z.object({});
+export type ListBranchProtectionsRequest = z.infer<
+  typeof ListBranchProtectionsRequest
+>;
+
+export const ListBranchProtectionsResponse = z.array(BranchProtectionResource);
+export type ListBranchProtectionsResponse = z.infer<
+  typeof ListBranchProtectionsResponse
+>;
+
+export const ListBranchProtections = {
+  path: "/repository/{owner}/{repo}/branch-protections",
+  method: "GET",
+  request: ListBranchProtectionsRequest,
+  response: ListBranchProtectionsResponse,
+} as const satisfies Endpoint;
+export type ListBranchProtections = typeof ListBranchProtections;
@@ -0,0 +1,27 @@
+import { z } from "zod";
+import { BranchProtectionResource } from "../../resource";
+import type { Endpoint } from "../endpoint";
+
+export const UpdateBranchProtectionRequest = z.object({
+  pattern: z.string(),
+  block_force_push: z.boolean(),
+  block_deletion: z.boolean(),
+  require_review: z.boolean(),
+  require_green_build: z.boolean(),
+});
+export type UpdateBranchProtectionRequest = z.infer<
+  typeof UpdateBranchProtectionRequest
+>;
+
+export const UpdateBranchProtectionResponse = BranchProtectionResource;
+export type UpdateBranchProtectionResponse = z.infer<
+  typeof UpdateBranchProtectionResponse
+>;
+
+export const UpdateBranchProtection = {
+  path: "/repository/{owner}/{repo}/branch-protections/{protection_id}",
+  method: "PATCH",
+  request: UpdateBranchProtectionRequest,
+  response: UpdateBranchProtectionResponse,
+} as const satisfies Endpoint;
+export type UpdateBranchProtection = typeof UpdateBranchProtection;
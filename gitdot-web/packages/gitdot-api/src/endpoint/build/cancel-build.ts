@@ -0,0 +1,17 @@
+import { z } from "zod";
+import { BuildResource } from "../../resource";
+import type { Endpoint } from "../endpoint";
+
+export const CancelBuildRequest = z.object({});
+export type CancelBuildRequest = z.infer<typeof CancelBuildRequest>;
+
+export const CancelBuildResponse = BuildResource;
+export type CancelBuildResponse = z.infer<typeof CancelBuildResponse>;
+
+export const CancelBuild = {
+  path: "/repository/{owner}/{repo}/build/{number}/cancel",
+  method: "POST",
+  request: CancelBuildRequest,
+  response: CancelBuildResponse,
+} as const satisfies Endpoint;
+export type CancelBuild = typeof CancelBuild;
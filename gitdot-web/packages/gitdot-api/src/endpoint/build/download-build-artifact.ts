@@ -0,0 +1,10 @@
+import { z } from "zod";
+import type { Endpoint } from "../endpoint";
+
+export const DownloadBuildArtifact = {
+  path: "/repository/{owner}/{repo}/build/{number}/artifact/{id}",
+  method: "GET",
+  request: z.object({}),
+  response: z.instanceof(Blob),
+} as const satisfies Endpoint;
+export type DownloadBuildArtifact = typeof DownloadBuildArtifact;
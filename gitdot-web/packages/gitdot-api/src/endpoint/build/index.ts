@@ -1,4 +1,8 @@
+export * from "./cancel-build";
 export * from "./create-build";
+export * from "./download-build-artifact";
 export * from "./get-build";
+export * from "./list-build-artifacts";
 export * from "./list-build-tasks";
 export * from "./list-builds";
+export * from "./retry-build-task";
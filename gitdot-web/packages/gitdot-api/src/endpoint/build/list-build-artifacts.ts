@@ -0,0 +1,21 @@
+import { z } from "zod";
+import { ArtifactResource } from "../../resource";
+import type { Endpoint } from "../endpoint";
+
+export const ListBuildArtifactsRequest = z.object({});
+export type ListBuildArtifactsRequest = z.infer<
+  typeof ListBuildArtifactsRequest
+>;
+
+export const ListBuildArtifactsResponse = z.array(ArtifactResource);
+export type ListBuildArtifactsResponse = z.infer<
+  typeof ListBuildArtifactsResponse
+>;
+
+export const ListBuildArtifacts = {
+  path: "/repository/{owner}/{repo}/build/{number}/artifacts",
+  method: "GET",
+  request: ListBuildArtifactsRequest,
+  response: ListBuildArtifactsResponse,
+} as const satisfies Endpoint;
+export type ListBuildArtifacts = typeof ListBuildArtifacts;
@@ -3,6 +3,7 @@ import { BuildResource, page } from "../../resource";
 import type { Endpoint } from "../endpoint";
 
 export const ListBuildsRequest = z.object({
+  review: z.number().int().optional(),
   cursor: z.string().optional(),
   limit: z.number().int().positive().optional(),
 });
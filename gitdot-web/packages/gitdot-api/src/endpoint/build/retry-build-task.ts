@@ -0,0 +1,17 @@
+import { z } from "zod";
+import { TaskResource } from "../../resource";
+import type { Endpoint } from "../endpoint";
+
+export const RetryBuildTaskRequest = z.object({});
+export type RetryBuildTaskRequest = z.infer<typeof RetryBuildTaskRequest>;
+
+export const RetryBuildTaskResponse = TaskResource;
+export type RetryBuildTaskResponse = z.infer<typeof RetryBuildTaskResponse>;
+
+export const RetryBuildTask = {
+  path: "/repository/{owner}/{repo}/build/{number}/task/{task_id}/retry",
+  method: "POST",
+  request: RetryBuildTaskRequest,
+  response: RetryBuildTaskResponse,
+} as const satisfies Endpoint;
+export type RetryBuildTask = typeof RetryBuildTask;
@@ -0,0 +1,25 @@
+import { z } from "zod";
+import { CodeSearchResource } from "../../resource";
+import type { Endpoint } from "../endpoint";
+
+export const SearchCodeRequest = z.object({
+  q: z.string().min(1),
+  regex: z.boolean().optional(),
+  case_sensitive: z.boolean().optional(),
+  path: z.string().optional(),
+  language: z.string().optional(),
+  context: z.number().int().nonnegative().optional(),
+  limit: z.number().int().positive().optional(),
+});
+export type SearchCodeRequest = z.infer<typeof SearchCodeRequest>;
+
+export const SearchCodeResponse = CodeSearchResource;
+export type SearchCodeResponse = z.infer<typeof SearchCodeResponse>;
+
+export const SearchCode = {
+  path: "/repository/{owner}/{repo}/search",
+  method: "GET",
+  request: SearchCodeRequest,
+  response: SearchCodeResponse,
+} as const satisfies Endpoint;
+export type SearchCode = typeof SearchCode;
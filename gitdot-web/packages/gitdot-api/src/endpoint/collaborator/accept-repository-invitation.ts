@@ -0,0 +1,21 @@
+import { z } from "zod";
+import { CollaboratorResource } from "../../resource";
+import type { Endpoint } from "../endpoint";
+
+export const AcceptRepositoryInvitationRequest = z.object({});
+export type AcceptRepositoryInvitationRequest = z.infer<
+  typeof AcceptRepositoryInvitationRequest
+>;
+
+export const AcceptRepositoryInvitationResponse = CollaboratorResource;
+export type AcceptRepositoryInvitationResponse = z.infer<
+  typeof AcceptRepositoryInvitationResponse
+>;
+
+export const AcceptRepositoryInvitation = {
+  path: "/user/invitation/{invitation_id}/accept",
+  method: "POST",
+  request: AcceptRepositoryInvitationRequest,
+  response: AcceptRepositoryInvitationResponse,
+} as const satisfies Endpoint;
+export type AcceptRepositoryInvitation = typeof AcceptRepositoryInvitation;
@@ -0,0 +1,10 @@
+import { z } from "zod";
+import type { Endpoint } from "../endpoint";
+
+export const DeclineRepositoryInvitation = {
+  path: "/user/invitation/{invitation_id}/decline",
+  method: "POST",
+  request: z.object({}),
+  response: z.object({}),
+} as const satisfies Endpoint;
+export type DeclineRepositoryInvitation = typeof DeclineRepositoryInvitation;
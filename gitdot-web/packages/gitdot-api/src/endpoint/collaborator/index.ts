@@ -0,0 +1,9 @@
+export * from "./accept-repository-invitation";
+export * from "./cancel-repository-invitation";
+export * from "./decline-repository-invitation";
+export * from "./invite-repository-collaborator";
+export * from "./list-repository-collaborators";
+export * from "./list-repository-invitations";
+export * from "./list-user-invitations";
+export * from "./remove-repository-collaborator";
+export * from "./update-repository-collaborator";
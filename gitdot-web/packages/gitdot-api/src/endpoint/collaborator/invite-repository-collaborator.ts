@@ -0,0 +1,25 @@
+import { z } from "zod";
+import { RepositoryInvitationResource } from "../../resource";
+import type { Endpoint } from "../endpoint";
+
+export const InviteRepositoryCollaboratorRequest = z.object({
+  user_name: z.string(),
+  role: z.string(),
+});
+export type InviteRepositoryCollaboratorRequest = z.infer<
+  typeof InviteRepositoryCollaboratorRequest
+>;
+
+export const InviteRepositoryCollaboratorResponse =
+  RepositoryInvitationResource;
+export type InviteRepositoryCollaboratorResponse = z.infer<
+  typeof InviteRepositoryCollaboratorResponse
+>;
+
+export const InviteRepositoryCollaborator = {
+  path: "/repository/{owner}/{repo}/collaborator",
+  method: "POST",
+  request: InviteRepositoryCollaboratorRequest,
+  response: InviteRepositoryCollaboratorResponse,
+} as const satisfies Endpoint;
+export type InviteRepositoryCollaborator = typeof InviteRepositoryCollaborator;
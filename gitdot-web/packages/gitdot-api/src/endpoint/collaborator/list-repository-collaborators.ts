@@ -0,0 +1,23 @@
+import { z } from "zod";
+import { CollaboratorResource } from "../../resource";
+import type { Endpoint } from "../endpoint";
+
+export const ListRepositoryCollaboratorsRequest = z.object({});
+export type ListRepositoryCollaboratorsRequest = z.infer<
+  typeof ListRepositoryCollaboratorsRequest
+>;
+
+export const ListRepositoryCollaboratorsResponse = z.array(
+  CollaboratorResource,
+);
+export type ListRepositoryCollaboratorsResponse = z.infer<
+  typeof ListRepositoryCollaboratorsResponse
+>;
+
+export const ListRepositoryCollaborators = {
+  path: "/repository/{owner}/{repo}/collaborators",
+  method: "GET",
+  request: ListRepositoryCollaboratorsRequest,
+  response: ListRepositoryCollaboratorsResponse,
+} as const satisfies Endpoint;
+export type ListRepositoryCollaborators = typeof ListRepositoryCollaborators;
@@ -0,0 +1,23 @@
+import { z } from "zod";
+import { RepositoryInvitationResource } from "../../resource";
+import type { Endpoint } from "../endpoint";
+
+export const ListRepositoryInvitationsRequest = z.object({});
+export type ListRepositoryInvitationsRequest = z.infer<
+  typeof ListRepositoryInvitationsRequest
+>;
+
+export const ListRepositoryInvitationsResponse = z.array(
+  RepositoryInvitationResource,
+);
+export type ListRepositoryInvitationsResponse = z.infer<
+  typeof ListRepositoryInvitationsResponse
+>;
+
+export const ListRepositoryInvitations = {
+  path: "/repository/{owner}/{repo}/invitations",
+  method: "GET",
+  request: ListRepositoryInvitationsRequest,
+  response: ListRepositoryInvitationsResponse,
+} as const satisfies Endpoint;
+export type ListRepositoryInvitations = typeof ListRepositoryInvitations;
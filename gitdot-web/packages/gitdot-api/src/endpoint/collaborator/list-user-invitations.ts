@@ -0,0 +1,23 @@
+import { z } from "zod";
+import { RepositoryInvitationResource } from "../../resource";
+import type { Endpoint } from "../endpoint";
+
+export const ListUserInvitationsRequest = z.object({});
+export type ListUserInvitationsRequest = z.infer<
+  typeof ListUserInvitationsRequest
+>;
+
+export const ListUserInvitationsResponse = z.array(
+  RepositoryInvitationResource,
+);
+export type ListUserInvitationsResponse = z.infer<
+  typeof ListUserInvitationsResponse
+>;
+
+export const ListUserInvitations = {
+  path: "/user/invitations",
+  method: "GET",
+  request: ListUserInvitationsRequest,
+  response: ListUserInvitationsResponse,
+} as const satisfies Endpoint;
+export type ListUserInvitations = typeof ListUserInvitations;
@@ -0,0 +1,10 @@
+import { z } from "zod";
+import type { Endpoint } from "../endpoint";
+
+export const RemoveRepositoryCollaborator = {
+  path: "/repository/{owner}/{repo}/collaborator/{user_name}",
+  method: "DELETE",
+  request: z.object({}),
+  response: z.object({}),
+} as const satisfies Endpoint;
+export type RemoveRepositoryCollaborator = typeof RemoveRepositoryCollaborator;
@@ -0,0 +1,23 @@
+import { z } from "zod";
+import { CollaboratorResource } from "../../resource";
+import type { Endpoint } from "../endpoint";
+
+export const UpdateRepositoryCollaboratorRequest = z.object({
+  role: z.string(),
+});
+export type UpdateRepositoryCollaboratorRequest = z.infer<
+  typeof UpdateRepositoryCollaboratorRequest
+>;
+
+export const UpdateRepositoryCollaboratorResponse = CollaboratorResource;
+export type UpdateRepositoryCollaboratorResponse = z.infer<
+  typeof UpdateRepositoryCollaboratorResponse
+>;
+
+export const UpdateRepositoryCollaborator = {
+  path: "/repository/{owner}/{repo}/collaborator/{user_name}",
+  method: "PATCH",
+  request: UpdateRepositoryCollaboratorRequest,
+  response: UpdateRepositoryCollaboratorResponse,
+} as const satisfies Endpoint;
+export type UpdateRepositoryCollaborator = typeof UpdateRepositoryCollaborator;
@@ -1,13 +1,22 @@
 export * from "./auth";
+export * from "./branch-protection";
 export * from "./build";
+export * from "./code-search";
+export * from "./collaborator";
 export type { Endpoint } from "./endpoint";
 export * from "./metrics";
+export * from "./maintenance";
 export * from "./migration";
+export * from "./mirror";
 export * from "./organization";
 export * from "./question";
+export * from "./release";
 export * from "./repository";
 export * from "./review";
 export * from "./runner";
+export * from "./secret";
+export * from "./tag";
 export * from "./task";
+export * from "./team";
 export * from "./user";
 export * from "./webhook";
@@ -0,0 +1,17 @@
+import { z } from "zod";
+import { MaintenanceResource } from "../../resource";
+import type { Endpoint } from "../endpoint";
+
+export const GetMaintenanceRequest = z.object({});
+export type GetMaintenanceRequest = z.infer<typeof GetMaintenanceRequest>;
+
+export const GetMaintenanceResponse = MaintenanceResource;
+export type GetMaintenanceResponse = z.infer<typeof GetMaintenanceResponse>;
+
+export const GetMaintenance = {
+  path: "/repository/{owner}/{repo}/maintenance",
+  method: "GET",
+  request: GetMaintenanceRequest,
+  response: GetMaintenanceResponse,
+} as const satisfies Endpoint;
+export type GetMaintenance = typeof GetMaintenance;
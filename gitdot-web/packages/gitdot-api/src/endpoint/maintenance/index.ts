@@ -0,0 +1,2 @@
+export * from "./get-maintenance";
+export * from "./request-maintenance";
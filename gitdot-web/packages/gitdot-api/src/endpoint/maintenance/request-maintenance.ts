@@ -0,0 +1,21 @@
+import { z } from "zod";
+import { MaintenanceResource } from "../../resource";
+import type { Endpoint } from "../endpoint";
+
+export const RequestMaintenanceRequest = z.object({});
+export type RequestMaintenanceRequest = z.infer<
+  typeof RequestMaintenanceRequest
+>;
+
+export const RequestMaintenanceResponse = MaintenanceResource;
+export type RequestMaintenanceResponse = z.infer<
+  typeof RequestMaintenanceResponse
+>;
+
+export const RequestMaintenance = {
+  path: "/repository/{owner}/{repo}/maintenance",
+  method: "POST",
+  request: RequestMaintenanceRequest,
+  response: RequestMaintenanceResponse,
+} as const satisfies Endpoint;
+export type RequestMaintenance = typeof RequestMaintenance;
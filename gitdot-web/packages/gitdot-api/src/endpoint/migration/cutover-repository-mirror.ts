@@ -0,0 +1,25 @@
+import { z } from "zod";
+import { MirrorCutoverResource } from "../../resource";
+import type { Endpoint } from "../endpoint";
+
+export const CutoverRepositoryMirrorRequest = z.object({
+  keep_push_mirror: z.boolean().optional(),
+  username: z.string().optional(),
+  password: z.string().optional(),
+});
+export type CutoverRepositoryMirrorRequest = z.infer<
+  typeof CutoverRepositoryMirrorRequest
+>;
+
+export const CutoverRepositoryMirrorResponse = MirrorCutoverResource;
+export type CutoverRepositoryMirrorResponse = z.infer<
+  typeof CutoverRepositoryMirrorResponse
+>;
+
+export const CutoverRepositoryMirror = {
+  path: "/repository/{owner}/{repo}/mirror/cutover",
+  method: "POST",
+  request: CutoverRepositoryMirrorRequest,
+  response: CutoverRepositoryMirrorResponse,
+} as const satisfies Endpoint;
+export type CutoverRepositoryMirror = typeof CutoverRepositoryMirror;
@@ -0,0 +1,21 @@
+import { z } from "zod";
+import { RepositoryMirrorResource } from "../../resource";
+import type { Endpoint } from "../endpoint";
+
+export const GetRepositoryMirrorRequest = z.object({});
+export type GetRepositoryMirrorRequest = z.infer<
+  typeof GetRepositoryMirrorRequest
+>;
+
+export const GetRepositoryMirrorResponse = RepositoryMirrorResource;
+export type GetRepositoryMirrorResponse = z.infer<
+  typeof GetRepositoryMirrorResponse
+>;
+
+export const GetRepositoryMirror = {
+  path: "/repository/{owner}/{repo}/mirror",
+  method: "GET",
+  request: GetRepositoryMirrorRequest,
+  response: GetRepositoryMirrorResponse,
+} as const satisfies Endpoint;
+export type GetRepositoryMirror = typeof GetRepositoryMirror;
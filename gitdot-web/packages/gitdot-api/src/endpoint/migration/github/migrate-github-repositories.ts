@@ -15,6 +15,7 @@ export const MigrateGitHubRepositoriesRequest = z.object({
   destination_type: z.string(),
   repositories: z.array(GitHubRepositoryRef),
   readonly: z.boolean(),
+  import_history: z.boolean().optional(),
 });
 export type MigrateGitHubRepositoriesRequest = z.infer<
   typeof MigrateGitHubRepositoriesRequest
@@ -1,3 +1,5 @@
+export * from "./cutover-repository-mirror";
 export * from "./get-migration";
+export * from "./get-repository-mirror";
 export * from "./github";
 export * from "./list-migrations";
@@ -0,0 +1,24 @@
+import { z } from "zod";
+import { MirrorResource } from "../../resource";
+import type { Endpoint } from "../endpoint";
+
+export const CreateMirrorRequest = z.object({
+  direction: z.enum(["pull", "push"]),
+  url: z.string(),
+  username: z.string().optional(),
+  password: z.string().optional(),
+  interval_seconds: z.number().int().positive().optional(),
+  replace_refs: z.boolean().optional(),
+});
+export type CreateMirrorRequest = z.infer<typeof CreateMirrorRequest>;
+
+export const CreateMirrorResponse = MirrorResource;
+export type CreateMirrorResponse = z.infer<typeof CreateMirrorResponse>;
+
+export const CreateMirror = {
+  path: "/repository/{owner}/{repo}/mirrors",
+  method: "POST",
+  request: CreateMirrorRequest,
+  response: CreateMirrorResponse,
+} as const satisfies Endpoint;
+export type CreateMirror = typeof CreateMirror;
@@ -0,0 +1,17 @@
+import { z } from "zod";
+import { MirrorResource } from "../../resource";
+import type { Endpoint } from "../endpoint";
+
+export const GetMirrorRequest = z.object({});
+export type GetMirrorRequest = z.infer<typeof GetMirrorRequest>;
+
+export const GetMirrorResponse = MirrorResource;
+export type GetMirrorResponse = z.infer<typeof GetMirrorResponse>;
+
+export const GetMirror = {
+  path: "/repository/{owner}/{repo}/mirrors/{mirror_id}",
+  method: "GET",
+  request: GetMirrorRequest,
+  response: GetMirrorResponse,
+} as const satisfies Endpoint;
+export type GetMirror = typeof GetMirror;
@@ -0,0 +1,5 @@
+export * from "./create-mirror";
+export * from "./delete-mirror";
+export * from "./get-mirror";
+export * from "./list-mirrors";
+export * from "./request-mirror-sync";
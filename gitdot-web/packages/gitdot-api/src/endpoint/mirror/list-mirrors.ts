@@ -0,0 +1,17 @@
+import { z } from "zod";
+import { MirrorResource } from "../../resource";
+import type { Endpoint } from "../endpoint";
+
+export const ListMirrorsRequest = z.object({});
+export type ListMirrorsRequest = z.infer<typeof ListMirrorsRequest>;
+
+export const ListMirrorsResponse = z.array(MirrorResource);
+export type ListMirrorsResponse = z.infer<typeof ListMirrorsResponse>;
+
+export const ListMirrors = {
+  path: "/repository/{owner}/{repo}/mirrors",
+  method: "GET",
+  request: ListMirrorsRequest,
+  response: ListMirrorsResponse,
+} as const satisfies Endpoint;
+export type ListMirrors = typeof ListMirrors;
@@ -0,0 +1,19 @@
+import { z } from "zod";
+import { MirrorResource } from "../../resource";
+import type { Endpoint } from "../endpoint";
+
+export const RequestMirrorSyncRequest = z.object({});
+export type RequestMirrorSyncRequest = z.infer<typeof RequestMirrorSyncRequest>;
+
+export const RequestMirrorSyncResponse = MirrorResource;
+export type RequestMirrorSyncResponse = z.infer<
+  typeof RequestMirrorSyncResponse
+>;
+
+export const RequestMirrorSync = {
+  path: "/repository/{owner}/{repo}/mirrors/{mirror_id}/sync",
+  method: "POST",
+  request: RequestMirrorSyncRequest,
+  response: RequestMirrorSyncResponse,
+} as const satisfies Endpoint;
+export type RequestMirrorSync = typeof RequestMirrorSync;
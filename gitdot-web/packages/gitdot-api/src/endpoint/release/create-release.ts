@@ -0,0 +1,23 @@
+import { z } from "zod";
+import { ReleaseResource } from "../../resource";
+import type { Endpoint } from "../endpoint";
+
+export const CreateReleaseRequest = z.object({
+  tag: z.string(),
+  name: z.string().optional(),
+  body: z.string().optional(),
+  draft: z.boolean().optional(),
+  prerelease: z.boolean().optional(),
+});
+export type CreateReleaseRequest = z.infer<typeof CreateReleaseRequest>;
+
+export const CreateReleaseResponse = ReleaseResource;
+export type CreateReleaseResponse = z.infer<typeof CreateReleaseResponse>;
+
+export const CreateRelease = {
+  path: "/repository/{owner}/{repo}/releases",
+  method: "POST",
+  request: CreateReleaseRequest,
+  response: CreateReleaseResponse,
+} as const satisfies Endpoint;
+export type CreateRelease = typeof CreateRelease;
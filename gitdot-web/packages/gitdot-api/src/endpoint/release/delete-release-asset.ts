@@ -0,0 +1,10 @@
+import { z } from "zod";
+import type { Endpoint } from "../endpoint";
+
+export const DeleteReleaseAsset = {
+  path: "/repository/{owner}/{repo}/releases/{tag}/assets/{name}",
+  method: "DELETE",
+  request: z.object({}),
+  response: z.object({}),
+} as const satisfies Endpoint;
+export type DeleteReleaseAsset = typeof DeleteReleaseAsset;
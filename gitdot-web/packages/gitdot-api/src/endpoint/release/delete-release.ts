@@ -0,0 +1,10 @@
+import { z } from "zod";
+import type { Endpoint } from "../endpoint";
+
+export const DeleteRelease = {
+  path: "/repository/{owner}/{repo}/releases/{tag}",
+  method: "DELETE",
+  request: z.object({}),
+  response: z.object({}),
+} as const satisfies Endpoint;
+export type DeleteRelease = typeof DeleteRelease;
@@ -0,0 +1,10 @@
+import { z } from "zod";
+import type { Endpoint } from "../endpoint";
+
+export const DownloadReleaseAsset = {
+  path: "/repository/{owner}/{repo}/releases/{tag}/assets/{name}",
+  method: "GET",
+  request: z.object({}),
+  response: z.instanceof(Blob),
+} as const satisfies Endpoint;
+export type DownloadReleaseAsset = typeof DownloadReleaseAsset;
@@ -0,0 +1,17 @@
+import { z } from "zod";
+import { ReleaseResource } from "../../resource";
+import type { Endpoint } from "../endpoint";
+
+export const GetReleaseRequest = z.object({});
+export type GetReleaseRequest = z.infer<typeof GetReleaseRequest>;
+
+export const GetReleaseResponse = ReleaseResource;
+export type GetReleaseResponse = z.infer<typeof GetReleaseResponse>;
+
+export const GetRelease = {
+  path: "/repository/{owner}/{repo}/releases/{tag}",
+  method: "GET",
+  request: GetReleaseRequest,
+  response: GetReleaseResponse,
+} as const satisfies Endpoint;
+export type GetRelease = typeof GetRelease;
@@ -0,0 +1,8 @@
+export * from "./create-release";
+export * from "./delete-release";
+export * from "./delete-release-asset";
+export * from "./download-release-asset";
+export * from "./get-release";
+export * from "./list-releases";
+export * from "./update-release";
+export * from "./upload-release-asset";
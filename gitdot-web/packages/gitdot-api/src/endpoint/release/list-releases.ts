@@ -0,0 +1,17 @@
+import { z } from "zod";
+import { ReleaseResource } from "../../resource";
+import type { Endpoint } from "../endpoint";
+
+export const ListReleasesRequest = z.object({});
+export type ListReleasesRequest = z.infer<typeof ListReleasesRequest>;
+
+export const ListReleasesResponse = z.array(ReleaseResource);
+export type ListReleasesResponse = z.infer<typeof ListReleasesResponse>;
+
+export const ListReleases = {
+  path: "/repository/{owner}/{repo}/releases",
+  method: "GET",
+  request: ListReleasesRequest,
+  response: ListReleasesResponse,
+} as const satisfies Endpoint;
+export type ListReleases = typeof ListReleases;
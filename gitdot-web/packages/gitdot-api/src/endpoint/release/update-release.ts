@@ -0,0 +1,22 @@
+import { z } from "zod";
+import { ReleaseResource } from "../../resource";
+import type { Endpoint } from "../endpoint";
+
+export const UpdateReleaseRequest = z.object({
+  name: z.string().optional(),
+  body: z.string().optional(),
+  draft: z.boolean().optional(),
+  prerelease: z.boolean().optional(),
+});
+export type UpdateReleaseRequest = z.infer<typeof UpdateReleaseRequest>;
+
+export const UpdateReleaseResponse = ReleaseResource;
+export type UpdateReleaseResponse = z.infer<typeof UpdateReleaseResponse>;
+
+export const UpdateRelease = {
+  path: "/repository/{owner}/{repo}/releases/{tag}",
+  method: "PATCH",
+  request: UpdateReleaseRequest,
+  response: UpdateReleaseResponse,
+} as const satisfies Endpoint;
+export type UpdateRelease = typeof UpdateRelease;
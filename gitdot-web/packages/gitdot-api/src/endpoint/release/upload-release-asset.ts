@@ -0,0 +1,21 @@
+import { z } from "zod";
+import { ReleaseAssetResource } from "../../resource";
+import type { Endpoint } from "../endpoint";
+
+export const UploadReleaseAssetRequest = z.object({ name: z.string() });
+export type UploadReleaseAssetRequest = z.infer<
+  typeof UploadReleaseAssetRequest
+>;
+
+export const UploadReleaseAssetResponse = ReleaseAssetResource;
+export type UploadReleaseAssetResponse = z.infer<
+  typeof UploadReleaseAssetResponse
+>;
+
+export const UploadReleaseAsset = {
+  path: "/repository/{owner}/{repo}/releases/{tag}/assets",
+  method: "POST",
+  request: UploadReleaseAssetRequest,
+  response: UploadReleaseAssetResponse,
+} as const satisfies Endpoint;
+export type UploadReleaseAsset = typeof UploadReleaseAsset;
@@ -0,0 +1,26 @@
+import { z } from "zod";
+import { RepositoryCompareResource } from "../../resource";
+import type { Endpoint } from "../endpoint";
+
+export const CompareRepositoryRefsRequest = z.object({
+  ignore_whitespace: z.enum(["none", "all", "change", "eol"]).optional(),
+  detect_renames: z.boolean().optional(),
+  cursor: z.string().optional(),
+  limit: z.number().int().positive().optional(),
+});
+export type CompareRepositoryRefsRequest = z.infer<
+  typeof CompareRepositoryRefsRequest
+>;
+
+export const CompareRepositoryRefsResponse = RepositoryCompareResource;
+export type CompareRepositoryRefsResponse = z.infer<
+  typeof CompareRepositoryRefsResponse
+>;
+
+export const CompareRepositoryRefs = {
+  path: "/repository/{owner}/{repo}/compare/{range}",
+  method: "GET",
+  request: CompareRepositoryRefsRequest,
+  response: CompareRepositoryRefsResponse,
+} as const satisfies Endpoint;
+export type CompareRepositoryRefs = typeof CompareRepositoryRefs;
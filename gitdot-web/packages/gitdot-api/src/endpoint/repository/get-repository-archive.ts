@@ -0,0 +1,10 @@
+import { z } from "zod";
+import type { Endpoint } from "../endpoint";
+
+export const GetRepositoryArchive = {
+  path: "/repository/{owner}/{repo}/archive/{archive}",
+  method: "GET",
+  request: z.object({}),
+  response: z.instanceof(Blob),
+} as const satisfies Endpoint;
+export type GetRepositoryArchive = typeof GetRepositoryArchive;
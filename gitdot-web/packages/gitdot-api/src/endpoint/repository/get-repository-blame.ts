@@ -0,0 +1,25 @@
+import { z } from "zod";
+import { RepositoryBlameResource } from "../../resource";
+import type { Endpoint } from "../endpoint";
+
+export const GetRepositoryBlameRequest = z.object({
+  path: z.string(),
+  ref_name: z.string().optional(),
+  ignore_revs_file: z.string().optional(),
+});
+export type GetRepositoryBlameRequest = z.infer<
+  typeof GetRepositoryBlameRequest
+>;
+
+export const GetRepositoryBlameResponse = RepositoryBlameResource;
+export type GetRepositoryBlameResponse = z.infer<
+  typeof GetRepositoryBlameResponse
+>;
+
+export const GetRepositoryBlame = {
+  path: "/repository/{owner}/{repo}/blame",
+  method: "GET",
+  request: GetRepositoryBlameRequest,
+  response: GetRepositoryBlameResponse,
+} as const satisfies Endpoint;
+export type GetRepositoryBlame = typeof GetRepositoryBlame;
@@ -1,9 +1,12 @@
+export * from "./compare-repository-refs";
 export * from "./create-repository";
 export * from "./create-repository-commit-filter";
 export * from "./delete-repository";
 export * from "./delete-repository-commit-filter";
 export * from "./get-repository";
 export * from "./get-repository-activity";
+export * from "./get-repository-archive";
+export * from "./get-repository-blame";
 export * from "./get-repository-blob";
 export * from "./get-repository-blobs";
 export * from "./get-repository-commit";
@@ -0,0 +1,21 @@
+import { z } from "zod";
+import { ReviewMergeabilityResource } from "../../resource";
+import type { Endpoint } from "../endpoint";
+
+export const GetReviewDiffMergeabilityRequest = z.object({});
+export type GetReviewDiffMergeabilityRequest = z.infer<
+  typeof GetReviewDiffMergeabilityRequest
+>;
+
+export const GetReviewDiffMergeabilityResponse = ReviewMergeabilityResource;
+export type GetReviewDiffMergeabilityResponse = z.infer<
+  typeof GetReviewDiffMergeabilityResponse
+>;
+
+export const GetReviewDiffMergeability = {
+  path: "/repository/{owner}/{repo}/review/{number}/diff/{position}/mergeability",
+  method: "GET",
+  request: GetReviewDiffMergeabilityRequest,
+  response: GetReviewDiffMergeabilityResponse,
+} as const satisfies Endpoint;
+export type GetReviewDiffMergeability = typeof GetReviewDiffMergeability;
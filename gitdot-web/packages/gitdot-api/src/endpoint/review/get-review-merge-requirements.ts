@@ -0,0 +1,21 @@
+import { z } from "zod";
+import { MergeRequirementsResource } from "../../resource";
+import type { Endpoint } from "../endpoint";
+
+export const GetReviewMergeRequirementsRequest = z.object({});
+export type GetReviewMergeRequirementsRequest = z.infer<
+  typeof GetReviewMergeRequirementsRequest
+>;
+
+export const GetReviewMergeRequirementsResponse = MergeRequirementsResource;
+export type GetReviewMergeRequirementsResponse = z.infer<
+  typeof GetReviewMergeRequirementsResponse
+>;
+
+export const GetReviewMergeRequirements = {
+  path: "/repository/{owner}/{repo}/review-merge-requirements",
+  method: "GET",
+  request: GetReviewMergeRequirementsRequest,
+  response: GetReviewMergeRequirementsResponse,
+} as const satisfies Endpoint;
+export type GetReviewMergeRequirements = typeof GetReviewMergeRequirements;
@@ -1,6 +1,8 @@
 export * from "./add-review-reviewer";
 export * from "./get-review";
 export * from "./get-review-diff-blobs";
+export * from "./get-review-diff-mergeability";
+export * from "./get-review-merge-requirements";
 export * from "./list-reviews";
 export * from "./merge-review-diff";
 export * from "./publish-review";
@@ -11,3 +13,4 @@ export * from "./review-review-diff";
 export * from "./update-review";
 export * from "./update-review-comment";
 export * from "./update-review-diff";
+export * from "./update-review-merge-requirements";
@@ -0,0 +1,27 @@
+import { z } from "zod";
+import { MergeRequirementsResource } from "../../resource";
+import type { Endpoint } from "../endpoint";
+
+export const UpdateReviewMergeRequirementsRequest = z.object({
+  required_approvals: z.number().int(),
+  block_on_rejection: z.boolean(),
+  require_resolved_comments: z.boolean(),
+  require_green_build: z.boolean(),
+});
+export type UpdateReviewMergeRequirementsRequest = z.infer<
+  typeof UpdateReviewMergeRequirementsRequest
+>;
+
+export const UpdateReviewMergeRequirementsResponse = MergeRequirementsResource;
+export type UpdateReviewMergeRequirementsResponse = z.infer<
+  typeof UpdateReviewMergeRequirementsResponse
+>;
+
+export const UpdateReviewMergeRequirements = {
+  path: "/repository/{owner}/{repo}/review-merge-requirements",
+  method: "PUT",
+  request: UpdateReviewMergeRequirementsRequest,
+  response: UpdateReviewMergeRequirementsResponse,
+} as const satisfies Endpoint;
+export type UpdateReviewMergeRequirements =
+  typeof UpdateReviewMergeRequirements;
@@ -0,0 +1,10 @@
+import { z } from "zod";
+import type { Endpoint } from "../endpoint";
+
+export const DeleteOrganizationSecret = {
+  path: "/organization/{org_name}/secret/{name}",
+  method: "DELETE",
+  request: z.object({}),
+  response: z.object({}),
+} as const satisfies Endpoint;
+export type DeleteOrganizationSecret = typeof DeleteOrganizationSecret;
@@ -0,0 +1,10 @@
+import { z } from "zod";
+import type { Endpoint } from "../endpoint";
+
+export const DeleteRepositorySecret = {
+  path: "/repository/{owner}/{repo}/secret/{name}",
+  method: "DELETE",
+  request: z.object({}),
+  response: z.object({}),
+} as const satisfies Endpoint;
+export type DeleteRepositorySecret = typeof DeleteRepositorySecret;
@@ -0,0 +1,6 @@
+export * from "./delete-organization-secret";
+export * from "./delete-repository-secret";
+export * from "./list-organization-secrets";
+export * from "./list-repository-secrets";
+export * from "./set-organization-secret";
+export * from "./set-repository-secret";
@@ -0,0 +1,21 @@
+import { z } from "zod";
+import { SecretResource } from "../../resource";
+import type { Endpoint } from "../endpoint";
+
+export const ListOrganizationSecretsRequest = z.object({});
+export type ListOrganizationSecretsRequest = z.infer<
+  typeof ListOrganizationSecretsRequest
+>;
+
+export const ListOrganizationSecretsResponse = z.array(SecretResource);
+export type ListOrganizationSecretsResponse = z.infer<
+  typeof ListOrganizationSecretsResponse
+>;
+
+export const ListOrganizationSecrets = {
+  path: "/organization/{org_name}/secrets",
+  method: "GET",
+  request: ListOrganizationSecretsRequest,
+  response: ListOrganizationSecretsResponse,
+} as const satisfies Endpoint;
+export type ListOrganizationSecrets = typeof ListOrganizationSecrets;
@@ -0,0 +1,21 @@
+import { z } from "zod";
+import { SecretResource } from "../../resource";
+import type { Endpoint } from "../endpoint";
+
+export const ListRepositorySecretsRequest = z.object({});
+export type ListRepositorySecretsRequest = z.infer<
+  typeof ListRepositorySecretsRequest
+>;
+
+export const ListRepositorySecretsResponse = z.array(SecretResource);
+export type ListRepositorySecretsResponse = z.infer<
+  typeof ListRepositorySecretsResponse
+>;
+
+export const ListRepositorySecrets = {
+  path: "/repository/{owner}/{repo}/secrets",
+  method: "GET",
+  request: ListRepositorySecretsRequest,
+  response: ListRepositorySecretsResponse,
+} as const satisfies Endpoint;
+export type ListRepositorySecrets = typeof ListRepositorySecrets;
@@ -0,0 +1,23 @@
+import { z } from "zod";
+import { SecretResource } from "../../resource";
+import type { Endpoint } from "../endpoint";
+
+export const SetOrganizationSecretRequest = z.object({
+  value: z.string(),
+});
+export type SetOrganizationSecretRequest = z.infer<
+  typeof SetOrganizationSecretRequest
+>;
+
+export const SetOrganizationSecretResponse = SecretResource;
+export type SetOrganizationSecretResponse = z.infer<
+  typeof SetOrganizationSecretResponse
+>;
+
+export const SetOrganizationSecret = {
+  path: "/organization/{org_name}/secret/{name}",
+  method: "PUT",
+  request: SetOrganizationSecretRequest,
+  response: SetOrganizationSecretResponse,
+} as const satisfies Endpoint;
+export type SetOrganizationSecret = typeof SetOrganizationSecret;
@@ -0,0 +1,23 @@
+import { z } from "zod";
+import { SecretResource } from "../../resource";
+import type { Endpoint } from "../endpoint";
+
+export const SetRepositorySecretRequest = z.object({
+  value: z.string(),
+});
+export type SetRepositorySecretRequest = z.infer<
+  typeof SetRepositorySecretRequest
+>;
+
+export const SetRepositorySecretResponse = SecretResource;
+export type SetRepositorySecretResponse = z.infer<
+  typeof SetRepositorySecretResponse
+>;
+
+export const SetRepositorySecret = {
+  path: "/repository/{owner}/{repo}/secret/{name}",
+  method: "PUT",
+  request: SetRepositorySecretRequest,
+  response: SetRepositorySecretResponse,
+} as const satisfies Endpoint;
+export type SetRepositorySecret = typeof SetRepositorySecret;
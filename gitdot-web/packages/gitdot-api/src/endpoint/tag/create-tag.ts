@@ -0,0 +1,21 @@
+import { z } from "zod";
+import { TagResource } from "../../resource";
+import type { Endpoint } from "../endpoint";
+
+export const CreateTagRequest = z.object({
+  name: z.string(),
+  target: z.string(),
+  message: z.string().optional(),
+});
+export type CreateTagRequest = z.infer<typeof CreateTagRequest>;
+
+export const CreateTagResponse = TagResource;
+export type CreateTagResponse = z.infer<typeof CreateTagResponse>;
+
+export const CreateTag = {
+  path: "/repository/{owner}/{repo}/tags",
+  method: "POST",
+  request: CreateTagRequest,
+  response: CreateTagResponse,
+} as const satisfies Endpoint;
+export type CreateTag = typeof CreateTag;
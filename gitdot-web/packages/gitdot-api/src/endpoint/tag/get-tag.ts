@@ -0,0 +1,17 @@
+import { z } from "zod";
+import { TagResource } from "../../resource";
+import type { Endpoint } from "../endpoint";
+
+export const GetTagRequest = z.object({});
+export type GetTagRequest = z.infer<typeof GetTagRequest>;
+
+export const GetTagResponse = TagResource;
+export type GetTagResponse = z.infer<typeof GetTagResponse>;
+
+export const GetTag = {
+  path: "/repository/{owner}/{repo}/tags/{tag}",
+  method: "GET",
+  request: GetTagRequest,
+  response: GetTagResponse,
+} as const satisfies Endpoint;
+export type GetTag = typeof GetTag;
@@ -0,0 +1,4 @@
+export * from "./create-tag";
+export * from "./delete-tag";
+export * from "./get-tag";
+export * from "./list-tags";
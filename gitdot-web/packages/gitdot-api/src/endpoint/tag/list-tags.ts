@@ -0,0 +1,17 @@
+import { z } from "zod";
+import { TagResource } from "../../resource";
+import type { Endpoint } from "../endpoint";
+
+export const ListTagsRequest = z.object({});
+export type ListTagsRequest = z.infer<typeof ListTagsRequest>;
+
+export const ListTagsResponse = z.array(TagResource);
+export type ListTagsResponse = z.infer<typeof ListTagsResponse>;
+
+export const ListTags = {
+  path: "/repository/{owner}/{repo}/tags",
+  method: "GET",
+  request: ListTagsRequest,
+  response: ListTagsResponse,
+} as const satisfies Endpoint;
+export type ListTags = typeof ListTags;
@@ -0,0 +1,10 @@
+import { z } from "zod";
+import type { Endpoint } from "../endpoint";
+
+export const DownloadTaskArtifact = {
+  path: "/ci/task/{id}/artifact/{artifact_id}",
+  method: "GET",
+  request: z.object({}),
+  response: z.instanceof(Blob),
+} as const satisfies Endpoint;
+export type DownloadTaskArtifact = typeof DownloadTaskArtifact;
@@ -0,0 +1,10 @@
+import { z } from "zod";
+import type { Endpoint } from "../endpoint";
+
+export const DownloadTaskCache = {
+  path: "/ci/task/{id}/cache",
+  method: "GET",
+  request: z.object({}),
+  response: z.instanceof(Blob).optional(),
+} as const satisfies Endpoint;
+export type DownloadTaskCache = typeof DownloadTaskCache;
@@ -0,0 +1,17 @@
+import { z } from "zod";
+import { TaskResource } from "../../resource";
+import type { Endpoint } from "../endpoint";
+
+export const HeartbeatTaskRequest = z.object({});
+export type HeartbeatTaskRequest = z.infer<typeof HeartbeatTaskRequest>;
+
+export const HeartbeatTaskResponse = TaskResource;
+export type HeartbeatTaskResponse = z.infer<typeof HeartbeatTaskResponse>;
+
+export const HeartbeatTask = {
+  path: "/ci/task/{id}/heartbeat",
+  method: "POST",
+  request: HeartbeatTaskRequest,
+  response: HeartbeatTaskResponse,
+} as const satisfies Endpoint;
+export type HeartbeatTask = typeof HeartbeatTask;
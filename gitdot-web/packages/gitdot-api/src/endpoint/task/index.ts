@@ -1,3 +1,9 @@
+export * from "./download-task-artifact";
+export * from "./download-task-cache";
+export * from "./heartbeat-task";
 export * from "./issue-task-token";
+export * from "./list-task-artifacts";
 export * from "./poll-task";
 export * from "./update-task";
+export * from "./upload-task-artifact";
+export * from "./upload-task-cache";
@@ -0,0 +1,19 @@
+import { z } from "zod";
+import { ArtifactResource } from "../../resource";
+import type { Endpoint } from "../endpoint";
+
+export const ListTaskArtifactsRequest = z.object({});
+export type ListTaskArtifactsRequest = z.infer<typeof ListTaskArtifactsRequest>;
+
+export const ListTaskArtifactsResponse = z.array(ArtifactResource);
+export type ListTaskArtifactsResponse = z.infer<
+  typeof ListTaskArtifactsResponse
+>;
+
+export const ListTaskArtifacts = {
+  path: "/ci/task/{id}/artifacts",
+  method: "GET",
+  request: ListTaskArtifactsRequest,
+  response: ListTaskArtifactsResponse,
+} as const satisfies Endpoint;
+export type ListTaskArtifacts = typeof ListTaskArtifacts;
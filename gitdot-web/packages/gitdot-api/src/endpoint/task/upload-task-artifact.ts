@@ -0,0 +1,21 @@
+import { z } from "zod";
+import { ArtifactResource } from "../../resource";
+import type { Endpoint } from "../endpoint";
+
+export const UploadTaskArtifactRequest = z.object({ name: z.string() });
+export type UploadTaskArtifactRequest = z.infer<
+  typeof UploadTaskArtifactRequest
+>;
+
+export const UploadTaskArtifactResponse = ArtifactResource;
+export type UploadTaskArtifactResponse = z.infer<
+  typeof UploadTaskArtifactResponse
+>;
+
+export const UploadTaskArtifact = {
+  path: "/ci/task/{id}/artifact",
+  method: "POST",
+  request: UploadTaskArtifactRequest,
+  response: UploadTaskArtifactResponse,
+} as const satisfies Endpoint;
+export type UploadTaskArtifact = typeof UploadTaskArtifact;
@@ -0,0 +1,10 @@
+import { z } from "zod";
+import type { Endpoint } from "../endpoint";
+
+export const UploadTaskCache = {
+  path: "/ci/task/{id}/cache",
+  method: "POST",
+  request: z.instanceof(Blob),
+  response: z.void(),
+} as const satisfies Endpoint;
+export type UploadTaskCache = typeof UploadTaskCache;
@@ -0,0 +1,19 @@
+import { z } from "zod";
+import { TeamMemberResource } from "../../resource";
+import type { Endpoint } from "../endpoint";
+
+export const AddTeamMemberRequest = z.object({
+  user_name: z.string(),
+});
+export type AddTeamMemberRequest = z.infer<typeof AddTeamMemberRequest>;
+
+export const AddTeamMemberResponse = TeamMemberResource;
+export type AddTeamMemberResponse = z.infer<typeof AddTeamMemberResponse>;
+
+export const AddTeamMember = {
+  path: "/organization/{org_name}/team/{team_name}/member",
+  method: "POST",
+  request: AddTeamMemberRequest,
+  response: AddTeamMemberResponse,
+} as const satisfies Endpoint;
+export type AddTeamMember = typeof AddTeamMember;
@@ -0,0 +1,20 @@
+import { z } from "zod";
+import { TeamResource } from "../../resource";
+import type { Endpoint } from "../endpoint";
+
+export const CreateTeamRequest = z.object({
+  name: z.string(),
+  description: z.string().nullable().optional(),
+});
+export type CreateTeamRequest = z.infer<typeof CreateTeamRequest>;
+
+export const CreateTeamResponse = TeamResource;
+export type CreateTeamResponse = z.infer<typeof CreateTeamResponse>;
+
+export const CreateTeam = {
+  path: "/organization/{org_name}/team",
+  method: "POST",
+  request: CreateTeamRequest,
+  response: CreateTeamResponse,
+} as const satisfies Endpoint;
+export type CreateTeam = typeof CreateTeam;
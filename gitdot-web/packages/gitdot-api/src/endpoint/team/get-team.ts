@@ -0,0 +1,17 @@
+import { z } from "zod";
+import { TeamResource } from "../../resource";
+import type { Endpoint } from "../endpoint";
+
+export const GetTeamRequest = z.object({});
+export type GetTeamRequest = z.infer<typeof GetTeamRequest>;
+
+export const GetTeamResponse = TeamResource;
+export type GetTeamResponse = z.infer<typeof GetTeamResponse>;
+
+export const GetTeam = {
+  path: "/organization/{org_name}/team/{team_name}",
+  method: "GET",
+  request: GetTeamRequest,
+  response: GetTeamResponse,
+} as const satisfies Endpoint;
+export type GetTeam = typeof GetTeam;
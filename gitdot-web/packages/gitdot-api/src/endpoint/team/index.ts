@@ -0,0 +1,8 @@
+export * from "./add-team-member";
+export * from "./create-team";
+export * from "./delete-team";
+export * from "./get-team";
+export * from "./list-teams";
+export * from "./remove-team-member";
+export * from "./remove-team-repository";
+export * from "./set-team-repository";
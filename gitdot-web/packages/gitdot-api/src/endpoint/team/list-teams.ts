@@ -0,0 +1,17 @@
+import { z } from "zod";
+import { TeamResource } from "../../resource";
+import type { Endpoint } from "../endpoint";
+
+export const ListTeamsRequest = z.object({});
+export type ListTeamsRequest = z.infer<typeof ListTeamsRequest>;
+
+export const ListTeamsResponse = z.array(TeamResource);
+export type ListTeamsResponse = z.infer<typeof ListTeamsResponse>;
+
+export const ListTeams = {
+  path: "/organization/{org_name}/teams",
+  method: "GET",
+  request: ListTeamsRequest,
+  response: ListTeamsResponse,
+} as const satisfies Endpoint;
+export type ListTeams = typeof ListTeams;
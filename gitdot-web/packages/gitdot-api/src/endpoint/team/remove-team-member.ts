@@ -0,0 +1,10 @@
+import { z } from "zod";
+import type { Endpoint } from "../endpoint";
+
+export const RemoveTeamMember = {
+  path: "/organization/{org_name}/team/{team_name}/member/{user_name}",
+  method: "DELETE",
+  request: z.object({}),
+  response: z.object({}),
+} as const satisfies Endpoint;
+export type RemoveTeamMember = typeof RemoveTeamMember;
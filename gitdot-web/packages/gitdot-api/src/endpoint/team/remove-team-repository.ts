@@ -0,0 +1,10 @@
+import { z } from "zod";
+import type { Endpoint } from "../endpoint";
+
+export const RemoveTeamRepository = {
+  path: "/organization/{org_name}/team/{team_name}/repository/{repo}",
+  method: "DELETE",
+  request: z.object({}),
+  response: z.object({}),
+} as const satisfies Endpoint;
+export type RemoveTeamRepository = typeof RemoveTeamRepository;
@@ -0,0 +1,21 @@
+import { z } from "zod";
+import { TeamRepositoryResource } from "../../resource";
+import type { Endpoint } from "../endpoint";
+
+export const SetTeamRepositoryRequest = z.object({
+  role: z.string(),
+});
+export type SetTeamRepositoryRequest = z.infer<typeof SetTeamRepositoryRequest>;
+
+export const SetTeamRepositoryResponse = TeamRepositoryResource;
+export type SetTeamRepositoryResponse = z.infer<
+  typeof SetTeamRepositoryResponse
+>;
+
+export const SetTeamRepository = {
+  path: "/organization/{org_name}/team/{team_name}/repository/{repo}",
+  method: "PUT",
+  request: SetTeamRepositoryRequest,
+  response: SetTeamRepositoryResponse,
+} as const satisfies Endpoint;
+export type SetTeamRepository = typeof SetTeamRepository;
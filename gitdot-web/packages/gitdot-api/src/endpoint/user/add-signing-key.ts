@@ -0,0 +1,21 @@
+import { z } from "zod";
+import { SigningKeyResource } from "../../resource";
+import type { Endpoint } from "../endpoint";
+
+export const AddSigningKeyRequest = z.object({
+  name: z.string().nullable().optional(),
+  public_key: z.string(),
+  signature: z.string().nullable().optional(),
+});
+export type AddSigningKeyRequest = z.infer<typeof AddSigningKeyRequest>;
+
+export const AddSigningKeyResponse = SigningKeyResource;
+export type AddSigningKeyResponse = z.infer<typeof AddSigningKeyResponse>;
+
+export const AddSigningKey = {
+  path: "/user/signing-key",
+  method: "POST",
+  request: AddSigningKeyRequest,
+  response: AddSigningKeyResponse,
+} as const satisfies Endpoint;
+export type AddSigningKey = typeof AddSigningKey;
@@ -0,0 +1,20 @@
+import { z } from "zod";
+import { SshKeyResource } from "../../resource";
+import type { Endpoint } from "../endpoint";
+
+export const AddSshKeyRequest = z.object({
+  name: z.string().nullable().optional(),
+  public_key: z.string(),
+});
+export type AddSshKeyRequest = z.infer<typeof AddSshKeyRequest>;
+
+export const AddSshKeyResponse = SshKeyResource;
+export type AddSshKeyResponse = z.infer<typeof AddSshKeyResponse>;
+
+export const AddSshKey = {
+  path: "/user/ssh-key",
+  method: "POST",
+  request: AddSshKeyRequest,
+  response: AddSshKeyResponse,
+} as const satisfies Endpoint;
+export type AddSshKey = typeof AddSshKey;
@@ -0,0 +1,25 @@
+import { z } from "zod";
+import { NewPersonalTokenResource, TokenScope } from "../../resource";
+import type { Endpoint } from "../endpoint";
+
+export const CreatePersonalTokenRequest = z.object({
+  name: z.string(),
+  scopes: z.array(TokenScope),
+  expires_at: z.iso.datetime().nullable().optional(),
+});
+export type CreatePersonalTokenRequest = z.infer<
+  typeof CreatePersonalTokenRequest
+>;
+
+export const CreatePersonalTokenResponse = NewPersonalTokenResource;
+export type CreatePersonalTokenResponse = z.infer<
+  typeof CreatePersonalTokenResponse
+>;
+
+export const CreatePersonalToken = {
+  path: "/user/token",
+  method: "POST",
+  request: CreatePersonalTokenRequest,
+  response: CreatePersonalTokenResponse,
+} as const satisfies Endpoint;
+export type CreatePersonalToken = typeof CreatePersonalToken;
@@ -1,10 +1,19 @@
+export * from "./add-signing-key";
+export * from "./add-ssh-key";
+export * from "./create-personal-token";
+export * from "./delete-signing-key";
+export * from "./delete-ssh-key";
 export * from "./get-current-user";
 export * from "./get-user";
 export * from "./has-user";
+export * from "./list-personal-tokens";
+export * from "./list-signing-keys";
+export * from "./list-ssh-keys";
 export * from "./list-user-commits";
 export * from "./list-user-organizations";
 export * from "./list-user-repositories";
 export * from "./list-user-repositories-contributed";
 export * from "./list-user-repositories-starred";
+export * from "./revoke-personal-token";
 export * from "./update-current-user";
 export * from "./upload-user-image";
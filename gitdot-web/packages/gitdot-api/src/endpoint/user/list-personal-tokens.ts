@@ -0,0 +1,21 @@
+import { z } from "zod";
+import { PersonalTokenResource } from "../../resource";
+import type { Endpoint } from "../endpoint";
+
+export const ListPersonalTokensRequest = z.object({});
+export type ListPersonalTokensRequest = z.infer<
+  typeof ListPersonalTokensRequest
+>;
+
+export const ListPersonalTokensResponse = z.array(PersonalTokenResource);
+export type ListPersonalTokensResponse = z.infer<
+  typeof ListPersonalTokensResponse
+>;
+
+export const ListPersonalTokens = {
+  path: "/user/tokens",
+  method: "GET",
+  request: ListPersonalTokensRequest,
+  response: ListPersonalTokensResponse,
+} as const satisfies Endpoint;
+export type ListPersonalTokens = typeof ListPersonalTokens;
@@ -0,0 +1,17 @@
+import { z } from "zod";
+import { SigningKeyResource } from "../../resource";
+import type { Endpoint } from "../endpoint";
+
+export const ListSigningKeysRequest = z.object({});
+export type ListSigningKeysRequest = z.infer<typeof ListSigningKeysRequest>;
+
+export const ListSigningKeysResponse = z.array(SigningKeyResource);
+export type ListSigningKeysResponse = z.infer<typeof ListSigningKeysResponse>;
+
+export const ListSigningKeys = {
+  path: "/user/signing-keys",
+  method: "GET",
+  request: ListSigningKeysRequest,
+  response: ListSigningKeysResponse,
+} as const satisfies Endpoint;
+export type ListSigningKeys = typeof ListSigningKeys;
@@ -0,0 +1,17 @@
+import { z } from "zod";
+import { SshKeyResource } from "../../resource";
+import type { Endpoint } from "../endpoint";
+
+export const ListSshKeysRequest = z.object({});
+export type ListSshKeysRequest = z.infer<typeof ListSshKeysRequest>;
+
+export const ListSshKeysResponse = z.array(SshKeyResource);
+export type ListSshKeysResponse = z.infer<typeof ListSshKeysResponse>;
+
+export const ListSshKeys = {
+  path: "/user/ssh-keys",
+  method: "GET",
+  request: ListSshKeysRequest,
+  response: ListSshKeysResponse,
+} as const satisfies Endpoint;
+export type ListSshKeys = typeof ListSshKeys;
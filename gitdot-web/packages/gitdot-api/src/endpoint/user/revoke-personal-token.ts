@@ -0,0 +1,10 @@
+import { z } from "zod";
+import type { Endpoint } from "../endpoint";
+
+export const RevokePersonalToken = {
+  path: "/user/token/{token_id}",
+  method: "DELETE",
+  request: z.object({}),
+  response: z.object({}),
+} as const satisfies Endpoint;
+export type RevokePersonalToken = typeof RevokePersonalToken;
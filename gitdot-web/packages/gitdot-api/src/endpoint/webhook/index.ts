@@ -1,5 +1,7 @@
 export * from "./create-webhook";
 export * from "./delete-webhook";
 export * from "./get-webhook";
+export * from "./list-webhook-deliveries";
 export * from "./list-webhooks";
+export * from "./redeliver-webhook-delivery";
 export * from "./update-webhook";
@@ -0,0 +1,24 @@
+import { z } from "zod";
+import { page, WebhookDeliveryResource } from "../../resource";
+import type { Endpoint } from "../endpoint";
+
+export const ListWebhookDeliveriesRequest = z.object({
+  cursor: z.string().optional(),
+  limit: z.number().int().positive().optional(),
+});
+export type ListWebhookDeliveriesRequest = z.infer<
+  typeof ListWebhookDeliveriesRequest
+>;
+
+export const ListWebhookDeliveriesResponse = page(WebhookDeliveryResource);
+export type ListWebhookDeliveriesResponse = z.infer<
+  typeof ListWebhookDeliveriesResponse
+>;
+
+export const ListWebhookDeliveries = {
+  path: "/repository/{owner}/{repo}/webhook/{webhook_id}/deliveries",
+  method: "GET",
+  request: ListWebhookDeliveriesRequest,
+  response: ListWebhookDeliveriesResponse,
+} as const satisfies Endpoint;
+export type ListWebhookDeliveries = typeof ListWebhookDeliveries;
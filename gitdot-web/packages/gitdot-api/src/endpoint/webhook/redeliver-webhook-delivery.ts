@@ -0,0 +1,11 @@
+import { z } from "zod";
+import { WebhookDeliveryResource } from "../../resource";
+import type { Endpoint } from "../endpoint";
+
+export const RedeliverWebhookDelivery = {
+  path: "/repository/{owner}/{repo}/webhook/{webhook_id}/delivery/{delivery_id}/redeliver",
+  method: "POST",
+  request: z.object({}),
+  response: WebhookDeliveryResource,
+} as const satisfies Endpoint;
+export type RedeliverWebhookDelivery = typeof RedeliverWebhookDelivery;
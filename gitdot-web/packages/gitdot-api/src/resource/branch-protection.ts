@@ -0,0 +1,14 @@
+import { z } from "zod";
+
+export const BranchProtectionResource = z.object({
+  id: z.uuid(),
+  repository_id: z.uuid(),
+  pattern: z.string(),
+  block_force_push: z.boolean(),
+  block_deletion: z.boolean(),
+  require_review: z.boolean(),
+  require_green_build: z.boolean(),
+  created_at: z.iso.datetime(),
+  updated_at: z.iso.datetime(),
+});
+export type BranchProtectionResource = z.infer<typeof BranchProtectionResource>;
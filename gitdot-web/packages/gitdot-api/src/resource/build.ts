@@ -6,11 +6,30 @@ export const BuildResource = z.object({
   repository_id: z.uuid(),
   ref_name: z.string(),
   commit_sha: z.string(),
-  trigger: z.enum(["pull_request", "push_to_main"]),
-  status: z.enum(["running", "success", "failure"]),
+  trigger: z.enum([
+    "pull_request",
+    "push_to_main",
+    "tag_push",
+    "manual",
+    "schedule",
+  ]),
+  status: z.enum(["running", "success", "failure", "cancelled"]),
+  review_id: z.uuid().nullable(),
+  diff_id: z.uuid().nullable(),
+  revision_id: z.uuid().nullable(),
   total_tasks: z.number().int(),
   completed_tasks: z.number().int(),
   created_at: z.iso.datetime(),
   updated_at: z.iso.datetime(),
 });
 export type BuildResource = z.infer<typeof BuildResource>;
+
+export const ArtifactResource = z.object({
+  id: z.uuid(),
+  build_id: z.uuid(),
+  task_id: z.uuid(),
+  name: z.string(),
+  size_bytes: z.number(),
+  created_at: z.iso.datetime(),
+});
+export type ArtifactResource = z.infer<typeof ArtifactResource>;
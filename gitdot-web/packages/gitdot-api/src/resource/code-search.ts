@@ -0,0 +1,32 @@
+import { z } from "zod";
+
+export const MatchRangeResource = z.object({
+  start: z.number().int(),
+  end: z.number().int(),
+});
+export type MatchRangeResource = z.infer<typeof MatchRangeResource>;
+
+export const CodeSearchMatchResource = z.object({
+  line_number: z.number().int(),
+  content: z.string(),
+  ranges: z.array(MatchRangeResource),
+  before: z.array(z.string()),
+  after: z.array(z.string()),
+});
+export type CodeSearchMatchResource = z.infer<typeof CodeSearchMatchResource>;
+
+export const CodeSearchFileResource = z.object({
+  path: z.string(),
+  blob_sha: z.string(),
+  language: z.string().optional(),
+  match_count: z.number().int(),
+  matches: z.array(CodeSearchMatchResource),
+});
+export type CodeSearchFileResource = z.infer<typeof CodeSearchFileResource>;
+
+export const CodeSearchResource = z.object({
+  commit_sha: z.string().optional(),
+  files: z.array(CodeSearchFileResource),
+  truncated: z.boolean(),
+});
+export type CodeSearchResource = z.infer<typeof CodeSearchResource>;
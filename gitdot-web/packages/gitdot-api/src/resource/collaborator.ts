@@ -0,0 +1,24 @@
+import { z } from "zod";
+
+export const CollaboratorResource = z.object({
+  user_id: z.uuid(),
+  user_name: z.string(),
+  role: z.string(),
+
+  created_at: z.iso.datetime(),
+  image_updated_at: z.iso.datetime(),
+});
+export type CollaboratorResource = z.infer<typeof CollaboratorResource>;
+
+export const RepositoryInvitationResource = z.object({
+  id: z.uuid(),
+  owner_name: z.string(),
+  repository_name: z.string(),
+  invitee_name: z.string(),
+  inviter_name: z.string(),
+  role: z.string(),
+  created_at: z.iso.datetime(),
+});
+export type RepositoryInvitationResource = z.infer<
+  typeof RepositoryInvitationResource
+>;
@@ -0,0 +1,13 @@
+import { z } from "zod";
+
+export const MaintenanceResource = z.object({
+  repository_id: z.uuid(),
+  pending_pushes: z.number().int(),
+  last_pushed_at: z.iso.datetime().nullable(),
+  requested_at: z.iso.datetime().nullable(),
+  started_at: z.iso.datetime().nullable(),
+  finished_at: z.iso.datetime().nullable(),
+  last_status: z.string().nullable(),
+  last_error: z.string().nullable(),
+});
+export type MaintenanceResource = z.infer<typeof MaintenanceResource>;
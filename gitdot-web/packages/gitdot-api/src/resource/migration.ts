@@ -37,6 +37,8 @@ export const MigrationRepositoryResource = z.object({
   visibility: z.string(),
   status: z.string(),
   error: z.string().nullable(),
+  imported_questions: z.number().int(),
+  imported_reviews: z.number().int(),
   created_at: z.iso.datetime(),
   updated_at: z.iso.datetime(),
 });
@@ -59,3 +61,21 @@ export const MigrationResource = z.object({
   repositories: z.array(MigrationRepositoryResource),
 });
 export type MigrationResource = z.infer<typeof MigrationResource>;
+
+export const RepositoryMirrorResource = z.object({
+  origin_full_name: z.string(),
+  status: z.string().nullable(),
+  error: z.string().nullable(),
+  last_attempted_at: z.iso.datetime().nullable(),
+  last_synced_at: z.iso.datetime().nullable(),
+});
+export type RepositoryMirrorResource = z.infer<typeof RepositoryMirrorResource>;
+
+export const MirrorCutoverResource = z.object({
+  id: z.uuid(),
+  origin_full_name: z.string(),
+  actor_id: z.uuid(),
+  push_mirror_id: z.uuid().nullable(),
+  created_at: z.iso.datetime(),
+});
+export type MirrorCutoverResource = z.infer<typeof MirrorCutoverResource>;
@@ -0,0 +1,29 @@
+import { z } from "zod";
+
+export const MirrorRunResource = z.object({
+  id: z.uuid(),
+  status: z.string(),
+  error: z.string().nullable(),
+  started_at: z.iso.datetime(),
+  finished_at: z.iso.datetime(),
+});
+export type MirrorRunResource = z.infer<typeof MirrorRunResource>;
+
+export const MirrorResource = z.object({
+  id: z.uuid(),
+  repository_id: z.uuid(),
+  direction: z.string(),
+  url: z.string(),
+  username: z.string().nullable(),
+  has_password: z.boolean(),
+  interval_seconds: z.number().int().nullable(),
+  requested_at: z.iso.datetime().nullable(),
+  started_at: z.iso.datetime().nullable(),
+  finished_at: z.iso.datetime().nullable(),
+  last_synced_at: z.iso.datetime().nullable(),
+  last_status: z.string().nullable(),
+  last_error: z.string().nullable(),
+  created_at: z.iso.datetime(),
+  runs: z.array(MirrorRunResource),
+});
+export type MirrorResource = z.infer<typeof MirrorResource>;
@@ -0,0 +1,25 @@
+import { z } from "zod";
+
+export const ReleaseAssetResource = z.object({
+  id: z.uuid(),
+  name: z.string(),
+  content_type: z.string(),
+  size_bytes: z.number().int(),
+  created_at: z.iso.datetime(),
+});
+export type ReleaseAssetResource = z.infer<typeof ReleaseAssetResource>;
+
+export const ReleaseResource = z.object({
+  id: z.uuid(),
+  tag_name: z.string(),
+  name: z.string(),
+  body: z.string(),
+  draft: z.boolean(),
+  prerelease: z.boolean(),
+  author_name: z.string().nullable(),
+  created_at: z.iso.datetime(),
+  updated_at: z.iso.datetime(),
+  published_at: z.iso.datetime().nullable(),
+  assets: z.array(ReleaseAssetResource),
+});
+export type ReleaseResource = z.infer<typeof ReleaseResource>;
@@ -26,6 +26,14 @@ export const CommitDiffResource = z.object({
 });
 export type CommitDiffResource = z.infer<typeof CommitDiffResource>;
 
+export const CommitSignatureStatus = z.enum([
+  "verified",
+  "unverified",
+  "unknown_key",
+  "bad_email",
+]);
+export type CommitSignatureStatus = z.infer<typeof CommitSignatureStatus>;
+
 export const RepositoryCommitResource = z.object({
   owner_name: z.string(),
   repo_name: z.string(),
@@ -37,6 +45,7 @@ export const RepositoryCommitResource = z.object({
   review_number: z.number().int().optional(),
   diff_position: z.number().int().optional(),
   diffs: z.array(CommitDiffResource),
+  signature_status: CommitSignatureStatus.optional(),
 });
 export type RepositoryCommitResource = z.infer<typeof RepositoryCommitResource>;
 
@@ -86,6 +95,72 @@ export const RepositoryBlobsResource = z.object({
 });
 export type RepositoryBlobsResource = z.infer<typeof RepositoryBlobsResource>;
 
+export const BlameRangeResource = z.object({
+  start_line: z.number().int(),
+  line_count: z.number().int(),
+  commit_sha: z.string(),
+});
+export type BlameRangeResource = z.infer<typeof BlameRangeResource>;
+
+export const RepositoryBlameResource = z.object({
+  ref_name: z.string(),
+  commit_sha: z.string(),
+  path: z.string(),
+  ranges: z.array(BlameRangeResource),
+  commits: z.array(RepositoryCommitResource),
+});
+export type RepositoryBlameResource = z.infer<typeof RepositoryBlameResource>;
+
+export const DiffLineResource = z.object({
+  origin: z.enum(["+", "-", " "]),
+  old_lineno: z.number().int().optional(),
+  new_lineno: z.number().int().optional(),
+  content: z.string(),
+});
+export type DiffLineResource = z.infer<typeof DiffLineResource>;
+
+export const DiffHunkResource = z.object({
+  header: z.string(),
+  old_start: z.number().int(),
+  old_lines: z.number().int(),
+  new_start: z.number().int(),
+  new_lines: z.number().int(),
+  lines: z.array(DiffLineResource),
+});
+export type DiffHunkResource = z.infer<typeof DiffHunkResource>;
+
+export const FileDiffResource = z.object({
+  path: z.string(),
+  old_path: z.string(),
+  status: z.enum([
+    "added",
+    "deleted",
+    "modified",
+    "renamed",
+    "copied",
+    "type_changed",
+  ]),
+  binary: z.boolean(),
+  lines_added: z.number().int(),
+  lines_removed: z.number().int(),
+  hunks: z.array(DiffHunkResource),
+});
+export type FileDiffResource = z.infer<typeof FileDiffResource>;
+
+export const RepositoryCompareResource = z.object({
+  base_sha: z.string(),
+  head_sha: z.string(),
+  merge_base_sha: z.string().optional(),
+  commits: z.array(RepositoryCommitResource),
+  total_commits: z.number().int(),
+  files: z.array(FileDiffResource),
+  total_files: z.number().int(),
+  next_cursor: z.string().optional(),
+});
+export type RepositoryCompareResource = z.infer<
+  typeof RepositoryCompareResource
+>;
+
 export const RepositoryBlobPairResource = z.object({
   path: z.string(),
   old: RepositoryBlobResource.optional(),
@@ -1,5 +1,7 @@
 import { z } from "zod";
 
+import { CommitSignatureStatus } from "./repository";
+
 export const ReviewAuthorResource = z.object({
   id: z.uuid(),
   name: z.string(),
@@ -25,6 +27,7 @@ export const RevisionResource = z.object({
   parent_hash: z.string(),
   created_at: z.iso.datetime(),
   verdicts: z.array(ReviewVerdictResource),
+  signature_status: CommitSignatureStatus.optional(),
 });
 export type RevisionResource = z.infer<typeof RevisionResource>;
 
@@ -93,3 +96,28 @@ export const ReviewResource = z.object({
   comments: z.array(ReviewCommentResource),
 });
 export type ReviewResource = z.infer<typeof ReviewResource>;
+
+export const MergeRequirementsResource = z.object({
+  required_approvals: z.number().int(),
+  block_on_rejection: z.boolean(),
+  require_resolved_comments: z.boolean(),
+  require_green_build: z.boolean(),
+});
+export type MergeRequirementsResource = z.infer<
+  typeof MergeRequirementsResource
+>;
+
+export const MergeCheckResource = z.object({
+  kind: z.string(),
+  passed: z.boolean(),
+  message: z.string(),
+});
+export type MergeCheckResource = z.infer<typeof MergeCheckResource>;
+
+export const ReviewMergeabilityResource = z.object({
+  mergeable: z.boolean(),
+  checks: z.array(MergeCheckResource),
+});
+export type ReviewMergeabilityResource = z.infer<
+  typeof ReviewMergeabilityResource
+>;
@@ -0,0 +1,8 @@
+import { z } from "zod";
+
+export const SecretResource = z.object({
+  name: z.string(),
+  created_at: z.iso.datetime(),
+  updated_at: z.iso.datetime(),
+});
+export type SecretResource = z.infer<typeof SecretResource>;
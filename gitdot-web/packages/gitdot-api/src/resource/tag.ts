@@ -0,0 +1,21 @@
+import { z } from "zod";
+
+import { RepositoryCommitResource } from "./repository";
+
+export const TaggerResource = z.object({
+  name: z.string(),
+  email: z.string(),
+  date: z.iso.datetime(),
+});
+export type TaggerResource = z.infer<typeof TaggerResource>;
+
+export const TagResource = z.object({
+  name: z.string(),
+  sha: z.string(),
+  commit: RepositoryCommitResource,
+  annotated: z.boolean(),
+  message: z.string().optional(),
+  tagger: TaggerResource.optional(),
+  signature: z.string().optional(),
+});
+export type TagResource = z.infer<typeof TagResource>;
@@ -7,9 +7,14 @@ export const TaskStatus = z.enum([
   "running",
   "success",
   "failure",
+  "cancelled",
+  "timed_out",
 ]);
 export type TaskStatus = z.infer<typeof TaskStatus>;
 
+export const TaskNetwork = z.enum(["none", "outbound"]);
+export type TaskNetwork = z.infer<typeof TaskNetwork>;
+
 export const TaskResource = z.object({
   id: z.uuid(),
   repository_id: z.uuid(),
@@ -19,6 +24,18 @@ export const TaskResource = z.object({
   command: z.string(),
   status: TaskStatus,
   waits_for: z.array(z.uuid()),
+  attempt: z.number(),
+  env: z.record(z.string(), z.string()),
+  timeout_seconds: z.number().nullable(),
+  retries: z.number(),
+  working_directory: z.string().nullable(),
+  allow_failure: z.boolean(),
+  artifacts: z.array(z.string()),
+  cache_key: z.string().nullable(),
+  cache_paths: z.array(z.string()),
+  image: z.string().nullable(),
+  network: TaskNetwork,
+  secrets: z.array(z.string()),
   created_at: z.iso.datetime(),
   updated_at: z.iso.datetime(),
 });
@@ -33,6 +50,18 @@ export const PollTaskResource = z.object({
   name: z.string(),
   command: z.string(),
   status: TaskStatus,
+  env: z.record(z.string(), z.string()),
+  timeout_seconds: z.number().nullable(),
+  retries: z.number(),
+  working_directory: z.string().nullable(),
+  allow_failure: z.boolean(),
+  artifacts: z.array(z.string()),
+  cache_key: z.string().nullable(),
+  cache_paths: z.array(z.string()),
+  image: z.string().nullable(),
+  network: TaskNetwork,
+  secrets: z.record(z.string(), z.string()),
+  secrets_withheld: z.boolean(),
 });
 export type PollTaskResource = z.infer<typeof PollTaskResource>;
 
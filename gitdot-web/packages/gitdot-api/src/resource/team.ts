@@ -0,0 +1,29 @@
+import { z } from "zod";
+
+export const TeamMemberResource = z.object({
+  user_id: z.uuid(),
+  user_name: z.string(),
+
+  created_at: z.iso.datetime(),
+  image_updated_at: z.iso.datetime(),
+});
+export type TeamMemberResource = z.infer<typeof TeamMemberResource>;
+
+export const TeamRepositoryResource = z.object({
+  repository_id: z.uuid(),
+  repository_name: z.string(),
+  role: z.string(),
+  created_at: z.iso.datetime(),
+});
+export type TeamRepositoryResource = z.infer<typeof TeamRepositoryResource>;
+
+export const TeamResource = z.object({
+  id: z.uuid(),
+  name: z.string(),
+  description: z.string().nullable().optional(),
+  created_at: z.iso.datetime(),
+
+  members: z.array(TeamMemberResource).nullable().optional(),
+  repositories: z.array(TeamRepositoryResource).nullable().optional(),
+});
+export type TeamResource = z.infer<typeof TeamResource>;
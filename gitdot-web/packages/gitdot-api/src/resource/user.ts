@@ -56,3 +56,47 @@ export const CurrentUserResource = z.object({
   image_updated_at: z.iso.datetime(),
 });
 export type CurrentUserResource = z.infer<typeof CurrentUserResource>;
+
+export const TokenScope = z.enum([
+  "repo:read",
+  "repo:write",
+  "review:write",
+  "runner:admin",
+  "admin:org",
+]);
+export type TokenScope = z.infer<typeof TokenScope>;
+
+export const PersonalTokenResource = z.object({
+  id: z.uuid(),
+  name: z.string(),
+  scopes: z.array(TokenScope),
+  expires_at: z.iso.datetime().nullable(),
+  created_at: z.iso.datetime(),
+  last_used_at: z.iso.datetime().nullable(),
+});
+export type PersonalTokenResource = z.infer<typeof PersonalTokenResource>;
+
+export const NewPersonalTokenResource = z.object({
+  token: z.string(),
+  personal_token: PersonalTokenResource,
+});
+export type NewPersonalTokenResource = z.infer<typeof NewPersonalTokenResource>;
+
+export const SshKeyResource = z.object({
+  id: z.uuid(),
+  name: z.string(),
+  key_type: z.string(),
+  fingerprint: z.string(),
+  created_at: z.iso.datetime(),
+  last_used_at: z.iso.datetime().nullable(),
+});
+export type SshKeyResource = z.infer<typeof SshKeyResource>;
+
+export const SigningKeyResource = z.object({
+  id: z.uuid(),
+  name: z.string(),
+  key_type: z.enum(["gpg", "ssh"]),
+  fingerprint: z.string(),
+  created_at: z.iso.datetime(),
+});
+export type SigningKeyResource = z.infer<typeof SigningKeyResource>;
@@ -10,3 +10,20 @@ export const WebhookResource = z.object({
   updated_at: z.iso.datetime(),
 });
 export type WebhookResource = z.infer<typeof WebhookResource>;
+
+export const WebhookDeliveryResource = z.object({
+  id: z.uuid(),
+  webhook_id: z.uuid(),
+  event: z.string(),
+  payload: z.unknown(),
+  status: z.string(),
+  attempts: z.number().int(),
+  status_code: z.number().int().nullable(),
+  duration_ms: z.number().int().nullable(),
+  response_excerpt: z.string().nullable(),
+  error: z.string().nullable(),
+  redelivery_of: z.uuid().nullable(),
+  created_at: z.iso.datetime(),
+  delivered_at: z.iso.datetime().nullable(),
+});
+export type WebhookDeliveryResource = z.infer<typeof WebhookDeliveryResource>;
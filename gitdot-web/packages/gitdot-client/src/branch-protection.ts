@@ -0,0 +1,63 @@
+import "server-only";
+
+import {
+  BranchProtectionResource,
+  type CreateBranchProtectionRequest,
+  ListBranchProtectionsResponse,
+  type UpdateBranchProtectionRequest,
+} from "gitdot-api";
+import {
+  authDelete,
+  authFetch,
+  authPatch,
+  authPost,
+  GITDOT_SERVER_URL,
+  handleResponse,
+} from "./util";
+
+export async function listBranchProtections(
+  owner: string,
+  repo: string,
+): Promise<ListBranchProtectionsResponse | null> {
+  const response = await authFetch(
+    `${GITDOT_SERVER_URL}/repository/${encodeURIComponent(owner)}/${encodeURIComponent(repo)}/branch-protections`,
+  );
+  return await handleResponse(response, ListBranchProtectionsResponse);
+}
+
+export async function createBranchProtection(
+  owner: string,
+  repo: string,
+  request: CreateBranchProtectionRequest,
+): Promise<BranchProtectionResource | null> {
+  const response = await authPost(
+    `${GITDOT_SERVER_URL}/repository/${encodeURIComponent(owner)}/${encodeURIComponent(repo)}/branch-protections`,
+    request,
+  );
+
+  return await handleResponse(response, BranchProtectionResource);
+}
+
+export async function updateBranchProtection(
+  owner: string,
+  repo: string,
+  protectionId: string,
+  request: UpdateBranchProtectionRequest,
+): Promise<BranchProtectionResource | null> {
+  const response = await authPatch(
+    `${GITDOT_SERVER_URL}/repository/${encodeURIComponent(owner)}/${encodeURIComponent(repo)}/branch-protections/${protectionId}`,
+    request,
+  );
+
+  return await handleResponse(response, BranchProtectionResource);
+}
+
+export async function deleteBranchProtection(
+  owner: string,
+  repo: string,
+  protectionId: string,
+): Promise<void> {
+  await authDelete(
+    `${GITDOT_SERVER_URL}/repository/${encodeURIComponent(owner)}/${encodeURIComponent(repo)}/branch-protections/${protectionId}`,
+  );
+}
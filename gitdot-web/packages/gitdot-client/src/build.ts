@@ -3,7 +3,9 @@ import "server-only";
 import {
   BuildResource,
   type CreateBuildRequest,
+  ListBuildArtifactsResponse,
   ListBuildsResponse,
+  TaskResource,
 } from "gitdot-api";
 import {
   authFetch,
@@ -16,9 +18,13 @@ import {
 export async function getBuilds(
   owner: string,
   repo: string,
-  opts?: { cursor?: string; limit?: number },
+  opts?: { review?: number; cursor?: string; limit?: number },
 ): Promise<ListBuildsResponse | null> {
-  const qs = toQueryString({ cursor: opts?.cursor, limit: opts?.limit });
+  const qs = toQueryString({
+    review: opts?.review,
+    cursor: opts?.cursor,
+    limit: opts?.limit,
+  });
   const url = `${GITDOT_SERVER_URL}/repository/${encodeURIComponent(owner)}/${encodeURIComponent(repo)}/builds${qs ? `?${qs}` : ""}`;
   const response = await authFetch(url);
   return await handleResponse(response, ListBuildsResponse);
@@ -48,3 +54,54 @@ export async function getBuild(
 
   return await handleResponse(response, BuildResource);
 }
+
+export async function cancelBuild(
+  owner: string,
+  repo: string,
+  number: number,
+): Promise<BuildResource | null> {
+  const response = await authPost(
+    `${GITDOT_SERVER_URL}/repository/${encodeURIComponent(owner)}/${encodeURIComponent(repo)}/build/${number}/cancel`,
+    {},
+  );
+
+  return await handleResponse(response, BuildResource);
+}
+
+export async function retryBuildTask(
+  owner: string,
+  repo: string,
+  number: number,
+  taskId: string,
+): Promise<TaskResource | null> {
+  const response = await authPost(
+    `${GITDOT_SERVER_URL}/repository/${encodeURIComponent(owner)}/${encodeURIComponent(repo)}/build/${number}/task/${encodeURIComponent(taskId)}/retry`,
+    {},
+  );
+
+  return await handleResponse(response, TaskResource);
+}
+
+export async function getBuildArtifacts(
+  owner: string,
+  repo: string,
+  number: number,
+): Promise<ListBuildArtifactsResponse | null> {
+  const response = await authFetch(
+    `${GITDOT_SERVER_URL}/repository/${encodeURIComponent(owner)}/${encodeURIComponent(repo)}/build/${number}/artifacts`,
+  );
+
+  return await handleResponse(response, ListBuildArtifactsResponse);
+}
+
+/** Returns the raw server response so the tarball can be streamed through. */
+export async function downloadBuildArtifact(
+  owner: string,
+  repo: string,
+  number: number,
+  artifactId: string,
+): Promise<Response> {
+  return await authFetch(
+    `${GITDOT_SERVER_URL}/repository/${encodeURIComponent(owner)}/${encodeURIComponent(repo)}/build/${number}/artifact/${encodeURIComponent(artifactId)}`,
+  );
+}
@@ -1,6 +1,7 @@
 import "server-only";
 
 export * from "./auth";
+export * from "./branch-protection";
 export * from "./build";
 export * from "./migration";
 export * from "./organization";
@@ -6,13 +6,16 @@ import type {
   ReviewReviewDiffRequest,
   UpdateReviewCommentRequest,
   UpdateReviewDiffRequest,
+  UpdateReviewMergeRequirementsRequest,
   UpdateReviewRequest,
 } from "gitdot-api";
 import {
   GetReviewDiffBlobsResponse,
   ListReviewsResponse,
+  MergeRequirementsResource,
   ReviewCommentResource,
   ReviewerResource,
+  ReviewMergeabilityResource,
   ReviewResource,
 } from "gitdot-api";
 import {
@@ -20,6 +23,7 @@ import {
   authFetch,
   authPatch,
   authPost,
+  authPut,
   GITDOT_SERVER_URL,
   handleEmptyResponse,
   handleResponse,
@@ -181,6 +185,43 @@ export async function mergeDiff(
   return await handleResponse(response, ReviewResource);
 }
 
+export async function getDiffMergeability(
+  owner: string,
+  repo: string,
+  number: number,
+  position: number,
+): Promise<ReviewMergeabilityResource | null> {
+  const response = await authFetch(
+    `${GITDOT_SERVER_URL}/repository/${owner}/${repo}/review/${number}/diff/${position}/mergeability`,
+  );
+
+  return await handleResponse(response, ReviewMergeabilityResource);
+}
+
+export async function getReviewMergeRequirements(
+  owner: string,
+  repo: string,
+): Promise<MergeRequirementsResource | null> {
+  const response = await authFetch(
+    `${GITDOT_SERVER_URL}/repository/${owner}/${repo}/review-merge-requirements`,
+  );
+
+  return await handleResponse(response, MergeRequirementsResource);
+}
+
+export async function updateReviewMergeRequirements(
+  owner: string,
+  repo: string,
+  request: UpdateReviewMergeRequirementsRequest,
+): Promise<MergeRequirementsResource | null> {
+  const response = await authPut(
+    `${GITDOT_SERVER_URL}/repository/${owner}/${repo}/review-merge-requirements`,
+    request,
+  );
+
+  return await handleResponse(response, MergeRequirementsResource);
+}
+
 export async function updateReviewComment(
   owner: string,
   repo: string,
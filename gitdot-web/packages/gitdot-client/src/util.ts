@@ -97,6 +97,19 @@ export async function authPatch(
   });
 }
 
+export async function authPut(
+  url: string,
+  request: unknown,
+): Promise<Response> {
+  return await authFetch(url, {
+    method: "PUT",
+    headers: {
+      "Content-Type": "application/json",
+    },
+    body: JSON.stringify(request),
+  });
+}
+
 export class ApiError extends Error {
   constructor(
     public readonly status: number,
@@ -2,8 +2,10 @@ import "server-only";
 
 import {
   type CreateWebhookRequest,
+  ListWebhookDeliveriesResponse,
   ListWebhooksResponse,
   type UpdateWebhookRequest,
+  WebhookDeliveryResource,
   WebhookResource,
 } from "gitdot-api";
 import {
@@ -63,3 +65,29 @@ export async function deleteWebhook(
     `${GITDOT_SERVER_URL}/repository/${encodeURIComponent(owner)}/${encodeURIComponent(repo)}/webhook/${webhookId}`,
   );
 }
+
+export async function listWebhookDeliveries(
+  owner: string,
+  repo: string,
+  webhookId: string,
+  opts?: { cursor?: string; limit?: number },
+): Promise<ListWebhookDeliveriesResponse | null> {
+  const qs = toQueryString({ cursor: opts?.cursor, limit: opts?.limit });
+  const url = `${GITDOT_SERVER_URL}/repository/${encodeURIComponent(owner)}/${encodeURIComponent(repo)}/webhook/${webhookId}/deliveries${qs ? `?${qs}` : ""}`;
+  const response = await authFetch(url);
+  return await handleResponse(response, ListWebhookDeliveriesResponse);
+}
+
+export async function redeliverWebhookDelivery(
+  owner: string,
+  repo: string,
+  webhookId: string,
+  deliveryId: string,
+): Promise<WebhookDeliveryResource | null> {
+  const response = await authPost(
+    `${GITDOT_SERVER_URL}/repository/${encodeURIComponent(owner)}/${encodeURIComponent(repo)}/webhook/${webhookId}/delivery/${deliveryId}/redeliver`,
+    {},
+  );
+
+  return await handleResponse(response, WebhookDeliveryResource);
+}